@@ -0,0 +1,217 @@
+use crate::Color32;
+
+/// A 4×5 matrix for transforming colors, like the `feColorMatrix` filter of SVG.
+///
+/// Each row computes one of the red, green, blue and alpha channels of the output
+/// from the input channels, plus an offset in the last column:
+///
+/// ```text
+/// r' = m[0][0] * r + m[0][1] * g + m[0][2] * b + m[0][3] * a + m[0][4]
+/// ```
+///
+/// The channels are unmultiplied and in gamma space, in the range `0-1`.
+///
+/// Combine filters with [`Self::then`], e.g. `ColorMatrix::grayscale(1.0).then(ColorMatrix::opacity(0.5))`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ColorMatrix(pub [[f32; 5]; 4]);
+
+impl Eq for ColorMatrix {}
+
+impl Default for ColorMatrix {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl ColorMatrix {
+    /// Leaves all colors unchanged.
+    pub const IDENTITY: Self = Self([
+        [1.0, 0.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0, 0.0],
+    ]);
+
+    /// Multiply each channel with the matching channel of `color`, like a tint.
+    pub fn tint(color: Color32) -> Self {
+        let [r, g, b, a] = color.to_srgba_unmultiplied().map(|c| c as f32 / 255.0);
+        Self::scale([r, g, b, a])
+    }
+
+    /// Multiply each channel with a factor.
+    pub fn scale([r, g, b, a]: [f32; 4]) -> Self {
+        Self([
+            [r, 0.0, 0.0, 0.0, 0.0],
+            [0.0, g, 0.0, 0.0, 0.0],
+            [0.0, 0.0, b, 0.0, 0.0],
+            [0.0, 0.0, 0.0, a, 0.0],
+        ])
+    }
+
+    /// Multiply the alpha with `opacity`.
+    pub fn opacity(opacity: f32) -> Self {
+        Self::scale([1.0, 1.0, 1.0, opacity])
+    }
+
+    /// Remove the colors, keeping the luminance.
+    ///
+    /// `amount` goes from `0` (unchanged) to `1` (completely gray).
+    pub fn grayscale(amount: f32) -> Self {
+        let t = 1.0 - amount.clamp(0.0, 1.0);
+        Self([
+            [
+                0.2126 + 0.7874 * t,
+                0.7152 - 0.7152 * t,
+                0.0722 - 0.0722 * t,
+                0.0,
+                0.0,
+            ],
+            [
+                0.2126 - 0.2126 * t,
+                0.7152 + 0.2848 * t,
+                0.0722 - 0.0722 * t,
+                0.0,
+                0.0,
+            ],
+            [
+                0.2126 - 0.2126 * t,
+                0.7152 - 0.7152 * t,
+                0.0722 + 0.9278 * t,
+                0.0,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    /// Make the colors look like an old photograph.
+    ///
+    /// `amount` goes from `0` (unchanged) to `1` (completely sepia).
+    pub fn sepia(amount: f32) -> Self {
+        let t = 1.0 - amount.clamp(0.0, 1.0);
+        Self([
+            [
+                0.393 + 0.607 * t,
+                0.769 - 0.769 * t,
+                0.189 - 0.189 * t,
+                0.0,
+                0.0,
+            ],
+            [
+                0.349 - 0.349 * t,
+                0.686 + 0.314 * t,
+                0.168 - 0.168 * t,
+                0.0,
+                0.0,
+            ],
+            [
+                0.272 - 0.272 * t,
+                0.534 - 0.534 * t,
+                0.131 + 0.869 * t,
+                0.0,
+                0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    /// Invert the colors, keeping the alpha.
+    ///
+    /// `amount` goes from `0` (unchanged) to `1` (completely inverted).
+    pub fn invert(amount: f32) -> Self {
+        let a = amount.clamp(0.0, 1.0);
+        let s = 1.0 - 2.0 * a;
+        Self([
+            [s, 0.0, 0.0, 0.0, a],
+            [0.0, s, 0.0, 0.0, a],
+            [0.0, 0.0, s, 0.0, a],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ])
+    }
+
+    /// First apply `self`, then `other`.
+    pub fn then(&self, other: Self) -> Self {
+        let (a, b) = (self.0, other.0);
+        let mut out = [[0.0; 5]; 4];
+        for (row, out_row) in out.iter_mut().enumerate() {
+            for (col, out_value) in out_row.iter_mut().enumerate() {
+                *out_value = (0..4).map(|k| b[row][k] * a[k][col]).sum();
+            }
+            out_row[4] += b[row][4];
+        }
+        Self(out)
+    }
+
+    /// Transform unmultiplied gamma-space channels in the range `0-1`.
+    pub fn apply_to_array(&self, rgba: [f32; 4]) -> [f32; 4] {
+        self.0.map(|row| {
+            let value: f32 = (0..4).map(|k| row[k] * rgba[k]).sum();
+            (value + row[4]).clamp(0.0, 1.0)
+        })
+    }
+
+    /// Transform a color.
+    pub fn apply(&self, color: Color32) -> Color32 {
+        let rgba = color.to_srgba_unmultiplied().map(|c| c as f32 / 255.0);
+        let [r, g, b, a] = self.apply_to_array(rgba).map(|c| (c * 255.0 + 0.5) as u8);
+        Color32::from_rgba_unmultiplied(r, g, b, a)
+    }
+
+    /// The columns of the matrix, with the offsets last.
+    ///
+    /// This is the column-major layout of a `mat4` plus a `vec4` in a shader.
+    pub fn columns(&self) -> [[f32; 4]; 5] {
+        std::array::from_fn(|col| self.0.map(|row| row[col]))
+    }
+
+    /// If this only scales each channel by itself, return the scale factors.
+    ///
+    /// Such a matrix can be applied by multiplying with a color,
+    /// e.g. as the tint of an image, instead of transforming every pixel.
+    pub fn as_scale(&self) -> Option<[f32; 4]> {
+        let mut scale = [0.0; 4];
+        for (row, values) in self.0.iter().enumerate() {
+            for (col, &value) in values.iter().enumerate() {
+                if col == row {
+                    scale[row] = value;
+                } else if value != 0.0 {
+                    return None;
+                }
+            }
+        }
+        Some(scale)
+    }
+
+    /// Is this [`Self::IDENTITY`]?
+    #[inline]
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+}
+
+#[test]
+fn test_color_matrix() {
+    let orange = Color32::from_rgb(255, 128, 0);
+    assert_eq!(ColorMatrix::IDENTITY.apply(orange), orange);
+    assert_eq!(
+        ColorMatrix::invert(1.0).apply(orange),
+        Color32::from_rgb(0, 127, 255)
+    );
+
+    let gray = ColorMatrix::grayscale(1.0).apply(orange);
+    assert!(gray.r() == gray.g() && gray.g() == gray.b());
+    assert_eq!(ColorMatrix::grayscale(0.0), ColorMatrix::IDENTITY);
+
+    assert_eq!(ColorMatrix::opacity(0.5).apply(orange).a(), 128);
+    assert_eq!(
+        ColorMatrix::opacity(0.5).as_scale(),
+        Some([1.0, 1.0, 1.0, 0.5])
+    );
+    assert_eq!(ColorMatrix::sepia(1.0).as_scale(), None);
+
+    // Inverting twice is the same as not inverting:
+    let twice = ColorMatrix::invert(1.0).then(ColorMatrix::invert(1.0));
+    assert_eq!(twice, ColorMatrix::IDENTITY);
+}
@@ -0,0 +1,243 @@
+use crate::{Color32, Rgba};
+
+/// Hue, saturation, lightness and alpha in the `HSLuv` color space. All in the range [0, 1].
+/// No premultiplied alpha.
+///
+/// This is like HSL, but with a perceptually uniform lightness,
+/// and every combination of the values is a displayable sRGB color.
+///
+/// See <https://www.hsluv.org>.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsluva {
+    /// hue 0-1
+    pub h: f32,
+
+    /// saturation 0-1
+    pub s: f32,
+
+    /// lightness 0-1
+    pub l: f32,
+
+    /// alpha 0-1
+    pub a: f32,
+}
+
+// The math is done in f64, as the intermediate values are large.
+
+/// Converts linear RGB to CIE XYZ.
+const XYZ_FROM_RGB: [[f64; 3]; 3] = [
+    [
+        0.412_390_799_265_95,
+        0.357_584_339_383_87,
+        0.180_480_788_401_83,
+    ],
+    [
+        0.212_639_005_871_51,
+        0.715_168_678_767_75,
+        0.072_192_315_360_733,
+    ],
+    [
+        0.019_330_818_715_591,
+        0.119_194_779_794_62,
+        0.950_532_152_249_66,
+    ],
+];
+
+/// Converts CIE XYZ to linear RGB.
+const RGB_FROM_XYZ: [[f64; 3]; 3] = [
+    [
+        3.240_969_941_904_521,
+        -1.537_383_177_570_093,
+        -0.498_610_760_293,
+    ],
+    [
+        -0.969_243_636_280_87,
+        1.875_967_501_507_72,
+        0.041_555_057_407_175,
+    ],
+    [
+        0.055_630_079_696_993,
+        -0.203_976_958_888_97,
+        1.056_971_514_242_878,
+    ],
+];
+
+const REF_U: f64 = 0.197_830_006_642_83;
+const REF_V: f64 = 0.468_319_994_938_79;
+const KAPPA: f64 = 903.296_296_296_296_3;
+const EPSILON: f64 = 0.008_856_451_679_035_631;
+
+impl Hsluva {
+    #[inline]
+    pub fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self { h, s, l, a }
+    }
+
+    /// From linear RGB.
+    pub fn from_rgb(rgb: [f32; 3]) -> Self {
+        let [x, y, z] =
+            XYZ_FROM_RGB.map(|row| row.iter().zip(rgb).map(|(m, c)| m * c as f64).sum::<f64>());
+
+        // XYZ to CIE LUV:
+        let l = if y <= EPSILON {
+            y * KAPPA
+        } else {
+            116.0 * y.cbrt() - 16.0
+        };
+        let divider = x + 15.0 * y + 3.0 * z;
+        let (u, v) = if l == 0.0 || divider == 0.0 {
+            (0.0, 0.0)
+        } else {
+            (
+                13.0 * l * (4.0 * x / divider - REF_U),
+                13.0 * l * (9.0 * y / divider - REF_V),
+            )
+        };
+
+        // LUV to LCh:
+        let c = u.hypot(v);
+        let h = if c < 1e-8 {
+            0.0 // hue is undefined
+        } else {
+            (v.atan2(u) / std::f64::consts::TAU + 1.0).fract()
+        };
+
+        // LCh to HSLuv:
+        let s = if !(1e-8..=99.999_999_9).contains(&l) {
+            0.0
+        } else {
+            c / max_chroma_for_lh(l, h)
+        };
+
+        Self {
+            h: h as f32,
+            s: s.clamp(0.0, 1.0) as f32,
+            l: (l / 100.0).clamp(0.0, 1.0) as f32,
+            a: 1.0,
+        }
+    }
+
+    /// To linear RGB.
+    pub fn to_rgb(&self) -> [f32; 3] {
+        let h = (self.h as f64).rem_euclid(1.0);
+        let s = (self.s as f64).clamp(0.0, 1.0);
+        let l = 100.0 * (self.l as f64).clamp(0.0, 1.0);
+
+        if l < 1e-8 {
+            return [0.0; 3];
+        }
+        if 99.999_999_9 < l {
+            return [1.0; 3];
+        }
+
+        // HSLuv to LCh to LUV:
+        let c = s * max_chroma_for_lh(l, h);
+        let (sin, cos) = (h * std::f64::consts::TAU).sin_cos();
+        let (u, v) = (c * cos, c * sin);
+
+        // LUV to XYZ:
+        let var_u = u / (13.0 * l) + REF_U;
+        let var_v = v / (13.0 * l) + REF_V;
+        let y = if l <= 8.0 {
+            l / KAPPA
+        } else {
+            ((l + 16.0) / 116.0).powi(3)
+        };
+        let x = -(9.0 * y * var_u) / ((var_u - 4.0) * var_v - var_u * var_v);
+        let z = (9.0 * y - 15.0 * var_v * y - var_v * x) / (3.0 * var_v);
+
+        RGB_FROM_XYZ.map(|[m0, m1, m2]| ((m0 * x + m1 * y + m2 * z) as f32).clamp(0.0, 1.0))
+    }
+}
+
+/// The highest chroma in the sRGB gamut for this lightness (0-100) and hue (0-1).
+fn max_chroma_for_lh(l: f64, h: f64) -> f64 {
+    let (sin, cos) = (h * std::f64::consts::TAU).sin_cos();
+
+    let sub1 = (l + 16.0).powi(3) / 1_560_896.0;
+    let sub2 = if sub1 > EPSILON { sub1 } else { l / KAPPA };
+
+    let mut max_chroma = f64::INFINITY;
+    for [m1, m2, m3] in RGB_FROM_XYZ {
+        for t in [0.0, 1.0] {
+            // Each of the six sides of the gamut is a line in the (u, v) plane:
+            let top1 = (284_517.0 * m1 - 94_839.0 * m3) * sub2;
+            let top2 =
+                (838_422.0 * m3 + 769_860.0 * m2 + 731_718.0 * m1) * l * sub2 - 769_860.0 * t * l;
+            let bottom = (632_260.0 * m3 - 126_452.0 * m2) * sub2 + 126_452.0 * t;
+            let (slope, intercept) = (top1 / bottom, top2 / bottom);
+
+            let length = intercept / (sin - slope * cos);
+            if 0.0 <= length {
+                max_chroma = max_chroma.min(length);
+            }
+        }
+    }
+    max_chroma
+}
+
+impl From<Hsluva> for Rgba {
+    fn from(hsluva: Hsluva) -> Self {
+        let [r, g, b] = hsluva.to_rgb();
+        Self::from_rgba_unmultiplied(r, g, b, hsluva.a)
+    }
+}
+
+impl From<Rgba> for Hsluva {
+    fn from(rgba: Rgba) -> Self {
+        let [r, g, b, a] = rgba.to_rgba_unmultiplied();
+        Self {
+            a,
+            ..Self::from_rgb([r, g, b])
+        }
+    }
+}
+
+impl From<Hsluva> for Color32 {
+    fn from(hsluva: Hsluva) -> Self {
+        Rgba::from(hsluva).into()
+    }
+}
+
+impl From<Color32> for Hsluva {
+    fn from(srgba: Color32) -> Self {
+        Rgba::from(srgba).into()
+    }
+}
+
+#[test]
+fn test_hsluv_reference_values() {
+    // Reference values from https://www.hsluv.org
+    let check = |srgb: Color32, h_degrees: f32, s_percent: f32, l_percent: f32| {
+        let hsluva = Hsluva::from(srgb);
+        if 0.0 < s_percent {
+            assert!(
+                (hsluva.h * 360.0 - h_degrees).abs() < 0.1,
+                "{srgb:?}: {hsluva:?}"
+            );
+        }
+        assert!(
+            (hsluva.s * 100.0 - s_percent).abs() < 0.1,
+            "{srgb:?}: {hsluva:?}"
+        );
+        assert!(
+            (hsluva.l * 100.0 - l_percent).abs() < 0.1,
+            "{srgb:?}: {hsluva:?}"
+        );
+        assert_eq!(Color32::from(hsluva), srgb);
+    };
+    check(Color32::WHITE, 0.0, 0.0, 100.0);
+    check(Color32::BLACK, 0.0, 0.0, 0.0);
+    check(Color32::from_rgb(255, 0, 0), 12.177, 100.0, 53.237);
+    check(Color32::from_rgb(0, 255, 0), 127.715, 100.0, 87.737);
+    check(Color32::from_rgb(0, 0, 255), 265.874, 100.0, 32.301);
+
+    for r in (0..=255).step_by(17) {
+        for g in (0..=255).step_by(17) {
+            for b in (0..=255).step_by(17) {
+                let srgb = Color32::from_rgb(r, g, b);
+                assert_eq!(Color32::from(Hsluva::from(srgb)), srgb);
+            }
+        }
+    }
+}
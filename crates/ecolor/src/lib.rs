@@ -16,12 +16,21 @@ mod cint_impl;
 mod color32;
 pub use color32::*;
 
+mod color_matrix;
+pub use color_matrix::*;
+
 mod hsva_gamma;
 pub use hsva_gamma::*;
 
 mod hsva;
 pub use hsva::*;
 
+mod hsluv;
+pub use hsluv::*;
+
+mod oklch;
+pub use oklch::*;
+
 #[cfg(feature = "color-hex")]
 mod hex_color_macro;
 #[cfg(feature = "color-hex")]
@@ -0,0 +1,152 @@
+use crate::{Color32, Rgba};
+
+/// Lightness, chroma, hue and alpha in the perceptually uniform OKLCH color space.
+/// No premultiplied alpha.
+///
+/// Unlike [`crate::Hsva`], equal steps in lightness and hue look like equal steps to the eye.
+/// Not all combinations are displayable: those outside the sRGB gamut are clamped when converted.
+///
+/// See <https://bottosson.github.io/posts/oklab/>.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklcha {
+    /// lightness 0-1
+    pub l: f32,
+
+    /// chroma, 0 for grays and up to about [`Self::MAX_CHROMA`] for the most saturated sRGB colors
+    pub c: f32,
+
+    /// hue 0-1
+    pub h: f32,
+
+    /// alpha 0-1
+    pub a: f32,
+}
+
+impl Oklcha {
+    /// The highest chroma of any sRGB color (pure blue is about `0.313`).
+    pub const MAX_CHROMA: f32 = 0.32;
+
+    #[inline]
+    pub fn new(l: f32, c: f32, h: f32, a: f32) -> Self {
+        Self { l, c, h, a }
+    }
+
+    /// From linear RGB, not clamped.
+    pub fn from_rgb([r, g, b]: [f32; 3]) -> Self {
+        let l = 0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b;
+        let m = 0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b;
+        let s = 0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b;
+
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+
+        let lightness = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+        let a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+        let b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+
+        let c = a.hypot(b);
+        let h = if c < 1e-6 {
+            0.0 // hue is undefined
+        } else {
+            (b.atan2(a) / std::f32::consts::TAU + 1.0).fract()
+        };
+        Self {
+            l: lightness,
+            c,
+            h,
+            a: 1.0,
+        }
+    }
+
+    /// To linear RGB, not clamped, so it may be outside the `0-1` range.
+    pub fn to_rgb(&self) -> [f32; 3] {
+        let (sin, cos) = (self.h * std::f32::consts::TAU).sin_cos();
+        let (a, b) = (self.c * cos, self.c * sin);
+
+        let l = self.l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m = self.l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s = self.l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+
+    /// Is this color inside the sRGB gamut, i.e. can it be shown without clamping?
+    pub fn is_in_gamut(&self) -> bool {
+        let eps = 1e-4;
+        self.to_rgb()
+            .iter()
+            .all(|&x| (-eps..=1.0 + eps).contains(&x))
+    }
+}
+
+impl From<Oklcha> for Rgba {
+    fn from(oklcha: Oklcha) -> Self {
+        let [r, g, b] = oklcha.to_rgb();
+        Self::from_rgba_unmultiplied(
+            r.clamp(0.0, 1.0),
+            g.clamp(0.0, 1.0),
+            b.clamp(0.0, 1.0),
+            oklcha.a,
+        )
+    }
+}
+
+impl From<Rgba> for Oklcha {
+    fn from(rgba: Rgba) -> Self {
+        let [r, g, b, a] = rgba.to_rgba_unmultiplied();
+        Self {
+            a,
+            ..Self::from_rgb([r, g, b])
+        }
+    }
+}
+
+impl From<Oklcha> for Color32 {
+    fn from(oklcha: Oklcha) -> Self {
+        Rgba::from(oklcha).into()
+    }
+}
+
+impl From<Color32> for Oklcha {
+    fn from(srgba: Color32) -> Self {
+        Rgba::from(srgba).into()
+    }
+}
+
+#[test]
+fn test_oklch_reference_values() {
+    // Reference values from https://oklch.com
+    let check = |srgb: Color32, l: f32, c: f32, h_degrees: f32| {
+        let oklcha = Oklcha::from(srgb);
+        assert!((oklcha.l - l).abs() < 1e-3, "{srgb:?}: {oklcha:?}");
+        assert!((oklcha.c - c).abs() < 1e-3, "{srgb:?}: {oklcha:?}");
+        if 0.0 < c {
+            assert!(
+                (oklcha.h * 360.0 - h_degrees).abs() < 0.1,
+                "{srgb:?}: {oklcha:?}"
+            );
+        }
+        assert_eq!(Color32::from(oklcha), srgb);
+    };
+    check(Color32::WHITE, 1.0, 0.0, 0.0);
+    check(Color32::BLACK, 0.0, 0.0, 0.0);
+    check(Color32::from_rgb(255, 0, 0), 0.627_96, 0.257_68, 29.234);
+    check(Color32::from_rgb(0, 255, 0), 0.866_44, 0.294_83, 142.495);
+    check(Color32::from_rgb(0, 0, 255), 0.452_01, 0.313_21, 264.052);
+
+    for r in (0..=255).step_by(17) {
+        for g in (0..=255).step_by(17) {
+            for b in (0..=255).step_by(17) {
+                let srgb = Color32::from_rgb(r, g, b);
+                assert_eq!(Color32::from(Oklcha::from(srgb)), srgb);
+            }
+        }
+    }
+
+    assert!(!Oklcha::new(0.9, 0.3, 0.8, 1.0).is_in_gamut());
+}
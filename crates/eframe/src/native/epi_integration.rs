@@ -378,7 +378,7 @@ impl EpiIntegration {
                     );
                 }
             }
-            if _app.persist_egui_memory() {
+            if _app.persist_egui_memory() && self.egui_ctx.options(|o| o.persist_memory) {
                 crate::profile_scope!("egui_memory");
                 self.egui_ctx
                     .memory(|mem| epi::set_value(storage, STORAGE_EGUI_MEMORY_KEY, mem));
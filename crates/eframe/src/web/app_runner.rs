@@ -161,7 +161,7 @@ impl AppRunner {
     }
 
     pub fn save(&mut self) {
-        if self.app.persist_egui_memory() {
+        if self.app.persist_egui_memory() && self.egui_ctx.options(|o| o.persist_memory) {
             super::storage::save_memory(&self.egui_ctx);
         }
         if let Some(storage) = self.frame.storage_mut() {
@@ -252,6 +252,8 @@ impl AppRunner {
             events: _, // already handled
             mutable_text_under_cursor,
             ime,
+            pick_screen_color: _, // not currently implemented
+            file_dialog: _,       // not currently implemented
             #[cfg(feature = "accesskit")]
                 accesskit_update: _, // not currently implemented
         } = platform_output;
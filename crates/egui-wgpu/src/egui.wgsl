@@ -3,6 +3,12 @@
 struct VertexOutput {
     @location(0) tex_coord: vec2<f32>,
     @location(1) color: vec4<f32>, // gamma 0-1
+    @location(2) @interpolate(flat) color_matrix_0: vec4<f32>,
+    @location(3) @interpolate(flat) color_matrix_1: vec4<f32>,
+    @location(4) @interpolate(flat) color_matrix_2: vec4<f32>,
+    @location(5) @interpolate(flat) color_matrix_3: vec4<f32>,
+    @location(6) @interpolate(flat) color_offset: vec4<f32>,
+    @location(7) @interpolate(flat) color_filter_enabled: u32,
     @builtin(position) position: vec4<f32>,
 };
 
@@ -59,10 +65,22 @@ fn vs_main(
     @location(0) a_pos: vec2<f32>,
     @location(1) a_tex_coord: vec2<f32>,
     @location(2) a_color: u32,
+    @location(3) a_color_matrix_0: vec4<f32>,
+    @location(4) a_color_matrix_1: vec4<f32>,
+    @location(5) a_color_matrix_2: vec4<f32>,
+    @location(6) a_color_matrix_3: vec4<f32>,
+    @location(7) a_color_offset: vec4<f32>,
+    @location(8) a_color_filter_enabled: u32,
 ) -> VertexOutput {
     var out: VertexOutput;
     out.tex_coord = a_tex_coord;
     out.color = unpack_color(a_color);
+    out.color_matrix_0 = a_color_matrix_0;
+    out.color_matrix_1 = a_color_matrix_1;
+    out.color_matrix_2 = a_color_matrix_2;
+    out.color_matrix_3 = a_color_matrix_3;
+    out.color_offset = a_color_offset;
+    out.color_filter_enabled = a_color_filter_enabled;
     out.position = position_from_screen(a_pos);
     return out;
 }
@@ -72,11 +90,25 @@ fn vs_main(
 @group(1) @binding(0) var r_tex_color: texture_2d<f32>;
 @group(1) @binding(1) var r_tex_sampler: sampler;
 
+// The `color_filter` of the mesh, on a premultiplied gamma color
+fn filter_color(in: VertexOutput, rgba: vec4<f32>) -> vec4<f32> {
+    if in.color_filter_enabled == 0u {
+        return rgba;
+    }
+    var unmultiplied = vec4<f32>(0.0);
+    if rgba.a > 0.0 {
+        unmultiplied = vec4<f32>(rgba.rgb / rgba.a, rgba.a);
+    }
+    let color_matrix = mat4x4<f32>(in.color_matrix_0, in.color_matrix_1, in.color_matrix_2, in.color_matrix_3);
+    let filtered = clamp(color_matrix * unmultiplied + in.color_offset, vec4<f32>(0.0), vec4<f32>(1.0));
+    return vec4<f32>(filtered.rgb * filtered.a, filtered.a);
+}
+
 @fragment
 fn fs_main_linear_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
     // We always have an sRGB aware texture at the moment.
     let tex_linear = textureSample(r_tex_color, r_tex_sampler, in.tex_coord);
-    let tex_gamma = gamma_from_linear_rgba(tex_linear);
+    let tex_gamma = filter_color(in, gamma_from_linear_rgba(tex_linear));
     let out_color_gamma = in.color * tex_gamma;
     return vec4<f32>(linear_from_gamma_rgb(out_color_gamma.rgb), out_color_gamma.a);
 }
@@ -85,7 +117,7 @@ fn fs_main_linear_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
 fn fs_main_gamma_framebuffer(in: VertexOutput) -> @location(0) vec4<f32> {
     // We always have an sRGB aware texture at the moment.
     let tex_linear = textureSample(r_tex_color, r_tex_sampler, in.tex_coord);
-    let tex_gamma = gamma_from_linear_rgba(tex_linear);
+    let tex_gamma = filter_color(in, gamma_from_linear_rgba(tex_linear));
     let out_color_gamma = in.color * tex_gamma;
     return out_color_gamma;
 }
@@ -144,6 +144,23 @@ impl PartialEq for UniformBuffer {
     }
 }
 
+/// The [`epaint::Mesh::color_filter`] of a mesh, as per-instance vertex data.
+#[derive(Clone, Copy, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+#[repr(C)]
+struct ColorFilter {
+    columns: [[f32; 4]; 5],
+    enabled: u32,
+}
+
+impl From<&epaint::ColorMatrix> for ColorFilter {
+    fn from(color_matrix: &epaint::ColorMatrix) -> Self {
+        Self {
+            columns: color_matrix.columns(),
+            enabled: !color_matrix.is_identity() as u32,
+        }
+    }
+}
+
 struct SlicedBuffer {
     buffer: wgpu::Buffer,
     slices: Vec<Range<usize>>,
@@ -156,6 +173,7 @@ pub struct Renderer {
 
     index_buffer: SlicedBuffer,
     vertex_buffer: SlicedBuffer,
+    color_filter_buffer: SlicedBuffer,
 
     uniform_buffer: wgpu::Buffer,
     previous_uniform_buffer_content: UniformBuffer,
@@ -286,14 +304,24 @@ impl Renderer {
                 vertex: wgpu::VertexState {
                     entry_point: "vs_main",
                     module: &module,
-                    buffers: &[wgpu::VertexBufferLayout {
-                        array_stride: 5 * 4,
-                        step_mode: wgpu::VertexStepMode::Vertex,
-                        // 0: vec2 position
-                        // 1: vec2 texture coordinates
-                        // 2: uint color
-                        attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Uint32],
-                    }],
+                    buffers: &[
+                        wgpu::VertexBufferLayout {
+                            array_stride: 5 * 4,
+                            step_mode: wgpu::VertexStepMode::Vertex,
+                            // 0: vec2 position
+                            // 1: vec2 texture coordinates
+                            // 2: uint color
+                            attributes: &wgpu::vertex_attr_array![0 => Float32x2, 1 => Float32x2, 2 => Uint32],
+                        },
+                        wgpu::VertexBufferLayout {
+                            array_stride: std::mem::size_of::<ColorFilter>() as _,
+                            step_mode: wgpu::VertexStepMode::Instance,
+                            // 3-6: vec4 color matrix columns
+                            // 7: vec4 color offset
+                            // 8: uint color filter enabled
+                            attributes: &wgpu::vertex_attr_array![3 => Float32x4, 4 => Float32x4, 5 => Float32x4, 6 => Float32x4, 7 => Float32x4, 8 => Uint32],
+                        },
+                    ],
                 },
                 primitive: wgpu::PrimitiveState {
                     topology: wgpu::PrimitiveTopology::TriangleList,
@@ -345,6 +373,8 @@ impl Renderer {
             (std::mem::size_of::<Vertex>() * 1024) as _;
         const INDEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
             (std::mem::size_of::<u32>() * 1024 * 3) as _;
+        const COLOR_FILTER_BUFFER_START_CAPACITY: wgpu::BufferAddress =
+            (std::mem::size_of::<ColorFilter>() * 64) as _;
 
         Self {
             pipeline,
@@ -358,6 +388,11 @@ impl Renderer {
                 slices: Vec::with_capacity(64),
                 capacity: INDEX_BUFFER_START_CAPACITY,
             },
+            color_filter_buffer: SlicedBuffer {
+                buffer: create_vertex_buffer(device, COLOR_FILTER_BUFFER_START_CAPACITY),
+                slices: Vec::with_capacity(64),
+                capacity: COLOR_FILTER_BUFFER_START_CAPACITY,
+            },
             uniform_buffer,
             // Buffers on wgpu are zero initialized, so this is indeed its current state!
             previous_uniform_buffer_content: UniformBuffer {
@@ -391,6 +426,7 @@ impl Renderer {
 
         let mut index_buffer_slices = self.index_buffer.slices.iter();
         let mut vertex_buffer_slices = self.vertex_buffer.slices.iter();
+        let mut color_filter_buffer_slices = self.color_filter_buffer.slices.iter();
 
         for epaint::ClippedPrimitive {
             clip_rect,
@@ -420,6 +456,7 @@ impl Renderer {
                         // If this is a mesh, we need to advance the index and vertex buffer iterators:
                         index_buffer_slices.next().unwrap();
                         vertex_buffer_slices.next().unwrap();
+                        color_filter_buffer_slices.next().unwrap();
                     }
                     continue;
                 }
@@ -431,6 +468,7 @@ impl Renderer {
                 Primitive::Mesh(mesh) => {
                     let index_buffer_slice = index_buffer_slices.next().unwrap();
                     let vertex_buffer_slice = vertex_buffer_slices.next().unwrap();
+                    let color_filter_buffer_slice = color_filter_buffer_slices.next().unwrap();
 
                     if let Some((_texture, bind_group)) = self.textures.get(&mesh.texture_id) {
                         render_pass.set_bind_group(1, bind_group, &[]);
@@ -446,6 +484,13 @@ impl Renderer {
                                 vertex_buffer_slice.start as u64..vertex_buffer_slice.end as u64,
                             ),
                         );
+                        render_pass.set_vertex_buffer(
+                            1,
+                            self.color_filter_buffer.buffer.slice(
+                                color_filter_buffer_slice.start as u64
+                                    ..color_filter_buffer_slice.end as u64,
+                            ),
+                        );
                         render_pass.draw_indexed(0..mesh.indices.len() as u32, 0, 0..1);
                     } else {
                         log::warn!("Missing texture: {:?}", mesh.texture_id);
@@ -524,6 +569,15 @@ impl Renderer {
                 );
                 Cow::Borrowed(&image.pixels)
             }
+            epaint::ImageData::Palette(image) => {
+                assert_eq!(
+                    width as usize * height as usize,
+                    image.indices.len(),
+                    "Mismatch between texture size and texel count"
+                );
+                crate::profile_scope!("palette -> sRGBA");
+                Cow::Owned(image.srgba_pixels().collect::<Vec<egui::Color32>>())
+            }
             epaint::ImageData::Font(image) => {
                 assert_eq!(
                     width as usize * height as usize,
@@ -795,23 +849,30 @@ impl Renderer {
 
         // Determine how many vertices & indices need to be rendered, and gather prepare callbacks
         let mut callbacks = Vec::new();
-        let (vertex_count, index_count) = {
+        let (vertex_count, index_count, mesh_count) = {
             crate::profile_scope!("count_vertices_indices");
-            paint_jobs.iter().fold((0, 0), |acc, clipped_primitive| {
-                match &clipped_primitive.primitive {
-                    Primitive::Mesh(mesh) => {
-                        (acc.0 + mesh.vertices.len(), acc.1 + mesh.indices.len())
-                    }
-                    Primitive::Callback(callback) => {
-                        if let Some(c) = callback.callback.downcast_ref::<Callback>() {
-                            callbacks.push(c.0.as_ref());
-                        } else {
-                            log::warn!("Unknown paint callback: expected `egui_wgpu::Callback`");
-                        };
-                        acc
-                    }
-                }
-            })
+            paint_jobs
+                .iter()
+                .fold(
+                    (0, 0, 0),
+                    |acc, clipped_primitive| match &clipped_primitive.primitive {
+                        Primitive::Mesh(mesh) => (
+                            acc.0 + mesh.vertices.len(),
+                            acc.1 + mesh.indices.len(),
+                            acc.2 + 1,
+                        ),
+                        Primitive::Callback(callback) => {
+                            if let Some(c) = callback.callback.downcast_ref::<Callback>() {
+                                callbacks.push(c.0.as_ref());
+                            } else {
+                                log::warn!(
+                                    "Unknown paint callback: expected `egui_wgpu::Callback`"
+                                );
+                            };
+                            acc
+                        }
+                    },
+                )
         };
 
         if index_count > 0 {
@@ -891,6 +952,47 @@ impl Renderer {
                 }
             }
         }
+        if mesh_count > 0 {
+            crate::profile_scope!("color filters", mesh_count.to_string());
+
+            self.color_filter_buffer.slices.clear();
+
+            let required_color_filter_buffer_size =
+                (std::mem::size_of::<ColorFilter>() * mesh_count) as u64;
+            if self.color_filter_buffer.capacity < required_color_filter_buffer_size {
+                // Resize color filter buffer if needed.
+                self.color_filter_buffer.capacity = (self.color_filter_buffer.capacity * 2)
+                    .at_least(required_color_filter_buffer_size);
+                self.color_filter_buffer.buffer =
+                    create_vertex_buffer(device, self.color_filter_buffer.capacity);
+            }
+
+            let color_filter_buffer_staging = queue.write_buffer_with(
+                &self.color_filter_buffer.buffer,
+                0,
+                NonZeroU64::new(required_color_filter_buffer_size).unwrap(),
+            );
+
+            let Some(mut color_filter_buffer_staging) = color_filter_buffer_staging else {
+                panic!("Failed to create staging buffer for color filter data. Mesh count: {mesh_count}. Required color filter buffer size: {required_color_filter_buffer_size}. Actual size {} and capacity: {} (bytes)", self.color_filter_buffer.buffer.size(), self.color_filter_buffer.capacity);
+            };
+
+            let mut color_filter_offset = 0;
+            for epaint::ClippedPrimitive { primitive, .. } in paint_jobs {
+                match primitive {
+                    Primitive::Mesh(mesh) => {
+                        let size = std::mem::size_of::<ColorFilter>();
+                        let slice = color_filter_offset..(size + color_filter_offset);
+                        color_filter_buffer_staging[slice.clone()].copy_from_slice(
+                            bytemuck::bytes_of(&ColorFilter::from(&mesh.color_filter)),
+                        );
+                        self.color_filter_buffer.slices.push(slice);
+                        color_filter_offset += size;
+                    }
+                    Primitive::Callback(_) => {}
+                }
+            }
+        }
 
         let mut user_cmd_bufs = Vec::new();
         {
@@ -82,6 +82,32 @@ impl Clipboard {
         Some(self.clipboard.clone())
     }
 
+    /// Is there text on the clipboard?
+    ///
+    /// Unlike [`Self::get`], this doesn't log an error when the clipboard is empty.
+    pub fn has_text(&mut self) -> bool {
+        #[cfg(all(
+            any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ),
+            feature = "smithay-clipboard"
+        ))]
+        if let Some(clipboard) = &mut self.smithay {
+            return clipboard.load().map_or(false, |text| !text.is_empty());
+        }
+
+        #[cfg(all(feature = "arboard", not(target_os = "android")))]
+        if let Some(clipboard) = &mut self.arboard {
+            return clipboard.get_text().map_or(false, |text| !text.is_empty());
+        }
+
+        !self.clipboard.is_empty()
+    }
+
     pub fn set(&mut self, text: String) {
         #[cfg(all(
             any(
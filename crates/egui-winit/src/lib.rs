@@ -102,6 +102,9 @@ pub struct State {
 
     allow_ime: bool,
     ime_rect_px: Option<egui::Rect>,
+
+    /// Was an egui context menu open after the last frame?
+    context_menu_open: bool,
 }
 
 impl State {
@@ -143,6 +146,8 @@ impl State {
 
             allow_ime: false,
             ime_rect_px: None,
+
+            context_menu_open: false,
         };
 
         slf.egui_input
@@ -535,6 +540,11 @@ impl State {
             if let Some(button) = translate_mouse_button(button) {
                 let pressed = state == winit::event::ElementState::Pressed;
 
+                if pressed && button == egui::PointerButton::Secondary {
+                    // This may open a context menu with a "Paste" item:
+                    self.egui_input.clipboard_has_text = Some(self.clipboard.has_text());
+                }
+
                 self.egui_input.events.push(egui::Event::PointerButton {
                     pos,
                     button,
@@ -814,6 +824,8 @@ impl State {
             events: _,                    // handled elsewhere
             mutable_text_under_cursor: _, // only used in eframe web
             ime,
+            pick_screen_color: _, // not currently implemented
+            file_dialog: _,       // not currently implemented
             #[cfg(feature = "accesskit")]
             accesskit_update,
         } = platform_output;
@@ -828,6 +840,18 @@ impl State {
             self.clipboard.set(copied_text);
         }
 
+        // A context menu can also be opened without the secondary mouse button,
+        // e.g. with a long touch, so check the clipboard for its "Paste" item when it opens:
+        let context_menu_open = self.egui_ctx.is_context_menu_open();
+        if context_menu_open && !self.context_menu_open {
+            let clipboard_has_text = Some(self.clipboard.has_text());
+            if self.egui_input.clipboard_has_text != clipboard_has_text {
+                self.egui_input.clipboard_has_text = clipboard_has_text;
+                self.egui_ctx.request_repaint_of(self.viewport_id);
+            }
+        }
+        self.context_menu_open = context_menu_open;
+
         let allow_ime = ime.is_some();
         if self.allow_ime != allow_ime {
             self.allow_ime = allow_ime;
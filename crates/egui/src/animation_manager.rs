@@ -35,6 +35,11 @@ impl AnimationManager {
         value: bool,
     ) -> f32 {
         let (start, end) = if value { (0.0, 1.0) } else { (1.0, 0.0) };
+        if animation_time <= 0.0 {
+            // No animation, so no need to remember anything:
+            self.bools.remove(&id);
+            return end;
+        }
         match self.bools.get_mut(&id) {
             None => {
                 self.bools.insert(
@@ -72,6 +77,11 @@ impl AnimationManager {
         id: Id,
         value: f32,
     ) -> f32 {
+        if animation_time <= 0.0 {
+            // No animation, so no need to remember anything:
+            self.values.remove(&id);
+            return value;
+        }
         match self.values.get_mut(&id) {
             None => {
                 self.values.insert(
@@ -0,0 +1,194 @@
+//! Laying out huge texts on a background thread, see [`Context::layout_job_in_background`].
+
+use std::sync::{Arc, Weak};
+
+use epaint::{
+    mutex::Mutex,
+    text::{FontsImpl, LayoutJob},
+    Galley, TextureAtlas,
+};
+
+use crate::{Context, Id, ViewportId};
+
+type Finished = (Arc<Galley>, Arc<Mutex<TextureAtlas>>);
+
+struct Job {
+    last_used_frame: u64,
+
+    /// What is being laid out, compared against the job asked for the next frame.
+    job: Arc<LayoutJob>,
+
+    /// Set by the worker when it is done.
+    ///
+    /// The galley is only valid for the font atlas it was laid out with.
+    result: Arc<Mutex<Option<Finished>>>,
+}
+
+/// A layout waiting for the worker.
+struct Work {
+    ctx: Context,
+    viewport_id: ViewportId,
+    fonts: FontsImpl,
+    job: Arc<LayoutJob>,
+
+    /// Dropped when the job is replaced or forgotten, so the worker can skip it.
+    result: Weak<Mutex<Option<Finished>>>,
+}
+
+impl Work {
+    fn run(mut self) {
+        if self.result.strong_count() == 0 {
+            return; // Nobody wants this anymore.
+        }
+        crate::profile_scope!("background_layout");
+        let galley = epaint::text::layout(&mut self.fonts, self.job);
+        if let Some(result) = self.result.upgrade() {
+            *result.lock() = Some((Arc::new(galley), self.fonts.texture_atlas().clone()));
+            self.ctx.request_repaint_of(self.viewport_id);
+        }
+    }
+}
+
+/// The single thread that all background layouts run on, one after the other.
+#[cfg(not(target_arch = "wasm32"))]
+struct Worker {
+    sender: std::sync::mpsc::Sender<Work>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Worker {
+    fn spawn() -> Self {
+        let (sender, receiver) = std::sync::mpsc::channel::<Work>();
+        // The thread quits when the sender is dropped together with the context.
+        std::thread::Builder::new()
+            .name("egui_text_layout".to_owned())
+            .spawn(move || receiver.into_iter().for_each(Work::run))
+            .expect("Failed to spawn text layout thread");
+        Self { sender }
+    }
+}
+
+/// The text layouts that are running or finished, one per id.
+///
+/// Jobs that are not asked for during a frame are forgotten.
+#[derive(Default)]
+pub(crate) struct BackgroundLayouts {
+    jobs: ahash::HashMap<Id, Job>,
+
+    #[cfg(not(target_arch = "wasm32"))]
+    worker: Option<Worker>,
+}
+
+impl BackgroundLayouts {
+    pub(crate) fn layout(ctx: &Context, id: Id, job: LayoutJob) -> Option<Arc<Galley>> {
+        let layouts = ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<Arc<Mutex<Self>>>(Id::NULL)
+                .clone()
+        });
+        let mut layouts = layouts.lock();
+
+        let frame_nr = ctx.frame_nr();
+        layouts
+            .jobs
+            .retain(|_, job| frame_nr <= job.last_used_frame + 1);
+
+        // If the font atlas was recreated, the glyphs of a finished layout point to the wrong texture:
+        let atlas = ctx.fonts(|fonts| fonts.texture_atlas());
+        let is_current = layouts.jobs.get(&id).map_or(false, |existing| {
+            *existing.job == job
+                && existing
+                    .result
+                    .lock()
+                    .as_ref()
+                    .map_or(true, |(_, job_atlas)| Arc::ptr_eq(job_atlas, &atlas))
+        });
+        if !is_current {
+            // Replacing the old job drops its result, so the worker skips it if it hasn't started yet:
+            let job = Arc::new(job);
+            let result = Arc::new(Mutex::new(None));
+            layouts.jobs.insert(
+                id,
+                Job {
+                    last_used_frame: frame_nr,
+                    job: job.clone(),
+                    result: result.clone(),
+                },
+            );
+            layouts.submit(Work {
+                ctx: ctx.clone(),
+                viewport_id: ctx.viewport_id(),
+                fonts: ctx.fonts(|fonts| fonts.fork()),
+                job,
+                result: Arc::downgrade(&result),
+            });
+        }
+
+        let job = layouts.jobs.get_mut(&id)?;
+        job.last_used_frame = frame_nr;
+        let galley = job.result.lock().as_ref().map(|(galley, _)| galley.clone());
+        galley
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn submit(&mut self, work: Work) {
+        let worker = self.worker.get_or_insert_with(Worker::spawn);
+        if let Err(std::sync::mpsc::SendError(work)) = worker.sender.send(work) {
+            // The worker died (e.g. a panic in the layout), so start a new one:
+            self.worker.insert(Worker::spawn()).sender.send(work).ok();
+        }
+    }
+
+    /// No threads on the web, so lay out right away.
+    #[cfg(target_arch = "wasm32")]
+    fn submit(&mut self, work: Work) {
+        work.run();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestHarness;
+
+    fn layout_until_done(harness: &TestHarness, id: Id, job: &LayoutJob) -> Arc<Galley> {
+        for _ in 0..1000 {
+            let galley = harness.run(vec![], |ctx| ctx.layout_job_in_background(id, job.clone()));
+            if let Some(galley) = galley {
+                return galley;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        panic!("the layout should finish");
+    }
+
+    #[test]
+    fn test_layout_in_background() {
+        let harness = TestHarness::with_default_fonts();
+        let text = "All work and no play makes Jack a dull boy.\n".repeat(1000);
+        let job = LayoutJob::simple(text, Default::default(), Default::default(), 300.0);
+
+        let galley = layout_until_done(&harness, Id::new("text"), &job);
+        let expected = harness.ctx.fonts(|fonts| fonts.layout_job(job));
+        assert_eq!(galley.rows.len(), expected.rows.len());
+        assert_eq!(galley.size(), expected.size());
+    }
+
+    #[test]
+    fn test_newer_job_replaces_older() {
+        let harness = TestHarness::with_default_fonts();
+        let id = Id::new("text");
+        let text = "All work and no play makes Jack a dull boy.\n".repeat(1000);
+        let job =
+            |width| LayoutJob::simple(text.clone(), Default::default(), Default::default(), width);
+
+        let wide = layout_until_done(&harness, id, &job(300.0));
+        let narrow = layout_until_done(&harness, id, &job(100.0));
+        assert!(narrow.rows.len() > wide.rows.len());
+
+        let layouts = harness.ctx.data_mut(|data| {
+            data.get_temp_mut_or_default::<Arc<Mutex<BackgroundLayouts>>>(Id::NULL)
+                .clone()
+        });
+        assert_eq!(layouts.lock().jobs.len(), 1);
+    }
+}
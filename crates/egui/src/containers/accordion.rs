@@ -0,0 +1,149 @@
+//! A list of collapsible sections where only one is open at a time, see [`Accordion`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct AccordionState {
+    /// The id of the open section, if any.
+    open: Option<Id>,
+}
+
+impl AccordionState {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// A list of [`CollapsingHeader`]s where opening one section closes the others.
+///
+/// Which section is open is remembered, like for a normal [`CollapsingHeader`].
+/// This is useful for e.g. settings pages with many categories.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Accordion::new("settings").show(ui, |accordion| {
+///     accordion.section("General", |ui| {
+///         ui.label("General settings");
+///     });
+///     accordion.section("Advanced", |ui| {
+///         ui.label("Advanced settings");
+///     });
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Accordion {
+    id_source: Id,
+    default_open: Option<usize>,
+}
+
+impl Accordion {
+    /// The id source must be unique among the siblings of the accordion.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            default_open: None,
+        }
+    }
+
+    /// Which section (counting from zero) is open before the user has opened or closed any.
+    ///
+    /// Default: all sections are closed.
+    #[inline]
+    pub fn default_open(mut self, index: Option<usize>) -> Self {
+        self.default_open = index;
+        self
+    }
+
+    /// Show the sections, added with [`AccordionBuilder::section`].
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_sections: impl FnOnce(&mut AccordionBuilder<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.make_persistent_id(self.id_source);
+        let state = AccordionState::load(ui.ctx(), id);
+
+        let InnerResponse { inner, response } = ui.vertical(|ui| {
+            let mut builder = AccordionBuilder {
+                ui,
+                id,
+                open: state.and_then(|state| state.open),
+                default_open: if state.is_none() {
+                    self.default_open
+                } else {
+                    None
+                },
+                index: 0,
+                changed: false,
+            };
+            let inner = add_sections(&mut builder);
+
+            if state.is_some() || builder.changed {
+                AccordionState { open: builder.open }.store(builder.ui.ctx(), id);
+            }
+            inner
+        });
+
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Adds the sections of an [`Accordion`].
+pub struct AccordionBuilder<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+    open: Option<Id>,
+    default_open: Option<usize>,
+    index: usize,
+
+    /// A header was clicked this frame.
+    changed: bool,
+}
+
+impl<'a> AccordionBuilder<'a> {
+    /// Add a section with the given heading.
+    ///
+    /// Sections are identified by their position in the accordion,
+    /// so add them in the same order every frame.
+    pub fn section<R>(
+        &mut self,
+        heading: impl Into<WidgetText>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        let section_id = self.id.with(self.index);
+        let is_open = match self.open {
+            Some(open) => open == section_id,
+            None => self.default_open == Some(self.index),
+        };
+        self.index += 1;
+
+        let response = CollapsingHeader::new(heading)
+            .id_source(section_id)
+            .default_open(is_open)
+            .open(Some(is_open))
+            .show(self.ui, add_contents);
+
+        if response.header_response.clicked() {
+            // Sections shown before this one close next frame:
+            self.open = (!is_open).then_some(section_id);
+            self.default_open = None;
+            self.changed = true;
+            self.ui.ctx().request_repaint();
+        }
+        response
+    }
+
+    /// The [`Ui`] the sections are added to, e.g. for adding a separator between sections.
+    #[inline]
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
@@ -95,6 +95,8 @@ pub struct Area {
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
     fade_in: bool,
+    snap_distance: f32,
+    respect_safe_area: bool,
 }
 
 impl WidgetWithState for Area {
@@ -120,6 +122,8 @@ impl Area {
             pivot: Align2::LEFT_TOP,
             anchor: None,
             fade_in: true,
+            snap_distance: 0.0,
+            respect_safe_area: false,
         }
     }
 
@@ -163,6 +167,13 @@ impl Area {
         self
     }
 
+    /// Remove any [`Self::anchor`], e.g. so that [`Self::fixed_pos`] can be used instead.
+    #[inline]
+    pub(crate) fn no_anchor(mut self) -> Self {
+        self.anchor = None;
+        self
+    }
+
     pub fn is_enabled(&self) -> bool {
         self.enabled
     }
@@ -258,6 +269,20 @@ impl Area {
         self
     }
 
+    /// Keep the area out of the parts of the screen covered by display cutouts,
+    /// rounded corners and the like.
+    ///
+    /// This shrinks the rectangle the area is constrained to (see [`Self::constrain`])
+    /// and anchored in (see [`Self::anchor`]).
+    /// See [`crate::RawInput::safe_area_insets`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
+
     /// Where the "root" of the area is.
     ///
     /// For instance, if you set this to [`Align2::RIGHT_TOP`]
@@ -295,6 +320,18 @@ impl Area {
         self.movable(false)
     }
 
+    /// When dragged, snap the edges of the area to the edges of the screen,
+    /// the panels, and other windows that are closer than this many points.
+    ///
+    /// A line is shown along the edge that was snapped to.
+    ///
+    /// Default: `0.0` (no snapping).
+    #[inline]
+    pub fn snap_distance(mut self, snap_distance: f32) -> Self {
+        self.snap_distance = snap_distance;
+        self
+    }
+
     pub(crate) fn get_pivot(&self) -> Align2 {
         if let Some((pivot, _)) = self.anchor {
             pivot
@@ -362,11 +399,17 @@ impl Area {
             constrain,
             constrain_rect,
             fade_in,
+            snap_distance,
+            respect_safe_area,
         } = self;
 
-        let constrain_rect = constrain_rect.unwrap_or_else(|| ctx.screen_rect());
+        let mut constrain_rect = constrain_rect.unwrap_or_else(|| ctx.screen_rect());
+        if respect_safe_area {
+            constrain_rect = constrain_rect.intersect(ctx.input(|i| i.safe_area_rect()));
+        }
 
         let layer_id = LayerId::new(order, id);
+        ctx.register_layer_name(layer_id, || format!("{kind:?}"));
 
         let state = AreaState::load(ctx, id).map(|mut state| {
             // override the saved state with the correct value
@@ -442,8 +485,22 @@ impl Area {
                 enabled,
             });
 
+            // Where the area would be without snapping, while it is being dragged:
+            let unsnapped_id = interact_id.with("unsnapped_pos");
             if movable && move_response.dragged() {
-                state.pivot_pos += move_response.drag_delta();
+                if 0.0 < snap_distance {
+                    let unsnapped_pos = ctx
+                        .data(|data| data.get_temp::<Pos2>(unsnapped_id))
+                        .unwrap_or(state.pivot_pos)
+                        + move_response.drag_delta();
+                    ctx.data_mut(|data| data.insert_temp(unsnapped_id, unsnapped_pos));
+                    state.pivot_pos = unsnapped_pos;
+                    state.pivot_pos += snap_to_edges(ctx, layer_id, state.rect(), snap_distance);
+                } else {
+                    state.pivot_pos += move_response.drag_delta();
+                }
+            } else if 0.0 < snap_distance {
+                ctx.data_mut(|data| data.remove::<Pos2>(unsnapped_id));
             }
 
             if (move_response.dragged() || move_response.clicked())
@@ -515,7 +572,7 @@ impl Prepared {
             UiStackInfo::new(self.kind),
         );
 
-        if self.fade_in {
+        if self.fade_in && !ctx.style().reduced_motion {
             let age =
                 ctx.input(|i| (i.time - self.state.last_became_visible_at) as f32 + i.predicted_dt);
             let opacity = crate::remap_clamp(age, 0.0..=ctx.style().animation_time, 0.0..=1.0);
@@ -553,6 +610,120 @@ impl Prepared {
     }
 }
 
+/// How much to move `rect` so that its edges line up with nearby edges of the
+/// screen, the panels, and other windows.
+///
+/// Also paints a hint along the edges that were snapped to.
+fn snap_to_edges(ctx: &Context, layer_id: LayerId, rect: Rect, snap_distance: f32) -> Vec2 {
+    // The edges we snap to from the inside:
+    let mut containers = vec![ctx.screen_rect(), ctx.available_rect()];
+    containers.dedup();
+
+    // The edges we snap to from either side:
+    let windows: Vec<Rect> = ctx.memory(|mem| {
+        let areas = mem.areas();
+        areas
+            .visible_layer_ids()
+            .into_iter()
+            .filter(|other| other.order == Order::Middle && *other != layer_id)
+            .filter_map(|other| areas.get(other.id).map(|state| state.rect()))
+            .collect()
+    });
+
+    // (offset, the coordinate we snap to, span of the hint line)
+    let mut best_x: Option<(f32, f32, Rangef)> = None;
+    let mut best_y: Option<(f32, f32, Rangef)> = None;
+
+    let consider = |best: &mut Option<(f32, f32, Rangef)>, edge: f32, target: f32, span: Rangef| {
+        let offset = target - edge;
+        if offset.abs() <= snap_distance
+            && best.map_or(true, |(best, _, _)| offset.abs() < best.abs())
+        {
+            *best = Some((offset, target, span));
+        }
+    };
+    let span = |a: Rangef, b: Rangef| Rangef::new(a.min.min(b.min), a.max.max(b.max));
+
+    for container in &containers {
+        consider(
+            &mut best_x,
+            rect.left(),
+            container.left(),
+            container.y_range(),
+        );
+        consider(
+            &mut best_x,
+            rect.right(),
+            container.right(),
+            container.y_range(),
+        );
+        consider(
+            &mut best_y,
+            rect.top(),
+            container.top(),
+            container.x_range(),
+        );
+        consider(
+            &mut best_y,
+            rect.bottom(),
+            container.bottom(),
+            container.x_range(),
+        );
+    }
+
+    for other in &windows {
+        if other
+            .y_range()
+            .expand(snap_distance)
+            .intersects(rect.y_range())
+        {
+            for edge in [rect.left(), rect.right()] {
+                for target in [other.left(), other.right()] {
+                    consider(
+                        &mut best_x,
+                        edge,
+                        target,
+                        span(rect.y_range(), other.y_range()),
+                    );
+                }
+            }
+        }
+        if other
+            .x_range()
+            .expand(snap_distance)
+            .intersects(rect.x_range())
+        {
+            for edge in [rect.top(), rect.bottom()] {
+                for target in [other.top(), other.bottom()] {
+                    consider(
+                        &mut best_y,
+                        edge,
+                        target,
+                        span(rect.x_range(), other.x_range()),
+                    );
+                }
+            }
+        }
+    }
+
+    let painter = ctx.layer_painter(LayerId::new(
+        Order::Foreground,
+        layer_id.id.with("snap_hint"),
+    ));
+    let stroke = ctx.style().visuals.selection.stroke;
+    let mut offset = Vec2::ZERO;
+    if let Some((dx, x, span)) = best_x {
+        offset.x = dx;
+        painter.vline(x, span, stroke);
+    }
+    if let Some((dy, y, span)) = best_y {
+        offset.y = dy;
+        painter.hline(span, y, stroke);
+    }
+
+    offset
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.pointer_interact_pos() {
         let any_pressed = ctx.input(|i| i.pointer.any_pressed());
@@ -1,6 +1,12 @@
+use std::collections::BTreeSet;
+
 use epaint::Shape;
 
-use crate::{style::WidgetVisuals, *};
+use crate::{
+    style::WidgetVisuals,
+    util::fuzzy::{fuzzy_score, prefix_match},
+    *,
+};
 
 #[allow(unused_imports)] // Documentation
 use crate::style::Spacing;
@@ -12,6 +18,33 @@ pub enum AboveOrBelow {
     Below,
 }
 
+/// How the text typed into a [`ComboBox::searchable`] combo box is matched against the items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SearchMode {
+    /// Show the items starting with the text, ignoring case.
+    Prefix,
+
+    /// Show the items containing all the typed characters in the same order, ignoring case,
+    /// so `"ude"` finds `"United Kingdom"`.
+    Fuzzy,
+}
+
+impl SearchMode {
+    fn matches(self, query: &str, text: &str) -> bool {
+        match self {
+            Self::Prefix => prefix_match(query, text),
+            Self::Fuzzy => fuzzy_score(query, text).is_some(),
+        }
+    }
+}
+
+/// Something shown between the items of a [`ComboBox`], see [`ComboBox::group_header`].
+#[derive(Clone)]
+enum Divider {
+    Separator,
+    Header(RichText),
+}
+
 /// A function that paints the [`ComboBox`] icon
 pub type IconPainter = Box<dyn FnOnce(&Ui, Rect, &WidgetVisuals, bool, AboveOrBelow)>;
 
@@ -41,6 +74,8 @@ pub struct ComboBox {
     height: Option<f32>,
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
+    search: Option<SearchMode>,
+    dividers: Vec<(usize, Divider)>,
 }
 
 impl ComboBox {
@@ -54,6 +89,8 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_mode: None,
+            search: None,
+            dividers: Vec::new(),
         }
     }
 
@@ -68,6 +105,8 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_mode: None,
+            search: None,
+            dividers: Vec::new(),
         }
     }
 
@@ -81,6 +120,8 @@ impl ComboBox {
             height: None,
             icon: None,
             wrap_mode: None,
+            search: None,
+            dividers: Vec::new(),
         }
     }
 
@@ -174,6 +215,72 @@ impl ComboBox {
         self
     }
 
+    /// Set [`Self::wrap_mode`] to [`TextWrapMode::TruncateMiddle`].
+    ///
+    /// Text that doesn't fit is elided in the middle, e.g. `/home/us…/file.txt`.
+    #[inline]
+    pub fn truncate_middle(mut self) -> Self {
+        self.wrap_mode = Some(TextWrapMode::TruncateMiddle);
+        self
+    }
+
+    /// Put a text field at the top of the popup, which filters the items as the user types.
+    ///
+    /// The arrow keys move between the matching items and Enter selects one,
+    /// so the user can pick from hundreds of items without touching the pointer.
+    ///
+    /// Only used by [`Self::show_index`], since other combo boxes don't know the labels of their items.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let countries = ["Denmark", "Germany", "Sweden", "United Kingdom"];
+    /// let mut selected = 0;
+    /// egui::ComboBox::from_label("Country")
+    ///     .searchable(egui::SearchMode::Fuzzy)
+    ///     .show_index(ui, &mut selected, countries.len(), |i| countries[i]);
+    /// # });
+    /// ```
+    #[inline]
+    pub fn searchable(mut self, search: SearchMode) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Show a heading above the item with the given index, to organize long lists into groups.
+    ///
+    /// The heading can't be selected, and is skipped when moving between the items with the keyboard.
+    /// When a [`Self::searchable`] combo box is filtered, the heading is only shown if some item in its group matches.
+    ///
+    /// Only used by [`Self::show_index`] and [`Self::show_multi_index`].
+    /// With [`Self::show_ui`] you can add e.g. [`Ui::weak`] labels yourself.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let devices = ["Headphones", "Speakers", "HDMI", "USB audio"];
+    /// let mut selected = 0;
+    /// egui::ComboBox::from_label("Output")
+    ///     .group_header(0, "Recent")
+    ///     .group_header(2, "All devices")
+    ///     .separator_before(2)
+    ///     .show_index(ui, &mut selected, devices.len(), |i| devices[i]);
+    /// # });
+    /// ```
+    #[inline]
+    pub fn group_header(mut self, index: usize, heading: impl Into<RichText>) -> Self {
+        self.dividers
+            .push((index, Divider::Header(heading.into().weak())));
+        self
+    }
+
+    /// Show a separator line above the item with the given index.
+    ///
+    /// If there is also a [`Self::group_header`] for that item, call this first to put the line above the heading.
+    #[inline]
+    pub fn separator_before(mut self, index: usize) -> Self {
+        self.dividers.push((index, Divider::Separator));
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     ///
     /// Returns `InnerResponse { inner: None }` if the combo box is closed.
@@ -182,13 +289,64 @@ impl ComboBox {
         ui: &mut Ui,
         menu_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<Option<R>> {
-        self.show_ui_dyn(ui, Box::new(menu_contents))
+        self.show_ui_dyn(
+            ui,
+            true,
+            Box::new(|ui, height| menu_scroll_area(ui, height, menu_contents)),
+        )
     }
 
+    /// Show the combo box, building only the rows of the popup that are scrolled into view,
+    /// like [`ScrollArea::show_rows`].
+    ///
+    /// This keeps combo boxes with a huge number of items fast, since the rows that aren't visible
+    /// are never laid out. All rows must be `row_height` high (not counting [`Spacing::item_spacing`]),
+    /// e.g. [`Spacing::interact_size`]`.y` for one line of text.
+    ///
+    /// `add_rows` is given the range of rows to add.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut selected = 0;
+    /// let row_height = ui.spacing().interact_size.y;
+    /// egui::ComboBox::from_label("Symbol")
+    ///     .selected_text(format!("Symbol {selected}"))
+    ///     .show_rows(ui, row_height, 100_000, |ui, rows| {
+    ///         for i in rows {
+    ///             ui.selectable_value(&mut selected, i, format!("Symbol {i}"));
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    pub fn show_rows<R>(
+        self,
+        ui: &mut Ui,
+        row_height: f32,
+        total_rows: usize,
+        add_rows: impl FnOnce(&mut Ui, std::ops::Range<usize>) -> R,
+    ) -> InnerResponse<Option<R>> {
+        self.show_ui_dyn(
+            ui,
+            true,
+            Box::new(|ui, height| {
+                ScrollArea::vertical()
+                    .max_height(height)
+                    .show_rows(ui, row_height, total_rows, |ui, rows| {
+                        // See `menu_scroll_area`:
+                        ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+                        add_rows(ui, rows)
+                    })
+                    .inner
+            }),
+        )
+    }
+
+    /// `popup_contents` is given the maximum height of the popup.
     fn show_ui_dyn<'c, R>(
         self,
         ui: &mut Ui,
-        menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        close_on_click_inside: bool,
+        popup_contents: Box<dyn FnOnce(&mut Ui, f32) -> R + 'c>,
     ) -> InnerResponse<Option<R>> {
         let Self {
             id_source,
@@ -198,6 +356,8 @@ impl ComboBox {
             height,
             icon,
             wrap_mode,
+            search: _,
+            dividers: _,
         } = self;
 
         let button_id = ui.make_persistent_id(id_source);
@@ -207,7 +367,8 @@ impl ComboBox {
                 ui,
                 button_id,
                 selected_text,
-                menu_contents,
+                popup_contents,
+                close_on_click_inside,
                 icon,
                 wrap_mode,
                 (width, height),
@@ -227,6 +388,11 @@ impl ComboBox {
 
     /// Show a list of items with the given selected index.
     ///
+    /// While the combo box has keyboard focus, or its popup is open,
+    /// typing selects the first item starting with the typed text, like native drop-downs.
+    /// Typing the same letter again moves to the next item starting with it.
+    ///
+    /// If `selected` is out of range, the combo box shows [`Self::selected_text`].
     ///
     /// ```
     /// # #[derive(Debug, PartialEq)]
@@ -250,19 +416,173 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> Text,
     ) -> Response {
-        let slf = self.selected_text(get(*selected));
+        let search = self.search;
+        let dividers = self.dividers.clone();
+        let button_id = ui.make_persistent_id(self.id_source);
 
         let mut changed = false;
 
-        let mut response = slf
-            .show_ui(ui, |ui| {
+        // Typing into a searchable popup filters the items instead:
+        let typeahead_active = ui.memory(|mem| mem.has_focus(button_id))
+            || (search.is_none() && Self::is_open(ui.ctx(), button_id));
+        let jumped = typeahead_active && {
+            let get = |i| get(i).into();
+            typeahead(ui, button_id, *selected, len, &get).map_or(false, |i| {
+                *selected = i;
+                true
+            })
+        };
+        changed |= jumped;
+
+        let slf = if *selected < len {
+            self.selected_text(get(*selected))
+        } else {
+            self
+        };
+
+        let mut response = if let Some(search) = search {
+            let response = slf
+                .show_ui_dyn(
+                    ui,
+                    false,
+                    Box::new(|ui, height| {
+                        let get = |i| get(i).into();
+                        if let Some(i) = search_list(
+                            ui, button_id, height, search, &dividers, *selected, len, &get,
+                        ) {
+                            *selected = i;
+                            changed = true;
+                        }
+                    }),
+                )
+                .response;
+            if !Self::is_open(ui.ctx(), button_id) {
+                // Start with an empty search the next time it opens:
+                ui.data_mut(|d| d.remove::<SearchState>(search_state_id(button_id)));
+            }
+            response
+        } else {
+            slf.show_ui(ui, |ui| {
                 for i in 0..len {
-                    if ui.selectable_label(i == *selected, get(i)).clicked() {
+                    show_dividers(ui, &dividers, i.checked_sub(1), i);
+                    let response = ui.selectable_label(i == *selected, get(i));
+                    if jumped && i == *selected {
+                        response.scroll_to_me(None);
+                    }
+                    if response.clicked() {
                         *selected = i;
                         changed = true;
                     }
                 }
             })
+            .response
+        };
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+
+    /// Show a list of values to choose from, each with a label.
+    ///
+    /// This is [`Self::show_index`] for when the items are values rather than indices,
+    /// e.g. the variants of an enum.
+    /// If `selected` isn't one of the values, the combo box shows [`Self::selected_text`].
+    ///
+    /// ```
+    /// # #[derive(Clone, Debug, PartialEq)]
+    /// # enum Fruit { Apple, Banana, Cherry }
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut fruit = Fruit::Banana;
+    /// egui::ComboBox::from_label("Fruit").show_items(
+    ///     ui,
+    ///     &mut fruit,
+    ///     &[
+    ///         (Fruit::Apple, "Apple"),
+    ///         (Fruit::Banana, "Banana"),
+    ///         (Fruit::Cherry, "Cherry"),
+    ///     ],
+    /// );
+    /// # });
+    /// ```
+    pub fn show_items<T: Clone + PartialEq, Text: Clone + Into<WidgetText>>(
+        self,
+        ui: &mut Ui,
+        selected: &mut T,
+        items: &[(T, Text)],
+    ) -> Response {
+        let mut index = items
+            .iter()
+            .position(|(value, _)| value == selected)
+            .unwrap_or(usize::MAX);
+        let response = self.show_index(ui, &mut index, items.len(), |i| items[i].1.clone());
+        if response.changed() {
+            *selected = items[index].0.clone();
+        }
+        response
+    }
+
+    /// Show a list of items with a checkbox each, of which any number can be selected.
+    ///
+    /// The popup stays open while the user checks and unchecks items.
+    /// When closed, the combo box shows the selected items separated by commas,
+    /// or how many are selected (e.g. "3 selected") if that doesn't fit.
+    /// While nothing is selected it shows [`Self::selected_text`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let toppings = ["Cheese", "Ham", "Mushrooms", "Olives"];
+    /// let mut selected = std::collections::BTreeSet::from([0, 2]);
+    /// egui::ComboBox::from_label("Toppings")
+    ///     .selected_text("None")
+    ///     .show_multi_index(ui, &mut selected, toppings.len(), |i| toppings[i]);
+    /// # });
+    /// ```
+    pub fn show_multi_index<Text: Into<WidgetText>>(
+        mut self,
+        ui: &mut Ui,
+        selected: &mut BTreeSet<usize>,
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> Response {
+        let labels: Vec<String> = selected
+            .range(..len)
+            .map(|&i| get(i).into().text().to_owned())
+            .collect();
+        if !labels.is_empty() {
+            let spacing = ui.spacing();
+            let max_width = self.width.unwrap_or(spacing.combo_width)
+                - 2.0 * spacing.button_padding.x
+                - spacing.icon_spacing
+                - spacing.icon_width;
+            self.selected_text = multi_select_summary(ui, &labels, max_width).into();
+        }
+
+        let dividers = self.dividers.clone();
+        let mut changed = false;
+
+        let mut response = self
+            .show_ui_dyn(
+                ui,
+                false,
+                Box::new(|ui, height| {
+                    menu_scroll_area(ui, height, |ui| {
+                        for i in 0..len {
+                            show_dividers(ui, &dividers, i.checked_sub(1), i);
+                            let mut checked = selected.contains(&i);
+                            if ui.checkbox(&mut checked, get(i)).changed() {
+                                if checked {
+                                    selected.insert(i);
+                                } else {
+                                    selected.remove(&i);
+                                }
+                                changed = true;
+                            }
+                        }
+                    });
+                }),
+            )
             .response;
 
         if changed {
@@ -287,7 +607,8 @@ fn combo_box_dyn<'c, R>(
     ui: &mut Ui,
     button_id: Id,
     selected_text: WidgetText,
-    menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    popup_contents: Box<dyn FnOnce(&mut Ui, f32) -> R + 'c>,
+    close_on_click_inside: bool,
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     (width, height): (Option<f32>, Option<f32>),
@@ -375,25 +696,13 @@ fn combo_box_dyn<'c, R>(
 
     let height = height.unwrap_or_else(|| ui.spacing().combo_height);
 
-    let inner = crate::popup::popup_above_or_below_widget(
+    let inner = crate::popup::popup_above_or_below_widget_with(
         ui,
         popup_id,
         &button_response,
         above_or_below,
-        |ui| {
-            ScrollArea::vertical()
-                .max_height(height)
-                .show(ui, |ui| {
-                    // Often the button is very narrow, which means this popup
-                    // is also very narrow. Having wrapping on would therefore
-                    // result in labels that wrap very early.
-                    // Instead, we turn it off by default so that the labels
-                    // expand the width of the menu.
-                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                    menu_contents(ui)
-                })
-                .inner
-        },
+        close_on_click_inside,
+        |ui| popup_contents(ui, height),
     );
 
     InnerResponse {
@@ -402,6 +711,242 @@ fn combo_box_dyn<'c, R>(
     }
 }
 
+/// How long after the last typed character [`typeahead`] starts over with a new prefix.
+const TYPEAHEAD_TIMEOUT: f64 = 1.0;
+
+/// The characters typed recently into a focused [`ComboBox`].
+#[derive(Clone, Default)]
+struct TypeaheadState {
+    prefix: String,
+    last_typed: f64,
+}
+
+/// Find the item to select for the text typed this frame, if any.
+fn typeahead(
+    ui: &Ui,
+    button_id: Id,
+    selected: usize,
+    len: usize,
+    get: &dyn Fn(usize) -> WidgetText,
+) -> Option<usize> {
+    let typed: String = ui.input(|i| {
+        i.events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Text(text) => Some(text.as_str()),
+                _ => None,
+            })
+            .collect()
+    });
+    if typed.is_empty() {
+        return None;
+    }
+
+    let state_id = button_id.with("typeahead");
+    let now = ui.input(|i| i.time);
+    let mut state = ui
+        .data(|d| d.get_temp::<TypeaheadState>(state_id))
+        .unwrap_or_default();
+    if TYPEAHEAD_TIMEOUT < now - state.last_typed {
+        state.prefix.clear();
+    }
+    for c in typed.chars() {
+        // Space opens the popup, unless it is part of a longer prefix:
+        if !state.prefix.is_empty() || !c.is_whitespace() {
+            state.prefix.push(c);
+        }
+    }
+    state.last_typed = now;
+    let prefix = state.prefix.clone();
+    ui.data_mut(|d| d.insert_temp(state_id, state));
+
+    let mut chars = prefix.chars();
+    let first = chars.next()?;
+    if chars.all(|c| c == first) {
+        // Pressing the same letter again cycles through the items starting with it:
+        let start = if selected < len { selected + 1 } else { 0 };
+        let first = first.to_string();
+        (0..len)
+            .map(|i| (start + i) % len)
+            .find(|&i| prefix_match(&first, get(i).text()))
+    } else {
+        (0..len).find(|&i| prefix_match(&prefix, get(i).text()))
+    }
+}
+
+/// Show the group headers and separators above item `i`,
+/// unless `prev` (the item shown above it, if any) is in the same group.
+fn show_dividers(ui: &mut Ui, dividers: &[(usize, Divider)], prev: Option<usize>, i: usize) {
+    let Some(group_start) = dividers
+        .iter()
+        .map(|(index, _)| *index)
+        .filter(|&index| index <= i)
+        .max()
+    else {
+        return;
+    };
+    if prev.map_or(false, |prev| group_start <= prev) {
+        return;
+    }
+    for (_, divider) in dividers.iter().filter(|(index, _)| *index == group_start) {
+        match divider {
+            Divider::Separator => {
+                ui.separator();
+            }
+            Divider::Header(heading) => {
+                ui.add(Label::new(heading.clone()).selectable(false));
+            }
+        }
+    }
+}
+
+/// The items in the popup scroll if they don't fit in `height`.
+fn menu_scroll_area<R>(ui: &mut Ui, height: f32, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+    ScrollArea::vertical()
+        .max_height(height)
+        .show(ui, |ui| {
+            // Often the button is very narrow, which means this popup
+            // is also very narrow. Having wrapping on would therefore
+            // result in labels that wrap very early.
+            // Instead, we turn it off by default so that the labels
+            // expand the width of the menu.
+            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+            add_contents(ui)
+        })
+        .inner
+}
+
+/// The text on a [`ComboBox::show_multi_index`] combo box: the selected labels if they fit, else how many there are.
+fn multi_select_summary(ui: &Ui, labels: &[String], max_width: f32) -> String {
+    let joined = labels.join(", ");
+    if labels.len() == 1 {
+        return joined;
+    }
+    let font_id = TextStyle::Button.resolve(ui.style());
+    let width = ui
+        .fonts(|f| f.layout_no_wrap(joined.clone(), font_id, Color32::PLACEHOLDER))
+        .size()
+        .x;
+    if width <= max_width {
+        joined
+    } else {
+        format!("{} selected", labels.len())
+    }
+}
+
+/// The search text and keyboard selection of an open [`ComboBox::searchable`] combo box.
+#[derive(Clone, Default)]
+struct SearchState {
+    query: String,
+
+    /// Index into the list of matching items.
+    highlighted: Option<usize>,
+}
+
+fn search_state_id(button_id: Id) -> Id {
+    ComboBox::widget_to_popup_id(button_id).with("search")
+}
+
+/// The contents of the popup of a [`ComboBox::searchable`] combo box:
+/// a text field, and below it the items matching the text.
+///
+/// Returns the index of the item the user chose, if any.
+#[allow(clippy::too_many_arguments)]
+fn search_list(
+    ui: &mut Ui,
+    button_id: Id,
+    height: f32,
+    search: SearchMode,
+    dividers: &[(usize, Divider)],
+    selected: usize,
+    len: usize,
+    get: &dyn Fn(usize) -> WidgetText,
+) -> Option<usize> {
+    let state_id = search_state_id(button_id);
+    let state = ui.data(|d| d.get_temp::<SearchState>(state_id));
+    let just_opened = state.is_none();
+    let mut state = state.unwrap_or_default();
+
+    // Take the keys before the text field, which would otherwise move the cursor or lose focus:
+    let (up, down, enter) = ui.input_mut(|i| {
+        (
+            i.consume_key(Modifiers::NONE, Key::ArrowUp),
+            i.consume_key(Modifiers::NONE, Key::ArrowDown),
+            i.consume_key(Modifiers::NONE, Key::Enter),
+        )
+    });
+
+    let query_response = ui.add(
+        TextEdit::singleline(&mut state.query)
+            .id(state_id)
+            .hint_text("Search…")
+            .desired_width(f32::INFINITY),
+    );
+    if !query_response.has_focus() {
+        // Keep typing into the text field, even after clicking the scroll bar:
+        query_response.request_focus();
+    }
+
+    let matches: Vec<(usize, WidgetText)> = (0..len)
+        .map(|i| (i, get(i)))
+        .filter(|(_, label)| search.matches(&state.query, label.text()))
+        .collect();
+
+    if just_opened {
+        state.highlighted = matches.iter().position(|(i, _)| *i == selected);
+    } else if query_response.changed() {
+        state.highlighted = Some(0);
+    }
+    if up {
+        state.highlighted = Some(state.highlighted.map_or(0, |h| h.saturating_sub(1)));
+    }
+    if down {
+        state.highlighted = Some(state.highlighted.map_or(0, |h| h + 1));
+    }
+    state.highlighted = state
+        .highlighted
+        .filter(|_| !matches.is_empty())
+        .map(|h| h.min(matches.len() - 1));
+
+    let mut chosen = if enter {
+        state.highlighted.map(|h| matches[h].0)
+    } else {
+        None
+    };
+
+    ui.separator();
+
+    menu_scroll_area(ui, height, |ui| {
+        if matches.is_empty() {
+            ui.weak("No matches");
+        }
+        let mut prev = None;
+        for (row, (i, label)) in matches.into_iter().enumerate() {
+            show_dividers(ui, dividers, prev, i);
+            prev = Some(i);
+            let mut response = ui.selectable_label(i == selected, label);
+            if state.highlighted == Some(row) {
+                response = response.highlight();
+                if just_opened || up || down {
+                    response.scroll_to_me(None);
+                }
+            }
+            if response.clicked() {
+                chosen = Some(i);
+            }
+        }
+    });
+
+    if chosen.is_some() {
+        ui.memory_mut(|mem| {
+            mem.close_popup();
+            mem.request_focus(button_id);
+        });
+    }
+    ui.data_mut(|d| d.insert_temp(state_id, state));
+    chosen
+}
+
 fn button_frame(
     ui: &mut Ui,
     id: Id,
@@ -0,0 +1,333 @@
+//! A searchable list of commands that pops up with a keyboard shortcut, see [`CommandPalette`].
+
+use std::hash::Hash;
+
+use crate::{util::fuzzy::fuzzy_score, *};
+
+/// How many recently used commands to remember.
+const MAX_RECENT: usize = 20;
+
+/// Added to the score of recently used commands when filtering,
+/// so they come before equally good matches.
+const RECENT_BONUS: i32 = 6;
+
+/// The state of an open [`CommandPalette`].
+#[derive(Clone, Debug, Default)]
+struct CommandPaletteState {
+    query: String,
+
+    /// Index into the filtered list of commands.
+    selected: usize,
+
+    /// Scroll the selected command into view.
+    scroll_to_selected: bool,
+
+    /// The vertical scroll offset and height of the list last frame.
+    scroll_offset: f32,
+    view_height: f32,
+
+    /// The frame the palette was opened in.
+    ///
+    /// The click that opened it (e.g. on a menu button) must not close it again.
+    opened_frame: u64,
+}
+
+/// The commands chosen last, most recent first.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct RecentCommands(Vec<String>);
+
+/// A text field and a list of commands that pops up at the top of the screen,
+/// e.g. when pressing Cmd+Shift+P.
+///
+/// As the user types, the list is filtered down to the commands that fuzzy-match the text,
+/// so `"ofi"` finds `"Open file"`.
+/// The arrow keys select a command, Enter chooses it, and Escape (or clicking elsewhere) closes the palette.
+///
+/// The commands that were chosen recently are listed first,
+/// and are remembered in [`Memory`] (and persisted, if the `persistence` feature is on).
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// let commands = ["Open file", "Save", "Save as…", "Quit"];
+/// # let mut last_command = None;
+/// if let Some(index) = egui::CommandPalette::new("commands").show(ctx, &commands) {
+///     last_command = Some(commands[index]);
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct CommandPalette {
+    id: Id,
+    shortcut: Option<KeyboardShortcut>,
+    hint_text: WidgetText,
+    width: f32,
+    max_height: f32,
+}
+
+impl CommandPalette {
+    /// The id should be globally unique, e.g. `Id::new("command_palette")`.
+    pub fn new(id: impl Hash) -> Self {
+        Self {
+            id: Id::new(id),
+            shortcut: Some(KeyboardShortcut::new(
+                Modifiers::COMMAND | Modifiers::SHIFT,
+                Key::P,
+            )),
+            hint_text: "Type a command…".into(),
+            width: 400.0,
+            max_height: 300.0,
+        }
+    }
+
+    /// The keyboard shortcut that opens (and closes) the palette.
+    ///
+    /// With `None` the palette is only opened by calling [`Self::open`].
+    ///
+    /// Default: Cmd+Shift+P.
+    #[inline]
+    pub fn shortcut(mut self, shortcut: Option<KeyboardShortcut>) -> Self {
+        self.shortcut = shortcut;
+        self
+    }
+
+    /// Shown in the text field before the user has typed anything.
+    ///
+    /// Default: "Type a command…".
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+
+    /// Default: `400.0`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// The height of the list of commands, after which it scrolls.
+    ///
+    /// Default: `300.0`.
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Open the palette, e.g. from a menu button.
+    ///
+    /// `id` is `Id::new` of what was passed to [`Self::new`].
+    pub fn open(ctx: &Context, id: Id) {
+        let state = CommandPaletteState {
+            opened_frame: ctx.frame_nr(),
+            ..Default::default()
+        };
+        ctx.data_mut(|d| d.insert_temp(id, state));
+        ctx.memory_mut(|mem| mem.request_focus(id.with("query")));
+    }
+
+    /// Close the palette, if it is open.
+    pub fn close(ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.remove::<CommandPaletteState>(id));
+    }
+
+    pub fn is_open(ctx: &Context, id: Id) -> bool {
+        ctx.data(|d| d.get_temp::<CommandPaletteState>(id))
+            .is_some()
+    }
+
+    /// Show the palette, if it is open, and return the index of the command the user chose, if any.
+    ///
+    /// Call this every frame, so the keyboard shortcut can open it.
+    pub fn show(self, ctx: &Context, commands: &[impl AsRef<str>]) -> Option<usize> {
+        let Self {
+            id,
+            shortcut,
+            hint_text,
+            width,
+            max_height,
+        } = self;
+
+        if shortcut.map_or(false, |shortcut| {
+            ctx.input_mut(|i| i.consume_shortcut(&shortcut))
+        }) {
+            if Self::is_open(ctx, id) {
+                Self::close(ctx, id);
+            } else {
+                Self::open(ctx, id);
+            }
+        }
+
+        let mut state = ctx.data(|d| d.get_temp::<CommandPaletteState>(id))?;
+        let mut recent: RecentCommands = ctx.data_mut(|d| d.get_persisted(id)).unwrap_or_default();
+
+        // Take the keys before the text field, which would otherwise move the cursor or lose focus:
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+
+        let screen_rect = ctx.screen_rect();
+        let width = width.at_most(screen_rect.width() - 16.0);
+        let mut chosen = None;
+
+        let area = Area::new(id)
+            .order(Order::Foreground)
+            .anchor(Align2::CENTER_TOP, vec2(0.0, 0.1 * screen_rect.height()))
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_width(width);
+
+                    let query_id = id.with("query");
+                    let query_response = ui.add(
+                        TextEdit::singleline(&mut state.query)
+                            .id(query_id)
+                            .hint_text(hint_text)
+                            .desired_width(f32::INFINITY),
+                    );
+                    if !query_response.has_focus() {
+                        // Keyboard first: the text field always has focus.
+                        query_response.request_focus();
+                    }
+                    if query_response.changed() {
+                        state.selected = 0;
+                        state.scroll_to_selected = true;
+                    }
+
+                    let matches = filter_commands(&state.query, commands, &recent.0);
+                    if !matches.is_empty() {
+                        if up {
+                            state.selected = (state.selected + matches.len() - 1) % matches.len();
+                            state.scroll_to_selected = true;
+                        }
+                        if down {
+                            state.selected = (state.selected + 1) % matches.len();
+                            state.scroll_to_selected = true;
+                        }
+                    }
+                    state.selected = state.selected.min(matches.len().saturating_sub(1));
+                    if enter {
+                        chosen = matches.get(state.selected).copied();
+                    }
+
+                    ui.separator();
+
+                    if matches.is_empty() {
+                        ui.weak("No matching commands");
+                        return;
+                    }
+
+                    let row_height = ui.spacing().interact_size.y;
+                    let mut scroll_area = ScrollArea::vertical()
+                        .id_source(id.with("list"))
+                        .max_height(max_height)
+                        .auto_shrink([false, true]);
+                    if state.scroll_to_selected {
+                        state.scroll_to_selected = false;
+                        let top =
+                            state.selected as f32 * (row_height + ui.spacing().item_spacing.y);
+                        if top < state.scroll_offset {
+                            scroll_area = scroll_area.vertical_scroll_offset(top);
+                        } else if state.scroll_offset + state.view_height < top + row_height {
+                            scroll_area = scroll_area
+                                .vertical_scroll_offset(top + row_height - state.view_height);
+                        }
+                    }
+
+                    let output =
+                        scroll_area.show_rows(ui, row_height, matches.len(), |ui, rows| {
+                            for row in rows {
+                                let index = matches[row];
+                                let button = Button::new(commands[index].as_ref())
+                                    .selected(row == state.selected)
+                                    .frame(false)
+                                    .truncate()
+                                    .elided_text_on_hover(true)
+                                    .min_size(vec2(ui.available_width(), row_height));
+                                if ui.add(button).clicked() {
+                                    chosen = Some(index);
+                                }
+                            }
+                        });
+                    state.scroll_offset = output.state.offset.y;
+                    state.view_height = output.inner_rect.height();
+                });
+            });
+
+        if let Some(index) = chosen {
+            let name = commands[index].as_ref();
+            recent.0.retain(|recent| recent != name);
+            recent.0.insert(0, name.to_owned());
+            recent.0.truncate(MAX_RECENT);
+            ctx.data_mut(|d| d.insert_persisted(id, recent));
+        }
+
+        let clicked_elsewhere =
+            state.opened_frame != ctx.frame_nr() && area.response.clicked_elsewhere();
+        if chosen.is_some() || escape || clicked_elsewhere {
+            Self::close(ctx, id);
+        } else {
+            ctx.data_mut(|d| d.insert_temp(id, state));
+        }
+        chosen
+    }
+}
+
+/// The indices of the commands matching the query, best match first.
+///
+/// With an empty query all commands are listed, the recently used ones first.
+fn filter_commands(query: &str, commands: &[impl AsRef<str>], recent: &[String]) -> Vec<usize> {
+    let recent_rank = |name: &str| recent.iter().position(|recent| recent == name);
+
+    let mut matches: Vec<(usize, i32, Option<usize>)> = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| {
+            let name = command.as_ref();
+            let rank = recent_rank(name);
+            let score = fuzzy_score(query, name)?;
+            let score = if rank.is_some() && !query.is_empty() {
+                score + RECENT_BONUS
+            } else {
+                score
+            };
+            Some((index, score, rank))
+        })
+        .collect();
+
+    // Higher score first, then the most recently used, then in the original order:
+    matches.sort_by_key(|&(index, score, rank)| (-score, rank.unwrap_or(usize::MAX), index));
+    matches.into_iter().map(|(index, _, _)| index).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_click_that_opens_does_not_close() {
+        let harness = TestHarness::new();
+        let id = Id::new("palette");
+        let commands = ["Open file", "Save"];
+        let click = || TestHarness::click_events(pos2(10.0, 500.0));
+
+        // E.g. a click on a menu button that opens the palette:
+        harness.run(click(), |ctx| {
+            CommandPalette::open(ctx, id);
+            CommandPalette::new("palette").show(ctx, &commands);
+        });
+        assert!(CommandPalette::is_open(&harness.ctx, id));
+
+        // A later click elsewhere closes it:
+        harness.run(click(), |ctx| {
+            CommandPalette::new("palette").show(ctx, &commands);
+        });
+        assert!(!CommandPalette::is_open(&harness.ctx, id));
+    }
+}
@@ -0,0 +1,256 @@
+//! A panel that slides in over the content from the side of the screen, see [`Drawer`].
+
+use std::hash::Hash;
+
+use crate::{containers::panel::Side, *};
+
+/// How close to the edge of the screen a swipe must start to open a [`Drawer`].
+const EDGE_SWIPE_WIDTH: f32 = 16.0;
+
+#[derive(Clone, Copy, Debug, Default)]
+struct DrawerState {
+    /// 0 when closed, 1 when open.
+    openness: f32,
+
+    /// Is the user dragging the drawer open or closed?
+    dragging: bool,
+}
+
+/// A panel that slides in from the left or right edge of the screen,
+/// on top of everything else, and dims the rest of the screen while it is open.
+///
+/// Unlike a [`SidePanel`] it doesn't take up any space, so it is often used for navigation on small screens.
+///
+/// The drawer closes when the user clicks outside of it or presses Escape.
+/// It can also be dragged closed, and on touch screens it can be opened
+/// by swiping in from the edge of the screen.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// # let mut menu_open = false;
+/// egui::Drawer::left("menu").show(ctx, &mut menu_open, |ui| {
+///     ui.heading("Menu");
+///     ui.label("Settings");
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Drawer {
+    id: Id,
+    side: Side,
+    width: f32,
+    frame: Option<Frame>,
+    swipe_to_open: bool,
+}
+
+impl Drawer {
+    /// A drawer that slides in from the left.
+    ///
+    /// The id should be globally unique, e.g. `Id::new("menu_drawer")`.
+    pub fn left(id: impl Hash) -> Self {
+        Self::new(Side::Left, id)
+    }
+
+    /// A drawer that slides in from the right.
+    ///
+    /// The id should be globally unique, e.g. `Id::new("details_drawer")`.
+    pub fn right(id: impl Hash) -> Self {
+        Self::new(Side::Right, id)
+    }
+
+    pub fn new(side: Side, id: impl Hash) -> Self {
+        Self {
+            id: Id::new(id),
+            side,
+            width: 280.0,
+            frame: None,
+            swipe_to_open: true,
+        }
+    }
+
+    /// How wide the drawer is.
+    ///
+    /// On narrow screens it is made narrower, so that there is always some space to click on to close it.
+    ///
+    /// Default: `280.0`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Change the background color, margins, etc.
+    #[inline]
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// On touch screens, open the drawer when the user swipes in from the edge of the screen.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn swipe_to_open(mut self, swipe_to_open: bool) -> Self {
+        self.swipe_to_open = swipe_to_open;
+        self
+    }
+
+    /// Show the drawer, if `open` is `true` (or while it is sliding closed).
+    ///
+    /// Sets `open` to `false` when the user closes the drawer,
+    /// and to `true` when they swipe it open.
+    pub fn show<R>(
+        self,
+        ctx: &Context,
+        open: &mut bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let Self {
+            id,
+            side,
+            width,
+            frame,
+            swipe_to_open,
+        } = self;
+
+        let screen_rect = ctx.screen_rect();
+        let width = width.at_most(0.85 * screen_rect.width());
+        // +1 when moving the pointer inwards from the edge opens the drawer, -1 when it closes it:
+        let inwards = match side {
+            Side::Left => 1.0,
+            Side::Right => -1.0,
+        };
+
+        let mut state = ctx
+            .data(|d| d.get_temp::<DrawerState>(id))
+            .unwrap_or_default();
+
+        if !*open && !state.dragging && swipe_to_open {
+            let started_at_edge = ctx.input(|i| {
+                i.any_touches()
+                    && i.pointer.is_decidedly_dragging()
+                    && i.pointer.press_origin().map_or(false, |origin| {
+                        let from_edge = match side {
+                            Side::Left => origin.x - screen_rect.left(),
+                            Side::Right => screen_rect.right() - origin.x,
+                        };
+                        from_edge < EDGE_SWIPE_WIDTH
+                    })
+            });
+            if started_at_edge && ctx.dragged_id().is_none() {
+                state.dragging = true;
+            }
+        }
+
+        if *open && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+            *open = false;
+        }
+
+        if state.dragging {
+            let (any_down, delta, velocity) = ctx.input(|i| {
+                (
+                    i.pointer.any_down(),
+                    i.pointer.delta(),
+                    i.pointer.velocity(),
+                )
+            });
+            if any_down {
+                state.openness = (state.openness + inwards * delta.x / width).clamp(0.0, 1.0);
+            } else {
+                // A quick flick decides, otherwise whatever is closest:
+                let velocity = inwards * velocity.x;
+                *open = if velocity.abs() > 300.0 {
+                    0.0 < velocity
+                } else {
+                    0.5 < state.openness
+                };
+                state.dragging = false;
+            }
+        } else {
+            let target = if *open { 1.0 } else { 0.0 };
+            let animation_time = ctx.style().animation_time;
+            state.openness = if ctx.style().reduced_motion || animation_time <= 0.0 {
+                target
+            } else {
+                let step = ctx.input(|i| i.stable_dt) / animation_time;
+                if state.openness < target {
+                    (state.openness + step).at_most(target)
+                } else {
+                    (state.openness - step).at_least(target)
+                }
+            };
+            if state.openness != target {
+                ctx.request_repaint();
+            }
+        }
+
+        if state.openness <= 0.0 && !state.dragging {
+            ctx.data_mut(|d| d.insert_temp(id, state));
+            return None;
+        }
+
+        let openness = emath::easing::cubic_out(state.openness);
+
+        // Dim everything behind the drawer, and close it when clicked:
+        let scrim = Area::new(id.with("scrim"))
+            .order(Order::Foreground)
+            .fixed_pos(screen_rect.min)
+            .constrain(false)
+            .fade_in(false)
+            .show(ctx, |ui| {
+                let (rect, response) = ui.allocate_exact_size(screen_rect.size(), Sense::click());
+                ui.painter().rect_filled(
+                    rect,
+                    0.0,
+                    Color32::from_black_alpha(100).gamma_multiply(openness),
+                );
+                response
+            });
+        if scrim.inner.clicked() {
+            *open = false;
+        }
+        ctx.move_to_top(scrim.response.layer_id);
+
+        let x = match side {
+            Side::Left => screen_rect.left() - (1.0 - openness) * width,
+            Side::Right => screen_rect.right() - openness * width,
+        };
+        let drawer_rect = Rect::from_min_size(
+            pos2(x, screen_rect.top()),
+            vec2(width, screen_rect.height()),
+        );
+
+        let frame = frame.unwrap_or_else(|| {
+            let style = ctx.style();
+            Frame::side_top_panel(&style)
+                .inner_margin(8.0)
+                .shadow(style.visuals.window_shadow)
+        });
+
+        let drawer = Area::new(id)
+            .order(Order::Foreground)
+            .fixed_pos(drawer_rect.min)
+            .constrain(false)
+            .fade_in(false)
+            .show(ctx, |ui| {
+                // Interact with the background first, so any widgets on top of it get the drags:
+                let background = ui.interact(drawer_rect, id.with("background"), Sense::drag());
+                if background.drag_started() {
+                    state.dragging = true;
+                }
+
+                let inner_size = drawer_rect.size() - frame.inner_margin.sum();
+                frame
+                    .show(ui, |ui| {
+                        ui.set_min_size(inner_size);
+                        ui.set_max_size(inner_size);
+                        add_contents(ui)
+                    })
+                    .inner
+            });
+        ctx.move_to_top(drawer.response.layer_id);
+
+        ctx.data_mut(|d| d.insert_temp(id, state));
+        Some(drawer)
+    }
+}
@@ -1,6 +1,6 @@
 //! Frame container
 
-use crate::{layers::ShapeIdx, *};
+use crate::{layers::ShapeIdx, load::SizedTexture, *};
 use epaint::*;
 
 /// Add a background, frame and/or margin to a rectangular background of a [`Ui`].
@@ -68,6 +68,14 @@ pub struct Frame {
     pub fill: Color32,
 
     pub stroke: Stroke,
+
+    /// If set, the frame is filled with this texture, multiplied with [`Self::fill`].
+    ///
+    /// Rounding is ignored when there is a texture.
+    pub fill_texture: Option<SizedTexture>,
+
+    /// How [`Self::fill_texture`] is stretched over the frame.
+    pub nine_patch: NinePatch,
 }
 
 impl Frame {
@@ -164,6 +172,20 @@ impl Frame {
         self
     }
 
+    /// Fill the frame with a texture, e.g. for skinning panels.
+    ///
+    /// Use the [`NinePatch`] to keep the corners of the texture from stretching.
+    ///
+    /// The texture is multiplied with [`Self::fill`], which this sets to white:
+    /// call [`Self::fill`] afterwards for tinting the texture.
+    #[inline]
+    pub fn fill_texture(mut self, texture: impl Into<SizedTexture>, nine_patch: NinePatch) -> Self {
+        self.fill_texture = Some(texture.into());
+        self.nine_patch = nine_patch;
+        self.fill = Color32::WHITE;
+        self
+    }
+
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
         self.stroke = stroke.into();
@@ -293,9 +315,20 @@ impl Frame {
             shadow,
             fill,
             stroke,
+            fill_texture,
+            nine_patch,
         } = *self;
 
-        let frame_shape = Shape::Rect(epaint::RectShape::new(outer_rect, rounding, fill, stroke));
+        let frame_shape = match fill_texture {
+            Some(texture) => {
+                let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+                let mut mesh = Mesh::with_texture(texture.id);
+                mesh.add_nine_patch(outer_rect, uv, texture.size, &nine_patch, fill);
+                let stroke = epaint::RectShape::stroke(outer_rect, rounding, stroke);
+                Shape::Vec(vec![Shape::mesh(mesh), Shape::Rect(stroke)])
+            }
+            None => Shape::Rect(epaint::RectShape::new(outer_rect, rounding, fill, stroke)),
+        };
 
         if shadow == Default::default() {
             frame_shape
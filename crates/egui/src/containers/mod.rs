@@ -2,24 +2,40 @@
 //!
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
+pub(crate) mod accordion;
 pub(crate) mod area;
 pub mod collapsing_header;
 mod combo_box;
+pub(crate) mod command_palette;
+pub(crate) mod drawer;
 pub(crate) mod frame;
 pub mod panel;
 pub mod popup;
+pub(crate) mod property_grid;
 pub(crate) mod resize;
 pub mod scroll_area;
+pub(crate) mod splitter;
+pub(crate) mod table;
+pub(crate) mod tabs;
+pub(crate) mod tree_view;
 pub(crate) mod window;
 
 pub use {
+    accordion::{Accordion, AccordionBuilder},
     area::{Area, AreaState},
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
+    command_palette::CommandPalette,
+    drawer::Drawer,
     frame::Frame,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
+    property_grid::{PropertyGrid, PropertyGridBuilder},
     resize::Resize,
     scroll_area::ScrollArea,
-    window::Window,
+    splitter::Splitter,
+    table::{Table, TableColumn, TableResponse, TableSort},
+    tabs::{Tabs, TabsResponse},
+    tree_view::{DropPosition, TreeDrop, TreeView, TreeViewBuilder, TreeViewResponse},
+    window::{Window, WindowState},
 };
@@ -21,6 +21,35 @@ fn animate_expansion(ctx: &Context, id: Id, is_expanded: bool) -> f32 {
     ctx.animate_bool_responsive(id, is_expanded)
 }
 
+/// How wide (or tall) the strip is that an auto-hiding panel collapses to.
+const AUTO_HIDE_STRIP_SIZE: f32 = 6.0;
+
+/// State of a panel shown with e.g. [`SidePanel::show_auto_hide`].
+#[derive(Clone, Copy, Debug, Default)]
+struct AutoHideState {
+    /// Toggled with the keyboard shortcut: stay expanded even when not hovered.
+    pinned: bool,
+
+    /// Was the pointer over the strip or the expanded panel last frame?
+    hovered: bool,
+}
+
+impl AutoHideState {
+    fn load(ctx: &Context, id: Id, shortcut: Option<KeyboardShortcut>) -> Self {
+        let mut state: Self = ctx.data(|d| d.get_temp(id)).unwrap_or_default();
+        if shortcut.map_or(false, |shortcut| {
+            ctx.input_mut(|i| i.consume_shortcut(&shortcut))
+        }) {
+            state.pinned = !state.pinned;
+        }
+        state
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id, self));
+    }
+}
+
 /// State regarding panels.
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -38,6 +67,16 @@ impl PanelState {
         self.rect.size()
     }
 
+    fn update(ctx: &Context, bar_id: Id, update: impl FnOnce(&mut Rect)) {
+        let mut state = Self::load(ctx, bar_id).unwrap_or(Self { rect: Rect::ZERO });
+        update(&mut state.rect);
+        state.store(ctx, bar_id);
+    }
+
+    fn remove(ctx: &Context, bar_id: Id) {
+        ctx.data_mut(|d| d.remove::<Self>(bar_id));
+    }
+
     fn store(self, ctx: &Context, bar_id: Id) {
         ctx.data_mut(|d| d.insert_persisted(bar_id, self));
     }
@@ -102,6 +141,8 @@ pub struct SidePanel {
     show_separator_line: bool,
     default_width: f32,
     width_range: Rangef,
+    relative_width_range: Option<Rangef>,
+    respect_safe_area: bool,
 }
 
 impl SidePanel {
@@ -125,6 +166,8 @@ impl SidePanel {
             show_separator_line: true,
             default_width: 200.0,
             width_range: Rangef::new(96.0, f32::INFINITY),
+            relative_width_range: None,
+            respect_safe_area: false,
         }
     }
 
@@ -193,6 +236,18 @@ impl SidePanel {
     pub fn exact_width(mut self, width: f32) -> Self {
         self.default_width = width;
         self.width_range = Rangef::point(width);
+        self.relative_width_range = None;
+        self
+    }
+
+    /// The allowable width range for the panel, as fractions of the available width,
+    /// e.g. `0.2..=0.5` for between a fifth and half of the screen.
+    ///
+    /// This is recomputed every frame, so the panel keeps within these limits when the window is resized.
+    /// It applies on top of [`Self::width_range`], and wins where the two don't overlap.
+    #[inline]
+    pub fn relative_width_range(mut self, relative_width_range: impl Into<Rangef>) -> Self {
+        self.relative_width_range = Some(relative_width_range.into());
         self
     }
 
@@ -202,22 +257,51 @@ impl SidePanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Keep the contents out of the parts of the screen covered by display cutouts,
+    /// rounded corners and the like, by adding to the inner margin of the frame.
+    ///
+    /// The background of the panel still covers them.
+    /// See [`crate::RawInput::safe_area_insets`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
 }
 
 impl SidePanel {
+    /// Resize the panel with the given id, as if the user had dragged its edge.
+    ///
+    /// The width is still kept within the limits set on the panel.
+    pub fn set_width(ctx: &Context, id: impl Into<Id>, width: f32) {
+        PanelState::update(ctx, id.into(), |rect| rect.set_width(width));
+    }
+
+    /// Forget the width the user has resized the panel with the given id to,
+    /// going back to [`Self::default_width`].
+    pub fn reset_width(ctx: &Context, id: impl Into<Id>) {
+        PanelState::remove(ctx, id.into());
+    }
+
     /// Show the panel inside a [`Ui`].
     pub fn show_inside<R>(
         self,
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.show_inside_dyn(ui, Box::new(add_contents))
+        self.show_inside_dyn(ui, 1.0, Box::new(add_contents))
     }
 
     /// Show the panel inside a [`Ui`].
+    ///
+    /// With `how_expanded < 1.0` the panel is moved partially out of view, past its side.
     fn show_inside_dyn<'c, R>(
         self,
         ui: &mut Ui,
+        how_expanded: f32,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
         let Self {
@@ -228,9 +312,12 @@ impl SidePanel {
             show_separator_line,
             default_width,
             width_range,
+            relative_width_range,
+            respect_safe_area,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
+        let width_range = limit_range(width_range, relative_width_range, available_rect.width());
         let mut panel_rect = available_rect;
         let mut width = default_width;
         {
@@ -242,6 +329,13 @@ impl SidePanel {
             ui.ctx().check_for_id_clash(id, panel_rect, "SidePanel");
         }
 
+        let resizable = resizable && 1.0 <= how_expanded;
+        let slide = (1.0 - how_expanded) * width;
+        panel_rect = panel_rect.translate(match side {
+            Side::Left => vec2(-slide, 0.0),
+            Side::Right => vec2(slide, 0.0),
+        });
+
         let resize_id = id.with("__resize");
         let mut resize_hover = false;
         let mut is_resizing = false;
@@ -271,7 +365,11 @@ impl SidePanel {
             })),
         );
         panel_ui.expand_to_include_rect(panel_rect);
-        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        panel_ui.set_clip_rect(panel_ui.clip_rect().intersect(available_rect));
+        let mut frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        if respect_safe_area {
+            frame.inner_margin = frame.inner_margin + safe_area_margin(ui.ctx(), panel_rect);
+        }
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect().height()); // Make sure the frame fills the full height
             ui.set_min_width((width_range.min - frame.inner_margin.sum().x).at_least(0.0));
@@ -292,7 +390,7 @@ impl SidePanel {
             }
             ui.set_cursor(cursor);
         }
-        ui.expand_to_include_rect(rect);
+        ui.expand_to_include_rect(rect.intersect(available_rect));
 
         if resizable {
             // Now we do the actual resize interaction, on top of all the contents.
@@ -317,7 +415,9 @@ impl SidePanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        if 1.0 <= how_expanded {
+            PanelState { rect }.store(ui.ctx(), id);
+        }
 
         {
             let stroke = if is_resizing {
@@ -347,13 +447,14 @@ impl SidePanel {
         ctx: &Context,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.show_dyn(ctx, Box::new(add_contents))
+        self.show_dyn(ctx, 1.0, Box::new(add_contents))
     }
 
     /// Show the panel at the top level.
     fn show_dyn<'c, R>(
         self,
         ctx: &Context,
+        how_expanded: f32,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
         let layer_id = LayerId::background();
@@ -369,7 +470,7 @@ impl SidePanel {
             UiStackInfo::default(),
         );
 
-        let inner_response = self.show_inside_dyn(&mut panel_ui, add_contents);
+        let inner_response = self.show_inside_dyn(&mut panel_ui, how_expanded, add_contents);
         let rect = inner_response.response.rect;
 
         match side {
@@ -449,6 +550,121 @@ impl SidePanel {
         }
     }
 
+    /// Like [`Self::show_animated`], but the panel slides in and out past its side,
+    /// contents and all, instead of an empty panel growing and shrinking.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let show_tools = true;
+    /// egui::SidePanel::left("tools").show_sliding(ctx, show_tools, |ui| {
+    ///     ui.label("Tools");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_sliding<R>(
+        self,
+        ctx: &Context,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = animate_expansion(ctx, self.id.with("animation"), is_expanded);
+        (0.0 < how_expanded).then(|| self.show_dyn(ctx, how_expanded, Box::new(add_contents)))
+    }
+
+    /// Like [`Self::show_animated_inside`], but the panel slides in and out past its side,
+    /// contents and all, instead of an empty panel growing and shrinking.
+    pub fn show_sliding_inside<R>(
+        self,
+        ui: &mut Ui,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = animate_expansion(ui.ctx(), self.id.with("animation"), is_expanded);
+        (0.0 < how_expanded).then(|| self.show_inside_dyn(ui, how_expanded, Box::new(add_contents)))
+    }
+
+    /// Collapse the panel to a thin strip along its side,
+    /// which expands over the rest of the contents while the pointer is over it.
+    ///
+    /// The expanded panel doesn't take up any space, so whatever is next to it (e.g. the [`CentralPanel`])
+    /// doesn't move around when it is shown and hidden.
+    /// If given, the `shortcut` keeps the panel expanded until it is pressed again,
+    /// so it can also be used without a pointer.
+    ///
+    /// Returns `None` while the panel is collapsed.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::B);
+    /// egui::SidePanel::left("tools").show_auto_hide(ctx, Some(shortcut), |ui| {
+    ///     ui.label("Tools");
+    /// });
+    /// egui::CentralPanel::default().show(ctx, |ui| {
+    ///     ui.label("The tools are shown on top of this");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_auto_hide<R>(
+        self,
+        ctx: &Context,
+        shortcut: Option<KeyboardShortcut>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let id = self.id;
+        let state_id = id.with("__auto_hide");
+        let mut state = AutoHideState::load(ctx, state_id, shortcut);
+
+        // The strip stays in place, so the contents next to it don't move:
+        let strip = Self::new(self.side, id.with("__strip"))
+            .resizable(false)
+            .show_separator_line(self.show_separator_line)
+            .exact_width(AUTO_HIDE_STRIP_SIZE)
+            .frame(Frame::side_top_panel(&ctx.style()).inner_margin(0.0))
+            .show(ctx, |_ui| {});
+
+        let mut hovered = strip.response.contains_pointer();
+        let is_expanded = state.pinned || state.hovered || hovered;
+        let how_expanded = animate_expansion(ctx, id.with("animation"), is_expanded);
+
+        let mut inner = None;
+        if 0.0 < how_expanded {
+            let available_rect = ctx.available_rect();
+            let width =
+                PanelState::load(ctx, id).map_or(self.default_width, |state| state.rect.width());
+            let width_range = limit_range(
+                self.width_range,
+                self.relative_width_range,
+                available_rect.width(),
+            );
+            let width = clamp_to_range(width, width_range).at_most(available_rect.width());
+            let mut overlay_rect = available_rect;
+            self.side.set_rect_width(&mut overlay_rect, width);
+
+            let area = Area::new(state_id)
+                .order(Order::Foreground)
+                .fixed_pos(overlay_rect.min)
+                .constrain(false)
+                .fade_in(false)
+                .show(ctx, |ui| {
+                    let mut panel_ui =
+                        ui.child_ui(overlay_rect, Layout::top_down(Align::Min), None);
+                    let inner =
+                        self.show_inside_dyn(&mut panel_ui, how_expanded, Box::new(add_contents));
+                    ui.expand_to_include_rect(inner.response.rect.intersect(overlay_rect));
+                    inner
+                });
+            let is_resizing = ctx
+                .read_response(id.with("__resize"))
+                .map_or(false, |response| response.dragged());
+            hovered |= area.response.contains_pointer() || is_resizing;
+            inner = Some(area.inner);
+        }
+
+        state.hovered = hovered;
+        state.store(ctx, state_id);
+        inner
+    }
+
     /// Show either a collapsed or a expanded panel, with a nice animation between.
     pub fn show_animated_between<R>(
         ctx: &Context,
@@ -573,6 +789,8 @@ pub struct TopBottomPanel {
     show_separator_line: bool,
     default_height: Option<f32>,
     height_range: Rangef,
+    relative_height_range: Option<Rangef>,
+    respect_safe_area: bool,
 }
 
 impl TopBottomPanel {
@@ -596,6 +814,8 @@ impl TopBottomPanel {
             show_separator_line: true,
             default_height: None,
             height_range: Rangef::new(20.0, f32::INFINITY),
+            relative_height_range: None,
+            respect_safe_area: false,
         }
     }
 
@@ -667,6 +887,18 @@ impl TopBottomPanel {
     pub fn exact_height(mut self, height: f32) -> Self {
         self.default_height = Some(height);
         self.height_range = Rangef::point(height);
+        self.relative_height_range = None;
+        self
+    }
+
+    /// The allowable height range for the panel, as fractions of the available height,
+    /// e.g. `0.1..=0.4`.
+    ///
+    /// This is recomputed every frame, so the panel keeps within these limits when the window is resized.
+    /// It applies on top of [`Self::height_range`], and wins where the two don't overlap.
+    #[inline]
+    pub fn relative_height_range(mut self, relative_height_range: impl Into<Rangef>) -> Self {
+        self.relative_height_range = Some(relative_height_range.into());
         self
     }
 
@@ -676,22 +908,51 @@ impl TopBottomPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Keep the contents out of the parts of the screen covered by display cutouts,
+    /// rounded corners and the like, by adding to the inner margin of the frame.
+    ///
+    /// The background of the panel still covers them.
+    /// See [`crate::RawInput::safe_area_insets`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
 }
 
 impl TopBottomPanel {
+    /// Resize the panel with the given id, as if the user had dragged its edge.
+    ///
+    /// The height is still kept within the limits set on the panel.
+    pub fn set_height(ctx: &Context, id: impl Into<Id>, height: f32) {
+        PanelState::update(ctx, id.into(), |rect| rect.set_height(height));
+    }
+
+    /// Forget the height the user has resized the panel with the given id to,
+    /// going back to [`Self::default_height`].
+    pub fn reset_height(ctx: &Context, id: impl Into<Id>) {
+        PanelState::remove(ctx, id.into());
+    }
+
     /// Show the panel inside a [`Ui`].
     pub fn show_inside<R>(
         self,
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.show_inside_dyn(ui, Box::new(add_contents))
+        self.show_inside_dyn(ui, 1.0, Box::new(add_contents))
     }
 
     /// Show the panel inside a [`Ui`].
+    ///
+    /// With `how_expanded < 1.0` the panel is moved partially out of view, past its side.
     fn show_inside_dyn<'c, R>(
         self,
         ui: &mut Ui,
+        how_expanded: f32,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
         let Self {
@@ -702,9 +963,13 @@ impl TopBottomPanel {
             show_separator_line,
             default_height,
             height_range,
+            relative_height_range,
+            respect_safe_area,
         } = self;
 
         let available_rect = ui.available_rect_before_wrap();
+        let height_range =
+            limit_range(height_range, relative_height_range, available_rect.height());
         let mut panel_rect = available_rect;
 
         let mut height = if let Some(state) = PanelState::load(ui.ctx(), id) {
@@ -719,6 +984,13 @@ impl TopBottomPanel {
                 .check_for_id_clash(id, panel_rect, "TopBottomPanel");
         }
 
+        let resizable = resizable && 1.0 <= how_expanded;
+        let slide = (1.0 - how_expanded) * height;
+        panel_rect = panel_rect.translate(match side {
+            TopBottomSide::Top => vec2(0.0, -slide),
+            TopBottomSide::Bottom => vec2(0.0, slide),
+        });
+
         let resize_id = id.with("__resize");
         let mut resize_hover = false;
         let mut is_resizing = false;
@@ -749,7 +1021,11 @@ impl TopBottomPanel {
             })),
         );
         panel_ui.expand_to_include_rect(panel_rect);
-        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        panel_ui.set_clip_rect(panel_ui.clip_rect().intersect(available_rect));
+        let mut frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        if respect_safe_area {
+            frame.inner_margin = frame.inner_margin + safe_area_margin(ui.ctx(), panel_rect);
+        }
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect().width()); // Make the frame fill full width
             ui.set_min_height((height_range.min - frame.inner_margin.sum().y).at_least(0.0));
@@ -770,7 +1046,7 @@ impl TopBottomPanel {
             }
             ui.set_cursor(cursor);
         }
-        ui.expand_to_include_rect(rect);
+        ui.expand_to_include_rect(rect.intersect(available_rect));
 
         if resizable {
             // Now we do the actual resize interaction, on top of all the contents.
@@ -796,7 +1072,9 @@ impl TopBottomPanel {
             ui.ctx().set_cursor_icon(cursor_icon);
         }
 
-        PanelState { rect }.store(ui.ctx(), id);
+        if 1.0 <= how_expanded {
+            PanelState { rect }.store(ui.ctx(), id);
+        }
 
         {
             let stroke = if is_resizing {
@@ -826,13 +1104,14 @@ impl TopBottomPanel {
         ctx: &Context,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
-        self.show_dyn(ctx, Box::new(add_contents))
+        self.show_dyn(ctx, 1.0, Box::new(add_contents))
     }
 
     /// Show the panel at the top level.
     fn show_dyn<'c, R>(
         self,
         ctx: &Context,
+        how_expanded: f32,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
         let layer_id = LayerId::background();
@@ -849,7 +1128,7 @@ impl TopBottomPanel {
             UiStackInfo::default(), // set by show_inside_dyn
         );
 
-        let inner_response = self.show_inside_dyn(&mut panel_ui, add_contents);
+        let inner_response = self.show_inside_dyn(&mut panel_ui, how_expanded, add_contents);
         let rect = inner_response.response.rect;
 
         match side {
@@ -938,6 +1217,123 @@ impl TopBottomPanel {
         }
     }
 
+    /// Like [`Self::show_animated`], but the panel slides in and out past its side,
+    /// contents and all, instead of an empty panel growing and shrinking.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let show_messages = true;
+    /// egui::TopBottomPanel::bottom("messages").show_sliding(ctx, show_messages, |ui| {
+    ///     ui.label("Messages");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_sliding<R>(
+        self,
+        ctx: &Context,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = animate_expansion(ctx, self.id.with("animation"), is_expanded);
+        (0.0 < how_expanded).then(|| self.show_dyn(ctx, how_expanded, Box::new(add_contents)))
+    }
+
+    /// Like [`Self::show_animated_inside`], but the panel slides in and out past its side,
+    /// contents and all, instead of an empty panel growing and shrinking.
+    pub fn show_sliding_inside<R>(
+        self,
+        ui: &mut Ui,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = animate_expansion(ui.ctx(), self.id.with("animation"), is_expanded);
+        (0.0 < how_expanded).then(|| self.show_inside_dyn(ui, how_expanded, Box::new(add_contents)))
+    }
+
+    /// Collapse the panel to a thin strip along its side,
+    /// which expands over the rest of the contents while the pointer is over it.
+    ///
+    /// The expanded panel doesn't take up any space, so whatever is next to it (e.g. the [`CentralPanel`])
+    /// doesn't move around when it is shown and hidden.
+    /// If given, the `shortcut` keeps the panel expanded until it is pressed again,
+    /// so it can also be used without a pointer.
+    ///
+    /// Returns `None` while the panel is collapsed.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::B);
+    /// egui::TopBottomPanel::bottom("messages").show_auto_hide(ctx, Some(shortcut), |ui| {
+    ///     ui.label("Messages");
+    /// });
+    /// egui::CentralPanel::default().show(ctx, |ui| {
+    ///     ui.label("The messages are shown on top of this");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_auto_hide<R>(
+        self,
+        ctx: &Context,
+        shortcut: Option<KeyboardShortcut>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let id = self.id;
+        let state_id = id.with("__auto_hide");
+        let mut state = AutoHideState::load(ctx, state_id, shortcut);
+
+        // The strip stays in place, so the contents next to it don't move:
+        let strip = Self::new(self.side, id.with("__strip"))
+            .resizable(false)
+            .show_separator_line(self.show_separator_line)
+            .exact_height(AUTO_HIDE_STRIP_SIZE)
+            .frame(Frame::side_top_panel(&ctx.style()).inner_margin(0.0))
+            .show(ctx, |_ui| {});
+
+        let mut hovered = strip.response.contains_pointer();
+        let is_expanded = state.pinned || state.hovered || hovered;
+        let how_expanded = animate_expansion(ctx, id.with("animation"), is_expanded);
+
+        let mut inner = None;
+        if 0.0 < how_expanded {
+            let available_rect = ctx.available_rect();
+            let height = PanelState::load(ctx, id)
+                .map(|state| state.rect.height())
+                .or(self.default_height)
+                .unwrap_or_else(|| ctx.style().spacing.interact_size.y);
+            let height_range = limit_range(
+                self.height_range,
+                self.relative_height_range,
+                available_rect.height(),
+            );
+            let height = clamp_to_range(height, height_range).at_most(available_rect.height());
+            let mut overlay_rect = available_rect;
+            self.side.set_rect_height(&mut overlay_rect, height);
+
+            let area = Area::new(state_id)
+                .order(Order::Foreground)
+                .fixed_pos(overlay_rect.min)
+                .constrain(false)
+                .fade_in(false)
+                .show(ctx, |ui| {
+                    let mut panel_ui =
+                        ui.child_ui(overlay_rect, Layout::top_down(Align::Min), None);
+                    let inner =
+                        self.show_inside_dyn(&mut panel_ui, how_expanded, Box::new(add_contents));
+                    ui.expand_to_include_rect(inner.response.rect.intersect(overlay_rect));
+                    inner
+                });
+            let is_resizing = ctx
+                .read_response(id.with("__resize"))
+                .map_or(false, |response| response.dragged());
+            hovered |= area.response.contains_pointer() || is_resizing;
+            inner = Some(area.inner);
+        }
+
+        state.hovered = hovered;
+        state.store(ctx, state_id);
+        inner
+    }
+
     /// Show either a collapsed or a expanded panel, with a nice animation between.
     pub fn show_animated_between<R>(
         ctx: &Context,
@@ -1043,6 +1439,7 @@ impl TopBottomPanel {
 #[derive(Default)]
 pub struct CentralPanel {
     frame: Option<Frame>,
+    respect_safe_area: bool,
 }
 
 impl CentralPanel {
@@ -1052,6 +1449,19 @@ impl CentralPanel {
         self.frame = Some(frame);
         self
     }
+
+    /// Keep the contents out of the parts of the screen covered by display cutouts,
+    /// rounded corners and the like, by adding to the inner margin of the frame.
+    ///
+    /// The background of the panel still covers them.
+    /// See [`crate::RawInput::safe_area_insets`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.respect_safe_area = respect_safe_area;
+        self
+    }
 }
 
 impl CentralPanel {
@@ -1070,7 +1480,10 @@ impl CentralPanel {
         ui: &mut Ui,
         add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
     ) -> InnerResponse<R> {
-        let Self { frame } = self;
+        let Self {
+            frame,
+            respect_safe_area,
+        } = self;
 
         let panel_rect = ui.available_rect_before_wrap();
         let mut panel_ui = ui.child_ui(
@@ -1079,7 +1492,10 @@ impl CentralPanel {
             Some(UiStackInfo::new(UiKind::CentralPanel)),
         );
 
-        let frame = frame.unwrap_or_else(|| Frame::central_panel(ui.style()));
+        let mut frame = frame.unwrap_or_else(|| Frame::central_panel(ui.style()));
+        if respect_safe_area {
+            frame.inner_margin = frame.inner_margin + safe_area_margin(ui.ctx(), panel_rect);
+        }
         frame.show(&mut panel_ui, |ui| {
             ui.expand_to_include_rect(ui.max_rect()); // Expand frame to include it all
             add_contents(ui)
@@ -1124,7 +1540,104 @@ impl CentralPanel {
     }
 }
 
+/// How far `rect` reaches into the unsafe parts at the edges of the screen.
+///
+/// See [`crate::RawInput::safe_area_insets`].
+fn safe_area_margin(ctx: &Context, rect: Rect) -> Margin {
+    let safe_rect = ctx.input(|i| i.safe_area_rect());
+    Margin {
+        left: (safe_rect.left() - rect.left()).at_least(0.0),
+        right: (rect.right() - safe_rect.right()).at_least(0.0),
+        top: (safe_rect.top() - rect.top()).at_least(0.0),
+        bottom: (rect.bottom() - safe_rect.bottom()).at_least(0.0),
+    }
+}
+
+/// Narrow `range` to the `relative` range (if any), as fractions of `available`.
+///
+/// If they don't overlap, the relative range wins.
+fn limit_range(range: Rangef, relative: Option<Rangef>, available: f32) -> Rangef {
+    let Some(relative) = relative else {
+        return range;
+    };
+    let relative = Rangef::new(relative.min * available, relative.max * available);
+    let limited = Rangef::new(range.min.max(relative.min), range.max.min(relative.max));
+    if limited.min <= limited.max {
+        limited
+    } else {
+        relative
+    }
+}
+
 fn clamp_to_range(x: f32, range: Rangef) -> f32 {
     let range = range.as_positive();
     x.clamp(range.min, range.max)
 }
+
+#[test]
+fn test_limit_range() {
+    let range = Rangef::new(100.0, 400.0);
+    assert_eq!(limit_range(range, None, 1000.0), range);
+
+    // Both apply:
+    let relative = Some(Rangef::new(0.2, 0.5));
+    assert_eq!(
+        limit_range(range, relative, 1000.0),
+        Rangef::new(200.0, 400.0)
+    );
+    assert_eq!(
+        limit_range(range, relative, 600.0),
+        Rangef::new(120.0, 300.0)
+    );
+
+    // They don't overlap, so the relative range wins:
+    assert_eq!(
+        limit_range(range, relative, 4000.0),
+        Rangef::new(800.0, 2000.0)
+    );
+    assert_eq!(limit_range(range, relative, 100.0), Rangef::new(20.0, 50.0));
+}
+
+#[test]
+fn test_respect_safe_area() {
+    let harness = TestHarness::new();
+    let input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 300.0))),
+        safe_area_insets: Margin {
+            left: 20.0,
+            right: 0.0,
+            top: 30.0,
+            bottom: 10.0,
+        },
+        ..Default::default()
+    };
+
+    let mut top = Rect::NOTHING;
+    let mut central = Rect::NOTHING;
+    let mut area = Rect::NOTHING;
+    let mut panel_rects = vec![];
+    for _ in 0..2 {
+        harness.run_input(input.clone(), |ctx| {
+            let panel = TopBottomPanel::top("top")
+                .respect_safe_area(true)
+                .show(ctx, |ui| top = ui.label("top").rect);
+            let central_panel = CentralPanel::default()
+                .respect_safe_area(true)
+                .show(ctx, |ui| central = ui.label("central").rect);
+            panel_rects = vec![panel.response.rect, central_panel.response.rect];
+            Area::new(Id::new("area"))
+                .anchor(Align2::LEFT_BOTTOM, Vec2::ZERO)
+                .respect_safe_area(true)
+                .show(ctx, |ui| area = ui.label("area").rect);
+        });
+    }
+
+    // The backgrounds still cover the insets, but the contents stay out of them:
+    assert_eq!(panel_rects[0].left_top(), Pos2::ZERO);
+    assert_eq!(panel_rects[1].right_bottom(), pos2(400.0, 300.0));
+    assert!(20.0 <= top.left() && 30.0 <= top.top());
+    assert!(20.0 <= central.left() && central.top() < 300.0 - 10.0);
+    assert_eq!(area.left(), 20.0);
+    assert!(area.bottom() <= 300.0 - 10.0);
+    assert!(300.0 - 10.0 - area.bottom() < 1.0);
+}
@@ -73,6 +73,30 @@ pub fn show_tooltip_for<R>(
         ctx,
         widget_id,
         allow_placing_below,
+        false,
+        widget_rect,
+        Box::new(add_contents),
+    )
+}
+
+/// Like [`show_tooltip_for`], but the tooltip can be used like a small window:
+/// the pointer can be moved into it to click buttons, follow links, scroll, or select text.
+///
+/// Most of the time it is easier to use [`Response::on_hover_ui_interactive`],
+/// which also keeps the tooltip open while the pointer is on its way to it.
+pub fn show_interactive_tooltip_for<R>(
+    ctx: &Context,
+    widget_id: Id,
+    widget_rect: &Rect,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> R {
+    let is_touch_screen = ctx.input(|i| i.any_touches());
+    let allow_placing_below = !is_touch_screen; // There is a finger below.
+    show_tooltip_at_avoid_dyn(
+        ctx,
+        widget_id,
+        allow_placing_below,
+        true,
         widget_rect,
         Box::new(add_contents),
     )
@@ -93,6 +117,7 @@ pub fn show_tooltip_at<R>(
         ctx,
         widget_id,
         allow_placing_below,
+        false,
         &rect,
         Box::new(add_contents),
     )
@@ -102,6 +127,7 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
     ctx: &Context,
     widget_id: Id,
     allow_placing_below: bool,
+    interactive: bool,
     widget_rect: &Rect,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> R {
@@ -136,14 +162,18 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
         .pivot(pivot)
         .fixed_pos(anchor)
         .default_width(ctx.style().spacing.tooltip_width)
-        .interactable(false) // Only affects the actual area, i.e. clicking and dragging it. The content can still be interactive.
+        // Only affects the actual area, i.e. clicking and dragging it. The content can still be interactive.
+        // Interactive tooltips need it for e.g. scrolling, since that only happens in the top layer under the pointer.
+        .interactable(interactive)
         .show(ctx, |ui| {
-            // By default the text in tooltips aren't selectable.
-            // This means that most tooltips aren't interactable,
-            // which also mean they won't stick around so you can click them.
-            // Only tooltips that have actual interactive stuff (buttons, links, …)
-            // will stick around when you try to click them.
-            ui.style_mut().interaction.selectable_labels = false;
+            if !interactive {
+                // By default the text in tooltips aren't selectable.
+                // This means that most tooltips aren't interactable,
+                // which also mean they won't stick around so you can click them.
+                // Only tooltips that have actual interactive stuff (buttons, links, …)
+                // will stick around when you try to click them.
+                ui.style_mut().interaction.selectable_labels = false;
+            }
 
             Frame::popup(&ctx.style()).show_dyn(ui, add_contents).inner
         });
@@ -183,44 +213,82 @@ fn find_tooltip_position(
 ) -> (Align2, Pos2) {
     let spacing = 4.0;
 
+    if let Some((_, pivot, pos)) = find_popup_placement(
+        screen_rect,
+        widget_rect,
+        allow_placing_below,
+        tooltip_size,
+        spacing,
+    ) {
+        return (pivot, pos);
+    }
+
+    // It doesn't fit anywhere :(
+
+    // Just show it anyway:
+    (Align2::LEFT_TOP, screen_rect.left_top())
+}
+
+/// Where a popup was put relative to the widget it belongs to.
+///
+/// See [`popup_below_widget_auto`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PopupPlacement {
+    Below,
+    Above,
+    Right,
+    Left,
+}
+
+/// Find the first side of the widget where a popup of the given size fits:
+/// below (if allowed), above, to the right, or to the left.
+///
+/// Returns `(PLACEMENT, PIVOT, POS)` to mean: put the `PIVOT` corner of the popup at `POS`.
+fn find_popup_placement(
+    screen_rect: Rect,
+    widget_rect: Rect,
+    allow_placing_below: bool,
+    popup_size: Vec2,
+    spacing: f32,
+) -> Option<(PopupPlacement, Align2, Pos2)> {
     // Does it fit below?
-    if allow_placing_below
-        && widget_rect.bottom() + spacing + tooltip_size.y <= screen_rect.bottom()
+    if allow_placing_below && widget_rect.bottom() + spacing + popup_size.y <= screen_rect.bottom()
     {
-        return (
+        return Some((
+            PopupPlacement::Below,
             Align2::LEFT_TOP,
             widget_rect.left_bottom() + spacing * Vec2::DOWN,
-        );
+        ));
     }
 
     // Does it fit above?
-    if screen_rect.top() + tooltip_size.y + spacing <= widget_rect.top() {
-        return (
+    if screen_rect.top() + popup_size.y + spacing <= widget_rect.top() {
+        return Some((
+            PopupPlacement::Above,
             Align2::LEFT_BOTTOM,
             widget_rect.left_top() + spacing * Vec2::UP,
-        );
+        ));
     }
 
     // Does it fit to the right?
-    if widget_rect.right() + spacing + tooltip_size.x <= screen_rect.right() {
-        return (
+    if widget_rect.right() + spacing + popup_size.x <= screen_rect.right() {
+        return Some((
+            PopupPlacement::Right,
             Align2::LEFT_TOP,
             widget_rect.right_top() + spacing * Vec2::RIGHT,
-        );
+        ));
     }
 
     // Does it fit to the left?
-    if screen_rect.left() + tooltip_size.x + spacing <= widget_rect.left() {
-        return (
+    if screen_rect.left() + popup_size.x + spacing <= widget_rect.left() {
+        return Some((
+            PopupPlacement::Left,
             Align2::RIGHT_TOP,
             widget_rect.left_top() + spacing * Vec2::LEFT,
-        );
+        ));
     }
 
-    // It doesn't fit anywhere :(
-
-    // Just show it anyway:
-    (Align2::LEFT_TOP, screen_rect.left_top())
+    None
 }
 
 /// Show some text at the current pointer position (if any).
@@ -254,6 +322,8 @@ pub fn was_tooltip_open_last_frame(ctx: &Context, widget_id: Id) -> bool {
 }
 
 /// Helper for [`popup_above_or_below_widget`].
+///
+/// See also [`popup_below_widget_auto`], which moves the popup if it doesn't fit below the widget.
 pub fn popup_below_widget<R>(
     ui: &Ui,
     popup_id: Id,
@@ -300,41 +370,162 @@ pub fn popup_above_or_below_widget<R>(
     widget_response: &Response,
     above_or_below: AboveOrBelow,
     add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    popup_above_or_below_widget_with(
+        parent_ui,
+        popup_id,
+        widget_response,
+        above_or_below,
+        true,
+        add_contents,
+    )
+}
+
+/// Like [`popup_above_or_below_widget`], but if `close_on_click_inside` is `false`
+/// the popup only closes when clicking outside of it (or pressing Escape),
+/// e.g. for popups with text fields or checkboxes in them.
+pub(crate) fn popup_above_or_below_widget_with<R>(
+    parent_ui: &Ui,
+    popup_id: Id,
+    widget_response: &Response,
+    above_or_below: AboveOrBelow,
+    close_on_click_inside: bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
     if parent_ui.memory(|mem| mem.is_popup_open(popup_id)) {
         let (pos, pivot) = match above_or_below {
             AboveOrBelow::Above => (widget_response.rect.left_top(), Align2::LEFT_BOTTOM),
             AboveOrBelow::Below => (widget_response.rect.left_bottom(), Align2::LEFT_TOP),
         };
+        let inner = show_popup(
+            parent_ui,
+            popup_id,
+            widget_response,
+            pos,
+            pivot,
+            close_on_click_inside,
+            add_contents,
+        );
+        Some(inner.inner)
+    } else {
+        None
+    }
+}
 
-        let frame = Frame::popup(parent_ui.style());
-        let frame_margin = frame.total_margin();
-        let inner_width = widget_response.rect.width() - frame_margin.sum().x;
-
-        let inner = Area::new(popup_id)
-            .kind(UiKind::Popup)
-            .order(Order::Foreground)
-            .fixed_pos(pos)
-            .default_width(inner_width)
-            .pivot(pivot)
-            .show(parent_ui.ctx(), |ui| {
-                frame
-                    .show(ui, |ui| {
-                        ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
-                            ui.set_min_width(inner_width);
-                            add_contents(ui)
-                        })
-                        .inner
+/// What [`popup_below_widget_auto`] returns.
+pub struct PlacedPopupResponse<R> {
+    /// What the user closure returned.
+    pub inner: R,
+
+    /// The response of the popup area, with the rectangle of the popup.
+    pub response: Response,
+
+    /// Which side of the widget the popup ended up on,
+    /// e.g. for drawing an arrow between the widget and the popup.
+    pub placement: PopupPlacement,
+}
+
+/// Like [`popup_below_widget`], but shows the popup above or beside the widget
+/// if there isn't enough room below it, like tooltips do.
+///
+/// The popup is kept within the screen in any case.
+///
+/// Returns `None` if the popup is not open.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.button("Open popup");
+/// let popup_id = ui.make_persistent_id("my_unique_id");
+/// if response.clicked() {
+///     ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+/// }
+/// if let Some(popup) = egui::popup::popup_below_widget_auto(ui, popup_id, &response, |ui| {
+///     ui.label("Some more info, or things you can select:");
+/// }) {
+///     if popup.placement == egui::PopupPlacement::Above {
+///         // e.g. draw an arrow pointing down
+///     }
+/// }
+/// # });
+/// ```
+pub fn popup_below_widget_auto<R>(
+    parent_ui: &Ui,
+    popup_id: Id,
+    widget_response: &Response,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<PlacedPopupResponse<R>> {
+    if !parent_ui.memory(|mem| mem.is_popup_open(popup_id)) {
+        return None;
+    }
+
+    // Before the popup has been shown, we don't know how big it is, so assume it fits below.
+    let popup_size = AreaState::load(parent_ui.ctx(), popup_id).map_or(Vec2::ZERO, |s| s.size);
+    let widget_rect = widget_response.rect;
+    let (placement, pivot, pos) = find_popup_placement(
+        parent_ui.ctx().screen_rect(),
+        widget_rect,
+        true,
+        popup_size,
+        0.0,
+    )
+    .unwrap_or((
+        PopupPlacement::Below,
+        Align2::LEFT_TOP,
+        widget_rect.left_bottom(),
+    ));
+
+    let InnerResponse { inner, response } = show_popup(
+        parent_ui,
+        popup_id,
+        widget_response,
+        pos,
+        pivot,
+        true,
+        add_contents,
+    );
+    Some(PlacedPopupResponse {
+        inner,
+        response,
+        placement,
+    })
+}
+
+/// Show the popup of a widget with its `pivot` corner at `pos`.
+fn show_popup<R>(
+    parent_ui: &Ui,
+    popup_id: Id,
+    widget_response: &Response,
+    pos: Pos2,
+    pivot: Align2,
+    close_on_click_inside: bool,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    let frame = Frame::popup(parent_ui.style());
+    let frame_margin = frame.total_margin();
+    let inner_width = widget_response.rect.width() - frame_margin.sum().x;
+
+    let response = Area::new(popup_id)
+        .kind(UiKind::Popup)
+        .order(Order::Foreground)
+        .fixed_pos(pos)
+        .default_width(inner_width)
+        .pivot(pivot)
+        .show(parent_ui.ctx(), |ui| {
+            frame
+                .show(ui, |ui| {
+                    ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                        ui.set_min_width(inner_width);
+                        add_contents(ui)
                     })
                     .inner
-            })
-            .inner;
+                })
+                .inner
+        });
 
-        if parent_ui.input(|i| i.key_pressed(Key::Escape)) || widget_response.clicked_elsewhere() {
-            parent_ui.memory_mut(|mem| mem.close_popup());
-        }
-        Some(inner)
-    } else {
-        None
+    let clicked_elsewhere = widget_response.clicked_elsewhere()
+        && (close_on_click_inside || response.response.clicked_elsewhere());
+    if parent_ui.input(|i| i.key_pressed(Key::Escape)) || clicked_elsewhere {
+        parent_ui.memory_mut(|mem| mem.close_popup());
     }
+    response
 }
@@ -0,0 +1,267 @@
+//! A two-column inspector of labeled properties, see [`PropertyGrid`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// A two-column property inspector, with labels on the left and editors on the right.
+///
+/// The editors line up, also across collapsible groups (see [`PropertyGridBuilder::group`]).
+/// Properties can have a reset button ([`PropertyGridBuilder::property_with_reset`]),
+/// and can edit the same value in many objects at once ([`PropertyGridBuilder::property_mixed`]).
+///
+/// With [`Self::filter`] only the properties with labels containing the filter text are shown,
+/// without their groups.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut search = String::new();
+/// # let mut name = String::new();
+/// # let mut position = egui::Vec2::ZERO;
+/// # let mut scale = 1.0;
+/// ui.text_edit_singleline(&mut search);
+/// egui::PropertyGrid::new("inspector")
+///     .filter(&search)
+///     .show(ui, |props| {
+///         props.property("Name", |ui| ui.text_edit_singleline(&mut name));
+///         props.group("Transform", |props| {
+///             props.property("Position", |ui| ui.vec2_edit(&mut position));
+///             props.property_with_reset("Scale", &mut scale, 1.0, |ui, scale| {
+///                 ui.add(egui::DragValue::new(scale).speed(0.01))
+///             });
+///         });
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct PropertyGrid {
+    id_source: Id,
+    label_width: Option<f32>,
+    filter: String,
+}
+
+impl PropertyGrid {
+    /// The id source must be unique among the siblings of the grid.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            label_width: None,
+            filter: String::new(),
+        }
+    }
+
+    /// The width of the label column.
+    ///
+    /// Default: 40% of the available width.
+    #[inline]
+    pub fn label_width(mut self, label_width: f32) -> Self {
+        self.label_width = Some(label_width);
+        self
+    }
+
+    /// Only show properties with labels containing this text, ignoring case.
+    ///
+    /// All the properties of a group with a matching heading are shown.
+    /// Default: show all properties.
+    #[inline]
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = filter.trim().to_lowercase();
+        self
+    }
+
+    /// Show the properties, added with the methods of [`PropertyGridBuilder`].
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_properties: impl FnOnce(&mut PropertyGridBuilder<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.make_persistent_id(self.id_source);
+        let label_width = self
+            .label_width
+            .unwrap_or_else(|| 0.4 * ui.available_width());
+        let editor_x = ui.cursor().left() + label_width;
+
+        ui.vertical(|ui| {
+            let mut builder = PropertyGridBuilder {
+                ui,
+                id,
+                editor_x,
+                filter: self.filter,
+                show_all: false,
+            };
+            add_properties(&mut builder)
+        })
+    }
+}
+
+/// Adds the properties of a [`PropertyGrid`].
+pub struct PropertyGridBuilder<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+
+    /// Where the editor column starts.
+    editor_x: f32,
+
+    /// Lowercase.
+    filter: String,
+
+    /// The heading of the current group matches the filter.
+    show_all: bool,
+}
+
+impl<'a> PropertyGridBuilder<'a> {
+    fn is_filtering(&self) -> bool {
+        !self.filter.is_empty()
+    }
+
+    fn matches(&self, text: &str) -> bool {
+        !self.is_filtering() || self.show_all || text.to_lowercase().contains(&self.filter)
+    }
+
+    /// A row with the label to the left, and the editor in the editor column.
+    fn row(
+        &mut self,
+        label: WidgetText,
+        add_editor: impl FnOnce(&mut Ui) -> Response,
+    ) -> Option<Response> {
+        if !self.matches(label.text()) {
+            return None;
+        }
+        let editor_x = self.editor_x;
+        let inner = self.ui.horizontal(|ui| {
+            let item_spacing = ui.spacing().item_spacing.x;
+            let label_width = (editor_x - ui.cursor().left() - item_spacing).at_least(0.0);
+            let label_size = vec2(label_width, ui.spacing().interact_size.y);
+            ui.allocate_ui_with_layout(label_size, Layout::left_to_right(Align::Center), |ui| {
+                ui.set_min_size(label_size);
+                ui.add(Label::new(label).truncate());
+            });
+            add_editor(ui)
+        });
+        Some(inner.inner)
+    }
+
+    /// Add a property.
+    ///
+    /// Returns the response of the editor, or `None` if the property is hidden by the filter.
+    pub fn property(
+        &mut self,
+        label: impl Into<WidgetText>,
+        add_editor: impl FnOnce(&mut Ui) -> Response,
+    ) -> Option<Response> {
+        self.row(label.into(), add_editor)
+    }
+
+    /// Add a property with a button that resets it to `default`.
+    ///
+    /// The button is only enabled if the value differs from the default.
+    /// The returned response is marked as changed when the button is clicked.
+    pub fn property_with_reset<T: PartialEq>(
+        &mut self,
+        label: impl Into<WidgetText>,
+        value: &mut T,
+        default: T,
+        add_editor: impl FnOnce(&mut Ui, &mut T) -> Response,
+    ) -> Option<Response> {
+        self.row(label.into(), |ui| {
+            let mut response = add_editor(ui, value);
+            let reset = ui
+                .add_enabled(*value != default, Button::new("⟲").small())
+                .on_hover_text("Reset to default");
+            if reset.clicked() {
+                *value = default;
+                response.mark_changed();
+            }
+            response
+        })
+    }
+
+    /// Add a property that edits the same value in many objects at once,
+    /// e.g. when editing a multi-selection.
+    ///
+    /// If the values differ, a `—` is shown instead of the editor.
+    /// Clicking it shows the editor for the first value,
+    /// and any change is then written to all of them.
+    pub fn property_mixed<'v, T: 'v + Clone + PartialEq>(
+        &mut self,
+        label: impl Into<WidgetText>,
+        values: impl IntoIterator<Item = &'v mut T>,
+        add_editor: impl FnOnce(&mut Ui, &mut T) -> Response,
+    ) -> Option<Response> {
+        let mut values: Vec<&mut T> = values.into_iter().collect();
+        let label = label.into();
+        let mixed_id = self.id.with(label.text());
+
+        self.row(label, |ui| {
+            let Some(first) = values.first() else {
+                return ui.weak("—");
+            };
+            let mut value = (*first).clone();
+            let is_mixed = values.iter().any(|other| **other != value);
+            let editing = ui.data(|d| d.get_temp::<bool>(mixed_id)).unwrap_or(false);
+
+            if is_mixed && !editing {
+                let response = ui
+                    .add(Button::new("—").frame(false))
+                    .on_hover_text("Multiple values. Click to edit them all together.");
+                if response.clicked() {
+                    ui.data_mut(|d| d.insert_temp(mixed_id, true));
+                }
+                return response;
+            }
+
+            let response = add_editor(ui, &mut value);
+            if response.changed() {
+                for other in &mut values {
+                    **other = value.clone();
+                }
+            }
+            if !is_mixed {
+                ui.data_mut(|d| d.remove::<bool>(mixed_id));
+            }
+            response
+        })
+    }
+
+    /// Add a collapsible group of properties.
+    ///
+    /// While filtering, the heading is hidden and the matching properties are shown directly.
+    pub fn group<R>(
+        &mut self,
+        heading: impl Into<WidgetText>,
+        add_properties: impl FnOnce(&mut PropertyGridBuilder<'_>) -> R,
+    ) -> Option<R> {
+        let heading = heading.into();
+        let group_id = self.id.with(heading.text());
+
+        if self.is_filtering() {
+            let show_all = self.show_all;
+            self.show_all |= heading.text().to_lowercase().contains(&self.filter);
+            let inner = add_properties(self);
+            self.show_all = show_all;
+            return Some(inner);
+        }
+
+        let editor_x = self.editor_x;
+        CollapsingHeader::new(heading)
+            .id_source(group_id)
+            .default_open(true)
+            .show(self.ui, |ui| {
+                let mut builder = PropertyGridBuilder {
+                    ui,
+                    id: group_id,
+                    editor_x,
+                    filter: String::new(),
+                    show_all: false,
+                };
+                add_properties(&mut builder)
+            })
+            .body_returned
+    }
+
+    /// The [`Ui`] the properties are added to, e.g. for adding a separator between them.
+    #[inline]
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+}
@@ -42,6 +42,31 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// When did this scroll area last use some of the scroll input?
+    ///
+    /// Used for [`ScrollChaining::AfterDelay`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_scroll_time: f64,
+
+    /// How far the content is pulled past the end, for the rubber-band effect.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll: Vec2,
+
+    /// The [`Ui::scroll_anchor`] at the top of the viewport, and how far down the content it was,
+    /// for [`ScrollArea::scroll_anchoring`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    scroll_anchor: Option<(Id, f32)>,
+
+    /// The offset last frame, to see if we have been scrolled.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    prev_offset: Vec2,
+
+    /// When did the offset last change?
+    ///
+    /// Used for [`crate::style::ScrollStyle::hide_when_idle`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_moved_time: f64,
 }
 
 impl Default for State {
@@ -56,6 +81,11 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            last_scroll_time: f64::NEG_INFINITY,
+            overscroll: Vec2::ZERO,
+            scroll_anchor: None,
+            prev_offset: Vec2::ZERO,
+            last_moved_time: f64::NEG_INFINITY,
         }
     }
 }
@@ -130,6 +160,133 @@ impl ScrollBarVisibility {
     ];
 }
 
+/// What a [`ScrollArea`] does with the scrolling it can't use because it is at its end.
+///
+/// This decides when scrolling inside a [`ScrollArea`] moves the enclosing [`ScrollArea`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ScrollChaining {
+    /// Never pass on scrolling to the enclosing scroll area while hovering this one,
+    /// unless this one has nothing to scroll.
+    Never,
+
+    /// Pass on any left-over scrolling to the enclosing scroll area right away.
+    Immediate,
+
+    /// Pass on left-over scrolling once this scroll area hasn't been scrolled for this many seconds.
+    ///
+    /// This way the scroll of e.g. a mouse wheel stops when it hits the end,
+    /// and scrolling again after a short pause moves the enclosing scroll area.
+    AfterDelay(f32),
+}
+
+impl Default for ScrollChaining {
+    /// [`Self::Immediate`], like egui has always done.
+    #[inline]
+    fn default() -> Self {
+        Self::Immediate
+    }
+}
+
+/// A header added with [`Ui::sticky_header`],
+/// waiting for its [`ScrollArea`] to decide where to paint it.
+#[derive(Clone)]
+struct StickyHeader {
+    layer_id: LayerId,
+
+    /// The range of shapes in the [`PaintList`] of the layer that make up the header.
+    shapes: std::ops::Range<usize>,
+
+    /// The range of widgets in the [`WidgetRects`] of the layer that are in the header.
+    widgets: std::ops::Range<usize>,
+
+    /// Where the header is in the scrolled content.
+    rect: Rect,
+}
+
+fn sticky_headers_id(content_ui_id: Id) -> Id {
+    content_ui_id.with("__sticky_headers")
+}
+
+/// See [`Ui::sticky_header`].
+pub(crate) fn sticky_header<'c, R>(
+    ui: &mut Ui,
+    add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+) -> InnerResponse<R> {
+    let content_ui_id = ui
+        .stack()
+        .iter()
+        .find(|frame| frame.kind() == Some(UiKind::ScrollArea))
+        .map(|frame| frame.id);
+    let Some(content_ui_id) = content_ui_id else {
+        return ui.scope(add_contents);
+    };
+
+    // The header needs a background, so it can cover the content scrolling under it:
+    let fill = ui
+        .stack()
+        .iter()
+        .map(|frame| frame.frame().fill)
+        .find(|fill| *fill != Color32::TRANSPARENT)
+        .unwrap_or(ui.visuals().panel_fill);
+    let background = ui.painter().add(Shape::Noop);
+    let layer_id = ui.layer_id();
+    let widgets_start = ui.ctx().widgets_this_frame(|w| w.layer_len(layer_id));
+
+    let InnerResponse { inner, response } = ui.scope(|ui| {
+        // Widgets don't paint themselves when they are scrolled out of view,
+        // but the header should be painted even then.
+        // The real clip rectangle is set by the scroll area once it knows where the header goes.
+        ui.set_clip_rect(Rect::EVERYTHING);
+        ui.set_min_width(ui.available_width());
+        add_contents(ui)
+    });
+    ui.painter()
+        .set(background, Shape::rect_filled(response.rect, 0.0, fill));
+
+    let shapes_end = ui.ctx().graphics_mut(|g| g.entry(layer_id).len());
+    let widgets_end = ui.ctx().widgets_this_frame(|w| w.layer_len(layer_id));
+    ui.data_mut(|d| {
+        d.get_temp_mut_or_default::<Vec<StickyHeader>>(sticky_headers_id(content_ui_id))
+            .push(StickyHeader {
+                layer_id,
+                shapes: background.0..shapes_end,
+                widgets: widgets_start..widgets_end,
+                rect: response.rect,
+            });
+    });
+
+    InnerResponse::new(inner, response)
+}
+
+fn scroll_anchors_id(content_ui_id: Id) -> Id {
+    content_ui_id.with("__scroll_anchors")
+}
+
+/// See [`Ui::scroll_anchor`].
+pub(crate) fn scroll_anchor<'c, R>(
+    ui: &mut Ui,
+    id_source: Id,
+    add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+) -> InnerResponse<R> {
+    let anchor_id = ui.make_persistent_id(id_source);
+    let InnerResponse { inner, response } = ui.push_id(id_source, add_contents);
+
+    let content_ui_id = ui
+        .stack()
+        .iter()
+        .find(|frame| frame.kind() == Some(UiKind::ScrollArea))
+        .map(|frame| frame.id);
+    if let Some(content_ui_id) = content_ui_id {
+        ui.data_mut(|d| {
+            d.get_temp_mut_or_default::<Vec<(Id, Rect)>>(scroll_anchors_id(content_ui_id))
+                .push((anchor_id, response.rect));
+        });
+    }
+
+    InnerResponse::new(inner, response)
+}
+
 /// Add vertical and/or horizontal scrolling to a contained [`Ui`].
 ///
 /// By default, scroll bars only show up when needed, i.e. when the contents
@@ -183,6 +340,12 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    scroll_chaining: ScrollChaining,
+    overscroll: bool,
+    kinetic_friction: f32,
+    scroll_anchoring: bool,
+    sync_id: Option<Id>,
 }
 
 impl ScrollArea {
@@ -227,6 +390,11 @@ impl ScrollArea {
             drag_to_scroll: true,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            scroll_chaining: Default::default(),
+            overscroll: false,
+            kinetic_friction: 1000.0,
+            scroll_anchoring: false,
+            sync_id: None,
         }
     }
 
@@ -410,6 +578,99 @@ impl ScrollArea {
         self
     }
 
+    /// What to do with scrolling that this scroll area can't use because it is at its end,
+    /// e.g. pass it on to an enclosing [`ScrollArea`].
+    ///
+    /// Default: [`ScrollChaining::Immediate`].
+    #[inline]
+    pub fn scroll_chaining(mut self, scroll_chaining: ScrollChaining) -> Self {
+        self.scroll_chaining = scroll_chaining;
+        self
+    }
+
+    /// Pull the content past the end when scrolling further, and let it spring back (a rubber-band effect).
+    ///
+    /// Only scrolling that isn't passed on to an enclosing scroll area does this,
+    /// see [`Self::scroll_chaining`].
+    ///
+    /// Flinging the content into the end also makes it bounce back,
+    /// instead of stopping dead.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    /// How quickly the scrolling slows down after the content has been flung,
+    /// i.e. dragged and let go of while moving (mostly on touch screens).
+    ///
+    /// In points per second squared. Use `f32::INFINITY` to stop as soon as the content is let go of.
+    ///
+    /// Default: `1000.0`.
+    #[inline]
+    pub fn kinetic_friction(mut self, kinetic_friction: f32) -> Self {
+        self.kinetic_friction = kinetic_friction;
+        self
+    }
+
+    /// Keep the content in the viewport from jumping when the content above it changes size,
+    /// e.g. when new messages are loaded above the ones the user is reading.
+    ///
+    /// Mark the items of the content with [`Ui::scroll_anchor`], giving each a stable id.
+    /// The scroll area remembers which of them is at the top of the viewport,
+    /// and changes the vertical offset to keep that one in the same place,
+    /// in the same frame as the content above it changed.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let messages = [(1, "Hello"), (2, "World")];
+    /// egui::ScrollArea::vertical().scroll_anchoring(true).show(ui, |ui| {
+    ///     for (message_id, text) in messages {
+    ///         ui.scroll_anchor(message_id, |ui| ui.label(text));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn scroll_anchoring(mut self, scroll_anchoring: bool) -> Self {
+        self.scroll_anchoring = scroll_anchoring;
+        self
+    }
+
+    /// Scroll together with all other scroll areas with the same `sync_id`,
+    /// e.g. the two sides of a diff view.
+    ///
+    /// Only the directions this scroll area can scroll in are synced,
+    /// so a vertical scroll area can follow the vertical scrolling of a [`Self::both`] one,
+    /// e.g. for the frozen columns of a table.
+    ///
+    /// The `sync_id` is global, so it doesn't need to be unique among siblings,
+    /// but it should be different from the `sync_id` of unrelated scroll areas.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.columns(2, |columns| {
+    ///     egui::ScrollArea::vertical()
+    ///         .id_source("old")
+    ///         .sync_id("diff")
+    ///         .show(&mut columns[0], |ui| ui.label("Old text"));
+    ///     egui::ScrollArea::vertical()
+    ///         .id_source("new")
+    ///         .sync_id("diff")
+    ///         .show(&mut columns[1], |ui| ui.label("New text"));
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn sync_id(mut self, sync_id: impl std::hash::Hash) -> Self {
+        self.sync_id = Some(Id::new(sync_id));
+        self
+    }
+
     /// Is any scrolling enabled?
     pub(crate) fn is_any_scroll_enabled(&self) -> bool {
         self.scroll_enabled[0] || self.scroll_enabled[1]
@@ -477,6 +738,17 @@ struct Prepared {
     scrolling_enabled: bool,
     stick_to_end: Vec2b,
     animated: bool,
+    scroll_chaining: ScrollChaining,
+    overscroll: bool,
+    scroll_anchoring: bool,
+
+    /// The `sync_id`, and our offset after syncing with it.
+    sync: Option<(Id, Vec2)>,
+
+    /// Where the shapes and widgets of the content start in the [`PaintList`] and [`WidgetRects`]
+    /// of the layer, so that they can be moved after the content is added.
+    shapes_start: usize,
+    widgets_start: usize,
 }
 
 impl ScrollArea {
@@ -494,6 +766,11 @@ impl ScrollArea {
             drag_to_scroll,
             stick_to_end,
             animated,
+            scroll_chaining,
+            overscroll,
+            kinetic_friction,
+            scroll_anchoring,
+            sync_id,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -508,6 +785,16 @@ impl ScrollArea {
         );
         let mut state = State::load(&ctx, id).unwrap_or_default();
 
+        if let Some(synced_offset) =
+            sync_id.and_then(|sync_id| ctx.data(|d| d.get_temp::<Vec2>(sync_id)))
+        {
+            for d in 0..2 {
+                if scroll_enabled[d] {
+                    state.offset[d] = synced_offset[d];
+                }
+            }
+        }
+
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
 
@@ -559,7 +846,23 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
+        let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+
+        // Spring back from overscroll:
+        for d in 0..2 {
+            if state.overscroll[d] != 0.0 {
+                state.overscroll[d] *= (1.0 - 12.0 * dt).at_least(0.0);
+                if state.overscroll[d].abs() < 0.5 {
+                    state.overscroll[d] = 0.0;
+                }
+                ctx.request_repaint();
+            }
+        }
+
+        let content_max_rect = Rect::from_min_size(
+            inner_rect.min - state.offset + state.overscroll,
+            content_max_size,
+        );
         let mut content_ui = ui.child_ui(
             content_max_rect,
             *ui.layout(),
@@ -587,7 +890,6 @@ impl ScrollArea {
         }
 
         let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
-        let dt = ui.input(|i| i.stable_dt).at_most(0.1);
 
         if (scrolling_enabled && drag_to_scroll)
             && (state.content_is_too_large[0] || state.content_is_too_large[1])
@@ -599,11 +901,18 @@ impl ScrollArea {
                 .interact_rect
                 .map(|rect| ui.interact(rect, id.with("area"), Sense::drag()));
 
-            if content_response_option.map(|response| response.dragged()) == Some(true) {
+            let dragged = content_response_option
+                .as_ref()
+                .map_or(false, Response::dragged);
+            let drag_stopped = content_response_option
+                .as_ref()
+                .map_or(false, Response::drag_stopped);
+            if dragged || drag_stopped {
                 for d in 0..2 {
                     if scroll_enabled[d] {
                         ui.input(|input| {
                             state.offset[d] -= input.pointer.delta()[d];
+                            // The velocity from the last drag frames is what the content is flung with:
                             state.vel[d] = input.pointer.velocity()[d];
                         });
                         state.scroll_stuck_to_end[d] = false;
@@ -616,9 +925,8 @@ impl ScrollArea {
                 for d in 0..2 {
                     // Kinetic scrolling
                     let stop_speed = 20.0; // Pixels per second.
-                    let friction_coeff = 1000.0; // Pixels per second squared.
 
-                    let friction = friction_coeff * dt;
+                    let friction = kinetic_friction * dt;
                     if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
                         state.vel[d] = 0.0;
                     } else {
@@ -677,6 +985,12 @@ impl ScrollArea {
             scrolling_enabled,
             stick_to_end,
             animated,
+            scroll_chaining,
+            overscroll,
+            scroll_anchoring,
+            sync: sync_id.map(|sync_id| (sync_id, state.offset)),
+            shapes_start: ctx.graphics_mut(|g| g.entry(ui.layer_id()).len()),
+            widgets_start: ctx.widgets_this_frame(|w| w.layer_len(ui.layer_id())),
         }
     }
 
@@ -740,6 +1054,98 @@ impl ScrollArea {
         })
     }
 
+    /// Like [`Self::show_rows`], but for rows of different heights.
+    ///
+    /// `row_height_sans_spacing` is called with the index of each row,
+    /// so it should be cheap (e.g. a lookup in a precomputed list).
+    /// Only the visible rows are handed to `add_contents`,
+    /// but the size of the scroll bar is correct for the whole list.
+    ///
+    /// If you don't know the heights up front, use [`Self::show_measured_rows`] instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let row_height = |row: usize| if row % 10 == 0 { 40.0 } else { 18.0 };
+    /// let total_rows = 10_000;
+    /// egui::ScrollArea::vertical().show_rows_with_heights(ui, row_height, total_rows, |ui, row_range| {
+    ///     for row in row_range {
+    ///         let size = egui::vec2(ui.available_width(), row_height(row));
+    ///         ui.add_sized(size, egui::Label::new(format!("Row {row}")));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn show_rows_with_heights<R>(
+        self,
+        ui: &mut Ui,
+        row_height_sans_spacing: impl Fn(usize) -> f32,
+        total_rows: usize,
+        add_contents: impl FnOnce(&mut Ui, std::ops::Range<usize>) -> R,
+    ) -> ScrollAreaOutput<R> {
+        let spacing = ui.spacing().item_spacing.y;
+        let row_tops = row_tops((0..total_rows).map(row_height_sans_spacing), spacing);
+        self.show_viewport(ui, |ui, viewport| {
+            show_visible_rows(ui, viewport, &row_tops, spacing, add_contents)
+        })
+    }
+
+    /// Efficiently show a large number of rows of different heights,
+    /// without knowing the heights up front.
+    ///
+    /// `add_row` is only called for the visible rows.
+    /// Each row is measured the first time it is shown and the height is remembered,
+    /// while `estimated_row_height` is used for the rows that have not been shown yet.
+    /// The scroll bar gets more accurate as more rows are shown.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let total_rows = 10_000;
+    /// egui::ScrollArea::vertical().show_measured_rows(ui, 18.0, total_rows, |ui, row| {
+    ///     if row % 10 == 0 {
+    ///         ui.heading(format!("Section {}", row / 10));
+    ///     } else {
+    ///         ui.label(format!("Row {row}"));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn show_measured_rows(
+        self,
+        ui: &mut Ui,
+        estimated_row_height: f32,
+        total_rows: usize,
+        mut add_row: impl FnMut(&mut Ui, usize),
+    ) -> ScrollAreaOutput<()> {
+        let spacing = ui.spacing().item_spacing.y;
+        self.show_viewport(ui, |ui, viewport| {
+            let heights_id = ui.id().with("__row_heights");
+            let mut heights: Vec<Option<f32>> =
+                ui.data_mut(|d| d.get_temp(heights_id)).unwrap_or_default();
+            heights.resize(total_rows, None);
+
+            let row_tops = row_tops(
+                heights.iter().map(|h| h.unwrap_or(estimated_row_height)),
+                spacing,
+            );
+            let mut remeasured = false;
+            show_visible_rows(ui, viewport, &row_tops, spacing, |ui, row_range| {
+                for row in row_range {
+                    let height = ui.scope(|ui| add_row(ui, row)).response.rect.height();
+                    if heights[row] != Some(height) {
+                        heights[row] = Some(height);
+                        remeasured = true;
+                    }
+                }
+            });
+
+            if remeasured {
+                // The rows below may have moved:
+                ui.ctx().request_repaint();
+            }
+            ui.data_mut(|d| d.insert_temp(heights_id, heights));
+        })
+    }
+
     /// This can be used to only paint the visible part of the contents.
     ///
     /// `add_contents` is given the viewport rectangle, which is the relative view of the content.
@@ -772,6 +1178,48 @@ impl ScrollArea {
     }
 }
 
+/// The top of each row relative to the top of the content, followed by the bottom of the last row plus `spacing`.
+fn row_tops(heights: impl Iterator<Item = f32>, spacing: f32) -> Vec<f32> {
+    let mut tops = vec![0.0];
+    let mut y = 0.0;
+    for height in heights {
+        y += height + spacing;
+        tops.push(y);
+    }
+    tops
+}
+
+/// Show the rows that are visible in the `viewport`, given their tops from [`row_tops`].
+fn show_visible_rows<R>(
+    ui: &mut Ui,
+    viewport: Rect,
+    row_tops: &[f32],
+    spacing: f32,
+    add_contents: impl FnOnce(&mut Ui, std::ops::Range<usize>) -> R,
+) -> R {
+    let total_rows = row_tops.len() - 1;
+    let total_height = row_tops[total_rows] - spacing;
+    ui.set_height(total_height.at_least(0.0));
+
+    // The last row that starts above the viewport, up to the first row that starts below it:
+    let min_row = row_tops[..total_rows]
+        .partition_point(|&top| top <= viewport.min.y)
+        .saturating_sub(1);
+    let max_row = row_tops[..total_rows]
+        .partition_point(|&top| top < viewport.max.y)
+        .max(min_row);
+
+    let y_min = ui.max_rect().top() + row_tops[min_row];
+    let y_max = ui.max_rect().top() + row_tops[max_row];
+    let rect = Rect::from_x_y_ranges(ui.max_rect().x_range(), y_min..=y_max);
+
+    ui.allocate_ui_at_rect(rect, |viewport_ui| {
+        viewport_ui.skip_ahead_auto_ids(min_row); // Make sure we get consistent IDs.
+        add_contents(viewport_ui, min_row..max_row)
+    })
+    .inner
+}
+
 impl Prepared {
     /// Returns content size and state
     fn end(self, ui: &mut Ui) -> (Vec2, State) {
@@ -789,10 +1237,80 @@ impl Prepared {
             scrolling_enabled,
             stick_to_end,
             animated,
+            scroll_chaining,
+            overscroll,
+            scroll_anchoring,
+            sync,
+            shapes_start,
+            widgets_start,
         } = self;
 
         let content_size = content_ui.min_size();
 
+        // The anchors in the content, and how far down the content they are:
+        let anchors = content_ui
+            .data_mut(|d| d.remove_temp::<Vec<(Id, Rect)>>(scroll_anchors_id(content_ui.id())))
+            .unwrap_or_default();
+        let anchor_candidates: Vec<(Id, f32)> = if scroll_anchoring {
+            let content_top = content_ui.max_rect().top();
+            anchors
+                .into_iter()
+                .map(|(id, rect)| (id, rect.top() - content_top))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // Keep the anchor where it was, even if the content above it changed size.
+        // The content is already laid out, so we move it to where it should be right away:
+        let mut anchor_moved = 0.0;
+        if let Some((anchor_id, anchor_y)) = state.scroll_anchor {
+            if let Some((_, y)) = anchor_candidates.iter().find(|(id, _)| *id == anchor_id) {
+                anchor_moved = y - anchor_y;
+            }
+        }
+        if anchor_moved != 0.0 {
+            state.offset.y += anchor_moved;
+            let layer_id = content_ui.layer_id();
+            let delta = vec2(0.0, -anchor_moved);
+            content_ui.ctx().graphics_mut(|g| {
+                let list = g.entry(layer_id);
+                list.translate(shapes_start..list.len(), delta);
+            });
+            content_ui.ctx().widgets_this_frame_mut(|w| {
+                let end = w.layer_len(layer_id);
+                w.translate(layer_id, widgets_start..end, delta, content_ui.clip_rect());
+            });
+            ui.ctx().request_repaint();
+        }
+
+        // Pin the sticky headers to the top of the viewport, each one pushed out by the next:
+        let sticky_headers = content_ui
+            .data_mut(|d| d.remove_temp::<Vec<StickyHeader>>(sticky_headers_id(content_ui.id())))
+            .unwrap_or_default();
+        for (i, header) in sticky_headers.iter().enumerate() {
+            let top = header.rect.top() - anchor_moved;
+            let mut offset = (inner_rect.top() - top).at_least(0.0);
+            if let Some(next) = sticky_headers.get(i + 1) {
+                offset = offset.min(next.rect.top() - header.rect.bottom());
+            }
+            content_ui.ctx().graphics_mut(|g| {
+                g.entry(header.layer_id).move_to_top(
+                    header.shapes.clone(),
+                    vec2(0.0, offset),
+                    content_ui.clip_rect(),
+                );
+            });
+            content_ui.ctx().widgets_this_frame_mut(|w| {
+                w.move_to_top(
+                    header.layer_id,
+                    header.widgets.clone(),
+                    vec2(0.0, offset),
+                    content_ui.clip_rect(),
+                );
+            });
+        }
+
         let scroll_delta = content_ui
             .ctx()
             .frame_state_mut(|state| std::mem::take(&mut state.scroll_delta));
@@ -903,24 +1421,59 @@ impl Prepared {
                         }
                     });
 
-                    let scrolling_up = state.offset[d] > 0.0 && scroll_delta > 0.0;
-                    let scrolling_down = state.offset[d] < max_offset[d] && scroll_delta < 0.0;
+                    if scroll_delta == 0.0 {
+                        continue;
+                    }
 
-                    if scrolling_up || scrolling_down {
-                        state.offset[d] -= scroll_delta;
+                    // Positive scroll delta means scrolling towards the start.
+                    let room = if 0.0 < scroll_delta {
+                        state.offset[d]
+                    } else {
+                        max_offset[d] - state.offset[d]
+                    };
+                    let used = scroll_delta.signum() * scroll_delta.abs().min(room.at_least(0.0));
+                    let left_over = scroll_delta - used;
+
+                    let now = ui.input(|i| i.time);
+                    let keep_left_over = content_is_too_large[d]
+                        && match scroll_chaining {
+                            ScrollChaining::Never => true,
+                            ScrollChaining::Immediate => false,
+                            ScrollChaining::AfterDelay(delay) => {
+                                now - state.last_scroll_time < delay as f64
+                            }
+                        };
+                    let consumed = if keep_left_over { scroll_delta } else { used };
 
-                        // Clear scroll delta so no parent scroll will use it:
+                    if used != 0.0 {
+                        state.offset[d] -= used;
+                        state.scroll_stuck_to_end[d] = false;
+                        state.offset_target[d] = None;
+                    }
+
+                    if overscroll && keep_left_over && left_over != 0.0 {
+                        // The further out we are, the harder it is to pull:
+                        let max_overscroll = 0.2 * inner_rect.size()[d];
+                        let resistance = 1.0 - state.overscroll[d].abs() / max_overscroll;
+                        state.overscroll[d] = (state.overscroll[d]
+                            + 0.5 * left_over * resistance.at_least(0.0))
+                        .clamp(-max_overscroll, max_overscroll);
+                        ui.ctx().request_repaint();
+                    }
+
+                    if consumed != 0.0 {
+                        state.last_scroll_time = now;
+
+                        // Only leave what we didn't use for any parent scroll:
                         ui.ctx().input_mut(|input| {
                             if always_scroll_enabled_direction {
-                                input.smooth_scroll_delta[0] = 0.0;
-                                input.smooth_scroll_delta[1] = 0.0;
+                                // The delta came from both directions, so leave the same part of each:
+                                let left = (scroll_delta - consumed) / scroll_delta;
+                                input.smooth_scroll_delta *= left;
                             } else {
-                                input.smooth_scroll_delta[d] = 0.0;
+                                input.smooth_scroll_delta[d] -= consumed;
                             }
                         });
-
-                        state.scroll_stuck_to_end[d] = false;
-                        state.offset_target[d] = None;
                     }
                 }
             }
@@ -942,6 +1495,24 @@ impl Prepared {
 
         let scroll_style = ui.spacing().scroll;
 
+        // Are we being scrolled (or were we, very recently)?
+        let now = ui.input(|i| i.time);
+        if state.offset != state.prev_offset {
+            state.prev_offset = state.offset;
+            state.last_moved_time = now;
+        }
+        let is_active = if scroll_style.hide_when_idle {
+            const SHOW_AFTER_SCROLL: f64 = 1.0;
+            let time_left = SHOW_AFTER_SCROLL - (now - state.last_moved_time);
+            if 0.0 < time_left {
+                ui.ctx()
+                    .request_repaint_after(std::time::Duration::from_secs_f64(time_left));
+            }
+            0.0 < time_left
+        } else {
+            is_hovering_outer_rect
+        };
+
         // Paint the bars:
         for d in 0..2 {
             // maybe force increase in offset to keep scroll stuck to end position
@@ -1075,6 +1646,13 @@ impl Prepared {
             state.offset[d] = state.offset[d].min(max_offset[d]);
 
             if state.offset[d] != unbounded_offset {
+                if overscroll && state.vel[d] != 0.0 && content_is_too_large[d] {
+                    // Bounce off the end:
+                    let max_overscroll = 0.2 * inner_rect.size()[d];
+                    state.overscroll[d] = (state.overscroll[d] + 0.05 * state.vel[d])
+                        .clamp(-max_overscroll, max_overscroll);
+                    ui.ctx().request_repaint();
+                }
                 state.vel[d] = 0.0;
             }
 
@@ -1131,14 +1709,14 @@ impl Prepared {
                     if response.hovered() || response.dragged() {
                         scroll_style.interact_handle_opacity
                     } else {
-                        let is_hovering_outer_rect_t = ui.ctx().animate_bool_responsive(
+                        let is_active_t = ui.ctx().animate_bool_responsive(
                             id.with((d, "is_hovering_outer_rect")),
-                            is_hovering_outer_rect,
+                            is_active,
                         );
                         lerp(
                             scroll_style.dormant_handle_opacity
                                 ..=scroll_style.active_handle_opacity,
-                            is_hovering_outer_rect_t,
+                            is_active_t,
                         )
                     }
                 } else {
@@ -1148,7 +1726,7 @@ impl Prepared {
                 let background_opacity = if scroll_style.floating {
                     if response.hovered() || response.dragged() {
                         scroll_style.interact_background_opacity
-                    } else if is_hovering_outer_rect {
+                    } else if is_active {
                         scroll_style.active_background_opacity
                     } else {
                         scroll_style.dormant_background_opacity
@@ -1163,10 +1741,12 @@ impl Prepared {
                     visuals.bg_fill
                 };
 
+                let rounding = scroll_style.rounding.unwrap_or(visuals.rounding);
+
                 // Background:
                 ui.painter().add(epaint::Shape::rect_filled(
                     outer_scroll_rect,
-                    visuals.rounding,
+                    rounding,
                     ui.visuals()
                         .extreme_bg_color
                         .gamma_multiply(background_opacity),
@@ -1175,7 +1755,7 @@ impl Prepared {
                 // Handle:
                 ui.painter().add(epaint::Shape::rect_filled(
                     handle_rect,
-                    visuals.rounding,
+                    rounding,
                     handle_color.gamma_multiply(handle_opacity),
                 ));
             }
@@ -1207,8 +1787,322 @@ impl Prepared {
         state.content_is_too_large = content_is_too_large;
         state.interact_rect = Some(inner_rect);
 
+        // The new anchor is the first one at the top of the viewport:
+        state.scroll_anchor = anchor_candidates
+            .into_iter()
+            .filter(|(_, y)| state.offset.y <= *y)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        if let Some((sync_id, synced_offset)) = sync {
+            if state.offset != synced_offset {
+                // We were scrolled, so the others should follow:
+                ui.ctx().data_mut(|data| {
+                    let shared = data.get_temp_mut_or_default::<Vec2>(sync_id);
+                    for d in 0..2 {
+                        if scroll_enabled[d] {
+                            shared[d] = state.offset[d];
+                        }
+                    }
+                });
+                ui.ctx().request_repaint();
+            }
+        }
+
         state.store(ui.ctx(), id);
 
         (content_size, state)
     }
 }
+
+#[test]
+fn test_scroll_chaining() {
+    /// Scroll a tall scroll area inside another one, returning the offsets of (inner, outer).
+    ///
+    /// The inner one can scroll 136 points: 200 of content in the default `min_scrolled_height` of 64.
+    fn run(
+        harness: &TestHarness,
+        time: f64,
+        scroll_chaining: ScrollChaining,
+        delta: Vec2,
+    ) -> (f32, f32) {
+        let input = RawInput {
+            time: Some(time),
+            events: vec![Event::PointerMoved(pos2(20.0, 20.0))],
+            ..Default::default()
+        };
+        let (offsets, _) = harness.run_input(input, |ctx| {
+            ctx.input_mut(|i| i.smooth_scroll_delta = delta);
+            CentralPanel::default()
+                .show(ctx, |ui| {
+                    let outer = ScrollArea::vertical()
+                        .id_source("outer")
+                        .max_height(100.0)
+                        .animated(false)
+                        .show(ui, |ui| {
+                            let inner = ScrollArea::vertical()
+                                .id_source("inner")
+                                .max_height(50.0)
+                                .animated(false)
+                                .scroll_chaining(scroll_chaining)
+                                .show(ui, |ui| ui.allocate_space(vec2(100.0, 200.0)));
+                            ui.allocate_space(vec2(100.0, 300.0));
+                            inner.state.offset.y
+                        });
+                    (outer.inner, outer.state.offset.y)
+                })
+                .inner
+        });
+        offsets
+    }
+    let down = |amount: f32| vec2(0.0, -amount);
+
+    // Default: what the inner area can't use scrolls the outer one.
+    let harness = TestHarness::new();
+    run(&harness, 0.0, Default::default(), Vec2::ZERO);
+    assert_eq!(
+        run(&harness, 0.0, Default::default(), down(200.0)),
+        (136.0, 64.0)
+    );
+
+    let harness = TestHarness::new();
+    run(&harness, 0.0, ScrollChaining::Never, Vec2::ZERO);
+    assert_eq!(
+        run(&harness, 0.0, ScrollChaining::Never, down(200.0)),
+        (136.0, 0.0)
+    );
+    assert_eq!(
+        run(&harness, 5.0, ScrollChaining::Never, down(200.0)),
+        (136.0, 0.0)
+    );
+
+    let after_delay = ScrollChaining::AfterDelay(0.3);
+    let harness = TestHarness::new();
+    run(&harness, 10.0, after_delay, Vec2::ZERO);
+    assert_eq!(run(&harness, 10.0, after_delay, down(100.0)), (100.0, 0.0));
+    // Hitting the end right after scrolling stops there…
+    assert_eq!(run(&harness, 10.1, after_delay, down(100.0)), (136.0, 0.0));
+    assert_eq!(run(&harness, 10.2, after_delay, down(100.0)), (136.0, 0.0));
+    // …and after a pause the outer area scrolls.
+    assert_eq!(
+        run(&harness, 11.0, after_delay, down(100.0)),
+        (136.0, 100.0)
+    );
+}
+
+#[test]
+fn test_scroll_only_direction_passes_on_left_over() {
+    // A horizontal scroll area inside a vertical one, scrolled with a vertical mouse wheel:
+    let harness = TestHarness::new();
+    harness
+        .ctx
+        .style_mut(|style| style.always_scroll_the_only_direction = true);
+    let run = |delta: Vec2| {
+        harness.run(vec![Event::PointerMoved(pos2(20.0, 20.0))], |ctx| {
+            ctx.input_mut(|i| i.smooth_scroll_delta = delta);
+            CentralPanel::default()
+                .show(ctx, |ui| {
+                    let outer = ScrollArea::vertical()
+                        .id_source("outer")
+                        .max_height(100.0)
+                        .animated(false)
+                        .show(ui, |ui| {
+                            ui.set_max_width(100.0);
+                            let inner = ScrollArea::horizontal()
+                                .id_source("inner")
+                                .animated(false)
+                                .show(ui, |ui| ui.allocate_space(vec2(150.0, 50.0)));
+                            ui.allocate_space(vec2(100.0, 300.0));
+                            inner.state.offset.x
+                        });
+                    (outer.inner, outer.state.offset.y)
+                })
+                .inner
+        })
+    };
+
+    run(Vec2::ZERO);
+    assert_eq!(run(vec2(0.0, -80.0)), (50.0, 30.0));
+}
+
+#[test]
+fn test_scroll_anchoring() {
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    let item_id = |i: usize| Id::new(("item", i));
+    // Items of 20 + 3 (the item spacing) points, with `first..10` in the content:
+    let run = |first: usize, offset: Option<f32>| {
+        let mut scroll_area = ScrollArea::vertical()
+            .max_height(50.0)
+            .animated(false)
+            .scroll_anchoring(true);
+        if let Some(offset) = offset {
+            scroll_area = scroll_area.vertical_scroll_offset(offset);
+        }
+        harness.run_ui(vec![], |ui| {
+            scroll_area
+                .show(ui, |ui| {
+                    for i in first..10 {
+                        ui.scroll_anchor(i, |ui| {
+                            let (rect, _) =
+                                ui.allocate_exact_size(vec2(100.0, 20.0), Sense::hover());
+                            ui.interact(rect, item_id(i), Sense::click());
+                        });
+                    }
+                })
+                .state
+                .offset
+                .y
+        })
+    };
+
+    run(2, None);
+    assert_eq!(run(2, Some(46.0)), 46.0);
+    assert_eq!(run(2, None), 46.0);
+    let item_rect = ctx.read_response(item_id(4)).unwrap().rect;
+
+    // Two items are added above the one at the top of the viewport, which stays in place:
+    assert_eq!(run(0, None), 46.0 + 2.0 * 23.0);
+    assert_eq!(ctx.read_response(item_id(4)).unwrap().rect, item_rect);
+    assert_eq!(run(0, None), 46.0 + 2.0 * 23.0);
+    assert_eq!(ctx.read_response(item_id(4)).unwrap().rect, item_rect);
+}
+
+#[test]
+fn test_sticky_header_interaction() {
+    let harness = TestHarness::new();
+    // Returns if the header and the content below it were clicked:
+    let run = |events: Vec<Event>| {
+        harness.run_ui(events, |ui| {
+            ScrollArea::vertical()
+                .max_height(100.0)
+                .animated(false)
+                .vertical_scroll_offset(50.0)
+                .show(ui, |ui| {
+                    ui.allocate_space(vec2(100.0, 20.0));
+                    let header = ui.sticky_header(|ui| {
+                        let (rect, _) = ui.allocate_exact_size(vec2(100.0, 20.0), Sense::hover());
+                        ui.interact(rect, Id::new("header"), Sense::click())
+                            .clicked()
+                    });
+                    let (rect, _) = ui.allocate_exact_size(vec2(100.0, 300.0), Sense::hover());
+                    let content = ui
+                        .interact(rect, Id::new("content"), Sense::click())
+                        .clicked();
+                    (header.inner, content)
+                })
+                .inner
+        })
+    };
+
+    run(vec![]);
+    run(vec![]);
+    // The header sticks to the top of the viewport:
+    let pos = pos2(20.0, 18.0);
+    run(vec![Event::PointerMoved(pos)]);
+    assert_eq!(run(TestHarness::click_events(pos)), (true, false));
+}
+
+#[test]
+fn test_scroll_sync() {
+    let harness = TestHarness::new();
+    // A scroll area in both directions, and a vertical one next to it following it:
+    let run = |pointer: Pos2, delta: Vec2| {
+        harness.run(vec![Event::PointerMoved(pointer)], |ctx| {
+            ctx.input_mut(|i| i.smooth_scroll_delta = delta);
+            CentralPanel::default()
+                .show(ctx, |ui| {
+                    ui.horizontal_top(|ui| {
+                        let show = |ui: &mut Ui, scroll_area: ScrollArea| {
+                            scroll_area
+                                .max_width(100.0)
+                                .max_height(100.0)
+                                .animated(false)
+                                .sync_id("sync")
+                                .show(ui, |ui| ui.allocate_space(vec2(300.0, 300.0)))
+                                .state
+                                .offset
+                        };
+                        (
+                            show(ui, ScrollArea::both().id_source("both")),
+                            show(ui, ScrollArea::vertical().id_source("vertical")),
+                        )
+                    })
+                    .inner
+                })
+                .inner
+        })
+    };
+
+    let on_both = pos2(20.0, 20.0);
+    let on_vertical = pos2(150.0, 20.0);
+    run(on_both, Vec2::ZERO);
+    run(on_both, vec2(-30.0, -40.0));
+    assert_eq!(
+        run(on_both, Vec2::ZERO),
+        (vec2(30.0, 40.0), vec2(0.0, 40.0))
+    );
+
+    run(on_vertical, vec2(0.0, -10.0));
+    assert_eq!(
+        run(on_vertical, Vec2::ZERO),
+        (vec2(30.0, 50.0), vec2(0.0, 50.0))
+    );
+}
+
+#[test]
+fn test_kinetic_scrolling() {
+    // Fling the content upwards, then see where it goes after being let go of:
+    let fling = |kinetic_friction: f32, overscroll: bool| {
+        let harness = TestHarness::new();
+        let mut time = 0.0;
+        let mut run = |events: Vec<Event>| {
+            time += 1.0 / 60.0;
+            let input = RawInput {
+                time: Some(time),
+                predicted_dt: 1.0 / 60.0,
+                events,
+                ..Default::default()
+            };
+            let (state, _) = harness.run_input(input, |ctx| {
+                CentralPanel::default()
+                    .show(ctx, |ui| {
+                        ScrollArea::vertical()
+                            .max_height(100.0)
+                            .animated(false)
+                            .kinetic_friction(kinetic_friction)
+                            .overscroll(overscroll)
+                            .show(ui, |ui| ui.allocate_space(vec2(100.0, 300.0)))
+                            .state
+                    })
+                    .inner
+            });
+            state
+        };
+        let mut pos = pos2(50.0, 80.0);
+        run(vec![]);
+        run(vec![Event::PointerMoved(pos)]);
+        let press = TestHarness::button_event(pos, PointerButton::Primary, true);
+        run(vec![press]);
+        for _ in 0..5 {
+            pos.y -= 10.0;
+            run(vec![Event::PointerMoved(pos)]);
+        }
+        let release = TestHarness::button_event(pos, PointerButton::Primary, false);
+        let let_go = run(vec![release, Event::PointerGone]);
+        let later = (0..30).map(|_| run(vec![])).last().unwrap();
+        (let_go, later)
+    };
+
+    // The content keeps moving, slower and slower:
+    let (let_go, later) = fling(1000.0, false);
+    assert!(let_go.offset.y < later.offset.y);
+    assert!(later.vel.y.abs() < let_go.vel.y.abs());
+
+    let (let_go, later) = fling(f32::INFINITY, false);
+    assert_eq!(let_go.offset.y, later.offset.y);
+
+    // Without friction it hits the end, and bounces off it:
+    let (_, later) = fling(0.0, true);
+    assert_eq!(later.offset.y, 200.0);
+    assert_ne!(later.overscroll.y, 0.0);
+}
@@ -0,0 +1,398 @@
+//! Split a [`Ui`] into resizable panes, see [`Splitter`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct SplitterState {
+    /// How large a fraction of the space each pane gets.
+    ratios: Vec<f32>,
+}
+
+impl SplitterState {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// Divide a [`Ui`] into panes next to each other (or above each other),
+/// with dividers in between that the user can drag to resize the panes.
+///
+/// Unlike [`SidePanel`], a [`Splitter`] can be used inside any [`Ui`],
+/// including inside the pane of another [`Splitter`].
+/// It fills all the available space.
+///
+/// The sizes of the panes are remembered as fractions of the available space.
+/// Double-click a divider to go back to the default sizes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Splitter::horizontal("split").show(ui, |panes| {
+///     panes[0].label("Left");
+///     panes[1].label("Right");
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Splitter {
+    id_source: Id,
+    vertical: bool,
+    num_panes: usize,
+    default_ratios: Option<Vec<f32>>,
+    min_size: f32,
+    max_size: f32,
+}
+
+impl Splitter {
+    /// Panes side by side, with vertical dividers.
+    ///
+    /// The id source must be unique among the siblings of the splitter.
+    pub fn horizontal(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            vertical: false,
+            num_panes: 2,
+            default_ratios: None,
+            min_size: 20.0,
+            max_size: f32::INFINITY,
+        }
+    }
+
+    /// Panes above each other, with horizontal dividers.
+    ///
+    /// The id source must be unique among the siblings of the splitter.
+    pub fn vertical(id_source: impl Hash) -> Self {
+        Self {
+            vertical: true,
+            ..Self::horizontal(id_source)
+        }
+    }
+
+    /// How many panes to show.
+    ///
+    /// Default: `2`.
+    #[inline]
+    pub fn num_panes(mut self, num_panes: usize) -> Self {
+        self.num_panes = num_panes.at_least(1);
+        self
+    }
+
+    /// How to divide the space before the user has dragged any divider,
+    /// e.g. `[1.0, 3.0]` to give the second pane three times the space of the first.
+    ///
+    /// There should be one value per pane, none of them negative, and not all of them zero.
+    /// Otherwise they are ignored.
+    /// Default: all panes get the same size.
+    #[inline]
+    pub fn default_ratios(mut self, ratios: impl Into<Vec<f32>>) -> Self {
+        self.default_ratios = Some(ratios.into());
+        self
+    }
+
+    /// The smallest size of a pane, in points.
+    ///
+    /// This also holds when the [`Ui`] shrinks, unless there isn't room for all panes to be this large.
+    ///
+    /// Default: `20.0`.
+    #[inline]
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// The largest size the user can drag a pane to, in points.
+    ///
+    /// Default: no limit.
+    #[inline]
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    fn default_state(&self) -> SplitterState {
+        let sum = |ratios: &[f32]| ratios.iter().sum::<f32>();
+        let ratios = match &self.default_ratios {
+            Some(ratios)
+                if ratios.len() == self.num_panes
+                    && ratios
+                        .iter()
+                        .all(|ratio| ratio.is_finite() && 0.0 <= *ratio)
+                    && 0.0 < sum(ratios) =>
+            {
+                ratios.iter().map(|ratio| ratio / sum(ratios)).collect()
+            }
+            _ => vec![1.0 / self.num_panes as f32; self.num_panes],
+        };
+        SplitterState { ratios }
+    }
+
+    /// Keep the sizes within [`Self::min_size`] and [`Self::max_size`], while still adding up to `space`.
+    fn clamp_sizes(&self, sizes: &mut [f32], space: f32) {
+        let fair_share = space / sizes.len() as f32;
+        let min = self.min_size.min(fair_share);
+        let max = self.max_size.clamp(fair_share, space);
+        if sizes.iter().all(|size| (min..=max).contains(size)) {
+            return;
+        }
+
+        for size in sizes.iter_mut() {
+            *size = size.clamp(min, max);
+        }
+
+        // Take the difference from the panes that have room for it, in proportion to that room:
+        let excess = sizes.iter().sum::<f32>() - space;
+        let room = |size: f32| if 0.0 < excess { size - min } else { max - size };
+        let total_room: f32 = sizes.iter().map(|&size| room(size)).sum();
+        if 0.0 < total_room {
+            for size in sizes.iter_mut() {
+                *size -= excess * room(*size) / total_room;
+            }
+        }
+    }
+
+    /// Show the panes.
+    ///
+    /// `add_contents` gets one [`Ui`] per pane, in order.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut [Ui]) -> R,
+    ) -> InnerResponse<R> {
+        let id = ui.make_persistent_id(self.id_source);
+        let num_panes = self.num_panes;
+        let axis = usize::from(self.vertical);
+
+        let rect = ui.available_rect_before_wrap();
+        let gap = ui.spacing().item_spacing[axis];
+        let space = (rect.size()[axis] - gap * (num_panes - 1) as f32).at_least(0.0);
+
+        let state = SplitterState::load(ui.ctx(), id)
+            .filter(|state| state.ratios.len() == num_panes)
+            .unwrap_or_else(|| self.default_state());
+        let mut sizes: Vec<f32> = state.ratios.iter().map(|ratio| ratio * space).collect();
+
+        // The space may have shrunk since the sizes were stored.
+        // We only store the clamped sizes if the user resizes the panes,
+        // so that they get back their old sizes when the space grows again.
+        self.clamp_sizes(&mut sizes, space);
+        let mut resized = false;
+
+        let divider_id = |i: usize| id.with("divider").with(i);
+
+        // First we read the divider interactions, to avoid frame latency in the resize:
+        for i in 0..num_panes - 1 {
+            let Some(response) = ui.ctx().read_response(divider_id(i)) else {
+                continue;
+            };
+            if response.double_clicked() {
+                sizes = self
+                    .default_state()
+                    .ratios
+                    .iter()
+                    .map(|ratio| ratio * space)
+                    .collect();
+                self.clamp_sizes(&mut sizes, space);
+                resized = true;
+            } else if let (true, Some(pointer)) =
+                (response.dragged(), response.interact_pointer_pos())
+            {
+                // Only the two panes next to the divider change size:
+                let start = rect.min[axis] + sizes[..i].iter().sum::<f32>() + gap * i as f32;
+                let both = sizes[i] + sizes[i + 1];
+                let min = self.min_size.max(both - self.max_size);
+                let max = self.max_size.min(both - self.min_size);
+                if min <= max {
+                    sizes[i] = (pointer[axis] - 0.5 * gap - start).clamp(min, max);
+                    sizes[i + 1] = both - sizes[i];
+                    resized = true;
+                }
+            }
+        }
+
+        let mut pane_rects = Vec::with_capacity(num_panes);
+        let mut pos = rect.min[axis];
+        for &size in &sizes {
+            let mut pane_rect = rect;
+            pane_rect.min[axis] = pos;
+            pane_rect.max[axis] = pos + size;
+            pane_rects.push(pane_rect);
+            pos += size + gap;
+        }
+
+        let clip_rect = ui.clip_rect();
+        let mut pane_uis: Vec<Ui> = pane_rects
+            .iter()
+            .enumerate()
+            .map(|(i, pane_rect)| {
+                let mut pane_ui = ui.child_ui_with_id_source(
+                    *pane_rect,
+                    Layout::top_down(Align::Min),
+                    id.with(i),
+                    None,
+                );
+                pane_ui.set_clip_rect(pane_rect.intersect(clip_rect));
+                pane_ui
+            })
+            .collect();
+        let inner = add_contents(&mut pane_uis);
+
+        // Now we do the actual divider interaction, on top of all the contents.
+        // Otherwise its input could be eaten by the contents, e.g. a `ScrollArea` in a pane.
+        let grab_radius = ui.style().interaction.resize_grab_radius_side;
+        for (i, pane_rect) in pane_rects.iter().take(num_panes - 1).enumerate() {
+            let divider_pos = pane_rect.max[axis] + 0.5 * gap;
+            let mut divider_rect = rect;
+            divider_rect.min[axis] = divider_pos - grab_radius;
+            divider_rect.max[axis] = divider_pos + grab_radius;
+            let response = ui.interact(divider_rect, divider_id(i), Sense::click_and_drag());
+
+            let stroke = if response.dragged() {
+                ui.style().visuals.widgets.active.fg_stroke // highly visible
+            } else if response.hovered() {
+                ui.style().visuals.widgets.hovered.fg_stroke // highly visible
+            } else {
+                ui.style().visuals.widgets.noninteractive.bg_stroke // dim
+            };
+            let divider_pos = ui.painter().round_to_pixel(divider_pos);
+            if self.vertical {
+                ui.painter().hline(rect.x_range(), divider_pos, stroke);
+            } else {
+                ui.painter().vline(divider_pos, rect.y_range(), stroke);
+            }
+
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(if self.vertical {
+                    CursorIcon::ResizeVertical
+                } else {
+                    CursorIcon::ResizeHorizontal
+                });
+            }
+        }
+
+        if resized && 0.0 < space {
+            SplitterState {
+                ratios: sizes.iter().map(|size| size / space).collect(),
+            }
+            .store(ui.ctx(), id);
+        }
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// The widths of the panes of a horizontal splitter that fills a screen this wide.
+#[cfg(test)]
+fn pane_widths(
+    harness: &TestHarness,
+    screen_width: f32,
+    splitter: impl FnOnce() -> Splitter,
+    events: Vec<Event>,
+) -> Vec<f32> {
+    let input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(screen_width, 300.0))),
+        events,
+        ..Default::default()
+    };
+    let (widths, _) = harness.run_input(input, |ctx| {
+        CentralPanel::default()
+            .frame(Frame::none())
+            .show(ctx, |ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                splitter()
+                    .show(ui, |panes| {
+                        panes
+                            .iter()
+                            .map(|pane| pane.max_rect().width())
+                            .collect::<Vec<_>>()
+                    })
+                    .inner
+            })
+            .inner
+    });
+    widths
+}
+
+#[test]
+fn test_splitter_resize() {
+    let harness = TestHarness::new();
+    let splitter = || {
+        Splitter::horizontal("split")
+            .default_ratios([1.0, 3.0])
+            .min_size(50.0)
+    };
+    let run = |events: Vec<Event>| pane_widths(&harness, 400.0, splitter, events);
+    assert_eq!(run(vec![]), [100.0, 300.0]);
+
+    let drag = |from: Pos2, to: Pos2| {
+        run(vec![Event::PointerMoved(from)]);
+        run(vec![TestHarness::button_event(
+            from,
+            PointerButton::Primary,
+            true,
+        )]);
+        run(vec![Event::PointerMoved(to)]);
+        run(vec![Event::PointerMoved(to)]);
+        run(vec![TestHarness::button_event(
+            to,
+            PointerButton::Primary,
+            false,
+        )])
+    };
+
+    assert_eq!(drag(pos2(100.0, 150.0), pos2(250.0, 150.0)), [250.0, 150.0]);
+
+    // The panes don't get smaller than the min size:
+    assert_eq!(drag(pos2(250.0, 150.0), pos2(390.0, 150.0)), [350.0, 50.0]);
+
+    // The sizes are remembered:
+    assert_eq!(run(vec![]), [350.0, 50.0]);
+}
+
+#[test]
+fn test_splitter_min_size_when_shrunk() {
+    let harness = TestHarness::new();
+    let splitter = || {
+        Splitter::horizontal("split")
+            .default_ratios([3.0, 1.0])
+            .min_size(50.0)
+    };
+    assert_eq!(
+        pane_widths(&harness, 400.0, splitter, vec![]),
+        [300.0, 100.0]
+    );
+
+    // The second pane would be 25 wide, but that is below the min size:
+    assert_eq!(pane_widths(&harness, 100.0, splitter, vec![]), [50.0, 50.0]);
+    assert_eq!(
+        pane_widths(&harness, 160.0, splitter, vec![]),
+        [110.0, 50.0]
+    );
+
+    // Not enough room for the min size:
+    assert_eq!(pane_widths(&harness, 60.0, splitter, vec![]), [30.0, 30.0]);
+
+    // With room again, the panes get their old sizes back:
+    assert_eq!(
+        pane_widths(&harness, 400.0, splitter, vec![]),
+        [300.0, 100.0]
+    );
+}
+
+#[test]
+fn test_splitter_bad_default_ratios() {
+    for ratios in [[0.0, 0.0], [-1.0, 2.0], [f32::NAN, 1.0]] {
+        let harness = TestHarness::new();
+        let splitter = || Splitter::horizontal("split").default_ratios(ratios);
+        assert_eq!(
+            pane_widths(&harness, 400.0, splitter, vec![]),
+            [200.0, 200.0]
+        );
+    }
+}
@@ -0,0 +1,633 @@
+//! A table with a header row and a scrolling body, see [`Table`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// The widths the user has resized the columns of a [`Table`] to.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct TableState {
+    /// `None` for columns that have not been resized.
+    widths: Vec<Option<f32>>,
+}
+
+impl TableState {
+    fn load(ctx: &Context, id: Id) -> Self {
+        ctx.data_mut(|d| d.get_persisted(id)).unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// How the width of a [`TableColumn`] is decided.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ColumnWidth {
+    /// Start at this width.
+    Initial(f32),
+
+    /// Always this width, and not resizable.
+    Exact(f32),
+
+    /// Take up what is left after the other columns.
+    Remainder,
+}
+
+/// A column of a [`Table`].
+pub struct TableColumn {
+    header: WidgetText,
+    width: ColumnWidth,
+    width_range: Rangef,
+    resizable: bool,
+    sortable: bool,
+}
+
+impl TableColumn {
+    /// A column with this text in the header.
+    ///
+    /// By default the column is 100 points wide, and can be resized.
+    pub fn new(header: impl Into<WidgetText>) -> Self {
+        Self {
+            header: header.into(),
+            width: ColumnWidth::Initial(100.0),
+            width_range: Rangef::new(24.0, f32::INFINITY),
+            resizable: true,
+            sortable: false,
+        }
+    }
+
+    /// The width of the column before the user has resized it.
+    #[inline]
+    pub fn initial_width(mut self, width: f32) -> Self {
+        self.width = ColumnWidth::Initial(width);
+        self
+    }
+
+    /// Always make the column this wide, and don't let the user resize it.
+    #[inline]
+    pub fn exact_width(mut self, width: f32) -> Self {
+        self.width = ColumnWidth::Exact(width);
+        self.resizable = false;
+        self
+    }
+
+    /// Let the column take up the width that is left over by the other columns,
+    /// e.g. for the last column.
+    ///
+    /// The column can't be resized, since it follows the size of the table.
+    #[inline]
+    pub fn remainder(mut self) -> Self {
+        self.width = ColumnWidth::Remainder;
+        self.resizable = false;
+        self
+    }
+
+    /// The widths the column can have.
+    ///
+    /// Default: at least `24.0`.
+    #[inline]
+    pub fn width_range(mut self, width_range: impl Into<Rangef>) -> Self {
+        self.width_range = width_range.into();
+        self
+    }
+
+    /// Can the user resize the column by dragging the right edge of its header?
+    ///
+    /// Default: `true`, except for [`Self::exact_width`] and [`Self::remainder`].
+    #[inline]
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Can the user click the header of the column to sort the table by it?
+    ///
+    /// See [`Table::sort`]. Default: `false`.
+    #[inline]
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// Which column a [`Table`] is sorted by, see [`Table::sort`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TableSort {
+    /// Index of the column.
+    pub column: usize,
+
+    /// Largest first?
+    pub descending: bool,
+}
+
+/// A table with a header row, where only the visible rows of the body are shown.
+///
+/// The table calls you back for each visible cell with its row and column index.
+/// It doesn't own the rows, so sorting and selection are given to it as references to your own state,
+/// and you sort your rows when [`TableResponse::sort_changed`] is set.
+///
+/// The widths of the columns that the user has resized are remembered.
+///
+/// This is a simpler table than the one in `egui_extras` (built with `egui_extras::TableBuilder`):
+/// every row has the same height and every cell is shown with the same callback,
+/// but it has built-in sorting and row selection, and needs no extra crate.
+/// Use the `egui_extras` table for rows of different heights, or columns laid out with `Strip`s.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut files = vec![("README.md", 1200), ("main.rs", 300)];
+/// # let mut sort = None;
+/// # let mut selected = None;
+/// use egui::{Table, TableColumn};
+///
+/// let response = Table::new("files")
+///     .column(TableColumn::new("Name").sortable(true))
+///     .column(TableColumn::new("Size").sortable(true).remainder())
+///     .sort(&mut sort)
+///     .selection(&mut selected)
+///     .show(ui, files.len(), |ui, row, column| {
+///         let (name, size) = files[row];
+///         match column {
+///             0 => ui.label(name),
+///             _ => ui.label(format!("{size} B")),
+///         };
+///     });
+///
+/// if response.sort_changed {
+///     if let Some(sort) = sort {
+///         files.sort_by(|a, b| match sort.column {
+///             0 => a.0.cmp(b.0),
+///             _ => a.1.cmp(&b.1),
+///         });
+///         if sort.descending {
+///             files.reverse();
+///         }
+///     }
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Table<'a> {
+    id_source: Id,
+    columns: Vec<TableColumn>,
+    row_height: Option<f32>,
+    striped: Option<bool>,
+    sort: Option<&'a mut Option<TableSort>>,
+    selection: Option<&'a mut Option<usize>>,
+}
+
+impl<'a> Table<'a> {
+    /// The id source must be unique among the siblings of the table.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            columns: Vec::new(),
+            row_height: None,
+            striped: None,
+            sort: None,
+            selection: None,
+        }
+    }
+
+    /// Add a column, left to right.
+    #[inline]
+    pub fn column(mut self, column: TableColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// The height of every row of the body.
+    ///
+    /// All rows have the same height, so that only the visible ones need to be shown.
+    /// Default: [`crate::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = Some(row_height);
+        self
+    }
+
+    /// Add a subtle background color to every other row.
+    ///
+    /// Default is whatever is in [`crate::Visuals::striped`].
+    #[inline]
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = Some(striped);
+        self
+    }
+
+    /// Which column the rows are sorted by, if any.
+    ///
+    /// Clicking the header of a [`TableColumn::sortable`] column sorts by it,
+    /// and clicking it again flips the order.
+    /// The header shows an arrow for the current order.
+    #[inline]
+    pub fn sort(mut self, sort: &'a mut Option<TableSort>) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// The index of the selected row, if any.
+    ///
+    /// Clicking a row selects it.
+    /// This is an index into your rows, so update it if you sort them.
+    #[inline]
+    pub fn selection(mut self, selection: &'a mut Option<usize>) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Show the table, filling the available space.
+    ///
+    /// `add_cell` is called with the row and column index of each visible cell.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        total_rows: usize,
+        mut add_cell: impl FnMut(&mut Ui, usize, usize),
+    ) -> TableResponse {
+        let Self {
+            id_source,
+            columns,
+            row_height,
+            striped,
+            mut sort,
+            mut selection,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let row_height = row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
+        let striped = striped.unwrap_or_else(|| ui.visuals().striped);
+        let x_spacing = ui.spacing().item_spacing.x;
+
+        let mut state = TableState::load(ui.ctx(), id);
+        let widths = column_widths(&columns, &state, ui.available_width(), x_spacing);
+
+        let mut sort_changed = false;
+        let mut selection_changed = false;
+
+        let response = ui
+            .vertical(|ui| {
+                // Header:
+                let (header_rect, _) = ui.allocate_exact_size(
+                    vec2(ui.available_width(), ui.spacing().interact_size.y),
+                    Sense::hover(),
+                );
+                let mut left = header_rect.left();
+                let mut resized = false;
+                for (index, (column, &width)) in columns.iter().zip(&widths).enumerate() {
+                    let rect = Rect::from_x_y_ranges(left..=left + width, header_rect.y_range());
+                    let current_sort = sort
+                        .as_deref()
+                        .copied()
+                        .flatten()
+                        .filter(|sort| sort.column == index);
+                    if header_cell_ui(ui, id.with(index), rect, column, current_sort).clicked() {
+                        if let Some(sort) = sort.as_deref_mut() {
+                            *sort = Some(TableSort {
+                                column: index,
+                                descending: current_sort.map_or(false, |sort| !sort.descending),
+                            });
+                            sort_changed = true;
+                        }
+                    }
+
+                    if column.resizable {
+                        if let Some(new_width) =
+                            resize_handle_ui(ui, id.with(("resize", index)), rect, x_spacing)
+                        {
+                            let new_width = clamp_width(new_width, column.width_range);
+                            state.widths.resize(state.widths.len().max(index + 1), None);
+                            state.widths[index] = Some(new_width);
+                            resized = true;
+                        }
+                    }
+                    left += width + x_spacing;
+                }
+                ui.painter().hline(
+                    header_rect.x_range(),
+                    header_rect.bottom() + 0.5 * ui.spacing().item_spacing.y,
+                    ui.visuals().widgets.noninteractive.bg_stroke,
+                );
+                if resized {
+                    state.clone().store(ui.ctx(), id);
+                    ui.ctx().request_repaint();
+                }
+
+                // Body:
+                ScrollArea::vertical()
+                    .id_source(id.with("body"))
+                    .auto_shrink(false)
+                    .show_rows(ui, row_height, total_rows, |ui, row_range| {
+                        for row in row_range {
+                            let is_selected = selection.as_deref() == Some(&Some(row));
+                            let (row_rect, row_response) = ui.allocate_exact_size(
+                                vec2(ui.available_width(), row_height),
+                                if selection.is_some() {
+                                    Sense::click()
+                                } else {
+                                    Sense::hover()
+                                },
+                            );
+                            let background_rect =
+                                row_rect.expand2(0.5 * ui.spacing().item_spacing.y * Vec2::Y);
+                            if is_selected {
+                                ui.painter().rect_filled(
+                                    background_rect,
+                                    0.0,
+                                    ui.visuals().selection.bg_fill,
+                                );
+                            } else if striped && row % 2 == 1 {
+                                ui.painter().rect_filled(
+                                    background_rect,
+                                    0.0,
+                                    ui.visuals().faint_bg_color,
+                                );
+                            }
+
+                            if row_response.clicked() {
+                                if let Some(selection) = selection.as_deref_mut() {
+                                    *selection = Some(row);
+                                    selection_changed = true;
+                                }
+                            }
+
+                            let mut left = row_rect.left();
+                            for (column, &width) in widths.iter().enumerate() {
+                                let cell_rect =
+                                    Rect::from_x_y_ranges(left..=left + width, row_rect.y_range());
+                                let mut cell_ui = ui.child_ui(
+                                    cell_rect,
+                                    Layout::left_to_right(Align::Center),
+                                    None,
+                                );
+                                cell_ui.set_clip_rect(cell_ui.clip_rect().intersect(cell_rect));
+                                if is_selected {
+                                    cell_ui.visuals_mut().override_text_color =
+                                        Some(ui.visuals().selection.stroke.color);
+                                }
+                                add_cell(&mut cell_ui, row, column);
+                                left += width + x_spacing;
+                            }
+                        }
+                    });
+            })
+            .response;
+
+        TableResponse {
+            response,
+            sort_changed,
+            selection_changed,
+        }
+    }
+}
+
+/// Returned by [`Table::show`].
+pub struct TableResponse {
+    /// Response of the whole table.
+    pub response: Response,
+
+    /// The user clicked a header, changing [`Table::sort`].
+    ///
+    /// The rows should be sorted again.
+    pub sort_changed: bool,
+
+    /// The user clicked a row, changing [`Table::selection`].
+    pub selection_changed: bool,
+}
+
+/// The width of each column, this frame.
+fn column_widths(
+    columns: &[TableColumn],
+    state: &TableState,
+    available_width: f32,
+    x_spacing: f32,
+) -> Vec<f32> {
+    let mut widths: Vec<Option<f32>> = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let resized = state.widths.get(index).copied().flatten();
+            let width = match column.width {
+                ColumnWidth::Initial(width) | ColumnWidth::Exact(width) => Some(width),
+                ColumnWidth::Remainder => None,
+            }?;
+            let width = if column.resizable {
+                resized.unwrap_or(width)
+            } else {
+                width
+            };
+            Some(clamp_width(width, column.width_range))
+        })
+        .collect();
+
+    let used: f32 =
+        widths.iter().flatten().sum::<f32>() + columns.len().saturating_sub(1) as f32 * x_spacing;
+    let num_remainders = widths.iter().filter(|width| width.is_none()).count();
+    let remainder = (available_width - used) / num_remainders.at_least(1) as f32;
+    for (width, column) in widths.iter_mut().zip(columns) {
+        if width.is_none() {
+            *width = Some(clamp_width(remainder, column.width_range));
+        }
+    }
+    widths.into_iter().flatten().collect()
+}
+
+fn clamp_width(width: f32, range: Rangef) -> f32 {
+    let range = range.as_positive();
+    width.clamp(range.min, range.max)
+}
+
+fn header_cell_ui(
+    ui: &Ui,
+    id: Id,
+    rect: Rect,
+    column: &TableColumn,
+    sort: Option<TableSort>,
+) -> Response {
+    let sense = if column.sortable {
+        Sense::click()
+    } else {
+        Sense::hover()
+    };
+    let response = ui.interact(rect, id, sense);
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, column.header.text()));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        if column.sortable && response.hovered() {
+            ui.painter()
+                .rect_filled(rect, visuals.rounding, visuals.weak_bg_fill);
+        }
+
+        let arrow = sort.map(|sort| if sort.descending { "⏷" } else { "⏶" });
+        let arrow_width = if arrow.is_some() {
+            ui.spacing().icon_width
+        } else {
+            0.0
+        };
+        let galley = column.header.clone().into_galley(
+            ui,
+            Some(TextWrapMode::Truncate),
+            rect.width() - arrow_width,
+            TextStyle::Body,
+        );
+        let text_pos = Align2::LEFT_CENTER
+            .align_size_within_rect(galley.size(), rect)
+            .min;
+        let text_color = ui.visuals().strong_text_color();
+        ui.painter().galley(text_pos, galley, text_color);
+
+        if let Some(arrow) = arrow {
+            ui.painter().text(
+                rect.right_center(),
+                Align2::RIGHT_CENTER,
+                arrow,
+                TextStyle::Body.resolve(ui.style()),
+                text_color,
+            );
+        }
+    }
+    response
+}
+
+/// Lets the user drag the right edge of a column header, returning the new width of the column.
+fn resize_handle_ui(ui: &Ui, id: Id, rect: Rect, x_spacing: f32) -> Option<f32> {
+    let edge_x = rect.right() + 0.5 * x_spacing;
+    let handle_rect = Rect::from_x_y_ranges(edge_x..=edge_x, rect.y_range())
+        .expand2(vec2(ui.style().interaction.resize_grab_radius_side, 0.0));
+    let response = ui.interact(handle_rect, id, Sense::drag());
+
+    let stroke = if response.dragged() {
+        ui.visuals().widgets.active.fg_stroke
+    } else if response.hovered() {
+        ui.visuals().widgets.hovered.fg_stroke
+    } else {
+        ui.visuals().widgets.noninteractive.bg_stroke
+    };
+    if response.hovered() || response.dragged() {
+        ui.ctx().set_cursor_icon(CursorIcon::ResizeColumn);
+    }
+    let edge_x = ui.painter().round_to_pixel(edge_x);
+    ui.painter().vline(edge_x, rect.y_range(), stroke);
+
+    if response.dragged() {
+        let pointer = response.interact_pointer_pos()?;
+        Some(pointer.x - 0.5 * x_spacing - rect.left())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resized(widths: &[Option<f32>]) -> TableState {
+        TableState {
+            widths: widths.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_remainder_is_split() {
+        let columns = [
+            TableColumn::new("a").exact_width(100.0),
+            TableColumn::new("b").remainder(),
+            TableColumn::new("c").remainder(),
+        ];
+        let widths = column_widths(&columns, &TableState::default(), 420.0, 10.0);
+        assert_eq!(widths, [100.0, 150.0, 150.0]);
+    }
+
+    #[test]
+    fn test_widths_are_clamped() {
+        let columns = [
+            TableColumn::new("a")
+                .initial_width(10.0)
+                .width_range(50.0..=80.0),
+            TableColumn::new("b")
+                .initial_width(200.0)
+                .width_range(50.0..=80.0),
+            // Not enough room left:
+            TableColumn::new("c").remainder().width_range(30.0..=100.0),
+        ];
+        let widths = column_widths(&columns, &TableState::default(), 100.0, 0.0);
+        assert_eq!(widths, [50.0, 80.0, 30.0]);
+    }
+
+    #[test]
+    fn test_resized_widths() {
+        let columns = [
+            TableColumn::new("a").width_range(50.0..=300.0),
+            TableColumn::new("b").exact_width(100.0),
+            TableColumn::new("c"),
+            TableColumn::new("d").remainder(),
+        ];
+
+        // Only resizable columns keep what the user resized them to, within their range:
+        let state = resized(&[Some(500.0), Some(20.0)]);
+        let widths = column_widths(&columns, &state, 1000.0, 0.0);
+        assert_eq!(widths, [300.0, 100.0, 100.0, 500.0]);
+    }
+
+    #[test]
+    fn test_sort_toggling() {
+        let harness = TestHarness::new();
+        let mut sort = None;
+        let run = |events, sort: &mut Option<TableSort>| {
+            harness.run_ui(events, |ui| {
+                let response = Table::new("table")
+                    .column(TableColumn::new("a").sortable(true))
+                    .column(TableColumn::new("b").sortable(true))
+                    .column(TableColumn::new("c"))
+                    .sort(sort)
+                    .show(ui, 3, |_ui, _row, _column| {});
+                let id = ui.make_persistent_id(Id::new("table"));
+                let header_rects: Vec<Rect> = (0..3)
+                    .map(|i| ui.ctx().read_response(id.with(i)).unwrap().rect)
+                    .collect();
+                (header_rects, response.sort_changed)
+            })
+        };
+        let click = |index: usize, sort: &mut Option<TableSort>| {
+            let (rects, _) = run(vec![], sort);
+            run(TestHarness::click_events(rects[index].center()), sort).1
+        };
+
+        assert!(click(0, &mut sort));
+        assert_eq!(
+            sort,
+            Some(TableSort {
+                column: 0,
+                descending: false
+            })
+        );
+
+        // Clicking again flips the order:
+        assert!(click(0, &mut sort));
+        assert_eq!(
+            sort,
+            Some(TableSort {
+                column: 0,
+                descending: true
+            })
+        );
+
+        // Another column starts out ascending:
+        assert!(click(1, &mut sort));
+        assert_eq!(
+            sort,
+            Some(TableSort {
+                column: 1,
+                descending: false
+            })
+        );
+
+        // A column that isn't sortable can't be clicked:
+        assert!(!click(2, &mut sort));
+        assert_eq!(sort.map(|sort| sort.column), Some(1));
+    }
+}
@@ -0,0 +1,403 @@
+//! A row of tabs where one is active at a time, see [`Tabs`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct TabsState {
+    /// The index of the active tab.
+    active: usize,
+}
+
+impl TabsState {
+    fn load(ctx: &Context, id: Id) -> Self {
+        ctx.data_mut(|d| d.get_persisted(id)).unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+}
+
+/// A row of tabs above the contents of the active tab.
+///
+/// Each tab is one element in a `Vec` that you own.
+/// The tabs are identified by their index, so they can be any type, and equal tabs are fine.
+/// The index of the active tab is remembered by egui,
+/// and follows the tabs when the user moves or closes them.
+///
+/// * Click on a tab to make it active.
+/// * When a tab has keyboard focus, use the arrow keys to switch tabs.
+/// * Drag a tab sideways to move it (see [`Self::reorderable`]).
+/// * Close a tab with its close button or by middle-clicking it (see [`Self::closable`]).
+/// * If the tabs don't fit, scroll the tab bar sideways.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut documents = vec!["README.md".to_owned(), "main.rs".to_owned()];
+/// # let mut recently_closed = Vec::new();
+/// let response = egui::Tabs::new("documents")
+///     .closable(true)
+///     .show(ui, &mut documents, |name| name.into(), |ui, name| {
+///         ui.label(format!("Contents of {name}"));
+///     });
+/// if let Some(closed) = response.closed {
+///     recently_closed.push(closed); // e.g. for "Reopen closed tab"
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Tabs {
+    id_source: Id,
+    closable: bool,
+    reorderable: bool,
+}
+
+impl Tabs {
+    /// The id source must be unique among the siblings of the tabs.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            closable: false,
+            reorderable: true,
+        }
+    }
+
+    /// Show a close button on each tab.
+    ///
+    /// A closed tab is removed from the tabs, and returned in [`TabsResponse::closed`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Can the user drag the tabs to change their order?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Show the tab bar, and below it the contents of the active tab.
+    ///
+    /// `tab_title` gives the text shown in each tab.
+    /// `add_contents` is called with the active tab, if there are any tabs.
+    pub fn show<T, R>(
+        self,
+        ui: &mut Ui,
+        tabs: &mut Vec<T>,
+        mut tab_title: impl FnMut(&T) -> WidgetText,
+        add_contents: impl FnOnce(&mut Ui, &mut T) -> R,
+    ) -> TabsResponse<T, R> {
+        let id = ui.make_persistent_id(self.id_source);
+        let mut state = TabsState::load(ui.ctx(), id);
+        let tab_id = |index: usize| id.with(index);
+
+        let stored_active = state.active;
+        let mut active = stored_active.min(tabs.len().saturating_sub(1));
+        let mut close_index = None;
+        let num_tabs = tabs.len();
+
+        let bar_response = ScrollArea::horizontal()
+            .id_source(id.with("scroll"))
+            .scroll_bar_visibility(scroll_area::ScrollBarVisibility::AlwaysHidden)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 2.0;
+
+                    let mut rects = Vec::with_capacity(tabs.len());
+                    let mut dragged = None;
+
+                    for (i, tab) in tabs.iter().enumerate() {
+                        let (response, close_clicked) =
+                            self.tab_ui(ui, tab_id(i), tab_title(tab), i == active);
+
+                        if response.clicked() || response.gained_focus() {
+                            active = i;
+                        }
+                        if response.has_focus() {
+                            // Move between the tabs, skipping their close buttons:
+                            let next = ui.input_mut(|input| {
+                                if input.consume_key(Modifiers::NONE, Key::ArrowLeft) {
+                                    i.checked_sub(1)
+                                } else if input.consume_key(Modifiers::NONE, Key::ArrowRight) {
+                                    Some(i + 1).filter(|&next| next < num_tabs)
+                                } else {
+                                    None
+                                }
+                            });
+                            if let Some(next) = next {
+                                ui.memory_mut(|mem| {
+                                    mem.request_focus(tab_id(next));
+                                    mem.stop_focus_navigation();
+                                });
+                            }
+                        }
+                        if i == active && stored_active != active {
+                            response.scroll_to_me(None);
+                        }
+                        if self.reorderable && response.dragged() {
+                            dragged = response.interact_pointer_pos().map(|pos| (i, pos));
+                            ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+                        }
+                        if self.closable && (close_clicked || response.middle_clicked()) {
+                            close_index = Some(i);
+                        }
+                        rects.push(response.rect);
+                    }
+
+                    // Swap places with a neighbor once the pointer passes its middle:
+                    if let Some((i, pointer_pos)) = dragged {
+                        let other = if 0 < i && pointer_pos.x < rects[i - 1].center().x {
+                            Some(i - 1)
+                        } else if i + 1 < rects.len() && rects[i + 1].center().x < pointer_pos.x {
+                            Some(i + 1)
+                        } else {
+                            None
+                        };
+                        if let Some(other) = other {
+                            tabs.swap(i, other);
+
+                            // The tab is still dragged (and focused) at its new index:
+                            ui.ctx().set_dragged_id(tab_id(other));
+                            if ui.memory(|mem| mem.has_focus(tab_id(i))) {
+                                ui.memory_mut(|mem| mem.request_focus(tab_id(other)));
+                            }
+                            if active == i {
+                                active = other;
+                            } else if active == other {
+                                active = i;
+                            }
+                        }
+                    }
+                })
+                .response
+            })
+            .inner;
+
+        let closed = close_index.map(|i| {
+            if i < active {
+                active -= 1;
+            }
+            tabs.remove(i)
+        });
+        active = active.min(tabs.len().saturating_sub(1));
+
+        ui.separator();
+
+        let inner = tabs.get_mut(active).map(|tab| add_contents(ui, tab));
+
+        state.active = active;
+        state.store(ui.ctx(), id);
+
+        TabsResponse {
+            bar_response,
+            active: tabs.get(active).map(|_| active),
+            closed,
+            inner,
+        }
+    }
+
+    /// Returns the response of the tab, and whether its close button was clicked.
+    fn tab_ui(&self, ui: &mut Ui, id: Id, title: WidgetText, selected: bool) -> (Response, bool) {
+        let padding = ui.spacing().button_padding;
+        let close_size = if self.closable {
+            ui.spacing().icon_width
+        } else {
+            0.0
+        };
+
+        let galley = title.into_galley(
+            ui,
+            Some(TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Button,
+        );
+        let close_width = if self.closable {
+            padding.x + close_size
+        } else {
+            0.0
+        };
+        let mut desired_size = galley.size() + 2.0 * padding + vec2(close_width, 0.0);
+        desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+
+        let (rect, _) = ui.allocate_exact_size(desired_size, Sense::hover());
+        let response = ui.interact(rect, id, Sense::click_and_drag());
+        response.widget_info(|| {
+            WidgetInfo::selected(WidgetType::SelectableLabel, selected, galley.text())
+        });
+
+        // Interact with the close button after the tab so that it is on top:
+        let close_response = self.closable.then(|| {
+            let close_rect = Rect::from_center_size(
+                pos2(rect.right() - padding.x - close_size / 2.0, rect.center().y),
+                Vec2::splat(close_size),
+            );
+            ui.interact(close_rect, id.with("close"), Sense::click())
+                .on_hover_text("Close")
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact_selectable(&response, selected);
+            if selected || response.hovered() || response.has_focus() {
+                // Only round the top, so that the tab connects to the contents below:
+                let rounding = Rounding {
+                    nw: visuals.rounding.nw,
+                    ne: visuals.rounding.ne,
+                    sw: 0.0,
+                    se: 0.0,
+                };
+                ui.painter().rect(
+                    rect.expand(visuals.expansion),
+                    rounding,
+                    visuals.weak_bg_fill,
+                    visuals.bg_stroke,
+                );
+            }
+
+            let text_pos = pos2(
+                rect.left() + padding.x,
+                rect.center().y - 0.5 * galley.size().y,
+            );
+            ui.painter().galley(text_pos, galley, visuals.text_color());
+
+            if let Some(close_response) = &close_response {
+                let visuals = ui.style().interact(close_response);
+                let rect = close_response.rect.shrink(2.0).expand(visuals.expansion);
+                let stroke = visuals.fg_stroke;
+                ui.painter() // paints \
+                    .line_segment([rect.left_top(), rect.right_bottom()], stroke);
+                ui.painter() // paints /
+                    .line_segment([rect.right_top(), rect.left_bottom()], stroke);
+            }
+        }
+
+        let close_clicked = close_response.map_or(false, |r| r.clicked());
+        (response, close_clicked)
+    }
+}
+
+/// Returned by [`Tabs::show`].
+pub struct TabsResponse<T, R> {
+    /// Response of the whole tab bar.
+    pub bar_response: Response,
+
+    /// Index of the active tab, or `None` if there are no tabs.
+    pub active: Option<usize>,
+
+    /// The tab that was closed this frame, if any.
+    ///
+    /// It has already been removed from the tabs.
+    pub closed: Option<T>,
+
+    /// What `add_contents` returned, or `None` if there are no tabs.
+    pub inner: Option<R>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Show the tabs, returning where each tab is, and the response.
+    fn run(
+        harness: &TestHarness,
+        tabs: &mut Vec<&'static str>,
+        events: Vec<Event>,
+    ) -> (Vec<Rect>, TabsResponse<&'static str, ()>) {
+        harness.run_ui(events, |ui| {
+            let response = Tabs::new("tabs").closable(true).show(
+                ui,
+                tabs,
+                |tab| (*tab).into(),
+                |_ui, _tab| {},
+            );
+            let id = ui.make_persistent_id(Id::new("tabs"));
+            let tab_rects = (0..tabs.len())
+                .map(|i| ui.ctx().read_response(id.with(i)).unwrap().rect)
+                .collect();
+            (tab_rects, response)
+        })
+    }
+
+    #[test]
+    fn test_equal_tabs_are_different_tabs() {
+        let harness = TestHarness::new();
+        let mut tabs = vec!["a", "a", "b"];
+        let (rects, response) = run(&harness, &mut tabs, vec![]);
+        assert_eq!(response.active, Some(0));
+
+        let pos = rects[1].left_center() + vec2(4.0, 0.0);
+        run(&harness, &mut tabs, TestHarness::click_events(pos));
+        assert_eq!(run(&harness, &mut tabs, vec![]).1.active, Some(1));
+    }
+
+    #[test]
+    fn test_switch_tabs_with_keyboard() {
+        let harness = TestHarness::new();
+        let mut tabs = vec!["a", "b", "c"];
+        run(&harness, &mut tabs, vec![]);
+
+        // Tab moves the focus to the first tab, and the arrow keys to its neighbors:
+        run(&harness, &mut tabs, vec![TestHarness::key_event(Key::Tab)]);
+        assert_eq!(run(&harness, &mut tabs, vec![]).1.active, Some(0));
+        run(
+            &harness,
+            &mut tabs,
+            vec![TestHarness::key_event(Key::ArrowRight)],
+        );
+        assert_eq!(run(&harness, &mut tabs, vec![]).1.active, Some(1));
+    }
+
+    #[test]
+    fn test_close_tab() {
+        let harness = TestHarness::new();
+        let mut tabs = vec!["a", "b", "c"];
+        let (rects, _) = run(&harness, &mut tabs, vec![]);
+        let pos = rects[2].left_center() + vec2(4.0, 0.0);
+        run(&harness, &mut tabs, TestHarness::click_events(pos));
+
+        // Closing a tab before the active one keeps the same tab active:
+        let pos = rects[0].left_center() + vec2(4.0, 0.0);
+        let middle_click = vec![
+            Event::PointerMoved(pos),
+            TestHarness::button_event(pos, PointerButton::Middle, true),
+            TestHarness::button_event(pos, PointerButton::Middle, false),
+        ];
+        let (_, response) = run(&harness, &mut tabs, middle_click);
+        assert_eq!(response.closed, Some("a"));
+        assert_eq!(tabs, ["b", "c"]);
+        assert_eq!(response.active, Some(1));
+        assert_eq!(run(&harness, &mut tabs, vec![]).1.active, Some(1));
+    }
+
+    #[test]
+    fn test_reorder_tabs() {
+        let harness = TestHarness::new();
+        let mut tabs = vec!["a", "b", "c"];
+        let (rects, _) = run(&harness, &mut tabs, vec![]);
+
+        // Drag the first tab past the middle of the third one:
+        let from = rects[0].left_center() + vec2(4.0, 0.0);
+        let to = rects[2].center() + vec2(4.0, 0.0);
+        let press = TestHarness::button_event(from, PointerButton::Primary, true);
+        let release = TestHarness::button_event(to, PointerButton::Primary, false);
+        run(&harness, &mut tabs, vec![Event::PointerMoved(from)]);
+        run(&harness, &mut tabs, vec![press]);
+        for step in 1..=10 {
+            let pos = from.lerp(to, step as f32 / 10.0);
+            run(&harness, &mut tabs, vec![Event::PointerMoved(pos)]);
+        }
+        let (_, response) = run(&harness, &mut tabs, vec![release]);
+        assert_eq!(tabs, ["b", "c", "a"]);
+
+        // The dragged tab stays active:
+        assert_eq!(response.active, Some(2));
+    }
+}
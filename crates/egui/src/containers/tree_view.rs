@@ -0,0 +1,641 @@
+//! A tree of nodes that can be expanded, selected and rearranged, see [`TreeView`].
+
+use std::hash::Hash;
+
+use crate::{collapsing_header::CollapsingState, *};
+
+/// Where to put the dragged nodes of a [`TreeDrop`], relative to [`TreeDrop::target`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropPosition {
+    /// Just before the target, under the same parent.
+    Before,
+
+    /// Just after the target, under the same parent.
+    After,
+
+    /// Inside the target, which is a directory.
+    Inside,
+}
+
+/// The user dropped some nodes of a [`TreeView`] somewhere else in the tree.
+///
+/// The [`TreeView`] doesn't own the nodes, so it's up to you to move them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TreeDrop<N> {
+    /// The nodes that were dragged.
+    ///
+    /// If the user dragged a selected node, this is the whole selection.
+    pub nodes: Vec<N>,
+
+    /// The node they were dropped at.
+    pub target: N,
+
+    /// Where to put them, relative to [`Self::target`].
+    pub position: DropPosition,
+}
+
+#[derive(Clone)]
+struct TreeViewState<N> {
+    selected: Vec<N>,
+
+    /// The node that was last clicked on or moved to with the keyboard.
+    ///
+    /// Shift-clicking selects everything from here to the clicked node.
+    anchor: Option<N>,
+}
+
+impl<N> Default for TreeViewState<N> {
+    fn default() -> Self {
+        Self {
+            selected: Vec::new(),
+            anchor: None,
+        }
+    }
+}
+
+/// A visible line of the tree.
+struct Row<N> {
+    node: N,
+    parent: Option<N>,
+    depth: usize,
+
+    /// `Some(is_open)` for directories.
+    open: Option<bool>,
+
+    response: Response,
+}
+
+/// A tree of nodes, where directories can be expanded and collapsed.
+///
+/// * Click on a node to select it.
+///   With [`Self::multi_select`], also ctrl/cmd-click to add to the selection,
+///   and shift-click to select a range.
+/// * Click the arrow (or double-click the node) to expand or collapse a directory.
+/// * With the keyboard: the up and down arrows move the selection,
+///   the right arrow expands a directory (or moves into it),
+///   and the left arrow collapses it (or moves to its parent).
+/// * With [`Self::reorderable`], nodes can be dragged and dropped to rearrange the tree,
+///   see [`TreeViewResponse::dropped`].
+///
+/// You identify the nodes with any type `N` you like, e.g. a path or an index.
+/// Each node must be unique in the tree.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut open_file = None;
+/// let response = egui::TreeView::new("files").show(ui, |tree| {
+///     tree.dir("src", "src", |tree| {
+///         tree.leaf("src/lib.rs", "lib.rs");
+///         tree.leaf("src/main.rs", "main.rs");
+///     });
+///     tree.leaf("Cargo.toml", "Cargo.toml");
+/// });
+/// if response.selection_changed {
+///     open_file = response.selected.first().copied();
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct TreeView {
+    id_source: Id,
+    multi_select: bool,
+    reorderable: bool,
+    default_open: bool,
+}
+
+impl TreeView {
+    /// The id source must be unique among the siblings of the tree.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            multi_select: false,
+            reorderable: false,
+            default_open: false,
+        }
+    }
+
+    /// Allow selecting more than one node, with ctrl/cmd-click and shift-click.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Let the user drag nodes to rearrange the tree.
+    ///
+    /// The result is in [`TreeViewResponse::dropped`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Are directories expanded when they are first shown?
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    /// Show the tree.
+    ///
+    /// Add the nodes with [`TreeViewBuilder::leaf`] and [`TreeViewBuilder::dir`].
+    pub fn show<N, R>(
+        self,
+        ui: &mut Ui,
+        add_nodes: impl FnOnce(&mut TreeViewBuilder<'_, N>) -> R,
+    ) -> TreeViewResponse<N, R>
+    where
+        N: Clone + PartialEq + Hash + Send + Sync + 'static,
+    {
+        let tree_id = ui.make_persistent_id(self.id_source);
+        let mut state = ui
+            .data(|d| d.get_temp::<TreeViewState<N>>(tree_id))
+            .unwrap_or_default();
+
+        let InnerResponse {
+            inner: (inner, rows),
+            response,
+        } = ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            let mut builder = TreeViewBuilder {
+                ui,
+                tree_id,
+                selected: &state.selected,
+                reorderable: self.reorderable,
+                default_open: self.default_open,
+                depth: 0,
+                parent: None,
+                rows: Vec::new(),
+            };
+            let inner = add_nodes(&mut builder);
+            (inner, builder.rows)
+        });
+
+        // The rows are not focusable on their own, so that tab moves past the whole tree:
+        let focus_response = ui.interact(response.rect, tree_id, Sense::focusable_noninteractive());
+
+        let prev_selected = state.selected.clone();
+        self.select_with_pointer(ui, &rows, &mut state, &focus_response);
+        if focus_response.has_focus() {
+            Self::navigate_with_keyboard(ui, tree_id, &rows, &mut state);
+        }
+
+        let dropped = if self.reorderable {
+            drag_and_drop(ui, &rows, &state.selected)
+        } else {
+            None
+        };
+
+        let selection_changed = state.selected != prev_selected;
+        let selected = state.selected.clone();
+        ui.data_mut(|d| d.insert_temp(tree_id, state));
+
+        TreeViewResponse {
+            response: response | focus_response,
+            selected,
+            selection_changed,
+            dropped,
+            inner,
+        }
+    }
+
+    fn select_with_pointer<N: Clone + PartialEq>(
+        &self,
+        ui: &Ui,
+        rows: &[Row<N>],
+        state: &mut TreeViewState<N>,
+        focus_response: &Response,
+    ) {
+        let modifiers = ui.input(|i| i.modifiers);
+        for (index, row) in rows.iter().enumerate() {
+            if !row.response.clicked() {
+                continue;
+            }
+            focus_response.request_focus();
+
+            let anchor_index = state
+                .anchor
+                .as_ref()
+                .and_then(|anchor| rows.iter().position(|row| &row.node == anchor));
+
+            if let (true, true, Some(anchor_index)) =
+                (self.multi_select, modifiers.shift, anchor_index)
+            {
+                let range = anchor_index.min(index)..=anchor_index.max(index);
+                state.selected = rows[range].iter().map(|row| row.node.clone()).collect();
+            } else if self.multi_select && modifiers.command {
+                if let Some(i) = state.selected.iter().position(|n| n == &row.node) {
+                    state.selected.remove(i);
+                } else {
+                    state.selected.push(row.node.clone());
+                }
+                state.anchor = Some(row.node.clone());
+            } else {
+                state.selected = vec![row.node.clone()];
+                state.anchor = Some(row.node.clone());
+            }
+        }
+    }
+
+    fn navigate_with_keyboard<N: Clone + PartialEq + Hash>(
+        ui: &Ui,
+        tree_id: Id,
+        rows: &[Row<N>],
+        state: &mut TreeViewState<N>,
+    ) {
+        ui.memory_mut(|mem| {
+            mem.set_focus_lock_filter(
+                tree_id,
+                EventFilter {
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    ..Default::default()
+                },
+            );
+        });
+        if rows.is_empty() {
+            return;
+        }
+
+        let pressed = |key| ui.input_mut(|i| i.consume_key(Modifiers::NONE, key));
+        let current = state
+            .anchor
+            .as_ref()
+            .and_then(|anchor| rows.iter().position(|row| &row.node == anchor));
+
+        let mut next = None;
+        if pressed(Key::ArrowDown) {
+            next = Some(current.map_or(0, |i| (i + 1).min(rows.len() - 1)));
+        }
+        if pressed(Key::ArrowUp) {
+            next = Some(current.map_or(0, |i| i.saturating_sub(1)));
+        }
+        if let Some(i) = current {
+            let row = &rows[i];
+            if pressed(Key::ArrowRight) {
+                match row.open {
+                    Some(false) => set_open(ui, tree_id, &row.node, true),
+                    Some(true) => {
+                        let has_children = rows
+                            .get(i + 1)
+                            .map_or(false, |child| child.parent.as_ref() == Some(&row.node));
+                        if has_children {
+                            next = Some(i + 1);
+                        }
+                    }
+                    None => {}
+                }
+            }
+            if pressed(Key::ArrowLeft) {
+                if row.open == Some(true) {
+                    set_open(ui, tree_id, &row.node, false);
+                } else if let Some(parent) = &row.parent {
+                    next = rows.iter().position(|row| &row.node == parent);
+                }
+            }
+        }
+
+        if let Some(i) = next {
+            state.selected = vec![rows[i].node.clone()];
+            state.anchor = Some(rows[i].node.clone());
+            ui.scroll_to_rect(rows[i].response.rect, None);
+        }
+    }
+}
+
+fn collapsing_id<N: Hash>(tree_id: Id, node: &N) -> Id {
+    tree_id.with(node).with("open")
+}
+
+fn set_open<N: Hash>(ui: &Ui, tree_id: Id, node: &N, open: bool) {
+    let id = collapsing_id(tree_id, node);
+    let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, open);
+    state.set_open(open);
+    state.store(ui.ctx());
+    ui.ctx().request_repaint();
+}
+
+/// Shows where the dragged nodes would go, and returns the drop when they are released.
+fn drag_and_drop<N: Clone + PartialEq>(
+    ui: &Ui,
+    rows: &[Row<N>],
+    selected: &[N],
+) -> Option<TreeDrop<N>> {
+    let dragged_row = rows
+        .iter()
+        .find(|row| row.response.dragged() || row.response.drag_stopped())?;
+    let nodes = if selected.contains(&dragged_row.node) {
+        selected.to_vec()
+    } else {
+        vec![dragged_row.node.clone()]
+    };
+    if dragged_row.response.dragged() {
+        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+    }
+
+    let pointer = ui.input(|i| i.pointer.interact_pos())?;
+    let target = rows
+        .iter()
+        .find(|row| row.response.rect.y_range().contains(pointer.y))?;
+
+    // Can't drop a node into itself:
+    let mut ancestor = Some(&target.node);
+    while let Some(node) = ancestor {
+        if nodes.contains(node) {
+            return None;
+        }
+        ancestor = rows
+            .iter()
+            .find(|row| &row.node == node)
+            .and_then(|row| row.parent.as_ref());
+    }
+
+    let rect = target.response.rect;
+    let t = (pointer.y - rect.top()) / rect.height();
+    let position = match target.open {
+        Some(is_open) => {
+            if t < 0.25 {
+                DropPosition::Before
+            } else if t < 0.75 || is_open {
+                // After an open directory is the same as first inside it
+                DropPosition::Inside
+            } else {
+                DropPosition::After
+            }
+        }
+        None => {
+            if t < 0.5 {
+                DropPosition::Before
+            } else {
+                DropPosition::After
+            }
+        }
+    };
+
+    let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+    let left = rect.left() + target.depth as f32 * ui.spacing().indent;
+    let painter = ui.painter();
+    match position {
+        DropPosition::Before => painter.hline(left..=rect.right(), rect.top(), stroke),
+        DropPosition::After => painter.hline(left..=rect.right(), rect.bottom(), stroke),
+        DropPosition::Inside => painter.rect_stroke(rect.with_min_x(left), 2.0, stroke),
+    };
+
+    dragged_row.response.drag_stopped().then(|| TreeDrop {
+        nodes,
+        target: target.node.clone(),
+        position,
+    })
+}
+
+/// Used to add the nodes of a [`TreeView`].
+pub struct TreeViewBuilder<'a, N> {
+    ui: &'a mut Ui,
+    tree_id: Id,
+    selected: &'a [N],
+    reorderable: bool,
+    default_open: bool,
+    depth: usize,
+    parent: Option<N>,
+    rows: Vec<Row<N>>,
+}
+
+impl<'a, N: Clone + PartialEq + Hash> TreeViewBuilder<'a, N> {
+    /// Add a node without children.
+    pub fn leaf(&mut self, node: N, label: impl Into<WidgetText>) -> Response {
+        let (response, _) = self.row(node, label.into(), None);
+        response
+    }
+
+    /// Add a node with children.
+    ///
+    /// `add_children` is only called when the directory is expanded.
+    pub fn dir(
+        &mut self,
+        node: N,
+        label: impl Into<WidgetText>,
+        add_children: impl FnOnce(&mut Self),
+    ) -> Response {
+        let ctx = self.ui.ctx().clone();
+        let mut collapsing = CollapsingState::load_with_default_open(
+            &ctx,
+            collapsing_id(self.tree_id, &node),
+            self.default_open,
+        );
+
+        let (response, arrow_clicked) =
+            self.row(node.clone(), label.into(), Some(collapsing.openness(&ctx)));
+        if arrow_clicked || response.double_clicked() {
+            collapsing.toggle(self.ui);
+        }
+        collapsing.store(&ctx);
+
+        let is_open = collapsing.is_open();
+        if let Some(row) = self.rows.last_mut() {
+            row.open = Some(is_open);
+        }
+
+        if is_open {
+            let parent = std::mem::replace(&mut self.parent, Some(node));
+            self.depth += 1;
+            add_children(self);
+            self.depth -= 1;
+            self.parent = parent;
+        }
+
+        response
+    }
+
+    /// Returns the response of the row, and whether the arrow was clicked.
+    fn row(&mut self, node: N, label: WidgetText, openness: Option<f32>) -> (Response, bool) {
+        let ui = &mut *self.ui;
+        let selected = self.selected.contains(&node);
+
+        let row_height = ui.spacing().interact_size.y;
+        let (rect, _) =
+            ui.allocate_exact_size(vec2(ui.available_width(), row_height), Sense::hover());
+        let sense = Sense {
+            click: true,
+            drag: self.reorderable,
+            focusable: false,
+        };
+        let response = ui.interact(rect, self.tree_id.with(&node), sense);
+
+        let icon_width = ui.spacing().icon_width;
+        let arrow_rect = Rect::from_min_size(
+            pos2(
+                rect.left() + self.depth as f32 * ui.spacing().indent,
+                rect.center().y - 0.5 * icon_width,
+            ),
+            Vec2::splat(icon_width),
+        );
+        // The arrow is on top of the row, so it gets the clicks:
+        let arrow_response = openness.map(|openness| {
+            let arrow_response = ui.interact(
+                arrow_rect,
+                self.tree_id.with(&node).with("arrow"),
+                Sense::click(),
+            );
+            (arrow_response, openness)
+        });
+
+        let galley = label.into_galley(
+            ui,
+            Some(TextWrapMode::Truncate),
+            (rect.right() - arrow_rect.right() - ui.spacing().icon_spacing).at_least(0.0),
+            TextStyle::Button,
+        );
+        response.widget_info(|| {
+            WidgetInfo::selected(WidgetType::SelectableLabel, selected, galley.text())
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact_selectable(&response, selected);
+            if selected {
+                ui.painter()
+                    .rect_filled(rect, visuals.rounding, ui.visuals().selection.bg_fill);
+            } else if response.hovered() {
+                ui.painter()
+                    .rect_filled(rect, visuals.rounding, visuals.weak_bg_fill);
+            }
+
+            if let Some((arrow_response, openness)) = &arrow_response {
+                collapsing_header::paint_default_icon(ui, *openness, arrow_response);
+            }
+
+            let text_color = if selected {
+                ui.visuals().selection.stroke.color
+            } else {
+                visuals.text_color()
+            };
+            let text_pos = pos2(
+                arrow_rect.right() + ui.spacing().icon_spacing,
+                rect.center().y - 0.5 * galley.size().y,
+            );
+            ui.painter().galley(text_pos, galley, text_color);
+        }
+
+        self.rows.push(Row {
+            node,
+            parent: self.parent.clone(),
+            depth: self.depth,
+            open: None,
+            response: response.clone(),
+        });
+
+        let arrow_clicked = arrow_response.map_or(false, |(response, _)| response.clicked());
+        (response, arrow_clicked)
+    }
+}
+
+/// Returned by [`TreeView::show`].
+pub struct TreeViewResponse<N, R> {
+    /// Response of the whole tree.
+    pub response: Response,
+
+    /// The selected nodes.
+    pub selected: Vec<N>,
+
+    /// Did the user change the selection this frame?
+    pub selection_changed: bool,
+
+    /// The user dropped dragged nodes this frame, see [`TreeView::reorderable`].
+    pub dropped: Option<TreeDrop<N>>,
+
+    /// What `add_nodes` returned.
+    pub inner: R,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Show a `src` directory with two files, and a `Cargo.toml`.
+    ///
+    /// Returns the visible nodes with where they are, and the response.
+    fn run(
+        harness: &TestHarness,
+        events: Vec<Event>,
+    ) -> (
+        Vec<(&'static str, Rect)>,
+        TreeViewResponse<&'static str, ()>,
+    ) {
+        harness.run_ui(events, |ui| {
+            let mut rows = Vec::new();
+            let response = TreeView::new("files").show(ui, |tree| {
+                let dir = tree.dir("src", "src", |tree| {
+                    for node in ["src/lib.rs", "src/main.rs"] {
+                        rows.push((node, tree.leaf(node, node).rect));
+                    }
+                });
+                rows.insert(0, ("src", dir.rect));
+                rows.push(("Cargo.toml", tree.leaf("Cargo.toml", "Cargo.toml").rect));
+            });
+            (rows, response)
+        })
+    }
+
+    fn visible_nodes(harness: &TestHarness) -> Vec<&'static str> {
+        run(harness, vec![])
+            .0
+            .into_iter()
+            .map(|(node, _)| node)
+            .collect()
+    }
+
+    fn press(harness: &TestHarness, key: Key) -> Vec<&'static str> {
+        run(harness, vec![TestHarness::key_event(key)]);
+        run(harness, vec![]).1.selected
+    }
+
+    #[test]
+    fn test_expand_and_collapse() {
+        let harness = TestHarness::new();
+        let (rows, _) = run(&harness, vec![]);
+        assert_eq!(visible_nodes(&harness), ["src", "Cargo.toml"]);
+
+        let arrow =
+            rows[0].1.left_center() + vec2(0.5 * harness.ctx.style().spacing.icon_width, 0.0);
+        run(&harness, TestHarness::click_events(arrow));
+        assert_eq!(
+            visible_nodes(&harness),
+            ["src", "src/lib.rs", "src/main.rs", "Cargo.toml"]
+        );
+
+        run(&harness, TestHarness::click_events(arrow));
+        assert_eq!(visible_nodes(&harness), ["src", "Cargo.toml"]);
+    }
+
+    #[test]
+    fn test_select_with_arrow_keys() {
+        let harness = TestHarness::new();
+        let (rows, _) = run(&harness, vec![]);
+        run(&harness, TestHarness::click_events(rows[0].1.center()));
+        assert_eq!(run(&harness, vec![]).1.selected, ["src"]);
+
+        assert_eq!(press(&harness, Key::ArrowDown), ["Cargo.toml"]);
+        assert_eq!(press(&harness, Key::ArrowDown), ["Cargo.toml"]);
+        assert_eq!(press(&harness, Key::ArrowUp), ["src"]);
+
+        // Right expands the directory, and then moves into it:
+        assert_eq!(press(&harness, Key::ArrowRight), ["src"]);
+        assert_eq!(
+            visible_nodes(&harness),
+            ["src", "src/lib.rs", "src/main.rs", "Cargo.toml"]
+        );
+        assert_eq!(press(&harness, Key::ArrowRight), ["src/lib.rs"]);
+        assert_eq!(press(&harness, Key::ArrowDown), ["src/main.rs"]);
+
+        // Left moves to the parent, and then collapses it:
+        assert_eq!(press(&harness, Key::ArrowLeft), ["src"]);
+        assert_eq!(press(&harness, Key::ArrowLeft), ["src"]);
+        assert_eq!(visible_nodes(&harness), ["src", "Cargo.toml"]);
+    }
+}
@@ -41,6 +41,13 @@ pub struct Window<'open> {
     default_open: bool,
     with_title_bar: bool,
     fade_out: bool,
+    scale_animation: bool,
+    opacity: f32,
+    modal: bool,
+    minimizable: bool,
+    maximizable: bool,
+    state: Option<&'open mut WindowState>,
+    title_bar_ui: Option<Box<dyn FnOnce(&mut Ui) + 'open>>,
 }
 
 impl<'open> Window<'open> {
@@ -64,6 +71,13 @@ impl<'open> Window<'open> {
             default_open: true,
             with_title_bar: true,
             fade_out: true,
+            scale_animation: false,
+            opacity: 1.0,
+            modal: false,
+            minimizable: false,
+            maximizable: false,
+            state: None,
+            title_bar_ui: None,
         }
     }
 
@@ -74,6 +88,25 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// The layer of this window, for use with e.g. [`Context::move_to_top`],
+    /// [`Context::move_to_bottom`] and [`Context::set_always_on_top`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let object_was_selected = true;
+    /// let inspector = egui::Window::new("Inspector");
+    /// if object_was_selected {
+    ///     ctx.move_to_top(inspector.layer_id());
+    /// }
+    /// inspector.show(ctx, |ui| {
+    ///     ui.label("Properties of the selected object");
+    /// });
+    /// # });
+    /// ```
+    pub fn layer_id(&self) -> LayerId {
+        self.area.layer()
+    }
+
     /// Call this to add a close-button to the window title bar.
     ///
     /// * If `*open == false`, the window will not be visible.
@@ -133,6 +166,105 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// If `true`, the `Window` grows into place when it appears and shrinks away when it closes.
+    ///
+    /// Like [`Self::fade_out`], the closing animation only works if you use [`Self::open`] to close the window.
+    ///
+    /// Turned off for everyone with [`crate::Style::reduced_motion`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn scale_animation(mut self, scale_animation: bool) -> Self {
+        self.scale_animation = scale_animation;
+        self
+    }
+
+    /// Make the whole `Window` (frame and contents) semi-transparent.
+    ///
+    /// `0.0` is invisible, `1.0` is fully opaque.
+    ///
+    /// Default: `1.0`.
+    #[inline]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Make this a modal window.
+    ///
+    /// A modal window dims everything behind it and blocks all pointer input to it.
+    /// Keyboard focus is kept inside the window, so Tab only cycles between its widgets.
+    /// Pressing Escape closes the window, if you use [`Self::open`].
+    ///
+    /// The window is kept on top of all other windows of the same [`Order`].
+    /// Popups and menus opened from it are still shown above it,
+    /// as long as the window is in [`Order::Middle`] (the default).
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut open = true;
+    /// egui::Window::new("Are you sure?")
+    ///     .modal(true)
+    ///     .open(&mut open)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("This cannot be undone.");
+    ///     });
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    /// Show a button in the title bar that minimizes the window.
+    ///
+    /// A minimized window is shrunk to its title bar, and docked at the bottom of [`Context::available_rect`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn minimizable(mut self, minimizable: bool) -> Self {
+        self.minimizable = minimizable;
+        self
+    }
+
+    /// Show a button in the title bar that maximizes the window.
+    ///
+    /// A maximized window fills [`Context::available_rect`].
+    /// When restored, it gets back the position and size it had before.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn maximizable(mut self, maximizable: bool) -> Self {
+        self.maximizable = maximizable;
+        self
+    }
+
+    /// Control whether the window is minimized, maximized or neither.
+    ///
+    /// The minimize, maximize and restore buttons will write to this.
+    /// If you don't call this, the state is kept in [`crate::Memory`] instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut state = egui::WindowState::Normal;
+    /// egui::Window::new("Inspector")
+    ///     .minimizable(true)
+    ///     .maximizable(true)
+    ///     .state(&mut state)
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Contents");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn state(mut self, state: &'open mut WindowState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
     /// Usage: `Window::new(…).mutate(|w| w.resize = w.resize.auto_expand_width(true))`
     // TODO(emilk): I'm not sure this is a good interface for this.
     #[inline]
@@ -231,6 +363,28 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Keep the window out of the parts of the screen covered by display cutouts,
+    /// rounded corners and the like.
+    ///
+    /// See [`Area::respect_safe_area`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn respect_safe_area(mut self, respect_safe_area: bool) -> Self {
+        self.area = self.area.respect_safe_area(respect_safe_area);
+        self
+    }
+
+    /// When dragged, snap the edges of the window to the edges of the screen,
+    /// the panels, and other windows that are closer than this many points.
+    ///
+    /// Default: `0.0` (no snapping).
+    #[inline]
+    pub fn snap_distance(mut self, snap_distance: f32) -> Self {
+        self.area = self.area.snap_distance(snap_distance);
+        self
+    }
+
     /// Constrain the movement of the window to the given rectangle.
     ///
     /// For instance: `.constrain_to(ctx.screen_rect())`.
@@ -346,6 +500,31 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Add your own widgets to the title bar, next to the close, maximize and minimize buttons.
+    ///
+    /// The widgets are laid out from right to left, so add the one closest to the buttons first.
+    /// Dragging the rest of the title bar still moves the window.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut search = String::new();
+    /// egui::Window::new("Log")
+    ///     .title_bar_ui(|ui| {
+    ///         ui.add(egui::TextEdit::singleline(&mut search).desired_width(80.0));
+    ///         ui.label(egui::RichText::new("⏺").color(egui::Color32::GREEN))
+    ///             .on_hover_text("Connected");
+    ///     })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Log messages");
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn title_bar_ui(mut self, add_contents: impl FnOnce(&mut Ui) + 'open) -> Self {
+        self.title_bar_ui = Some(Box::new(add_contents));
+        self
+    }
+
     /// Not resizable, just takes the size of its contents.
     /// Also disabled scrolling.
     /// Text will not wrap, but will instead make your window width expand.
@@ -416,7 +595,7 @@ impl<'open> Window<'open> {
     ) -> Option<InnerResponse<Option<R>>> {
         let Window {
             title,
-            open,
+            mut open,
             area,
             frame,
             resize,
@@ -425,8 +604,24 @@ impl<'open> Window<'open> {
             default_open,
             with_title_bar,
             fade_out,
+            scale_animation,
+            opacity: window_opacity,
+            modal,
+            minimizable,
+            maximizable,
+            mut state,
+            title_bar_ui,
         } = self;
 
+        if let Some(open) = open.as_deref_mut().filter(|open| modal && **open) {
+            // Let an open popup (e.g. a `ComboBox`) in the modal take the Escape first:
+            if !ctx.memory(|mem| mem.any_popup_open())
+                && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape))
+            {
+                *open = false;
+            }
+        }
+
         let header_color =
             frame.map_or_else(|| ctx.style().visuals.widgets.open.weak_bg_fill, |f| f.fill);
         let mut window_frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
@@ -438,29 +633,37 @@ impl<'open> Window<'open> {
 
         let is_explicitly_closed = matches!(open, Some(false));
         let is_open = !is_explicitly_closed || ctx.memory(|mem| mem.everything_is_visible());
-        let opacity = ctx.animate_bool_with_easing(
-            area.id.with("fade-out"),
-            is_open,
-            emath::easing::cubic_out,
-        );
+        let reduced_motion = ctx.style().reduced_motion;
+        let opacity = if reduced_motion {
+            // Close right away:
+            ctx.animate_bool_with_time(area.id.with("fade-out"), is_open, 0.0)
+        } else {
+            ctx.animate_bool_with_easing(
+                area.id.with("fade-out"),
+                is_open,
+                emath::easing::cubic_out,
+            )
+        };
         if opacity <= 0.0 {
             return None;
         }
 
         let area_id = area.id;
         let area_layer_id = area.layer();
-        let resize_id = area_id.with("resize");
-        let mut collapsing =
-            CollapsingState::load_with_default_open(ctx, area_id.with("collapsing"), default_open);
 
-        let is_collapsed = with_title_bar && !collapsing.is_open();
-        let possible = PossibleInteractions::new(&area, &resize, is_collapsed);
+        let scale = if scale_animation && !reduced_motion {
+            open_animation_scale(ctx, area_id, area_layer_id, is_open)
+        } else {
+            1.0
+        };
 
-        let resize = resize.resizable(false); // We resize it manually
-        let mut resize = resize.id(resize_id);
+        if modal {
+            show_modal_backdrop(ctx, area_layer_id, opacity);
+        }
 
-        let on_top = Some(area_layer_id) == ctx.top_layer_id();
-        let mut area = area.begin(ctx);
+        let resize_id = area_id.with("resize");
+        let mut collapsing =
+            CollapsingState::load_with_default_open(ctx, area_id.with("collapsing"), default_open);
 
         // Calculate roughly how much larger the window size is compared to the inner rect
         let (title_bar_height, title_content_spacing) = if with_title_bar {
@@ -474,6 +677,62 @@ impl<'open> Window<'open> {
             (0.0, 0.0)
         };
 
+        let state_id = area_id.with("window_state");
+        let mut stored_state = StoredWindowState::load(ctx, state_id);
+        let window_state = if with_title_bar {
+            state.as_deref().copied().unwrap_or(stored_state.requested)
+        } else {
+            WindowState::Normal
+        };
+        let restore = stored_state.transition_to(ctx, window_state, area_id, resize_id);
+
+        let mut area = area;
+        let mut resize = resize;
+        match window_state {
+            WindowState::Normal => {}
+            WindowState::Minimized => {
+                let available_rect = ctx.available_rect();
+                let last_height = AreaState::load(ctx, area_id).map_or(
+                    title_bar_height
+                        + window_frame.inner_margin.sum().y
+                        + window_frame.outer_margin.sum().y,
+                    |state| state.size.y,
+                );
+                let x = minimized_slot(ctx, area_id, available_rect.left());
+                area = area
+                    .no_anchor()
+                    .pivot(Align2::LEFT_TOP)
+                    .fixed_pos(pos2(x, available_rect.bottom() - last_height));
+            }
+            WindowState::Maximized => {
+                let available_rect = ctx.available_rect();
+                let inner_size = available_rect.size()
+                    - window_frame.outer_margin.sum()
+                    - window_frame.inner_margin.sum()
+                    - vec2(0.0, title_bar_height);
+                area = area
+                    .no_anchor()
+                    .pivot(Align2::LEFT_TOP)
+                    .fixed_pos(available_rect.min);
+                resize = resize.fixed_size(inner_size.max(Vec2::ZERO));
+            }
+        }
+
+        let is_minimized = window_state == WindowState::Minimized;
+        let is_collapsed = is_minimized || (with_title_bar && !collapsing.is_open());
+        let possible = PossibleInteractions::new(&area, &resize, is_collapsed);
+
+        let resize = resize.resizable(false); // We resize it manually
+        let mut resize = resize.id(resize_id);
+
+        let on_top = Some(area_layer_id) == ctx.top_layer_id();
+        let mut area = area.begin(ctx);
+        ctx.register_layer_name(area_layer_id, || title.text().to_owned());
+
+        if let Some(restore_pos) = restore {
+            area.state_mut().set_left_top_pos(restore_pos);
+        }
+
         {
             // Prevent window from becoming larger than the constrain rect.
             let constrain_rect = area.constrain_rect();
@@ -508,6 +767,7 @@ impl<'open> Window<'open> {
         } else if fade_out {
             area_content_ui.multiply_opacity(opacity);
         }
+        area_content_ui.multiply_opacity(window_opacity);
 
         let content_inner = {
             // BEGIN FRAME --------------------------------
@@ -523,13 +783,29 @@ impl<'open> Window<'open> {
             // Use title bar spacing as the item spacing before the content
             frame.content_ui.spacing_mut().item_spacing.y = title_content_spacing;
 
+            let title_bar_buttons = TitleBarButtons {
+                close: show_close_button,
+                minimize: minimizable,
+                maximize: maximizable,
+            };
+
+            // We only know how wide the custom title bar widgets are after showing them:
+            let title_bar_ui_width_id = area_id.with("title_bar_ui_width");
+            let title_bar_ui_width = if title_bar_ui.is_some() {
+                ctx.data(|data| data.get_temp::<f32>(title_bar_ui_width_id))
+                    .unwrap_or_default()
+            } else {
+                0.0
+            };
+
             let title_bar = if with_title_bar {
                 let title_bar = show_title_bar(
                     &mut frame.content_ui,
                     title,
-                    show_close_button,
+                    title_bar_buttons,
+                    title_bar_ui_width,
                     &mut collapsing,
-                    collapsible,
+                    collapsible && !is_minimized,
                 );
                 resize.min_size.x = resize.min_size.x.at_least(title_bar.rect.width()); // Prevent making window smaller than title bar width
                 Some(title_bar)
@@ -540,20 +816,27 @@ impl<'open> Window<'open> {
             // Remove item spacing after the title bar
             frame.content_ui.spacing_mut().item_spacing.y = 0.0;
 
-            let (content_inner, mut content_response) = collapsing
-                .show_body_unindented(&mut frame.content_ui, |ui| {
-                    // Restore item spacing for the content
-                    ui.spacing_mut().item_spacing.y = item_spacing.y;
-
-                    resize.show(ui, |ui| {
-                        if scroll.is_any_scroll_enabled() {
-                            scroll.show(ui, add_contents).inner
-                        } else {
-                            add_contents(ui)
-                        }
+            let (content_inner, mut content_response) = if is_minimized {
+                (None, None)
+            } else {
+                collapsing
+                    .show_body_unindented(&mut frame.content_ui, |ui| {
+                        // Restore item spacing for the content
+                        ui.spacing_mut().item_spacing.y = item_spacing.y;
+
+                        resize.show(ui, |ui| {
+                            if window_state == WindowState::Maximized {
+                                ui.set_min_size(ui.max_rect().size());
+                            }
+                            if scroll.is_any_scroll_enabled() {
+                                scroll.show(ui, add_contents).inner
+                            } else {
+                                add_contents(ui)
+                            }
+                        })
                     })
-                })
-                .map_or((None, None), |ir| (Some(ir.inner), Some(ir.response)));
+                    .map_or((None, None), |ir| (Some(ir.inner), Some(ir.response)))
+            };
 
             let outer_rect = frame.end(&mut area_content_ui).rect;
             paint_resize_corner(
@@ -599,17 +882,33 @@ impl<'open> Window<'open> {
                     response.rect.min.y = outer_rect.min.y + title_bar_height + border_padding;
                 }
 
-                title_bar.ui(
+                let (requested_state, new_title_bar_ui_width) = title_bar.ui(
                     &mut area_content_ui,
                     title_rect,
                     &content_response,
                     open,
+                    title_bar_buttons,
+                    title_bar_ui,
+                    window_state,
                     &mut collapsing,
-                    collapsible,
+                    collapsible && !is_minimized,
                 );
+                if new_title_bar_ui_width != title_bar_ui_width {
+                    ctx.data_mut(|data| {
+                        data.insert_temp(title_bar_ui_width_id, new_title_bar_ui_width);
+                    });
+                    ctx.request_repaint();
+                }
+                if let Some(requested_state) = requested_state {
+                    stored_state.requested = requested_state;
+                    if let Some(state) = &mut state {
+                        **state = requested_state;
+                    }
+                }
             }
 
             collapsing.store(ctx);
+            stored_state.store(ctx, state_id);
 
             paint_frame_interaction(&area_content_ui, outer_rect, resize_interaction);
 
@@ -618,6 +917,15 @@ impl<'open> Window<'open> {
 
         let full_response = area.end(ctx, area_content_ui);
 
+        if scale != 1.0 {
+            // Scale around the center of the window. This only affects the painting, not the input.
+            let center = full_response.rect.center().to_vec2();
+            let transform = emath::TSTransform::from_translation(center)
+                * emath::TSTransform::from_scaling(scale)
+                * emath::TSTransform::from_translation(-center);
+            ctx.transform_layer_shapes(area_layer_id, transform);
+        }
+
         let inner_response = InnerResponse {
             inner: content_inner,
             response: full_response,
@@ -626,6 +934,139 @@ impl<'open> Window<'open> {
     }
 }
 
+/// How much to scale a window that is opening or closing.
+///
+/// Goes from a bit smaller than `1.0` when the window appears (or has closed) to `1.0` when it is fully open.
+fn open_animation_scale(ctx: &Context, area_id: Id, layer_id: LayerId, is_open: bool) -> f32 {
+    const MIN_SCALE: f32 = 0.9;
+
+    let id = area_id.with("open-animation");
+    let animation_time = ctx.style().animation_time;
+
+    let just_appeared = is_open && !ctx.memory(|mem| mem.areas().visible_last_frame(&layer_id));
+    if just_appeared {
+        // Forget any earlier animation, and start the opening animation from the beginning:
+        ctx.animate_value_with_time(id, 0.0, 0.0);
+        ctx.animate_value_with_time(id, 0.0, animation_time);
+    }
+
+    let t = ctx.animate_value_with_time(id, if is_open { 1.0 } else { 0.0 }, animation_time);
+    emath::lerp(MIN_SCALE..=1.0, emath::easing::cubic_out(t))
+}
+
+/// Whether a [`Window`] is minimized, maximized or neither.
+///
+/// See [`Window::state`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WindowState {
+    /// Positioned and sized as usual.
+    #[default]
+    Normal,
+
+    /// Only the title bar is shown, docked at the bottom of the available rect.
+    Minimized,
+
+    /// Fills the available rect.
+    Maximized,
+}
+
+/// What [`WindowState`] a window is in, and how to restore it.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct StoredWindowState {
+    /// What the title bar buttons asked for (used if [`Window::state`] isn't set).
+    requested: WindowState,
+
+    /// What the window was shown as last frame.
+    shown: WindowState,
+
+    /// Position and inner size of the window before it was minimized or maximized.
+    restore: Option<(Pos2, Vec2)>,
+}
+
+impl StoredWindowState {
+    fn load(ctx: &Context, id: Id) -> Self {
+        ctx.data_mut(|d| d.get_persisted(id)).unwrap_or_default()
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+
+    /// Remember or restore the normal position and size when changing state.
+    ///
+    /// Returns the position to restore the window to, if any.
+    fn transition_to(
+        &mut self,
+        ctx: &Context,
+        state: WindowState,
+        area_id: Id,
+        resize_id: Id,
+    ) -> Option<Pos2> {
+        if state == self.shown {
+            return None;
+        }
+
+        if self.shown == WindowState::Normal {
+            let pos = AreaState::load(ctx, area_id).map(|state| state.left_top_pos());
+            let size = resize::State::load(ctx, resize_id).map(|state| state.desired_size);
+            self.restore = pos.zip(size);
+        }
+        self.shown = state;
+
+        if state != WindowState::Normal {
+            return None;
+        }
+        let (pos, size) = self.restore.take()?;
+        if let Some(mut resize_state) = resize::State::load(ctx, resize_id) {
+            resize_state.requested_size = Some(size);
+            resize_state.store(ctx, resize_id);
+        }
+        Some(pos)
+    }
+}
+
+/// The x coordinate for the next minimized window this frame.
+fn minimized_slot(ctx: &Context, area_id: Id, left: f32) -> f32 {
+    let slots_id = Id::new("__egui_minimized_windows");
+    let frame_nr = ctx.frame_nr();
+    let width = AreaState::load(ctx, area_id).map_or(0.0, |state| state.size.x);
+    let spacing = ctx.style().spacing.item_spacing.x;
+    ctx.data_mut(|d| {
+        let (slot_frame_nr, next_x) = d.get_temp_mut_or_insert_with(slots_id, || (frame_nr, left));
+        if *slot_frame_nr != frame_nr {
+            *slot_frame_nr = frame_nr;
+            *next_x = left;
+        }
+        let x = *next_x;
+        *next_x += width + spacing;
+        x
+    })
+}
+
+/// Dim everything behind a modal window, and swallow all pointer input to it.
+fn show_modal_backdrop(ctx: &Context, modal_layer: LayerId, opacity: f32) {
+    let screen_rect = ctx.screen_rect();
+    let backdrop = Area::new(modal_layer.id.with("modal_backdrop"))
+        .order(modal_layer.order)
+        .fixed_pos(screen_rect.min)
+        .constrain(false)
+        .fade_in(false)
+        .show(ctx, |ui| {
+            let (rect, _response) =
+                ui.allocate_exact_size(screen_rect.size(), Sense::click_and_drag());
+            let fill = Color32::from_black_alpha(100).gamma_multiply(opacity);
+            ui.painter().rect_filled(rect, 0.0, fill);
+        });
+
+    ctx.memory_mut(|mem| {
+        mem.areas_mut()
+            .keep_modal_on_top(backdrop.response.layer_id, modal_layer);
+        mem.set_modal_layer(modal_layer);
+    });
+}
+
 fn paint_resize_corner(
     ui: &Ui,
     possible: &PossibleInteractions,
@@ -1036,10 +1477,25 @@ struct TitleBar {
     rect: Rect,
 }
 
+/// Which buttons to show on the right side of the title bar.
+#[derive(Clone, Copy)]
+struct TitleBarButtons {
+    close: bool,
+    minimize: bool,
+    maximize: bool,
+}
+
+impl TitleBarButtons {
+    fn count(self) -> usize {
+        usize::from(self.close) + usize::from(self.minimize) + usize::from(self.maximize)
+    }
+}
+
 fn show_title_bar(
     ui: &mut Ui,
     title: WidgetText,
-    show_close_button: bool,
+    buttons: TitleBarButtons,
+    title_bar_ui_width: f32,
     collapsing: &mut CollapsingState,
     collapsible: bool,
 ) -> TitleBar {
@@ -1066,9 +1522,16 @@ fn show_title_bar(
             TextStyle::Heading,
         );
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title is centered):
-            2.0 * (pad + button_size.x + item_spacing.x) + title_galley.size().x
+        let num_buttons = buttons.count().max(usize::from(collapsible));
+        let title_bar_ui_width = if 0.0 < title_bar_ui_width {
+            title_bar_ui_width + item_spacing.x
+        } else {
+            0.0
+        };
+        let minimum_width = if 0 < num_buttons || 0.0 < title_bar_ui_width {
+            // If at least one button is shown we make room for buttons on both sides (since title is centered):
+            2.0 * (pad + num_buttons as f32 * (button_size.x + item_spacing.x) + title_bar_ui_width)
+                + title_galley.size().x
         } else {
             pad + title_galley.size().x + pad
         };
@@ -1100,34 +1563,96 @@ impl TitleBar {
     ///   a result of rendering the window content
     /// - `open`: if `None`, no "Close" button will be rendered, otherwise renders and processes
     ///   the "Close" button and writes a `false` if window was closed
+    /// - `buttons`: which of the minimize and maximize buttons to show
+    /// - `title_bar_ui`: custom widgets to show to the left of the buttons
+    /// - `window_state`: the current state, which decides if minimize/maximize or restore buttons are shown
     /// - `collapsing`: holds the current expanding state. Can be changed by double click on the
     ///   title if `collapsible` is `true`
     /// - `collapsible`: if `true`, double click on the title bar will be handled for a change
     ///   of `collapsing` state
+    ///
+    /// Returns the new [`WindowState`] if the user clicked the minimize, maximize or restore button,
+    /// and the width of the custom widgets.
+    #[allow(clippy::too_many_arguments)]
     fn ui(
         mut self,
         ui: &mut Ui,
         outer_rect: Rect,
         content_response: &Option<Response>,
         open: Option<&mut bool>,
+        buttons: TitleBarButtons,
+        title_bar_ui: Option<Box<dyn FnOnce(&mut Ui) + '_>>,
+        window_state: WindowState,
         collapsing: &mut CollapsingState,
         collapsible: bool,
-    ) {
+    ) -> (Option<WindowState>, f32) {
         if let Some(content_response) = &content_response {
             // Now we know how large we got to be:
             self.rect.max.x = self.rect.max.x.max(content_response.rect.max.x);
         }
 
+        // Add the buttons now that we know our full width, from the right:
+        let mut button_index = 0;
         if let Some(open) = open {
-            // Add close button now that we know our full width:
-            if self.close_button_ui(ui).clicked() {
+            if close_button(ui, self.button_rect(ui, button_index)).clicked() {
                 *open = false;
             }
+            button_index += 1;
+        }
+
+        let mut requested_state = None;
+        if buttons.maximize {
+            let rect = self.button_rect(ui, button_index);
+            let (icon, target) = if window_state == WindowState::Maximized {
+                (WindowButtonIcon::Restore, WindowState::Normal)
+            } else {
+                (WindowButtonIcon::Maximize, WindowState::Maximized)
+            };
+            if window_button(ui, rect, icon).clicked() {
+                requested_state = Some(target);
+            }
+            button_index += 1;
+        }
+        if buttons.minimize {
+            let rect = self.button_rect(ui, button_index);
+            let (icon, target) = if window_state == WindowState::Minimized {
+                (WindowButtonIcon::Restore, WindowState::Normal)
+            } else {
+                (WindowButtonIcon::Minimize, WindowState::Minimized)
+            };
+            if window_button(ui, rect, icon).clicked() {
+                requested_state = Some(target);
+            }
+            button_index += 1;
+        }
+
+        let mut title_bar_ui_rect = None;
+        if let Some(add_contents) = title_bar_ui {
+            let right = if 0 < button_index {
+                self.button_rect(ui, button_index - 1).left() - ui.spacing().item_spacing.x
+            } else {
+                self.rect.right() - ui.spacing().item_spacing.x
+            };
+            let max_rect = Rect::from_x_y_ranges(self.rect.left()..=right, self.rect.y_range());
+            let mut child_ui = ui.child_ui_with_id_source(
+                max_rect,
+                Layout::right_to_left(Align::Center),
+                "title_bar_ui",
+                None,
+            );
+            add_contents(&mut child_ui);
+            title_bar_ui_rect = Some(child_ui.min_rect());
         }
 
         let full_top_rect = Rect::from_x_y_ranges(self.rect.x_range(), self.min_rect.y_range());
-        let text_pos =
+        let mut text_pos =
             emath::align::center_size_in_rect(self.title_galley.size(), full_top_rect).left_top();
+        if let Some(title_bar_ui_rect) = title_bar_ui_rect {
+            // Don't paint the title under the custom widgets:
+            let max_x =
+                title_bar_ui_rect.left() - ui.spacing().item_spacing.x - self.title_galley.size().x;
+            text_pos.x = text_pos.x.min(max_x);
+        }
         let text_pos = text_pos - self.title_galley.rect.min.to_vec2();
         let text_pos = text_pos - 1.5 * Vec2::Y; // HACK: center on x-height of text (looks better)
         ui.painter().galley(
@@ -1148,7 +1673,11 @@ impl TitleBar {
         }
 
         // Don't cover the close- and collapse buttons:
-        let double_click_rect = self.rect.shrink2(vec2(32.0, 0.0));
+        let mut double_click_rect = self.rect.shrink2(vec2(32.0, 0.0));
+        if let Some(title_bar_ui_rect) = title_bar_ui_rect {
+            // …nor the custom widgets:
+            double_click_rect.max.x = double_click_rect.max.x.min(title_bar_ui_rect.left());
+        }
 
         if ui
             .interact(double_click_rect, self.id, Sense::click())
@@ -1157,25 +1686,27 @@ impl TitleBar {
         {
             collapsing.toggle(ui);
         }
+
+        let title_bar_ui_width = title_bar_ui_rect.map_or(0.0, |rect| rect.width());
+        (requested_state, title_bar_ui_width)
     }
 
-    /// Paints the "Close" button at the right side of the title bar
-    /// and processes clicks on it.
+    /// The rectangle of a button at the right side of the title bar,
+    /// with index `0` being the rightmost one.
     ///
     /// The button is square and its size is determined by the
     /// [`crate::style::Spacing::icon_width`] setting.
-    fn close_button_ui(&self, ui: &mut Ui) -> Response {
+    fn button_rect(&self, ui: &Ui, index: usize) -> Rect {
         let button_size = Vec2::splat(ui.spacing().icon_width);
         let pad = (self.rect.height() - button_size.y) / 2.0; // calculated so that the icon is on the diagonal (if window padding is symmetrical)
-        let button_rect = Rect::from_min_size(
+        let offset = index as f32 * (button_size.x + ui.spacing().item_spacing.x);
+        Rect::from_min_size(
             pos2(
-                self.rect.right() - pad - button_size.x,
+                self.rect.right() - pad - button_size.x - offset,
                 self.rect.center().y - 0.5 * button_size.y,
             ),
             button_size,
-        );
-
-        close_button(ui, button_rect)
+        )
     }
 }
 
@@ -1203,3 +1734,90 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[derive(Clone, Copy)]
+enum WindowButtonIcon {
+    Minimize,
+    Maximize,
+    Restore,
+}
+
+/// Paints a minimize, maximize or restore button of the window and processes clicks on it.
+fn window_button(ui: &mut Ui, rect: Rect, icon: WindowButtonIcon) -> Response {
+    let id = ui.auto_id_with(match icon {
+        WindowButtonIcon::Minimize => "window_minimize_button",
+        WindowButtonIcon::Maximize => "window_maximize_button",
+        WindowButtonIcon::Restore => "window_restore_button",
+    });
+    let response = ui.interact(rect, id, Sense::click());
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    let stroke = visuals.fg_stroke;
+    let painter = ui.painter();
+    match icon {
+        WindowButtonIcon::Minimize => {
+            painter.hline(rect.x_range(), rect.bottom(), stroke);
+        }
+        WindowButtonIcon::Maximize => {
+            painter.rect_stroke(rect, 0.0, stroke);
+        }
+        WindowButtonIcon::Restore => {
+            let offset = 0.25 * rect.width();
+            let back =
+                Rect::from_min_max(rect.min + vec2(offset, 0.0), rect.max - vec2(0.0, offset));
+            let front = back.translate(vec2(-offset, offset));
+            painter.rect_stroke(back, 0.0, stroke);
+            painter.rect_filled(front, 0.0, ui.visuals().window_fill);
+            painter.rect_stroke(front, 0.0, stroke);
+        }
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maximize_and_restore() {
+        let harness = TestHarness::new();
+        let screen_rect = Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0));
+        let input = || RawInput {
+            screen_rect: Some(screen_rect),
+            ..Default::default()
+        };
+
+        let mut state = WindowState::Normal;
+        let run = |state: &mut WindowState| {
+            let mut window_rect = Rect::NOTHING;
+            for _ in 0..3 {
+                (window_rect, _) = harness.run_input(input(), |ctx| {
+                    Window::new("Test")
+                        .default_rect(Rect::from_min_size(pos2(100.0, 100.0), vec2(200.0, 100.0)))
+                        .maximizable(true)
+                        .state(state)
+                        .show(ctx, |ui| ui.label("Contents"))
+                        .unwrap()
+                        .response
+                        .rect
+                });
+            }
+            window_rect
+        };
+
+        let normal_rect = run(&mut state);
+        assert!(normal_rect.width() < screen_rect.width());
+
+        state = WindowState::Maximized;
+        let maximized_rect = run(&mut state);
+        assert_eq!(maximized_rect.min, screen_rect.min);
+        assert!((maximized_rect.width() - screen_rect.width()).abs() < 1.0);
+
+        state = WindowState::Normal;
+        let restored_rect = run(&mut state);
+        assert!((restored_rect.min - normal_rect.min).length() < 1.0);
+        assert!((restored_rect.size() - normal_rect.size()).length() < 1.0);
+    }
+}
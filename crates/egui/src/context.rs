@@ -12,7 +12,7 @@ use crate::{
     data::output::PlatformOutput,
     frame_state::FrameState,
     input_state::*,
-    layers::GraphicLayers,
+    layers::{GraphicLayers, LayerInspection, LayerStats},
     load::{Bytes, Loaders, SizedTexture},
     memory::Options,
     os::OperatingSystem,
@@ -73,6 +73,9 @@ impl Default for WrappedTextureManager {
 /// Generic event callback.
 pub type ContextCallback = Arc<dyn Fn(&Context) + Send + Sync>;
 
+/// See [`Context::set_open_url_handler`].
+type OpenUrlHandler = Arc<dyn Fn(&Context, &crate::OpenUrl) -> bool + Send + Sync>;
+
 #[derive(Clone)]
 struct NamedContextCallback {
     debug_name: &'static str,
@@ -250,6 +253,10 @@ pub struct ViewportState {
     // The output of a frame:
     //
     pub graphics: GraphicLayers,
+
+    /// Used by [`Context::layers_ui`].
+    pub(crate) layer_inspection: LayerInspection,
+
     // Most of the things in `PlatformOutput` are not actually viewport dependent.
     pub output: PlatformOutput,
     pub commands: Vec<ViewportCommand>,
@@ -362,6 +369,9 @@ struct ContextImpl {
 
     plugins: Plugins,
 
+    /// See [`Context::set_open_url_handler`].
+    open_url_handler: Option<OpenUrlHandler>,
+
     /// All viewports share the same texture manager and texture namespace.
     ///
     /// In all viewports, [`TextureId::default`] is special, and points to the font atlas.
@@ -468,7 +478,13 @@ impl ContextImpl {
                 }
             });
 
-            viewport.hits = if let Some(pos) = viewport.input.pointer.interact_pos() {
+            let pointer = &viewport.input.pointer;
+            let proximity_pos = if self.memory.options.style.interaction.proximity_hover {
+                pointer.proximity_pos()
+            } else {
+                None
+            };
+            viewport.hits = if let Some(pos) = pointer.interact_pos().or(proximity_pos) {
                 let interact_radius = self.memory.options.style.interaction.interact_radius;
 
                 crate::hit_test::hit_test(
@@ -528,7 +544,12 @@ impl ContextImpl {
 
         let input = &self.viewport().input;
         let pixels_per_point = input.pixels_per_point();
-        let max_texture_side = input.max_texture_side;
+        let max_texture_side = input.max_texture_side.min(
+            self.memory
+                .options
+                .max_font_atlas_side
+                .map_or(usize::MAX, |side| side.max(epaint::TextureAtlas::MIN_SIDE)),
+        );
 
         if let Some(font_definitions) = self.memory.new_font_definitions.take() {
             // New font definition loaded, so we need to reload all fonts.
@@ -559,6 +580,7 @@ impl ContextImpl {
         {
             crate::profile_scope!("Fonts::begin_frame");
             fonts.begin_frame(pixels_per_point, max_texture_side);
+            fonts.set_max_cached_galleys(self.memory.options.max_cached_galleys);
         }
 
         if is_new && self.memory.options.preload_font_glyphs {
@@ -706,6 +728,9 @@ impl Default for Context {
 
         // Register built-in plugins:
         crate::debug_text::register(&ctx);
+        crate::help_mode::register(&ctx);
+        crate::documents::register(&ctx);
+        crate::toasts::register(&ctx);
         crate::text_selection::LabelSelectionState::register(&ctx);
         crate::DragAndDrop::register(&ctx);
 
@@ -1033,11 +1058,15 @@ impl Context {
             viewport.widgets_this_frame.insert(w.layer_id, w);
 
             if w.sense.focusable {
-                ctx.memory.interested_in_focus(w.id);
+                ctx.memory.interested_in_focus_on_layer(w.id, w.layer_id);
             }
         });
 
-        if !w.enabled || !w.sense.focusable || !w.layer_id.allow_interaction() {
+        if !w.enabled
+            || !w.sense.focusable
+            || !w.layer_id.allow_interaction()
+            || self.memory(|mem| mem.is_behind_modal(w.layer_id))
+        {
             // Not interested or allowed input:
             self.memory_mut(|mem| mem.surrender_focus(w.id));
         }
@@ -1077,6 +1106,51 @@ impl Context {
         .map(|widget_rect| self.get_response(widget_rect))
     }
 
+    /// Read the widgets that have been added so far this frame.
+    pub(crate) fn widgets_this_frame<R>(&self, reader: impl FnOnce(&WidgetRects) -> R) -> R {
+        self.write(|ctx| reader(&ctx.viewport().widgets_this_frame))
+    }
+
+    pub(crate) fn widgets_this_frame_mut<R>(
+        &self,
+        writer: impl FnOnce(&mut WidgetRects) -> R,
+    ) -> R {
+        self.write(|ctx| writer(&mut ctx.viewport().widgets_this_frame))
+    }
+
+    /// Store the [`crate::WidgetInfo`] of all widgets in this layer this frame,
+    /// see [`WidgetRects::keep_infos_for_layer`].
+    pub(crate) fn keep_widget_infos_for_layer(&self, layer_id: LayerId) {
+        self.write(|ctx| {
+            ctx.viewport()
+                .widgets_this_frame
+                .keep_infos_for_layer(layer_id);
+        });
+    }
+
+    /// Give the layer a readable name in [`Self::layers_ui`], e.g. the title of a window.
+    ///
+    /// Only stored while the layer list is shown.
+    pub(crate) fn register_layer_name(
+        &self,
+        layer_id: LayerId,
+        make_name: impl FnOnce() -> String,
+    ) {
+        let is_shown = self.read(|ctx| {
+            ctx.viewports
+                .get(&ctx.viewport_id())
+                .map_or(false, |viewport| {
+                    !viewport.layer_inspection.stats.is_empty()
+                })
+        });
+        if is_shown {
+            let name = make_name();
+            self.write(|ctx| {
+                ctx.viewport().layer_inspection.names.insert(layer_id, name);
+            });
+        }
+    }
+
     /// Returns `true` if the widget with the given `Id` contains the pointer.
     #[deprecated = "Use Response.contains_pointer or Context::read_response instead"]
     pub fn widget_contains_pointer(&self, id: Id) -> bool {
@@ -1211,19 +1285,24 @@ impl Context {
     /// This is called by [`Response::widget_info`], but can also be called directly.
     ///
     /// With some debug flags it will store the widget info in [`WidgetRects`] for later display.
+    /// It is also stored for widgets in layers that asked for it with [`WidgetRects::keep_infos_for_layer`].
     #[inline]
     pub fn register_widget_info(&self, id: Id, make_info: impl Fn() -> crate::WidgetInfo) {
-        #[cfg(debug_assertions)]
-        self.write(|ctx| {
-            if ctx.memory.options.style.debug.show_interactive_widgets {
-                ctx.viewport().widgets_this_frame.set_info(id, make_info());
-            }
+        let keep_info = self.read(|ctx| {
+            let show_interactive_widgets =
+                cfg!(debug_assertions) && ctx.memory.options.style.debug.show_interactive_widgets;
+            show_interactive_widgets
+                || ctx
+                    .viewports
+                    .get(&ctx.viewport_id())
+                    .map_or(false, |viewport| viewport.widgets_this_frame.keeps_info(id))
         });
-
-        #[cfg(not(debug_assertions))]
-        {
-            _ = (self, id, make_info);
+        if !keep_info {
+            return;
         }
+
+        let info = make_info();
+        self.write(|ctx| ctx.viewport().widgets_this_frame.set_info(id, info));
     }
 
     /// Get a full-screen painter for a new or existing layer
@@ -1237,6 +1316,18 @@ impl Context {
         Self::layer_painter(self, LayerId::debug())
     }
 
+    /// Show short notifications in a corner of the screen.
+    ///
+    /// ```
+    /// # let ctx = &egui::Context::default();
+    /// ctx.toasts().add(egui::toasts::Toast::info("Saved"));
+    /// ```
+    ///
+    /// See [`crate::toasts`] for more.
+    pub fn toasts(&self) -> crate::toasts::Toasts {
+        crate::toasts::Toasts::new(self.clone())
+    }
+
     /// Print this text next to the cursor at the end of the frame.
     ///
     /// If you call this multiple times, the text will be appended.
@@ -1255,6 +1346,25 @@ impl Context {
         crate::debug_text::print(self, text);
     }
 
+    /// Lay out the text on a background thread, for texts so large that laying them out would stall the frame.
+    ///
+    /// Returns `None` until the layout is done, after which a repaint is requested.
+    /// Keep calling this each frame with the same `id` and job until then.
+    /// Each `id` has one job: asking for a different job (e.g. a new wrap width) replaces the old one,
+    /// which is skipped if it hasn't started yet.
+    /// Jobs that are not asked for during a frame are forgotten.
+    ///
+    /// All jobs share a single worker thread. On the web this lays out the text right away.
+    ///
+    /// See also [`crate::Label::layout_in_background`].
+    pub fn layout_job_in_background(
+        &self,
+        id: Id,
+        job: epaint::text::LayoutJob,
+    ) -> Option<Arc<Galley>> {
+        crate::background_layout::BackgroundLayouts::layout(self, id, job)
+    }
+
     /// What operating system are we running on?
     ///
     /// When compiling natively, this is
@@ -1293,7 +1403,15 @@ impl Context {
     /// # let open_url = egui::OpenUrl::same_tab("http://www.example.com");
     /// ctx.output_mut(|o| o.open_url = Some(open_url));
     /// ```
+    ///
+    /// …unless the url is intercepted by [`Self::set_open_url_handler`].
     pub fn open_url(&self, open_url: crate::OpenUrl) {
+        let handler = self.read(|ctx| ctx.open_url_handler.clone());
+        if let Some(handler) = handler {
+            if handler(self, &open_url) {
+                return;
+            }
+        }
         self.output_mut(|o| o.open_url = Some(open_url));
     }
 
@@ -1542,6 +1660,37 @@ impl Context {
         };
         self.write(|ctx| ctx.plugins.on_end_frame.push(named_cb));
     }
+
+    /// Intercept urls opened with [`Self::open_url`], e.g. by a [`crate::Hyperlink`].
+    ///
+    /// If the handler returns `true` the url is considered handled,
+    /// and it will NOT be opened by the integration (e.g. in the web browser).
+    /// This lets you route internal links (e.g. `myapp://settings`) within your app.
+    ///
+    /// Only one handler can be set at a time. Setting a new one replaces the old one.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.set_open_url_handler(|_ctx, open_url| {
+    ///     if let Some(page) = open_url.url.strip_prefix("myapp://") {
+    ///         // … navigate to `page` within the app
+    ///         true
+    ///     } else {
+    ///         false // open in the browser, as usual
+    ///     }
+    /// });
+    /// ```
+    pub fn set_open_url_handler(
+        &self,
+        handler: impl Fn(&Self, &crate::OpenUrl) -> bool + Send + Sync + 'static,
+    ) {
+        self.write(|ctx| ctx.open_url_handler = Some(Arc::new(handler)));
+    }
+
+    /// Remove the handler set with [`Self::set_open_url_handler`].
+    pub fn clear_open_url_handler(&self) {
+        self.write(|ctx| ctx.open_url_handler = None);
+    }
 }
 
 impl Context {
@@ -2005,6 +2154,95 @@ impl ContextImpl {
             }
         }
 
+        let inspection = &mut viewport.layer_inspection;
+        if std::mem::take(&mut inspection.is_shown) {
+            crate::profile_scope!("layer_inspection");
+            let area_order = self.memory.areas().order();
+            let mut tessellator = self.fonts.get(&pixels_per_point.into()).map(|fonts| {
+                let (font_tex_size, prepared_discs) = {
+                    let atlas = fonts.texture_atlas();
+                    let atlas = atlas.lock();
+                    (atlas.size(), atlas.prepared_discs())
+                };
+                tessellator::Tessellator::new(
+                    pixels_per_point,
+                    self.memory.options.tessellation_options,
+                    font_tex_size,
+                    prepared_discs,
+                )
+            });
+            inspection.stats = viewport
+                .graphics
+                .layer_ids_in_paint_order(area_order)
+                .into_iter()
+                .map(|layer_id| {
+                    let list = viewport.graphics.get(layer_id);
+                    let num_shapes = list.map_or(0, |list| list.all_entries().count());
+                    let num_vertices = inspection.expanded.contains(&layer_id).then(|| {
+                        let Some(tessellator) = &mut tessellator else {
+                            return 0;
+                        };
+                        let shapes: Vec<ClippedShape> = list
+                            .map_or_else(Vec::new, |list| list.all_entries().cloned().collect());
+                        tessellator
+                            .tessellate_shapes(shapes)
+                            .iter()
+                            .map(|primitive| match &primitive.primitive {
+                                epaint::Primitive::Mesh(mesh) => mesh.vertices.len(),
+                                epaint::Primitive::Callback(_) => 0,
+                            })
+                            .sum()
+                    });
+                    LayerStats {
+                        layer_id,
+                        num_shapes,
+                        num_vertices,
+                    }
+                })
+                .collect();
+
+            if let Some(highlighted) = inspection.hovered.take().or(inspection.highlighted) {
+                let bounds = viewport.graphics.get(highlighted).map(|list| {
+                    list.all_entries().fold(Rect::NOTHING, |bounds, clipped| {
+                        bounds.union(
+                            clipped
+                                .shape
+                                .visual_bounding_rect()
+                                .intersect(clipped.clip_rect),
+                        )
+                    })
+                });
+                if let Some(mut bounds) = bounds.filter(|bounds| bounds.is_positive()) {
+                    if let Some(transform) = self.memory.layer_transforms.get(&highlighted) {
+                        bounds = *transform * bounds;
+                    }
+                    let color = Color32::from_rgb(255, 0, 255);
+                    viewport.graphics.entry(LayerId::debug()).add(
+                        Rect::EVERYTHING,
+                        Shape::rect_filled(bounds, 0.0, color.gamma_multiply(0.1)),
+                    );
+                    viewport.graphics.entry(LayerId::debug()).add(
+                        Rect::EVERYTHING,
+                        Shape::rect_stroke(bounds, 0.0, (2.0, color)),
+                    );
+                }
+            }
+
+            for layer_id in &inspection.hidden {
+                if let Some(list) = viewport.graphics.get_mut(*layer_id) {
+                    list.clear();
+                }
+            }
+        } else {
+            // The layer list is closed, so show everything again:
+            inspection.stats.clear();
+            inspection.hidden.clear();
+            inspection.highlighted = None;
+            inspection.hovered = None;
+            inspection.expanded.clear();
+            inspection.names.clear();
+        }
+
         let shapes = viewport
             .graphics
             .drain(self.memory.areas().order(), &self.memory.layer_transforms);
@@ -2024,6 +2262,10 @@ impl ContextImpl {
                 &mut viewport.widgets_this_frame,
             );
             viewport.widgets_this_frame.clear();
+
+            if let Some(min_widgets) = self.memory.options.hit_test_index_threshold {
+                viewport.widgets_prev_frame.build_spatial_index(min_widgets);
+            }
         }
 
         if repaint_needed || viewport.input.wants_repaint() {
@@ -2374,11 +2616,41 @@ impl Context {
         self.memory_mut(|mem| mem.areas_mut().move_to_top(layer_id));
     }
 
+    /// Moves the given area to the bottom in its [`Order`], i.e. behind everything else of that order.
+    pub fn move_to_bottom(&self, layer_id: LayerId) {
+        self.memory_mut(|mem| mem.areas_mut().move_to_bottom(layer_id));
+    }
+
+    /// Keep the given area above everything else in its [`Order`],
+    /// even when other areas are clicked or moved to the top.
+    ///
+    /// Only a modal [`Window`] goes above it.
+    pub fn set_always_on_top(&self, layer_id: LayerId, always_on_top: bool) {
+        self.memory_mut(|mem| mem.areas_mut().set_always_on_top(layer_id, always_on_top));
+    }
+
+    /// Was this area set to be always on top with [`Self::set_always_on_top`]?
+    pub fn is_always_on_top(&self, layer_id: LayerId) -> bool {
+        self.memory(|mem| mem.areas().is_always_on_top(layer_id))
+    }
+
     /// Retrieve the [`LayerId`] of the top level windows.
     pub fn top_layer_id(&self) -> Option<LayerId> {
         self.memory(|mem| mem.areas().top_layer_id(Order::Middle))
     }
 
+    /// All layers of the given [`Order`], back-to-front (the top is last).
+    ///
+    /// For the windows, use [`Order::Middle`].
+    /// The order is updated at the end of each frame.
+    pub fn layer_ids_in_order(&self, order: Order) -> Vec<LayerId> {
+        self.memory(|mem| {
+            mem.layer_ids()
+                .filter(|layer| layer.order == order)
+                .collect()
+        })
+    }
+
     /// Does the given rectangle contain the mouse pointer?
     ///
     /// Will return false if some other area is covering the given layer.
@@ -2619,6 +2891,12 @@ impl Context {
                 paint_stats.ui(ui);
             });
 
+        CollapsingHeader::new("🗐 Layers")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.layers_ui(ui);
+            });
+
         CollapsingHeader::new("🖼 Textures")
             .default_open(false)
             .show(ui, |ui| {
@@ -2649,6 +2927,124 @@ impl Context {
             });
     }
 
+    /// Show all layers painted last frame, in paint order.
+    ///
+    /// Each layer can be temporarily hidden or highlighted,
+    /// which is useful for finding out what is covering what,
+    /// or which invisible layer is eating your clicks.
+    /// The layers are shown again when this ui is no longer shown.
+    pub fn layers_ui(&self, ui: &mut Ui) {
+        let viewport_id = self.viewport_id();
+        let mut inspection = self.write(|ctx| {
+            let inspection = &mut ctx.viewport_for(viewport_id).layer_inspection;
+            inspection.is_shown = true;
+            inspection.clone()
+        });
+        if inspection.stats.is_empty() {
+            ui.label("Collecting layer stats…");
+            self.request_repaint();
+            return;
+        }
+
+        let top_layer = self
+            .pointer_hover_pos()
+            .and_then(|pos| self.layer_id_at(pos));
+        let mut hovered_layer = None;
+
+        crate::Grid::new("layers_ui")
+            .num_columns(6)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("Layer");
+                ui.strong("Shapes");
+                ui.strong("Vertices");
+                ui.strong("Interactable");
+                ui.strong("Hide");
+                ui.strong("Highlight");
+                ui.end_row();
+
+                for stats in &inspection.stats {
+                    let layer_id = stats.layer_id;
+                    let interactable = layer_id.allow_interaction()
+                        && self.memory(|mem| {
+                            mem.areas()
+                                .get(layer_id.id)
+                                .map_or(true, |state| state.interactable)
+                        });
+
+                    let mut expanded = inspection.expanded.contains(&layer_id);
+                    let response = ui
+                        .horizontal(|ui| {
+                            let arrow = if expanded { "⏷" } else { "⏵" };
+                            if ui
+                                .add(Button::new(arrow).frame(false))
+                                .on_hover_text("Count the vertices of this layer")
+                                .clicked()
+                            {
+                                expanded = !expanded;
+                            }
+
+                            let name = inspection.names.get(&layer_id).map_or_else(
+                                || layer_id.short_debug_format(),
+                                |name| format!("{} {name}", layer_id.order.short_debug_format()),
+                            );
+                            let mut label = RichText::new(name);
+                            if Some(layer_id) == top_layer {
+                                label = label.strong();
+                            }
+                            let response = ui.label(label);
+                            if Some(layer_id) == top_layer {
+                                response.on_hover_text("This layer is under the mouse")
+                            } else {
+                                response
+                            }
+                        })
+                        .inner;
+                    if response.hovered() {
+                        hovered_layer = Some(layer_id);
+                    }
+                    if expanded {
+                        inspection.expanded.insert(layer_id);
+                    } else {
+                        inspection.expanded.remove(&layer_id);
+                    }
+
+                    ui.label(stats.num_shapes.to_string());
+                    ui.label(
+                        stats
+                            .num_vertices
+                            .map_or_else(|| "–".to_owned(), |n| n.to_string()),
+                    );
+                    ui.label(if interactable { "yes" } else { "no" });
+
+                    let mut hidden = inspection.hidden.contains(&layer_id);
+                    if ui.checkbox(&mut hidden, "").changed() {
+                        if hidden {
+                            inspection.hidden.insert(layer_id);
+                        } else {
+                            inspection.hidden.remove(&layer_id);
+                        }
+                    }
+
+                    let mut highlighted = inspection.highlighted == Some(layer_id);
+                    if ui.checkbox(&mut highlighted, "").changed() {
+                        inspection.highlighted = highlighted.then_some(layer_id);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        ui.label("Hover a layer name to highlight it. Expand a row to count its vertices.");
+
+        self.write(|ctx| {
+            let stored = &mut ctx.viewport_for(viewport_id).layer_inspection;
+            stored.hidden = inspection.hidden;
+            stored.expanded = inspection.expanded;
+            stored.highlighted = inspection.highlighted;
+            stored.hovered = hovered_layer;
+        });
+    }
+
     /// Show stats about the allocated textures.
     pub fn texture_ui(&self, ui: &mut crate::Ui) {
         let tex_mngr = self.tex_manager();
@@ -3427,6 +3823,153 @@ impl Context {
     }
 }
 
+/// ## Documents
+impl Context {
+    /// Mark a document as having unsaved changes.
+    ///
+    /// While any document is dirty, egui cancels requests to close the app
+    /// (see [`crate::ViewportInfo::close_requested`])
+    /// and shows a modal window asking the user whether to discard the changes.
+    ///
+    /// The name is shown in that window, and must be unique among your documents.
+    /// Call [`Self::mark_document_clean`] once the document has been saved.
+    pub fn mark_document_dirty(&self, name: impl Into<String>) {
+        let name = name.into();
+        self.data_mut(|data| {
+            let documents = data.get_temp_mut_or_default::<crate::documents::Documents>(Id::NULL);
+            if documents.dirty.insert(name) {
+                // Discarding the changes to the other documents doesn't cover this one:
+                documents.discard = false;
+            }
+        });
+    }
+
+    /// Mark a document as saved (or closed), undoing [`Self::mark_document_dirty`].
+    pub fn mark_document_clean(&self, name: &str) {
+        self.data_mut(|data| {
+            data.get_temp_mut_or_default::<crate::documents::Documents>(Id::NULL)
+                .dirty
+                .remove(name);
+        });
+    }
+
+    /// Does this document have unsaved changes?
+    ///
+    /// See [`Self::mark_document_dirty`].
+    pub fn is_document_dirty(&self, name: &str) -> bool {
+        self.data(|data| {
+            data.get_temp::<crate::documents::Documents>(Id::NULL)
+                .map_or(false, |documents| documents.dirty.contains(name))
+        })
+    }
+
+    /// Does any document have unsaved changes?
+    ///
+    /// See [`Self::mark_document_dirty`].
+    pub fn has_dirty_documents(&self) -> bool {
+        !self.dirty_documents().is_empty()
+    }
+
+    /// The names of all documents with unsaved changes, in alphabetical order.
+    ///
+    /// See [`Self::mark_document_dirty`].
+    pub fn dirty_documents(&self) -> Vec<String> {
+        self.data(|data| {
+            data.get_temp::<crate::documents::Documents>(Id::NULL)
+                .map(|documents| documents.dirty.into_iter().collect())
+                .unwrap_or_default()
+        })
+    }
+}
+
+#[test]
+fn dirty_documents_cancel_close() {
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    ctx.mark_document_dirty("notes.txt");
+    assert!(ctx.is_document_dirty("notes.txt"));
+
+    let mut input = RawInput::default();
+    input
+        .viewports
+        .entry(ViewportId::ROOT)
+        .or_default()
+        .events
+        .push(crate::ViewportEvent::Close);
+    let ((), output) = harness.run_input(input, |_ctx| {});
+    assert!(output.viewport_output[&ViewportId::ROOT]
+        .commands
+        .contains(&ViewportCommand::CancelClose));
+
+    ctx.mark_document_clean("notes.txt");
+    assert!(!ctx.has_dirty_documents());
+}
+
+#[test]
+fn layers_ui_only_counts_expanded_vertices() {
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    let run = || {
+        harness.run(vec![], |ctx| {
+            crate::Window::new("Layers").show(ctx, |ui| ctx.layers_ui(ui));
+        });
+    };
+    for _ in 0..3 {
+        run();
+    }
+
+    let window = LayerId::new(Order::Middle, Id::new("Layers"));
+    let inspection = ctx.viewport(|viewport| viewport.layer_inspection.clone());
+    assert_eq!(
+        inspection.names.get(&window).map(String::as_str),
+        Some("Layers")
+    );
+    assert!(inspection
+        .stats
+        .iter()
+        .any(|stats| stats.layer_id == window));
+    assert!(inspection
+        .stats
+        .iter()
+        .all(|stats| stats.num_vertices.is_none()));
+
+    ctx.write(|ctx| ctx.viewport().layer_inspection.expanded.insert(window));
+    run();
+    let inspection = ctx.viewport(|viewport| viewport.layer_inspection.clone());
+    for stats in &inspection.stats {
+        assert_eq!(stats.num_vertices.is_some(), stats.layer_id == window);
+    }
+}
+
+#[test]
+fn pen_proximity_hovers_widgets() {
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    let run = |events: Vec<Event>| harness.run_ui(events, |ui| ui.button("Pen"));
+
+    let pos = run(vec![]).rect.center();
+    let proximity = Event::PointerProximity {
+        pos,
+        distance: Some(0.5),
+    };
+    run(vec![proximity.clone()]);
+    assert!(run(vec![]).hovered());
+    ctx.input(|i| {
+        assert_eq!(i.pointer.proximity_pos(), Some(pos));
+        assert_eq!(i.pointer.proximity_distance(), Some(0.5));
+        assert_eq!(i.pointer.hover_pos(), None);
+    });
+
+    ctx.style_mut(|style| style.interaction.proximity_hover = false);
+    run(vec![]);
+    assert!(!run(vec![]).hovered());
+
+    ctx.style_mut(|style| style.interaction.proximity_hover = true);
+    run(vec![Event::PointerProximityGone]);
+    assert!(!run(vec![]).hovered());
+    assert_eq!(ctx.input(|i| i.pointer.proximity_pos()), None);
+}
+
 #[test]
 fn context_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
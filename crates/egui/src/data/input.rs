@@ -1,8 +1,8 @@
 //! The input needed by egui.
 
-use epaint::ColorImage;
+use epaint::{Color32, ColorImage};
 
-use crate::{emath::*, Key, ViewportId, ViewportIdMap};
+use crate::{emath::*, Key, Margin, ViewportId, ViewportIdMap};
 
 /// What the integrations provides to egui at the start of each frame.
 ///
@@ -34,6 +34,15 @@ pub struct RawInput {
     /// `None` will be treated as "same as last frame", with the default being a very big area.
     pub screen_rect: Option<Rect>,
 
+    /// How much of each edge of [`Self::screen_rect`] is covered by display cutouts (notches),
+    /// rounded display corners, system bars and the like, in points.
+    ///
+    /// Panels and areas can be told to keep their content out of these regions,
+    /// see e.g. [`crate::CentralPanel::respect_safe_area`] and [`crate::Area::respect_safe_area`].
+    ///
+    /// The default is zero on all sides.
+    pub safe_area_insets: Margin,
+
     /// Maximum size of one side of the font texture.
     ///
     /// Ask your graphics drivers about this. This corresponds to `GL_MAX_TEXTURE_SIZE`.
@@ -73,6 +82,15 @@ pub struct RawInput {
     ///
     /// False when the user alt-tab away from the application, for instance.
     pub focused: bool,
+
+    /// Is there text on the system clipboard that can be pasted?
+    ///
+    /// Used to enable or disable "Paste" in the context menu of a [`crate::TextEdit`].
+    /// Since reading the clipboard can be slow, integrations only need to update this
+    /// when a context menu could be opened, e.g. when the secondary mouse button is pressed.
+    ///
+    /// `None` means "unknown", which is the default, and egui then assumes there may be.
+    pub clipboard_has_text: Option<bool>,
 }
 
 impl Default for RawInput {
@@ -81,6 +99,7 @@ impl Default for RawInput {
             viewport_id: ViewportId::ROOT,
             viewports: std::iter::once((ViewportId::ROOT, Default::default())).collect(),
             screen_rect: None,
+            safe_area_insets: Margin::ZERO,
             max_texture_side: None,
             time: None,
             predicted_dt: 1.0 / 60.0,
@@ -89,6 +108,7 @@ impl Default for RawInput {
             hovered_files: Default::default(),
             dropped_files: Default::default(),
             focused: true, // integrations opt into global focus tracking
+            clipboard_has_text: None,
         }
     }
 }
@@ -109,6 +129,7 @@ impl RawInput {
             viewport_id: self.viewport_id,
             viewports: self.viewports.clone(),
             screen_rect: self.screen_rect.take(),
+            safe_area_insets: self.safe_area_insets,
             max_texture_side: self.max_texture_side.take(),
             time: self.time.take(),
             predicted_dt: self.predicted_dt,
@@ -117,6 +138,7 @@ impl RawInput {
             hovered_files: self.hovered_files.clone(),
             dropped_files: std::mem::take(&mut self.dropped_files),
             focused: self.focused,
+            clipboard_has_text: self.clipboard_has_text,
         }
     }
 
@@ -126,6 +148,7 @@ impl RawInput {
             viewport_id: viewport_ids,
             viewports,
             screen_rect,
+            safe_area_insets,
             max_texture_side,
             time,
             predicted_dt,
@@ -134,11 +157,13 @@ impl RawInput {
             mut hovered_files,
             mut dropped_files,
             focused,
+            clipboard_has_text,
         } = newer;
 
         self.viewport_id = viewport_ids;
         self.viewports = viewports;
         self.screen_rect = screen_rect.or(self.screen_rect);
+        self.safe_area_insets = safe_area_insets; // use latest
         self.max_texture_side = max_texture_side.or(self.max_texture_side);
         self.time = time; // use latest time
         self.predicted_dt = predicted_dt; // use latest dt
@@ -147,6 +172,7 @@ impl RawInput {
         self.hovered_files.append(&mut hovered_files);
         self.dropped_files.append(&mut dropped_files);
         self.focused = focused;
+        self.clipboard_has_text = clipboard_has_text;
     }
 }
 
@@ -426,6 +452,24 @@ pub enum Event {
     /// On touch-up first send `PointerButton{pressed: false, …}` followed by `PointerLeft`.
     PointerGone,
 
+    /// A pen (or, on some devices, a finger) is hovering above the screen without touching it.
+    ///
+    /// Send [`Self::PointerProximityGone`] when it is no longer detected.
+    /// Touching the screen is reported with [`Self::PointerButton`] as usual.
+    PointerProximity {
+        /// Where on the screen the pen is hovering.
+        pos: Pos2,
+
+        /// How far above the screen the pen is,
+        /// from 0.0 (touching) to 1.0 (as far as the device can detect).
+        ///
+        /// `None` if the device doesn't report the distance.
+        distance: Option<f32>,
+    },
+
+    /// The pen or finger of [`Self::PointerProximity`] moved out of range.
+    PointerProximityGone,
+
     /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
     /// * `zoom = 1`: no change.
     /// * `zoom < 1`: pinch together
@@ -492,6 +536,20 @@ pub enum Event {
         viewport_id: crate::ViewportId,
         image: std::sync::Arc<ColorImage>,
     },
+
+    /// The user picked this color from the screen.
+    ///
+    /// The reply to [`crate::PlatformOutput::pick_screen_color`].
+    ScreenColorPicked(Color32),
+
+    /// The user picked this path in a file dialog.
+    ///
+    /// The reply to [`crate::PlatformOutput::file_dialog`].
+    FileDialogPicked {
+        /// The [`crate::FileDialogRequest::id`] of the request.
+        id: crate::Id,
+        path: std::path::PathBuf,
+    },
 }
 
 /// IME event.
@@ -996,6 +1054,7 @@ impl RawInput {
             viewport_id,
             viewports,
             screen_rect,
+            safe_area_insets,
             max_texture_side,
             time,
             predicted_dt,
@@ -1004,6 +1063,7 @@ impl RawInput {
             hovered_files,
             dropped_files,
             focused,
+            clipboard_has_text,
         } = self;
 
         ui.label(format!("Active viwport: {viewport_id:?}"));
@@ -1016,6 +1076,7 @@ impl RawInput {
             });
         }
         ui.label(format!("screen_rect: {screen_rect:?} points"));
+        ui.label(format!("safe_area_insets: {safe_area_insets:?} points"));
 
         ui.label(format!("max_texture_side: {max_texture_side:?}"));
         if let Some(time) = time {
@@ -1028,6 +1089,7 @@ impl RawInput {
         ui.label(format!("hovered_files: {}", hovered_files.len()));
         ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("focused: {focused}"));
+        ui.label(format!("clipboard_has_text: {clipboard_has_text:?}"));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {events:#?}"))
@@ -118,6 +118,22 @@ pub struct PlatformOutput {
     /// Useful for IME.
     pub ime: Option<IMEOutput>,
 
+    /// egui wants the user to pick a color from anywhere on the screen,
+    /// e.g. because the eyedropper of a [`crate::color_picker`] was clicked.
+    ///
+    /// An integration that supports it should let the user pick a color,
+    /// and reply with [`crate::Event::ScreenColorPicked`].
+    /// Integrations that don't can ignore this,
+    /// and egui will fall back to sampling a screenshot of its own viewport.
+    pub pick_screen_color: bool,
+
+    /// If set, egui wants the integration to show a file dialog, e.g. because the browse button
+    /// of a [`crate::PathEdit`] was clicked.
+    ///
+    /// An integration that supports it should reply with [`crate::Event::FileDialogPicked`]
+    /// when the user picks a file, and with nothing if they cancel.
+    pub file_dialog: Option<FileDialogRequest>,
+
     /// The difference in the widget tree since last frame.
     ///
     /// NOTE: this needs to be per-viewport.
@@ -153,6 +169,8 @@ impl PlatformOutput {
             mut events,
             mutable_text_under_cursor,
             ime,
+            pick_screen_color,
+            file_dialog,
             #[cfg(feature = "accesskit")]
             accesskit_update,
         } = newer;
@@ -167,6 +185,10 @@ impl PlatformOutput {
         self.events.append(&mut events);
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.ime = ime.or(self.ime);
+        self.pick_screen_color |= pick_screen_color;
+        if file_dialog.is_some() {
+            self.file_dialog = file_dialog;
+        }
 
         #[cfg(feature = "accesskit")]
         {
@@ -216,6 +238,19 @@ impl OpenUrl {
     }
 }
 
+/// A file dialog egui wants shown, see [`PlatformOutput::file_dialog`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FileDialogRequest {
+    /// Send this back in [`crate::Event::FileDialogPicked`].
+    pub id: crate::Id,
+
+    /// Only show files with these extensions, e.g. `["png", "jpg"]`.
+    ///
+    /// If empty, show all files.
+    pub extensions: Vec<String>,
+}
+
 /// Types of attention to request from a user when a native window is not in focus.
 ///
 /// See [winit's documentation][user_attention_type] for platform-specific meaning of the attention types.
@@ -628,6 +663,7 @@ impl WidgetInfo {
             WidgetType::TextEdit => "text edit",
             WidgetType::Button => "button",
             WidgetType::Checkbox => "checkbox",
+            WidgetType::Switch => "switch",
             WidgetType::RadioButton => "radio",
             WidgetType::SelectableLabel => "selectable",
             WidgetType::ComboBox => "combo",
@@ -646,6 +682,9 @@ impl WidgetInfo {
             if *typ == WidgetType::Checkbox {
                 let state = if *selected { "checked" } else { "unchecked" };
                 description = format!("{state} {description}");
+            } else if *typ == WidgetType::Switch {
+                let state = if *selected { "on" } else { "off" };
+                description = format!("{state} {description}");
             } else {
                 description += if *selected { "selected" } else { "" };
             };
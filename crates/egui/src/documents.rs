@@ -0,0 +1,133 @@
+//! Tracking of documents with unsaved changes, see [`Context::mark_document_dirty`].
+//!
+//! If the user tries to close the app while there are dirty documents,
+//! egui cancels the close and asks the user whether to discard the changes.
+
+use std::collections::BTreeSet;
+
+use crate::*;
+
+/// Register this plugin on the given egui context,
+/// so that it will be called every frame.
+///
+/// This is a built-in plugin in egui,
+/// meaning [`Context`] calls this from its `Default` implementation,
+/// so this is marked as `pub(crate)`.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("documents", std::sync::Arc::new(Documents::end_frame));
+}
+
+/// The documents plugin.
+///
+/// This is a built-in plugin in egui.
+#[derive(Clone, Default)]
+pub(crate) struct Documents {
+    /// Names of the documents with unsaved changes.
+    pub(crate) dirty: BTreeSet<String>,
+
+    /// Are we asking the user whether to discard the changes and close?
+    asking: bool,
+
+    /// The user said to discard the changes, so let the next close through.
+    ///
+    /// Reset once that close has happened, or when another document becomes dirty.
+    pub(crate) discard: bool,
+}
+
+impl Documents {
+    fn end_frame(ctx: &Context) {
+        if ctx.viewport_id() != ViewportId::ROOT {
+            return;
+        }
+
+        let mut state = ctx.data(|data| data.get_temp::<Self>(Id::NULL).unwrap_or_default());
+
+        let close_requested = ctx.input(|i| i.viewport().close_requested());
+        if close_requested && !state.dirty.is_empty() {
+            if state.discard {
+                // Let this close through, but ask again if it is cancelled some other way:
+                state.discard = false;
+            } else {
+                ctx.send_viewport_cmd(ViewportCommand::CancelClose);
+                state.asking = true;
+            }
+        }
+
+        if state.asking && !state.dirty.is_empty() {
+            state.confirm_discard_ui(ctx);
+        } else {
+            state.asking = false;
+        }
+
+        ctx.data_mut(|data| data.insert_temp(Id::NULL, state));
+    }
+
+    fn confirm_discard_ui(&mut self, ctx: &Context) {
+        let mut open = true;
+        Window::new("Unsaved changes")
+            .id(Id::new("__egui_confirm_discard"))
+            .modal(true)
+            .collapsible(false)
+            .resizable(false)
+            .pivot(Align2::CENTER_CENTER)
+            .default_pos(ctx.screen_rect().center())
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label("These documents have unsaved changes:");
+                for name in &self.dirty {
+                    ui.label(format!("• {name}"));
+                }
+                ui.label("Do you want to discard the changes and close?");
+                ui.add_space(4.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button("Discard changes").clicked() {
+                        self.asking = false;
+                        self.discard = true;
+                        ctx.send_viewport_cmd(ViewportCommand::Close);
+                    }
+                    if ui.button("Cancel").clicked() {
+                        self.asking = false;
+                    }
+                });
+            });
+        if !open {
+            self.asking = false;
+        }
+    }
+}
+
+#[test]
+fn discarding_changes_lets_one_close_through() {
+    let close = || {
+        let mut input = RawInput::default();
+        input
+            .viewports
+            .entry(ViewportId::ROOT)
+            .or_default()
+            .events
+            .push(crate::ViewportEvent::Close);
+        input
+    };
+    let is_cancelled = |output: FullOutput| {
+        output.viewport_output[&ViewportId::ROOT]
+            .commands
+            .contains(&ViewportCommand::CancelClose)
+    };
+
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    let run = || harness.run_input(close(), |_ctx| {}).1;
+    ctx.mark_document_dirty("notes.txt");
+    ctx.data_mut(|data| data.get_temp_mut_or_default::<Documents>(Id::NULL).discard = true);
+    assert!(!is_cancelled(run()));
+    assert!(is_cancelled(run()));
+
+    // Another dirty document needs to be confirmed again:
+    ctx.data_mut(|data| data.get_temp_mut_or_default::<Documents>(Id::NULL).discard = true);
+    ctx.mark_document_dirty("notes.txt");
+    assert!(!is_cancelled(run()));
+    ctx.data_mut(|data| data.get_temp_mut_or_default::<Documents>(Id::NULL).discard = true);
+    ctx.mark_document_dirty("todo.txt");
+    assert!(is_cancelled(run()));
+}
@@ -6,6 +6,8 @@
 //! 1. easy to parse
 //! 2. easy to learn
 //! 3. similar to markdown
+//!
+//! The same parser also reads a subset of Markdown, see [`Parser::markdown`].
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Item<'a> {
@@ -41,7 +43,9 @@ pub enum Item<'a> {
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub struct Style {
     /// # heading (large text)
-    pub heading: bool,
+    ///
+    /// The level of the heading, from `1` for `#` to `6` for `######`, or `0` for no heading.
+    pub heading: u8,
 
     /// > quoted (slightly dimmer color or other font style)
     pub quoted: bool,
@@ -55,10 +59,10 @@ pub struct Style {
     /// _underline_
     pub underline: bool,
 
-    /// ~strikethrough~
+    /// ~strikethrough~ (`~~strikethrough~~` in Markdown)
     pub strikethrough: bool,
 
-    /// /italics/
+    /// /italics/ (`*italics*` or `_italics_` in Markdown)
     pub italics: bool,
 
     /// $small$
@@ -68,18 +72,27 @@ pub struct Style {
     pub raised: bool,
 }
 
+/// Which markup language a [`Parser`] reads.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Flavor {
+    EasyMark,
+    Markdown,
+}
+
 /// Parser for the `EasyMark` markup language.
 ///
 /// See the module-level documentation for details.
 ///
 /// # Example:
 /// ```
-/// # use egui_demo_lib::easy_mark::parser::Parser;
+/// # use egui::easy_mark::parser::Parser;
 /// for item in Parser::new("Hello *world*!") {
 /// }
 ///
 /// ```
 pub struct Parser<'a> {
+    flavor: Flavor,
+
     /// The remainder of the input text
     s: &'a str,
 
@@ -93,12 +106,132 @@ pub struct Parser<'a> {
 impl<'a> Parser<'a> {
     pub fn new(s: &'a str) -> Self {
         Self {
+            flavor: Flavor::EasyMark,
             s,
             start_of_line: true,
             style: Style::default(),
         }
     }
 
+    /// Parse a subset of [Markdown](https://commonmark.org/help/) instead of `EasyMark`.
+    ///
+    /// The differences to `EasyMark` are:
+    /// * `*italics*` or `_italics_`, `**strong**` or `__strong__`, and `~~strikethrough~~`
+    /// * `* ` and `+ ` are also bullet points
+    /// * Lines are joined into paragraphs, which are separated by empty lines
+    /// * There is no underline, small or raised text
+    pub fn markdown(s: &'a str) -> Self {
+        Self {
+            flavor: Flavor::Markdown,
+            ..Self::new(s)
+        }
+    }
+
+    /// `# `, `## ` etc.
+    fn heading(&mut self) -> bool {
+        let level = self.s.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&level) && self.s[level..].starts_with(' ') {
+            self.s = &self.s[level + 1..];
+            self.start_of_line = false;
+            self.style.heading = level as u8;
+            return true;
+        }
+        false
+    }
+
+    /// `- `, and in Markdown also `* ` and `+ `.
+    fn bullet_point(&mut self) -> bool {
+        let is_bullet = match self.flavor {
+            Flavor::EasyMark => self.s.starts_with("- "),
+            Flavor::Markdown => ["- ", "* ", "+ "].iter().any(|b| self.s.starts_with(b)),
+        };
+        if is_bullet {
+            self.s = &self.s[2..];
+            self.start_of_line = false;
+        }
+        is_bullet
+    }
+
+    /// In Markdown, a single newline continues the paragraph,
+    /// unless the next line starts a new block (heading, list, quote, …).
+    fn is_soft_line_break(&self) -> bool {
+        if self.flavor != Flavor::Markdown || self.style.heading != 0 {
+            return false;
+        }
+        let next_line = self.s[1..].trim_start_matches(' ');
+        let next_line = &next_line[..next_line.find('\n').unwrap_or(next_line.len())];
+        let starts_block = ["#", "> ", "- ", "* ", "+ ", "```", "---"]
+            .iter()
+            .any(|prefix| next_line.starts_with(prefix))
+            || Self::new(next_line).numbered_list().is_some();
+        !next_line.trim().is_empty() && !starts_block
+    }
+
+    /// `*`, `**`, `_`, `__` and `~~` in Markdown, returning whether the style changed.
+    fn markdown_emphasis(&mut self) -> bool {
+        if let Some(rest) = self.s.strip_prefix("~~") {
+            self.s = rest;
+            self.start_of_line = false;
+            self.style.strikethrough = !self.style.strikethrough;
+            return true;
+        }
+
+        let Some(c) = self.s.chars().next().filter(|&c| c == '*' || c == '_') else {
+            return false;
+        };
+        let is_double = self.s[1..].starts_with(c);
+        let marker_len = if is_double { 2 } else { 1 };
+        let is_open = if is_double {
+            self.style.strong
+        } else {
+            self.style.italics
+        };
+        // `2 * 3` is not emphasis:
+        let opens = self.s[marker_len..]
+            .chars()
+            .next()
+            .map_or(false, |next| !next.is_whitespace());
+        if !is_open && !opens {
+            return false;
+        }
+
+        self.s = &self.s[marker_len..];
+        self.start_of_line = false;
+        if is_double {
+            self.style.strong = !self.style.strong;
+        } else {
+            self.style.italics = !self.style.italics;
+        }
+        true
+    }
+
+    /// Where the plain text at the start of [`Self::s`] ends.
+    fn text_end(&self) -> usize {
+        match self.flavor {
+            Flavor::EasyMark => self
+                .s
+                .find(&['*', '`', '~', '_', '/', '$', '^', '\\', '<', '[', '\n'][..])
+                .map_or_else(|| self.s.len(), |special| special.max(1)),
+            Flavor::Markdown => {
+                let mut prev = None;
+                for (i, c) in self.s.char_indices() {
+                    // `snake_case` is not emphasis:
+                    let is_intraword = c == '_'
+                        && prev.map_or(false, char::is_alphanumeric)
+                        && self.s[i + 1..]
+                            .chars()
+                            .next()
+                            .map_or(false, char::is_alphanumeric);
+                    if 0 < i && !is_intraword && "*`~_\\<[\n".contains(c) {
+                        return i;
+                    }
+                    prev = Some(c);
+                }
+                self.s.len()
+            }
+        }
+    }
+
     /// `1. `, `42. ` etc.
     fn numbered_list(&mut self) -> Option<Item<'a>> {
         let n_digits = self.s.chars().take_while(|c| c.is_ascii_digit()).count();
@@ -196,6 +329,11 @@ impl<'a> Iterator for Parser<'a> {
             }
 
             // \n
+            if self.s.starts_with('\n') && self.is_soft_line_break() {
+                self.s = self.s[1..].trim_start_matches(' ');
+                self.start_of_line = false;
+                return Some(Item::Text(self.style, " "));
+            }
             if self.s.starts_with('\n') {
                 self.s = &self.s[1..];
                 self.start_of_line = true;
@@ -228,10 +366,7 @@ impl<'a> Iterator for Parser<'a> {
                 }
 
                 // # Heading
-                if let Some(after) = self.s.strip_prefix("# ") {
-                    self.s = after;
-                    self.start_of_line = false;
-                    self.style.heading = true;
+                if self.heading() {
                     continue;
                 }
 
@@ -244,9 +379,7 @@ impl<'a> Iterator for Parser<'a> {
                 }
 
                 // - bullet point
-                if self.s.starts_with("- ") {
-                    self.s = &self.s[2..];
-                    self.start_of_line = false;
+                if self.bullet_point() {
                     return Some(Item::BulletPoint);
                 }
 
@@ -274,41 +407,47 @@ impl<'a> Iterator for Parser<'a> {
                 return Some(item);
             }
 
-            if let Some(rest) = self.s.strip_prefix('*') {
-                self.s = rest;
-                self.start_of_line = false;
-                self.style.strong = !self.style.strong;
-                continue;
-            }
-            if let Some(rest) = self.s.strip_prefix('_') {
-                self.s = rest;
-                self.start_of_line = false;
-                self.style.underline = !self.style.underline;
-                continue;
-            }
-            if let Some(rest) = self.s.strip_prefix('~') {
-                self.s = rest;
-                self.start_of_line = false;
-                self.style.strikethrough = !self.style.strikethrough;
-                continue;
-            }
-            if let Some(rest) = self.s.strip_prefix('/') {
-                self.s = rest;
-                self.start_of_line = false;
-                self.style.italics = !self.style.italics;
-                continue;
-            }
-            if let Some(rest) = self.s.strip_prefix('$') {
-                self.s = rest;
-                self.start_of_line = false;
-                self.style.small = !self.style.small;
-                continue;
-            }
-            if let Some(rest) = self.s.strip_prefix('^') {
-                self.s = rest;
-                self.start_of_line = false;
-                self.style.raised = !self.style.raised;
-                continue;
+            if self.flavor == Flavor::Markdown {
+                if self.markdown_emphasis() {
+                    continue;
+                }
+            } else {
+                if let Some(rest) = self.s.strip_prefix('*') {
+                    self.s = rest;
+                    self.start_of_line = false;
+                    self.style.strong = !self.style.strong;
+                    continue;
+                }
+                if let Some(rest) = self.s.strip_prefix('_') {
+                    self.s = rest;
+                    self.start_of_line = false;
+                    self.style.underline = !self.style.underline;
+                    continue;
+                }
+                if let Some(rest) = self.s.strip_prefix('~') {
+                    self.s = rest;
+                    self.start_of_line = false;
+                    self.style.strikethrough = !self.style.strikethrough;
+                    continue;
+                }
+                if let Some(rest) = self.s.strip_prefix('/') {
+                    self.s = rest;
+                    self.start_of_line = false;
+                    self.style.italics = !self.style.italics;
+                    continue;
+                }
+                if let Some(rest) = self.s.strip_prefix('$') {
+                    self.s = rest;
+                    self.start_of_line = false;
+                    self.style.small = !self.style.small;
+                    continue;
+                }
+                if let Some(rest) = self.s.strip_prefix('^') {
+                    self.s = rest;
+                    self.start_of_line = false;
+                    self.style.raised = !self.style.raised;
+                    continue;
+                }
             }
 
             // `<url>` or `[link](url)`
@@ -317,10 +456,7 @@ impl<'a> Iterator for Parser<'a> {
             }
 
             // Swallow everything up to the next special character:
-            let end = self
-                .s
-                .find(&['*', '`', '~', '_', '/', '$', '^', '\\', '<', '[', '\n'][..])
-                .map_or_else(|| self.s.len(), |special| special.max(1));
+            let end = self.text_end();
 
             let item = Item::Text(self.style, &self.s[..end]);
             self.s = &self.s[end..];
@@ -354,3 +490,62 @@ fn test_easy_mark_parser() {
         ]
     );
 }
+
+#[test]
+fn test_headings() {
+    let headings: Vec<_> = Parser::new("# One\n### Three\n####### Seven")
+        .filter_map(|item| match item {
+            Item::Text(style, text) => Some((style.heading, text)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        headings,
+        vec![(1, "One"), (3, "Three"), (0, "####### Seven")]
+    );
+}
+
+#[test]
+fn test_markdown_parser() {
+    let strong = Style {
+        strong: true,
+        ..Default::default()
+    };
+    let italics = Style {
+        italics: true,
+        ..Default::default()
+    };
+    let items: Vec<_> = Parser::markdown(
+        "Some **strong**\n_italic_ snake_case 2 * 3\n\n* one\n  + [two](url)\n\n## Title",
+    )
+    .collect();
+    assert_eq!(
+        items,
+        vec![
+            Item::Text(Style::default(), "Some "),
+            Item::Text(strong, "strong"),
+            // The line break is just a space:
+            Item::Text(Style::default(), " "),
+            Item::Text(italics, "italic"),
+            Item::Text(Style::default(), " snake_case 2 "),
+            Item::Text(Style::default(), "* 3"),
+            Item::Newline,
+            Item::Newline,
+            Item::BulletPoint,
+            Item::Text(Style::default(), "one"),
+            Item::Newline,
+            Item::Indentation(2),
+            Item::BulletPoint,
+            Item::Hyperlink(Style::default(), "two", "url"),
+            Item::Newline,
+            Item::Newline,
+            Item::Text(
+                Style {
+                    heading: 2,
+                    ..Default::default()
+                },
+                "Title"
+            ),
+        ]
+    );
+}
@@ -1,5 +1,5 @@
 use super::easy_mark_parser as easy_mark;
-use egui::*;
+use crate::*;
 
 /// Parse and display a VERY simple and small subset of Markdown.
 pub fn easy_mark(ui: &mut Ui, easy_mark: &str) {
@@ -7,6 +7,15 @@ pub fn easy_mark(ui: &mut Ui, easy_mark: &str) {
 }
 
 pub fn easy_mark_it<'em>(ui: &mut Ui, items: impl Iterator<Item = easy_mark::Item<'em>>) {
+    show_items(ui, items, item_ui);
+}
+
+/// Lays out the items like [`easy_mark_it`], but shows each of them with `add_item`.
+pub(crate) fn show_items<'em>(
+    ui: &mut Ui,
+    items: impl Iterator<Item = easy_mark::Item<'em>>,
+    mut add_item: impl FnMut(&mut Ui, easy_mark::Item<'em>),
+) -> Response {
     let initial_size = vec2(
         ui.available_width(),
         ui.spacing().interact_size.y, // Assume there will be
@@ -20,9 +29,10 @@ pub fn easy_mark_it<'em>(ui: &mut Ui, items: impl Iterator<Item = easy_mark::Ite
         ui.set_row_height(row_height);
 
         for item in items {
-            item_ui(ui, item);
+            add_item(ui, item);
         }
-    });
+    })
+    .response
 }
 
 pub fn item_ui(ui: &mut Ui, item: easy_mark::Item<'_>) {
@@ -38,7 +48,7 @@ pub fn item_ui(ui: &mut Ui, item: easy_mark::Item<'_>) {
         }
 
         easy_mark::Item::Text(style, text) => {
-            let label = rich_text_from_style(text, &style);
+            let label = rich_text_from_style(ui.style(), text, &style);
             if style.small && !style.raised {
                 ui.with_layout(Layout::left_to_right(Align::BOTTOM), |ui| {
                     ui.set_min_height(row_height);
@@ -49,7 +59,7 @@ pub fn item_ui(ui: &mut Ui, item: easy_mark::Item<'_>) {
             }
         }
         easy_mark::Item::Hyperlink(style, text, url) => {
-            let label = rich_text_from_style(text, &style);
+            let label = rich_text_from_style(ui.style(), text, &style);
             if style.small && !style.raised {
                 ui.with_layout(Layout::left_to_right(Align::BOTTOM), |ui| {
                     ui.set_height(row_height);
@@ -101,7 +111,11 @@ pub fn item_ui(ui: &mut Ui, item: easy_mark::Item<'_>) {
     };
 }
 
-fn rich_text_from_style(text: &str, style: &easy_mark::Style) -> RichText {
+pub(crate) fn rich_text_from_style(
+    egui_style: &Style,
+    text: &str,
+    style: &easy_mark::Style,
+) -> RichText {
     let easy_mark::Style {
         heading,
         quoted,
@@ -117,10 +131,10 @@ fn rich_text_from_style(text: &str, style: &easy_mark::Style) -> RichText {
     let small = small || raised; // Raised text is also smaller
 
     let mut rich_text = RichText::new(text);
-    if heading && !small {
-        rich_text = rich_text.heading().strong();
+    if heading != 0 && !small {
+        rich_text = rich_text.size(heading_size(egui_style, heading)).strong();
     }
-    if small && !heading {
+    if small && heading == 0 {
         rich_text = rich_text.small();
     }
     if code {
@@ -146,6 +160,15 @@ fn rich_text_from_style(text: &str, style: &easy_mark::Style) -> RichText {
     rich_text
 }
 
+/// Headings go from the size of [`TextStyle::Heading`] for `#`
+/// down to the size of [`TextStyle::Body`] for `######`.
+pub(crate) fn heading_size(egui_style: &Style, level: u8) -> f32 {
+    let body = TextStyle::Body.resolve(egui_style).size;
+    let heading = TextStyle::Heading.resolve(egui_style).size;
+    let t = (6 - level.clamp(1, 6)) as f32 / 5.0;
+    lerp(body..=heading, t)
+}
+
 fn bullet_point(ui: &mut Ui, width: f32) -> Response {
     let row_height = ui.text_style_height(&TextStyle::Body);
     let (rect, response) = ui.allocate_exact_size(vec2(width, row_height), Sense::hover());
@@ -172,3 +195,15 @@ fn numbered_point(ui: &mut Ui, width: f32, number: &str) -> Response {
     );
     response
 }
+
+#[test]
+fn test_heading_sizes() {
+    let style = Style::default();
+    let sizes: Vec<f32> = (1..=6).map(|level| heading_size(&style, level)).collect();
+    assert_eq!(sizes[0], TextStyle::Heading.resolve(&style).size);
+    assert_eq!(sizes[5], TextStyle::Body.resolve(&style).size);
+    assert!(
+        sizes.windows(2).all(|pair| pair[1] < pair[0]),
+        "every heading level should have its own size: {sizes:?}"
+    );
+}
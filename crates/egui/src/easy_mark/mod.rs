@@ -0,0 +1,13 @@
+//! `EasyMark`: a very simple markup language, similar to Markdown.
+//!
+//! The parser also reads a subset of Markdown, which is how [`crate::Markdown`] is shown.
+//!
+//! Requires the `markdown` feature.
+
+pub mod easy_mark_parser;
+mod easy_mark_viewer;
+
+pub use easy_mark_parser as parser;
+pub use easy_mark_viewer::{easy_mark, easy_mark_it, item_ui};
+
+pub(crate) use easy_mark_viewer::{rich_text_from_style, show_items};
@@ -42,6 +42,89 @@ impl State {
         self.col_widths.iter().sum::<f32>()
             + (self.col_widths.len().at_least(1) - 1) as f32 * x_spacing
     }
+
+    /// Make the last of the spanned columns wider, if the cell doesn't fit in them.
+    fn make_room_for_width(&mut self, span: &CellSpan, x_spacing: f32) {
+        let last_col = span.col + span.colspan - 1;
+        let other_widths: f32 = (span.col..last_col)
+            .map(|col| self.col_width(col).unwrap_or(0.0) + x_spacing)
+            .sum();
+        self.set_min_col_width(last_col, span.size.x - other_widths);
+    }
+
+    /// Make the last of the spanned rows taller, if the cell doesn't fit in them.
+    fn make_room_for_height(&mut self, span: &CellSpan, y_spacing: f32) {
+        let last_row = span.last_row();
+        let other_heights: f32 = (span.row..last_row)
+            .map(|row| self.row_height(row).unwrap_or(0.0) + y_spacing)
+            .sum();
+        self.set_min_row_height(last_row, span.size.y - other_heights);
+    }
+}
+
+/// A cell spanning more than one column or row, see [`GridCell`].
+#[derive(Clone, Copy, Debug)]
+struct CellSpan {
+    col: usize,
+    row: usize,
+    colspan: usize,
+    rowspan: usize,
+    size: Vec2,
+}
+
+impl CellSpan {
+    fn last_row(&self) -> usize {
+        self.row + self.rowspan - 1
+    }
+}
+
+/// The widths the user has resized the columns of a [`Grid::resizable`] to.
+///
+/// Unlike [`State`] this is persisted, since the user chose these widths.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct ResizedColumns {
+    /// `None` for columns that fit their contents.
+    widths: Vec<Option<f32>>,
+}
+
+impl ResizedColumns {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id.with("__resized_columns")))
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id.with("__resized_columns"), self));
+    }
+
+    fn width(&self, col: usize) -> Option<f32> {
+        self.widths.get(col).copied().flatten()
+    }
+
+    fn set_width(&mut self, col: usize, width: Option<f32>) {
+        self.widths.resize(self.widths.len().max(col + 1), None);
+        self.widths[col] = width;
+    }
+}
+
+/// How wide a column of a [`Grid`] is, see [`Grid::column_width`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridColumnWidth {
+    /// Always this wide, no matter the contents.
+    Exact(f32),
+
+    /// At least this wide, and wider if the contents need it.
+    AtLeast(f32),
+
+    /// Take up the width that is left over by the other columns, e.g. for the last column.
+    Remainder,
+}
+
+/// Options for one column of a [`Grid`].
+#[derive(Clone, Copy, Debug, Default)]
+struct ColumnOptions {
+    align: Option<Align>,
+    width: Option<GridColumnWidth>,
 }
 
 // ----------------------------------------------------------------------------
@@ -71,10 +154,27 @@ pub(crate) struct GridLayout {
     min_cell_size: Vec2,
     max_cell_size: Vec2,
     color_picker: Option<ColorPickerFn>,
+    resized_columns: ResizedColumns,
+    columns: Vec<ColumnOptions>,
+
+    /// The row number of the first row, for [`Self::color_picker`].
+    start_row: usize,
+
+    /// Never make columns narrower than they were the previous frame.
+    keep_col_widths: bool,
 
     // Cursor:
     col: usize,
     row: usize,
+
+    /// How many columns and rows the next cell spans, see [`GridCell`].
+    next_span: (usize, usize),
+
+    /// Cells that span more than one column or row this frame.
+    spans: Vec<CellSpan>,
+
+    /// For each column, the first row that is not covered by a cell spanning several rows.
+    free_from_row: Vec<usize>,
 }
 
 impl GridLayout {
@@ -106,17 +206,59 @@ impl GridLayout {
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
             color_picker: None,
+            resized_columns: ResizedColumns::default(),
+            columns: Vec::new(),
+            start_row: 0,
+            keep_col_widths: false,
 
             col: 0,
             row: 0,
+            next_span: (1, 1),
+            spans: Vec::new(),
+            free_from_row: Vec::new(),
         }
     }
 }
 
 impl GridLayout {
+    fn column_options(&self, col: usize) -> ColumnOptions {
+        self.columns.get(col).copied().unwrap_or_default()
+    }
+
+    /// The width of a column that doesn't depend on its contents, if any.
+    fn fixed_col_width(&self, col: usize) -> Option<f32> {
+        match self.column_options(col).width {
+            Some(GridColumnWidth::Exact(width)) => Some(width),
+            _ => self.resized_columns.width(col),
+        }
+    }
+
+    fn min_col_width(&self, col: usize) -> f32 {
+        match self.column_options(col).width {
+            Some(GridColumnWidth::AtLeast(width)) => width.max(self.min_cell_size.x),
+            _ => self.min_cell_size.x,
+        }
+    }
+
+    /// If the current column is [`GridColumnWidth::Remainder`], how wide it is when it starts at `left`.
+    fn remainder_width(&self, left: f32) -> Option<f32> {
+        if self.is_first_frame
+            || self.column_options(self.col).width != Some(GridColumnWidth::Remainder)
+        {
+            return None;
+        }
+        let later_columns: f32 = (self.col + 1..self.prev_state.col_widths.len())
+            .map(|col| self.spacing.x + self.prev_col_width(col))
+            .sum();
+        let width = self.initial_available.right() - left - later_columns;
+        width
+            .is_finite()
+            .then(|| width.at_least(self.min_col_width(self.col)))
+    }
+
     fn prev_col_width(&self, col: usize) -> f32 {
-        self.prev_state
-            .col_width(col)
+        self.fixed_col_width(col)
+            .or_else(|| self.prev_state.col_width(col))
             .unwrap_or(self.min_cell_size.x)
     }
 
@@ -126,12 +268,48 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.y)
     }
 
+    /// The width of the next cell according to the previous frame, including the spacing between the columns it spans.
+    fn prev_span_width(&self) -> f32 {
+        let colspan = self.next_span.0;
+        (self.col..self.col + colspan)
+            .map(|col| self.prev_col_width(col))
+            .sum::<f32>()
+            + (colspan - 1) as f32 * self.spacing.x
+    }
+
+    /// The height of the next cell according to the previous frame, including the spacing between the rows it spans.
+    fn prev_span_height(&self) -> f32 {
+        let rowspan = self.next_span.1;
+        (self.row..self.row + rowspan)
+            .map(|row| self.prev_row_height(row))
+            .sum::<f32>()
+            + (rowspan - 1) as f32 * self.spacing.y
+    }
+
+    /// Let the next cell span this many columns and rows.
+    pub(crate) fn set_next_span(&mut self, colspan: usize, rowspan: usize) {
+        self.next_span = (colspan.at_least(1), rowspan.at_least(1));
+    }
+
+    /// Move past the columns that are covered by cells from rows above.
+    fn skip_covered_columns(&mut self, cursor: &mut Rect) {
+        while self
+            .free_from_row
+            .get(self.col)
+            .map_or(false, |&free_from_row| self.row < free_from_row)
+        {
+            cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
+            self.col += 1;
+        }
+    }
+
     pub(crate) fn wrap_text(&self) -> bool {
         self.max_cell_size.x.is_finite()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let (colspan, _) = self.next_span;
+        let is_last_column = Some(self.col + colspan) == self.num_columns;
 
         let width = if is_last_column {
             // The first frame we don't really know the widths of the previous columns,
@@ -144,6 +322,8 @@ impl GridLayout {
                 (self.initial_available.right() - region.cursor.left())
                     .at_most(self.max_cell_size.x)
             }
+        } else if 1 < colspan {
+            self.prev_span_width()
         } else if self.max_cell_size.x.is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
             self.max_cell_size.x
@@ -156,8 +336,17 @@ impl GridLayout {
                 .unwrap_or(self.min_cell_size.x)
         };
 
-        // If something above was wider, we can be wider:
-        let width = width.max(self.curr_state.col_width(self.col).unwrap_or(0.0));
+        let fixed_width = self.fixed_col_width(self.col).filter(|_| colspan == 1);
+        let width = if let Some(fixed_width) = fixed_width {
+            fixed_width
+        } else if 1 < colspan {
+            width
+        } else if let Some(remainder_width) = self.remainder_width(region.cursor.left()) {
+            remainder_width
+        } else {
+            // If something above was wider, we can be wider:
+            width.max(self.curr_state.col_width(self.col).unwrap_or(0.0))
+        };
 
         let available = region.max_rect.intersect(region.cursor);
 
@@ -170,16 +359,21 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
-        let height = self.prev_row_height(self.row);
+        let width = if self.next_span.0 == 1 {
+            self.fixed_col_width(self.col)
+                .or_else(|| self.prev_state.col_width(self.col))
+                .unwrap_or(0.0)
+        } else {
+            self.prev_span_width()
+        };
+        let height = self.prev_span_height();
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size)
     }
 
-    #[allow(clippy::unused_self)]
     pub(crate) fn align_size_within_rect(&self, size: Vec2, frame: Rect) -> Rect {
-        // TODO(emilk): allow this alignment to be customized
-        Align2::LEFT_CENTER.align_size_within_rect(size, frame)
+        let align = self.column_options(self.col).align.unwrap_or(Align::Min);
+        Align2([align, Align::Center]).align_size_within_rect(size, frame)
     }
 
     pub(crate) fn justify_and_align(&self, frame: Rect, size: Vec2) -> Rect {
@@ -212,13 +406,45 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
-        self.curr_state
-            .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
+        let (colspan, rowspan) = std::mem::replace(&mut self.next_span, (1, 1));
+        let size = widget_rect.size().max(self.min_cell_size);
+
+        if colspan == 1 {
+            let col_width = self.fixed_col_width(self.col).unwrap_or_else(|| {
+                let remainder_width = self.remainder_width(cursor.left()).unwrap_or(0.0);
+                size.x
+                    .max(self.min_col_width(self.col))
+                    .max(remainder_width)
+            });
+            self.curr_state.set_min_col_width(self.col, col_width);
+        }
+        if rowspan == 1 {
+            self.curr_state.set_min_row_height(self.row, size.y);
+        }
+        if 1 < colspan || 1 < rowspan {
+            // How much space the spanned columns and rows need is decided once all cells are known:
+            self.spans.push(CellSpan {
+                col: self.col,
+                row: self.row,
+                colspan,
+                rowspan,
+                size,
+            });
+        }
+        if 1 < rowspan {
+            let end_col = self.col + colspan;
+            self.free_from_row
+                .resize(self.free_from_row.len().max(end_col), 0);
+            for free_from_row in &mut self.free_from_row[self.col..end_col] {
+                *free_from_row = self.row + rowspan;
+            }
+        }
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        for _ in 0..colspan {
+            cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
+            self.col += 1;
+        }
+        self.skip_covered_columns(cursor);
     }
 
     fn paint_row(&mut self, cursor: &Rect, painter: &Painter) {
@@ -226,7 +452,7 @@ impl GridLayout {
         let Some(color_picker) = self.color_picker.as_ref() else {
             return;
         };
-        let Some(row_color) = color_picker(self.row, &self.style) else {
+        let Some(row_color) = color_picker(self.start_row + self.row, &self.style) else {
             return;
         };
         let Some(height) = self.prev_state.row_height(self.row) else {
@@ -242,6 +468,13 @@ impl GridLayout {
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
+        // All the rows of cells ending in this row are known now:
+        for span in &self.spans {
+            if span.last_row() == self.row {
+                self.curr_state.make_room_for_height(span, self.spacing.y);
+            }
+        }
+
         cursor.min.x = self.initial_available.min.x;
         cursor.min.y += self.spacing.y;
         cursor.min.y += self
@@ -253,11 +486,22 @@ impl GridLayout {
         self.row += 1;
 
         self.paint_row(cursor, painter);
+        self.skip_covered_columns(cursor);
     }
 
     pub(crate) fn save(&self) {
-        if self.curr_state != self.prev_state {
-            self.curr_state.clone().store(&self.ctx, self.id);
+        let mut curr_state = self.curr_state.clone();
+        for span in &self.spans {
+            curr_state.make_room_for_width(span, self.spacing.x);
+            curr_state.make_room_for_height(span, self.spacing.y);
+        }
+        if self.keep_col_widths {
+            for (col, &width) in self.prev_state.col_widths.iter().enumerate() {
+                curr_state.set_min_col_width(col, width);
+            }
+        }
+        if curr_state != self.prev_state {
+            curr_state.store(&self.ctx, self.id);
             self.ctx.request_repaint();
         }
     }
@@ -301,6 +545,9 @@ pub struct Grid {
     spacing: Option<Vec2>,
     start_row: usize,
     color_picker: Option<ColorPickerFn>,
+    resizable: bool,
+    columns: Vec<ColumnOptions>,
+    keep_col_widths: bool,
 }
 
 impl Grid {
@@ -315,7 +562,17 @@ impl Grid {
             spacing: None,
             start_row: 0,
             color_picker: None,
+            resizable: false,
+            columns: Vec::new(),
+            keep_col_widths: false,
+        }
+    }
+
+    fn column_options_mut(&mut self, col: usize) -> &mut ColumnOptions {
+        if self.columns.len() <= col {
+            self.columns.resize(col + 1, ColumnOptions::default());
         }
+        &mut self.columns[col]
     }
 
     /// Setting this will allow for dynamic coloring of rows of the grid object
@@ -381,6 +638,37 @@ impl Grid {
         self
     }
 
+    /// How to align the contents of the cells of a column (counting from zero) horizontally,
+    /// e.g. [`Align::Max`] to right-align numbers.
+    ///
+    /// Default: [`Align::Min`].
+    #[inline]
+    pub fn column_align(mut self, col: usize, align: Align) -> Self {
+        self.column_options_mut(col).align = Some(align);
+        self
+    }
+
+    /// How wide a column (counting from zero) is.
+    ///
+    /// By default a column is as wide as its widest cell.
+    #[inline]
+    pub fn column_width(mut self, col: usize, width: GridColumnWidth) -> Self {
+        self.column_options_mut(col).width = Some(width);
+        self
+    }
+
+    /// Let the user resize the columns by dragging the space between them.
+    ///
+    /// The widths are remembered by the id of the grid.
+    /// Double-click the space after a column to make it fit its contents again.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
     /// Change which row number the grid starts on.
     /// This can be useful when you have a large [`Grid`] inside of [`ScrollArea::show_rows`].
     #[inline]
@@ -391,6 +679,64 @@ impl Grid {
 }
 
 impl Grid {
+    /// Show a grid with a huge number of rows in a vertical [`ScrollArea`],
+    /// only laying out the rows that are visible.
+    ///
+    /// `add_row` is called for each visible row with its index, and should add the cells of that row
+    /// (without calling [`Ui::end_row`]).
+    /// All rows must fit in `row_height`.
+    ///
+    /// Since only some of the rows are measured, the columns never get narrower,
+    /// so they don't jump around while scrolling.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let row_height = ui.spacing().interact_size.y;
+    /// egui::Grid::new("log").striped(true).show_rows(ui, row_height, 100_000, |ui, row| {
+    ///     ui.label(row.to_string());
+    ///     ui.label(format!("Message number {row}"));
+    /// });
+    /// # });
+    /// ```
+    pub fn show_rows(
+        mut self,
+        ui: &mut Ui,
+        row_height: f32,
+        total_rows: usize,
+        mut add_row: impl FnMut(&mut Ui, usize),
+    ) -> scroll_area::ScrollAreaOutput<()> {
+        let spacing = self.spacing.unwrap_or_else(|| ui.spacing().item_spacing);
+        let row_height_with_spacing = row_height + spacing.y;
+        self.min_row_height = Some(row_height);
+        self.keep_col_widths = true;
+
+        ScrollArea::vertical()
+            .id_source(self.id_source)
+            .show_viewport(ui, |ui, viewport| {
+                ui.set_height(
+                    (row_height_with_spacing * total_rows as f32 - spacing.y).at_least(0.0),
+                );
+
+                let min_row =
+                    ((viewport.min.y / row_height_with_spacing).floor() as usize).min(total_rows);
+                let max_row = ((viewport.max.y / row_height_with_spacing).ceil() as usize + 1)
+                    .min(total_rows);
+
+                let y_min = ui.max_rect().top() + min_row as f32 * row_height_with_spacing;
+                let rect =
+                    Rect::from_x_y_ranges(ui.max_rect().x_range(), y_min..=ui.max_rect().bottom());
+                ui.allocate_ui_at_rect(rect, |ui| {
+                    ui.skip_ahead_auto_ids(min_row); // Make sure we get consistent IDs.
+                    self.start_row(min_row).show(ui, |ui| {
+                        for row in min_row..max_row {
+                            add_row(ui, row);
+                            ui.end_row();
+                        }
+                    });
+                });
+            })
+    }
+
     pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
         self.show_dyn(ui, Box::new(add_contents))
     }
@@ -409,6 +755,9 @@ impl Grid {
             spacing,
             start_row,
             mut color_picker,
+            resizable,
+            columns,
+            keep_col_widths,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
@@ -419,6 +768,11 @@ impl Grid {
 
         let id = ui.make_persistent_id(id_source);
         let prev_state = State::load(ui.ctx(), id);
+        let resized_columns = if resizable {
+            ResizedColumns::load(ui.ctx(), id).unwrap_or_default()
+        } else {
+            ResizedColumns::default()
+        };
 
         // Each grid cell is aligned LEFT_CENTER.
         // If somebody wants to wrap more things inside a cell,
@@ -438,7 +792,10 @@ impl Grid {
                     min_cell_size: vec2(min_col_width, min_row_height),
                     max_cell_size,
                     spacing,
-                    row: start_row,
+                    start_row,
+                    keep_col_widths,
+                    resized_columns: resized_columns.clone(),
+                    columns,
                     ..GridLayout::new(ui, id, prev_state)
                 };
 
@@ -452,6 +809,9 @@ impl Grid {
                 ui.set_grid(grid);
                 let r = add_contents(ui);
                 ui.save_grid();
+                if resizable {
+                    resize_columns(ui, id, resized_columns, min_col_width, spacing.x);
+                }
                 r
             })
             .inner
@@ -459,6 +819,136 @@ impl Grid {
     }
 }
 
+/// Let the user drag the space after each column of the grid in `ui` to resize it.
+fn resize_columns(
+    ui: &Ui,
+    id: Id,
+    mut resized_columns: ResizedColumns,
+    min_col_width: f32,
+    x_spacing: f32,
+) {
+    let Some(state) = State::load(ui.ctx(), id) else {
+        return;
+    };
+    let grid_rect = ui.min_rect();
+    let grab_radius = ui.style().interaction.resize_grab_radius_side;
+    let mut changed = false;
+
+    let mut left = grid_rect.left();
+    for (col, &width) in state.col_widths.iter().enumerate() {
+        let edge_x = left + width + 0.5 * x_spacing;
+        let edge_rect = Rect::from_x_y_ranges(edge_x..=edge_x, grid_rect.y_range())
+            .expand2(vec2(grab_radius, 0.0));
+        let response = ui.interact(
+            edge_rect,
+            id.with(("__resize_column", col)),
+            Sense::click_and_drag(),
+        );
+
+        if response.double_clicked() {
+            resized_columns.set_width(col, None);
+            changed = true;
+            // Measure the contents again next frame:
+            ui.data_mut(|d| d.remove::<State>(id));
+        } else if response.dragged() {
+            if let Some(pointer) = response.interact_pointer_pos() {
+                let new_width = (pointer.x - 0.5 * x_spacing - left).at_least(min_col_width);
+                resized_columns.set_width(col, Some(new_width));
+                changed = true;
+            }
+        }
+
+        let stroke = if response.dragged() {
+            ui.visuals().widgets.active.fg_stroke
+        } else if response.hovered() {
+            ui.visuals().widgets.hovered.fg_stroke
+        } else {
+            Stroke::NONE
+        };
+        if stroke != Stroke::NONE {
+            ui.ctx().set_cursor_icon(CursorIcon::ResizeColumn);
+            let edge_x = ui.painter().round_to_pixel(edge_x);
+            ui.painter().vline(edge_x, grid_rect.y_range(), stroke);
+        }
+
+        left += width + x_spacing;
+    }
+
+    if changed {
+        resized_columns.store(ui.ctx(), id);
+        ui.ctx().request_repaint();
+    }
+}
+
+/// A cell of a [`Grid`] that spans several columns and/or rows.
+///
+/// Cells in later rows skip the columns covered by cells spanning several rows.
+/// Like any grid cell the contents are laid out left to right,
+/// so use e.g. [`Ui::vertical`] to stack several widgets in a cell spanning several rows.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Grid::new("form").num_columns(2).show(ui, |ui| {
+///     ui.label("Name");
+///     ui.label("Ferris");
+///     ui.end_row();
+///
+///     egui::GridCell::new().colspan(2).show(ui, |ui| {
+///         ui.add(egui::Separator::default().horizontal());
+///     });
+///     ui.end_row();
+///
+///     ui.label("Species");
+///     ui.label("Crab");
+///     ui.end_row();
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct GridCell {
+    colspan: usize,
+    rowspan: usize,
+}
+
+impl Default for GridCell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GridCell {
+    /// A cell covering one column and one row.
+    pub fn new() -> Self {
+        Self {
+            colspan: 1,
+            rowspan: 1,
+        }
+    }
+
+    /// How many columns the cell covers. Default: `1`.
+    #[inline]
+    pub fn colspan(mut self, colspan: usize) -> Self {
+        self.colspan = colspan;
+        self
+    }
+
+    /// How many rows the cell covers, starting with this one. Default: `1`.
+    #[inline]
+    pub fn rowspan(mut self, rowspan: usize) -> Self {
+        self.rowspan = rowspan;
+        self
+    }
+
+    /// Add the contents of the cell to the grid in `ui`.
+    ///
+    /// Outside of a [`Grid`] this is the same as [`Ui::scope`].
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        ui.set_next_grid_span(self.colspan, self.rowspan);
+        ui.scope(add_contents)
+    }
+}
+
 fn striped_row_color(row: usize, style: &Style) -> Option<Color32> {
     if row % 2 == 1 {
         return Some(style.visuals.faint_bg_color);
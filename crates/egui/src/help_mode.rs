@@ -0,0 +1,260 @@
+//! A help mode, where clicking a widget shows its help text instead of using the widget.
+//!
+//! Annotate widgets with [`Response::help`], and let the user enter help mode
+//! by clicking a [`toggle`] button,
+//! or by pressing F1 if you turn on [`crate::Options::help_mode_with_keyboard`].
+//!
+//! In help mode, all annotated widgets are highlighted,
+//! and all other interaction is blocked.
+//! Press Escape (or F1 again, if turned on) to leave help mode.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! egui::help_mode::toggle(ui);
+//! ui.button("Bake")
+//!     .help("Bakes all the lighting in the scene. This can take a few minutes.");
+//! # });
+//! ```
+
+use crate::*;
+
+/// Register this plugin on the given egui context,
+/// so that it will be called every frame.
+///
+/// This is a built-in plugin in egui,
+/// meaning [`Context`] calls this from its `Default` implementation,
+/// so this is marked as `pub(crate)`.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("help_mode", std::sync::Arc::new(State::end_frame));
+}
+
+/// Is help mode on?
+pub fn is_enabled(ctx: &Context) -> bool {
+    ctx.data(|data| data.get_temp::<State>(Id::NULL))
+        .map_or(false, |state| state.enabled)
+}
+
+/// Turn help mode on or off.
+pub fn set_enabled(ctx: &Context, enabled: bool) {
+    ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<State>(Id::NULL);
+        state.enabled = enabled;
+        state.shown = None;
+    });
+    ctx.request_repaint();
+}
+
+/// A small button that turns help mode on and off.
+pub fn toggle(ui: &mut Ui) -> Response {
+    let mut enabled = is_enabled(ui.ctx());
+    let hover_text = if ui.ctx().options(|o| o.help_mode_with_keyboard) {
+        "Help mode: click on something to learn what it does (F1)"
+    } else {
+        "Help mode: click on something to learn what it does"
+    };
+    let response = ui
+        .toggle_value(&mut enabled, "❓")
+        .on_hover_text(hover_text);
+    if response.clicked() {
+        set_enabled(ui.ctx(), enabled);
+    }
+    response
+}
+
+/// Register the help text of a widget. Called by [`Response::help`].
+pub(crate) fn annotate(response: &Response, text: WidgetText) {
+    let ctx = &response.ctx;
+    ctx.data_mut(|data| {
+        let state = data.get_temp_mut_or_default::<State>(Id::NULL);
+        if state.enabled {
+            state.annotations.push(Annotation {
+                id: response.id,
+                layer_id: response.layer_id,
+                rect: response.rect,
+                text,
+            });
+        }
+    });
+}
+
+#[derive(Clone)]
+struct Annotation {
+    id: Id,
+    layer_id: LayerId,
+    rect: Rect,
+    text: WidgetText,
+}
+
+/// The help mode plugin.
+///
+/// This is a built-in plugin in egui.
+#[derive(Clone, Default)]
+struct State {
+    enabled: bool,
+
+    /// The widget whose help text is shown.
+    shown: Option<Id>,
+
+    // This gets re-filled every frame.
+    annotations: Vec<Annotation>,
+}
+
+impl State {
+    fn end_frame(ctx: &Context) {
+        if ctx.options(|o| o.help_mode_with_keyboard)
+            && ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::F1))
+        {
+            set_enabled(ctx, !is_enabled(ctx));
+        }
+
+        let mut state = ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<Self>(Id::NULL);
+            let annotations = std::mem::take(&mut state.annotations);
+            Self {
+                annotations,
+                ..state.clone()
+            }
+        });
+        if !state.enabled {
+            return;
+        }
+
+        if ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape)) {
+            if state.shown.is_some() {
+                state.shown = None;
+            } else {
+                state.enabled = false;
+            }
+        }
+
+        if state.enabled {
+            state.show(ctx);
+        }
+
+        ctx.data_mut(|data| {
+            let stored = data.get_temp_mut_or_default::<Self>(Id::NULL);
+            stored.enabled = state.enabled;
+            stored.shown = state.shown;
+        });
+    }
+
+    fn show(&mut self, ctx: &Context) {
+        let screen_rect = ctx.screen_rect();
+
+        // Annotations on top of others come last:
+        let layer_order: Vec<LayerId> = ctx.memory(|mem| mem.areas().order().to_vec());
+        let layer_index = |layer_id: LayerId| layer_order.iter().position(|l| *l == layer_id);
+        self.annotations
+            .sort_by_key(|annotation| layer_index(annotation.layer_id));
+        let transformed = |annotation: &Annotation| {
+            ctx.memory(|mem| mem.layer_transforms.get(&annotation.layer_id).copied())
+                .map_or(annotation.rect, |transform| transform * annotation.rect)
+        };
+
+        let overlay = Area::new(Id::new("__egui_help_mode"))
+            .order(Order::Foreground)
+            .fixed_pos(screen_rect.min)
+            .constrain(false)
+            .show(ctx, |ui| {
+                let (rect, response) =
+                    ui.allocate_exact_size(screen_rect.size(), Sense::click_and_drag());
+                let painter = ui.painter();
+                painter.rect_filled(rect, 0.0, Color32::from_black_alpha(40));
+
+                let pointer_pos = response.hover_pos();
+                let hovered = pointer_pos.and_then(|pos| {
+                    self.annotations
+                        .iter()
+                        .rev()
+                        .find(|annotation| transformed(annotation).contains(pos))
+                        .map(|annotation| annotation.id)
+                });
+
+                let selection = ui.visuals().selection;
+                for annotation in &self.annotations {
+                    let rect = transformed(annotation).expand(2.0);
+                    if Some(annotation.id) == hovered || Some(annotation.id) == self.shown {
+                        painter.rect(rect, 2.0, selection.bg_fill.gamma_multiply(0.3), selection.stroke);
+                    } else {
+                        painter.rect_stroke(rect, 2.0, (1.0, selection.stroke.color));
+                    }
+                }
+
+                painter.text(
+                    rect.center_top() + vec2(0.0, 8.0),
+                    Align2::CENTER_TOP,
+                    "Help mode: click on a highlighted widget to learn about it. Press Esc to exit.",
+                    TextStyle::Body.resolve(ui.style()),
+                    ui.visuals().strong_text_color(),
+                );
+
+                if response.clicked() {
+                    self.shown = hovered;
+                }
+
+                if hovered.is_some() {
+                    ctx.set_cursor_icon(CursorIcon::Help);
+                }
+            });
+        ctx.move_to_top(overlay.response.layer_id);
+
+        let Some(annotation) = self
+            .shown
+            .and_then(|id| self.annotations.iter().find(|a| a.id == id))
+        else {
+            self.shown = None;
+            return;
+        };
+
+        let widget_rect = transformed(annotation);
+        let popup = Area::new(Id::new("__egui_help_mode_popup"))
+            .order(Order::Foreground)
+            .fixed_pos(widget_rect.left_bottom() + vec2(0.0, 4.0))
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_max_width(ui.spacing().tooltip_width);
+                    ui.add(Label::new(annotation.text.clone()).selectable(false));
+                });
+            });
+        ctx.move_to_top(popup.response.layer_id);
+    }
+}
+
+#[test]
+fn test_help_mode() {
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    ctx.options_mut(|o| o.help_mode_with_keyboard = true);
+    let run = |events: Vec<Event>| {
+        harness.run_ui(events, |ui| ui.button("Bake").help("Bakes the lighting."))
+    };
+    let key = TestHarness::key_event;
+    let shown = || {
+        ctx.data(|data| data.get_temp::<State>(Id::NULL))
+            .and_then(|s| s.shown)
+    };
+
+    let button = run(vec![]);
+    run(vec![key(Key::F1)]);
+    assert!(is_enabled(ctx));
+
+    // Clicking the widget shows its help, instead of clicking it:
+    let pos = button.rect.center();
+    let press = TestHarness::button_event(pos, PointerButton::Primary, true);
+    let release = TestHarness::button_event(pos, PointerButton::Primary, false);
+    run(vec![Event::PointerMoved(pos)]);
+    assert!(!run(vec![press]).is_pointer_button_down_on());
+    assert!(!run(vec![release]).clicked());
+    run(vec![]);
+    assert_eq!(shown(), Some(button.id));
+
+    // The first Escape hides the help, the second one leaves help mode:
+    run(vec![key(Key::Escape)]);
+    assert_eq!(shown(), None);
+    assert!(is_enabled(ctx));
+    run(vec![key(Key::Escape)]);
+    assert!(!is_enabled(ctx));
+
+    // Without help mode, the button works again:
+    assert!(run(TestHarness::click_events(pos)).clicked());
+}
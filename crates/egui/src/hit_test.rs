@@ -56,7 +56,13 @@ pub fn hit_test(
     let mut close: Vec<WidgetRect> = layer_order
         .iter()
         .filter(|layer| layer.order.allow_interaction())
-        .flat_map(|&layer_id| widgets.get_layer(layer_id))
+        .flat_map(|&layer_id| {
+            // Only widgets within `search_radius` can be hit, so we only need to check those:
+            let pos_in_layer = pos_in_layers.get(&layer_id).copied().unwrap_or(pos);
+            let search_rect =
+                Rect::from_center_size(pos_in_layer, Vec2::splat(2.0 * search_radius));
+            widgets.get_layer_candidates(layer_id, search_rect)
+        })
         .filter(|&w| {
             let pos_in_layer = pos_in_layers.get(&w.layer_id).copied().unwrap_or(pos);
             let dist_sq = w.interact_rect.distance_sq_to_pos(pos_in_layer);
@@ -203,7 +203,7 @@ impl InputState {
         for touch_state in self.touch_states.values_mut() {
             touch_state.begin_frame(time, &new, self.pointer.interact_pos);
         }
-        let pointer = self.pointer.begin_frame(time, &new);
+        let pointer = self.pointer.begin_frame(time, &new, options);
 
         let mut keys_down = self.keys_down;
         let mut zoom_factor_delta = 1.0; // TODO(emilk): smoothing for zoom factor
@@ -349,6 +349,14 @@ impl InputState {
         self.screen_rect
     }
 
+    /// The part of [`Self::screen_rect`] that is not covered by display cutouts, rounded corners etc.
+    ///
+    /// See [`RawInput::safe_area_insets`].
+    #[inline]
+    pub fn safe_area_rect(&self) -> Rect {
+        self.screen_rect - self.raw.safe_area_insets
+    }
+
     /// Zoom scale factor this frame (e.g. from ctrl-scroll or pinch gesture).
     /// * `zoom = 1`: no change
     /// * `zoom < 1`: pinch together
@@ -745,6 +753,11 @@ pub struct PointerState {
 
     /// All button events that occurred this frame
     pub(crate) pointer_events: Vec<PointerEvent>,
+
+    /// Where a pen or finger is hovering above the screen, and how far above it.
+    ///
+    /// See [`Event::PointerProximity`].
+    proximity: Option<(Pos2, Option<f32>)>,
 }
 
 impl Default for PointerState {
@@ -766,13 +779,19 @@ impl Default for PointerState {
             last_last_click_time: std::f64::NEG_INFINITY,
             last_move_time: std::f64::NEG_INFINITY,
             pointer_events: vec![],
+            proximity: None,
         }
     }
 }
 
 impl PointerState {
     #[must_use]
-    pub(crate) fn begin_frame(mut self, time: f64, new: &RawInput) -> Self {
+    pub(crate) fn begin_frame(
+        mut self,
+        time: f64,
+        new: &RawInput,
+        options: &crate::Options,
+    ) -> Self {
         let was_decidedly_dragging = self.is_decidedly_dragging();
 
         self.time = time;
@@ -821,6 +840,9 @@ impl PointerState {
                     }
 
                     if pressed {
+                        // Touching the screen ends the hovering above it:
+                        self.proximity = None;
+
                         self.press_origin = Some(pos);
                         self.press_start_time = Some(time);
                         self.has_moved_too_much_for_a_click = false;
@@ -872,6 +894,12 @@ impl PointerState {
                     // so we don't treat this as a `PointerEvent::Released`.
                     // NOTE: we do NOT clear `self.interact_pos` here. It will be cleared next frame.
                 }
+                Event::PointerProximity { pos, distance } => {
+                    self.proximity = Some((*pos, *distance));
+                }
+                Event::PointerProximityGone => {
+                    self.proximity = None;
+                }
                 Event::MouseMoved(delta) => *self.motion.get_or_insert(Vec2::ZERO) += *delta,
                 _ => {}
             }
@@ -883,7 +911,9 @@ impl PointerState {
             Vec2::ZERO
         };
 
-        if let Some(pos) = self.latest_pos {
+        if !options.track_pointer_velocity {
+            self.pos_history.clear();
+        } else if let Some(pos) = self.latest_pos {
             self.pos_history.add(time, pos);
         } else {
             // we do not clear the `pos_history` here, because it is exactly when a finger has
@@ -968,6 +998,24 @@ impl PointerState {
         self.interact_pos
     }
 
+    /// Where a pen (or finger) is hovering above the screen, without touching it.
+    ///
+    /// Only some devices report this, see [`Event::PointerProximity`].
+    /// To also hover widgets with it, turn on [`crate::style::Interaction::proximity_hover`].
+    #[inline(always)]
+    pub fn proximity_pos(&self) -> Option<Pos2> {
+        self.proximity.map(|(pos, _)| pos)
+    }
+
+    /// How far above the screen a pen (or finger) is hovering,
+    /// from 0.0 (touching) to 1.0 (as far as the device can detect).
+    ///
+    /// `None` if nothing is hovering above the screen, or if the device doesn't report the distance.
+    #[inline(always)]
+    pub fn proximity_distance(&self) -> Option<f32> {
+        self.proximity.and_then(|(_, distance)| distance)
+    }
+
     /// Do we have a pointer?
     ///
     /// `false` if the mouse is not over the egui area, or if no touches are down on touch screens.
@@ -1276,6 +1324,7 @@ impl PointerState {
             last_last_click_time,
             pointer_events,
             last_move_time,
+            proximity,
         } = self;
 
         ui.label(format!("latest_pos: {latest_pos:?}"));
@@ -1299,5 +1348,6 @@ impl PointerState {
         ui.label(format!("last_last_click_time: {last_last_click_time:#?}"));
         ui.label(format!("last_move_time: {last_move_time:#?}"));
         ui.label(format!("pointer_events: {pointer_events:?}"));
+        ui.label(format!("proximity: {proximity:?}"));
     }
 }
@@ -124,6 +124,12 @@ impl PaintList {
         self.0.is_empty()
     }
 
+    /// The number of shapes in the list, which is also the index the next added [`Shape`] will get.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
     /// Returns the index of the new [`Shape`] that can be used with `PaintList::set`.
     #[inline(always)]
     pub fn add(&mut self, clip_rect: Rect, shape: Shape) -> ShapeIdx {
@@ -166,10 +172,38 @@ impl PaintList {
         }
     }
 
+    /// Translate the shapes in `range`, but not their clip rectangles.
+    pub fn translate(&mut self, range: std::ops::Range<usize>, delta: Vec2) {
+        for clipped in &mut self.0[range] {
+            clipped.shape.translate(delta);
+        }
+    }
+
+    /// Move the shapes in `range` to the end of the list, so that they are painted on top of everything else,
+    /// translating them by `delta` and clipping them to `clip_rect`.
+    ///
+    /// Their old places in the list are left as [`Shape::Noop`].
+    pub fn move_to_top(&mut self, range: std::ops::Range<usize>, delta: Vec2, clip_rect: Rect) {
+        let moved: Vec<ClippedShape> = self.0[range]
+            .iter_mut()
+            .map(|clipped| {
+                let mut shape = std::mem::replace(&mut clipped.shape, Shape::Noop);
+                shape.translate(delta);
+                ClippedShape { clip_rect, shape }
+            })
+            .collect();
+        self.0.extend(moved);
+    }
+
     /// Read-only access to all held shapes.
     pub fn all_entries(&self) -> impl ExactSizeIterator<Item = &ClippedShape> {
         self.0.iter()
     }
+
+    /// Remove all shapes.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
 }
 
 /// This is where painted [`Shape`]s end up during a frame.
@@ -194,6 +228,30 @@ impl GraphicLayers {
         self.0[layer_id.order as usize].get_mut(&layer_id.id)
     }
 
+    /// All non-empty layers, in the order they will be painted by [`Self::drain`].
+    pub fn layer_ids_in_paint_order(&self, area_order: &[LayerId]) -> Vec<LayerId> {
+        let mut layer_ids = vec![];
+        for &order in &Order::ALL {
+            let order_map = &self.0[order as usize];
+            for layer_id in area_order {
+                if layer_id.order == order
+                    && order_map
+                        .get(&layer_id.id)
+                        .is_some_and(|list| !list.is_empty())
+                {
+                    layer_ids.push(*layer_id);
+                }
+            }
+            for (id, list) in order_map {
+                let layer_id = LayerId::new(order, *id);
+                if !list.is_empty() && !area_order.contains(&layer_id) {
+                    layer_ids.push(layer_id);
+                }
+            }
+        }
+        layer_ids
+    }
+
     pub fn drain(
         &mut self,
         area_order: &[LayerId],
@@ -244,3 +302,43 @@ impl GraphicLayers {
         all_shapes
     }
 }
+
+/// How much a layer painted last frame.
+///
+/// Shown by [`crate::Context::layers_ui`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LayerStats {
+    pub layer_id: LayerId,
+    pub num_shapes: usize,
+
+    /// Number of vertices after tessellation.
+    ///
+    /// Only counted for the rows that are expanded in the list, since it means
+    /// tessellating the layer an extra time.
+    pub num_vertices: Option<usize>,
+}
+
+/// State of the layer list in [`crate::Context::layers_ui`].
+#[derive(Clone, Default)]
+pub(crate) struct LayerInspection {
+    /// Set by [`crate::Context::layers_ui`] each frame it is shown.
+    pub is_shown: bool,
+
+    /// Collected at the end of the last frame, in paint order.
+    pub stats: Vec<LayerStats>,
+
+    /// These layers are not painted (but can still be interacted with).
+    pub hidden: ahash::HashSet<LayerId>,
+
+    /// Paint an outline around this layer.
+    pub highlighted: Option<LayerId>,
+
+    /// The layer whose name is hovered in the list, highlighted for one frame.
+    pub hovered: Option<LayerId>,
+
+    /// Count the vertices of these layers.
+    pub expanded: ahash::HashSet<LayerId>,
+
+    /// The names of the areas, e.g. the window titles, registered while the list is shown.
+    pub names: ahash::HashMap<LayerId, String>,
+}
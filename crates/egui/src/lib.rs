@@ -374,14 +374,19 @@
 #![allow(clippy::manual_range_contains)]
 
 mod animation_manager;
+mod background_layout;
 pub mod containers;
 mod context;
 mod data;
 pub mod debug_text;
+mod documents;
 mod drag_and_drop;
+#[cfg(feature = "markdown")]
+pub mod easy_mark;
 mod frame_state;
 pub(crate) mod grid;
 pub mod gui_zoom;
+pub mod help_mode;
 mod hit_test;
 mod id;
 mod input_state;
@@ -394,11 +399,14 @@ mod memory;
 pub mod menu;
 pub mod os;
 mod painter;
+mod placement;
 pub(crate) mod placer;
 mod response;
 mod sense;
+mod shape_cache;
 pub mod style;
 pub mod text_selection;
+pub mod toasts;
 mod ui;
 mod ui_stack;
 pub mod util;
@@ -422,7 +430,7 @@ pub use epaint::emath;
 
 #[cfg(feature = "color-hex")]
 pub use ecolor::hex_color;
-pub use ecolor::{Color32, Rgba};
+pub use ecolor::{Color32, ColorMatrix, Rgba};
 pub use emath::{
     lerp, pos2, remap, remap_clamp, vec2, Align, Align2, NumExt, Pos2, Rangef, Rect, Vec2, Vec2b,
 };
@@ -430,15 +438,17 @@ pub use epaint::{
     mutex,
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
-    ClippedPrimitive, ColorImage, FontImage, ImageData, Margin, Mesh, PaintCallback,
-    PaintCallbackInfo, Rounding, Shadow, Shape, Stroke, TextureHandle, TextureId,
+    ClippedPrimitive, ColorImage, FontImage, ImageData, Margin, Mesh, NinePatch, NinePatchFill,
+    PaintCallback, PaintCallbackInfo, PaletteImage, Rounding, Shadow, Shape, Stroke, TextureHandle,
+    TextureId,
 };
 
 pub mod text {
     pub use crate::text_selection::{CCursorRange, CursorRange};
     pub use epaint::text::{
-        cursor::CCursor, FontData, FontDefinitions, FontFamily, Fonts, Galley, LayoutJob,
-        LayoutSection, TextFormat, TextWrapping, TAB_SIZE,
+        cursor::CCursor, ElisionPosition, FontData, FontDefinitions, FontFamily, Fonts, Galley,
+        LayoutJob, LayoutSection, LineBreak, LineBreakFn, PlacedGlyph, TabStops, TextFormat,
+        TextWrapping, TAB_SIZE,
     };
 }
 
@@ -448,22 +458,25 @@ pub use {
     data::{
         input::*,
         output::{
-            self, CursorIcon, FullOutput, OpenUrl, PlatformOutput, UserAttentionType, WidgetInfo,
+            self, CursorIcon, FileDialogRequest, FullOutput, OpenUrl, PlatformOutput,
+            UserAttentionType, WidgetInfo,
         },
         Key,
     },
     drag_and_drop::DragAndDrop,
     epaint::text::TextWrapMode,
-    grid::Grid,
+    grid::{Grid, GridCell, GridColumnWidth},
     id::{Id, IdMap},
     input_state::{InputState, MultiTouchInfo, PointerState},
     layers::{LayerId, Order},
     layout::*,
     load::SizeHint,
     memory::{Memory, Options},
-    painter::Painter,
+    painter::{Painter, WorldTextSize},
+    placement::Placement,
     response::{InnerResponse, Response},
     sense::Sense,
+    shape_cache::ShapeCache,
     style::{FontSelection, Style, TextStyle, Visuals},
     text::{Galley, TextFormat},
     ui::Ui,
@@ -613,6 +626,9 @@ pub enum WidgetType {
 
     Checkbox,
 
+    /// An on/off switch, e.g. a [`ToggleSwitch`].
+    Switch,
+
     RadioButton,
 
     SelectableLabel,
@@ -659,6 +675,86 @@ pub fn __run_test_ui(mut add_contents: impl FnMut(&mut Ui)) {
     });
 }
 
+/// Runs frames of a [`Context`] in the unit tests of egui.
+#[cfg(test)]
+pub(crate) struct TestHarness {
+    pub ctx: Context,
+}
+
+#[cfg(test)]
+impl TestHarness {
+    /// A new [`Context`] that doesn't load any fonts (to save CPU time).
+    pub fn new() -> Self {
+        let ctx = Context::default();
+        ctx.set_fonts(FontDefinitions::empty());
+        Self { ctx }
+    }
+
+    /// Like [`Self::new`], but with the default fonts, for tests that need actual glyphs.
+    pub fn with_default_fonts() -> Self {
+        Self {
+            ctx: Context::default(),
+        }
+    }
+
+    /// Run a frame with this input, returning what `run_ui` returns, and the output of the frame.
+    pub fn run_input<R>(
+        &self,
+        input: RawInput,
+        run_ui: impl FnOnce(&Context) -> R,
+    ) -> (R, FullOutput) {
+        let mut ret = None;
+        let output = self.ctx.run(input, |ctx| ret = Some(run_ui(ctx)));
+        (ret.expect("run_ui is called once per frame"), output)
+    }
+
+    /// Run a frame with these input events, returning what `run_ui` returns.
+    pub fn run<R>(&self, events: Vec<Event>, run_ui: impl FnOnce(&Context) -> R) -> R {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        self.run_input(input, run_ui).0
+    }
+
+    /// Like [`Self::run`], with `add_contents` in a [`CentralPanel`].
+    pub fn run_ui<R>(&self, events: Vec<Event>, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        self.run(events, |ctx| {
+            CentralPanel::default().show(ctx, add_contents).inner
+        })
+    }
+
+    /// Pressing or releasing a mouse button at `pos`.
+    pub fn button_event(pos: Pos2, button: PointerButton, pressed: bool) -> Event {
+        Event::PointerButton {
+            pos,
+            button,
+            pressed,
+            modifiers: Modifiers::NONE,
+        }
+    }
+
+    /// Moving the pointer to `pos`, and clicking the primary mouse button there.
+    pub fn click_events(pos: Pos2) -> Vec<Event> {
+        vec![
+            Event::PointerMoved(pos),
+            Self::button_event(pos, PointerButton::Primary, true),
+            Self::button_event(pos, PointerButton::Primary, false),
+        ]
+    }
+
+    /// Pressing `key`, without any modifiers.
+    pub fn key_event(key: Key) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: Modifiers::NONE,
+        }
+    }
+}
+
 #[cfg(feature = "accesskit")]
 pub fn accesskit_root_id() -> Id {
     Id::new("accesskit_root")
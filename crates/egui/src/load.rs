@@ -378,6 +378,7 @@ pub trait ImageLoader {
 
 /// A texture with a known size.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct SizedTexture {
     pub id: TextureId,
     pub size: Vec2,
@@ -453,6 +454,59 @@ impl TexturePoll {
 
 pub type TextureLoadResult = Result<TexturePoll>;
 
+/// A texture that may not be available yet, e.g. one that is being loaded by a background task.
+///
+/// Implemented for `Option`s, so a `poll_promise::Promise<TextureHandle>` can be used as `&promise.ready()`,
+/// and for [`Mutex`]es and `Arc`s, so a background thread can fill in an `Arc<Mutex<Option<TextureHandle>>>`.
+///
+/// Use with [`crate::Image::from_promise`].
+pub trait TexturePromise {
+    /// The texture, once it is available.
+    fn ready_texture(&self) -> Option<SizedTexture>;
+}
+
+impl TexturePromise for SizedTexture {
+    #[inline]
+    fn ready_texture(&self) -> Option<SizedTexture> {
+        Some(*self)
+    }
+}
+
+impl TexturePromise for TextureHandle {
+    #[inline]
+    fn ready_texture(&self) -> Option<SizedTexture> {
+        Some(SizedTexture::from_handle(self))
+    }
+}
+
+impl<T: TexturePromise> TexturePromise for Option<T> {
+    #[inline]
+    fn ready_texture(&self) -> Option<SizedTexture> {
+        self.as_ref().and_then(T::ready_texture)
+    }
+}
+
+impl<T: TexturePromise + ?Sized> TexturePromise for &T {
+    #[inline]
+    fn ready_texture(&self) -> Option<SizedTexture> {
+        (**self).ready_texture()
+    }
+}
+
+impl<T: TexturePromise + ?Sized> TexturePromise for Arc<T> {
+    #[inline]
+    fn ready_texture(&self) -> Option<SizedTexture> {
+        (**self).ready_texture()
+    }
+}
+
+impl<T: TexturePromise> TexturePromise for Mutex<T> {
+    #[inline]
+    fn ready_texture(&self) -> Option<SizedTexture> {
+        self.lock().ready_texture()
+    }
+}
+
 /// A `TextureLoader` uploads a [`ColorImage`] to the GPU, returning a [`SizedTexture`].
 ///
 /// `egui` comes with an implementation that uses [`Context::load_texture`],
@@ -1,6 +1,6 @@
 #![warn(missing_docs)] // Let's keep this file well-documented.` to memory.rs
 
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
 use epaint::emath::TSTransform;
 
 use crate::{
@@ -89,6 +89,11 @@ pub struct Memory {
     /// Transforms per layer
     pub layer_transforms: HashMap<LayerId, TSTransform>,
 
+    /// The urls of all [`crate::Hyperlink`]s the user has opened.
+    ///
+    /// See [`Self::is_link_visited`].
+    visited_links: HashSet<String>,
+
     // -------------------------------------------------
     // Per-viewport:
     areas: ViewportIdMap<Areas>,
@@ -112,6 +117,7 @@ impl Default for Memory {
             viewport_id: Default::default(),
             areas: Default::default(),
             layer_transforms: Default::default(),
+            visited_links: Default::default(),
             popup: Default::default(),
             everything_is_visible: Default::default(),
         };
@@ -194,6 +200,13 @@ pub struct Options {
     #[cfg_attr(feature = "serde", serde(skip))]
     pub zoom_with_keyboard: bool,
 
+    /// If `true`, pressing F1 turns [`crate::help_mode`] on and off.
+    ///
+    /// Turn this on if your app annotates its widgets with [`crate::Response::help`].
+    ///
+    /// Default is `false`.
+    pub help_mode_with_keyboard: bool,
+
     /// Controls the tessellator.
     pub tessellation_options: epaint::TessellationOptions,
 
@@ -248,6 +261,58 @@ pub struct Options {
     ///
     /// Default is `false`.
     pub reduce_texture_memory: bool,
+
+    /// Layers with at least this many widgets get a spatial index,
+    /// which makes hit-testing fast even with tens of thousands of interactive widgets
+    /// (canvas nodes, table cells, plot markers, …).
+    ///
+    /// Building the index has a small cost each frame, so it is not worth it for few widgets.
+    ///
+    /// `None` turns off the spatial index.
+    ///
+    /// Default is `Some(1000)`.
+    pub hit_test_index_threshold: Option<usize>,
+
+    /// Limit the size of the font atlas texture (one side, in pixels).
+    ///
+    /// A smaller atlas uses less memory, but fills up sooner.
+    /// When the atlas is almost full, it is cleared at the start of the next frame,
+    /// evicting all glyphs. Only the glyphs that are still in use are then added to it again.
+    ///
+    /// The atlas is never smaller than [`epaint::TextureAtlas::MIN_SIDE`].
+    ///
+    /// `None` (default) means use the largest texture supported by the backend.
+    pub max_font_atlas_side: Option<usize>,
+
+    /// Limit how many laid out texts are cached between frames.
+    ///
+    /// `None` (default) means no limit.
+    pub max_cached_galleys: Option<usize>,
+
+    /// Limit how many undo points each [`crate::TextEdit`] keeps.
+    ///
+    /// `None` (default) means use the default of [`crate::util::undoer::Settings`].
+    pub max_text_edit_undos: Option<usize>,
+
+    /// Keep a history of the pointer position, to calculate [`crate::PointerState::velocity`].
+    ///
+    /// The velocity is used for e.g. flinging a [`crate::ScrollArea`].
+    ///
+    /// Default is `true`.
+    pub track_pointer_velocity: bool,
+
+    /// Should the integration (e.g. `eframe`) save the [`Memory`] to disk?
+    ///
+    /// Default is `true`.
+    pub persist_memory: bool,
+
+    /// Does the integration reply to [`crate::ViewportCommand::Screenshot`]?
+    ///
+    /// If not, the eyedropper of [`crate::widgets::color_picker::color_edit_button_hsva`]
+    /// only works if the integration supports [`crate::PlatformOutput::pick_screen_color`].
+    ///
+    /// Default is `false` on web, where `eframe` doesn't take screenshots, and `true` elsewhere.
+    pub supports_screenshots: bool,
 }
 
 impl Default for Options {
@@ -264,6 +329,7 @@ impl Default for Options {
             style: Default::default(),
             zoom_factor: 1.0,
             zoom_with_keyboard: true,
+            help_mode_with_keyboard: false,
             tessellation_options: Default::default(),
             repaint_on_widget_change: false,
             screen_reader: false,
@@ -274,6 +340,47 @@ impl Default for Options {
             line_scroll_speed,
             scroll_zoom_speed: 1.0 / 200.0,
             reduce_texture_memory: false,
+            hit_test_index_threshold: Some(1000),
+
+            // Memory:
+            max_font_atlas_side: None,
+            max_cached_galleys: None,
+            max_text_edit_undos: None,
+            track_pointer_velocity: true,
+            persist_memory: true,
+            supports_screenshots: !is_web,
+        }
+    }
+}
+
+impl Options {
+    /// Options that keep the memory use of egui low, at some cost to speed and features.
+    ///
+    /// Useful for embedded targets, e.g. ones rendering to a framebuffer:
+    /// * A small font atlas (512x512 texels), evicting unused glyphs when it fills up
+    /// * A capped text layout cache and undo history
+    /// * No animations, and no pointer velocity tracking (so no flinging of scroll areas)
+    /// * No persistence
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.options_mut(|o| *o = egui::Options::low_memory());
+    /// ```
+    pub fn low_memory() -> Self {
+        let style = crate::Style {
+            animation_time: 0.0,
+            ..Default::default()
+        };
+        Self {
+            style: style.into(),
+            preload_font_glyphs: false,
+            hit_test_index_threshold: None,
+            max_font_atlas_side: Some(epaint::TextureAtlas::MIN_SIDE),
+            max_cached_galleys: Some(256),
+            max_text_edit_undos: Some(10),
+            track_pointer_velocity: false,
+            persist_memory: false,
+            ..Default::default()
         }
     }
 }
@@ -285,6 +392,7 @@ impl Options {
             style,          // covered above
             zoom_factor: _, // TODO(emilk)
             zoom_with_keyboard,
+            help_mode_with_keyboard,
             tessellation_options,
             repaint_on_widget_change,
             screen_reader: _, // needs to come from the integration
@@ -294,6 +402,13 @@ impl Options {
             line_scroll_speed,
             scroll_zoom_speed,
             reduce_texture_memory,
+            hit_test_index_threshold: _,
+            max_font_atlas_side: _,
+            max_cached_galleys: _,
+            max_text_edit_undos: _,
+            track_pointer_velocity,
+            persist_memory,
+            supports_screenshots: _, // needs to come from the integration
         } = self;
 
         use crate::Widget as _;
@@ -311,9 +426,15 @@ impl Options {
                     "Zoom with keyboard (Cmd +, Cmd -, Cmd 0)",
                 );
 
+                ui.checkbox(help_mode_with_keyboard, "Toggle help mode with F1");
+
                 ui.checkbox(warn_on_id_clash, "Warn if two widgets have the same Id");
 
                 ui.checkbox(reduce_texture_memory, "Reduce texture memory");
+
+                ui.checkbox(track_pointer_velocity, "Track pointer velocity");
+
+                ui.checkbox(persist_memory, "Persist memory");
             });
 
         use crate::containers::*;
@@ -410,6 +531,14 @@ pub(crate) struct Focus {
 
     /// A cache of widget ids that are interested in focus with their corresponding rectangles.
     focus_widgets_cache: IdMap<Rect>,
+
+    /// The layer of the modal window shown this frame (or last frame), if any.
+    ///
+    /// Widgets in layers behind it cannot get keyboard focus.
+    modal_layer: Option<LayerId>,
+
+    /// The modal layer registered so far this frame.
+    modal_layer_next_frame: Option<LayerId>,
 }
 
 /// The widget with focus.
@@ -454,6 +583,7 @@ impl Focus {
         }
 
         self.focus_direction = FocusDirection::None;
+        self.modal_layer = self.modal_layer_next_frame.take();
 
         for event in &new_input.events {
             if !event_filter.matches(event) {
@@ -756,6 +886,12 @@ impl Memory {
         self.focus_mut().focused_widget = Some(FocusWidget::new(id));
     }
 
+    /// Don't move the keyboard focus with the arrow keys this frame,
+    /// because they were used for something else, e.g. moving between menu items.
+    pub(crate) fn stop_focus_navigation(&mut self) {
+        self.focus_mut().reset_focus();
+    }
+
     /// Surrender keyboard focus for a specific widget.
     /// See also [`crate::Response::surrender_focus`].
     #[inline(always)]
@@ -778,6 +914,42 @@ impl Memory {
         self.focus_mut().interested_in_focus(id);
     }
 
+    /// Like [`Self::interested_in_focus`], but ignored if the layer is behind a modal window.
+    pub(crate) fn interested_in_focus_on_layer(&mut self, id: Id, layer_id: LayerId) {
+        if self.is_behind_modal(layer_id) {
+            self.surrender_focus(id);
+        } else {
+            self.interested_in_focus(id);
+        }
+    }
+
+    /// Mark the given layer as a modal window for this frame and the next one.
+    ///
+    /// While set, all layers behind it lose keyboard focus and are skipped when tabbing.
+    pub(crate) fn set_modal_layer(&mut self, layer_id: LayerId) {
+        let focus = self.focus_mut();
+        focus.modal_layer = Some(layer_id);
+        focus.modal_layer_next_frame = Some(layer_id);
+    }
+
+    /// Is the given layer covered by a modal window?
+    pub(crate) fn is_behind_modal(&self, layer_id: LayerId) -> bool {
+        let Some(modal_layer) = self.focus().and_then(|f| f.modal_layer) else {
+            return false;
+        };
+        if layer_id == modal_layer {
+            return false;
+        }
+        let order = self.areas().order();
+        let index_of = |layer| order.iter().position(|x| *x == layer);
+        match (index_of(layer_id), index_of(modal_layer)) {
+            (Some(layer_index), Some(modal_index)) => layer_index < modal_index,
+            // Layers new this frame are put on top:
+            (None, _) => false,
+            (Some(_), None) => layer_id.order <= modal_layer.order,
+        }
+    }
+
     /// Stop editing of active [`TextEdit`](crate::TextEdit) (if any).
     #[inline(always)]
     pub fn stop_text_input(&mut self) {
@@ -924,6 +1096,24 @@ impl Memory {
     pub fn set_everything_is_visible(&mut self, value: bool) {
         self.everything_is_visible = value;
     }
+
+    /// Has the user opened a [`crate::Hyperlink`] to this url?
+    ///
+    /// This is saved between different program runs if you use the `persistence` feature.
+    #[inline]
+    pub fn is_link_visited(&self, url: &str) -> bool {
+        self.visited_links.contains(url)
+    }
+
+    /// Mark the given url as visited, so that hyperlinks to it are shown as such.
+    pub fn mark_link_visited(&mut self, url: impl Into<String>) {
+        self.visited_links.insert(url.into());
+    }
+
+    /// Forget all visited links.
+    pub fn forget_visited_links(&mut self) {
+        self.visited_links.clear();
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -951,6 +1141,19 @@ pub struct Areas {
     /// So if you close three windows and then reopen them all in one frame,
     /// they will all be sent to the top, but keep their previous internal order.
     wants_to_be_on_top: ahash::HashSet<LayerId>,
+
+    /// Like [`Self::wants_to_be_on_top`], but for moving to the bottom.
+    wants_to_be_on_bottom: ahash::HashSet<LayerId>,
+
+    /// These layers are kept above all other layers of the same [`Order`]
+    /// (except a modal window).
+    always_on_top: ahash::HashSet<LayerId>,
+
+    /// The backdrop and the layer of the modal window shown this frame, if any.
+    ///
+    /// These are kept above everything else of the same [`Order`] at the end of the frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    modal: Option<(LayerId, LayerId)>,
 }
 
 impl Areas {
@@ -1042,6 +1245,41 @@ impl Areas {
         }
     }
 
+    /// Move the layer to the bottom of its [`Order`], i.e. behind all other layers of the same order.
+    pub fn move_to_bottom(&mut self, layer_id: LayerId) {
+        self.wants_to_be_on_bottom.insert(layer_id);
+        self.wants_to_be_on_top.remove(&layer_id);
+
+        if !self.order.iter().any(|x| *x == layer_id) {
+            self.order.insert(0, layer_id);
+        }
+    }
+
+    /// Keep the layer above all other layers of the same [`Order`], even when they are clicked.
+    pub fn set_always_on_top(&mut self, layer_id: LayerId, always_on_top: bool) {
+        if always_on_top {
+            self.always_on_top.insert(layer_id);
+            if !self.order.iter().any(|x| *x == layer_id) {
+                self.order.push(layer_id);
+            }
+        } else {
+            self.always_on_top.remove(&layer_id);
+        }
+    }
+
+    /// Was this layer set to be always on top with [`Self::set_always_on_top`]?
+    pub fn is_always_on_top(&self, layer_id: LayerId) -> bool {
+        self.always_on_top.contains(&layer_id)
+    }
+
+    /// Keep a modal window, and the backdrop directly below it,
+    /// on top of all other layers of the same [`Order`].
+    pub(crate) fn keep_modal_on_top(&mut self, backdrop: LayerId, modal: LayerId) {
+        self.move_to_top(backdrop);
+        self.move_to_top(modal);
+        self.modal = Some((backdrop, modal));
+    }
+
     pub fn top_layer_id(&self, order: Order) -> Option<LayerId> {
         self.order
             .iter()
@@ -1056,20 +1294,139 @@ impl Areas {
             visible_current_frame,
             order,
             wants_to_be_on_top,
+            wants_to_be_on_bottom,
+            always_on_top,
+            modal,
             ..
         } = self;
 
         std::mem::swap(visible_last_frame, visible_current_frame);
         visible_current_frame.clear();
-        order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
+        let modal = modal.take();
+        let modal_rank = |layer: &LayerId| match modal {
+            Some((backdrop, _)) if backdrop == *layer => 1,
+            Some((_, modal)) if modal == *layer => 2,
+            _ => 0,
+        };
+        order.sort_by_key(|layer| {
+            (
+                layer.order,
+                modal_rank(layer),
+                always_on_top.contains(layer),
+                !wants_to_be_on_bottom.contains(layer),
+                wants_to_be_on_top.contains(layer),
+            )
+        });
         wants_to_be_on_top.clear();
+        wants_to_be_on_bottom.clear();
     }
 }
 
 // ----------------------------------------------------------------------------
 
+#[test]
+fn modal_stays_on_top() {
+    let layer = |name: &str| LayerId::new(Order::Middle, Id::new(name));
+    let (window, backdrop, modal) = (layer("window"), layer("backdrop"), layer("modal"));
+
+    let mut areas = Areas::default();
+    areas.move_to_top(window);
+    areas.keep_modal_on_top(backdrop, modal);
+    areas.end_frame();
+    assert_eq!(areas.order(), &[window, backdrop, modal]);
+
+    // Another window moving to the top in the same frame must stay behind the modal:
+    let new_window = layer("new_window");
+    areas.keep_modal_on_top(backdrop, modal);
+    areas.move_to_top(new_window);
+    areas.end_frame();
+    assert_eq!(areas.order(), &[window, new_window, backdrop, modal]);
+}
+
+#[test]
+fn programmatic_layer_order() {
+    let layer = |name: &str| LayerId::new(Order::Middle, Id::new(name));
+    let a = layer("a");
+    let b = layer("b");
+    let c = layer("c");
+
+    let mut areas = Areas::default();
+    for layer in [a, b, c] {
+        areas.move_to_top(layer);
+    }
+    areas.end_frame();
+    assert_eq!(areas.order(), &[a, b, c]);
+
+    areas.move_to_bottom(c);
+    areas.end_frame();
+    assert_eq!(areas.order(), &[c, a, b]);
+
+    // Stays on top even when another layer is clicked:
+    areas.set_always_on_top(c, true);
+    areas.move_to_top(a);
+    areas.end_frame();
+    assert_eq!(areas.order(), &[b, a, c]);
+
+    areas.set_always_on_top(c, false);
+    areas.move_to_top(b);
+    areas.end_frame();
+    assert_eq!(areas.order(), &[a, c, b]);
+}
+
 #[test]
 fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Memory>();
 }
+
+#[test]
+fn low_memory_options() {
+    let harness = crate::TestHarness::new();
+    let ctx = &harness.ctx;
+    ctx.options_mut(|o| *o = Options::low_memory());
+    let mut input = crate::RawInput {
+        max_texture_side: Some(4096),
+        ..Default::default()
+    };
+    for frame in 0..3 {
+        let x = 10.0 * frame as f32;
+        input.time = Some(frame as f64 / 60.0);
+        input.events = vec![crate::Event::PointerMoved(crate::pos2(x, x))];
+        harness.run_input(input.clone(), |ctx| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                for i in 0..300 {
+                    ui.label(i.to_string());
+                }
+            });
+        });
+    }
+
+    ctx.fonts(|fonts| {
+        assert_eq!(fonts.max_texture_side(), epaint::TextureAtlas::MIN_SIDE);
+        assert!(fonts.num_galleys_in_cache() <= 256);
+    });
+    assert_eq!(ctx.input(|i| i.pointer.velocity()), Vec2::ZERO);
+}
+
+#[test]
+fn low_memory_font_atlas_eviction() {
+    let harness = crate::TestHarness::with_default_fonts();
+    harness.ctx.options_mut(|o| *o = Options::low_memory());
+    let run = |sizes: std::ops::Range<u32>| {
+        harness.run_ui(vec![], |ui| {
+            for size in sizes {
+                ui.label(crate::RichText::new("abcdefghijklmnopqrstuvwxyz").size(size as f32));
+            }
+        });
+        harness.ctx.fonts(|fonts| fonts.font_atlas_fill_ratio())
+    };
+
+    // Lots of glyphs fill up the small atlas:
+    assert!(0.8 < run(10..60));
+
+    // So it is cleared, and only the glyphs still in use are added again:
+    assert!(run(10..11) < 0.1);
+    harness.ctx.fonts(|fonts| {
+        assert_eq!(fonts.max_texture_side(), epaint::TextureAtlas::MIN_SIDE);
+    });
+}
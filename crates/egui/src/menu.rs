@@ -1,5 +1,9 @@
 //! Menu bar functionality (very basic so far).
 //!
+//! Menus can be used with the keyboard: the arrow keys move between the items,
+//! Enter or Space activates the focused item, Right opens a sub-menu and Left or Escape closes it.
+//! Typing a letter jumps to the next item starting with it.
+//!
 //! Usage:
 //! ```
 //! fn show_menu(ui: &mut egui::Ui) {
@@ -19,7 +23,7 @@ use super::{
     style::WidgetVisuals, Align, Context, Id, InnerResponse, PointerState, Pos2, Rect, Response,
     Sense, TextStyle, Ui, Vec2,
 };
-use crate::{widgets::*, *};
+use crate::{id::IdSet, widgets::*, *};
 use epaint::mutex::RwLock;
 use std::sync::Arc;
 
@@ -137,11 +141,20 @@ fn menu_popup<'c, R>(
     ctx: &Context,
     menu_state_arc: &Arc<RwLock<MenuState>>,
     menu_id: Id,
+    is_submenu: bool,
     add_contents: impl FnOnce(&mut Ui) -> R + 'c,
 ) -> InnerResponse<R> {
     let pos = {
         let mut menu_state = menu_state_arc.write();
         menu_state.entry_count = 0;
+        if ctx.input(|i| i.pointer.delta() != Vec2::ZERO) {
+            menu_state.keyboard_active = false;
+        }
+        let icon_column_used = std::mem::take(&mut menu_state.icon_column_used);
+        if menu_state.reserve_icon_column != icon_column_used {
+            menu_state.reserve_icon_column = icon_column_used;
+            ctx.request_repaint();
+        }
         menu_state.rect.min
     };
 
@@ -158,6 +171,9 @@ fn menu_popup<'c, R>(
     let area_response = area.show(ctx, |ui| {
         sizing_pass = ui.is_sizing_pass();
 
+        // So we can find the items by their text:
+        ui.ctx().keep_widget_infos_for_layer(ui.layer_id());
+
         set_menu_style(ui.style_mut());
 
         Frame::menu(ui.style())
@@ -169,16 +185,22 @@ fn menu_popup<'c, R>(
             .inner
     });
 
-    menu_state_arc.write().rect = if sizing_pass {
+    let mut menu_state = menu_state_arc.write();
+    menu_state.rect = if sizing_pass {
         // During the sizing pass we didn't know the size yet,
         // so we might have just constrained the position unnecessarily.
         // Therefore keep the original=desired position until the next frame.
-        Rect::from_min_size(pos, area_response.response.rect.size())
+        // The size of the contents is known now though, and is needed for placing sub-menus.
+        let size = AreaState::load(ctx, area_response.response.layer_id.id)
+            .map_or(area_response.response.rect.size(), |state| state.size);
+        Rect::from_min_size(pos, size)
     } else {
         // We knew the size, and this is where it ended up (potentially constrained to screen).
         // Remember it for the future:
         area_response.response.rect
     };
+    menu_state.keyboard_navigation(ctx, area_response.response.layer_id, is_submenu);
+    drop(menu_state);
 
     area_response
 }
@@ -231,10 +253,66 @@ fn stationary_menu_image_impl<'c, R>(
 
 pub(crate) const CONTEXT_MENU_ID_STR: &str = "__egui::context_menu";
 
+/// The widgets that got a [`Response::context_menu`] this frame or the last one.
+///
+/// The built-in context menu of a widget, like the one of [`crate::TextEdit`],
+/// makes way for these.
+#[derive(Clone, Default)]
+struct CustomContextMenus {
+    frame_nr: u64,
+    this_frame: IdSet,
+    last_frame: IdSet,
+}
+
+impl CustomContextMenus {
+    fn update<R>(ctx: &Context, update: impl FnOnce(&mut Self) -> R) -> R {
+        let id = Id::new(CONTEXT_MENU_ID_STR).with("custom");
+        let frame_nr = ctx.frame_nr();
+        ctx.data_mut(|d| {
+            let menus = d.get_temp_mut_or_default::<Self>(id);
+            if menus.frame_nr != frame_nr {
+                let this_frame = std::mem::take(&mut menus.this_frame);
+                menus.last_frame = if menus.frame_nr + 1 == frame_nr {
+                    this_frame
+                } else {
+                    IdSet::default()
+                };
+                menus.frame_nr = frame_nr;
+            }
+            update(menus)
+        })
+    }
+}
+
 /// Response to secondary clicks (right-clicks) by showing the given menu.
 pub(crate) fn context_menu(
     response: &Response,
     add_contents: impl FnOnce(&mut Ui),
+) -> Option<InnerResponse<()>> {
+    CustomContextMenus::update(&response.ctx, |menus| {
+        menus.this_frame.insert(response.id);
+    });
+    show_context_menu(response, add_contents)
+}
+
+/// Show the built-in context menu of a widget,
+/// unless it got a menu of its own with [`Response::context_menu`].
+pub(crate) fn builtin_context_menu(
+    response: &Response,
+    add_contents: impl FnOnce(&mut Ui),
+) -> Option<InnerResponse<()>> {
+    let has_custom_menu = CustomContextMenus::update(&response.ctx, |menus| {
+        menus.this_frame.contains(&response.id) || menus.last_frame.contains(&response.id)
+    });
+    if has_custom_menu {
+        return None;
+    }
+    show_context_menu(response, add_contents)
+}
+
+fn show_context_menu(
+    response: &Response,
+    add_contents: impl FnOnce(&mut Ui),
 ) -> Option<InnerResponse<()>> {
     let menu_id = Id::new(CONTEXT_MENU_ID_STR);
     let mut bar_state = BarState::load(&response.ctx, menu_id);
@@ -319,7 +397,8 @@ impl MenuRoot {
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> (MenuResponse, Option<InnerResponse<R>>) {
         if self.id == button.id {
-            let inner_response = menu_popup(&button.ctx, &self.menu_state, self.id, add_contents);
+            let inner_response =
+                menu_popup(&button.ctx, &self.menu_state, self.id, false, add_contents);
             let menu_state = self.menu_state.read();
 
             if menu_state.response.is_close() {
@@ -335,13 +414,12 @@ impl MenuRoot {
     fn stationary_interaction(button: &Response, root: &mut MenuRootManager) -> MenuResponse {
         let id = button.id;
 
-        if (button.clicked() && root.is_menu_open(id))
-            || button.ctx.input(|i| i.key_pressed(Key::Escape))
-        {
-            // menu open and button clicked or esc pressed
+        if button.clicked() && root.is_menu_open(id) {
+            // menu open and button clicked
+            // (Escape is handled by the menu itself, see `MenuState::keyboard_navigation`)
             return MenuResponse::Close;
-        } else if (button.clicked() && !root.is_menu_open(id))
-            || (button.hovered() && root.is_some())
+        } else if !root.is_menu_open(id)
+            && (button.clicked() || (button.hovered() && root.is_some()))
         {
             // menu not open and button clicked
             // or button hovered while other menu is open
@@ -431,6 +509,13 @@ impl MenuRoot {
     pub fn stationary_click_interaction(button: &Response, root: &mut MenuRootManager) {
         let menu_response = Self::stationary_interaction(button, root);
         Self::handle_menu_response(root, menu_response);
+
+        if let (MenuResponse::Create(..), Some(root)) = (menu_response, root.inner.as_ref()) {
+            if button.fake_primary_click {
+                // Opened with the keyboard, so continue with the keyboard:
+                root.menu_state.write().focus_first_item = true;
+            }
+        }
     }
 }
 
@@ -491,7 +576,13 @@ impl SubMenuButton {
         let text_icon_gap = ui.spacing().item_spacing.x;
         let button_padding = ui.spacing().button_padding;
         let total_extra = button_padding + button_padding;
-        let text_available_width = ui.available_width() - total_extra.x;
+        // Line up with the text of any [`MenuItem`]s with icons:
+        let indent = if menu_state.reserve_icon_column {
+            icon_column_width(ui)
+        } else {
+            0.0
+        };
+        let text_available_width = ui.available_width() - total_extra.x - indent;
         let text_galley = text.into_galley(
             ui,
             Some(TextWrapMode::Wrap),
@@ -507,7 +598,7 @@ impl SubMenuButton {
             text_style,
         );
         let text_and_icon_size = Vec2::new(
-            text_galley.size().x + text_icon_gap + icon_galley.size().x,
+            indent + text_galley.size().x + text_icon_gap + icon_galley.size().x,
             text_galley.size().y.max(icon_galley.size().y),
         );
         let mut desired_size = text_and_icon_size + 2.0 * button_padding;
@@ -522,7 +613,8 @@ impl SubMenuButton {
             let visuals = Self::visuals(ui, &response, menu_state, sub_id);
             let text_pos = Align2::LEFT_CENTER
                 .align_size_within_rect(text_galley.size(), rect.shrink2(button_padding))
-                .min;
+                .min
+                + vec2(indent, 0.0);
             let icon_pos = Align2::RIGHT_CENTER
                 .align_size_within_rect(icon_galley.size(), rect.shrink2(button_padding))
                 .min;
@@ -567,14 +659,312 @@ impl SubMenu {
         self.parent_state
             .write()
             .submenu_button_interaction(ui, sub_id, &response);
-        let inner = self
-            .parent_state
-            .write()
-            .show_submenu(ui.ctx(), sub_id, add_contents);
+        let inner =
+            self.parent_state
+                .write()
+                .show_submenu(ui.ctx(), sub_id, response.id, add_contents);
         InnerResponse::new(inner, response)
     }
 }
 
+/// What kind of [`MenuItem`] it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MenuItemKind {
+    /// A normal item.
+    Plain,
+
+    /// Shows a checkmark when `true`.
+    Check(bool),
+
+    /// One of a group of mutually exclusive items, shows a dot when `true`.
+    Radio(bool),
+}
+
+/// The icon of a [`MenuItem`].
+enum MenuIcon<'a> {
+    Image(Image<'a>),
+    Glyph(WidgetText),
+}
+
+/// An item in a menu, with an optional icon, checkmark or radio dot,
+/// and an optional keyboard shortcut shown at the right edge.
+///
+/// The icon, checkmark and radio dot are shown in a column left of the text.
+/// If any [`MenuItem`] in a menu uses that column, all of them leave room for it
+/// (as do the sub-menu buttons), so that the texts line up.
+///
+/// The returned [`Response`] is clicked when the item is activated.
+/// Toggling the state and closing the menu is up to you.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # #[derive(PartialEq)] enum Theme { Light, Dark }
+/// # let mut auto_save = true;
+/// # let mut theme = Theme::Dark;
+/// use egui::menu::MenuItem;
+///
+/// ui.menu_button("Settings", |ui| {
+///     if ui.add(MenuItem::new("Save").icon("💾").shortcut("Ctrl+S")).clicked() {
+///         ui.close_menu();
+///     }
+///     if ui.add(MenuItem::new("Auto save").checked(auto_save)).clicked() {
+///         auto_save = !auto_save;
+///         ui.close_menu();
+///     }
+///     ui.separator();
+///     if ui.add(MenuItem::new("Light").radio(theme == Theme::Light)).clicked() {
+///         theme = Theme::Light;
+///     }
+///     if ui.add(MenuItem::new("Dark").radio(theme == Theme::Dark)).clicked() {
+///         theme = Theme::Dark;
+///     }
+/// });
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct MenuItem<'a> {
+    text: WidgetText,
+    kind: MenuItemKind,
+    icon: Option<MenuIcon<'a>>,
+    shortcut: WidgetText,
+}
+
+impl<'a> MenuItem<'a> {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            kind: MenuItemKind::Plain,
+            icon: None,
+            shortcut: Default::default(),
+        }
+    }
+
+    /// Show an image left of the text, e.g. `(texture_id, size)` or [`crate::include_image!`].
+    ///
+    /// It is shrunk to fit the icon column if needed.
+    #[inline]
+    pub fn image(mut self, image: impl Into<Image<'a>>) -> Self {
+        self.icon = Some(MenuIcon::Image(image.into()));
+        self
+    }
+
+    /// Show a glyph left of the text, e.g. an emoji like `"💾"`.
+    #[inline]
+    pub fn icon(mut self, icon: impl Into<WidgetText>) -> Self {
+        self.icon = Some(MenuIcon::Glyph(icon.into()));
+        self
+    }
+
+    /// Make this a toggle item, showing a checkmark when `checked` is `true`.
+    ///
+    /// If the item also has an icon, the icon is highlighted instead.
+    #[inline]
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.kind = MenuItemKind::Check(checked);
+        self
+    }
+
+    /// Make this one of a group of mutually exclusive items,
+    /// showing a dot when `selected` is `true`.
+    #[inline]
+    pub fn radio(mut self, selected: bool) -> Self {
+        self.kind = MenuItemKind::Radio(selected);
+        self
+    }
+
+    /// Show a keyboard shortcut, e.g. `"Ctrl+S"`, right-aligned in a weaker color.
+    ///
+    /// This is only for show: you still need to check for the shortcut yourself,
+    /// e.g. with [`crate::InputState::consume_shortcut`] and [`Context::format_shortcut`].
+    #[inline]
+    pub fn shortcut(mut self, shortcut: impl Into<WidgetText>) -> Self {
+        self.shortcut = shortcut.into();
+        self
+    }
+}
+
+impl<'a> Widget for MenuItem<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            text,
+            kind,
+            icon,
+            shortcut,
+        } = self;
+
+        let button_padding = ui.spacing().button_padding;
+        let gap_before_shortcut = ui.spacing().item_spacing.x;
+        let uses_icon_column = kind != MenuItemKind::Plain || icon.is_some();
+        let icon_column_width = if reserve_icon_column(ui, uses_icon_column) {
+            icon_column_width(ui)
+        } else {
+            0.0
+        };
+
+        // Note: we don't wrap the shortcut text
+        let shortcut_galley = (!shortcut.is_empty()).then(|| {
+            shortcut.into_galley(
+                ui,
+                Some(TextWrapMode::Extend),
+                f32::INFINITY,
+                TextStyle::Button,
+            )
+        });
+
+        let mut text_wrap_width = ui.available_width() - 2.0 * button_padding.x - icon_column_width;
+        if let Some(shortcut_galley) = &shortcut_galley {
+            text_wrap_width -= gap_before_shortcut + shortcut_galley.size().x;
+        }
+        let galley = text.into_galley(ui, None, text_wrap_width, TextStyle::Button);
+
+        let mut desired_size = vec2(icon_column_width + galley.size().x, galley.size().y);
+        if let Some(shortcut_galley) = &shortcut_galley {
+            desired_size.x += gap_before_shortcut + shortcut_galley.size().x;
+            desired_size.y = desired_size.y.max(shortcut_galley.size().y);
+        }
+        desired_size += 2.0 * button_padding;
+        desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+
+        let (rect, response) = ui.allocate_at_least(desired_size, Sense::click());
+        response.widget_info(|| match kind {
+            MenuItemKind::Plain => WidgetInfo::labeled(WidgetType::Button, galley.text()),
+            MenuItemKind::Check(checked) => {
+                WidgetInfo::selected(WidgetType::Checkbox, checked, galley.text())
+            }
+            MenuItemKind::Radio(selected) => {
+                WidgetInfo::selected(WidgetType::RadioButton, selected, galley.text())
+            }
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let inner_rect = rect.shrink2(button_padding);
+
+            if ui.visuals().button_frame {
+                ui.painter().rect_filled(
+                    rect.expand(visuals.expansion),
+                    visuals.rounding,
+                    visuals.weak_bg_fill,
+                );
+            }
+
+            let icon_rect = Rect::from_min_size(
+                inner_rect.left_top(),
+                vec2(ui.spacing().icon_width, inner_rect.height()),
+            );
+            let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(icon_rect);
+            let highlighted = matches!(kind, MenuItemKind::Check(true) | MenuItemKind::Radio(true));
+            match icon {
+                Some(icon) => {
+                    if highlighted {
+                        ui.painter().rect_filled(
+                            big_icon_rect.expand(visuals.expansion),
+                            visuals.rounding,
+                            ui.visuals().selection.bg_fill,
+                        );
+                    }
+                    match icon {
+                        MenuIcon::Image(image) => {
+                            let image_size = image
+                                .load_and_calc_size(ui, big_icon_rect.size())
+                                .unwrap_or(big_icon_rect.size());
+                            let image_rect =
+                                Rect::from_center_size(big_icon_rect.center(), image_size);
+                            image.paint_at(ui, image_rect);
+                        }
+                        MenuIcon::Glyph(glyph) => {
+                            let glyph_galley = glyph.into_galley(
+                                ui,
+                                Some(TextWrapMode::Extend),
+                                f32::INFINITY,
+                                TextStyle::Button,
+                            );
+                            let glyph_pos = Align2::CENTER_CENTER
+                                .align_size_within_rect(glyph_galley.size(), big_icon_rect)
+                                .min;
+                            ui.painter()
+                                .galley(glyph_pos, glyph_galley, visuals.text_color());
+                        }
+                    }
+                }
+                None if kind == MenuItemKind::Check(true) => {
+                    ui.painter().add(Shape::line(
+                        vec![
+                            pos2(small_icon_rect.left(), small_icon_rect.center().y),
+                            pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
+                            pos2(small_icon_rect.right(), small_icon_rect.top()),
+                        ],
+                        visuals.fg_stroke,
+                    ));
+                }
+                None if kind == MenuItemKind::Radio(true) => {
+                    ui.painter().circle_filled(
+                        small_icon_rect.center(),
+                        small_icon_rect.width() / 3.0,
+                        visuals.fg_stroke.color,
+                    );
+                }
+                None => {}
+            }
+
+            let text_pos = pos2(
+                inner_rect.left() + icon_column_width,
+                inner_rect.center().y - 0.5 * galley.size().y,
+            );
+            ui.painter().galley(text_pos, galley, visuals.text_color());
+
+            if let Some(shortcut_galley) = shortcut_galley {
+                let shortcut_pos = pos2(
+                    inner_rect.right() - shortcut_galley.size().x,
+                    inner_rect.center().y - 0.5 * shortcut_galley.size().y,
+                );
+                ui.painter().galley(
+                    shortcut_pos,
+                    shortcut_galley,
+                    ui.visuals().weak_text_color(),
+                );
+            }
+        }
+
+        response
+    }
+}
+
+/// The width of the column for icons and checkmarks in menus, including the space after it.
+fn icon_column_width(ui: &Ui) -> f32 {
+    ui.spacing().icon_width + ui.spacing().icon_spacing
+}
+
+/// Should an item leave room for the icon column?
+///
+/// In menus this is the case if any item used it last frame.
+fn reserve_icon_column(ui: &Ui, uses_icon_column: bool) -> bool {
+    match ui.menu_state() {
+        Some(menu_state) if uses_icon_column => {
+            menu_state.write().icon_column_used = true;
+            true
+        }
+        Some(menu_state) => menu_state.read().reserve_icon_column,
+        None => uses_icon_column,
+    }
+}
+
+/// Put the sub-menu left of its parent menu if it doesn't fit to the right of it.
+fn flip_submenu_if_needed(ctx: &Context, parent_rect: Rect, sub_menu: &mut MenuState) {
+    let screen_rect = ctx.screen_rect();
+    let spacing = ctx.style().spacing.menu_spacing;
+    let width = sub_menu.rect.width();
+
+    let right_x = parent_rect.right() + spacing;
+    let left_x = parent_rect.left() - spacing - width;
+    let x = if screen_rect.right() < right_x + width && screen_rect.left() <= left_x {
+        left_x
+    } else {
+        right_x
+    };
+    sub_menu.rect = sub_menu.rect.translate(vec2(x - sub_menu.rect.left(), 0.0));
+}
+
 /// Components of menu state, public for advanced usage.
 ///
 /// Usually you don't need to use it directly.
@@ -591,6 +981,27 @@ pub struct MenuState {
 
     /// Used to hash different [`Id`]s for sub-menus
     entry_count: usize,
+
+    /// Give keyboard focus to the first item, because the menu was opened with the keyboard.
+    focus_first_item: bool,
+
+    /// The keyboard was used last, so ignore the pointer hovering items until it moves.
+    keyboard_active: bool,
+
+    /// This sub-menu was closed with the keyboard, so its parent should close it.
+    close_requested: bool,
+
+    /// The sub-menu button the pointer rests on, and since when.
+    hovered_button: Option<(Id, f64)>,
+
+    /// When the pointer left the open sub-menu and its button, if it has.
+    left_submenu_at: Option<f64>,
+
+    /// Some item used the icon column last frame, so all items should leave room for it.
+    reserve_icon_column: bool,
+
+    /// Some item used the icon column so far this frame.
+    icon_column_used: bool,
 }
 
 impl MenuState {
@@ -600,6 +1011,13 @@ impl MenuState {
             sub_menu: None,
             response: MenuResponse::Stay,
             entry_count: 0,
+            focus_first_item: false,
+            keyboard_active: false,
+            close_requested: false,
+            hovered_button: None,
+            left_submenu_at: None,
+            reserve_icon_column: false,
+            icon_column_used: false,
         }
     }
 
@@ -612,16 +1030,125 @@ impl MenuState {
         &mut self,
         ctx: &Context,
         id: Id,
+        button_id: Id,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> Option<R> {
-        let (sub_response, response) = self.submenu(id).map(|sub| {
-            let inner_response = menu_popup(ctx, sub, id, add_contents);
-            (sub.read().response, inner_response.inner)
+        let rect = self.rect;
+        let (sub_response, close_requested, response) = self.submenu(id).map(|sub| {
+            flip_submenu_if_needed(ctx, rect, &mut sub.write());
+            let inner_response = menu_popup(ctx, sub, id, true, add_contents);
+            let sub = sub.read();
+            (sub.response, sub.close_requested, inner_response.inner)
         })?;
         self.cascade_close_response(sub_response);
+        if close_requested {
+            self.close_submenu();
+            self.keyboard_active = true;
+            ctx.memory_mut(|mem| mem.request_focus(button_id));
+            ctx.request_repaint();
+        }
         Some(response)
     }
 
+    /// Handle the keys for moving between the items of the menu shown in this layer.
+    ///
+    /// Only the innermost open menu responds to the keyboard.
+    fn keyboard_navigation(&mut self, ctx: &Context, layer_id: LayerId, is_submenu: bool) {
+        if self.sub_menu.is_some() {
+            return;
+        }
+
+        // Escape closes the menu even if it has nothing to navigate between:
+        let escape = ctx.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Escape));
+        if escape {
+            if is_submenu {
+                self.close_requested = true;
+            } else {
+                self.close();
+            }
+            return;
+        }
+
+        let items: Vec<(Id, WidgetType, Option<String>)> = ctx.widgets_this_frame(|widgets| {
+            widgets
+                .get_layer(layer_id)
+                .filter(|w| w.enabled && w.sense.focusable)
+                .map(|w| {
+                    let info = widgets.info(w.id);
+                    (
+                        w.id,
+                        info.map_or(WidgetType::Other, |info| info.typ),
+                        info.and_then(|info| info.label.clone()),
+                    )
+                })
+                .collect()
+        });
+        if items.is_empty() {
+            return;
+        }
+
+        if std::mem::take(&mut self.focus_first_item) {
+            self.keyboard_active = true;
+            ctx.memory_mut(|mem| mem.request_focus(items[0].0));
+            return;
+        }
+
+        let focused = ctx
+            .memory(|mem| mem.focused())
+            .and_then(|id| items.iter().position(|(item_id, ..)| *item_id == id));
+        if focused.map_or(false, |i| items[i].1 == WidgetType::TextEdit) {
+            // The keys are for the text edit.
+            return;
+        }
+
+        let (up, down, left, right, typed) = ctx.input_mut(|i| {
+            let typed = i.events.iter().find_map(|event| match event {
+                Event::Text(text) => text.chars().next().filter(|c| !c.is_whitespace()),
+                _ => None,
+            });
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::ArrowLeft),
+                i.consume_key(Modifiers::NONE, Key::ArrowRight),
+                typed,
+            )
+        });
+
+        let n = items.len();
+        let new_focus = if up {
+            Some(focused.map_or(n - 1, |i| (i + n - 1) % n))
+        } else if down {
+            Some(focused.map_or(0, |i| (i + 1) % n))
+        } else if let Some(typed) = typed {
+            // Jump to the next item starting with the typed letter:
+            let start = focused.map_or(0, |i| i + 1);
+            (0..n).map(|i| (start + i) % n).find(|&i| {
+                items[i].2.as_ref().map_or(false, |label| {
+                    label
+                        .chars()
+                        .next()
+                        .map_or(false, |c| c.to_lowercase().eq(typed.to_lowercase()))
+                })
+            })
+        } else {
+            None
+        };
+
+        if let Some(new_focus) = new_focus {
+            ctx.memory_mut(|mem| mem.request_focus(items[new_focus].0));
+        }
+
+        if is_submenu && left {
+            self.close_requested = true;
+        }
+
+        if up || down || left || right || new_focus.is_some() {
+            self.keyboard_active = true;
+            ctx.memory_mut(|mem| mem.stop_focus_navigation());
+        }
+    }
+
     /// Check if position is in the menu hierarchy's area.
     pub fn area_contains(&self, pos: Pos2) -> bool {
         self.rect.contains(pos)
@@ -640,36 +1167,90 @@ impl MenuState {
     fn submenu_button_interaction(&mut self, ui: &Ui, sub_id: Id, button: &Response) {
         let pointer = ui.input(|i| i.pointer.clone());
         let open = self.is_open(sub_id);
-        if self.moving_towards_current_submenu(&pointer) {
+        let keyboard_open = button.has_focus()
+            && (button.fake_primary_click
+                || ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::ArrowRight)));
+        if keyboard_open {
+            let pos = self.submenu_pos(ui, button);
+            self.open_submenu(sub_id, pos);
+            if let Some(sub_menu) = self.current_submenu() {
+                sub_menu.write().focus_first_item = true;
+            }
+            self.keyboard_active = true;
+        } else if self.keyboard_active {
+            // Don't let a pointer resting on another item undo what was done with the keyboard.
+        } else if self.moving_towards_current_submenu(ui, &pointer) {
             // We don't close the submenu if the pointer is on its way to hover it.
+            self.left_submenu_at = None;
             // ensure to repaint once even when pointer is not moving
             ui.ctx().request_repaint();
-        } else if !open && button.hovered() {
-            // TODO(emilk): open menu to the left if there isn't enough space to the right
-            let mut pos = button.rect.right_top();
-            pos.x = self.rect.right() + ui.spacing().menu_spacing;
-            pos.y -= Frame::menu(ui.style()).total_margin().top; // align the first button in the submenu with the parent button
-
-            self.open_submenu(sub_id, pos);
-        } else if open
-            && ui.interact_bg(Sense::hover()).contains_pointer()
-            && !button.hovered()
-            && !self.hovering_current_submenu(&pointer)
-        {
-            // We are hovering something else in the menu, so close the submenu.
-            self.close_submenu();
+        } else if open {
+            if button.hovered() || self.hovering_current_submenu(&pointer) {
+                self.left_submenu_at = None;
+            } else if ui.interact_bg(Sense::hover()).contains_pointer() {
+                // We are hovering something else in the menu, so close the submenu,
+                // unless the pointer is just passing through on its way back.
+                let now = ui.input(|i| i.time);
+                let left_at = *self.left_submenu_at.get_or_insert(now);
+                let close_delay = ui.style().interaction.submenu_close_delay;
+                let time_left = close_delay as f64 - (now - left_at);
+                if time_left <= 0.0 {
+                    self.close_submenu();
+                } else {
+                    ui.ctx()
+                        .request_repaint_after(std::time::Duration::from_secs_f64(time_left));
+                }
+            }
+        } else if button.hovered() {
+            // Open the sub-menu once the pointer has rested on the button for a while,
+            // and any other sub-menu has closed.
+            let now = ui.input(|i| i.time);
+            let hovered_since = match self.hovered_button {
+                Some((id, since)) if id == sub_id => since,
+                _ => {
+                    self.hovered_button = Some((sub_id, now));
+                    now
+                }
+            };
+            let open_delay = ui.style().interaction.submenu_open_delay;
+            let time_left = open_delay as f64 - (now - hovered_since);
+            if self.sub_menu.is_some() {
+                // Waiting for the open one to close, see above.
+            } else if time_left <= 0.0 {
+                let pos = self.submenu_pos(ui, button);
+                self.open_submenu(sub_id, pos);
+                self.hovered_button = None;
+            } else {
+                ui.ctx()
+                    .request_repaint_after(std::time::Duration::from_secs_f64(time_left));
+            }
+        } else if self.hovered_button.map(|(id, _)| id) == Some(sub_id) {
+            self.hovered_button = None;
         }
     }
 
+    /// Where to open the sub-menu of this button.
+    ///
+    /// It opens to the right, but is flipped to the left if there is no room for it,
+    /// see [`Self::show_submenu`].
+    fn submenu_pos(&self, ui: &Ui, button: &Response) -> Pos2 {
+        let mut pos = button.rect.right_top();
+        pos.x = self.rect.right() + ui.spacing().menu_spacing;
+        pos.y -= Frame::menu(ui.style()).total_margin().top; // align the first button in the submenu with the parent button
+        pos
+    }
+
     /// Check if pointer is moving towards current submenu.
-    fn moving_towards_current_submenu(&self, pointer: &PointerState) -> bool {
+    fn moving_towards_current_submenu(&self, ui: &Ui, pointer: &PointerState) -> bool {
         if pointer.is_still() {
             return false;
         }
 
         if let Some(sub_menu) = self.current_submenu() {
             if let Some(pos) = pointer.hover_pos() {
-                let rect = sub_menu.read().rect;
+                // Be forgiving with diagonal movements that just miss a corner of the sub-menu:
+                let tolerance = ui.spacing().interact_size.y;
+                let rect = sub_menu.read().rect.expand2(vec2(0.0, tolerance));
                 return rect.intersects_ray(pos, pointer.velocity().normalized());
             }
         }
@@ -722,3 +1303,32 @@ impl MenuState {
         self.sub_menu = None;
     }
 }
+
+#[test]
+fn escape_closes_menu_without_items() {
+    use crate::{Event, TestHarness};
+
+    let harness = TestHarness::new();
+    // Returns if the menu is open, and where its button is:
+    let run = |events: Vec<Event>| {
+        harness.run_ui(events, |ui| {
+            let mut is_open = false;
+            let button_rect = ui
+                .menu_button("File", |ui| {
+                    is_open = true;
+                    ui.label("Nothing to do");
+                })
+                .response
+                .rect;
+            (is_open, button_rect)
+        })
+    };
+
+    let (is_open, button_rect) = run(vec![]);
+    assert!(!is_open);
+    run(TestHarness::click_events(button_rect.center()));
+    assert!(run(vec![]).0);
+
+    run(vec![TestHarness::key_event(Key::Escape)]);
+    assert!(!run(vec![]).0);
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::{
-    emath::{Align2, Pos2, Rangef, Rect, Vec2},
+    emath::{remap_clamp, Align2, NumExt as _, Pos2, Rangef, Rect, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
     Color32, Context, FontId,
 };
@@ -453,6 +453,54 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint text whose size is given in world units, e.g. on a zoomable canvas.
+    ///
+    /// `font_id.size` is in world units, and `points_per_world_unit` is the current zoom.
+    /// The rendered size is clamped to [`WorldTextSize::min_size`]..=[`WorldTextSize::max_size`] points,
+    /// and the text fades out when it would be too small to read.
+    ///
+    /// Returns where the text ended up, or `None` if it was too small to be painted at all.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let zoom = 0.5;
+    /// # let node_pos = egui::Pos2::ZERO;
+    /// ui.painter().world_text(
+    ///     node_pos,
+    ///     egui::Align2::CENTER_CENTER,
+    ///     "Node label",
+    ///     egui::FontId::proportional(20.0),
+    ///     ui.visuals().text_color(),
+    ///     zoom,
+    ///     egui::WorldTextSize::default(),
+    /// );
+    /// # });
+    /// ```
+    #[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+    pub fn world_text(
+        &self,
+        pos: Pos2,
+        anchor: Align2,
+        text: impl ToString,
+        mut font_id: FontId,
+        text_color: Color32,
+        points_per_world_unit: f32,
+        size: WorldTextSize,
+    ) -> Option<Rect> {
+        let screen_size = font_id.size * points_per_world_unit;
+        let opacity = size.opacity(screen_size);
+        if opacity <= 0.0 {
+            return None;
+        }
+
+        font_id.size = size.clamp(screen_size);
+        let text_color = text_color.gamma_multiply(opacity);
+        let galley = self.layout_no_wrap(text.to_string(), font_id, text_color);
+        let rect = anchor.anchor_size(pos, galley.size());
+        self.galley(rect.min, galley, text_color);
+        Some(rect)
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
@@ -535,6 +583,55 @@ impl Painter {
     }
 }
 
+/// Limits the on-screen size of text painted with [`Painter::world_text`].
+///
+/// All sizes are in points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WorldTextSize {
+    /// The text is never rendered smaller than this.
+    pub min_size: f32,
+
+    /// The text is never rendered larger than this.
+    pub max_size: f32,
+
+    /// When the unclamped size of the text is below [`Self::min_size`],
+    /// it starts fading out, and is completely invisible at this size.
+    ///
+    /// Set this to the same value as [`Self::min_size`] to never fade the text out,
+    /// but just hide it when it gets too small.
+    pub fade_out_size: f32,
+}
+
+impl Default for WorldTextSize {
+    fn default() -> Self {
+        Self {
+            min_size: 8.0,
+            max_size: f32::INFINITY,
+            fade_out_size: 4.0,
+        }
+    }
+}
+
+impl WorldTextSize {
+    /// The size the text should be rendered at, given its unclamped size.
+    #[inline]
+    pub fn clamp(&self, size: f32) -> f32 {
+        size.at_least(self.min_size).at_most(self.max_size)
+    }
+
+    /// The opacity of the text, given its unclamped size.
+    #[inline]
+    pub fn opacity(&self, size: f32) -> f32 {
+        if self.min_size <= size {
+            1.0
+        } else if size <= self.fade_out_size {
+            0.0
+        } else {
+            remap_clamp(size, self.fade_out_size..=self.min_size, 0.0..=1.0)
+        }
+    }
+}
+
 fn tint_shape_towards(shape: &mut Shape, target: Color32) {
     epaint::shape_transform::adjust_colors(shape, move |color| {
         if *color != Color32::PLACEHOLDER {
@@ -550,3 +647,51 @@ fn multiply_opacity(shape: &mut Shape, opacity: f32) {
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestHarness;
+
+    #[test]
+    fn test_world_text_fades_out() {
+        let size = WorldTextSize::default();
+        let font_size = 20.0;
+        let points_per_world_unit = 6.0 / font_size; // halfway between `fade_out_size` and `min_size`
+        let opacity = size.opacity(font_size * points_per_world_unit);
+        assert!(0.0 < opacity && opacity < 1.0);
+
+        let harness = TestHarness::with_default_fonts();
+        let ((), output) = harness.run_input(Default::default(), |ctx| {
+            let painter = ctx.layer_painter(LayerId::background());
+            painter.world_text(
+                Pos2::ZERO,
+                Align2::LEFT_TOP,
+                "Fading",
+                FontId::proportional(font_size),
+                Color32::WHITE,
+                points_per_world_unit,
+                size,
+            );
+        });
+
+        let expected = Color32::WHITE.gamma_multiply(opacity);
+        let mut num_vertices = 0;
+        for clipped in &output.shapes {
+            if let Shape::Text(text) = &clipped.shape {
+                for row in &text.galley.rows {
+                    for vertex in &row.visuals.mesh.vertices {
+                        let color = if vertex.color == Color32::PLACEHOLDER {
+                            text.fallback_color
+                        } else {
+                            vertex.color
+                        };
+                        assert_eq!(color, expected);
+                        num_vertices += 1;
+                    }
+                }
+            }
+        }
+        assert!(0 < num_vertices, "No text was painted");
+    }
+}
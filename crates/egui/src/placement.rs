@@ -0,0 +1,88 @@
+use crate::{Align2, Rect, Vec2};
+
+/// Where to put a child [`crate::Ui`] within a parent rectangle, see [`crate::Ui::place`].
+///
+/// Everything is relative to the size of the parent,
+/// so the child moves and resizes along with it.
+///
+/// ```
+/// # use egui::{Align2, Placement, vec2};
+/// // A box in the top right corner, a fifth of the width and a tenth of the height of the parent,
+/// // moved in from the corner by 2% of the parent's size:
+/// let placement = Placement::new(Align2::RIGHT_TOP)
+///     .size(vec2(0.2, 0.1))
+///     .offset(vec2(-0.02, 0.02));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Placement {
+    /// Which point of the parent the child is anchored to,
+    /// which is also the point of the child that is put there.
+    pub anchor: Align2,
+
+    /// How far to move the child from the anchor, as fractions of the size of the parent.
+    ///
+    /// Positive values move it right and down.
+    pub offset: Vec2,
+
+    /// The size of the child, as fractions of the size of the parent.
+    pub size: Vec2,
+}
+
+impl Placement {
+    /// Anchor the child to this point of the parent (e.g. [`Align2::LEFT_BOTTOM`]),
+    /// filling the whole parent.
+    ///
+    /// Call [`Self::size`] to make it smaller.
+    pub fn new(anchor: Align2) -> Self {
+        Self {
+            anchor,
+            offset: Vec2::ZERO,
+            size: Vec2::splat(1.0),
+        }
+    }
+
+    /// How far to move the child from the anchor, as fractions of the size of the parent.
+    ///
+    /// Positive values move it right and down.
+    #[inline]
+    pub fn offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// The size of the child, as fractions of the size of the parent,
+    /// e.g. `vec2(0.5, 1.0)` for half the width and the full height.
+    #[inline]
+    pub fn size(mut self, size: Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Where the child goes within this parent rectangle.
+    pub fn rect_in(&self, parent: Rect) -> Rect {
+        let pos = self.anchor.pos_in_rect(&parent) + self.offset * parent.size();
+        self.anchor.anchor_size(pos, self.size * parent.size())
+    }
+}
+
+#[test]
+fn test_placement_rect_in() {
+    use crate::{pos2, vec2};
+
+    let parent = Rect::from_min_size(pos2(100.0, 100.0), vec2(200.0, 100.0));
+    assert_eq!(Placement::new(Align2::LEFT_TOP).rect_in(parent), parent);
+
+    let top_right = Placement::new(Align2::RIGHT_TOP)
+        .size(vec2(0.2, 0.1))
+        .offset(vec2(-0.05, 0.1));
+    assert_eq!(
+        top_right.rect_in(parent),
+        Rect::from_min_max(pos2(250.0, 110.0), pos2(290.0, 120.0))
+    );
+
+    let centered = Placement::new(Align2::CENTER_CENTER).size(vec2(0.5, 0.5));
+    assert_eq!(
+        centered.rect_in(parent),
+        Rect::from_center_size(parent.center(), vec2(100.0, 50.0))
+    );
+}
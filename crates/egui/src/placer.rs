@@ -33,6 +33,12 @@ impl Placer {
         self.grid.as_ref()
     }
 
+    pub(crate) fn set_next_grid_span(&mut self, colspan: usize, rowspan: usize) {
+        if let Some(grid) = &mut self.grid {
+            grid.set_next_span(colspan, rowspan);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn is_grid(&self) -> bool {
         self.grid.is_some()
@@ -235,6 +235,9 @@ impl Response {
     ///
     /// In contrast to [`Self::contains_pointer`], this will be `false` whenever some other widget is being dragged.
     /// `hovered` is always `false` for disabled widgets.
+    ///
+    /// This includes a pen hovering above the screen, if the device reports it
+    /// and [`crate::style::Interaction::proximity_hover`] is on.
     #[inline(always)]
     pub fn hovered(&self) -> bool {
         self.hovered
@@ -537,19 +540,71 @@ impl Response {
     #[doc(alias = "tooltip")]
     pub fn on_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         if self.enabled && self.should_show_hover_ui() {
-            self.show_tooltip_ui(add_contents);
+            self.show_hover_tooltip(add_contents);
+        }
+        self
+    }
+
+    /// Like [`Self::on_hover_ui`], but the tooltip stays open while the pointer moves into it,
+    /// so it can contain buttons, links, a [`crate::ScrollArea`], selectable text, etc.
+    ///
+    /// The tooltip closes once the pointer leaves both the widget and the tooltip
+    /// (and the space between them).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.label("Hover me").on_hover_ui_interactive(|ui| {
+    ///     ui.label("Read more in the documentation:");
+    ///     ui.hyperlink("https://docs.rs/egui");
+    /// });
+    /// # });
+    /// ```
+    pub fn on_hover_ui_interactive(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        if self.enabled && (self.pointer_near_interactive_tooltip() || self.should_show_hover_ui())
+        {
+            crate::containers::show_interactive_tooltip_for(
+                &self.ctx,
+                self.id,
+                &self.rect,
+                add_contents,
+            );
         }
         self
     }
 
+    /// Is the tooltip open, and the pointer on it, on the widget, or in between?
+    fn pointer_near_interactive_tooltip(&self) -> bool {
+        if !self.is_tooltip_open() {
+            return false;
+        }
+        let Some(pointer_pos) = self.ctx.input(|i| i.pointer.hover_pos()) else {
+            return false;
+        };
+        let tooltip_id = crate::next_tooltip_id(&self.ctx, self.id);
+        AreaState::load(&self.ctx, tooltip_id).map_or(false, |area| {
+            // The bounding box also covers the gap between the widget and the tooltip,
+            // and forgives cutting a corner on the way there:
+            self.rect.union(area.rect()).contains(pointer_pos)
+        })
+    }
+
     /// Show this UI when hovering if the widget is disabled.
     pub fn on_disabled_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         if !self.enabled && self.should_show_hover_ui() {
-            crate::containers::show_tooltip_for(&self.ctx, self.id, &self.rect, add_contents);
+            self.show_hover_tooltip(add_contents);
         }
         self
     }
 
+    /// Show a tooltip below the widget or at the pointer, see [`crate::style::Interaction::tooltips_follow_pointer`].
+    fn show_hover_tooltip(&self, add_contents: impl FnOnce(&mut Ui)) {
+        if self.ctx.style().interaction.tooltips_follow_pointer {
+            crate::containers::show_tooltip_at_pointer(&self.ctx, self.id, add_contents);
+        } else {
+            crate::containers::show_tooltip_for(&self.ctx, self.id, &self.rect, add_contents);
+        }
+    }
+
     /// Like `on_hover_ui`, but show the ui next to cursor.
     pub fn on_hover_ui_at_pointer(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
         if self.enabled && self.should_show_hover_ui() {
@@ -616,8 +671,10 @@ impl Response {
                     let rect = area.rect();
 
                     if let Some(pos) = pointer_pos {
+                        // (A still pointer is not on its way anywhere.)
                         let pointer_in_area_or_on_the_way_there = rect.contains(pos)
-                            || rect.intersects_ray(pos, pointer_vel.normalized());
+                            || (pointer_vel != Vec2::ZERO
+                                && rect.intersects_ray(pos, pointer_vel.normalized()));
 
                         if pointer_in_area_or_on_the_way_there {
                             return true;
@@ -627,12 +684,36 @@ impl Response {
             }
         }
 
-        // Fast early-outs:
-        if self.enabled {
-            if !self.hovered || !self.ctx.input(|i| i.pointer.has_pointer()) {
-                return false;
+        let pointer_on_widget = if self.enabled {
+            self.hovered && self.ctx.input(|i| i.pointer.has_pointer())
+        } else {
+            self.ctx.rect_contains_pointer(self.layer_id, self.rect)
+        };
+
+        let tooltip_hide_delay = self.ctx.style().interaction.tooltip_hide_delay;
+        if is_tooltip_open && 0.0 < tooltip_hide_delay {
+            // Keep the tooltip open for a while after the pointer has left the widget:
+            let left_at_id = self.id.with("__tooltip_pointer_left_at");
+            if pointer_on_widget {
+                self.ctx.data_mut(|d| d.remove::<f64>(left_at_id));
+            } else {
+                let now = self.ctx.input(|i| i.time);
+                let left_at = self
+                    .ctx
+                    .data_mut(|d| *d.get_temp_mut_or_insert_with(left_at_id, || now));
+                let time_left = tooltip_hide_delay - (now - left_at) as f32;
+                if 0.0 < time_left {
+                    if let Ok(duration) = std::time::Duration::try_from_secs_f32(time_left) {
+                        self.ctx.request_repaint_after(duration);
+                    }
+                    return true;
+                }
+                self.ctx.data_mut(|d| d.remove::<f64>(left_at_id));
             }
-        } else if !self.ctx.rect_contains_pointer(self.layer_id, self.rect) {
+        }
+
+        // Fast early-outs:
+        if !pointer_on_widget {
             return false;
         }
 
@@ -723,6 +804,14 @@ impl Response {
         })
     }
 
+    /// Attach a help text to this widget, shown when the user clicks it in [`crate::help_mode`].
+    ///
+    /// This does nothing when help mode is off.
+    pub fn help(self, text: impl Into<WidgetText>) -> Self {
+        crate::help_mode::annotate(&self, text.into());
+        self
+    }
+
     /// Highlight this widget, to make it look like it is hovered, even if it isn't.
     ///
     /// The highlight takes one frame to take effect if you call this after the widget has been fully rendered.
@@ -905,6 +994,7 @@ impl Response {
                 Role::Button
             }
             WidgetType::Checkbox => Role::CheckBox,
+            WidgetType::Switch => Role::Switch,
             WidgetType::RadioButton => Role::RadioButton,
             WidgetType::SelectableLabel => Role::ToggleButton,
             WidgetType::ComboBox => Role::ComboBox,
@@ -977,6 +1067,8 @@ impl Response {
     /// # });
     /// ```
     ///
+    /// This replaces the built-in context menu of a widget, like the one of [`crate::TextEdit`].
+    ///
     /// See also: [`Ui::menu_button`] and [`Ui::close_menu`].
     pub fn context_menu(&self, add_contents: impl FnOnce(&mut Ui)) -> Option<InnerResponse<()>> {
         menu::context_menu(self, add_contents)
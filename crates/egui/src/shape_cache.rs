@@ -0,0 +1,214 @@
+use epaint::{
+    emath::TSTransform, tessellator::Tessellator, ClippedShape, Primitive, Shape,
+    TessellationOptions,
+};
+
+use crate::{Painter, Rect};
+
+/// A retained set of shapes that is tessellated once, and then painted cheaply every frame.
+///
+/// Use this for static, complex drawings (technical drawings, backgrounds, maps, …)
+/// that would otherwise be re-tessellated at full cost each frame.
+///
+/// The shapes are tessellated lazily on [`Self::paint`], and only re-tessellated
+/// when they are changed with [`Self::set_shapes`], or when something that affects
+/// tessellation changes (e.g. `pixels_per_point` or the tessellation options).
+///
+/// The cached meshes are painted with a [`TSTransform`], so you can pan and zoom them
+/// without re-tessellating. Note that anti-aliasing is computed for a scale of `1.0`,
+/// so if you zoom in or out a lot you may want to re-build the shapes at the new scale.
+///
+/// Text in the shapes refers to the font atlas, so if you use text,
+/// call [`Self::invalidate`] when the fonts change.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui::{Color32, Shape, Stroke};
+/// let mut cache = egui::ShapeCache::default(); // Keep this in your app state
+/// if cache.is_empty() {
+///     cache.set_shapes((0..100).map(|i| {
+///         Shape::circle_stroke(egui::pos2(i as f32, 0.0), 5.0, Stroke::new(1.0, Color32::RED))
+///     }));
+/// }
+/// cache.paint(ui.painter(), egui::emath::TSTransform::from_translation(ui.min_rect().min.to_vec2()));
+/// # });
+/// ```
+#[derive(Clone, Default)]
+pub struct ShapeCache {
+    shapes: Vec<Shape>,
+
+    /// The tessellated shapes: meshes and callbacks.
+    tessellated: Option<Tessellated>,
+}
+
+#[derive(Clone)]
+struct Tessellated {
+    pixels_per_point: f32,
+    font_tex_size: [usize; 2],
+    options: TessellationOptions,
+    shapes: Vec<Shape>,
+}
+
+impl ShapeCache {
+    /// Create a cache from the given shapes.
+    pub fn new(shapes: impl IntoIterator<Item = Shape>) -> Self {
+        Self {
+            shapes: shapes.into_iter().collect(),
+            tessellated: None,
+        }
+    }
+
+    /// Replace all the shapes in the cache.
+    ///
+    /// They will be tessellated on the next call to [`Self::paint`].
+    pub fn set_shapes(&mut self, shapes: impl IntoIterator<Item = Shape>) {
+        self.shapes = shapes.into_iter().collect();
+        self.tessellated = None;
+    }
+
+    /// The shapes in the cache, in their un-tessellated form.
+    #[inline]
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    /// No shapes in the cache?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Force a re-tessellation on the next call to [`Self::paint`].
+    #[inline]
+    pub fn invalidate(&mut self) {
+        self.tessellated = None;
+    }
+
+    /// Is there an up-to-date tessellation of the shapes?
+    #[inline]
+    pub fn is_tessellated(&self) -> bool {
+        self.tessellated.is_some()
+    }
+
+    /// Bounding rectangle of the cached shapes, before transformation.
+    pub fn bounding_rect(&self) -> Rect {
+        let mut rect = Rect::NOTHING;
+        for shape in &self.shapes {
+            rect = rect.union(shape.visual_bounding_rect());
+        }
+        rect
+    }
+
+    /// Paint the cached shapes with the given transform, tessellating them first if needed.
+    ///
+    /// The shapes are clipped to the clip rectangle of the painter.
+    pub fn paint(&mut self, painter: &Painter, transform: TSTransform) {
+        if self.shapes.is_empty() {
+            return;
+        }
+
+        let ctx = painter.ctx();
+        let pixels_per_point = ctx.pixels_per_point();
+        let options = ctx.tessellation_options(|options| *options);
+        let font_tex_size = ctx.fonts(|f| f.font_image_size());
+
+        let is_up_to_date = self.tessellated.as_ref().map_or(false, |tessellated| {
+            tessellated.pixels_per_point == pixels_per_point
+                && tessellated.font_tex_size == font_tex_size
+                && tessellated.options == options
+        });
+
+        if !is_up_to_date {
+            crate::profile_scope!("ShapeCache::tessellate");
+            let prepared_discs = ctx.fonts(|f| f.texture_atlas().lock().prepared_discs());
+            let mut tessellator =
+                Tessellator::new(pixels_per_point, options, font_tex_size, prepared_discs);
+
+            let mut primitives = vec![];
+            for shape in &self.shapes {
+                tessellator.tessellate_clipped_shape(
+                    ClippedShape {
+                        clip_rect: Rect::EVERYTHING,
+                        shape: shape.clone(),
+                    },
+                    &mut primitives,
+                );
+            }
+
+            let shapes = primitives
+                .into_iter()
+                .map(|primitive| match primitive.primitive {
+                    Primitive::Mesh(mesh) => Shape::mesh(mesh),
+                    Primitive::Callback(callback) => Shape::Callback(callback),
+                })
+                .collect();
+
+            self.tessellated = Some(Tessellated {
+                pixels_per_point,
+                font_tex_size,
+                options,
+                shapes,
+            });
+        }
+
+        if let Some(tessellated) = &self.tessellated {
+            for shape in &tessellated.shapes {
+                let mut shape = shape.clone();
+                if transform != TSTransform::IDENTITY {
+                    shape.transform(transform);
+                }
+                painter.add(shape);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_shape_cache() {
+    use crate::{pos2, vec2, Color32, Id, LayerId, Order, Stroke, TestHarness};
+
+    let mut cache = ShapeCache::new([Shape::line_segment(
+        [pos2(0.0, 0.0), pos2(10.0, 0.0)],
+        Stroke::new(1.0, Color32::RED),
+    )]);
+    assert!(!cache.is_tessellated());
+
+    let harness = TestHarness::new();
+    let run = |cache: &mut ShapeCache, pixels_per_point: f32| {
+        harness.ctx.set_pixels_per_point(pixels_per_point);
+        let layer_id = LayerId::new(Order::Background, Id::new("shapes"));
+        harness.run(vec![], |ctx| {
+            let painter = ctx.layer_painter(layer_id);
+            cache.paint(&painter, TSTransform::from_translation(vec2(100.0, 50.0)));
+            ctx.graphics(|graphics| {
+                graphics
+                    .get(layer_id)
+                    .map(|list| list.all_entries().map(|s| s.shape.clone()).collect())
+                    .unwrap_or_default()
+            })
+        })
+    };
+
+    let painted: Vec<Shape> = run(&mut cache, 1.0);
+    assert!(cache.is_tessellated());
+    assert_eq!(cache.tessellated.as_ref().unwrap().pixels_per_point, 1.0);
+    assert_eq!(painted.len(), 1);
+    let Shape::Mesh(mesh) = &painted[0] else {
+        panic!("Expected a mesh, got {:?}", painted[0]);
+    };
+    // The line, moved by the transform, plus some feathering:
+    let bounds = mesh.calc_bounds();
+    assert!(bounds
+        .expand(1.0)
+        .contains_rect(Rect::from_min_max(pos2(100.0, 50.0), pos2(110.0, 50.0))));
+    assert!(bounds.center().distance(pos2(105.0, 50.0)) < 0.5);
+    assert_eq!(cache.bounding_rect().center(), pos2(5.0, 0.0));
+
+    // Changing the scale re-tessellates:
+    run(&mut cache, 2.0);
+    assert_eq!(cache.tessellated.as_ref().unwrap().pixels_per_point, 2.0);
+
+    cache.set_shapes([]);
+    assert!(cache.is_empty() && !cache.is_tessellated());
+    assert!(run(&mut cache, 2.0).is_empty());
+}
@@ -213,6 +213,11 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// Turn off animations that move, scale or fade things, such as windows opening and closing.
+    ///
+    /// For users that are bothered by motion on screen.
+    pub reduced_motion: bool,
+
     /// Options to help debug why egui behaves strangely.
     ///
     /// Only available in debug builds.
@@ -316,6 +321,9 @@ pub struct Spacing {
     /// This is the spacing between the icon and the text
     pub icon_spacing: f32,
 
+    /// Size of a [`ToggleSwitch`], not counting its text label.
+    pub switch_size: Vec2,
+
     /// The size used for the [`Ui::max_rect`] the first frame.
     ///
     /// Text will wrap at this width, and images that expand to fill the available space
@@ -389,6 +397,11 @@ pub struct ScrollStyle {
     /// Make sure the scroll handle is at least this big
     pub handle_min_length: f32,
 
+    /// The rounding of the corners of the scroll bar and its handle.
+    ///
+    /// `None` means the rounding of the widget visuals, e.g. [`WidgetVisuals::rounding`].
+    pub rounding: Option<Rounding>,
+
     /// Margin between contents and scroll bar.
     pub bar_inner_margin: f32,
 
@@ -453,6 +466,12 @@ pub struct ScrollStyle {
     /// This is only for floating scroll bars.
     /// Solid scroll bars are always opaque.
     pub interact_handle_opacity: f32,
+
+    /// Only show floating scroll bars while the content is being scrolled (and for a second after),
+    /// instead of whenever the pointer is over the scroll area, like on a phone.
+    ///
+    /// Hovering the scroll bars still shows them.
+    pub hide_when_idle: bool,
 }
 
 impl Default for ScrollStyle {
@@ -468,6 +487,7 @@ impl ScrollStyle {
             floating: false,
             bar_width: 6.0,
             handle_min_length: 12.0,
+            rounding: None,
             bar_inner_margin: 4.0,
             bar_outer_margin: 0.0,
             floating_width: 2.0,
@@ -482,6 +502,8 @@ impl ScrollStyle {
             dormant_handle_opacity: 0.0,
             active_handle_opacity: 0.6,
             interact_handle_opacity: 1.0,
+
+            hide_when_idle: false,
         }
     }
 
@@ -522,6 +544,26 @@ impl ScrollStyle {
         }
     }
 
+    /// Thin, round scroll bars that are only shown while scrolling,
+    /// and that expand when you hover them.
+    ///
+    /// This is what scroll bars look like on phones and macOS.
+    pub fn overlay() -> Self {
+        Self {
+            floating: true,
+            bar_width: 8.0,
+            floating_width: 4.0,
+            rounding: Some(Rounding::same(4.0)),
+            foreground_color: true,
+            floating_allocated_width: 0.0,
+            dormant_background_opacity: 0.0,
+            dormant_handle_opacity: 0.0,
+            active_background_opacity: 0.0,
+            hide_when_idle: true,
+            ..Self::solid()
+        }
+    }
+
     /// Width of a solid vertical scrollbar, or height of a horizontal scroll bar, when it is at its widest.
     pub fn allocated_width(&self) -> f32 {
         if self.floating {
@@ -537,6 +579,7 @@ impl ScrollStyle {
             ui.selectable_value(self, Self::solid(), "Solid");
             ui.selectable_value(self, Self::thin(), "Thin");
             ui.selectable_value(self, Self::floating(), "Floating");
+            ui.selectable_value(self, Self::overlay(), "Overlay");
         });
 
         ui.collapsing("Details", |ui| {
@@ -549,6 +592,7 @@ impl ScrollStyle {
             floating,
             bar_width,
             handle_min_length,
+            rounding,
             bar_inner_margin,
             bar_outer_margin,
             floating_width,
@@ -562,6 +606,8 @@ impl ScrollStyle {
             dormant_handle_opacity,
             active_handle_opacity,
             interact_handle_opacity,
+
+            hide_when_idle,
         } = self;
 
         ui.horizontal(|ui| {
@@ -589,6 +635,19 @@ impl ScrollStyle {
             ui.add(DragValue::new(handle_min_length).clamp_range(0.0..=32.0));
             ui.label("Minimum handle length");
         });
+        ui.horizontal(|ui| {
+            let mut custom_rounding = rounding.is_some();
+            ui.checkbox(&mut custom_rounding, "Rounding");
+            if custom_rounding {
+                let rounding = rounding.get_or_insert(Rounding::same(2.0));
+                ui.add(DragValue::new(&mut rounding.nw).clamp_range(0.0..=16.0));
+                rounding.ne = rounding.nw;
+                rounding.sw = rounding.nw;
+                rounding.se = rounding.nw;
+            } else {
+                *rounding = None;
+            }
+        });
         ui.horizontal(|ui| {
             ui.add(DragValue::new(bar_outer_margin).clamp_range(0.0..=32.0));
             ui.label("Outer margin");
@@ -601,6 +660,8 @@ impl ScrollStyle {
         });
 
         if *floating {
+            ui.checkbox(hide_when_idle, "Only show while scrolling");
+
             crate::Grid::new("opacity").show(ui, |ui| {
                 fn opacity_ui(ui: &mut Ui, opacity: &mut f32) {
                     ui.add(DragValue::new(opacity).speed(0.01).clamp_range(0.0..=1.0));
@@ -665,6 +726,30 @@ pub struct Interaction {
     /// This lets the user quickly move over some dead space to hover the next thing.
     pub tooltip_grace_time: f32,
 
+    /// How long in seconds a tooltip stays open after the pointer has left its widget.
+    ///
+    /// Tooltips of other widgets can show up in the meantime.
+    pub tooltip_hide_delay: f32,
+
+    /// If `true`, tooltips made with [`crate::Response::on_hover_ui`] and friends
+    /// are shown next to the pointer and follow it around,
+    /// instead of being anchored below (or above) the widget.
+    ///
+    /// Interactive tooltips ([`crate::Response::on_hover_ui_interactive`]) are always anchored,
+    /// or it would be impossible to reach them.
+    ///
+    /// The width of tooltips is set with [`Spacing::tooltip_width`].
+    pub tooltips_follow_pointer: bool,
+
+    /// How long in seconds the pointer must rest on a sub-menu button before the sub-menu opens.
+    pub submenu_open_delay: f32,
+
+    /// How long in seconds the pointer can be outside of an open sub-menu (and its button)
+    /// before the sub-menu closes.
+    ///
+    /// This forgives briefly crossing a neighboring item on the way to the sub-menu.
+    pub submenu_close_delay: f32,
+
     /// Can you select the text on a [`crate::Label`] by default?
     pub selectable_labels: bool,
 
@@ -673,6 +758,11 @@ pub struct Interaction {
     /// The default is `true`, but text seelction can be slightly glitchy,
     /// so you may want to disable it.
     pub multi_widget_text_select: bool,
+
+    /// Hover widgets with a pen (or finger) that is close above the screen, but not touching it.
+    ///
+    /// Only some devices report this, see [`crate::InputState::pointer`] and [`crate::PointerState::proximity_pos`].
+    pub proximity_hover: bool,
 }
 
 /// Look and feel of the text cursor.
@@ -748,6 +838,11 @@ pub struct Visuals {
     /// The color used for [`Hyperlink`],
     pub hyperlink_color: Color32,
 
+    /// The color used for a [`Hyperlink`] that has already been visited.
+    ///
+    /// See [`crate::Memory::is_link_visited`].
+    pub hyperlink_visited_color: Color32,
+
     /// Something just barely different from the background color.
     /// Used for [`crate::Grid::striped`].
     pub faint_bg_color: Color32,
@@ -824,6 +919,9 @@ pub struct Visuals {
 
     /// How to display numeric color values.
     pub numeric_color_space: NumericColorSpace,
+
+    /// Which color space the sliders of the color picker use.
+    pub color_picker_mode: ColorPickerMode,
 }
 
 impl Visuals {
@@ -1066,6 +1164,7 @@ impl Default for Style {
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            reduced_motion: false,
             #[cfg(debug_assertions)]
             debug: Default::default(),
             explanation_tooltips: false,
@@ -1091,6 +1190,7 @@ impl Default for Spacing {
             icon_width: 14.0,
             icon_width_inner: 8.0,
             icon_spacing: 4.0,
+            switch_size: vec2(36.0, 18.0),
             default_area_size: vec2(600.0, 400.0),
             tooltip_width: 500.0,
             menu_width: 400.0,
@@ -1111,8 +1211,13 @@ impl Default for Interaction {
             show_tooltips_only_when_still: true,
             tooltip_delay: 0.5,
             tooltip_grace_time: 0.2,
+            tooltip_hide_delay: 0.0,
+            tooltips_follow_pointer: false,
+            submenu_open_delay: 0.1,
+            submenu_close_delay: 0.3,
             selectable_labels: true,
             multi_widget_text_select: true,
+            proximity_hover: true,
         }
     }
 }
@@ -1126,6 +1231,7 @@ impl Visuals {
             widgets: Widgets::default(),
             selection: Selection::default(),
             hyperlink_color: Color32::from_rgb(90, 170, 255),
+            hyperlink_visited_color: Color32::from_rgb(180, 140, 255),
             faint_bg_color: Color32::from_additive_luminance(5), // visible, but barely so
             extreme_bg_color: Color32::from_gray(10),            // e.g. TextEdit background
             code_bg_color: Color32::from_gray(64),
@@ -1173,6 +1279,7 @@ impl Visuals {
             image_loading_spinners: true,
 
             numeric_color_space: NumericColorSpace::GammaByte,
+            color_picker_mode: ColorPickerMode::Hsv,
         }
     }
 
@@ -1183,6 +1290,7 @@ impl Visuals {
             widgets: Widgets::light(),
             selection: Selection::light(),
             hyperlink_color: Color32::from_rgb(0, 155, 255),
+            hyperlink_visited_color: Color32::from_rgb(120, 70, 200),
             faint_bg_color: Color32::from_additive_luminance(5), // visible, but barely so
             extreme_bg_color: Color32::from_gray(255),           // e.g. TextEdit background
             code_bg_color: Color32::from_gray(230),
@@ -1361,6 +1469,7 @@ impl Style {
             interaction,
             visuals,
             animation_time,
+            reduced_motion,
             #[cfg(debug_assertions)]
             debug,
             explanation_tooltips,
@@ -1423,6 +1532,11 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Reduced motion");
+            ui.checkbox(reduced_motion, "")
+                .on_hover_text("Turn off animations of windows opening and closing");
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text Styles", |ui| text_styles_ui(ui, text_styles));
@@ -1479,6 +1593,7 @@ impl Spacing {
             icon_width,
             icon_width_inner,
             icon_spacing,
+            switch_size,
             default_area_size,
             tooltip_width,
             menu_width,
@@ -1570,6 +1685,10 @@ impl Spacing {
                     );
                 });
                 ui.end_row();
+
+                ui.label("Switch size");
+                ui.add(two_drag_values(switch_size, 0.0..=60.0));
+                ui.end_row();
             });
 
         ui.checkbox(
@@ -1599,8 +1718,13 @@ impl Interaction {
             show_tooltips_only_when_still,
             tooltip_delay,
             tooltip_grace_time,
+            tooltip_hide_delay,
+            tooltips_follow_pointer,
+            submenu_open_delay,
+            submenu_close_delay,
             selectable_labels,
             multi_widget_text_select,
+            proximity_hover,
         } = self;
 
         ui.spacing_mut().item_spacing = vec2(12.0, 8.0);
@@ -1643,12 +1767,46 @@ impl Interaction {
                         .suffix(" s"),
                 );
                 ui.end_row();
+
+                ui.label("Tooltip hide delay").on_hover_text(
+                    "How long a tooltip stays open after the pointer has left its widget",
+                );
+                ui.add(
+                    DragValue::new(tooltip_hide_delay)
+                        .clamp_range(0.0..=1.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Sub-menu open delay").on_hover_text(
+                    "How long the pointer must rest on a sub-menu button before the sub-menu opens",
+                );
+                ui.add(
+                    DragValue::new(submenu_open_delay)
+                        .clamp_range(0.0..=1.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Sub-menu close delay").on_hover_text(
+                    "How long the pointer can be outside of an open sub-menu before it closes",
+                );
+                ui.add(
+                    DragValue::new(submenu_close_delay)
+                        .clamp_range(0.0..=1.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
             });
 
         ui.checkbox(
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.checkbox(tooltips_follow_pointer, "Tooltips follow the mouse");
 
         ui.horizontal(|ui| {
             ui.checkbox(selectable_labels, "Selectable text in labels");
@@ -1657,6 +1815,9 @@ impl Interaction {
             }
         });
 
+        ui.checkbox(proximity_hover, "Hover with a pen above the screen")
+            .on_hover_text("Widgets are hovered by a pen (or finger) that is close to the screen, on devices that report this");
+
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset interaction settings"));
     }
 }
@@ -1801,6 +1962,7 @@ impl Visuals {
             widgets,
             selection,
             hyperlink_color,
+            hyperlink_visited_color,
             faint_bg_color,
             extreme_bg_color,
             code_bg_color,
@@ -1837,6 +1999,7 @@ impl Visuals {
             image_loading_spinners,
 
             numeric_color_space,
+            color_picker_mode,
         } = self;
 
         ui.collapsing("Background Colors", |ui| {
@@ -1864,6 +2027,7 @@ impl Visuals {
             ui_text_color(ui, error_fg_color, RichText::new("Errors"));
 
             ui_text_color(ui, hyperlink_color, "hyperlink_color");
+            ui_text_color(ui, hyperlink_visited_color, "hyperlink_visited_color");
 
             ui_color(ui, code_bg_color, RichText::new("Code background").code()).on_hover_ui(
                 |ui| {
@@ -1965,6 +2129,11 @@ impl Visuals {
                 ui.label("Color picker type");
                 numeric_color_space.toggle_button_ui(ui);
             });
+
+            ui.horizontal(|ui| {
+                ui.label("Color picker mode");
+                color_picker_mode.ui(ui);
+            });
         });
 
         ui.vertical_centered(|ui| reset_button(ui, self, "Reset visuals"));
@@ -2154,6 +2323,52 @@ impl std::fmt::Display for NumericColorSpace {
     }
 }
 
+/// Which color space the sliders of the color picker use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ColorPickerMode {
+    /// Hue, saturation and value.
+    Hsv,
+
+    /// Lightness, chroma and hue in the perceptually uniform OKLCH color space.
+    ///
+    /// Some combinations of lightness and chroma are outside the sRGB gamut, and are clamped.
+    Oklch,
+
+    /// Hue, saturation and lightness in the perceptually uniform `HSLuv` color space.
+    Hsluv,
+}
+
+impl ColorPickerMode {
+    pub const ALL: [Self; 3] = [Self::Hsv, Self::Oklch, Self::Hsluv];
+
+    /// A row of buttons for picking the mode.
+    pub fn ui(&mut self, ui: &mut Ui) -> crate::Response {
+        let old_mode = *self;
+        let mut response = ui
+            .horizontal(|ui| {
+                for mode in Self::ALL {
+                    ui.selectable_value(self, mode, mode.to_string());
+                }
+            })
+            .response;
+        if *self != old_mode {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+impl std::fmt::Display for ColorPickerMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hsv => write!(f, "HSV"),
+            Self::Oklch => write!(f, "OKLCH"),
+            Self::Hsluv => write!(f, "HSLuv"),
+        }
+    }
+}
+
 impl Widget for &mut Margin {
     fn ui(self, ui: &mut Ui) -> Response {
         let mut same = self.is_same();
@@ -2329,6 +2544,8 @@ impl Widget for &mut crate::Frame {
             shadow,
             fill,
             stroke,
+            fill_texture: _,
+            nine_patch: _,
         } = self;
 
         crate::Grid::new("frame")
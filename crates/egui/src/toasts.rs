@@ -0,0 +1,337 @@
+//! Short notifications ("toasts") in a corner of the screen, see [`Context::toasts`].
+//!
+//! ```
+//! # let ctx = &egui::Context::default();
+//! use egui::toasts::Toast;
+//! ctx.toasts().add(Toast::success("Saved"));
+//!
+//! let download = ctx.toasts().add(Toast::info("Downloading…").duration(None).progress(0.0));
+//! // Later:
+//! ctx.toasts().set_progress(download, 0.5);
+//! ```
+
+use crate::*;
+
+/// Register this plugin on the given egui context,
+/// so that it will be called every frame.
+///
+/// This is a built-in plugin in egui,
+/// meaning [`Context`] calls this from its `Default` implementation,
+/// so this is marked as `pub(crate)`.
+pub(crate) fn register(ctx: &Context) {
+    ctx.on_end_frame("toasts", std::sync::Arc::new(State::end_frame));
+}
+
+/// What sort of message a [`Toast`] is. Decides its icon and color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Info => "ℹ",
+            Self::Success => "✔",
+            Self::Warning => "⚠",
+            Self::Error => "🗙",
+        }
+    }
+
+    fn color(self, visuals: &Visuals) -> Color32 {
+        match self {
+            Self::Info => visuals.strong_text_color(),
+            Self::Success => Color32::from_rgb(0, 180, 70),
+            Self::Warning => visuals.warn_fg_color,
+            Self::Error => visuals.error_fg_color,
+        }
+    }
+}
+
+/// A short notification, shown with [`Toasts::add`].
+#[derive(Clone)]
+pub struct Toast {
+    kind: ToastKind,
+    text: WidgetText,
+    duration: Option<f32>,
+    progress: Option<f32>,
+}
+
+impl Toast {
+    pub fn new(kind: ToastKind, text: impl Into<WidgetText>) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            duration: Some(4.0),
+            progress: None,
+        }
+    }
+
+    pub fn info(text: impl Into<WidgetText>) -> Self {
+        Self::new(ToastKind::Info, text)
+    }
+
+    pub fn success(text: impl Into<WidgetText>) -> Self {
+        Self::new(ToastKind::Success, text)
+    }
+
+    pub fn warning(text: impl Into<WidgetText>) -> Self {
+        Self::new(ToastKind::Warning, text)
+    }
+
+    pub fn error(text: impl Into<WidgetText>) -> Self {
+        Self::new(ToastKind::Error, text)
+    }
+
+    /// For how many seconds to show the toast,
+    /// or `None` to show it until it is clicked or dismissed with [`Toasts::dismiss`].
+    ///
+    /// The time doesn't run out while the toast is hovered.
+    ///
+    /// Default: 4 seconds.
+    #[inline]
+    pub fn duration(mut self, duration: Option<f32>) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Show a progress bar in the toast, from `0.0` to `1.0`.
+    ///
+    /// Update it with [`Toasts::set_progress`].
+    #[inline]
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+}
+
+/// Identifies a [`Toast`] that has been added with [`Toasts::add`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+#[derive(Clone)]
+struct Entry {
+    id: ToastId,
+    toast: Toast,
+
+    /// Seconds until the toast goes away, if it has a duration.
+    time_left: Option<f32>,
+}
+
+/// The toasts plugin.
+///
+/// This is a built-in plugin in egui.
+#[derive(Clone)]
+struct State {
+    entries: Vec<Entry>,
+    next_id: u64,
+    anchor: Align2,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            next_id: 0,
+            anchor: Align2::RIGHT_BOTTOM,
+        }
+    }
+}
+
+/// The toasts of a [`Context`], see [`Context::toasts`].
+pub struct Toasts {
+    ctx: Context,
+}
+
+impl Toasts {
+    pub(crate) fn new(ctx: Context) -> Self {
+        Self { ctx }
+    }
+
+    fn state_mut<R>(&self, writer: impl FnOnce(&mut State) -> R) -> R {
+        // We use `Id::NULL` as the id, since we only have one instance of this plugin.
+        self.ctx
+            .data_mut(|data| writer(data.get_temp_mut_or_default::<State>(Id::NULL)))
+    }
+
+    /// Show a new toast, below (or above) the toasts already shown.
+    pub fn add(&self, toast: Toast) -> ToastId {
+        self.ctx.request_repaint();
+        self.state_mut(|state| {
+            let id = ToastId(state.next_id);
+            state.next_id += 1;
+            state.entries.push(Entry {
+                id,
+                time_left: toast.duration,
+                toast,
+            });
+            id
+        })
+    }
+
+    /// Remove a toast before its time runs out.
+    pub fn dismiss(&self, id: ToastId) {
+        self.ctx.request_repaint();
+        self.state_mut(|state| state.entries.retain(|entry| entry.id != id));
+    }
+
+    /// Remove all toasts.
+    pub fn clear(&self) {
+        self.ctx.request_repaint();
+        self.state_mut(|state| state.entries.clear());
+    }
+
+    /// Update the progress bar of a toast, from `0.0` to `1.0`.
+    pub fn set_progress(&self, id: ToastId, progress: f32) {
+        self.ctx.request_repaint();
+        self.state_mut(|state| {
+            if let Some(entry) = state.entries.iter_mut().find(|entry| entry.id == id) {
+                entry.toast.progress = Some(progress);
+            }
+        });
+    }
+
+    /// Is this toast still shown?
+    pub fn is_shown(&self, id: ToastId) -> bool {
+        self.state_mut(|state| state.entries.iter().any(|entry| entry.id == id))
+    }
+
+    /// Which corner (or edge) of the screen to show the toasts in.
+    ///
+    /// Default: [`Align2::RIGHT_BOTTOM`].
+    pub fn set_anchor(&self, anchor: Align2) {
+        self.state_mut(|state| state.anchor = anchor);
+    }
+}
+
+impl State {
+    fn end_frame(ctx: &Context) {
+        // The toasts are only shown in the root viewport,
+        // so that their time doesn't run out once per viewport.
+        if ctx.viewport_id() != ViewportId::ROOT {
+            return;
+        }
+
+        let mut state = ctx.data_mut(|data| {
+            let state = data.get_temp_mut_or_default::<Self>(Id::NULL);
+            std::mem::take(&mut state.entries)
+        });
+        if state.is_empty() {
+            return;
+        }
+
+        let anchor = ctx.data_mut(|data| data.get_temp_mut_or_default::<Self>(Id::NULL).anchor);
+        let dt = ctx.input(|i| i.stable_dt).at_most(0.1);
+        let margin = 8.0;
+        let screen_rect = ctx.screen_rect().shrink(margin);
+
+        let mut pos = anchor.pos_in_rect(&screen_rect);
+        // Bottom toasts stack upwards, the others downwards:
+        let direction = if anchor.y() == Align::Max { -1.0 } else { 1.0 };
+
+        state.retain_mut(|entry| {
+            let area_id = Id::new("__egui_toast").with(entry.id);
+            let response = Area::new(area_id)
+                .order(Order::Foreground)
+                .pivot(anchor)
+                .fixed_pos(pos)
+                .constrain(false)
+                .show(ctx, |ui| entry.ui(ui))
+                .inner;
+
+            pos.y += direction * (response.rect.height() + margin);
+
+            if let Some(time_left) = &mut entry.time_left {
+                if !response.hovered() {
+                    *time_left -= dt;
+                }
+                ctx.request_repaint();
+            }
+
+            let expired = entry.time_left.map_or(false, |time_left| time_left <= 0.0);
+            !response.clicked() && !expired
+        });
+
+        ctx.data_mut(|data| {
+            let stored = data.get_temp_mut_or_default::<Self>(Id::NULL);
+            // Toasts added during the frame go after the ones we already had:
+            state.append(&mut stored.entries);
+            stored.entries = state;
+        });
+    }
+}
+
+impl Entry {
+    fn ui(&self, ui: &mut Ui) -> Response {
+        let Toast {
+            kind,
+            text,
+            duration,
+            progress,
+        } = &self.toast;
+
+        let frame = Frame::popup(ui.style()).show(ui, |ui| {
+            ui.set_max_width(ui.spacing().tooltip_width);
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(kind.icon()).color(kind.color(ui.visuals())));
+                ui.add(Label::new(text.clone()).selectable(false));
+            });
+            if let Some(progress) = progress {
+                ui.add(ProgressBar::new(*progress).desired_height(4.0));
+            }
+        });
+
+        // Show how much time is left with a thin line at the bottom:
+        if let (Some(duration), Some(time_left)) = (duration, self.time_left) {
+            let rect = frame.response.rect.shrink(2.0);
+            let fraction = (time_left / duration).clamp(0.0, 1.0);
+            let x = rect.left() + fraction * rect.width();
+            ui.painter().hline(
+                rect.left()..=x,
+                rect.bottom(),
+                (1.0, kind.color(ui.visuals()).gamma_multiply(0.5)),
+            );
+        }
+
+        ui.interact(frame.response.rect, ui.id().with("toast"), Sense::click())
+            .on_hover_cursor(CursorIcon::PointingHand)
+    }
+}
+
+#[test]
+fn test_toast_expires() {
+    let harness = TestHarness::new();
+    let toasts = harness.ctx.toasts();
+    let short = toasts.add(Toast::info("Short").duration(Some(0.5)));
+    let sticky = toasts.add(Toast::info("Sticky").duration(None));
+
+    // Another viewport running frames doesn't make the time run out faster:
+    let child = ViewportId::from_hash_of("child");
+    let mut time = 0.0;
+    let mut run = |seconds: f64| {
+        for _ in 0..(seconds * 60.0).round() as usize {
+            time += 1.0 / 60.0;
+            for viewport_id in [ViewportId::ROOT, child] {
+                let mut input = RawInput {
+                    viewport_id,
+                    time: Some(time),
+                    ..Default::default()
+                };
+                input.viewports.insert(child, Default::default());
+                harness.run_input(input, |_ctx| {});
+            }
+        }
+    };
+
+    run(0.4);
+    assert!(toasts.is_shown(short));
+    run(0.2);
+    assert!(!toasts.is_shown(short));
+    assert!(toasts.is_shown(sticky));
+
+    toasts.dismiss(sticky);
+    assert!(!toasts.is_shown(sticky));
+}
@@ -1344,6 +1344,36 @@ impl Ui {
         .inner
     }
 
+    /// Add a child [`Ui`] at a position relative to the [`Self::max_rect`] of this [`Ui`] (manual layout).
+    ///
+    /// The child is put where the [`Placement`] says, and clipped to this [`Ui`].
+    /// No space is allocated in this [`Ui`], so this can be used for overlays on top of other content,
+    /// e.g. a HUD in the corner of a canvas. Widgets in the child can be interacted with as normal.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{vec2, Align2, Placement};
+    /// ui.place(Placement::new(Align2::RIGHT_TOP).size(vec2(0.3, 0.2)), |ui| {
+    ///     ui.label("Score: 42");
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also [`Self::put`].
+    pub fn place<R>(
+        &mut self,
+        placement: Placement,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let parent_rect = self.max_rect();
+        let rect = placement.rect_in(parent_rect);
+        let mut child_ui = self.child_ui(rect, *self.layout(), None);
+        child_ui.set_clip_rect(parent_rect.intersect(self.clip_rect()));
+        let inner = add_contents(&mut child_ui);
+        let response = self.interact(child_ui.min_rect(), child_ui.id(), Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+
     /// Add a single [`Widget`] that is possibly disabled, i.e. greyed out and non-interactive.
     ///
     /// If you call `add_enabled` from within an already disabled [`Ui`],
@@ -1595,6 +1625,22 @@ impl Ui {
         Hyperlink::from_label_and_url(label, url).ui(self)
     }
 
+    /// Show some [Markdown](https://commonmark.org/help/) text.
+    ///
+    /// Shortcut for `add(Markdown::new(text))`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.markdown("# Changelog\n* **New:** markdown rendering");
+    /// # });
+    /// ```
+    ///
+    /// Requires the `markdown` feature. See also [`Markdown`].
+    #[cfg(feature = "markdown")]
+    pub fn markdown(&mut self, text: &str) -> Response {
+        Markdown::new(text).ui(self)
+    }
+
     /// No newlines (`\n`) allowed. Pressing enter key will result in the [`TextEdit`] losing focus (`response.lost_focus`).
     ///
     /// See also [`TextEdit`].
@@ -1765,8 +1811,40 @@ impl Ui {
         Spinner::new().ui(self)
     }
 
+    /// Edit a [`Vec2`] with a [`VectorEdit`].
+    pub fn vec2_edit(&mut self, vec2: &mut Vec2) -> Response {
+        VectorEdit::new([&mut vec2.x, &mut vec2.y]).ui(self)
+    }
+
+    /// Edit a [`Pos2`] with a [`VectorEdit`].
+    pub fn pos2_edit(&mut self, pos2: &mut Pos2) -> Response {
+        VectorEdit::new([&mut pos2.x, &mut pos2.y]).ui(self)
+    }
+
+    /// Edit a 3D vector with a [`VectorEdit`].
+    pub fn vec3_edit(&mut self, vec3: &mut [f32; 3]) -> Response {
+        VectorEdit::new(vec3).ui(self)
+    }
+
+    /// Edit a 4D vector with a [`VectorEdit`].
+    pub fn vec4_edit(&mut self, vec4: &mut [f32; 4]) -> Response {
+        VectorEdit::new(vec4).ui(self)
+    }
+
+    /// Edit the corners of a [`Rect`] with two [`VectorEdit`]s, one for [`Rect::min`] and one for [`Rect::max`].
+    pub fn rect_edit(&mut self, rect: &mut Rect) -> Response {
+        self.horizontal(|ui| {
+            let min = ui.label("Min") | ui.pos2_edit(&mut rect.min);
+            let max = ui.label("Max") | ui.pos2_edit(&mut rect.max);
+            min | max
+        })
+        .inner
+    }
+
     /// Modify an angle. The given angle should be in radians, but is shown to the user in degrees.
     /// The angle is NOT wrapped, so the user may select, for instance 720° = 2𝞃 = 4π
+    ///
+    /// See [`DragAngle`] for more options, like wrapping and a circular angle pad.
     pub fn drag_angle(&mut self, radians: &mut f32) -> Response {
         let mut degrees = radians.to_degrees();
         let mut response = self.add(DragValue::new(&mut degrees).speed(1.0).suffix("°"));
@@ -1783,6 +1861,8 @@ impl Ui {
     /// Modify an angle. The given angle should be in radians,
     /// but is shown to the user in fractions of one Tau (i.e. fractions of one turn).
     /// The angle is NOT wrapped, so the user may select, for instance 2𝞃 (720°)
+    ///
+    /// See [`DragAngle`] for more options, like wrapping and a circular angle pad.
     pub fn drag_angle_tau(&mut self, radians: &mut f32) -> Response {
         use std::f32::consts::TAU;
 
@@ -1920,6 +2000,25 @@ impl Ui {
         *rgba_unmul = rgba.to_rgba_unmultiplied();
         response
     }
+
+    /// Edit a gradient, given as a list of `(position, color)` stops.
+    ///
+    /// Drag the stops to move them, click them to edit their color,
+    /// double-click the gradient to add a stop, and right-click a stop to remove it.
+    /// See [`GradientEditor`] for more options.
+    ///
+    /// Use [`color_picker::gradient_color_at`] to sample the resulting gradient.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui::Color32;
+    /// let mut stops = vec![(0.0, Color32::BLACK), (1.0, Color32::WHITE)];
+    /// ui.gradient_edit(&mut stops);
+    /// # });
+    /// ```
+    pub fn gradient_edit(&mut self, stops: &mut Vec<(f32, Color32)>) -> Response {
+        color_picker::gradient_edit(self, stops)
+    }
 }
 
 /// # Adding Containers / Sub-uis:
@@ -1939,6 +2038,51 @@ impl Ui {
         crate::Frame::group(self.style()).show(self, add_contents)
     }
 
+    /// Add a header that sticks to the top of the enclosing [`ScrollArea`]
+    /// while the content below it is scrolled through, until the next sticky header pushes it out.
+    ///
+    /// The header is laid out where it would normally be, and then moved to where it sticks,
+    /// together with where its widgets can be interacted with.
+    ///
+    /// Outside of a [`ScrollArea`] this is the same as [`Self::scope`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     for section in ["Fruit", "Vegetables"] {
+    ///         ui.sticky_header(|ui| ui.heading(section));
+    ///         for i in 0..20 {
+    ///             ui.label(format!("{section} {i}"));
+    ///         }
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn sticky_header<R>(
+        &mut self,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        crate::containers::scroll_area::sticky_header(self, Box::new(add_contents))
+    }
+
+    /// Add an item that the enclosing [`ScrollArea`] keeps in place
+    /// when the content above it changes size, see [`ScrollArea::scroll_anchoring`].
+    ///
+    /// `id_source` must stay the same for the item from frame to frame,
+    /// e.g. the id of a message in a chat log.
+    /// Like [`Self::push_id`], it is also used for the [`Id`]s of the widgets in the item.
+    pub fn scroll_anchor<R>(
+        &mut self,
+        id_source: impl Hash,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        crate::containers::scroll_area::scroll_anchor(
+            self,
+            Id::new(id_source),
+            Box::new(add_contents),
+        )
+    }
+
     /// Create a child Ui with an explicit [`Id`].
     ///
     /// ```
@@ -2316,6 +2460,11 @@ impl Ui {
         self.placer.is_grid()
     }
 
+    /// Let the next cell of the grid this [`Ui`] is laying out span several columns and rows.
+    pub(crate) fn set_next_grid_span(&mut self, colspan: usize, rowspan: usize) {
+        self.placer.set_next_grid_span(colspan, rowspan);
+    }
+
     /// Move to the next row in a grid layout or wrapping layout.
     /// Otherwise does nothing.
     pub fn end_row(&mut self) {
@@ -2508,6 +2657,11 @@ impl Ui {
         self.menu_state = menu_state;
     }
 
+    /// The state of the menu we are in, if any.
+    pub(crate) fn menu_state(&self) -> Option<&Arc<RwLock<MenuState>>> {
+        self.menu_state.as_ref()
+    }
+
     #[inline]
     /// Create a menu button that when clicked will show the given menu.
     ///
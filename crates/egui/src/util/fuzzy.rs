@@ -0,0 +1,89 @@
+//! Matching search queries against item labels, e.g. for [`crate::CommandPalette`] and [`crate::ComboBox`].
+
+/// How well does `query` match `text`, ignoring case?
+///
+/// All characters of the query (except whitespace) must appear in the text, in the same order,
+/// but not necessarily next to each other. So `"ofi"` matches `"Open file"`.
+/// Characters matched at the start of a word, or right after the previous match, score higher,
+/// and characters skipped between matches lower the score.
+///
+/// Returns `None` if it doesn't match. The empty query matches everything with a score of zero.
+pub(crate) fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let mut query_chars = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+
+    let mut score = 0;
+    let mut prev_char: Option<char> = None;
+    let mut prev_matched = false;
+    let mut started = false;
+
+    for c in text.chars() {
+        let Some(&wanted) = query_chars.peek() else {
+            break;
+        };
+
+        let is_word_start = prev_char.map_or(true, |prev| {
+            !prev.is_alphanumeric() || (prev.is_lowercase() && c.is_uppercase())
+        });
+
+        if c.to_lowercase().next() == Some(wanted) {
+            score += 1;
+            if is_word_start {
+                score += 8;
+            }
+            if prev_matched {
+                score += 8;
+            }
+            query_chars.next();
+            prev_matched = true;
+            started = true;
+        } else {
+            if started {
+                score -= 1;
+            }
+            prev_matched = false;
+        }
+        prev_char = Some(c);
+    }
+
+    query_chars.peek().is_none().then_some(score)
+}
+
+/// Does `text` start with `query`, ignoring case?
+pub(crate) fn prefix_match(query: &str, text: &str) -> bool {
+    let mut text_chars = text.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|c| text_chars.next() == Some(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert_eq!(fuzzy_score("", "Open file"), Some(0));
+        assert!(fuzzy_score("ofi", "Open file").is_some());
+        assert!(fuzzy_score("OF", "open file").is_some());
+        assert_eq!(fuzzy_score("fo", "Open file"), None);
+
+        // Word starts beat matches in the middle of words:
+        assert!(fuzzy_score("of", "Open file") > fuzzy_score("of", "proof"));
+
+        // Consecutive matches beat scattered ones:
+        assert!(fuzzy_score("save", "Save all") > fuzzy_score("save", "Show a video editor"));
+    }
+
+    #[test]
+    fn test_prefix_match() {
+        assert!(prefix_match("", "anything"));
+        assert!(prefix_match("ger", "Germany"));
+        assert!(!prefix_match("many", "Germany"));
+        assert!(!prefix_match("Germany!", "Germany"));
+    }
+}
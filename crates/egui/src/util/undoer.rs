@@ -112,6 +112,14 @@ where
         }
     }
 
+    /// Change [`Settings::max_undos`], dropping the oldest undo points if needed.
+    pub fn set_max_undos(&mut self, max_undos: usize) {
+        self.settings.max_undos = max_undos;
+        while self.undos.len() > max_undos {
+            self.undos.pop_front();
+        }
+    }
+
     /// Do we have an undo point different from the given state?
     pub fn has_undo(&self, current_state: &State) -> bool {
         match self.undos.len() {
@@ -219,3 +227,16 @@ where
         }
     }
 }
+
+#[test]
+fn test_set_max_undos() {
+    let mut undoer = Undoer::default();
+    for state in 0..5 {
+        undoer.add_undo(&state);
+    }
+    undoer.set_max_undos(2);
+    assert_eq!(undoer.undos, [3, 4]);
+
+    undoer.add_undo(&5);
+    assert_eq!(undoer.undos, [4, 5]);
+}
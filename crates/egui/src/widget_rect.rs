@@ -1,4 +1,4 @@
-use ahash::HashMap;
+use ahash::{HashMap, HashSet};
 
 use crate::*;
 
@@ -59,6 +59,14 @@ pub struct WidgetRects {
     /// Only filled in if the widget is interacted with,
     /// or if this is a debug build.
     infos: IdMap<WidgetInfo>,
+
+    /// Layers whose widgets always get their info stored, see [`Self::keep_infos_for_layer`].
+    info_layers: HashSet<LayerId>,
+
+    /// Spatial indices for layers with many widgets.
+    ///
+    /// See [`Self::build_spatial_index`].
+    spatial_index: HashMap<LayerId, SpatialIndex>,
 }
 
 impl PartialEq for WidgetRects {
@@ -100,12 +108,60 @@ impl WidgetRects {
         self.by_layer.get(&layer_id).into_iter().flatten()
     }
 
+    /// The widgets in this layer that may intersect the given rectangle, sorted back-to-front.
+    ///
+    /// If the layer has a spatial index (see [`Self::build_spatial_index`]),
+    /// this is fast even for layers with many widgets.
+    /// Otherwise all the widgets in the layer are returned.
+    ///
+    /// Either way, the result can contain widgets that are outside of `rect`,
+    /// so you still need to check each returned widget.
+    pub fn get_layer_candidates(
+        &self,
+        layer_id: LayerId,
+        rect: Rect,
+    ) -> impl Iterator<Item = &WidgetRect> + '_ {
+        let widgets = self.by_layer.get(&layer_id).map_or(&[][..], |w| &w[..]);
+        match self.spatial_index.get(&layer_id) {
+            Some(index) => LayerCandidates::Indexed {
+                widgets,
+                indices: index.query(rect).into_iter(),
+            },
+            None => LayerCandidates::All(widgets.iter()),
+        }
+    }
+
+    /// Build a spatial index for each layer with at least `min_widgets` widgets.
+    ///
+    /// This speeds up [`Self::get_layer_candidates`], and thus hit-testing,
+    /// for layers with thousands of small interactive widgets.
+    ///
+    /// The index of a layer is discarded as soon as a widget is inserted into it.
+    pub fn build_spatial_index(&mut self, min_widgets: usize) {
+        crate::profile_function!();
+
+        let Self {
+            by_layer,
+            spatial_index,
+            ..
+        } = self;
+
+        spatial_index.clear();
+        for (layer_id, widgets) in by_layer {
+            if min_widgets <= widgets.len() {
+                spatial_index.insert(*layer_id, SpatialIndex::new(widgets));
+            }
+        }
+    }
+
     /// Clear the contents while retaining allocated memory.
     pub fn clear(&mut self) {
         let Self {
             by_layer,
             by_id,
             infos,
+            info_layers,
+            spatial_index,
         } = self;
 
         for rects in by_layer.values_mut() {
@@ -115,6 +171,9 @@ impl WidgetRects {
         by_id.clear();
 
         infos.clear();
+        info_layers.clear();
+
+        spatial_index.clear();
     }
 
     /// Insert the given widget rect in the given layer.
@@ -123,8 +182,14 @@ impl WidgetRects {
             by_layer,
             by_id,
             infos: _,
+            info_layers: _,
+            spatial_index,
         } = self;
 
+        if !spatial_index.is_empty() {
+            spatial_index.remove(&layer_id);
+        }
+
         let layer_widgets = by_layer.entry(layer_id).or_default();
 
         match by_id.entry(widget_rect.id) {
@@ -164,4 +229,288 @@ impl WidgetRects {
     pub fn info(&self, id: Id) -> Option<&WidgetInfo> {
         self.infos.get(&id)
     }
+
+    /// Store the [`WidgetInfo`] of every widget in this layer for the rest of the frame,
+    /// not just of those that are interacted with.
+    ///
+    /// Used e.g. by menus to find items by their text.
+    pub fn keep_infos_for_layer(&mut self, layer_id: LayerId) {
+        self.info_layers.insert(layer_id);
+    }
+
+    /// The number of widgets inserted into this layer so far.
+    pub fn layer_len(&self, layer_id: LayerId) -> usize {
+        self.by_layer.get(&layer_id).map_or(0, Vec::len)
+    }
+
+    /// Move the widgets at `range` in the given layer (see [`Self::layer_len`]) by `delta`,
+    /// e.g. because they are painted somewhere else than where they were laid out.
+    ///
+    /// Where they can be interacted with is clipped to `clip_rect`.
+    pub fn translate(
+        &mut self,
+        layer_id: LayerId,
+        range: std::ops::Range<usize>,
+        delta: Vec2,
+        clip_rect: Rect,
+    ) {
+        let Some(layer_widgets) = self.by_layer.get_mut(&layer_id) else {
+            return;
+        };
+        self.spatial_index.remove(&layer_id);
+        for widget in &mut layer_widgets[range] {
+            widget.rect = widget.rect.translate(delta);
+            widget.interact_rect = widget.interact_rect.translate(delta).intersect(clip_rect);
+            if let Some((_, by_id)) = self.by_id.get_mut(&widget.id) {
+                *by_id = *widget;
+            }
+        }
+    }
+
+    /// Move the widgets at `range` in the given layer to the top of it, translating them by `delta`,
+    /// like [`crate::layers::PaintList::move_to_top`] does with their shapes.
+    ///
+    /// Where they can be interacted with is clipped to `clip_rect`.
+    /// Their old places in the layer are left disabled and without any area.
+    pub fn move_to_top(
+        &mut self,
+        layer_id: LayerId,
+        range: std::ops::Range<usize>,
+        delta: Vec2,
+        clip_rect: Rect,
+    ) {
+        let Some(layer_widgets) = self.by_layer.get_mut(&layer_id) else {
+            return;
+        };
+        self.spatial_index.remove(&layer_id);
+        for idx in range {
+            let mut widget = layer_widgets[idx];
+            layer_widgets[idx] = WidgetRect {
+                rect: Rect::NOTHING,
+                interact_rect: Rect::NOTHING,
+                sense: Sense::hover(),
+                enabled: false,
+                ..widget
+            };
+            widget.rect = widget.rect.translate(delta);
+            widget.interact_rect = widget.interact_rect.translate(delta).intersect(clip_rect);
+            if let Some(by_id) = self.by_id.get_mut(&widget.id) {
+                *by_id = (layer_widgets.len(), widget);
+            }
+            layer_widgets.push(widget);
+        }
+    }
+
+    /// Should the info of this widget be stored, see [`Self::keep_infos_for_layer`]?
+    pub fn keeps_info(&self, id: Id) -> bool {
+        !self.info_layers.is_empty()
+            && self
+                .get(id)
+                .map_or(false, |w| self.info_layers.contains(&w.layer_id))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A uniform grid over the interact rects of the widgets in one layer.
+#[derive(Clone)]
+struct SpatialIndex {
+    /// The area covered by the grid.
+    bounds: Rect,
+
+    /// Number of cells along x and y.
+    num_cells: [usize; 2],
+
+    /// Size of each cell.
+    cell_size: Vec2,
+
+    /// For each cell, row-major: indices of the widgets that overlap it.
+    cells: Vec<Vec<usize>>,
+
+    /// Indices of widgets that are too big (or weird) to put into the cells.
+    ///
+    /// These are always returned by [`Self::query`].
+    unindexed: Vec<usize>,
+}
+
+impl SpatialIndex {
+    fn new(widgets: &[WidgetRect]) -> Self {
+        let is_indexable =
+            |rect: &Rect| rect.is_finite() && rect.min.x <= rect.max.x && rect.min.y <= rect.max.y;
+
+        let mut bounds = Rect::NOTHING;
+        for w in widgets {
+            if is_indexable(&w.interact_rect) {
+                bounds = bounds.union(w.interact_rect);
+            }
+        }
+
+        // About one widget per cell, if they are evenly spread out:
+        let side = ((widgets.len() as f32).sqrt().ceil() as usize).clamp(1, 256);
+        let num_cells = [side, side];
+        let cell_size = if bounds.is_positive() {
+            bounds.size() / side as f32
+        } else {
+            Vec2::splat(1.0)
+        };
+        let cell_size = cell_size.max(Vec2::splat(f32::EPSILON));
+
+        let mut index = Self {
+            bounds,
+            num_cells,
+            cell_size,
+            cells: vec![vec![]; side * side],
+            unindexed: vec![],
+        };
+
+        // Widgets spanning more cells than this are cheaper to always check:
+        let max_cells_per_widget = 4 * side;
+
+        for (i, w) in widgets.iter().enumerate() {
+            let rect = w.interact_rect;
+            let cell_range = is_indexable(&rect)
+                .then(|| index.cell_range(rect))
+                .flatten();
+            match cell_range {
+                Some([x_range, y_range])
+                    if x_range.len() * y_range.len() <= max_cells_per_widget =>
+                {
+                    for y in y_range {
+                        for x in x_range.clone() {
+                            index.cells[y * side + x].push(i);
+                        }
+                    }
+                }
+                _ => index.unindexed.push(i),
+            }
+        }
+
+        index
+    }
+
+    /// The cells overlapping the given rectangle, if any.
+    fn cell_range(&self, rect: Rect) -> Option<[std::ops::Range<usize>; 2]> {
+        let range = |d: usize| {
+            let min = ((rect.min[d] - self.bounds.min[d]) / self.cell_size[d]).floor();
+            let max = ((rect.max[d] - self.bounds.min[d]) / self.cell_size[d]).floor();
+            let last = self.num_cells[d] as f32 - 1.0;
+            if max < 0.0 || last < min {
+                None
+            } else {
+                Some(min.max(0.0) as usize..max.min(last) as usize + 1)
+            }
+        };
+        Some([range(0)?, range(1)?])
+    }
+
+    /// Sorted indices of all widgets that may overlap the given rectangle.
+    fn query(&self, rect: Rect) -> Vec<usize> {
+        let mut indices = self.unindexed.clone();
+        if let Some([x_range, y_range]) = self.cell_range(rect) {
+            for y in y_range {
+                for x in x_range.clone() {
+                    indices.extend_from_slice(&self.cells[y * self.num_cells[0] + x]);
+                }
+            }
+        }
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+/// The result of [`WidgetRects::get_layer_candidates`].
+///
+/// Without a spatial index, this just iterates over the widgets of the layer,
+/// so that the common case doesn't allocate.
+enum LayerCandidates<'a> {
+    All(std::slice::Iter<'a, WidgetRect>),
+    Indexed {
+        widgets: &'a [WidgetRect],
+        indices: std::vec::IntoIter<usize>,
+    },
+}
+
+impl<'a> Iterator for LayerCandidates<'a> {
+    type Item = &'a WidgetRect;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::All(iter) => iter.next(),
+            Self::Indexed { widgets, indices } => indices.next().map(|i| &widgets[i]),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::All(iter) => iter.size_hint(),
+            Self::Indexed { indices, .. } => indices.size_hint(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spatial_index_finds_overlapping_widgets() {
+        let mut widgets = WidgetRects::default();
+        let layer_id = LayerId::background();
+        let mut add = |name: &str, rect: Rect| {
+            widgets.insert(
+                layer_id,
+                WidgetRect {
+                    id: Id::new(name),
+                    layer_id,
+                    rect,
+                    interact_rect: rect,
+                    sense: Sense::click(),
+                    enabled: true,
+                },
+            );
+        };
+
+        add("background", Rect::EVERYTHING);
+        for y in 0..100 {
+            for x in 0..100 {
+                let min = pos2(10.0 * x as f32, 10.0 * y as f32);
+                add(
+                    &format!("{x},{y}"),
+                    Rect::from_min_size(min, vec2(8.0, 8.0)),
+                );
+            }
+        }
+        add(
+            "on-top",
+            Rect::from_min_size(pos2(500.0, 500.0), vec2(100.0, 100.0)),
+        );
+
+        let query = |widgets: &WidgetRects, rect: Rect| -> Vec<Id> {
+            widgets
+                .get_layer_candidates(layer_id, rect)
+                .filter(|w| w.interact_rect.intersects(rect))
+                .map(|w| w.id)
+                .collect()
+        };
+
+        let rects = [
+            Rect::from_center_size(pos2(555.0, 555.0), vec2(4.0, 4.0)),
+            Rect::from_min_size(pos2(-50.0, -50.0), vec2(60.0, 60.0)),
+            Rect::from_min_size(pos2(2000.0, 2000.0), vec2(10.0, 10.0)),
+        ];
+        let without_index: Vec<_> = rects.iter().map(|&r| query(&widgets, r)).collect();
+
+        widgets.build_spatial_index(100);
+        assert!(widgets.get_layer_candidates(layer_id, rects[0]).count() < 100);
+        let with_index: Vec<_> = rects.iter().map(|&r| query(&widgets, r)).collect();
+
+        assert_eq!(without_index, with_index);
+        assert_eq!(
+            with_index[0],
+            vec![Id::new("background"), Id::new("55,55"), Id::new("on-top")]
+        );
+    }
 }
@@ -34,6 +34,7 @@ pub struct Button<'a> {
     min_size: Vec2,
     rounding: Option<Rounding>,
     selected: bool,
+    elided_text_on_hover: bool,
 }
 
 impl<'a> Button<'a> {
@@ -67,6 +68,7 @@ impl<'a> Button<'a> {
             min_size: Vec2::ZERO,
             rounding: None,
             selected: false,
+            elided_text_on_hover: false,
         }
     }
 
@@ -96,6 +98,26 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Set [`Self::wrap_mode`] to [`TextWrapMode::TruncateMiddle`].
+    ///
+    /// Text that doesn't fit is elided in the middle, e.g. `/home/us…/file.txt`.
+    #[inline]
+    pub fn truncate_middle(mut self) -> Self {
+        self.wrap_mode = Some(TextWrapMode::TruncateMiddle);
+        self
+    }
+
+    /// If the text doesn't fit and is elided (see [`Self::truncate`]), show the full text on hover.
+    ///
+    /// Leave this off if you add a tooltip of your own with [`Response::on_hover_text`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn elided_text_on_hover(mut self, elided_text_on_hover: bool) -> Self {
+        self.elided_text_on_hover = elided_text_on_hover;
+        self
+    }
+
     /// Override background fill color. Note that this will override any on-hover effects.
     /// Calling this will also turn on the frame.
     #[inline]
@@ -187,6 +209,7 @@ impl Widget for Button<'_> {
             min_size,
             rounding,
             selected,
+            elided_text_on_hover,
         } = self;
 
         let frame = frame.unwrap_or_else(|| ui.visuals().button_frame);
@@ -272,6 +295,14 @@ impl Widget for Button<'_> {
         });
 
         if ui.is_rect_visible(rect) {
+            if let Some(galley) = galley
+                .as_ref()
+                .filter(|galley| elided_text_on_hover && galley.elided)
+            {
+                // Show the full (non-elided) text on hover:
+                response = response.on_hover_text(galley.text());
+            }
+
             let visuals = ui.style().interact(&response);
 
             let (frame_expansion, frame_rounding, frame_fill, frame_stroke) = if selected {
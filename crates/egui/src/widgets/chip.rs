@@ -0,0 +1,186 @@
+use crate::*;
+
+/// A small rounded pill with a text, e.g. for showing tags or filters.
+///
+/// A chip can have a leading icon, a trailing close button (see [`Self::closable`]),
+/// and be shown as selected (see [`Self::selected`]).
+///
+/// Clicking the close button does not count as clicking the chip,
+/// so use [`Self::show`] to find out if it was clicked.
+/// It is up to you to remove the chip when that happens.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut tags = vec!["rust".to_owned(), "gui".to_owned()];
+/// let mut removed = None;
+/// ui.horizontal_wrapped(|ui| {
+///     for (i, tag) in tags.iter().enumerate() {
+///         if egui::Chip::new(tag).closable(true).show(ui).close_clicked() {
+///             removed = Some(i);
+///         }
+///     }
+/// });
+/// if let Some(i) = removed {
+///     tags.remove(i);
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);` or call `.show(ui)`"]
+pub struct Chip {
+    text: WidgetText,
+    icon: Option<WidgetText>,
+    closable: bool,
+    selected: bool,
+}
+
+impl Chip {
+    pub fn new(text: impl Into<WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            icon: None,
+            closable: false,
+            selected: false,
+        }
+    }
+
+    /// Show an icon before the text, e.g. an emoji.
+    #[inline]
+    pub fn icon(mut self, icon: impl Into<WidgetText>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Show a close button (`×`) after the text.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Show the chip as selected, like a [`SelectableLabel`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Show the chip, and find out if its close button was clicked.
+    pub fn show(self, ui: &mut Ui) -> ChipResponse {
+        let Self {
+            text,
+            icon,
+            closable,
+            selected,
+        } = self;
+
+        let padding = ui.spacing().button_padding;
+        let icon_spacing = ui.spacing().icon_spacing;
+        let close_size = ui.spacing().icon_width;
+
+        let layout = |text: WidgetText| {
+            text.into_galley(
+                ui,
+                Some(TextWrapMode::Extend),
+                f32::INFINITY,
+                TextStyle::Button,
+            )
+        };
+        let galley = layout(text);
+        let icon_galley = icon.map(layout);
+
+        let height = (galley.size().y + 2.0 * padding.y).at_least(ui.spacing().interact_size.y);
+        // The ends are half circles, so leave room for those:
+        let side_margin = 0.5 * height;
+        let mut width = 2.0 * side_margin + galley.size().x;
+        if let Some(icon_galley) = &icon_galley {
+            width += icon_galley.size().x + icon_spacing;
+        }
+        if closable {
+            width += icon_spacing + close_size;
+        }
+
+        let (rect, response) = ui.allocate_exact_size(vec2(width, height), Sense::click());
+        response.widget_info(|| {
+            WidgetInfo::selected(WidgetType::SelectableLabel, selected, galley.text())
+        });
+
+        // Interact with the close button after the chip so that it is on top:
+        let close_response = closable.then(|| {
+            let close_rect = Rect::from_center_size(
+                pos2(
+                    rect.right() - side_margin - 0.5 * close_size,
+                    rect.center().y,
+                ),
+                Vec2::splat(close_size),
+            );
+            let close_response = ui
+                .interact(close_rect, response.id.with("close"), Sense::click())
+                .on_hover_text("Remove");
+            close_response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, "Remove"));
+            close_response
+        });
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact_selectable(&response, selected);
+            let rect = rect.expand(visuals.expansion);
+            ui.painter().rect(
+                rect,
+                0.5 * rect.height(),
+                visuals.weak_bg_fill,
+                visuals.bg_stroke,
+            );
+
+            let mut x = rect.left() + side_margin;
+            for galley in icon_galley.into_iter().chain(std::iter::once(galley)) {
+                let width = galley.size().x;
+                let pos = pos2(x, rect.center().y - 0.5 * galley.size().y);
+                ui.painter().galley(pos, galley, visuals.text_color());
+                x += width + icon_spacing;
+            }
+
+            if let Some(close_response) = &close_response {
+                let close_visuals = ui.style().interact(close_response);
+                let rect = close_response
+                    .rect
+                    .shrink(3.0)
+                    .expand(close_visuals.expansion);
+                let stroke = Stroke::new(close_visuals.fg_stroke.width, visuals.text_color());
+                ui.painter() // paints \
+                    .line_segment([rect.left_top(), rect.right_bottom()], stroke);
+                ui.painter() // paints /
+                    .line_segment([rect.right_top(), rect.left_bottom()], stroke);
+            }
+        }
+
+        ChipResponse {
+            response,
+            close_response,
+        }
+    }
+}
+
+impl Widget for Chip {
+    fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+/// Returned by [`Chip::show`].
+pub struct ChipResponse {
+    /// Response of the chip itself.
+    pub response: Response,
+
+    /// Response of the close button, if the chip is [`Chip::closable`].
+    pub close_response: Option<Response>,
+}
+
+impl ChipResponse {
+    /// Was the close button clicked?
+    pub fn close_clicked(&self) -> bool {
+        self.close_response.as_ref().map_or(false, |r| r.clicked())
+    }
+}
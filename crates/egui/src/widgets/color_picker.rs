@@ -1,5 +1,6 @@
 //! Color picker widgets.
 
+use crate::style::ColorPickerMode;
 use crate::util::fixed_cache::FixedCache;
 use crate::*;
 use epaint::{ecolor::*, *};
@@ -296,6 +297,9 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
         }
     }
 
+    hsv_edit_ui(ui, hsvag);
+    hex_edit_ui(ui, hsvag, alpha_control);
+
     let current_color_size = vec2(ui.spacing().slider_width, ui.spacing().interact_size.y);
     show_color(ui, *hsvag, current_color_size).on_hover_text("Selected color");
 
@@ -319,28 +323,34 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
 
     let opaque = HsvaGamma { a: 1.0, ..*hsvag };
 
-    let HsvaGamma { h, s, v, a: _ } = hsvag;
+    match color_picker_mode_ui(ui) {
+        ColorPickerMode::Hsv => {
+            let HsvaGamma { h, s, v, a: _ } = hsvag;
 
-    if false {
-        color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into()).on_hover_text("Saturation");
-    }
+            if false {
+                color_slider_1d(ui, s, |s| HsvaGamma { s, ..opaque }.into())
+                    .on_hover_text("Saturation");
+            }
 
-    if false {
-        color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into()).on_hover_text("Value");
-    }
+            if false {
+                color_slider_1d(ui, v, |v| HsvaGamma { v, ..opaque }.into()).on_hover_text("Value");
+            }
 
-    color_slider_2d(ui, s, v, |s, v| HsvaGamma { s, v, ..opaque }.into());
+            color_slider_2d(ui, s, v, |s, v| HsvaGamma { s, v, ..opaque }.into());
 
-    color_slider_1d(ui, h, |h| {
-        HsvaGamma {
-            h,
-            s: 1.0,
-            v: 1.0,
-            a: 1.0,
+            color_slider_1d(ui, h, |h| {
+                HsvaGamma {
+                    h,
+                    s: 1.0,
+                    v: 1.0,
+                    a: 1.0,
+                }
+                .into()
+            })
+            .on_hover_text("Hue");
         }
-        .into()
-    })
-    .on_hover_text("Hue");
+        mode => perceptual_sliders_ui(ui, hsvag, mode),
+    }
 
     let additive = is_additive_alpha(hsvag.a);
 
@@ -360,6 +370,72 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
     }
 }
 
+/// The sliders for [`ColorPickerMode::Oklch`] and [`ColorPickerMode::Hsluv`].
+fn perceptual_sliders_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, mode: ColorPickerMode) {
+    // The values of the 2D slider and the hue slider, all in 0-1:
+    let color_at = |[x, y, h]: [f32; 3]| -> Color32 {
+        match mode {
+            ColorPickerMode::Oklch => Oklcha::new(y, x * Oklcha::MAX_CHROMA, h, 1.0).into(),
+            ColorPickerMode::Hsv | ColorPickerMode::Hsluv => Hsluva::new(h, x, y, 1.0).into(),
+        }
+    };
+    let values_of = |color: Color32| -> [f32; 3] {
+        match mode {
+            ColorPickerMode::Oklch => {
+                let Oklcha { l, c, h, a: _ } = Oklcha::from(color);
+                [(c / Oklcha::MAX_CHROMA).at_most(1.0), l, h]
+            }
+            ColorPickerMode::Hsv | ColorPickerMode::Hsluv => {
+                let Hsluva { h, s, l, a: _ } = Hsluva::from(color);
+                [s, l, h]
+            }
+        }
+    };
+
+    // Remember the slider values, so that e.g. the hue isn't lost when the chroma goes to zero,
+    // and a clamped out-of-gamut OKLCH color keeps its values:
+    let cache_id = ui.id().with("perceptual_color");
+    let color = Color32::from(HsvaGamma { a: 1.0, ..*hsvag });
+    let old_values = ui
+        .data(|d| d.get_temp::<(Color32, ColorPickerMode, [f32; 3])>(cache_id))
+        .filter(|&(cached_color, cached_mode, _)| cached_color == color && cached_mode == mode)
+        .map_or_else(|| values_of(color), |(_, _, values)| values);
+
+    let [mut x, mut y, mut h] = old_values;
+    let x_name = match mode {
+        ColorPickerMode::Oklch => "Chroma",
+        ColorPickerMode::Hsv | ColorPickerMode::Hsluv => "Saturation",
+    };
+    color_slider_2d(ui, &mut x, &mut y, |x, y| color_at([x, y, h]))
+        .on_hover_text(format!("{x_name} and lightness"));
+    let hue_sample = match mode {
+        ColorPickerMode::Oklch => [0.4, 0.75],
+        ColorPickerMode::Hsv | ColorPickerMode::Hsluv => [1.0, 0.65],
+    };
+    color_slider_1d(ui, &mut h, |h| color_at([hue_sample[0], hue_sample[1], h]))
+        .on_hover_text("Hue");
+
+    let values = [x, y, h];
+    if values != old_values {
+        let new_color = color_at(values);
+        *hsvag = HsvaGamma {
+            a: hsvag.a,
+            ..HsvaGamma::from(new_color)
+        };
+        ui.data_mut(|d| d.insert_temp(cache_id, (new_color, mode, values)));
+    }
+}
+
+/// A switch between the [`ColorPickerMode`]s, returning the current mode.
+fn color_picker_mode_ui(ui: &mut Ui) -> ColorPickerMode {
+    let mut mode = ui.style().visuals.color_picker_mode;
+    if mode.ui(ui).changed() {
+        ui.style_mut().visuals.color_picker_mode = mode;
+        ui.ctx().style_mut(|s| s.visuals.color_picker_mode = mode);
+    }
+    mode
+}
+
 fn input_type_button_ui(ui: &mut Ui) {
     let mut input_type = ui.ctx().style().visuals.numeric_color_space;
     if input_type.toggle_button_ui(ui).changed() {
@@ -444,6 +520,97 @@ fn rgba_edit_ui(ui: &mut Ui, [r, g, b, a]: &mut [f32; 4], alpha: Alpha) -> bool
     edited
 }
 
+/// Shows 3 `DragValue` widgets to be used to edit the hue (in degrees),
+/// saturation and value (in percent).
+fn hsv_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma) {
+    fn drag_value(ui: &mut Ui, prefix: &str, suffix: &str, value: &mut f32, max: f32) -> bool {
+        DragValue::new(value)
+            .speed(0.5)
+            .prefix(prefix)
+            .suffix(suffix)
+            .clamp_range(0.0..=max)
+            .max_decimals(1)
+            .ui(ui)
+            .changed()
+    }
+
+    let Hsva { h, s, v, a } = Hsva::from(*hsvag);
+    let [mut h, mut s, mut v] = [360.0 * h, 100.0 * s, 100.0 * v];
+
+    ui.horizontal(|ui| {
+        let mut edited = false;
+        edited |= drag_value(ui, "H ", "°", &mut h, 360.0);
+        edited |= drag_value(ui, "S ", "%", &mut s, 100.0);
+        edited |= drag_value(ui, "V ", "%", &mut v, 100.0);
+        // Only update if changed to avoid rounding issues.
+        if edited {
+            *hsvag = HsvaGamma::from(Hsva::new(h / 360.0, s / 100.0, v / 100.0, a));
+        }
+    });
+}
+
+/// Shows a text field for editing the color as a hex string, like `#ff8000` or `#ff800080`.
+/// The alpha is omitted when `Alpha::Opaque`.
+///
+/// Invalid strings are shown in [`Visuals::error_fg_color`], and don't change the color.
+fn hex_edit_ui(ui: &mut Ui, hsvag: &mut HsvaGamma, alpha: Alpha) {
+    let [r, g, b, a] = Hsva::from(*hsvag).to_srgba_unmultiplied();
+    let hex = if alpha == Alpha::Opaque {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+    let parse = |text: &str| {
+        let text = text.trim();
+        let text = text.strip_prefix('#').unwrap_or(text);
+        HexColor::from_str_without_hash(text).ok()
+    };
+
+    ui.horizontal(|ui| {
+        ui.label("Hex");
+
+        // Keep what the user typed while they are editing it:
+        let id = ui.next_auto_id();
+        let editing = ui.memory(|mem| mem.has_focus(id));
+        let mut text = if editing {
+            ui.data(|d| d.get_temp::<String>(id)).unwrap_or(hex)
+        } else {
+            hex
+        };
+
+        let is_valid = parse(&text).is_some();
+        let mut text_edit = TextEdit::singleline(&mut text)
+            .id(id)
+            .font(TextStyle::Monospace)
+            .desired_width(ui.spacing().interact_size.x * 2.0);
+        if !is_valid {
+            text_edit = text_edit.text_color(ui.visuals().error_fg_color);
+        }
+        let response = ui.add(text_edit);
+
+        if response.changed() {
+            if let Some(hex_color) = parse(&text) {
+                let [r, g, b, new_a] = hex_color.color().to_srgba_unmultiplied();
+                if is_additive_alpha(hsvag.a) {
+                    let alpha = hsvag.a;
+                    *hsvag = HsvaGamma::from(Hsva::from_additive_srgb([r, g, b]));
+                    // Don't edit the alpha:
+                    hsvag.a = alpha;
+                } else {
+                    let a = if alpha == Alpha::Opaque { a } else { new_a };
+                    *hsvag = HsvaGamma::from(Hsva::from_srgba_unmultiplied([r, g, b, a]));
+                }
+            }
+        }
+
+        if response.has_focus() {
+            ui.data_mut(|d| d.insert_temp(id, text));
+        } else {
+            ui.data_mut(|d| d.remove::<String>(id));
+        }
+    });
+}
+
 /// Shows a color picker where the user can change the given [`Hsva`] color.
 ///
 /// Returns `true` on change.
@@ -472,6 +639,12 @@ pub fn color_picker_color32(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> b
     changed
 }
 
+/// Shows a button with the given color.
+/// If the user clicks the button, a full color picker is shown.
+///
+/// A color can also be dropped onto the button, e.g. from a [`ColorPalette`].
+///
+/// The colors picked with it are added to the recent colors of the [`ColorPalette`]s.
 pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Response {
     let popup_id = ui.auto_id_with("popup");
     let open = ui.memory(|mem| mem.is_popup_open(popup_id));
@@ -480,8 +653,34 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
         button_response = button_response.on_hover_text("Click to edit color");
     }
 
+    if button_response.dnd_hover_payload::<Color32>().is_some() {
+        ui.painter()
+            .rect_stroke(button_response.rect, 0.0, ui.visuals().selection.stroke);
+    }
+    if let Some(dropped) = button_response.dnd_release_payload::<Color32>() {
+        *hsva = Hsva::from(*dropped);
+        button_response.mark_changed();
+        ColorPalette::add_recent_color(ui.ctx(), *dropped);
+    }
+
+    let eyedropper_id = button_response.id.with("eyedropper");
+    let picking = ui.data(|d| d.get_temp::<Eyedropper>(eyedropper_id).is_some());
+    if let Some(picked) = eyedropper_ui(ui.ctx(), eyedropper_id) {
+        *hsva = Hsva {
+            a: hsva.a,
+            ..Hsva::from(picked)
+        };
+        button_response.mark_changed();
+        ColorPalette::add_recent_color(ui.ctx(), (*hsva).into());
+    }
+
+    // The color when the popup was opened, so we know if a new color was picked once it closes:
+    let initial_id = popup_id.with("initial_color");
     if button_response.clicked() {
         ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+        if !open {
+            ui.data_mut(|d| d.insert_temp(initial_id, Color32::from(*hsva)));
+        }
     }
 
     const COLOR_SLIDER_WIDTH: f32 = 275.0;
@@ -498,20 +697,141 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
                     if color_picker_hsva_2d(ui, hsva, alpha) {
                         button_response.mark_changed();
                     }
+                    let eyedropper_button = ui
+                        .add_enabled(!picking, Button::new("Pick from screen"))
+                        .on_hover_text("Click anywhere to pick its color");
+                    if eyedropper_button.clicked() {
+                        ui.data_mut(|d| d.insert_temp(eyedropper_id, Eyedropper::Picking));
+                        ui.output_mut(|o| o.pick_screen_color = true);
+                    }
                 });
             })
             .response;
 
+        // Clicking with the eyedropper shouldn't close the popup:
         if !button_response.clicked()
+            && !picking
             && (ui.input(|i| i.key_pressed(Key::Escape)) || area_response.clicked_elsewhere())
         {
             ui.memory_mut(|mem| mem.close_popup());
         }
     }
 
+    if !ui.memory(|mem| mem.is_popup_open(popup_id)) {
+        // However the popup was closed:
+        if let Some(initial) = ui.data_mut(|d| d.remove_temp::<Color32>(initial_id)) {
+            let color = Color32::from(*hsva);
+            if color != initial {
+                ColorPalette::add_recent_color(ui.ctx(), color);
+            }
+        }
+    }
+
     button_response
 }
 
+/// State of the eyedropper of a [`color_edit_button_hsva`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Eyedropper {
+    /// Waiting for the user to click somewhere.
+    Picking,
+
+    /// Waiting for a screenshot to sample at `pos`, requested at `time`.
+    WaitingForScreenshot { pos: Pos2, time: f64 },
+}
+
+/// Give up on a screenshot that didn't arrive after this many seconds.
+const SCREENSHOT_TIMEOUT: f64 = 2.0;
+
+/// Returns the color picked with the eyedropper, if any.
+///
+/// The integration may reply to [`PlatformOutput::pick_screen_color`] with a color from anywhere
+/// on the screen. If it doesn't, we sample a screenshot where the user clicks,
+/// if [`crate::Options::supports_screenshots`].
+///
+/// Escape cancels the picking.
+fn eyedropper_ui(ctx: &Context, id: Id) -> Option<Color32> {
+    let state = ctx.data(|d| d.get_temp::<Eyedropper>(id))?;
+    if ctx.input(|i| i.key_pressed(Key::Escape)) {
+        ctx.data_mut(|d| d.remove::<Eyedropper>(id));
+        return None;
+    }
+
+    let mut picked = ctx.input(|i| {
+        i.events.iter().find_map(|event| match event {
+            Event::ScreenColorPicked(color) => Some(*color),
+            _ => None,
+        })
+    });
+    let mut new_state = Some(state);
+
+    match state {
+        Eyedropper::Picking => {
+            // Cover the whole screen, so that the click doesn't hit whatever is below it:
+            let response = Area::new(id)
+                .order(Order::Foreground)
+                .fixed_pos(Pos2::ZERO)
+                .show(ctx, |ui| {
+                    ui.allocate_response(ctx.screen_rect().size(), Sense::click())
+                        .on_hover_cursor(CursorIcon::Crosshair)
+                })
+                .inner;
+            ctx.move_to_top(response.layer_id);
+
+            if let Some(pos) = response
+                .interact_pointer_pos()
+                .filter(|_| response.clicked())
+            {
+                if ctx.options(|o| o.supports_screenshots) {
+                    ctx.send_viewport_cmd(ViewportCommand::Screenshot);
+                    new_state = Some(Eyedropper::WaitingForScreenshot {
+                        pos,
+                        time: ctx.input(|i| i.time),
+                    });
+                } else {
+                    new_state = None;
+                }
+            }
+        }
+        Eyedropper::WaitingForScreenshot { pos, time } => {
+            let viewport_id = ctx.viewport_id();
+            let image = ctx.input(|i| {
+                i.events.iter().find_map(|event| match event {
+                    Event::Screenshot {
+                        viewport_id: id,
+                        image,
+                    } if *id == viewport_id => Some(image.clone()),
+                    _ => None,
+                })
+            });
+            if let Some(image) = image {
+                let pixel = pos * ctx.pixels_per_point();
+                let [width, height] = image.size;
+                let (x, y) = (pixel.x as usize, pixel.y as usize);
+                if x < width && y < height {
+                    picked = picked.or(Some(image[(x, y)]));
+                }
+                new_state = None;
+            } else if ctx.input(|i| i.time) - time > SCREENSHOT_TIMEOUT {
+                // The integration doesn't seem to take screenshots.
+                new_state = None;
+            } else {
+                ctx.request_repaint();
+            }
+        }
+    }
+
+    if picked.is_some() {
+        new_state = None;
+    }
+    ctx.data_mut(|d| match new_state {
+        Some(state) => d.insert_temp(id, state),
+        None => d.remove::<Eyedropper>(id),
+    });
+
+    picked
+}
+
 /// Shows a button with the given color.
 /// If the user clicks the button, a full color picker is shown.
 pub fn color_edit_button_srgba(ui: &mut Ui, srgba: &mut Color32, alpha: Alpha) -> Response {
@@ -571,3 +891,677 @@ fn color_cache_set(ctx: &Context, rgba: impl Into<Rgba>, hsva: Hsva) {
 fn use_color_cache<R>(ctx: &Context, f: impl FnOnce(&mut FixedCache<Rgba, Hsva>) -> R) -> R {
     ctx.data_mut(|d| f(d.get_temp_mut_or_default(Id::NULL)))
 }
+
+// ----------------------------------------------------------------------------
+
+/// Sample a gradient, given as a list of `(position, color)` stops, at `t`.
+///
+/// The stops need not be sorted. Colors are interpolated in gamma space,
+/// and `t` outside the range of the stops is clamped to the closest stop.
+/// An empty list of stops yields [`Color32::TRANSPARENT`].
+pub fn gradient_color_at(stops: &[(f32, Color32)], t: f32) -> Color32 {
+    let mut below: Option<(f32, Color32)> = None;
+    let mut above: Option<(f32, Color32)> = None;
+    for &(pos, color) in stops {
+        if pos <= t && below.map_or(true, |(b, _)| b <= pos) {
+            below = Some((pos, color));
+        }
+        if t <= pos && above.map_or(true, |(a, _)| pos < a) {
+            above = Some((pos, color));
+        }
+    }
+
+    match (below, above) {
+        (Some((b, below)), Some((a, above))) => {
+            if a <= b {
+                below
+            } else {
+                below.lerp_to_gamma(above, (t - b) / (a - b))
+            }
+        }
+        (Some((_, color)), None) | (None, Some((_, color))) => color,
+        (None, None) => Color32::TRANSPARENT,
+    }
+}
+
+/// Paint a horizontal gradient strip of the given stops into `rect`, with background checkers
+/// to show transparency.
+pub fn show_gradient_at(painter: &Painter, stops: &[(f32, Color32)], rect: Rect) {
+    background_checkers(painter, rect);
+    painter.add(Shape::mesh(gradient_mesh(stops, rect)));
+}
+
+/// A [`Mesh`] filling `rect` with a horizontal gradient of the given stops.
+///
+/// Use this to paint the gradient from a [`GradientEditor`] yourself.
+pub fn gradient_mesh(stops: &[(f32, Color32)], rect: Rect) -> Mesh {
+    #![allow(clippy::identity_op)]
+
+    let mut mesh = Mesh::default();
+    for i in 0..=N {
+        let t = i as f32 / (N as f32);
+        let color = gradient_color_at(stops, t);
+        let x = lerp(rect.left()..=rect.right(), t);
+        mesh.colored_vertex(pos2(x, rect.top()), color);
+        mesh.colored_vertex(pos2(x, rect.bottom()), color);
+        if i < N {
+            mesh.add_triangle(2 * i + 0, 2 * i + 1, 2 * i + 2);
+            mesh.add_triangle(2 * i + 1, 2 * i + 2, 2 * i + 3);
+        }
+    }
+    mesh
+}
+
+/// Edit a gradient, given as a list of `(position, color)` stops with positions in `0..=1`.
+///
+/// Shorthand for `ui.add(GradientEditor::new(stops))`, see [`GradientEditor`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui::Color32;
+/// let mut stops = vec![(0.0, Color32::BLACK), (1.0, Color32::WHITE)];
+/// egui::widgets::color_picker::gradient_edit(ui, &mut stops);
+/// # });
+/// ```
+pub fn gradient_edit(ui: &mut Ui, stops: &mut Vec<(f32, Color32)>) -> Response {
+    ui.add(GradientEditor::new(stops))
+}
+
+/// Edit a gradient, given as a list of `(position, color)` stops with positions in `0..=1`,
+/// e.g. a color ramp for a heatmap.
+///
+/// * Drag a stop handle to move it.
+/// * Click a stop handle to edit its color.
+/// * Double-click on the gradient strip to add a new stop (see [`Self::add_on_double_click`]).
+/// * Right-click a stop handle to remove it.
+///
+/// The stops are kept sorted by position.
+/// Use [`gradient_color_at`] to sample the gradient, or [`gradient_mesh`] to paint it.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Color32, GradientEditor};
+/// let mut stops = vec![(0.0, Color32::BLUE), (0.5, Color32::YELLOW), (1.0, Color32::RED)];
+/// ui.add(GradientEditor::new(&mut stops).desired_width(200.0));
+///
+/// let (rect, _) = ui.allocate_exact_size(egui::vec2(200.0, 20.0), egui::Sense::hover());
+/// let mesh = egui::widgets::color_picker::gradient_mesh(&stops, rect);
+/// ui.painter().add(mesh);
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct GradientEditor<'a> {
+    stops: &'a mut Vec<(f32, Color32)>,
+    desired_width: Option<f32>,
+    alpha: Alpha,
+    add_on_double_click: bool,
+}
+
+impl<'a> GradientEditor<'a> {
+    pub fn new(stops: &'a mut Vec<(f32, Color32)>) -> Self {
+        Self {
+            stops,
+            desired_width: None,
+            alpha: Alpha::OnlyBlend,
+            add_on_double_click: true,
+        }
+    }
+
+    /// Width of the gradient strip.
+    ///
+    /// Default: [`crate::style::Spacing::slider_width`].
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    /// What alpha options to show in the color picker of a stop.
+    ///
+    /// Default: [`Alpha::OnlyBlend`].
+    #[inline]
+    pub fn alpha(mut self, alpha: Alpha) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Add a stop with a double-click on the gradient strip, or with a single click if `false`.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn add_on_double_click(mut self, add_on_double_click: bool) -> Self {
+        self.add_on_double_click = add_on_double_click;
+        self
+    }
+}
+
+impl<'a> Widget for GradientEditor<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            stops,
+            desired_width,
+            alpha,
+            add_on_double_click,
+        } = self;
+
+        let strip_height = ui.spacing().interact_size.y;
+        let handle_height = 0.75 * strip_height;
+        let width = desired_width.unwrap_or(ui.spacing().slider_width);
+        let desired_size = vec2(width, strip_height + handle_height);
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+        let id = response.id;
+        let popup_id = id.with("popup");
+
+        let strip_rect = Rect::from_min_size(rect.min, vec2(rect.width(), strip_height));
+        let handle_y = strip_rect.bottom()..=rect.bottom();
+        let handle_half_width = handle_height / 2.0;
+        let x_range = strip_rect.x_range();
+
+        let mut changed = false;
+
+        let add_clicked = if add_on_double_click {
+            response.double_clicked()
+        } else {
+            response.clicked()
+        };
+        if add_clicked {
+            if let Some(pointer_pos) = response.interact_pointer_pos() {
+                if strip_rect.contains(pointer_pos) {
+                    let t = remap_clamp(pointer_pos.x, x_range, 0.0..=1.0);
+                    stops.push((t, gradient_color_at(stops, t)));
+                    changed = true;
+                }
+            }
+        }
+
+        let mut any_dragged = false;
+        let mut open_index = None;
+        let mut remove_index = None;
+
+        let mut handles = Vec::with_capacity(stops.len());
+        for (i, (t, _)) in stops.iter_mut().enumerate() {
+            let x = lerp(x_range, *t);
+            let handle_rect = Rect::from_x_y_ranges(
+                x - handle_half_width..=x + handle_half_width,
+                handle_y.clone(),
+            );
+            let handle_response = ui.interact(handle_rect, id.with(i), Sense::click_and_drag());
+
+            if handle_response.dragged() {
+                any_dragged = true;
+                if let Some(pointer_pos) = handle_response.interact_pointer_pos() {
+                    let new_t = remap_clamp(pointer_pos.x, x_range, 0.0..=1.0);
+                    if new_t != *t {
+                        *t = new_t;
+                        changed = true;
+                    }
+                }
+            }
+            if handle_response.clicked() {
+                open_index = Some(i);
+            }
+            if handle_response.secondary_clicked() {
+                remove_index = Some(i);
+            }
+
+            handles.push(handle_response);
+        }
+
+        if let Some(i) = remove_index {
+            if 1 < stops.len() {
+                stops.remove(i);
+                handles.remove(i);
+                changed = true;
+                ui.memory_mut(|mem| {
+                    if mem.is_popup_open(popup_id) {
+                        mem.close_popup();
+                    }
+                });
+            }
+        }
+
+        if let Some(i) = open_index {
+            let was_open_for = ui
+                .memory(|mem| mem.is_popup_open(popup_id))
+                .then(|| ui.data(|d| d.get_temp::<usize>(popup_id)))
+                .flatten();
+            if was_open_for == Some(i) {
+                ui.memory_mut(|mem| mem.close_popup());
+            } else {
+                ui.data_mut(|d| d.insert_temp(popup_id, i));
+                ui.memory_mut(|mem| mem.open_popup(popup_id));
+            }
+        }
+
+        let popup_index = ui
+            .memory(|mem| mem.is_popup_open(popup_id))
+            .then(|| ui.data(|d| d.get_temp::<usize>(popup_id)))
+            .flatten()
+            .filter(|&i| i < stops.len());
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            show_gradient_at(ui.painter(), stops, strip_rect);
+            ui.painter().rect_stroke(strip_rect, 0.0, visuals.bg_stroke);
+
+            for (i, (handle_response, &(t, color))) in handles.iter().zip(stops.iter()).enumerate()
+            {
+                let visuals = if popup_index == Some(i) {
+                    &ui.visuals().widgets.open
+                } else {
+                    ui.style().interact(handle_response)
+                };
+                let x = lerp(x_range, t);
+                ui.painter().add(Shape::convex_polygon(
+                    vec![
+                        pos2(x, *handle_y.start()),                   // tip
+                        pos2(x + handle_half_width, *handle_y.end()), // right bottom
+                        pos2(x - handle_half_width, *handle_y.end()), // left bottom
+                    ],
+                    color,
+                    Stroke::new(visuals.fg_stroke.width, contrast_color(color)),
+                ));
+            }
+        }
+
+        if let Some(i) = popup_index {
+            let handle_rect = handles[i].rect;
+            let area_response = Area::new(popup_id)
+                .kind(UiKind::Picker)
+                .order(Order::Foreground)
+                .fixed_pos(handle_rect.center_bottom())
+                .show(ui.ctx(), |ui| {
+                    ui.spacing_mut().slider_width = 275.0;
+                    Frame::popup(ui.style()).show(ui, |ui| {
+                        changed |= color_picker_color32(ui, &mut stops[i].1, alpha);
+                    });
+                })
+                .response;
+
+            if open_index.is_none()
+                && (ui.input(|i| i.key_pressed(Key::Escape)) || area_response.clicked_elsewhere())
+            {
+                ui.memory_mut(|mem| mem.close_popup());
+            }
+        }
+
+        if !any_dragged {
+            // Only sort when nothing is being dragged, so that the stop under the pointer keeps its id.
+            let popup_stop = popup_index.map(|i| stops[i]);
+            stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+            if let Some(popup_stop) = popup_stop {
+                // Make sure the popup stays with the same stop:
+                if let Some(i) = stops.iter().position(|stop| *stop == popup_stop) {
+                    ui.data_mut(|d| d.insert_temp(popup_id, i));
+                }
+            }
+        }
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The colors most recently picked in a [`ColorPalette`] or with a [`color_edit_button_hsva`], newest first.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct RecentColors(Vec<Color32>);
+
+/// How many recent colors are remembered.
+const MAX_RECENT_COLORS: usize = 16;
+
+fn recent_colors_id() -> Id {
+    Id::new("egui::color_picker::recent_colors")
+}
+
+/// A grid of color swatches to pick a color from.
+///
+/// * Click a swatch to pick its color.
+/// * Drag a swatch onto a color edit button (e.g. [`color_edit_button_srgba`]) to give it that color.
+///
+/// Below the swatches is a row of the most recently picked colors, which is shared by all palettes
+/// and remembered in [`Memory`].
+///
+/// The returned [`Response`] is marked as changed when a color is picked.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Color32, ColorPalette};
+/// # let mut color = Color32::RED;
+/// let colors = [Color32::RED, Color32::GREEN, Color32::BLUE, Color32::YELLOW];
+/// ui.add(ColorPalette::new(&mut color, colors).columns(4));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ColorPalette<'a> {
+    color: &'a mut Color32,
+    colors: Vec<Color32>,
+    columns: usize,
+    swatch_size: Option<f32>,
+    show_recent: bool,
+}
+
+impl<'a> ColorPalette<'a> {
+    pub fn new(color: &'a mut Color32, colors: impl IntoIterator<Item = Color32>) -> Self {
+        Self {
+            color,
+            colors: colors.into_iter().collect(),
+            columns: 8,
+            swatch_size: None,
+            show_recent: true,
+        }
+    }
+
+    /// How many swatches to show per row. Default: `8`.
+    #[inline]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.at_least(1);
+        self
+    }
+
+    /// Width and height of each swatch.
+    ///
+    /// Default: [`crate::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn swatch_size(mut self, swatch_size: f32) -> Self {
+        self.swatch_size = Some(swatch_size);
+        self
+    }
+
+    /// Show a row of the most recently picked colors. Default: `true`.
+    #[inline]
+    pub fn show_recent(mut self, show_recent: bool) -> Self {
+        self.show_recent = show_recent;
+        self
+    }
+
+    /// The most recently picked colors, newest first.
+    pub fn recent_colors(ctx: &Context) -> Vec<Color32> {
+        ctx.data_mut(|d| d.get_persisted::<RecentColors>(recent_colors_id()))
+            .unwrap_or_default()
+            .0
+    }
+
+    /// Add a color to the recent colors, e.g. when it was picked with a widget of your own.
+    ///
+    /// The color edit buttons (e.g. [`color_edit_button_srgba`]) do this by themselves.
+    pub fn add_recent_color(ctx: &Context, color: Color32) {
+        ctx.data_mut(|d| {
+            let recent = d.get_persisted_mut_or_default::<RecentColors>(recent_colors_id());
+            recent.0.retain(|&c| c != color);
+            recent.0.insert(0, color);
+            recent.0.truncate(MAX_RECENT_COLORS);
+        });
+    }
+}
+
+impl<'a> Widget for ColorPalette<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            color,
+            colors,
+            columns,
+            swatch_size,
+            show_recent,
+        } = self;
+
+        let swatch_size = swatch_size.unwrap_or(ui.spacing().interact_size.y);
+        let gap = ui.spacing().item_spacing.y;
+
+        let mut recent = if show_recent {
+            Self::recent_colors(ui.ctx())
+        } else {
+            vec![]
+        };
+        recent.truncate(columns);
+
+        let palette_rows = colors.len().div_ceil(columns);
+        let recent_rows = usize::from(!recent.is_empty());
+        let row_count = palette_rows + recent_rows;
+        let width = columns.min(colors.len().max(recent.len())) as f32 * (swatch_size + gap) - gap;
+        let height =
+            row_count as f32 * (swatch_size + gap) - gap + if recent_rows > 0 { gap } else { 0.0 }; // extra gap above the recent colors
+
+        let (rect, mut response) = ui.allocate_exact_size(
+            vec2(width.at_least(0.0), height.at_least(0.0)),
+            Sense::hover(),
+        );
+        let id = response.id;
+        response.widget_info(|| WidgetInfo::new(WidgetType::Other));
+
+        let swatch_rect = |row: usize, column: usize| {
+            let mut min = rect.min + (swatch_size + gap) * vec2(column as f32, row as f32);
+            if palette_rows <= row {
+                min.y += gap;
+            }
+            Rect::from_min_size(min, Vec2::splat(swatch_size))
+        };
+
+        let swatches = colors
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (swatch_rect(i / columns, i % columns), c))
+            .chain(
+                recent
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &c)| (swatch_rect(palette_rows, i), c)),
+            );
+
+        let mut picked = None;
+        for (i, (swatch_rect, swatch_color)) in swatches.enumerate() {
+            let swatch_response = ui.interact(swatch_rect, id.with(i), Sense::click_and_drag());
+            swatch_response.dnd_set_drag_payload(swatch_color);
+            if swatch_response.clicked() {
+                picked = Some(swatch_color);
+            }
+
+            if ui.is_rect_visible(swatch_rect) {
+                let visuals = ui.style().interact(&swatch_response);
+                let swatch_rect = swatch_rect.expand(visuals.expansion);
+                show_color_at(ui.painter(), swatch_color, swatch_rect);
+                if swatch_color == *color {
+                    ui.painter()
+                        .rect_stroke(swatch_rect, 0.0, ui.visuals().selection.stroke);
+                } else if swatch_response.hovered() {
+                    ui.painter()
+                        .rect_stroke(swatch_rect, 0.0, visuals.fg_stroke);
+                }
+            }
+
+            if swatch_response.dragged() {
+                // Show the color under the pointer while it is dragged:
+                if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
+                    let painter = ui
+                        .ctx()
+                        .layer_painter(LayerId::new(Order::Tooltip, id.with("drag")));
+                    let rect = Rect::from_center_size(pointer_pos, Vec2::splat(swatch_size));
+                    show_color_at(&painter, swatch_color, rect);
+                    painter.rect_stroke(rect, 0.0, ui.visuals().window_stroke);
+                }
+            }
+        }
+
+        if let Some(picked) = picked {
+            if *color != picked {
+                *color = picked;
+                response.mark_changed();
+            }
+            Self::add_recent_color(ui.ctx(), picked);
+        }
+
+        response
+    }
+}
+
+#[test]
+fn test_color_edit_button_records_recent_colors() {
+    let harness = TestHarness::new();
+    let mut color = Color32::RED;
+    let run = |events: Vec<Event>, color: &mut Color32| {
+        harness.run_ui(events, |ui| {
+            color_edit_button_srgba(ui, color, Alpha::Opaque).rect
+        })
+    };
+
+    let pos = run(vec![], &mut color).center();
+    run(TestHarness::click_events(pos), &mut color);
+
+    // As if the user picked a color in the popup:
+    color = Color32::GREEN;
+    run(vec![], &mut color);
+    assert!(ColorPalette::recent_colors(&harness.ctx).is_empty());
+
+    run(vec![TestHarness::key_event(Key::Escape)], &mut color);
+    assert_eq!(ColorPalette::recent_colors(&harness.ctx), [Color32::GREEN]);
+}
+
+#[test]
+fn test_gradient_color_at() {
+    assert_eq!(gradient_color_at(&[], 0.5), Color32::TRANSPARENT);
+
+    // The stops need not be sorted:
+    let stops = [
+        (1.0, Color32::WHITE),
+        (0.0, Color32::BLACK),
+        (0.5, Color32::RED),
+    ];
+    assert_eq!(gradient_color_at(&stops, 0.0), Color32::BLACK);
+    assert_eq!(gradient_color_at(&stops, 0.5), Color32::RED);
+    assert_eq!(gradient_color_at(&stops, 1.0), Color32::WHITE);
+    assert_eq!(
+        gradient_color_at(&stops, 0.25),
+        Color32::BLACK.lerp_to_gamma(Color32::RED, 0.5)
+    );
+
+    // Clamped to the closest stop:
+    assert_eq!(gradient_color_at(&stops, -1.0), Color32::BLACK);
+    assert_eq!(gradient_color_at(&stops, 2.0), Color32::WHITE);
+    assert_eq!(
+        gradient_color_at(&[(0.3, Color32::BLUE)], 0.9),
+        Color32::BLUE
+    );
+}
+
+#[test]
+fn test_gradient_mesh() {
+    let stops = [(0.0, Color32::BLACK), (1.0, Color32::WHITE)];
+    let rect = Rect::from_min_size(pos2(10.0, 20.0), vec2(100.0, 10.0));
+    let mesh = gradient_mesh(&stops, rect);
+    assert_eq!(mesh.vertices.len(), 2 * (N as usize + 1));
+    assert_eq!(mesh.indices.len(), 6 * N as usize);
+    assert_eq!(mesh.calc_bounds(), rect);
+
+    let first = &mesh.vertices[0];
+    let last = &mesh.vertices[mesh.vertices.len() - 1];
+    assert_eq!((first.pos, first.color), (rect.left_top(), Color32::BLACK));
+    assert_eq!(
+        (last.pos, last.color),
+        (rect.right_bottom(), Color32::WHITE)
+    );
+}
+
+#[test]
+fn test_gradient_editor_adds_and_removes_stops() {
+    let harness = TestHarness::new();
+    let mut stops = vec![(0.0, Color32::BLACK), (1.0, Color32::WHITE)];
+    let run = |events: Vec<Event>, stops: &mut Vec<(f32, Color32)>, add_on_double_click| {
+        harness.run_ui(events, |ui| {
+            let editor = GradientEditor::new(stops)
+                .desired_width(100.0)
+                .add_on_double_click(add_on_double_click);
+            ui.add(editor).rect
+        })
+    };
+    let rect = run(vec![], &mut stops, true);
+    let strip_height = harness.ctx.style().spacing.interact_size.y;
+    let on_strip = pos2(rect.left() + 25.0, rect.top() + strip_height / 2.0);
+
+    // A single click only adds a stop when asked for:
+    run(TestHarness::click_events(on_strip), &mut stops, true);
+    assert_eq!(stops.len(), 2);
+    run(TestHarness::click_events(on_strip), &mut stops, false);
+    let expected_color = Color32::BLACK.lerp_to_gamma(Color32::WHITE, 0.25);
+    assert_eq!(
+        stops,
+        [
+            (0.0, Color32::BLACK),
+            (0.25, expected_color),
+            (1.0, Color32::WHITE)
+        ]
+    );
+
+    // Secondary click on its handle removes it again:
+    let on_handle = pos2(on_strip.x, rect.bottom() - 1.0);
+    run(vec![Event::PointerMoved(on_handle)], &mut stops, true);
+    let secondary_click = vec![
+        TestHarness::button_event(on_handle, PointerButton::Secondary, true),
+        TestHarness::button_event(on_handle, PointerButton::Secondary, false),
+    ];
+    run(secondary_click, &mut stops, true);
+    assert_eq!(stops, [(0.0, Color32::BLACK), (1.0, Color32::WHITE)]);
+}
+
+#[test]
+fn test_eyedropper() {
+    let id = Id::new("eyedropper");
+    let run = |harness: &TestHarness, time: f64, events: Vec<Event>| {
+        let input = RawInput {
+            time: Some(time),
+            events,
+            ..Default::default()
+        };
+        let (picked, output) = harness.run_input(input, |ctx| eyedropper_ui(ctx, id));
+        let screenshot = output.viewport_output[&ViewportId::ROOT]
+            .commands
+            .contains(&ViewportCommand::Screenshot);
+        (picked, screenshot)
+    };
+    let is_picking =
+        |harness: &TestHarness| harness.ctx.data(|d| d.get_temp::<Eyedropper>(id).is_some());
+    let start_picking = |harness: &TestHarness| {
+        harness
+            .ctx
+            .data_mut(|d| d.insert_temp(id, Eyedropper::Picking));
+        run(harness, 0.0, vec![]);
+        // Hover the eyedropper before clicking it, like a real pointer would:
+        let pos = pos2(1.0, 1.0);
+        run(harness, 0.0, vec![Event::PointerMoved(pos)]);
+        run(harness, 0.0, TestHarness::click_events(pos))
+    };
+
+    // A screenshot is sampled where the user clicked:
+    let harness = TestHarness::new();
+    assert_eq!(start_picking(&harness), (None, true));
+    let image = ColorImage::new([4, 4], Color32::GOLD);
+    let screenshot = Event::Screenshot {
+        viewport_id: ViewportId::ROOT,
+        image: image.into(),
+    };
+    assert_eq!(run(&harness, 0.1, vec![screenshot]).0, Some(Color32::GOLD));
+    assert!(!is_picking(&harness));
+
+    // Give up if the screenshot never arrives:
+    let harness = TestHarness::new();
+    start_picking(&harness);
+    run(&harness, 1.0, vec![]);
+    assert!(is_picking(&harness));
+    run(&harness, 1.0 + SCREENSHOT_TIMEOUT, vec![]);
+    assert!(!is_picking(&harness));
+
+    // Escape works while waiting too:
+    let harness = TestHarness::new();
+    start_picking(&harness);
+    run(&harness, 0.1, vec![TestHarness::key_event(Key::Escape)]);
+    assert!(!is_picking(&harness));
+
+    // Don't ask for screenshots that will never come:
+    let harness = TestHarness::new();
+    harness.ctx.options_mut(|o| o.supports_screenshots = false);
+    assert_eq!(start_picking(&harness), (None, false));
+    assert!(!is_picking(&harness));
+}
@@ -0,0 +1,315 @@
+use std::f32::consts::TAU;
+
+use crate::*;
+
+/// How an angle is shown to the user by [`DragAngle`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum AngleUnit {
+    /// 360° per turn.
+    #[default]
+    Degrees,
+
+    /// 2π radians per turn.
+    Radians,
+
+    /// Fractions of one turn, `τ`.
+    Turns,
+}
+
+impl AngleUnit {
+    pub const ALL: [Self; 3] = [Self::Degrees, Self::Radians, Self::Turns];
+
+    /// How many of this unit there are in one radian.
+    fn per_radian(self) -> f32 {
+        match self {
+            Self::Degrees => 360.0 / TAU,
+            Self::Radians => 1.0,
+            Self::Turns => 1.0 / TAU,
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::Degrees => "°",
+            Self::Radians => " rad",
+            Self::Turns => "τ",
+        }
+    }
+
+    fn speed(self) -> f64 {
+        match self {
+            Self::Degrees => 1.0,
+            Self::Radians | Self::Turns => 0.01,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Degrees => "Degrees",
+            Self::Radians => "Radians",
+            Self::Turns => "Turns",
+        }
+    }
+}
+
+/// Edit an angle, given in radians.
+///
+/// The user can switch between showing the angle in degrees, radians and turns
+/// by right-clicking the value.
+/// Next to the value is a button that opens a circular angle pad.
+///
+/// See also [`Ui::drag_angle`] and [`Ui::drag_angle_tau`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut radians: f32 = 0.0;
+/// ui.add(egui::DragAngle::new(&mut radians).wrap(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct DragAngle<'a> {
+    radians: &'a mut f32,
+    unit: AngleUnit,
+    wrap: bool,
+    angle_pad: bool,
+}
+
+impl<'a> DragAngle<'a> {
+    pub fn new(radians: &'a mut f32) -> Self {
+        Self {
+            radians,
+            unit: AngleUnit::Degrees,
+            wrap: false,
+            angle_pad: true,
+        }
+    }
+
+    /// How to first show the angle. The user can change it by right-clicking the value.
+    ///
+    /// Default: [`AngleUnit::Degrees`].
+    #[inline]
+    pub fn unit(mut self, unit: AngleUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Wrap the angle to one turn, i.e. `[0, 2π)`.
+    ///
+    /// If `false` the user may select, for instance 720° = 2𝞃 = 4π.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Show a button that opens a circular pad for picking the angle.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn angle_pad(mut self, angle_pad: bool) -> Self {
+        self.angle_pad = angle_pad;
+        self
+    }
+}
+
+impl<'a> Widget for DragAngle<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            radians,
+            unit,
+            wrap,
+            angle_pad,
+        } = self;
+
+        let id = ui.next_auto_id().with("drag_angle");
+        let unit_id = id.with("unit");
+        let mut unit = ui.data(|data| data.get_temp(unit_id)).unwrap_or(unit);
+
+        let inner = ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = ui.spacing().item_spacing.x.min(2.0);
+
+            let per_radian = unit.per_radian();
+            let mut value = *radians * per_radian;
+            let mut response = ui.add(
+                DragValue::new(&mut value)
+                    .speed(unit.speed())
+                    .suffix(unit.suffix()),
+            );
+
+            // only touch `*radians` if we actually changed the value
+            if value != *radians * per_radian {
+                *radians = value / per_radian;
+                response.changed = true;
+            }
+
+            if ui.style().explanation_tooltips && unit == AngleUnit::Turns {
+                response =
+                    response.on_hover_text("1τ = one turn, 0.5τ = half a turn, etc. 0.25τ = 90°");
+            }
+
+            response.context_menu(|ui| {
+                for option in AngleUnit::ALL {
+                    if ui.radio_value(&mut unit, option, option.name()).clicked() {
+                        ui.close_menu();
+                    }
+                }
+            });
+
+            if angle_pad {
+                let popup_id = id.with("angle_pad");
+                let size = Vec2::splat(ui.spacing().interact_size.y);
+                let (rect, button) = ui.allocate_exact_size(size, Sense::click());
+                if ui.is_rect_visible(rect) {
+                    let visuals = ui.style().interact(&button);
+                    paint_angle_indicator(ui.painter(), rect.shrink(1.0), *radians, visuals);
+                }
+                if button.clicked() {
+                    ui.memory_mut(|mem| mem.toggle_popup(popup_id));
+                }
+                // Keep the pad open while the user clicks around in it:
+                popup::popup_above_or_below_widget_with(
+                    ui,
+                    popup_id,
+                    &button,
+                    AboveOrBelow::Below,
+                    false,
+                    |ui| {
+                        if angle_pad_ui(ui, radians).changed() {
+                            response.changed = true;
+                        }
+                    },
+                );
+            }
+
+            if wrap {
+                let wrapped = radians.rem_euclid(TAU);
+                if wrapped != *radians {
+                    *radians = wrapped;
+                    response.changed = true;
+                }
+            }
+
+            response
+        });
+
+        ui.data_mut(|data| data.insert_temp(unit_id, unit));
+
+        let changed = inner.inner.changed;
+        let mut response = inner.inner.union(inner.response);
+        response.changed = changed;
+        response
+    }
+}
+
+fn paint_angle_indicator(
+    painter: &Painter,
+    rect: Rect,
+    radians: f32,
+    visuals: &style::WidgetVisuals,
+) {
+    let radius = 0.5 * rect.width().min(rect.height());
+    let center = rect.center();
+    painter.circle(center, radius, visuals.bg_fill, visuals.bg_stroke);
+    let tip = center + radius * Vec2::angled(radians);
+    painter.line_segment([center, tip], visuals.fg_stroke);
+}
+
+/// The angle closest to `radians` that points the same way as `picked`,
+/// so that picking on the pad keeps the number of whole turns.
+fn closest_angle(radians: f32, picked: f32) -> f32 {
+    let difference = (picked - radians + TAU / 2.0).rem_euclid(TAU) - TAU / 2.0;
+    radians + difference
+}
+
+/// A circle to pick an angle from.
+fn angle_pad_ui(ui: &mut Ui, radians: &mut f32) -> Response {
+    let size = Vec2::splat(ui.spacing().slider_width.at_least(64.0));
+    let (rect, mut response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+
+    if let Some(pointer) = response.interact_pointer_pos() {
+        let delta = pointer - rect.center();
+        if delta != Vec2::ZERO {
+            let picked = closest_angle(*radians, delta.angle());
+            if picked != *radians {
+                *radians = picked;
+                response.mark_changed();
+            }
+        }
+    }
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact(&response);
+        let radius = 0.5 * rect.width() - visuals.fg_stroke.width;
+        let center = rect.center();
+        let painter = ui.painter();
+        painter.circle(
+            center,
+            radius,
+            ui.visuals().extreme_bg_color,
+            visuals.bg_stroke,
+        );
+        for i in 0..8 {
+            let dir = Vec2::angled(i as f32 * TAU / 8.0);
+            let tick = if i % 2 == 0 { 0.15 } else { 0.08 };
+            painter.line_segment(
+                [center + radius * (1.0 - tick) * dir, center + radius * dir],
+                ui.visuals().widgets.noninteractive.fg_stroke,
+            );
+        }
+        let tip = center + radius * Vec2::angled(*radians);
+        painter.line_segment([center, tip], visuals.fg_stroke);
+        painter.circle_filled(
+            tip,
+            0.6 * ui.spacing().slider_rail_height,
+            visuals.fg_stroke.color,
+        );
+    }
+
+    response
+}
+
+#[test]
+fn test_closest_angle() {
+    use std::f32::consts::PI;
+
+    let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+    assert!(close(closest_angle(0.0, 0.5), 0.5));
+    // `Vec2::angle` is in (-π, π], but the angle keeps going the short way around:
+    assert!(close(closest_angle(0.75 * TAU, -0.5 * PI), 0.75 * TAU));
+    assert!(close(closest_angle(0.1, -0.1), -0.1));
+    // Whole turns are kept:
+    assert!(close(closest_angle(2.0 * TAU + 0.1, 0.2), 2.0 * TAU + 0.2));
+    assert!(close(closest_angle(-TAU, 0.5 * PI), -0.75 * TAU));
+}
+
+#[test]
+fn test_angle_pad_stays_open() {
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    let mut radians = 0.0;
+    let mut run = |events: Vec<Event>| {
+        // The pad button is the last thing in the row:
+        let button_rect = harness.run_ui(events, |ui| ui.add(DragAngle::new(&mut radians)).rect);
+        (button_rect, radians)
+    };
+    let mut click = |pos: Pos2| {
+        run(vec![Event::PointerMoved(pos)]);
+        run(TestHarness::click_events(pos))
+    };
+
+    let (rect, _) = click(Pos2::ZERO);
+    click(rect.right_center() - vec2(4.0, 0.0));
+    assert!(ctx.memory(|mem| mem.any_popup_open()));
+
+    // Clicking below the center of the pad points the angle down (+y):
+    let pad = ctx.memory(|mem| {
+        let layer_id = mem.areas().top_layer_id(Order::Foreground).unwrap();
+        mem.area_rect(layer_id.id).unwrap()
+    });
+    let (_, radians) = click(pad.center() + vec2(0.0, 0.25 * pad.height()));
+    assert!((radians - 0.25 * TAU).abs() < 1e-5, "{radians}");
+    assert!(ctx.memory(|mem| mem.any_popup_open()));
+}
@@ -15,16 +15,102 @@ type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
 /// for the borrow checker.
 type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
 
-fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
-    (get_set_value)(None)
+/// Like [`GetSetValue`], but for integers, which can't all be represented exactly by an `f64`.
+pub(crate) type GetSetInteger<'a> = Box<dyn 'a + FnMut(Option<i128>) -> i128>;
+
+/// How a value widget reads and writes its value.
+pub(crate) enum ValueAccess<'a> {
+    Float(GetSetValue<'a>),
+
+    /// Edited in integer space, so that e.g. `i64` and `u64` values above 2^53 are not corrupted.
+    ///
+    /// The integer is a mantissa in units of `10^-decimals`, to also support exact decimal types.
+    Integer {
+        get_set: GetSetInteger<'a>,
+        decimals: u32,
+    },
+}
+
+impl<'a> ValueAccess<'a> {
+    pub(crate) fn new<Num: emath::Numeric>(value: &'a mut Num) -> Self {
+        if value.to_i128().is_some() {
+            Self::Integer {
+                get_set: Box::new(move |v: Option<i128>| {
+                    if let Some(v) = v {
+                        *value = Num::from_i128(v);
+                    }
+                    value.to_i128().unwrap_or_default()
+                }),
+                decimals: Num::FIXED_DECIMALS,
+            }
+        } else {
+            Self::Float(Box::new(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = Num::from_f64(v);
+                }
+                value.to_f64()
+            }))
+        }
+    }
+
+    /// Number of decimals of the integer mantissa (zero for floats and plain integers).
+    pub(crate) fn decimals(&self) -> u32 {
+        match self {
+            Self::Float(_) => 0,
+            Self::Integer { decimals, .. } => *decimals,
+        }
+    }
+
+    /// How much bigger the integer mantissa is than the value it represents.
+    pub(crate) fn scale(&self) -> f64 {
+        emath::fixed_point::pow10_i128(self.decimals()).map_or(f64::INFINITY, |scale| scale as f64)
+    }
 }
 
-fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
-    (get_set_value)(Some(value));
+pub(crate) fn get(access: &mut ValueAccess<'_>) -> f64 {
+    let scale = access.scale();
+    match access {
+        ValueAccess::Float(get_set_value) => (get_set_value)(None),
+        ValueAccess::Integer { get_set, .. } => (get_set)(None) as f64 / scale,
+    }
+}
+
+pub(crate) fn set(access: &mut ValueAccess<'_>, value: f64) {
+    let scale = access.scale();
+    match access {
+        ValueAccess::Float(get_set_value) => {
+            (get_set_value)(Some(value));
+        }
+        ValueAccess::Integer { get_set, .. } => {
+            (get_set)(Some(float_to_integer((value * scale).round())));
+        }
+    }
+}
+
+/// The exact value (mantissa), if it is an integer or fixed-point number.
+pub(crate) fn get_integer(access: &mut ValueAccess<'_>) -> Option<i128> {
+    match access {
+        ValueAccess::Float(_) => None,
+        ValueAccess::Integer { get_set, .. } => Some((get_set)(None)),
+    }
+}
+
+pub(crate) fn set_integer(access: &mut ValueAccess<'_>, value: i128) {
+    match access {
+        ValueAccess::Float(get_set_value) => {
+            (get_set_value)(Some(value as f64));
+        }
+        ValueAccess::Integer { get_set, .. } => {
+            (get_set)(Some(value));
+        }
+    }
 }
 
 /// A numeric value that you can change by dragging the number. More compact than a [`Slider`].
 ///
+/// Integers (up to `i128`) are edited exactly, even above 2^53.
+/// The exception is `u128` values above [`i128::MAX`], which are edited via `f64`.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// # let mut my_f32: f32 = 0.0;
@@ -33,26 +119,29 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct DragValue<'a> {
-    get_set_value: GetSetValue<'a>,
+    access: ValueAccess<'a>,
     speed: f64,
     prefix: String,
     suffix: String,
     clamp_range: RangeInclusive<f64>,
+
+    /// The exact clamp range, if it was given in integers (or fixed-point numbers),
+    /// and the number of decimals of those.
+    integer_clamp_range: Option<(RangeInclusive<i128>, u32)>,
     min_decimals: usize,
     max_decimals: Option<usize>,
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
     update_while_editing: bool,
+    double_click_to_edit: bool,
 }
 
 impl<'a> DragValue<'a> {
+    /// Integer values are edited in integer space,
+    /// so even huge `i64`, `u64` and `i128` values are kept exact.
+    /// The same goes for fixed-point types like `emath::Decimal` (behind the `decimal` feature).
     pub fn new<Num: emath::Numeric>(value: &'a mut Num) -> Self {
-        let slf = Self::from_get_set(move |v: Option<f64>| {
-            if let Some(v) = v {
-                *value = Num::from_f64(v);
-            }
-            value.to_f64()
-        });
+        let slf = Self::from_access(ValueAccess::new(value));
 
         if Num::INTEGRAL {
             slf.max_decimals(0)
@@ -64,17 +153,23 @@ impl<'a> DragValue<'a> {
     }
 
     pub fn from_get_set(get_set_value: impl 'a + FnMut(Option<f64>) -> f64) -> Self {
+        Self::from_access(ValueAccess::Float(Box::new(get_set_value)))
+    }
+
+    pub(crate) fn from_access(access: ValueAccess<'a>) -> Self {
         Self {
-            get_set_value: Box::new(get_set_value),
+            access,
             speed: 1.0,
             prefix: Default::default(),
             suffix: Default::default(),
             clamp_range: f64::NEG_INFINITY..=f64::INFINITY,
+            integer_clamp_range: None,
             min_decimals: 0,
             max_decimals: None,
             custom_formatter: None,
             custom_parser: None,
             update_while_editing: true,
+            double_click_to_edit: false,
         }
     }
 
@@ -91,6 +186,27 @@ impl<'a> DragValue<'a> {
     #[inline]
     pub fn clamp_range<Num: emath::Numeric>(mut self, clamp_range: RangeInclusive<Num>) -> Self {
         self.clamp_range = clamp_range.start().to_f64()..=clamp_range.end().to_f64();
+        self.integer_clamp_range = clamp_range
+            .start()
+            .to_i128()
+            .zip(clamp_range.end().to_i128())
+            .map(|(start, end)| (start..=end, Num::FIXED_DECIMALS));
+        self
+    }
+
+    /// Like [`Self::clamp_range`], but exact for huge integers.
+    ///
+    /// The range is in units of `10^-decimals`.
+    #[inline]
+    pub(crate) fn integer_clamp_range(
+        mut self,
+        clamp_range: RangeInclusive<i128>,
+        decimals: u32,
+    ) -> Self {
+        let scale =
+            emath::fixed_point::pow10_i128(decimals).map_or(f64::INFINITY, |scale| scale as f64);
+        self.clamp_range = *clamp_range.start() as f64 / scale..=*clamp_range.end() as f64 / scale;
+        self.integer_clamp_range = Some((clamp_range, decimals));
         self
     }
 
@@ -345,6 +461,40 @@ impl<'a> DragValue<'a> {
         .custom_parser(|s| i64::from_str_radix(s, 16).map(|n| n as f64).ok())
     }
 
+    /// Show a `0.0..=1.0` fraction as a percentage, e.g. `0.25` as `25%`.
+    ///
+    /// The user can type the value with or without the `%` sign.
+    /// The value itself is not clamped: use [`Self::clamp_range`] for that.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut opacity: f32 = 0.5;
+    /// ui.add(egui::DragValue::new(&mut opacity).percent().clamp_range(0.0..=1.0));
+    /// # });
+    /// ```
+    pub fn percent(self) -> Self {
+        self.speed(0.005)
+            .custom_formatter(|n, decimals| {
+                // Two of the decimals are taken up by the multiplication:
+                let decimals =
+                    decimals.start().saturating_sub(2)..=decimals.end().saturating_sub(2);
+                let percent = n * 100.0;
+                if percent == 0.0 {
+                    "0%".to_owned()
+                } else {
+                    format!(
+                        "{}%",
+                        emath::format_with_decimals_in_range(percent, decimals)
+                    )
+                }
+            })
+            .custom_parser(|s| {
+                let s = s.trim();
+                let s = s.strip_suffix('%').unwrap_or(s).trim();
+                s.parse::<f64>().ok().map(|percent| percent / 100.0)
+            })
+    }
+
     /// Update the value on each key press when text-editing the value.
     ///
     /// Default: `true`.
@@ -354,14 +504,27 @@ impl<'a> DragValue<'a> {
         self.update_while_editing = update;
         self
     }
+
+    /// Require a double-click (instead of a single click) to start editing the value as text.
+    ///
+    /// This is useful in dense editors, where a single click is easily
+    /// triggered by accident when the user tries to start a drag.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn double_click_to_edit(mut self, double_click_to_edit: bool) -> Self {
+        self.double_click_to_edit = double_click_to_edit;
+        self
+    }
 }
 
 impl<'a> Widget for DragValue<'a> {
     fn ui(self, ui: &mut Ui) -> Response {
         let Self {
-            mut get_set_value,
+            mut access,
             speed,
             clamp_range,
+            integer_clamp_range,
             prefix,
             suffix,
             min_decimals,
@@ -369,6 +532,7 @@ impl<'a> Widget for DragValue<'a> {
             custom_formatter,
             custom_parser,
             update_while_editing,
+            double_click_to_edit,
         } = self;
 
         let shift = ui.input(|i| i.modifiers.shift_only());
@@ -380,8 +544,9 @@ impl<'a> Widget for DragValue<'a> {
         // it is immediately rendered in edit mode, rather than being rendered
         // in button mode for just one frame. This is important for
         // screen readers.
+        let layer_id = ui.layer_id();
         let is_kb_editing = ui.memory_mut(|mem| {
-            mem.interested_in_focus(id);
+            mem.interested_in_focus_on_layer(id, layer_id);
             mem.has_focus(id)
         });
 
@@ -389,8 +554,23 @@ impl<'a> Widget for DragValue<'a> {
             ui.data_mut(|data| data.remove::<String>(id));
         }
 
-        let old_value = get(&mut get_set_value);
+        let old_value = get(&mut access);
         let mut value = old_value;
+
+        // Integers are kept exact, and not round-tripped through `f64`:
+        let old_integer = get_integer(&mut access);
+        let mut integer = old_integer;
+        let (decimals, scale) = (access.decimals(), access.scale());
+        let integer_clamp_range = match integer_clamp_range {
+            Some((range, range_decimals)) => {
+                rescale_integer(*range.start(), range_decimals, decimals, true)
+                    ..=rescale_integer(*range.end(), range_decimals, decimals, false)
+            }
+            None => {
+                float_to_integer((clamp_range.start() * scale).ceil())
+                    ..=float_to_integer((clamp_range.end() * scale).floor())
+            }
+        };
         let aim_rad = ui.input(|i| i.aim_radius() as f64);
 
         let auto_decimals = (aim_rad / speed.abs()).log10().ceil().clamp(0.0, 15.0) as usize;
@@ -439,21 +619,42 @@ impl<'a> Widget for DragValue<'a> {
             });
         }
 
-        if change != 0.0 {
-            value += speed * change;
-            value = emath::round_to_decimals(value, auto_decimals);
-        }
+        if let Some(old_integer) = old_integer {
+            let mut new_integer = if value == old_value {
+                old_integer
+            } else {
+                float_to_integer((value * scale).round())
+            };
+            if change != 0.0 {
+                new_integer = new_integer.saturating_add(integer_step(speed * change * scale));
+            }
 
-        value = clamp_to_range(value, clamp_range.clone());
-        if old_value != value {
-            set(&mut get_set_value, value);
-            ui.data_mut(|data| data.remove::<String>(id));
+            let new_integer = clamp_integer_to_range(new_integer, integer_clamp_range.clone());
+            if old_integer != new_integer {
+                set_integer(&mut access, new_integer);
+                ui.data_mut(|data| data.remove::<String>(id));
+            }
+            integer = Some(new_integer);
+            value = new_integer as f64 / scale;
+        } else {
+            if change != 0.0 {
+                value += speed * change;
+                value = emath::round_to_decimals(value, auto_decimals);
+            }
+
+            value = clamp_to_range(value, clamp_range.clone());
+            if old_value != value {
+                set(&mut access, value);
+                ui.data_mut(|data| data.remove::<String>(id));
+            }
         }
 
         let value_text = match custom_formatter {
             Some(custom_formatter) => custom_formatter(value, auto_decimals..=max_decimals),
             None => {
-                if value == 0.0 {
+                if let Some(integer) = integer {
+                    emath::fixed_point::format_fixed_point(integer, decimals)
+                } else if value == 0.0 {
                     "0".to_owned()
                 } else {
                     emath::format_with_decimals_in_range(value, auto_decimals..=max_decimals)
@@ -468,14 +669,13 @@ impl<'a> Widget for DragValue<'a> {
             if let Some(value_text) = value_text {
                 // We were editing the value as text last frame, but lost focus.
                 // Make sure we applied the last text value:
-                let parsed_value = match &custom_parser {
-                    Some(parser) => parser(&value_text),
-                    None => value_text.parse().ok(),
-                };
-                if let Some(parsed_value) = parsed_value {
-                    let parsed_value = clamp_to_range(parsed_value, clamp_range.clone());
-                    set(&mut get_set_value, parsed_value);
-                }
+                set_from_text(
+                    &mut access,
+                    &custom_parser,
+                    &clamp_range,
+                    &integer_clamp_range,
+                    &value_text,
+                );
             }
         }
 
@@ -505,14 +705,13 @@ impl<'a> Widget for DragValue<'a> {
                 response.lost_focus()
             };
             if update {
-                let parsed_value = match &custom_parser {
-                    Some(parser) => parser(&value_text),
-                    None => value_text.parse().ok(),
-                };
-                if let Some(parsed_value) = parsed_value {
-                    let parsed_value = clamp_to_range(parsed_value, clamp_range.clone());
-                    set(&mut get_set_value, parsed_value);
-                }
+                set_from_text(
+                    &mut access,
+                    &custom_parser,
+                    &clamp_range,
+                    &integer_clamp_range,
+                    &value_text,
+                );
             }
             ui.data_mut(|data| data.insert_temp(id, value_text));
             response
@@ -537,11 +736,18 @@ impl<'a> Widget for DragValue<'a> {
             let mut response = response.on_hover_cursor(cursor_icon);
 
             if ui.style().explanation_tooltips {
+                let click = if double_click_to_edit {
+                    "double-click"
+                } else {
+                    "click"
+                };
+                // Show full precision value on-hover. TODO(emilk): figure out f64 vs f32
+                let full_value = match integer {
+                    Some(integer) => emath::fixed_point::format_fixed_point(integer, decimals),
+                    None => (value as f32).to_string(),
+                };
                 response = response.on_hover_text(format!(
-                    "{}{}{}\nDrag to edit or click to enter a value.\nPress 'Shift' while dragging for better control.",
-                    prefix,
-                    value as f32, // Show full precision value on-hover. TODO(emilk): figure out f64 vs f32
-                    suffix
+                    "{prefix}{full_value}{suffix}\nDrag to edit or {click} to enter a value.\nPress 'Shift' while dragging for better control.",
                 ));
             }
 
@@ -550,7 +756,13 @@ impl<'a> Widget for DragValue<'a> {
                 ui.data_mut(|data| data.remove::<f64>(id));
             }
 
-            if response.clicked() {
+            let start_editing = if double_click_to_edit {
+                response.double_clicked()
+            } else {
+                response.clicked()
+            };
+
+            if start_editing {
                 ui.data_mut(|data| data.remove::<String>(id));
                 ui.memory_mut(|mem| mem.request_focus(id));
                 let mut state = TextEdit::load_state(ui.ctx(), id).unwrap_or_default();
@@ -569,7 +781,22 @@ impl<'a> Widget for DragValue<'a> {
 
                 let delta_value = delta_points as f64 * speed;
 
-                if delta_value != 0.0 {
+                if let (Some(integer), true) = (integer, delta_value != 0.0) {
+                    // Accumulate the fractional part of the drag in memory,
+                    // and only apply whole steps to the integer:
+                    let remainder = ui.data_mut(|data| data.get_temp::<f64>(id));
+                    let remainder = remainder.unwrap_or_default() + delta_value * scale;
+                    let whole_steps = remainder.trunc();
+                    let new_integer = clamp_integer_to_range(
+                        integer.saturating_add(float_to_integer(whole_steps)),
+                        integer_clamp_range.clone(),
+                    );
+                    if new_integer != integer {
+                        set_integer(&mut access, new_integer);
+                    }
+
+                    ui.data_mut(|data| data.insert_temp::<f64>(id, remainder - whole_steps));
+                } else if delta_value != 0.0 {
                     // Since we round the value being dragged, we need to store the full precision value in memory:
                     let precise_value = ui.data_mut(|data| data.get_temp::<f64>(id));
                     let precise_value = precise_value.unwrap_or(value);
@@ -583,7 +810,7 @@ impl<'a> Widget for DragValue<'a> {
                     let rounded_new_value =
                         emath::round_to_decimals(rounded_new_value, auto_decimals);
                     let rounded_new_value = clamp_to_range(rounded_new_value, clamp_range.clone());
-                    set(&mut get_set_value, rounded_new_value);
+                    set(&mut access, rounded_new_value);
 
                     ui.data_mut(|data| data.insert_temp::<f64>(id, precise_value));
                 }
@@ -592,7 +819,10 @@ impl<'a> Widget for DragValue<'a> {
             response
         };
 
-        response.changed = get(&mut get_set_value) != old_value;
+        response.changed = match old_integer {
+            Some(old_integer) => get_integer(&mut access) != Some(old_integer),
+            None => get(&mut access) != old_value,
+        };
 
         response.widget_info(|| WidgetInfo::drag_value(value));
 
@@ -647,6 +877,78 @@ impl<'a> Widget for DragValue<'a> {
     }
 }
 
+/// Parse the text the user entered, and set the value if it is valid.
+fn set_from_text(
+    access: &mut ValueAccess<'_>,
+    custom_parser: &Option<NumParser<'_>>,
+    clamp_range: &RangeInclusive<f64>,
+    integer_clamp_range: &RangeInclusive<i128>,
+    value_text: &str,
+) {
+    let parse_float = |text: &str| match custom_parser {
+        Some(parser) => parser(text),
+        None => text.parse().ok(),
+    };
+
+    if let ValueAccess::Integer { decimals, .. } = access {
+        let decimals = *decimals;
+        let scale = access.scale();
+        let parsed_value = match custom_parser {
+            Some(_) => None,
+            None => emath::fixed_point::parse_fixed_point(value_text, decimals),
+        };
+        let parsed_value = parsed_value
+            .or_else(|| parse_float(value_text).map(|v| float_to_integer((v * scale).round())));
+        if let Some(parsed_value) = parsed_value {
+            let parsed_value = clamp_integer_to_range(parsed_value, integer_clamp_range.clone());
+            set_integer(access, parsed_value);
+        }
+    } else if let Some(parsed_value) = parse_float(value_text) {
+        let parsed_value = clamp_to_range(parsed_value, clamp_range.clone());
+        set(access, parsed_value);
+    }
+}
+
+/// Saturating conversion, e.g. infinity becomes [`i128::MAX`]. The value should already be rounded.
+fn float_to_integer(x: f64) -> i128 {
+    x as i128
+}
+
+/// How much to change an integer for a keyboard change of `delta`: at least one step.
+fn integer_step(delta: f64) -> i128 {
+    let step = delta.round();
+    if step == 0.0 {
+        float_to_integer(delta.signum())
+    } else {
+        float_to_integer(step)
+    }
+}
+
+/// Change the number of decimals of a fixed-point mantissa, saturating on overflow.
+///
+/// When decimals are removed the result is rounded up or down.
+fn rescale_integer(x: i128, from_decimals: u32, to_decimals: u32, round_up: bool) -> i128 {
+    if from_decimals <= to_decimals {
+        match emath::fixed_point::pow10_i128(to_decimals - from_decimals) {
+            Some(factor) => x.saturating_mul(factor),
+            None if x == 0 => 0,
+            None if x < 0 => i128::MIN,
+            None => i128::MAX,
+        }
+    } else {
+        match emath::fixed_point::pow10_i128(from_decimals - to_decimals) {
+            Some(factor) if round_up => -(x.saturating_neg().div_euclid(factor)),
+            Some(factor) => x.div_euclid(factor),
+            None => 0,
+        }
+    }
+}
+
+fn clamp_integer_to_range(x: i128, range: RangeInclusive<i128>) -> i128 {
+    let (min, max) = (*range.start(), *range.end());
+    x.clamp(min.min(max), min.max(max))
+}
+
 fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
     let (mut min, mut max) = (*range.start(), *range.end());
 
@@ -665,7 +967,9 @@ fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use super::clamp_to_range;
+    use super::{
+        clamp_to_range, get_integer, integer_step, rescale_integer, set_from_text, ValueAccess,
+    };
 
     macro_rules! total_assert_eq {
         ($a:expr, $b:expr) => {
@@ -691,4 +995,100 @@ mod tests {
         total_assert_eq!(5.0_f64, clamp_to_range(15.0, 5.0..=1.0));
         total_assert_eq!(1.0_f64, clamp_to_range(-5.0, 5.0..=1.0));
     }
+
+    #[test]
+    fn test_huge_integers_are_exact() {
+        let mut value: u64 = 0;
+        let mut access = ValueAccess::new(&mut value);
+        set_from_text(
+            &mut access,
+            &None,
+            &(0.0..=f64::INFINITY),
+            &(0..=(u64::MAX - 1) as i128),
+            "18446744073709551615",
+        );
+        drop(access);
+        assert_eq!(value, u64::MAX - 1);
+
+        let mut value: i64 = (1 << 60) + 1;
+        let mut access = ValueAccess::new(&mut value);
+        set_from_text(
+            &mut access,
+            &None,
+            &(0.0..=0.0),
+            &(i64::MIN as i128..=i64::MAX as i128),
+            "1152921504606846979",
+        );
+        drop(access);
+        assert_eq!(value, (1 << 60) + 3);
+
+        let mut value: i128 = 0;
+        let mut access = ValueAccess::new(&mut value);
+        set_from_text(
+            &mut access,
+            &None,
+            &(0.0..=0.0),
+            &(i128::MIN..=i128::MAX),
+            "-170141183460469231731687303715884105728",
+        );
+        drop(access);
+        assert_eq!(value, i128::MIN);
+
+        // A fixed-point number with two decimals:
+        let mut mantissa: i128 = 0;
+        let mut access = ValueAccess::Integer {
+            get_set: Box::new(|v| {
+                if let Some(v) = v {
+                    mantissa = v;
+                }
+                mantissa
+            }),
+            decimals: 2,
+        };
+        let clamp = rescale_integer(-5, 0, 2, true)
+            ..=rescale_integer(1_000_000_000_000_000_000_000, 0, 2, false);
+        set_from_text(
+            &mut access,
+            &None,
+            &(0.0..=0.0),
+            &clamp,
+            "123456789012345678901.239",
+        );
+        assert_eq!(
+            get_integer(&mut access),
+            Some(12_345_678_901_234_567_890_124)
+        );
+        set_from_text(&mut access, &None, &(0.0..=0.0), &clamp, "-7.5");
+        assert_eq!(get_integer(&mut access), Some(-500));
+
+        // `u128` is exact up to `i128::MAX`:
+        let mut value: u128 = 0;
+        let mut access = ValueAccess::new(&mut value);
+        set_from_text(
+            &mut access,
+            &None,
+            &(0.0..=0.0),
+            &(0..=i128::MAX),
+            "170141183460469231731687303715884105727",
+        );
+        drop(access);
+        assert_eq!(value, i128::MAX as u128);
+
+        // …and above that it is not silently clamped to `i128::MAX`:
+        assert_eq!(emath::Numeric::to_i128(u128::MAX), None);
+        for original in [u128::MAX, u128::MAX - 12345, (i128::MAX as u128) + 1] {
+            let mut value = original;
+            crate::__run_test_ui(|ui| {
+                ui.add(super::DragValue::new(&mut value));
+            });
+            assert_eq!(value, original);
+        }
+
+        assert_eq!(rescale_integer(-1999, 2, 0, true), -19);
+        assert_eq!(rescale_integer(-1999, 2, 0, false), -20);
+
+        assert_eq!(integer_step(0.25), 1);
+        assert_eq!(integer_step(-0.25), -1);
+        assert_eq!(integer_step(2.6), 3);
+    }
 }
@@ -0,0 +1,116 @@
+use crate::*;
+
+/// The text being edited, while an [`EditableLabel`] is in edit mode.
+#[derive(Clone, Default)]
+struct EditableLabelState {
+    text: String,
+}
+
+/// A label that can be renamed in place.
+///
+/// It looks like a normal [`Label`] until it is double-clicked (or F2 is pressed while it has keyboard focus).
+/// Then it turns into a [`TextEdit`] of the same size as the text.
+/// Pressing Enter or clicking elsewhere keeps the new text, pressing Escape throws it away.
+///
+/// The returned [`Response`] is marked as changed when the new text is committed.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut name = "Untitled".to_owned();
+/// # let mut unsaved_changes = false;
+/// if ui.add(egui::EditableLabel::new(&mut name)).changed() {
+///     unsaved_changes = true;
+/// }
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct EditableLabel<'t> {
+    text: &'t mut String,
+    id_source: Option<Id>,
+}
+
+impl<'t> EditableLabel<'t> {
+    pub fn new(text: &'t mut String) -> Self {
+        Self {
+            text,
+            id_source: None,
+        }
+    }
+
+    /// Use this to make sure the label keeps its edit state
+    /// even if the layout around it changes, e.g. in a list whose items move around.
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Start editing a label, e.g. from a "Rename" context menu item.
+    ///
+    /// `id` is the [`Response::id`] of the label when it is not being edited.
+    pub fn start_editing(ctx: &Context, id: Id, text: &str) {
+        ctx.data_mut(|d| {
+            d.insert_temp(
+                id,
+                EditableLabelState {
+                    text: text.to_owned(),
+                },
+            );
+        });
+        ctx.memory_mut(|mem| mem.request_focus(id.with("edit")));
+    }
+}
+
+impl<'t> Widget for EditableLabel<'t> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self { text, id_source } = self;
+        let id = id_source.map_or_else(
+            || ui.next_auto_id().with("editable_label"),
+            |id_source| ui.make_persistent_id(id_source),
+        );
+        let edit_id = id.with("edit");
+
+        if let Some(mut state) = ui.data(|d| d.get_temp::<EditableLabelState>(id)) {
+            let mut response = ui.add(
+                TextEdit::singleline(&mut state.text)
+                    .id(edit_id)
+                    .desired_width(0.0)
+                    .clip_text(false),
+            );
+
+            if response.lost_focus() {
+                ui.data_mut(|d| d.remove::<EditableLabelState>(id));
+                let (confirmed, cancelled) =
+                    ui.input(|i| (i.key_pressed(Key::Enter), i.key_pressed(Key::Escape)));
+                if !cancelled && *text != state.text {
+                    *text = state.text;
+                    response.mark_changed();
+                } else {
+                    response.changed = false;
+                }
+                if confirmed || cancelled {
+                    // Keep the focus on the label, so it can be renamed again with F2.
+                    // When focus moved elsewhere (click, Tab) we leave it there.
+                    ui.memory_mut(|mem| mem.request_focus(id));
+                }
+            } else {
+                // Not committed yet:
+                response.changed = false;
+                ui.data_mut(|d| d.insert_temp(id, state));
+            }
+            response
+        } else {
+            let label = ui.add(Label::new(text.as_str()).selectable(false));
+            let response = ui.interact(label.rect, id, Sense::click());
+            response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, &*text));
+
+            let rename = response.double_clicked()
+                || (response.has_focus() && ui.input(|i| i.key_pressed(Key::F2)));
+            if rename {
+                Self::start_editing(ui.ctx(), id, text);
+                ui.ctx().request_repaint();
+            }
+            response
+        }
+    }
+}
@@ -23,24 +23,41 @@ use self::text_selection::LabelSelectionState;
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Link {
     text: WidgetText,
+    visited: bool,
 }
 
 impl Link {
     pub fn new(text: impl Into<WidgetText>) -> Self {
-        Self { text: text.into() }
+        Self {
+            text: text.into(),
+            visited: false,
+        }
+    }
+
+    /// Show the link as already visited, using [`crate::Visuals::hyperlink_visited_color`].
+    ///
+    /// [`Hyperlink`] does this automatically.
+    #[inline]
+    pub fn visited(mut self, visited: bool) -> Self {
+        self.visited = visited;
+        self
     }
 }
 
 impl Widget for Link {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Self { text } = self;
+        let Self { text, visited } = self;
         let label = Label::new(text).sense(Sense::click());
 
         let (galley_pos, galley, response) = label.layout_in_ui(ui);
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Link, galley.text()));
 
         if ui.is_rect_visible(response.rect) {
-            let color = ui.visuals().hyperlink_color;
+            let color = if visited {
+                ui.visuals().hyperlink_visited_color
+            } else {
+                ui.visuals().hyperlink_color
+            };
             let visuals = ui.style().interact(&response);
 
             let underline = if response.hovered() || response.has_focus() {
@@ -69,6 +86,12 @@ impl Widget for Link {
 
 /// A clickable hyperlink, e.g. to `"https://github.com/emilk/egui"`.
 ///
+/// Clicking it opens the url with [`Context::open_url`].
+/// Middle-clicking, or clicking with a modifier key held down, opens it in a new tab.
+/// To route some urls within your app instead, see [`Context::set_open_url_handler`].
+///
+/// Links that have been opened are shown with [`crate::Visuals::hyperlink_visited_color`].
+///
 /// See also [`Ui::hyperlink`] and [`Ui::hyperlink_to`].
 ///
 /// ```
@@ -121,20 +144,25 @@ impl Widget for Hyperlink {
     fn ui(self, ui: &mut Ui) -> Response {
         let Self { url, text, new_tab } = self;
 
-        let response = ui.add(Link::new(text));
+        let visited = ui.memory(|mem| mem.is_link_visited(&url));
+        let response = ui.add(Link::new(text).visited(visited));
 
-        if response.clicked() {
+        let open_url = if response.clicked() {
             let modifiers = ui.ctx().input(|i| i.modifiers);
-            ui.ctx().open_url(crate::OpenUrl {
+            Some(crate::OpenUrl {
                 url: url.clone(),
                 new_tab: new_tab || modifiers.any(),
-            });
-        }
-        if response.middle_clicked() {
-            ui.ctx().open_url(crate::OpenUrl {
-                url: url.clone(),
-                new_tab: true,
-            });
+            })
+        } else if response.middle_clicked() {
+            Some(crate::OpenUrl::new_tab(&url))
+        } else {
+            None
+        };
+        if let Some(open_url) = open_url {
+            if !visited {
+                ui.memory_mut(|mem| mem.mark_link_visited(url.clone()));
+            }
+            ui.ctx().open_url(open_url);
         }
 
         if ui.style().url_in_tooltip {
@@ -144,3 +172,47 @@ impl Widget for Hyperlink {
         }
     }
 }
+
+#[test]
+fn test_hyperlink_visited_and_open_url_handler() {
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    let url = "https://www.example.com";
+    let run = |events: Vec<Event>| {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        let (rect, output) = harness.run_input(input, |ctx| {
+            CentralPanel::default()
+                .show(ctx, |ui| ui.hyperlink(url).rect)
+                .inner
+        });
+        (rect, output.platform_output.open_url)
+    };
+    let click = |pos| {
+        run(vec![Event::PointerMoved(pos)]);
+        run(TestHarness::click_events(pos)).1
+    };
+
+    let pos = run(vec![]).0.center();
+    assert!(!ctx.memory(|mem| mem.is_link_visited(url)));
+    assert!(click(pos) == Some(OpenUrl::same_tab(url)));
+    assert!(ctx.memory(|mem| mem.is_link_visited(url)));
+
+    // A handler that takes care of the url stops the integration from opening it:
+    let handled = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    ctx.set_open_url_handler({
+        let handled = handled.clone();
+        move |_ctx, open_url| {
+            handled.store(open_url.url == url, std::sync::atomic::Ordering::Relaxed);
+            true
+        }
+    });
+    assert!(click(pos).is_none());
+    assert!(handled.load(std::sync::atomic::Ordering::Relaxed));
+
+    ctx.clear_open_url_handler();
+    ctx.memory_mut(|mem| mem.forget_visited_links());
+    assert!(click(pos) == Some(OpenUrl::same_tab(url)));
+}
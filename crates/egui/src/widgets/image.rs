@@ -4,7 +4,7 @@ use emath::{Float as _, Rot2};
 use epaint::RectShape;
 
 use crate::{
-    load::{Bytes, SizeHint, SizedTexture, TextureLoadResult, TexturePoll},
+    load::{Bytes, SizeHint, SizedTexture, TextureLoadResult, TexturePoll, TexturePromise},
     *,
 };
 
@@ -49,6 +49,11 @@ pub struct Image<'a> {
     sense: Sense,
     size: ImageSize,
     pub(crate) show_loading_spinner: Option<bool>,
+    placeholder: ImagePlaceholder,
+    fade_in: f32,
+
+    /// Made with [`Self::from_promise`] from a promise that is not ready yet.
+    pending: bool,
 }
 
 impl<'a> Image<'a> {
@@ -74,6 +79,9 @@ impl<'a> Image<'a> {
                 sense: Sense::hover(),
                 size,
                 show_loading_spinner: None,
+                placeholder: ImagePlaceholder::Spinner,
+                fade_in: 0.0,
+                pending: false,
             }
         }
 
@@ -94,6 +102,31 @@ impl<'a> Image<'a> {
         Self::new(ImageSource::Texture(texture.into()))
     }
 
+    /// Show the texture of a [`TexturePromise`] once it is ready, and a placeholder until then.
+    ///
+    /// Combine with [`Self::placeholder`] and [`Self::fade_in`], e.g. when loading textures on a background thread.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let promise: std::sync::Arc<egui::mutex::Mutex<Option<egui::TextureHandle>>> = Default::default();
+    /// ui.add(
+    ///     egui::Image::from_promise(&promise)
+    ///         .placeholder(egui::ImagePlaceholder::BlurHash("LEHV6nWB2yk8pyo0adR*.7kCMdnj".into()))
+    ///         .fade_in(0.3)
+    ///         .fit_to_exact_size(egui::vec2(64.0, 64.0)),
+    /// );
+    /// # });
+    /// ```
+    pub fn from_promise(promise: &impl TexturePromise) -> Self {
+        match promise.ready_texture() {
+            Some(texture) => Self::from_texture(texture),
+            None => Self {
+                pending: true,
+                ..Self::new(ImageSource::Uri(Cow::Borrowed("")))
+            },
+        }
+    }
+
     /// Load the image from some raw bytes.
     ///
     /// For better error messages, use the `bytes://` prefix for the URI.
@@ -194,12 +227,33 @@ impl<'a> Image<'a> {
     }
 
     /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
+    ///
+    /// Use this for showing part of a texture, e.g. a sprite in a sprite sheet.
+    /// A range with `min` larger than `max` flips the image along that axis.
     #[inline]
     pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
         self.image_options.uv = uv.into();
         self
     }
 
+    /// Mirror the image left-to-right.
+    ///
+    /// This is applied to the [`Self::uv`] range, before any rotation.
+    #[inline]
+    pub fn flip_horizontal(mut self, flip: bool) -> Self {
+        self.image_options.flip.x = flip;
+        self
+    }
+
+    /// Mirror the image top-to-bottom.
+    ///
+    /// This is applied to the [`Self::uv`] range, before any rotation.
+    #[inline]
+    pub fn flip_vertical(mut self, flip: bool) -> Self {
+        self.image_options.flip.y = flip;
+        self
+    }
+
     /// A solid color to put behind the image. Useful for transparent images.
     #[inline]
     pub fn bg_fill(mut self, bg_fill: impl Into<Color32>) -> Self {
@@ -214,6 +268,19 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Transform the colors of the image, e.g. with [`ColorMatrix::grayscale`] for a disabled icon.
+    ///
+    /// The backend applies the filter when it samples the texture (see [`epaint::Mesh::color_filter`]),
+    /// so this works for any [`ImageSource`], and doesn't need another texture.
+    ///
+    /// Combine filters with [`ColorMatrix::then`].
+    /// The [`Self::tint`] is applied after the filter.
+    #[inline]
+    pub fn color_filter(mut self, color_filter: ColorMatrix) -> Self {
+        self.image_options.color_filter = color_filter;
+        self
+    }
+
     /// Rotate the image about an origin by some angle
     ///
     /// Positive angle is clockwise.
@@ -245,6 +312,20 @@ impl<'a> Image<'a> {
         self
     }
 
+    /// Paint the image as a [`NinePatch`], so that its corners keep their size
+    /// while its edges and center are stretched or tiled to fill the rest.
+    ///
+    /// The insets are in the same units as the size of the texture.
+    ///
+    /// Due to limitations in the current implementation,
+    /// this will turn off rounding of the image.
+    #[inline]
+    pub fn nine_patch(mut self, nine_patch: NinePatch) -> Self {
+        self.image_options.nine_patch = Some(nine_patch);
+        self.image_options.rounding = Rounding::ZERO; // incompatible with nine-patch
+        self
+    }
+
     /// Show a spinner when the image is loading.
     ///
     /// By default this uses the value of [`Visuals::image_loading_spinners`].
@@ -253,6 +334,29 @@ impl<'a> Image<'a> {
         self.show_loading_spinner = Some(show);
         self
     }
+
+    /// What to show while the image is loading.
+    ///
+    /// Default: [`ImagePlaceholder::Spinner`].
+    #[inline]
+    pub fn placeholder(mut self, placeholder: ImagePlaceholder) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Cross-fade from the placeholder to the image over this many seconds, once it is loaded.
+    ///
+    /// Images that are already loaded when first shown don't fade in.
+    ///
+    /// With [`Self::paint_at`], the fade belongs to the image source,
+    /// so all places that paint the same image fade in together.
+    ///
+    /// Default: `0.0` (no fade).
+    #[inline]
+    pub fn fade_in(mut self, seconds: f32) -> Self {
+        self.fade_in = seconds;
+        self
+    }
 }
 
 impl<'a, T: Into<ImageSource<'a>>> From<T> for Image<'a> {
@@ -299,6 +403,9 @@ impl<'a> Image<'a> {
     /// # Errors
     /// May fail if they underlying [`Context::try_load_texture`] call fails.
     pub fn load_for_size(&self, ctx: &Context, available_size: Vec2) -> TextureLoadResult {
+        if self.pending {
+            return Ok(TexturePoll::Pending { size: None });
+        }
         let size_hint = self.size.hint(available_size);
         self.source
             .clone()
@@ -318,13 +425,78 @@ impl<'a> Image<'a> {
     /// ```
     #[inline]
     pub fn paint_at(&self, ui: &Ui, rect: Rect) {
-        paint_texture_load_result(
-            ui,
-            &self.load_for_size(ui.ctx(), rect.size()),
-            rect,
-            self.show_loading_spinner,
-            &self.image_options,
-        );
+        let tlr = self.load_for_size(ui.ctx(), rect.size());
+        // There is no widget to take the id from, and an auto id would change with the layout:
+        let id = Id::new(("image_paint_at", self.source.uri()));
+        self.paint_load_result(ui, &tlr, rect, id);
+    }
+
+    /// Paint the image or its placeholder, cross-fading between them.
+    fn paint_load_result(&self, ui: &Ui, tlr: &TextureLoadResult, rect: Rect, id: Id) {
+        let is_ready = matches!(tlr, Ok(TexturePoll::Ready { .. }));
+        let opacity = if 0.0 < self.fade_in {
+            ui.ctx()
+                .animate_bool_with_time(id.with("fade_in"), is_ready, self.fade_in)
+        } else if is_ready {
+            1.0
+        } else {
+            0.0
+        };
+
+        if opacity < 1.0 && tlr.is_ok() {
+            self.paint_placeholder(ui, rect, 1.0 - opacity);
+        }
+        if is_ready && opacity < 1.0 {
+            let mut options = self.image_options.clone();
+            options.tint = options.tint.gamma_multiply(opacity);
+            options.bg_fill = options.bg_fill.gamma_multiply(opacity);
+            paint_texture_load_result(ui, tlr, rect, None, &options);
+        } else if !matches!(tlr, Ok(TexturePoll::Pending { .. })) {
+            paint_texture_load_result(ui, tlr, rect, None, &self.image_options);
+        }
+    }
+
+    fn paint_placeholder(&self, ui: &Ui, rect: Rect, opacity: f32) {
+        let rounding = self.image_options.rounding;
+        match &self.placeholder {
+            ImagePlaceholder::Spinner => {
+                let show_loading_spinner = self
+                    .show_loading_spinner
+                    .unwrap_or(ui.visuals().image_loading_spinners);
+                if show_loading_spinner {
+                    let color = ui.visuals().strong_text_color().gamma_multiply(opacity);
+                    Spinner::new().color(color).paint_at(ui, rect);
+                }
+            }
+            ImagePlaceholder::Solid(color) => {
+                ui.painter()
+                    .rect_filled(rect, rounding, color.gamma_multiply(opacity));
+            }
+            ImagePlaceholder::BlurHash(blurhash) => {
+                let id = Id::new(("blurhash", blurhash));
+                let texture = ui.data(|d| d.get_temp::<Option<TextureHandle>>(id));
+                let texture = texture.unwrap_or_else(|| {
+                    let image = ColorImage::from_blurhash(blurhash, [32, 32]);
+                    let texture = image.map(|image| {
+                        ui.ctx().load_texture(
+                            format!("blurhash {blurhash}"),
+                            image,
+                            Default::default(),
+                        )
+                    });
+                    ui.data_mut(|d| d.insert_temp(id, texture.clone()));
+                    texture
+                });
+                if let Some(texture) = texture {
+                    let options = ImageOptions {
+                        tint: Color32::WHITE.gamma_multiply(opacity),
+                        rounding,
+                        ..Default::default()
+                    };
+                    paint_texture_at(ui.painter(), rect, &options, &(&texture).into());
+                }
+            }
+        }
     }
 }
 
@@ -336,18 +508,29 @@ impl<'a> Widget for Image<'a> {
 
         let (rect, response) = ui.allocate_exact_size(ui_size, self.sense);
         if ui.is_rect_visible(rect) {
-            paint_texture_load_result(
-                ui,
-                &tlr,
-                rect,
-                self.show_loading_spinner,
-                &self.image_options,
-            );
+            self.paint_load_result(ui, &tlr, rect, response.id);
         }
         texture_load_result_response(&self.source, &tlr, response)
     }
 }
 
+/// What an [`Image`] shows while it is loading, see [`Image::placeholder`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ImagePlaceholder {
+    /// A [`Spinner`], if enabled with [`Image::show_loading_spinner`]
+    /// or [`Visuals::image_loading_spinners`].
+    #[default]
+    Spinner,
+
+    /// A solid color, e.g. the average color of the image.
+    Solid(Color32),
+
+    /// A blurry preview of the image, decoded from a [BlurHash](https://blurha.sh).
+    ///
+    /// Shows nothing if the hash is invalid.
+    BlurHash(String),
+}
+
 /// This type determines the constraints on how
 /// the size of an image should be calculated.
 #[derive(Debug, Clone, Copy)]
@@ -608,11 +791,17 @@ pub fn texture_load_result_response(
     match tlr {
         Ok(TexturePoll::Ready { .. }) => response,
         Ok(TexturePoll::Pending { .. }) => {
-            let uri = source.uri().unwrap_or("image");
+            let uri = source
+                .uri()
+                .filter(|uri| !uri.is_empty())
+                .unwrap_or("image");
             response.on_hover_text(format!("Loading {uri}…"))
         }
         Err(err) => {
-            let uri = source.uri().unwrap_or("image");
+            let uri = source
+                .uri()
+                .filter(|uri| !uri.is_empty())
+                .unwrap_or("image");
             response.on_hover_text(format!("Failed loading {uri}: {err}"))
         }
     }
@@ -692,8 +881,15 @@ impl<T: Into<SizedTexture>> From<T> for ImageSource<'static> {
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct ImageOptions {
     /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
+    ///
+    /// A range with `min` larger than `max` flips the image along that axis.
     pub uv: Rect,
 
+    /// Mirror the image along the x and/or y axis.
+    ///
+    /// This is applied to [`Self::uv`], before any rotation.
+    pub flip: Vec2b,
+
     /// A solid color to put behind the image. Useful for transparent images.
     pub bg_fill: Color32,
 
@@ -718,20 +914,47 @@ pub struct ImageOptions {
     /// Due to limitations in the current implementation,
     /// this will turn off any rotation of the image.
     pub rounding: Rounding,
+
+    /// Transform the colors of the image, before the [`Self::tint`].
+    ///
+    /// See [`Image::color_filter`].
+    pub color_filter: ColorMatrix,
+
+    /// Paint the image as a [`NinePatch`].
+    ///
+    /// Rounding is ignored for nine-patch images.
+    pub nine_patch: Option<NinePatch>,
 }
 
 impl Default for ImageOptions {
     fn default() -> Self {
         Self {
             uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            flip: Vec2b::FALSE,
             bg_fill: Default::default(),
             tint: Color32::WHITE,
             rotation: None,
             rounding: Rounding::ZERO,
+            color_filter: ColorMatrix::IDENTITY,
+            nine_patch: None,
         }
     }
 }
 
+impl ImageOptions {
+    /// The UV range after applying [`Self::flip`].
+    pub fn flipped_uv(&self) -> Rect {
+        let mut uv = self.uv;
+        if self.flip.x {
+            std::mem::swap(&mut uv.min.x, &mut uv.max.x);
+        }
+        if self.flip.y {
+            std::mem::swap(&mut uv.min.y, &mut uv.max.y);
+        }
+        uv
+    }
+}
+
 pub fn paint_texture_at(
     painter: &Painter,
     rect: Rect,
@@ -742,30 +965,201 @@ pub fn paint_texture_at(
         painter.add(RectShape::filled(rect, options.rounding, options.bg_fill));
     }
 
-    match options.rotation {
-        Some((rot, origin)) => {
-            // TODO(emilk): implement this using `PathShape` (add texture support to it).
-            // This will also give us anti-aliasing of rotated images.
-            debug_assert!(
-                options.rounding == Rounding::ZERO,
-                "Image had both rounding and rotation. Please pick only one"
+    let uv = options.flipped_uv();
+    let (tint, color_filter) = match options.color_filter.as_scale() {
+        Some([r, g, b, a]) => {
+            // Multiply in gamma space, like `Color32::gamma_multiply`:
+            let scale = |i: usize, factor: f32| (options.tint[i] as f32 * factor + 0.5) as u8;
+            let tint = Color32::from_rgba_premultiplied(
+                scale(0, r * a),
+                scale(1, g * a),
+                scale(2, b * a),
+                scale(3, a),
             );
-
-            let mut mesh = Mesh::with_texture(texture.id);
-            mesh.add_rect_with_uv(rect, options.uv, options.tint);
+            (tint, ColorMatrix::IDENTITY)
+        }
+        None => (options.tint, options.color_filter),
+    };
+
+    if let Some(nine_patch) = &options.nine_patch {
+        // The insets belong to the sides of the texture, so they flip with it:
+        let mut nine_patch = *nine_patch;
+        if uv.min.x > uv.max.x {
+            std::mem::swap(&mut nine_patch.insets.left, &mut nine_patch.insets.right);
+        }
+        if uv.min.y > uv.max.y {
+            std::mem::swap(&mut nine_patch.insets.top, &mut nine_patch.insets.bottom);
+        }
+        let mut mesh = Mesh::with_texture(texture.id);
+        let source_size = texture.size * uv.size().abs();
+        mesh.add_nine_patch(rect, uv, source_size, &nine_patch, tint);
+        mesh.color_filter = color_filter;
+        if let Some((rot, origin)) = options.rotation {
             mesh.rotate(rot, rect.min + origin * rect.size());
-            painter.add(Shape::mesh(mesh));
-        }
-        None => {
-            painter.add(RectShape {
-                rect,
-                rounding: options.rounding,
-                fill: options.tint,
-                stroke: Stroke::NONE,
-                blur_width: 0.0,
-                fill_texture_id: texture.id,
-                uv: options.uv,
-            });
         }
+        painter.add(Shape::mesh(mesh));
+        return;
+    }
+
+    if let Some((rot, origin)) = options.rotation {
+        // TODO(emilk): implement this using `PathShape` (add texture support to it).
+        // This will also give us anti-aliasing of rotated images.
+        debug_assert!(
+            options.rounding == Rounding::ZERO,
+            "Image had both rounding and rotation. Please pick only one"
+        );
+
+        let mut mesh = Mesh::with_texture(texture.id);
+        mesh.add_rect_with_uv(rect, uv, tint);
+        mesh.color_filter = color_filter;
+        mesh.rotate(rot, rect.min + origin * rect.size());
+        painter.add(Shape::mesh(mesh));
+        return;
     }
+
+    let rect_shape = RectShape {
+        rect,
+        rounding: options.rounding,
+        fill: tint,
+        stroke: Stroke::NONE,
+        blur_width: 0.0,
+        fill_texture_id: texture.id,
+        uv,
+    };
+    if color_filter.is_identity() {
+        painter.add(rect_shape);
+    } else {
+        // Only a mesh has a color filter, so tessellate the (rounded) rectangle here:
+        let ctx = painter.ctx();
+        let mut tessellator = epaint::Tessellator::new(
+            ctx.pixels_per_point(),
+            ctx.tessellation_options(|options| *options),
+            ctx.fonts(|fonts| fonts.font_image_size()),
+            vec![],
+        );
+        let mut mesh = Mesh::with_texture(texture.id);
+        tessellator.tessellate_rect(&rect_shape, &mut mesh);
+        mesh.color_filter = color_filter;
+        painter.add(Shape::mesh(mesh));
+    }
+}
+
+#[test]
+fn test_color_filter_on_mesh() {
+    let harness = TestHarness::new();
+    let ctx = &harness.ctx;
+    let red = ColorImage::new([2, 2], Color32::RED);
+    let texture = ctx.load_texture("red", red, Default::default());
+    let texture_count = || ctx.tex_manager().read().allocated().count();
+    let sepia = ColorMatrix::sepia(1.0);
+
+    let textures_before = texture_count();
+    let (_, output) = harness.run_input(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| {
+            let image =
+                |rounding: f32| Image::new((texture.id(), vec2(10.0, 10.0))).rounding(rounding);
+            ui.add(image(0.0));
+            ui.add(image(0.0).color_filter(sepia));
+            ui.add(image(3.0).color_filter(sepia));
+            ui.add(image(0.0).color_filter(ColorMatrix::opacity(0.5)));
+        });
+    });
+    assert_eq!(texture_count(), textures_before);
+
+    // The sepia images get a mesh of their own, and the opacity goes into the vertex colors:
+    let primitives = ctx.tessellate(output.shapes, output.pixels_per_point);
+    let color_filters: Vec<ColorMatrix> = primitives
+        .iter()
+        .filter_map(|primitive| match &primitive.primitive {
+            epaint::Primitive::Mesh(mesh) if mesh.texture_id == texture.id() => {
+                Some(mesh.color_filter)
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        color_filters,
+        [ColorMatrix::IDENTITY, sepia, ColorMatrix::IDENTITY]
+    );
+}
+
+#[test]
+fn test_flip() {
+    let harness = TestHarness::new();
+    let texture = SizedTexture::new(TextureId::User(1), vec2(8.0, 8.0));
+    let rect = Rect::from_min_size(Pos2::ZERO, vec2(20.0, 20.0));
+    let paint = |image: Image<'_>| {
+        let (_, output) = harness.run_input(Default::default(), |ctx| {
+            CentralPanel::default().show(ctx, |ui| {
+                paint_texture_at(ui.painter(), rect, image.image_options(), &texture);
+            });
+        });
+        let mut shapes = output
+            .shapes
+            .into_iter()
+            .map(|clipped| clipped.shape)
+            .filter(|shape| shape.texture_id() == texture.id);
+        let shape = shapes.next().expect("the image wasn't painted");
+        assert!(shapes.next().is_none());
+        shape
+    };
+    let image = || Image::new(texture);
+
+    // A flip swaps the sides of the UV rect:
+    let uv = Rect::from_min_max(pos2(0.25, 0.0), pos2(0.75, 0.5));
+    let flipped = image().uv(uv).flip_horizontal(true);
+    assert_eq!(
+        flipped.image_options().flipped_uv(),
+        Rect::from_min_max(pos2(0.75, 0.0), pos2(0.25, 0.5))
+    );
+    let flipped = flipped.flip_vertical(true);
+    let flipped_uv = Rect::from_min_max(pos2(0.75, 0.5), pos2(0.25, 0.0));
+    assert_eq!(flipped.image_options().flipped_uv(), flipped_uv);
+    match paint(flipped) {
+        Shape::Rect(rect_shape) => assert_eq!(rect_shape.uv, flipped_uv),
+        shape => panic!("Expected a rectangle, got {shape:?}"),
+    }
+
+    // The image is flipped before it is rotated, so the top-left texel moves
+    // to the top-right by the flip, and then to the bottom-right by the rotation:
+    let rotated = image()
+        .flip_horizontal(true)
+        .rotate(std::f32::consts::TAU / 4.0, Vec2::splat(0.5));
+    let Shape::Mesh(mesh) = paint(rotated) else {
+        panic!("Expected a mesh");
+    };
+    let top_left_texel = mesh
+        .vertices
+        .iter()
+        .find(|vertex| vertex.uv == pos2(0.0, 0.0))
+        .unwrap();
+    assert!(
+        (top_left_texel.pos - rect.right_bottom()).length() < 1e-4,
+        "{:?}",
+        top_left_texel.pos
+    );
+
+    // The insets of a nine-patch belong to the sides of the texture, so they flip with it:
+    let nine_patch = image()
+        .nine_patch(NinePatch::new(Margin {
+            left: 1.0,
+            right: 3.0,
+            top: 2.0,
+            bottom: 2.0,
+        }))
+        .flip_horizontal(true);
+    let Shape::Mesh(mesh) = paint(nine_patch) else {
+        panic!("Expected a mesh");
+    };
+    let mut columns: Vec<(f32, f32)> = mesh
+        .vertices
+        .iter()
+        .map(|vertex| (vertex.pos.x, vertex.uv.x))
+        .collect();
+    columns.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    columns.dedup();
+    assert_eq!(
+        columns,
+        [(0.0, 1.0), (3.0, 0.625), (19.0, 0.125), (20.0, 0.0)]
+    );
 }
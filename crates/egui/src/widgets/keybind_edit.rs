@@ -0,0 +1,142 @@
+use crate::*;
+
+type Validator<'a> = Box<dyn 'a + Fn(&KeyboardShortcut) -> Result<(), String>>;
+
+/// A button for changing a [`KeyboardShortcut`].
+///
+/// Click it to start capturing. The next key pressed, together with the modifiers held down,
+/// becomes the new shortcut. Press Escape or click elsewhere to cancel.
+///
+/// Use [`Self::validate`] to flag shortcuts that are not allowed,
+/// e.g. because they are already bound to something else.
+///
+/// The returned [`Response`] is marked as changed when a new shortcut is captured.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// # let mut save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+/// let open = KeyboardShortcut::new(Modifiers::COMMAND, Key::O);
+/// ui.add(egui::KeybindEdit::new(&mut save).validate(|shortcut| {
+///     if *shortcut == open {
+///         Err("Already used by Open".to_owned())
+///     } else {
+///         Ok(())
+///     }
+/// }));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct KeybindEdit<'a> {
+    shortcut: &'a mut KeyboardShortcut,
+    validate: Option<Validator<'a>>,
+}
+
+impl<'a> KeybindEdit<'a> {
+    pub fn new(shortcut: &'a mut KeyboardShortcut) -> Self {
+        Self {
+            shortcut,
+            validate: None,
+        }
+    }
+
+    /// Check the shortcut, e.g. for conflicts with other shortcuts.
+    ///
+    /// If this returns an error, the shortcut is shown in [`Visuals::error_fg_color`],
+    /// with the error message as hover text.
+    #[inline]
+    pub fn validate(
+        mut self,
+        validate: impl Fn(&KeyboardShortcut) -> Result<(), String> + 'a,
+    ) -> Self {
+        self.validate = Some(Box::new(validate));
+        self
+    }
+}
+
+impl<'a> Widget for KeybindEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self { shortcut, validate } = self;
+
+        let id = ui.next_auto_id();
+        let mut capturing = ui.data(|d| d.get_temp::<bool>(id)).unwrap_or(false);
+
+        let mut captured = None;
+        if capturing {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    id,
+                    EventFilter {
+                        tab: true,
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        escape: true,
+                    },
+                );
+            });
+            let pressed = ui.input_mut(|i| {
+                let index = i
+                    .events
+                    .iter()
+                    .position(|event| matches!(event, Event::Key { pressed: true, .. }))?;
+                match i.events.remove(index) {
+                    Event::Key { key, modifiers, .. } => {
+                        Some(KeyboardShortcut::new(modifiers, key))
+                    }
+                    _ => None,
+                }
+            });
+            if let Some(pressed) = pressed {
+                capturing = false;
+                if pressed != KeyboardShortcut::new(Modifiers::NONE, Key::Escape) {
+                    captured = Some(pressed);
+                }
+            }
+        }
+
+        let error = validate.and_then(|validate| validate(shortcut).err());
+        let text = if capturing {
+            WidgetText::from("Press a key…")
+        } else {
+            let text = ui.ctx().format_shortcut(shortcut);
+            if error.is_some() {
+                RichText::new(text)
+                    .color(ui.visuals().error_fg_color)
+                    .into()
+            } else {
+                text.into()
+            }
+        };
+
+        let mut response = ui.add(Button::new(text).selected(capturing));
+
+        if let Some(new_shortcut) = captured {
+            if new_shortcut != *shortcut {
+                *shortcut = new_shortcut;
+                response.mark_changed();
+            }
+            ui.memory_mut(|m| m.surrender_focus(id));
+            ui.ctx().request_repaint();
+        } else if response.clicked() {
+            capturing = !capturing;
+            if capturing {
+                ui.memory_mut(|m| m.request_focus(id));
+            }
+            ui.ctx().request_repaint();
+        } else if capturing && (response.clicked_elsewhere() || response.lost_focus()) {
+            capturing = false;
+        }
+
+        if capturing {
+            ui.data_mut(|d| d.insert_temp(id, true));
+        } else {
+            ui.data_mut(|d| d.remove::<bool>(id));
+        }
+
+        if let Some(error) = error.filter(|_| !capturing) {
+            response = response.on_hover_text(error);
+        }
+
+        response
+    }
+}
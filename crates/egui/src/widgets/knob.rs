@@ -0,0 +1,337 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::{f32::consts::TAU, ops::RangeInclusive};
+
+use crate::*;
+
+use super::drag_value::{get, get_integer, set, ValueAccess};
+
+type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
+
+/// How dragging a [`Knob`] changes its value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KnobMode {
+    /// Dragging up increases the value, dragging down decreases it, like most audio software.
+    #[default]
+    Vertical,
+
+    /// The knob points at the pointer while dragging.
+    Circular,
+}
+
+/// A rotary knob for changing a number, as found on synthesizers and in audio software.
+///
+/// By default the value is changed by dragging up and down, see [`Knob::mode`].
+/// Hold Shift for finer adjustments.
+/// The arrow keys change the value while the knob has keyboard focus.
+///
+/// The current value is shown in the middle of the knob, unless turned off with [`Knob::show_value`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut gain: f32 = 0.5;
+/// ui.add(egui::Knob::new(&mut gain, 0.0..=1.0).ticks(11));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Knob<'a> {
+    access: ValueAccess<'a>,
+    range: RangeInclusive<f64>,
+    mode: KnobMode,
+    diameter: Option<f32>,
+    sweep: f32,
+    ticks: usize,
+    show_value: bool,
+    prefix: String,
+    suffix: String,
+    max_decimals: Option<usize>,
+    custom_formatter: Option<NumFormatter<'a>>,
+}
+
+impl<'a> Knob<'a> {
+    pub fn new<Num: emath::Numeric>(value: &'a mut Num, range: RangeInclusive<Num>) -> Self {
+        let range = range.start().to_f64()..=range.end().to_f64();
+        Self::from_access(range, ValueAccess::new(value))
+    }
+
+    pub fn from_get_set(
+        range: RangeInclusive<f64>,
+        get_set_value: impl 'a + FnMut(Option<f64>) -> f64,
+    ) -> Self {
+        Self::from_access(range, ValueAccess::Float(Box::new(get_set_value)))
+    }
+
+    fn from_access(range: RangeInclusive<f64>, access: ValueAccess<'a>) -> Self {
+        Self {
+            access,
+            range,
+            mode: KnobMode::default(),
+            diameter: None,
+            sweep: 0.75 * TAU,
+            ticks: 0,
+            show_value: true,
+            prefix: Default::default(),
+            suffix: Default::default(),
+            max_decimals: None,
+            custom_formatter: None,
+        }
+    }
+
+    /// How dragging changes the value. Default: [`KnobMode::Vertical`].
+    #[inline]
+    pub fn mode(mut self, mode: KnobMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Default: twice [`crate::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// How far the knob turns from the lowest to the highest value, in radians.
+    ///
+    /// The arc is centered on the top of the knob, leaving a gap at the bottom.
+    ///
+    /// Default: three quarters of a turn.
+    #[inline]
+    pub fn sweep(mut self, sweep: f32) -> Self {
+        self.sweep = sweep.clamp(0.0, TAU);
+        self
+    }
+
+    /// Show this many evenly spaced tick marks around the knob, including both ends.
+    ///
+    /// Default: `0` (no ticks).
+    #[inline]
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Show the value in the middle of the knob. Default: `true`.
+    #[inline]
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Show a prefix before the number, e.g. "x: "
+    #[inline]
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Add a suffix to the number, this can be e.g. a unit ("°" or " dB")
+    #[inline]
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show at most this many decimals of the value.
+    ///
+    /// Default: depends on the size of the range.
+    #[inline]
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// Set custom formatter defining how numbers are converted into text, like [`DragValue::custom_formatter`].
+    pub fn custom_formatter(
+        mut self,
+        formatter: impl 'a + Fn(f64, RangeInclusive<usize>) -> String,
+    ) -> Self {
+        self.custom_formatter = Some(Box::new(formatter));
+        self
+    }
+}
+
+/// Points along an arc, going clockwise (on screen) from angle `start` to `end`.
+fn arc_points(center: Pos2, radius: f32, start: f32, end: f32) -> Vec<Pos2> {
+    let segments = ((end - start).abs() / 0.1).ceil().max(1.0) as usize;
+    (0..=segments)
+        .map(|i| {
+            let angle = emath::lerp(start..=end, i as f32 / segments as f32);
+            center + radius * Vec2::angled(angle)
+        })
+        .collect()
+}
+
+impl<'a> Widget for Knob<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            mut access,
+            range,
+            mode,
+            diameter,
+            sweep,
+            ticks,
+            show_value,
+            prefix,
+            suffix,
+            max_decimals,
+            custom_formatter,
+        } = self;
+
+        let diameter = diameter.unwrap_or_else(|| 2.0 * ui.spacing().interact_size.y);
+        let (rect, mut response) =
+            ui.allocate_exact_size(Vec2::splat(diameter), Sense::click_and_drag());
+        let center = rect.center();
+        let radius = 0.5 * diameter;
+
+        // Angles go clockwise from the right, so a quarter turn is straight down:
+        let start_angle = 0.25 * TAU + 0.5 * (TAU - sweep);
+        let (min, max) = (*range.start(), *range.end());
+        let span = max - min;
+        let clamp = |value: f64| {
+            if min <= max {
+                value.clamp(min, max)
+            } else {
+                value.clamp(max, min)
+            }
+        };
+
+        let old_value = get(&mut access);
+        let mut value = old_value;
+
+        if response.drag_started() {
+            // The unrounded value, so that slowly dragging an integer knob still changes it:
+            ui.data_mut(|d| d.insert_temp(response.id, value));
+        }
+        if mode == KnobMode::Vertical && response.dragged() {
+            let mut dragged: f64 = ui.data(|d| d.get_temp(response.id)).unwrap_or(value);
+            let points_per_range = if ui.input(|i| i.modifiers.shift_only()) {
+                2000.0
+            } else {
+                200.0
+            };
+            dragged = clamp(dragged - span * f64::from(response.drag_delta().y) / points_per_range);
+            ui.data_mut(|d| d.insert_temp(response.id, dragged));
+            value = dragged;
+        }
+        if mode == KnobMode::Circular && response.is_pointer_button_down_on() {
+            if let Some(pointer) = response.interact_pointer_pos() {
+                let delta = pointer - center;
+                if delta.length() > 1.0 {
+                    let turned = (delta.angle() - start_angle).rem_euclid(TAU);
+                    let t = if turned <= sweep {
+                        turned / sweep
+                    } else if turned - sweep < TAU - turned {
+                        1.0
+                    } else {
+                        0.0
+                    };
+                    value = min + span * f64::from(t);
+                }
+            }
+        }
+
+        if response.has_focus() {
+            let step = match access {
+                ValueAccess::Integer { .. } => (span.abs() / 100.0).max(1.0 / access.scale()),
+                ValueAccess::Float(_) => span.abs() / 100.0,
+            };
+            let change = ui.input_mut(|i| {
+                i.count_and_consume_key(Modifiers::NONE, Key::ArrowUp) as f64
+                    + i.count_and_consume_key(Modifiers::NONE, Key::ArrowRight) as f64
+                    - i.count_and_consume_key(Modifiers::NONE, Key::ArrowDown) as f64
+                    - i.count_and_consume_key(Modifiers::NONE, Key::ArrowLeft) as f64
+            });
+            if change != 0.0 {
+                value = clamp(value + change * step.copysign(span));
+            }
+        }
+
+        if value != old_value {
+            set(&mut access, value);
+            value = get(&mut access);
+            if value != old_value {
+                response.mark_changed();
+            }
+        }
+
+        let max_decimals = max_decimals.unwrap_or(if span.abs() >= 100.0 {
+            0
+        } else if span.abs() >= 10.0 {
+            1
+        } else {
+            2
+        });
+        let value_text = match custom_formatter {
+            Some(custom_formatter) => custom_formatter(value, 0..=max_decimals),
+            None => match get_integer(&mut access) {
+                Some(integer) => emath::fixed_point::format_fixed_point(integer, access.decimals()),
+                None => emath::format_with_decimals_in_range(value, 0..=max_decimals),
+            },
+        };
+        let value_text = format!("{prefix}{value_text}{suffix}");
+
+        response.widget_info(|| WidgetInfo::slider(value, &value_text));
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let painter = ui.painter();
+            let t = if span == 0.0 {
+                0.0
+            } else {
+                ((value - min) / span).clamp(0.0, 1.0) as f32
+            };
+            let value_angle = start_angle + t * sweep;
+
+            let track_radius = radius - 4.0;
+            let track_width = 3.0;
+            painter.add(Shape::line(
+                arc_points(center, track_radius, start_angle, start_angle + sweep),
+                Stroke::new(track_width, ui.visuals().widgets.inactive.bg_fill),
+            ));
+            if t > 0.0 {
+                painter.add(Shape::line(
+                    arc_points(center, track_radius, start_angle, value_angle),
+                    Stroke::new(track_width, ui.visuals().selection.bg_fill),
+                ));
+            }
+
+            if ticks >= 2 {
+                let tick_stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+                for i in 0..ticks {
+                    let angle = start_angle + sweep * i as f32 / (ticks - 1) as f32;
+                    let dir = Vec2::angled(angle);
+                    painter.line_segment(
+                        [center + (radius - 1.5) * dir, center + radius * dir],
+                        tick_stroke,
+                    );
+                }
+            }
+
+            let body_radius = track_radius - track_width - 2.0;
+            painter.circle(
+                center,
+                body_radius + visuals.expansion,
+                visuals.bg_fill,
+                visuals.bg_stroke,
+            );
+            let dir = Vec2::angled(value_angle);
+            painter.line_segment(
+                [center + 0.6 * body_radius * dir, center + body_radius * dir],
+                Stroke::new(2.0, visuals.fg_stroke.color),
+            );
+
+            if show_value {
+                painter.text(
+                    center,
+                    Align2::CENTER_CENTER,
+                    value_text,
+                    TextStyle::Small.resolve(ui.style()),
+                    visuals.text_color(),
+                );
+            }
+        }
+
+        response
+    }
+}
@@ -26,6 +26,7 @@ pub struct Label {
     wrap_mode: Option<TextWrapMode>,
     sense: Option<Sense>,
     selectable: Option<bool>,
+    layout_in_background: bool,
 }
 
 impl Label {
@@ -35,6 +36,7 @@ impl Label {
             wrap_mode: None,
             sense: None,
             selectable: None,
+            layout_in_background: false,
         }
     }
 
@@ -68,6 +70,17 @@ impl Label {
         self
     }
 
+    /// Set [`Self::wrap_mode`] to [`TextWrapMode::TruncateMiddle`].
+    ///
+    /// Text that doesn't fit is elided in the middle, e.g. `/home/us…/file.txt`.
+    ///
+    /// Such a label is not selectable, but shows its full text on hover.
+    #[inline]
+    pub fn truncate_middle(mut self) -> Self {
+        self.wrap_mode = Some(TextWrapMode::TruncateMiddle);
+        self
+    }
+
     /// Can the user select the text with the mouse?
     ///
     /// Overrides [`crate::style::Interaction::selectable_labels`].
@@ -77,6 +90,18 @@ impl Label {
         self
     }
 
+    /// Lay out the text on a background thread, showing a placeholder until it is done.
+    ///
+    /// Use this for huge texts (e.g. long logs) that would otherwise stall the frame.
+    /// Changing the available width starts the layout over.
+    ///
+    /// See [`Context::layout_job_in_background`].
+    #[inline]
+    pub fn layout_in_background(mut self, layout_in_background: bool) -> Self {
+        self.layout_in_background = layout_in_background;
+        self
+    }
+
     /// Make the label respond to clicks and/or drags.
     ///
     /// By default, a label is inert and does not respond to click or drags.
@@ -100,10 +125,18 @@ impl Label {
 
 impl Label {
     /// Do layout and position the galley in the ui, without painting it or adding widget info.
+    ///
+    /// If the text was truncated, [`Galley::elided`] is set on the returned galley.
+    /// When added with [`Ui::add`], the label will then show the full text on hover.
     pub fn layout_in_ui(self, ui: &mut Ui) -> (Pos2, Arc<Galley>, Response) {
+        let wrap_mode = self.wrap_mode.unwrap_or_else(|| ui.wrap_mode());
+
+        // The glyphs of middle-elided text don't line up with its characters,
+        // so selecting it would copy the wrong text:
         let selectable = self
             .selectable
-            .unwrap_or_else(|| ui.style().interaction.selectable_labels);
+            .unwrap_or_else(|| ui.style().interaction.selectable_labels)
+            && wrap_mode != TextWrapMode::TruncateMiddle;
 
         let mut sense = self.sense.unwrap_or_else(|| {
             if ui.memory(|mem| mem.options.screen_reader) {
@@ -149,7 +182,6 @@ impl Label {
 
         let available_width = ui.available_width();
 
-        let wrap_mode = self.wrap_mode.unwrap_or_else(|| ui.wrap_mode());
         if wrap_mode == TextWrapMode::Wrap
             && ui.layout().main_dir() == Direction::LeftToRight
             && ui.layout().main_wrap()
@@ -169,7 +201,7 @@ impl Label {
             if let Some(first_section) = layout_job.sections.first_mut() {
                 first_section.leading_space = first_row_indentation;
             }
-            let galley = ui.fonts(|fonts| fonts.layout_job(layout_job));
+            let galley = layout(ui, layout_job, self.layout_in_background);
 
             let pos = pos2(ui.max_rect().left(), ui.cursor().top());
             assert!(!galley.rows.is_empty(), "Galleys are never empty");
@@ -194,7 +226,7 @@ impl Label {
                 layout_job.justify = ui.layout().horizontal_justify();
             };
 
-            let galley = ui.fonts(|fonts| fonts.layout_job(layout_job));
+            let galley = layout(ui, layout_job, self.layout_in_background);
             let (rect, response) = ui.allocate_exact_size(galley.size(), sense);
             let galley_pos = match galley.job.halign {
                 Align::LEFT => rect.left_top(),
@@ -206,6 +238,25 @@ impl Label {
     }
 }
 
+fn layout(ui: &Ui, layout_job: text::LayoutJob, in_background: bool) -> Arc<Galley> {
+    if !in_background {
+        return ui.fonts(|fonts| fonts.layout_job(layout_job));
+    }
+
+    let placeholder = text::LayoutJob::simple(
+        "⏳ Laying out text…".to_owned(),
+        layout_job
+            .sections
+            .first()
+            .map_or_else(FontId::default, |section| section.format.font_id.clone()),
+        ui.visuals().weak_text_color(),
+        layout_job.wrap.max_width,
+    );
+    ui.ctx()
+        .layout_job_in_background(ui.next_auto_id(), layout_job)
+        .unwrap_or_else(|| ui.fonts(|fonts| fonts.layout_job(placeholder)))
+}
+
 impl Widget for Label {
     fn ui(self, ui: &mut Ui) -> Response {
         // Interactive = the uses asked to sense interaction.
@@ -242,7 +293,7 @@ impl Widget for Label {
             );
 
             let selectable = selectable.unwrap_or_else(|| ui.style().interaction.selectable_labels);
-            if selectable {
+            if selectable && galley.elided_middle.is_none() {
                 LabelSelectionState::label_text_selection(ui, &response, galley_pos, &galley);
             }
         }
@@ -0,0 +1,106 @@
+use crate::{
+    easy_mark::{
+        self,
+        parser::{Item, Parser},
+    },
+    *,
+};
+
+/// Renders a subset of [Markdown](https://commonmark.org/help/).
+///
+/// Supported are:
+/// * `# Headings` (all six levels)
+/// * `*italics*`, `**bold**` and `~~strikethrough~~` (also with `_` and `__`)
+/// * `` `inline code` ``
+/// * `[links](https://www.egui.rs)`
+/// * Bulleted (`-`, `*`, `+`) and numbered (`1.`) lists, which can be nested by indenting them
+/// * `> Block quotes`
+/// * Fenced code blocks (` ``` `)
+/// * Horizontal rules (`---`)
+///
+/// Paragraphs are separated by empty lines.
+/// Anything else is shown as plain text.
+///
+/// This is parsed and shown with [`crate::easy_mark`], see [`crate::easy_mark::parser::Parser::markdown`].
+///
+/// By default, clicking a link opens it (like a [`Hyperlink`]).
+/// Use [`Self::on_link_click`] to handle link clicks yourself.
+///
+/// Requires the `markdown` feature.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.markdown("# Help\nSee the [docs](https://docs.rs/egui) for **more** info.");
+///
+/// let mut clicked_link = None;
+/// ui.add(egui::Markdown::new("[Open settings](settings)").on_link_click(|url| {
+///     clicked_link = Some(url.to_owned());
+/// }));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Markdown<'a> {
+    text: &'a str,
+    on_link_click: Option<Box<dyn FnMut(&str) + 'a>>,
+}
+
+impl<'a> Markdown<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            on_link_click: None,
+        }
+    }
+
+    /// Called with the url of a link when it is clicked.
+    ///
+    /// When set, links are no longer opened automatically.
+    #[inline]
+    pub fn on_link_click(mut self, on_link_click: impl FnMut(&str) + 'a) -> Self {
+        self.on_link_click = Some(Box::new(on_link_click));
+        self
+    }
+}
+
+impl<'a> Widget for Markdown<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            text,
+            mut on_link_click,
+        } = self;
+
+        easy_mark::show_items(ui, Parser::markdown(text), |ui, item| {
+            match (item, &mut on_link_click) {
+                (Item::Hyperlink(style, text, url), Some(on_link_click)) => {
+                    let text = easy_mark::rich_text_from_style(ui.style(), text, &style);
+                    if ui.link(text).on_hover_text(url).clicked() {
+                        on_link_click(url);
+                    }
+                }
+                (item, _) => easy_mark::item_ui(ui, item),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_link_click() {
+        let harness = crate::TestHarness::with_default_fonts();
+        let mut clicked = None;
+        let run = |events, clicked: &mut Option<String>| {
+            harness.run_ui(events, |ui| {
+                ui.add(Markdown::new("[link](url)").on_link_click(|url| {
+                    *clicked = Some(url.to_owned());
+                }))
+                .rect
+            })
+        };
+        let pos = run(vec![], &mut clicked).left_center() + vec2(4.0, 0.0);
+        run(crate::TestHarness::click_events(pos), &mut clicked);
+        assert_eq!(clicked.as_deref(), Some("url"));
+    }
+}
@@ -0,0 +1,168 @@
+use crate::*;
+
+type CellFormatter<'a> = Box<dyn 'a + Fn(usize, usize, f64) -> String>;
+type CellPredicate<'a> = Box<dyn 'a + Fn(usize, usize) -> bool>;
+
+/// Edit a matrix, or any small table of numbers, as a grid of [`DragValue`]s.
+///
+/// The matrix is given as a slice of rows, e.g. `&mut [[f32; 4]; 4]` or `&mut [Vec<f64>]`.
+///
+/// The returned [`Response`] is marked as changed when any cell changes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut transform = [[1.0_f32, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+/// ui.add(
+///     egui::MatrixEdit::new(&mut transform)
+///         .column_headers(["X", "Y", "T"])
+///         .cell_formatter(|_row, _col, value| format!("{value:.2}"))
+///         // The bottom row of an affine transform is always the same:
+///         .read_only(|row, _col| row == 2),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct MatrixEdit<'a, Num: emath::Numeric> {
+    rows: Vec<&'a mut [Num]>,
+    id_source: Option<Id>,
+    row_headers: Vec<WidgetText>,
+    column_headers: Vec<WidgetText>,
+    speed: f64,
+    cell_formatter: Option<CellFormatter<'a>>,
+    read_only: Option<CellPredicate<'a>>,
+}
+
+impl<'a, Num: emath::Numeric> MatrixEdit<'a, Num> {
+    pub fn new<Row: AsMut<[Num]>>(rows: &'a mut [Row]) -> Self {
+        Self {
+            rows: rows.iter_mut().map(|row| row.as_mut()).collect(),
+            id_source: None,
+            row_headers: vec![],
+            column_headers: vec![],
+            speed: 0.1,
+            cell_formatter: None,
+            read_only: None,
+        }
+    }
+
+    /// Set the id source of the underlying [`Grid`].
+    ///
+    /// Use this if the matrix moves around in your layout,
+    /// as the grid remembers the widths of its columns.
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Show a header to the left of each row.
+    #[inline]
+    pub fn row_headers(mut self, headers: impl IntoIterator<Item = impl Into<WidgetText>>) -> Self {
+        self.row_headers = headers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Show a header above each column.
+    #[inline]
+    pub fn column_headers(
+        mut self,
+        headers: impl IntoIterator<Item = impl Into<WidgetText>>,
+    ) -> Self {
+        self.column_headers = headers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// How much the values change when dragged, see [`DragValue::speed`].
+    ///
+    /// Default: `0.1`.
+    #[inline]
+    pub fn speed(mut self, speed: impl Into<f64>) -> Self {
+        self.speed = speed.into();
+        self
+    }
+
+    /// Format the value of each cell, given its row, column and value.
+    ///
+    /// The formatted text must still be parseable as a number if the cell is editable,
+    /// see [`DragValue::custom_formatter`].
+    #[inline]
+    pub fn cell_formatter(mut self, formatter: impl 'a + Fn(usize, usize, f64) -> String) -> Self {
+        self.cell_formatter = Some(Box::new(formatter));
+        self
+    }
+
+    /// Make some cells read-only, given their row and column.
+    ///
+    /// Read-only cells are shown as disabled [`DragValue`]s.
+    #[inline]
+    pub fn read_only(mut self, read_only: impl 'a + Fn(usize, usize) -> bool) -> Self {
+        self.read_only = Some(Box::new(read_only));
+        self
+    }
+}
+
+impl<'a, Num: emath::Numeric> Widget for MatrixEdit<'a, Num> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            rows,
+            id_source,
+            row_headers,
+            column_headers,
+            speed,
+            cell_formatter,
+            read_only,
+        } = self;
+
+        let num_columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let has_row_headers = !row_headers.is_empty();
+        let grid = Grid::new(id_source.unwrap_or_else(|| ui.next_auto_id()))
+            .num_columns(num_columns + usize::from(has_row_headers));
+
+        let inner = grid.show(ui, |ui| {
+            if !column_headers.is_empty() {
+                if has_row_headers {
+                    ui.label("");
+                }
+                for header in column_headers {
+                    ui.vertical_centered(|ui| ui.label(header));
+                }
+                ui.end_row();
+            }
+
+            let mut row_headers = row_headers.into_iter();
+            let mut response: Option<Response> = None;
+            for (row, values) in rows.into_iter().enumerate() {
+                if has_row_headers {
+                    ui.label(row_headers.next().unwrap_or_default());
+                }
+                for (col, value) in values.iter_mut().enumerate() {
+                    let mut drag_value = DragValue::new(value).speed(speed);
+                    if let Some(cell_formatter) = &cell_formatter {
+                        drag_value =
+                            drag_value.custom_formatter(move |n, _| cell_formatter(row, col, n));
+                    }
+                    let enabled = read_only
+                        .as_ref()
+                        .map_or(true, |read_only| !read_only(row, col));
+                    let cell_response = ui.add_enabled(enabled, drag_value);
+                    response = Some(match response {
+                        Some(response) => response | cell_response,
+                        None => cell_response,
+                    });
+                }
+                ui.end_row();
+            }
+            response
+        });
+
+        match inner.inner {
+            Some(cells) => {
+                let changed = cells.changed();
+                let mut response = cells.union(inner.response);
+                response.changed = changed;
+                response
+            }
+            None => inner.response,
+        }
+    }
+}
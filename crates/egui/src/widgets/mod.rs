@@ -8,37 +8,69 @@ use crate::*;
 
 mod button;
 mod checkbox;
+mod chip;
 pub mod color_picker;
+mod drag_angle;
 pub(crate) mod drag_value;
+mod editable_label;
 mod hyperlink;
 mod image;
 mod image_button;
+mod keybind_edit;
+mod knob;
 mod label;
+#[cfg(feature = "markdown")]
+mod markdown;
+mod matrix_edit;
+mod path_edit;
 mod progress_bar;
 mod radio_button;
+mod rating;
+mod segmented_control;
 mod selected_label;
 mod separator;
 mod slider;
 mod spinner;
 pub mod text_edit;
+mod title_bar;
+mod toggle_switch;
+mod vector_edit;
 
 pub use self::{
     button::Button,
     checkbox::Checkbox,
+    chip::{Chip, ChipResponse},
+    color_picker::{ColorPalette, GradientEditor},
+    drag_angle::{AngleUnit, DragAngle},
     drag_value::DragValue,
+    editable_label::EditableLabel,
     hyperlink::{Hyperlink, Link},
-    image::{paint_texture_at, Image, ImageFit, ImageOptions, ImageSize, ImageSource},
+    image::{
+        paint_texture_at, Image, ImageFit, ImageOptions, ImagePlaceholder, ImageSize, ImageSource,
+    },
     image_button::ImageButton,
+    keybind_edit::KeybindEdit,
+    knob::{Knob, KnobMode},
     label::Label,
+    matrix_edit::MatrixEdit,
+    path_edit::PathEdit,
     progress_bar::ProgressBar,
     radio_button::RadioButton,
+    rating::Rating,
+    segmented_control::SegmentedControl,
     selected_label::SelectableLabel,
     separator::Separator,
     slider::{Slider, SliderOrientation},
     spinner::Spinner,
     text_edit::{TextBuffer, TextEdit},
+    title_bar::TitleBar,
+    toggle_switch::ToggleSwitch,
+    vector_edit::VectorEdit,
 };
 
+#[cfg(feature = "markdown")]
+pub use self::markdown::Markdown;
+
 // ----------------------------------------------------------------------------
 
 /// Anything implementing Widget can be added to a [`Ui`] with [`Ui::add`].
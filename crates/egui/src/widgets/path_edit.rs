@@ -0,0 +1,181 @@
+use std::path::{Path, PathBuf};
+
+use crate::*;
+
+/// A text field for a file path, with a button for browsing for the file.
+///
+/// The browse button asks the integration to show a file dialog, see [`PlatformOutput::file_dialog`].
+/// Integrations that don't support this will just ignore it.
+/// A file can also be dragged from the OS and dropped onto the widget.
+///
+/// Paths that fail the checks of [`Self::extensions`] and [`Self::must_exist`]
+/// are shown in [`Visuals::error_fg_color`], with the reason as hover text.
+///
+/// The returned [`Response`] is marked as changed when the path changes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut path = std::path::PathBuf::new();
+/// ui.add(egui::PathEdit::new(&mut path).extensions(["png", "jpg"]));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct PathEdit<'a> {
+    path: &'a mut PathBuf,
+    extensions: Vec<String>,
+    must_exist: bool,
+    hint_text: WidgetText,
+    desired_width: Option<f32>,
+}
+
+impl<'a> PathEdit<'a> {
+    pub fn new(path: &'a mut PathBuf) -> Self {
+        Self {
+            path,
+            extensions: vec![],
+            must_exist: false,
+            hint_text: Default::default(),
+            desired_width: None,
+        }
+    }
+
+    /// Only accept files with one of these extensions, e.g. `["png", "jpg"]`.
+    ///
+    /// Compared ignoring case, and also passed on to the file dialog.
+    #[inline]
+    pub fn extensions(mut self, extensions: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Only accept paths that exist.
+    ///
+    /// This checks the file system every frame, and never passes on the web.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn must_exist(mut self, must_exist: bool) -> Self {
+        self.must_exist = must_exist;
+        self
+    }
+
+    /// Show this text when the path is empty.
+    #[inline]
+    pub fn hint_text(mut self, hint_text: impl Into<WidgetText>) -> Self {
+        self.hint_text = hint_text.into();
+        self
+    }
+
+    /// The width of the text field.
+    ///
+    /// Default: [`crate::style::Spacing::text_edit_width`].
+    #[inline]
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+
+    fn validate(&self, path: &Path) -> Result<(), String> {
+        if path.as_os_str().is_empty() {
+            return Ok(());
+        }
+        if !self.extensions.is_empty() {
+            let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase());
+            let matches = |expected: &String| extension.as_ref() == Some(&expected.to_lowercase());
+            if !self.extensions.iter().any(matches) {
+                return Err(format!(
+                    "Expected a .{} file",
+                    self.extensions.join(" or .")
+                ));
+            }
+        }
+        if self.must_exist && !path.exists() {
+            return Err("No such file".to_owned());
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Widget for PathEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let id = ui.next_auto_id();
+        let old_path = self.path.clone();
+        let mut new_path = None;
+
+        let inner = ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = ui.spacing().item_spacing.x.min(2.0);
+
+            let mut text = self.path.to_string_lossy().into_owned();
+            let error = self.validate(self.path).err();
+            let mut text_edit = TextEdit::singleline(&mut text)
+                .id(id)
+                .hint_text(self.hint_text.clone())
+                .desired_width(self.desired_width.unwrap_or(ui.spacing().text_edit_width));
+            if error.is_some() {
+                text_edit = text_edit.text_color(ui.visuals().error_fg_color);
+            }
+            let mut text_response = ui.add(text_edit);
+            if text_response.changed() {
+                new_path = Some(PathBuf::from(text));
+            }
+            if let Some(error) = error {
+                text_response = text_response.on_hover_text(error);
+            }
+
+            let browse_response = ui.button("🗁").on_hover_text("Browse…");
+            if browse_response.clicked() {
+                ui.output_mut(|o| {
+                    o.file_dialog = Some(FileDialogRequest {
+                        id,
+                        extensions: self.extensions.clone(),
+                    });
+                });
+            }
+
+            text_response | browse_response
+        });
+        let mut response = inner.inner.union(inner.response);
+        response.changed = false;
+
+        // The reply to our file dialog:
+        let picked = ui.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                Event::FileDialogPicked { id: reply_id, path } if *reply_id == id => {
+                    Some(path.clone())
+                }
+                _ => None,
+            })
+        });
+        new_path = picked.or(new_path);
+
+        // Files dragged from the OS:
+        if ui.rect_contains_pointer(response.rect) {
+            let (hovering_files, dropped) = ui.input(|i| {
+                (
+                    !i.raw.hovered_files.is_empty(),
+                    i.raw
+                        .dropped_files
+                        .iter()
+                        .find_map(|file| file.path.clone()),
+                )
+            });
+            if hovering_files {
+                ui.painter().rect_stroke(
+                    response.rect.expand(1.0),
+                    ui.visuals().widgets.hovered.rounding,
+                    ui.visuals().selection.stroke,
+                );
+            }
+            new_path = dropped.or(new_path);
+        }
+
+        if let Some(new_path) = new_path {
+            if new_path != old_path {
+                *self.path = new_path;
+                response.mark_changed();
+            }
+        }
+
+        response
+    }
+}
@@ -7,6 +7,8 @@ enum ProgressBarText {
 
 /// A simple progress bar.
 ///
+/// Use [`Self::indeterminate`] when you don't know how far along you are.
+///
 /// See also: [`crate::Spinner`].
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct ProgressBar {
@@ -17,6 +19,8 @@ pub struct ProgressBar {
     fill: Option<Color32>,
     animate: bool,
     rounding: Option<Rounding>,
+    indeterminate: bool,
+    segments: Vec<(f32, Color32, WidgetText)>,
 }
 
 impl ProgressBar {
@@ -30,6 +34,49 @@ impl ProgressBar {
             fill: None,
             animate: false,
             rounding: None,
+            indeterminate: false,
+            segments: Vec::new(),
+        }
+    }
+
+    /// A progress bar for when you don't know how far along you are.
+    ///
+    /// Instead of a fill, a segment sweeps across the bar.
+    /// This will cause the UI to be redrawn for as long as the bar is visible.
+    pub fn indeterminate() -> Self {
+        Self {
+            indeterminate: true,
+            ..Self::new(0.0)
+        }
+    }
+
+    /// A progress bar made up of several colored segments, e.g. for passed, failed and skipped tests.
+    ///
+    /// Each segment is a `(fraction, color, hover_text)`, where the fractions are of the whole bar.
+    /// The segments are shown left to right, and hovering one shows its hover text (unless it is empty).
+    /// The total progress is the sum of the fractions.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{Color32, ProgressBar};
+    /// ui.add(ProgressBar::stacked([
+    ///     (0.6, Color32::GREEN, "60 passed"),
+    ///     (0.1, Color32::RED, "10 failed"),
+    ///     (0.3, Color32::GRAY, "30 skipped"),
+    /// ]));
+    /// # });
+    /// ```
+    pub fn stacked<Text: Into<WidgetText>>(
+        segments: impl IntoIterator<Item = (f32, Color32, Text)>,
+    ) -> Self {
+        let segments: Vec<_> = segments
+            .into_iter()
+            .map(|(fraction, color, text)| (fraction.clamp(0.0, 1.0), color, text.into()))
+            .collect();
+        let progress = segments.iter().map(|(fraction, _, _)| fraction).sum();
+        Self {
+            segments,
+            ..Self::new(progress)
         }
     }
 
@@ -48,6 +95,8 @@ impl ProgressBar {
     }
 
     /// The fill color of the bar.
+    ///
+    /// This does nothing for a [`Self::stacked`] progress bar.
     #[inline]
     pub fn fill(mut self, color: Color32) -> Self {
         self.fill = Some(color);
@@ -62,6 +111,8 @@ impl ProgressBar {
     }
 
     /// Show the progress in percent on the progress bar.
+    ///
+    /// This does nothing for an [`Self::indeterminate`] progress bar.
     #[inline]
     pub fn show_percentage(mut self) -> Self {
         self.text = Some(ProgressBarText::Percentage);
@@ -103,6 +154,8 @@ impl Widget for ProgressBar {
             fill,
             animate,
             rounding,
+            indeterminate,
+            segments,
         } = self;
 
         let animate = animate && progress < 1.0;
@@ -110,7 +163,7 @@ impl Widget for ProgressBar {
         let desired_width =
             desired_width.unwrap_or_else(|| ui.available_size_before_wrap().x.at_least(96.0));
         let height = desired_height.unwrap_or(ui.spacing().interact_size.y);
-        let (outer_rect, response) =
+        let (outer_rect, mut response) =
             ui.allocate_exact_size(vec2(desired_width, height), Sense::hover());
 
         response.widget_info(|| {
@@ -119,13 +172,41 @@ impl Widget for ProgressBar {
             } else {
                 WidgetInfo::new(WidgetType::ProgressIndicator)
             };
-            info.value = Some((progress as f64 * 100.0).floor());
+            if !indeterminate {
+                info.value = Some((progress as f64 * 100.0).floor());
+            }
 
             info
         });
 
+        let mut left = outer_rect.left();
+        let segment_rects: Vec<Rect> = segments
+            .iter()
+            .map(|(fraction, _, _)| {
+                let right = (left + fraction * outer_rect.width()).at_most(outer_rect.right());
+                let rect = Rect::from_x_y_ranges(left..=right, outer_rect.y_range());
+                left = right;
+                rect
+            })
+            .collect();
+
+        if let Some(pos) = response.hover_pos() {
+            if let Some(i) = segment_rects.iter().position(|rect| rect.contains(pos)) {
+                let hover_text = &segments[i].2;
+                if !hover_text.is_empty() {
+                    response = response.on_hover_text(hover_text.clone());
+                }
+            }
+        }
+
+        let text = text.and_then(|text_kind| match text_kind {
+            ProgressBarText::Custom(text) => Some(text),
+            ProgressBarText::Percentage if indeterminate => None,
+            ProgressBarText::Percentage => Some(format!("{}%", (progress * 100.0) as usize).into()),
+        });
+
         if ui.is_rect_visible(response.rect) {
-            if animate {
+            if animate || indeterminate {
                 ui.ctx().request_repaint();
             }
 
@@ -135,6 +216,45 @@ impl Widget for ProgressBar {
             let rounding = rounding.unwrap_or_else(|| corner_radius.into());
             ui.painter()
                 .rect(outer_rect, rounding, visuals.extreme_bg_color, Stroke::NONE);
+            let fill = fill.unwrap_or(visuals.selection.bg_fill);
+
+            if indeterminate {
+                // A segment a third of the bar wide, sweeping from left to right:
+                let period = 1.5;
+                let t = (ui.input(|i| i.time) / period).fract() as f32;
+                let segment_width = outer_rect.width() / 3.0;
+                let left = lerp((outer_rect.left() - segment_width)..=outer_rect.right(), t);
+                // Shrink the segment at the ends rather than clipping it, so it stays rounded:
+                let segment_rect =
+                    Rect::from_x_y_ranges(left..=left + segment_width, outer_rect.y_range())
+                        .intersect(outer_rect);
+                if segment_rect.is_positive() {
+                    ui.painter()
+                        .rect(segment_rect, rounding, fill, Stroke::NONE);
+                }
+
+                if let Some(text) = text {
+                    paint_text(ui, outer_rect, text);
+                }
+                return response;
+            }
+
+            if !segments.is_empty() {
+                for (i, ((_, color, _), segment_rect)) in
+                    segments.iter().zip(&segment_rects).enumerate()
+                {
+                    // Only the ends of the bar are rounded:
+                    let segment_rounding = rounding.segment(i, segments.len());
+                    ui.painter()
+                        .rect(*segment_rect, segment_rounding, *color, Stroke::NONE);
+                }
+
+                if let Some(text) = text {
+                    paint_text(ui, outer_rect, text);
+                }
+                return response;
+            }
+
             let min_width = 2.0 * rounding.sw.at_least(rounding.nw).at_most(corner_radius);
             let filled_width = (outer_rect.width() * progress).at_least(min_width);
             let inner_rect =
@@ -151,9 +271,7 @@ impl Widget for ProgressBar {
             ui.painter().rect(
                 inner_rect,
                 rounding,
-                Color32::from(
-                    Rgba::from(fill.unwrap_or(visuals.selection.bg_fill)) * color_factor as f32,
-                ),
+                Color32::from(Rgba::from(fill) * color_factor as f32),
                 Stroke::NONE,
             );
 
@@ -176,30 +294,29 @@ impl Widget for ProgressBar {
                     .add(Shape::line(points, Stroke::new(2.0, visuals.text_color())));
             }
 
-            if let Some(text_kind) = text {
-                let text = match text_kind {
-                    ProgressBarText::Custom(text) => text,
-                    ProgressBarText::Percentage => {
-                        format!("{}%", (progress * 100.0) as usize).into()
-                    }
-                };
-                let galley = text.into_galley(
-                    ui,
-                    Some(TextWrapMode::Extend),
-                    f32::INFINITY,
-                    TextStyle::Button,
-                );
-                let text_pos = outer_rect.left_center() - Vec2::new(0.0, galley.size().y / 2.0)
-                    + vec2(ui.spacing().item_spacing.x, 0.0);
-                let text_color = visuals
-                    .override_text_color
-                    .unwrap_or(visuals.selection.stroke.color);
-                ui.painter()
-                    .with_clip_rect(outer_rect)
-                    .galley(text_pos, galley, text_color);
+            if let Some(text) = text {
+                paint_text(ui, outer_rect, text);
             }
         }
 
         response
     }
 }
+
+fn paint_text(ui: &Ui, outer_rect: Rect, text: WidgetText) {
+    let galley = text.into_galley(
+        ui,
+        Some(TextWrapMode::Extend),
+        f32::INFINITY,
+        TextStyle::Button,
+    );
+    let text_pos = outer_rect.left_center() - Vec2::new(0.0, galley.size().y / 2.0)
+        + vec2(ui.spacing().item_spacing.x, 0.0);
+    let visuals = ui.visuals();
+    let text_color = visuals
+        .override_text_color
+        .unwrap_or(visuals.selection.stroke.color);
+    ui.painter()
+        .with_clip_rect(outer_rect)
+        .galley(text_pos, galley, text_color);
+}
@@ -0,0 +1,167 @@
+use crate::*;
+
+/// A row of stars (or other glyphs) for picking a rating, e.g. 3.5 out of 5.
+///
+/// Hovering the stars previews the rating under the pointer, and clicking sets it.
+/// When the widget has keyboard focus, the arrow keys step the rating up or down.
+///
+/// The returned [`Response`] is marked as changed when the rating changes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut rating = 3.5;
+/// ui.add(egui::Rating::new(&mut rating).max(5).half_steps(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Rating<'a> {
+    value: &'a mut f32,
+    max: u32,
+    half_steps: bool,
+    glyph: char,
+    size: Option<f32>,
+}
+
+impl<'a> Rating<'a> {
+    pub fn new(value: &'a mut f32) -> Self {
+        Self {
+            value,
+            max: 5,
+            half_steps: false,
+            glyph: '★',
+            size: None,
+        }
+    }
+
+    /// The number of glyphs, i.e. the highest possible rating. Default: `5`.
+    #[inline]
+    pub fn max(mut self, max: u32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Allow ratings like `3.5`, by clicking the left half of a glyph. Default: `false`.
+    #[inline]
+    pub fn half_steps(mut self, half_steps: bool) -> Self {
+        self.half_steps = half_steps;
+        self
+    }
+
+    /// The glyph to show for each step. Default: `'★'`.
+    #[inline]
+    pub fn glyph(mut self, glyph: char) -> Self {
+        self.glyph = glyph;
+        self
+    }
+
+    /// Width and height of each glyph.
+    ///
+    /// Default: [`crate::style::Spacing::interact_size`]`.y`.
+    #[inline]
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+}
+
+impl<'a> Widget for Rating<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            value,
+            max,
+            half_steps,
+            glyph,
+            size,
+        } = self;
+
+        let size = size.unwrap_or(ui.spacing().interact_size.y);
+        let step = if half_steps { 0.5 } else { 1.0 };
+        let max_value = max as f32;
+
+        let desired_size = vec2(size * max_value, size);
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        // The rating you would get by clicking at this position:
+        let value_at = |pos: Pos2| {
+            let x = ((pos.x - rect.left()) / size).clamp(0.0, max_value);
+            ((x / step).floor() + 1.0) * step
+        };
+
+        let old_value = *value;
+        let mut new_value = old_value;
+
+        if response.clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                new_value = value_at(pos);
+            }
+        }
+
+        if response.has_focus() {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        vertical_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+            let change = ui.input_mut(|i| {
+                i.count_and_consume_key(Modifiers::NONE, Key::ArrowUp) as f32
+                    + i.count_and_consume_key(Modifiers::NONE, Key::ArrowRight) as f32
+                    - i.count_and_consume_key(Modifiers::NONE, Key::ArrowDown) as f32
+                    - i.count_and_consume_key(Modifiers::NONE, Key::ArrowLeft) as f32
+            });
+            if change != 0.0 {
+                new_value = ((new_value / step).round() + change) * step;
+            }
+        }
+
+        new_value = new_value.clamp(0.0, max_value);
+        if new_value != old_value {
+            *value = new_value;
+            response.mark_changed();
+        }
+
+        response.widget_info(|| WidgetInfo::slider(*value as f64, ""));
+
+        if ui.is_rect_visible(rect) {
+            let shown_value = response.hover_pos().map_or(*value, value_at);
+            let full_color = ui.visuals().selection.bg_fill;
+            let empty_color = ui.visuals().weak_text_color();
+            let font_id = FontId::proportional(size);
+
+            for i in 0..max {
+                let glyph_rect =
+                    Rect::from_min_size(rect.min + vec2(i as f32 * size, 0.0), Vec2::splat(size));
+                let center = glyph_rect.center();
+                let fill = (shown_value - i as f32).clamp(0.0, 1.0);
+
+                if fill < 1.0 {
+                    ui.painter().text(
+                        center,
+                        Align2::CENTER_CENTER,
+                        glyph,
+                        font_id.clone(),
+                        empty_color,
+                    );
+                }
+                if fill > 0.0 {
+                    // Only paint the filled part of a partially filled glyph:
+                    let mut filled_rect = glyph_rect;
+                    filled_rect.max.x = lerp(glyph_rect.x_range(), fill);
+                    ui.painter().with_clip_rect(filled_rect).text(
+                        center,
+                        Align2::CENTER_CENTER,
+                        glyph,
+                        font_id.clone(),
+                        full_color,
+                    );
+                }
+            }
+        }
+
+        response
+    }
+}
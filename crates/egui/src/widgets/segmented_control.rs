@@ -0,0 +1,197 @@
+use crate::*;
+
+/// A connected row of segments, of which exactly one is selected.
+///
+/// This is like a row of [`Ui::selectable_value`], but drawn as a single control,
+/// with only the outer corners rounded.
+///
+/// Click a segment to select it.
+/// When the control has keyboard focus, the left and right arrow keys select the previous and next segment.
+///
+/// The returned [`Response`] is marked as changed when the selection changes.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// #[derive(Clone, PartialEq)]
+/// enum View { List, Grid, Columns }
+/// # let mut view = View::List;
+///
+/// ui.add(
+///     egui::SegmentedControl::new(&mut view)
+///         .segment(View::List, "List")
+///         .segment(View::Grid, "Grid")
+///         .segment(View::Columns, "Columns"),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct SegmentedControl<'a, T> {
+    current: &'a mut T,
+    segments: Vec<(T, WidgetText)>,
+    equal_width: bool,
+}
+
+impl<'a, T: Clone + PartialEq> SegmentedControl<'a, T> {
+    pub fn new(current: &'a mut T) -> Self {
+        Self {
+            current,
+            segments: Vec::new(),
+            equal_width: false,
+        }
+    }
+
+    /// Add a segment that selects `value`.
+    #[inline]
+    pub fn segment(mut self, value: T, text: impl Into<WidgetText>) -> Self {
+        self.segments.push((value, text.into()));
+        self
+    }
+
+    /// Make all segments as wide as the widest one,
+    /// instead of each being as wide as its text.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn equal_width(mut self, equal_width: bool) -> Self {
+        self.equal_width = equal_width;
+        self
+    }
+}
+
+impl<'a, T: Clone + PartialEq> Widget for SegmentedControl<'a, T> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            current,
+            segments,
+            equal_width,
+        } = self;
+
+        let button_padding = ui.spacing().button_padding;
+        let galleys: Vec<_> = segments
+            .iter()
+            .map(|(_, text)| {
+                text.clone().into_galley(
+                    ui,
+                    Some(TextWrapMode::Extend),
+                    f32::INFINITY,
+                    TextStyle::Button,
+                )
+            })
+            .collect();
+
+        let mut widths: Vec<f32> = galleys
+            .iter()
+            .map(|galley| galley.size().x + 2.0 * button_padding.x)
+            .collect();
+        if equal_width {
+            let widest = widths.iter().copied().fold(0.0, f32::max);
+            widths.iter_mut().for_each(|width| *width = widest);
+        }
+        let height = galleys
+            .iter()
+            .map(|galley| galley.size().y + 2.0 * button_padding.y)
+            .fold(ui.spacing().interact_size.y, f32::max);
+
+        let desired_size = vec2(widths.iter().sum(), height);
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        let mut segment_rects = Vec::with_capacity(widths.len());
+        let mut left = rect.left();
+        for width in &widths {
+            segment_rects.push(Rect::from_x_y_ranges(left..=left + width, rect.y_range()));
+            left += width;
+        }
+        let segment_at = |pos: Pos2| segment_rects.iter().position(|r| r.contains(pos));
+
+        let old_index = segments.iter().position(|(value, _)| value == current);
+        let mut new_index = old_index;
+
+        if response.clicked() {
+            if let Some(index) = response.interact_pointer_pos().and_then(segment_at) {
+                new_index = Some(index);
+            }
+        }
+
+        if response.has_focus() && !segments.is_empty() {
+            ui.memory_mut(|m| {
+                m.set_focus_lock_filter(
+                    response.id,
+                    EventFilter {
+                        horizontal_arrows: true,
+                        ..Default::default()
+                    },
+                );
+            });
+            let (left_presses, right_presses) = ui.input_mut(|i| {
+                (
+                    i.count_and_consume_key(Modifiers::NONE, Key::ArrowLeft),
+                    i.count_and_consume_key(Modifiers::NONE, Key::ArrowRight),
+                )
+            });
+            if left_presses + right_presses > 0 {
+                let index = new_index.map_or(0, |index| {
+                    (index + right_presses).saturating_sub(left_presses)
+                });
+                new_index = Some(index.min(segments.len() - 1));
+            }
+        }
+
+        if new_index != old_index {
+            if let Some(index) = new_index {
+                *current = segments[index].0.clone();
+                response.mark_changed();
+            }
+        }
+
+        response.widget_info(|| {
+            WidgetInfo::labeled(
+                WidgetType::Other,
+                new_index.map_or("", |index| galleys[index].text()),
+            )
+        });
+
+        if ui.is_rect_visible(rect) {
+            let frame_visuals = ui.style().interact(&response);
+            let rounding = frame_visuals.rounding;
+            let hovered_index = response.hover_pos().and_then(segment_at);
+
+            for (i, (galley, segment_rect)) in galleys.into_iter().zip(&segment_rects).enumerate() {
+                let widgets = &ui.visuals().widgets;
+                let (fill, text_color) = if Some(i) == new_index {
+                    let selection = &ui.visuals().selection;
+                    (selection.bg_fill, selection.stroke.color)
+                } else if Some(i) == hovered_index {
+                    (widgets.hovered.weak_bg_fill, widgets.hovered.text_color())
+                } else {
+                    (widgets.inactive.weak_bg_fill, widgets.inactive.text_color())
+                };
+
+                // Only the outer corners of the control are rounded:
+                let segment_rounding = rounding.segment(i, segments.len());
+                ui.painter()
+                    .rect_filled(*segment_rect, segment_rounding, fill);
+
+                if i != 0 {
+                    ui.painter().vline(
+                        segment_rect.left(),
+                        segment_rect.y_range().shrink(button_padding.y + 2.0),
+                        ui.visuals().widgets.noninteractive.bg_stroke,
+                    );
+                }
+
+                let text_pos = Align2::CENTER_CENTER
+                    .align_size_within_rect(galley.size(), *segment_rect)
+                    .min;
+                ui.painter().galley(text_pos, galley, text_color);
+            }
+
+            ui.painter().rect_stroke(
+                rect.expand(frame_visuals.expansion),
+                rounding,
+                frame_visuals.bg_stroke,
+            );
+        }
+
+        response
+    }
+}
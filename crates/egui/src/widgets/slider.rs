@@ -4,6 +4,8 @@ use std::ops::RangeInclusive;
 
 use crate::{style::HandleShape, *};
 
+use super::drag_value::{get, get_integer, set, set_integer, ValueAccess};
+
 // ----------------------------------------------------------------------------
 
 type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
@@ -11,20 +13,6 @@ type NumParser<'a> = Box<dyn 'a + Fn(&str) -> Option<f64>>;
 
 // ----------------------------------------------------------------------------
 
-/// Combined into one function (rather than two) to make it easier
-/// for the borrow checker.
-type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
-
-fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
-    (get_set_value)(None)
-}
-
-fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
-    (get_set_value)(Some(value));
-}
-
-// ----------------------------------------------------------------------------
-
 #[derive(Clone)]
 struct SliderSpec {
     logarithmic: bool,
@@ -67,8 +55,11 @@ pub enum SliderOrientation {
 /// The default [`Slider`] size is set by [`crate::style::Spacing::slider_width`].
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Slider<'a> {
-    get_set_value: GetSetValue<'a>,
+    access: ValueAccess<'a>,
     range: RangeInclusive<f64>,
+
+    /// The exact range, if the value is an integer.
+    integer_range: Option<RangeInclusive<i128>>,
     spec: SliderSpec,
     clamp_to_range: bool,
     smart_aim: bool,
@@ -94,12 +85,14 @@ impl<'a> Slider<'a> {
     /// Creates a new horizontal slider.
     pub fn new<Num: emath::Numeric>(value: &'a mut Num, range: RangeInclusive<Num>) -> Self {
         let range_f64 = range.start().to_f64()..=range.end().to_f64();
-        let slf = Self::from_get_set(range_f64, move |v: Option<f64>| {
-            if let Some(v) = v {
-                *value = Num::from_f64(v);
-            }
-            value.to_f64()
-        });
+        let mut slf = Self::from_access(range_f64, ValueAccess::new(value));
+        if matches!(slf.access, ValueAccess::Integer { .. }) {
+            slf.integer_range = range
+                .start()
+                .to_i128()
+                .zip(range.end().to_i128())
+                .map(|(start, end)| start..=end);
+        }
 
         if Num::INTEGRAL {
             slf.integer()
@@ -112,9 +105,14 @@ impl<'a> Slider<'a> {
         range: RangeInclusive<f64>,
         get_set_value: impl 'a + FnMut(Option<f64>) -> f64,
     ) -> Self {
+        Self::from_access(range, ValueAccess::Float(Box::new(get_set_value)))
+    }
+
+    fn from_access(range: RangeInclusive<f64>, access: ValueAccess<'a>) -> Self {
         Self {
-            get_set_value: Box::new(get_set_value),
+            access,
             range,
+            integer_range: None,
             spec: SliderSpec {
                 logarithmic: false,
                 smallest_positive: 1e-6,
@@ -519,7 +517,7 @@ impl<'a> Slider<'a> {
     }
 
     fn get_value(&mut self) -> f64 {
-        let value = get(&mut self.get_set_value);
+        let value = get(&mut self.access);
         if self.clamp_to_range {
             let start = *self.range.start();
             let end = *self.range.end();
@@ -542,7 +540,41 @@ impl<'a> Slider<'a> {
             let start = *self.range.start();
             value = start + ((value - start) / step).round() * step;
         }
-        set(&mut self.get_set_value, value);
+        set(&mut self.access, value);
+    }
+
+    /// The exact value, if it is an integer.
+    fn get_integer(&mut self) -> Option<i128> {
+        let value = get_integer(&mut self.access)?;
+        match &self.integer_range {
+            Some(range) if self.clamp_to_range => {
+                let (start, end) = (*range.start(), *range.end());
+                Some(value.clamp(start.min(end), start.max(end)))
+            }
+            _ => Some(value),
+        }
+    }
+
+    /// Like [`Self::set_value`], but exact for huge integers.
+    fn set_integer(&mut self, mut value: i128) {
+        if let Some(range) = &self.integer_range {
+            let (start, end) = (*range.start(), *range.end());
+            if self.clamp_to_range {
+                value = value.clamp(start.min(end), start.max(end));
+            }
+            if let Some(step) = self.integer_step() {
+                let offset = value.saturating_sub(start);
+                let rounded = offset.saturating_add(offset.signum() * step / 2) / step * step;
+                value = start.saturating_add(rounded);
+            }
+        }
+        set_integer(&mut self.access, value);
+    }
+
+    /// [`Self::step`] in units of the exact integer mantissa, if it is a whole number of those.
+    fn integer_step(&self) -> Option<i128> {
+        let step = self.step?.abs() * self.access.scale();
+        (step.fract() == 0.0 && 1.0 <= step).then_some(step as i128)
     }
 
     fn clamp_range(&self) -> RangeInclusive<f64> {
@@ -645,7 +677,13 @@ impl<'a> Slider<'a> {
 
         let kb_step = increment as f32 - decrement as f32;
 
-        if kb_step != 0.0 {
+        if let (true, Some(step), Some(prev_integer)) =
+            (kb_step != 0.0, self.integer_step(), self.get_integer())
+        {
+            // Step in integer space, so huge integers stay exact:
+            let delta = (kb_step as i128).saturating_mul(step);
+            self.set_integer(prev_integer.saturating_add(delta));
+        } else if kb_step != 0.0 {
             let ui_point_per_step = 1.0; // move this many ui points for each kb_step
             let prev_value = self.get_value();
             let prev_position = self.position_from_value(prev_value, position_range);
@@ -814,6 +852,40 @@ impl<'a> Slider<'a> {
                 .unwrap_or_else(|| self.current_gradient(position_range))
         };
 
+        if let Some(old_integer) = self.get_integer() {
+            // Keep huge integers exact:
+            let mut integer = old_integer;
+            let decimals = self.access.decimals();
+            let mut dv = DragValue::from_access(ValueAccess::Integer {
+                get_set: Box::new(|v: Option<i128>| {
+                    if let Some(v) = v {
+                        integer = v;
+                    }
+                    integer
+                }),
+                decimals,
+            })
+            .speed(speed)
+            .min_decimals(self.min_decimals)
+            .max_decimals_opt(self.max_decimals)
+            .suffix(self.suffix.clone())
+            .prefix(self.prefix.clone());
+            if let (Some(range), true) = (&self.integer_range, self.clamp_to_range) {
+                dv = dv.integer_clamp_range(range.clone(), decimals);
+            }
+            if let Some(fmt) = &self.custom_formatter {
+                dv = dv.custom_formatter(fmt);
+            };
+            if let Some(parser) = &self.custom_parser {
+                dv = dv.custom_parser(parser);
+            }
+            let response = ui.add(dv);
+            if integer != old_integer {
+                self.set_integer(integer);
+            }
+            return response;
+        }
+
         let mut value = self.get_value();
         let response = ui.add({
             let mut dv = DragValue::new(&mut value)
@@ -80,6 +80,8 @@ pub struct TextEdit<'t> {
     clip_text: bool,
     char_limit: usize,
     return_key: Option<KeyboardShortcut>,
+    context_menu: bool,
+    extra_context_menu_items: Option<Box<dyn FnOnce(&mut Ui) + 't>>,
 }
 
 impl<'t> WidgetWithState for TextEdit<'t> {
@@ -138,6 +140,8 @@ impl<'t> TextEdit<'t> {
             clip_text: false,
             char_limit: usize::MAX,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
+            context_menu: true,
+            extra_context_menu_items: None,
         }
     }
 
@@ -369,6 +373,43 @@ impl<'t> TextEdit<'t> {
         self.return_key = return_key.into();
         self
     }
+
+    /// Show a context menu with Cut, Copy, Paste, Select All, Undo and Redo
+    /// when the [`TextEdit`] is right-clicked.
+    ///
+    /// If you call [`Response::context_menu`] on the response, your menu is shown instead.
+    /// To add to this menu, use [`Self::extra_context_menu_items`].
+    ///
+    /// "Paste" is disabled when the integration reports an empty clipboard
+    /// in [`crate::RawInput::clipboard_has_text`].
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn context_menu(mut self, context_menu: bool) -> Self {
+        self.context_menu = context_menu;
+        self
+    }
+
+    /// Add your own items to the end of the context menu.
+    ///
+    /// This also turns on the [`Self::context_menu`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut text = String::new();
+    /// ui.add(egui::TextEdit::singleline(&mut text).extra_context_menu_items(|ui| {
+    ///     if ui.button("Insert date").clicked() {
+    ///         ui.close_menu();
+    ///     }
+    /// }));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn extra_context_menu_items(mut self, add_items: impl FnOnce(&mut Ui) + 't) -> Self {
+        self.context_menu = true;
+        self.extra_context_menu_items = Some(Box::new(add_items));
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -467,6 +508,8 @@ impl<'t> TextEdit<'t> {
             clip_text,
             char_limit,
             return_key,
+            context_menu,
+            extra_context_menu_items,
         } = self;
 
         let text_color = text_color
@@ -564,14 +607,22 @@ impl<'t> TextEdit<'t> {
                     text_selection::visuals::paint_cursor_end(&painter, ui.visuals(), cursor_rect);
                 }
 
+                // Right-clicking the selection keeps it, for the context menu:
+                let context_click_on_selection = context_menu
+                    && ui.input(|i| i.pointer.button_pressed(PointerButton::Secondary))
+                    && state.cursor.range(&galley).map_or(false, |range| {
+                        !range.is_empty() && range.contains(&CursorRange::one(cursor_at_pointer))
+                    });
+
                 let is_being_dragged = ui.ctx().is_being_dragged(response.id);
-                let did_interact = state.cursor.pointer_interaction(
-                    ui,
-                    &response,
-                    cursor_at_pointer,
-                    &galley,
-                    is_being_dragged,
-                );
+                let did_interact = !context_click_on_selection
+                    && state.cursor.pointer_interaction(
+                        ui,
+                        &response,
+                        cursor_at_pointer,
+                        &galley,
+                        is_being_dragged,
+                    );
 
                 if did_interact {
                     ui.memory_mut(|mem| mem.request_focus(response.id));
@@ -583,9 +634,27 @@ impl<'t> TextEdit<'t> {
             ui.ctx().set_cursor_icon(CursorIcon::Text);
         }
 
+        // Clicking a menu item takes the focus away from the text edit,
+        // so we apply the menu actions even without focus:
+        let mut menu_events = Vec::new();
+        if interactive && context_menu {
+            menu::builtin_context_menu(&response, |ui| {
+                context_menu_ui(
+                    ui,
+                    &state,
+                    text,
+                    &galley,
+                    id,
+                    password,
+                    extra_context_menu_items,
+                    &mut menu_events,
+                );
+            });
+        }
+
         let mut cursor_range = None;
         let prev_cursor_range = state.cursor.range(&galley);
-        if interactive && ui.memory(|mem| mem.has_focus(id)) {
+        if interactive && (ui.memory(|mem| mem.has_focus(id)) || !menu_events.is_empty()) {
             ui.memory_mut(|mem| mem.set_focus_lock_filter(id, event_filter));
 
             let default_cursor_range = if cursor_at_end {
@@ -608,6 +677,7 @@ impl<'t> TextEdit<'t> {
                 char_limit,
                 event_filter,
                 return_key,
+                menu_events,
             );
 
             if changed {
@@ -797,6 +867,100 @@ impl<'t> TextEdit<'t> {
     }
 }
 
+/// The built-in context menu of a [`TextEdit`].
+///
+/// The actions are turned into the same events as their keyboard shortcuts,
+/// so that they are handled (and undone) the same way.
+#[allow(clippy::too_many_arguments)]
+fn context_menu_ui(
+    ui: &mut Ui,
+    state: &TextEditState,
+    text: &dyn TextBuffer,
+    galley: &Galley,
+    id: Id,
+    password: bool,
+    extra_items: Option<Box<dyn FnOnce(&mut Ui) + '_>>,
+    events: &mut Vec<Event>,
+) {
+    let cursor_range = state.cursor.range(galley).unwrap_or_default();
+    let has_selection = !cursor_range.is_empty();
+    let mutable = text.is_mutable();
+    let undo_state = (cursor_range.as_ccursor_range(), text.as_str().to_owned());
+    let (has_undo, has_redo) = {
+        let undoer = state.undoer.lock();
+        (undoer.has_undo(&undo_state), undoer.has_redo(&undo_state))
+    };
+
+    let key_event = |modifiers: Modifiers, key: Key| Event::Key {
+        key,
+        physical_key: None,
+        pressed: true,
+        repeat: false,
+        modifiers,
+    };
+    let item = |ui: &mut Ui, enabled: bool, label: &str, shortcut: KeyboardShortcut| {
+        let button = Button::new(label).shortcut_text(ui.ctx().format_shortcut(&shortcut));
+        let clicked = ui.add_enabled(enabled, button).clicked();
+        if clicked {
+            ui.memory_mut(|mem| mem.request_focus(id));
+            ui.close_menu();
+        }
+        clicked
+    };
+
+    let shortcut = KeyboardShortcut::new;
+    if item(
+        ui,
+        mutable && has_selection && !password,
+        "Cut",
+        shortcut(Modifiers::COMMAND, Key::X),
+    ) {
+        events.push(Event::Cut);
+    }
+    if item(
+        ui,
+        has_selection && !password,
+        "Copy",
+        shortcut(Modifiers::COMMAND, Key::C),
+    ) {
+        events.push(Event::Copy);
+    }
+    // egui can't read the clipboard, so we ask the integration to paste it:
+    let can_paste = ui.input(|i| i.raw.clipboard_has_text) != Some(false);
+    if item(
+        ui,
+        mutable && can_paste,
+        "Paste",
+        shortcut(Modifiers::COMMAND, Key::V),
+    ) {
+        ui.ctx().send_viewport_cmd(ViewportCommand::RequestPaste);
+    }
+    if item(
+        ui,
+        !text.as_str().is_empty(),
+        "Select All",
+        shortcut(Modifiers::COMMAND, Key::A),
+    ) {
+        events.push(key_event(Modifiers::COMMAND, Key::A));
+    }
+
+    if mutable {
+        ui.separator();
+        if item(ui, has_undo, "Undo", shortcut(Modifiers::COMMAND, Key::Z)) {
+            events.push(key_event(Modifiers::COMMAND, Key::Z));
+        }
+        let redo = Modifiers::COMMAND | Modifiers::SHIFT;
+        if item(ui, has_redo, "Redo", shortcut(redo, Key::Z)) {
+            events.push(key_event(redo, Key::Z));
+        }
+    }
+
+    if let Some(extra_items) = extra_items {
+        ui.separator();
+        extra_items(ui);
+    }
+}
+
 fn mask_if_password(is_password: bool, text: &str) -> String {
     fn mask_password(text: &str) -> String {
         std::iter::repeat(epaint::text::PASSWORD_REPLACEMENT_CHAR)
@@ -829,11 +993,16 @@ fn events(
     char_limit: usize,
     event_filter: EventFilter,
     return_key: Option<KeyboardShortcut>,
+    menu_events: Vec<Event>,
 ) -> (bool, CursorRange) {
     let os = ui.ctx().os();
 
     let mut cursor_range = state.cursor.range(galley).unwrap_or(default_cursor_range);
 
+    if let Some(max_undos) = ui.ctx().options(|o| o.max_text_edit_undos) {
+        state.undoer.lock().set_max_undos(max_undos);
+    }
+
     // We feed state to the undoer both before and after handling input
     // so that the undoer creates automatic saves even when there are no events for a while.
     state.undoer.lock().feed_state(
@@ -849,7 +1018,8 @@ fn events(
 
     let mut any_change = false;
 
-    let events = ui.input(|i| i.filtered_events(&event_filter));
+    let mut events = ui.input(|i| i.filtered_events(&event_filter));
+    events.extend(menu_events);
     for event in &events {
         let did_mutate_text = match event {
             // First handle events that only changes the selection cursor, not the text:
@@ -1122,3 +1292,93 @@ fn check_for_mutating_key_press(
         _ => None,
     }
 }
+
+#[test]
+fn test_context_menu() {
+    fn run(
+        harness: &TestHarness,
+        text: &mut String,
+        custom_menu: bool,
+        events: Vec<Event>,
+    ) -> (Rect, FullOutput) {
+        let input = RawInput {
+            events,
+            ..Default::default()
+        };
+        harness.run_input(input, |ctx| {
+            CentralPanel::default()
+                .show(ctx, |ui| {
+                    let response = ui.text_edit_singleline(text);
+                    if custom_menu {
+                        response.context_menu(|ui| {
+                            ui.label("Custom");
+                        });
+                    }
+                    response.rect
+                })
+                .inner
+        })
+    }
+
+    /// Where the menu item with this label was painted.
+    fn item_pos(output: &FullOutput, label: &str) -> Option<Pos2> {
+        output
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                Shape::Text(shape) if shape.galley.text() == label => {
+                    Some(shape.visual_bounding_rect().center())
+                }
+                _ => None,
+            })
+    }
+
+    fn right_click(pos: Pos2) -> Vec<Event> {
+        vec![
+            Event::PointerMoved(pos),
+            TestHarness::button_event(pos, PointerButton::Secondary, true),
+            TestHarness::button_event(pos, PointerButton::Secondary, false),
+        ]
+    }
+
+    let harness = TestHarness::with_default_fonts();
+    let mut text = String::from("hello");
+    let (rect, _) = run(&harness, &mut text, false, vec![]);
+
+    // Opens the menu, then clicks the item, returning the output of that frame:
+    let choose = |text: &mut String, label: &str| {
+        run(
+            &harness,
+            text,
+            false,
+            vec![Event::PointerMoved(rect.center())],
+        );
+        run(&harness, text, false, right_click(rect.center()));
+        let (_, output) = run(&harness, text, false, vec![]);
+        assert!(harness.ctx.is_context_menu_open());
+        let pos = item_pos(&output, label).unwrap();
+        run(&harness, text, false, vec![Event::PointerMoved(pos)]);
+        let (_, output) = run(&harness, text, false, TestHarness::click_events(pos));
+        assert!(!harness.ctx.is_context_menu_open());
+        output
+    };
+
+    choose(&mut text, "Select All");
+    let output = choose(&mut text, "Copy");
+    assert_eq!(output.platform_output.copied_text, "hello");
+
+    let output = choose(&mut text, "Paste");
+    let commands = &output.viewport_output[&ViewportId::ROOT].commands;
+    assert!(commands.contains(&ViewportCommand::RequestPaste));
+
+    let output = choose(&mut text, "Cut");
+    assert_eq!(output.platform_output.copied_text, "hello");
+    assert_eq!(text, "");
+
+    // A context menu of your own replaces the built-in one:
+    run(&harness, &mut text, true, vec![]);
+    run(&harness, &mut text, true, right_click(rect.center()));
+    let (_, output) = run(&harness, &mut text, true, vec![]);
+    assert!(item_pos(&output, "Custom").is_some());
+    assert!(item_pos(&output, "Paste").is_none());
+}
@@ -0,0 +1,141 @@
+use crate::*;
+
+/// A title bar for windows without decorations, i.e. made with `ViewportBuilder::with_decorations(false)`.
+///
+/// Dragging the title bar moves the native window, and double-clicking it maximizes or restores the window.
+/// It has buttons to minimize, maximize and close the window.
+///
+/// The title bar takes up the full width available in the [`Ui`].
+/// The returned [`Response`] is for the draggable title region.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// ui.add(egui::TitleBar::new("My app"));
+/// ui.label("The contents of the window");
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct TitleBar {
+    title: WidgetText,
+    height: f32,
+    minimize_button: bool,
+    maximize_button: bool,
+    close_button: bool,
+}
+
+impl TitleBar {
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        Self {
+            title: title.into(),
+            height: 32.0,
+            minimize_button: true,
+            maximize_button: true,
+            close_button: true,
+        }
+    }
+
+    /// Default: `32.0`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Show a button that minimizes the window. Default: `true`.
+    #[inline]
+    pub fn minimize_button(mut self, minimize_button: bool) -> Self {
+        self.minimize_button = minimize_button;
+        self
+    }
+
+    /// Show a button that maximizes (or restores) the window. Default: `true`.
+    ///
+    /// This also decides if double-clicking the title bar maximizes the window.
+    #[inline]
+    pub fn maximize_button(mut self, maximize_button: bool) -> Self {
+        self.maximize_button = maximize_button;
+        self
+    }
+
+    /// Show a button that closes the window. Default: `true`.
+    #[inline]
+    pub fn close_button(mut self, close_button: bool) -> Self {
+        self.close_button = close_button;
+        self
+    }
+}
+
+impl Widget for TitleBar {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            title,
+            height,
+            minimize_button,
+            maximize_button,
+            close_button,
+        } = self;
+
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(ui.available_width(), height), Sense::click_and_drag());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Other, title.text()));
+
+        let is_maximized = ui.input(|i| i.viewport().maximized.unwrap_or(false));
+
+        if maximize_button && response.double_clicked() {
+            ui.ctx()
+                .send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+        } else if response.drag_started_by(PointerButton::Primary) {
+            ui.ctx().send_viewport_cmd(ViewportCommand::StartDrag);
+        }
+
+        if ui.is_rect_visible(rect) {
+            let galley = title.into_galley(
+                ui,
+                Some(TextWrapMode::Truncate),
+                rect.width(),
+                TextStyle::Heading,
+            );
+            let text_pos = Align2::CENTER_CENTER
+                .align_size_within_rect(galley.size(), rect)
+                .min;
+            ui.painter()
+                .galley(text_pos, galley, ui.visuals().text_color());
+
+            ui.painter().hline(
+                rect.x_range().shrink(1.0),
+                rect.bottom(),
+                ui.visuals().widgets.noninteractive.bg_stroke,
+            );
+        }
+
+        let mut buttons_ui = ui.child_ui(rect, Layout::right_to_left(Align::Center), None);
+        buttons_ui.spacing_mut().item_spacing.x = 0.0;
+        buttons_ui.visuals_mut().button_frame = false;
+        buttons_ui.add_space(8.0);
+        let button = |ui: &mut Ui, icon: &str, hover_text: &str| {
+            ui.add(Button::new(RichText::new(icon).size(12.0)))
+                .on_hover_text(hover_text)
+                .clicked()
+        };
+
+        if close_button && button(&mut buttons_ui, "❌", "Close the window") {
+            ui.ctx().send_viewport_cmd(ViewportCommand::Close);
+        }
+        if maximize_button {
+            let hover_text = if is_maximized {
+                "Restore window"
+            } else {
+                "Maximize window"
+            };
+            if button(&mut buttons_ui, "🗗", hover_text) {
+                ui.ctx()
+                    .send_viewport_cmd(ViewportCommand::Maximized(!is_maximized));
+            }
+        }
+        if minimize_button && button(&mut buttons_ui, "🗕", "Minimize the window") {
+            ui.ctx().send_viewport_cmd(ViewportCommand::Minimized(true));
+        }
+
+        response
+    }
+}
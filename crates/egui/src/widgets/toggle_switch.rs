@@ -0,0 +1,102 @@
+use crate::*;
+
+/// iOS-style on/off switch, with an optional text label.
+///
+/// It behaves like a [`Checkbox`], but is reported to screen readers as a switch.
+/// The size of the switch is [`crate::style::Spacing::switch_size`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut dark_mode = true;
+/// ui.add(egui::ToggleSwitch::new(&mut dark_mode, "Dark mode"));
+/// ui.add(egui::ToggleSwitch::without_text(&mut dark_mode));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct ToggleSwitch<'a> {
+    on: &'a mut bool,
+    text: WidgetText,
+}
+
+impl<'a> ToggleSwitch<'a> {
+    pub fn new(on: &'a mut bool, text: impl Into<WidgetText>) -> Self {
+        Self {
+            on,
+            text: text.into(),
+        }
+    }
+
+    pub fn without_text(on: &'a mut bool) -> Self {
+        Self::new(on, WidgetText::default())
+    }
+}
+
+impl<'a> Widget for ToggleSwitch<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self { on, text } = self;
+
+        let spacing = ui.spacing();
+        let switch_size = spacing.switch_size;
+        let icon_spacing = spacing.icon_spacing;
+
+        let (galley, mut desired_size) = if text.is_empty() {
+            (None, switch_size)
+        } else {
+            let total_extra = vec2(switch_size.x + icon_spacing, 0.0);
+
+            let wrap_width = ui.available_width() - total_extra.x;
+            let galley = text.into_galley(ui, None, wrap_width, TextStyle::Button);
+
+            let desired_size = (total_extra + galley.size()).at_least(spacing.interact_size);
+            (Some(galley), desired_size)
+        };
+        desired_size.y = desired_size.y.max(switch_size.y);
+
+        let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
+
+        if response.clicked() {
+            *on = !*on;
+            response.mark_changed();
+        }
+        response.widget_info(|| {
+            WidgetInfo::selected(
+                WidgetType::Switch,
+                *on,
+                galley.as_ref().map_or("", |x| x.text()),
+            )
+        });
+
+        if ui.is_rect_visible(rect) {
+            let how_on = ui.ctx().animate_bool_responsive(response.id, *on);
+            let visuals = ui.style().interact_selectable(&response, *on);
+
+            let switch_rect = Rect::from_min_size(
+                pos2(rect.left(), rect.center().y - 0.5 * switch_size.y),
+                switch_size,
+            )
+            .expand(visuals.expansion);
+            let radius = 0.5 * switch_rect.height();
+            ui.painter()
+                .rect(switch_rect, radius, visuals.bg_fill, visuals.bg_stroke);
+
+            let circle_x = lerp(
+                (switch_rect.left() + radius)..=(switch_rect.right() - radius),
+                how_on,
+            );
+            let center = pos2(circle_x, switch_rect.center().y);
+            ui.painter()
+                .circle(center, 0.75 * radius, visuals.bg_fill, visuals.fg_stroke);
+
+            if let Some(galley) = galley {
+                let text_pos = pos2(
+                    rect.min.x + switch_size.x + icon_spacing,
+                    rect.center().y - 0.5 * galley.size().y,
+                );
+                let text_color = ui.style().interact(&response).text_color();
+                ui.painter().galley(text_pos, galley, text_color);
+            }
+        }
+
+        response
+    }
+}
@@ -0,0 +1,202 @@
+use crate::*;
+
+/// Colors of the X, Y, Z and W axis labels.
+const AXIS_COLORS: [Color32; 4] = [
+    Color32::from_rgb(230, 90, 90),
+    Color32::from_rgb(110, 200, 90),
+    Color32::from_rgb(90, 140, 240),
+    Color32::from_rgb(180, 180, 180),
+];
+
+/// Edit a vector as a row of [`DragValue`]s, one per component, each with a colored axis label.
+///
+/// Right-click a component to reset the whole vector to its default value (see [`Self::default_values`]).
+/// With [`Self::uniform_lock`] there is a button for locking the components together,
+/// so that changing one of them scales all the others by the same factor.
+///
+/// See also [`Ui::vec2_edit`], [`Ui::pos2_edit`], [`Ui::vec3_edit`], [`Ui::vec4_edit`] and [`Ui::rect_edit`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut scale = egui::vec2(1.0, 1.0);
+/// ui.add(
+///     egui::VectorEdit::new([&mut scale.x, &mut scale.y])
+///         .speed(0.01)
+///         .default_values([1.0, 1.0])
+///         .uniform_lock(true),
+/// );
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct VectorEdit<'a> {
+    values: Vec<&'a mut f32>,
+    labels: Vec<String>,
+    axis_colors: bool,
+    speed: f64,
+    default_values: Vec<f32>,
+    uniform_lock: bool,
+}
+
+impl<'a> VectorEdit<'a> {
+    /// The components to edit, e.g. `[&mut v.x, &mut v.y]` or `&mut [f32; 3]`.
+    pub fn new(values: impl IntoIterator<Item = &'a mut f32>) -> Self {
+        let values: Vec<&'a mut f32> = values.into_iter().collect();
+        let labels = (0..values.len())
+            .map(|i| {
+                ["X", "Y", "Z", "W"]
+                    .get(i)
+                    .map_or(i.to_string(), |l| (*l).to_owned())
+            })
+            .collect();
+        Self {
+            values,
+            labels,
+            axis_colors: true,
+            speed: 0.1,
+            default_values: vec![],
+            uniform_lock: false,
+        }
+    }
+
+    /// The labels of the components.
+    ///
+    /// Default: `X`, `Y`, `Z`, `W`.
+    #[inline]
+    pub fn labels(mut self, labels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        for (label, new_label) in self.labels.iter_mut().zip(labels) {
+            *label = new_label.into();
+        }
+        self
+    }
+
+    /// Color the labels red, green, blue and gray, like the axes of a 3D editor.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn axis_colors(mut self, axis_colors: bool) -> Self {
+        self.axis_colors = axis_colors;
+        self
+    }
+
+    /// How much the values change when dragged, see [`DragValue::speed`].
+    ///
+    /// Default: `0.1`.
+    #[inline]
+    pub fn speed(mut self, speed: impl Into<f64>) -> Self {
+        self.speed = speed.into();
+        self
+    }
+
+    /// What the components are reset to when right-clicked.
+    /// Missing values are `0.0`.
+    ///
+    /// Default: all `0.0`.
+    #[inline]
+    pub fn default_values(mut self, default_values: impl IntoIterator<Item = f32>) -> Self {
+        self.default_values = default_values.into_iter().collect();
+        self
+    }
+
+    /// Show a button for locking the components together, e.g. for a uniform scale.
+    ///
+    /// The lock is off until the user toggles it.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn uniform_lock(mut self, uniform_lock: bool) -> Self {
+        self.uniform_lock = uniform_lock;
+        self
+    }
+}
+
+impl<'a> Widget for VectorEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            mut values,
+            labels,
+            axis_colors,
+            speed,
+            default_values,
+            uniform_lock,
+        } = self;
+
+        let lock_id = ui.next_auto_id().with("uniform_lock");
+        let mut locked = uniform_lock && ui.data(|d| d.get_temp(lock_id)).unwrap_or(false);
+        let old_values: Vec<f32> = values.iter().map(|value| **value).collect();
+
+        let inner = ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = ui.spacing().item_spacing.x.min(4.0);
+
+            let mut response: Option<Response> = None;
+            let mut reset = false;
+            let mut changed_index = None;
+
+            for (i, (value, label)) in values.iter_mut().zip(&labels).enumerate() {
+                let mut label = RichText::new(label.as_str()).strong();
+                if axis_colors {
+                    label = label.color(AXIS_COLORS[i.min(AXIS_COLORS.len() - 1)]);
+                }
+                let label_response = ui.add(Label::new(label).sense(Sense::click()));
+                let drag_response = ui.add(DragValue::new(*value).speed(speed));
+
+                reset |= label_response.secondary_clicked() || drag_response.secondary_clicked();
+                if drag_response.changed() {
+                    changed_index = Some(i);
+                }
+
+                let component_response = label_response | drag_response;
+                response = Some(match response {
+                    Some(response) => response | component_response,
+                    None => component_response,
+                });
+            }
+
+            if uniform_lock {
+                let lock_response =
+                    ui.add(Button::new("🔗").selected(locked))
+                        .on_hover_text(if locked {
+                            "Unlock the components"
+                        } else {
+                            "Lock the components together"
+                        });
+                if lock_response.clicked() {
+                    locked = !locked;
+                }
+            }
+
+            (response, reset, changed_index)
+        });
+
+        let (response, reset, changed_index) = inner.inner;
+        let mut response = response.map_or(inner.response.clone(), |r| r.union(inner.response));
+        response.changed = false;
+
+        if reset {
+            for (i, value) in values.iter_mut().enumerate() {
+                let default_value = default_values.get(i).copied().unwrap_or(0.0);
+                if **value != default_value {
+                    **value = default_value;
+                    response.changed = true;
+                }
+            }
+        } else if let Some(i) = changed_index {
+            response.changed = true;
+            // Scale all the others by the same factor.
+            // There is no factor when scaling from zero, so then only the edited one changes.
+            if locked && old_values[i] != 0.0 {
+                let factor = *values[i] / old_values[i];
+                for (j, value) in values.iter_mut().enumerate() {
+                    if j != i {
+                        **value = old_values[j] * factor;
+                    }
+                }
+            }
+        }
+
+        if uniform_lock {
+            ui.data_mut(|d| d.insert_temp(lock_id, locked));
+        }
+
+        response
+    }
+}
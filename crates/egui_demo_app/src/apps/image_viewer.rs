@@ -78,6 +78,13 @@ impl eframe::App for ImageViewer {
             ui.add(Slider::new(&mut self.image_options.uv.max.x, 0.0..=1.0).text("max x"));
             ui.add(Slider::new(&mut self.image_options.uv.max.y, 0.0..=1.0).text("max y"));
 
+            // flip
+            ui.add_space(2.0);
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut self.image_options.flip.x, "Flip horizontal");
+                ui.checkbox(&mut self.image_options.flip.y, "Flip vertical");
+            });
+
             // rotation
             ui.add_space(2.0);
             let had_rotation = self.image_options.rotation.is_some();
@@ -195,6 +202,8 @@ impl eframe::App for ImageViewer {
             egui::ScrollArea::both().show(ui, |ui| {
                 let mut image = egui::Image::from_uri(&self.current_uri);
                 image = image.uv(self.image_options.uv);
+                image = image.flip_horizontal(self.image_options.flip.x);
+                image = image.flip_vertical(self.image_options.flip.y);
                 image = image.bg_fill(self.image_options.bg_fill);
                 image = image.tint(self.image_options.tint);
                 let (angle, origin) = self
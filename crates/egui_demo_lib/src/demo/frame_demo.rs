@@ -19,6 +19,7 @@ impl Default for FrameDemo {
                 },
                 fill: egui::Color32::from_rgba_unmultiplied(97, 0, 255, 128),
                 stroke: egui::Stroke::new(1.0, egui::Color32::GRAY),
+                ..Default::default()
             },
         }
     }
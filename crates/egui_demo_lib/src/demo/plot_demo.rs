@@ -4,9 +4,10 @@ use std::ops::RangeInclusive;
 use egui::*;
 
 use egui_plot::{
-    Arrows, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, CoordinatesFormatter, Corner,
-    GridInput, GridMark, HLine, Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint,
-    PlotPoints, PlotResponse, Points, Polygon, Text, VLine,
+    Annotation, Arrows, AxisHints, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Candle, Candlestick,
+    CoordinatesFormatter, Corner, ErrorBars, FillBetween, GridInput, GridMark, HLine, Heatmap,
+    Legend, Line, LineStyle, MarkerShape, Plot, PlotImage, PlotPoint, PlotPoints, PlotResponse,
+    PlotSelection, Points, Polygon, Text, VLine,
 };
 
 // ----------------------------------------------------------------------------
@@ -20,6 +21,9 @@ enum Panel {
     Items,
     Interaction,
     CustomAxes,
+    LogAxes,
+    SecondaryAxis,
+    TimeAxis,
     LinkedAxes,
 }
 
@@ -40,6 +44,9 @@ pub struct PlotDemo {
     items_demo: ItemsDemo,
     interaction_demo: InteractionDemo,
     custom_axes_demo: CustomAxesDemo,
+    log_axes_demo: LogAxesDemo,
+    secondary_axis_demo: SecondaryAxisDemo,
+    time_axis_demo: TimeAxisDemo,
     linked_axes_demo: LinkedAxesDemo,
     open_panel: Panel,
 }
@@ -86,6 +93,9 @@ impl super::View for PlotDemo {
             ui.selectable_value(&mut self.open_panel, Panel::Items, "Items");
             ui.selectable_value(&mut self.open_panel, Panel::Interaction, "Interaction");
             ui.selectable_value(&mut self.open_panel, Panel::CustomAxes, "Custom Axes");
+            ui.selectable_value(&mut self.open_panel, Panel::LogAxes, "Log Axes");
+            ui.selectable_value(&mut self.open_panel, Panel::SecondaryAxis, "Secondary Axis");
+            ui.selectable_value(&mut self.open_panel, Panel::TimeAxis, "Time Axis");
             ui.selectable_value(&mut self.open_panel, Panel::LinkedAxes, "Linked Axes");
         });
         ui.separator();
@@ -112,6 +122,15 @@ impl super::View for PlotDemo {
             Panel::CustomAxes => {
                 self.custom_axes_demo.ui(ui);
             }
+            Panel::LogAxes => {
+                self.log_axes_demo.ui(ui);
+            }
+            Panel::SecondaryAxis => {
+                self.secondary_axis_demo.ui(ui);
+            }
+            Panel::TimeAxis => {
+                self.time_axis_demo.ui(ui);
+            }
             Panel::LinkedAxes => {
                 self.linked_axes_demo.ui(ui);
             }
@@ -596,6 +615,123 @@ impl CustomAxesDemo {
 
 // ----------------------------------------------------------------------------
 
+#[derive(PartialEq)]
+struct LogAxesDemo {
+    log_x: bool,
+    log_y: bool,
+}
+
+impl Default for LogAxesDemo {
+    fn default() -> Self {
+        Self {
+            log_x: true,
+            log_y: true,
+        }
+    }
+}
+
+impl LogAxesDemo {
+    /// `f` sampled at even distances on a logarithmic x axis.
+    fn line(name: &str, f: impl Fn(f64) -> f64) -> Line {
+        let points: PlotPoints = (0..=500)
+            .map(|i| {
+                let x = 10.0_f64.powf(-2.0 + i as f64 / 100.0);
+                [x, f(x)]
+            })
+            .collect();
+        Line::new(points).name(name)
+    }
+
+    fn ui(&mut self, ui: &mut Ui) -> Response {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.log_x, "Logarithmic x axis");
+            ui.checkbox(&mut self.log_y, "Logarithmic y axis");
+        });
+
+        Plot::new("log_axes_demo")
+            .log_axes([self.log_x, self.log_y])
+            .legend(Legend::default())
+            .show(ui, |plot_ui| {
+                plot_ui.line(Self::line("x", |x| x));
+                plot_ui.line(Self::line("x²", |x| x * x));
+                plot_ui.line(Self::line("√x", |x| x.sqrt()));
+                plot_ui.line(Self::line("1 / x", |x| 1.0 / x));
+            })
+            .response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(PartialEq, Default)]
+struct SecondaryAxisDemo {}
+
+impl SecondaryAxisDemo {
+    #[allow(clippy::unused_self)]
+    fn ui(&mut self, ui: &mut Ui) -> Response {
+        // A day of weather, one value per ten minutes:
+        let hours = (0..=144).map(|i| i as f64 / 6.0);
+        let temperature: PlotPoints = hours
+            .clone()
+            .map(|h| [h, 15.0 - 6.0 * (TAU * h / 24.0).cos()])
+            .collect();
+        let humidity: PlotPoints = hours
+            .map(|h| [h, 70.0 + 20.0 * (TAU * h / 24.0).cos()])
+            .collect();
+
+        Plot::new("secondary_axis_demo")
+            .legend(Legend::default())
+            .x_axis_label("Hour")
+            .y_axis_label("Temperature (°C)")
+            .secondary_y_axis(AxisHints::new_y().label("Humidity (%)"))
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new(temperature).name("Temperature"));
+                plot_ui.line(Line::new(humidity).name("Humidity").secondary_y(true));
+            })
+            .response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(PartialEq, Default)]
+struct TimeAxisDemo {}
+
+impl TimeAxisDemo {
+    #[allow(clippy::unused_self)]
+    fn ui(&mut self, ui: &mut Ui) -> Response {
+        ui.label("Zoom in to go from years to months, days, hours and seconds.");
+
+        // Two years from 2024-01-01 00:00 UTC, one value per hour:
+        let start = 1_704_067_200.0;
+        let hour = 3600.0;
+        let values: PlotPoints = (0..2 * 365 * 24)
+            .map(|i| {
+                let t = i as f64;
+                let daily = (TAU * t / 24.0).sin();
+                let yearly = (TAU * t / (365.0 * 24.0)).sin();
+                [start + t * hour, 10.0 * yearly + 2.0 * daily]
+            })
+            .collect();
+
+        // Mark the highest and lowest value of the first year:
+        let quarter_year = 365.0 * 24.0 / 4.0 * hour;
+        let high = PlotPoint::new(start + quarter_year, 10.0);
+        let low = PlotPoint::new(start + 3.0 * quarter_year, -10.0);
+
+        Plot::new("time_axis_demo")
+            .x_time_axis()
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new(values));
+                plot_ui.annotation(Annotation::new(high, "Yearly high"));
+                plot_ui.annotation(Annotation::new(low, "Yearly low").offset([20.0, 30.0]));
+            })
+            .response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(PartialEq)]
 struct LinkedAxesDemo {
     link_x: bool,
@@ -714,6 +850,10 @@ impl ItemsDemo {
             100,
         ));
         let points = Points::new(sin_values).stems(-1.5).radius(1.0);
+        let band = FillBetween::new(
+            PlotPoints::from_explicit_callback(|x| -6.0 + 0.5 * x.sin() + 0.8, -TAU..TAU, 100),
+            PlotPoints::from_explicit_callback(|x| -6.0 + 0.5 * x.sin() - 0.8, -TAU..TAU, 100),
+        );
 
         let arrows = {
             let pos_radius = 8.0;
@@ -753,6 +893,7 @@ impl ItemsDemo {
             plot_ui.vline(VLine::new(-9.0).name("Lines vertical"));
             plot_ui.line(line.name("Line with fill"));
             plot_ui.polygon(polygon.name("Convex polygon"));
+            plot_ui.fill_between(band.name("Filled band"));
             plot_ui.points(points.name("Points with stems"));
             plot_ui.text(Text::new(PlotPoint::new(-3.0, -3.0), "wow").name("Text"));
             plot_ui.text(Text::new(PlotPoint::new(-2.0, 2.5), "so graph").name("Text"));
@@ -768,10 +909,11 @@ impl ItemsDemo {
 // ----------------------------------------------------------------------------
 
 #[derive(Default, PartialEq)]
-struct InteractionDemo {}
+struct InteractionDemo {
+    selection: Option<PlotSelection>,
+}
 
 impl InteractionDemo {
-    #[allow(clippy::unused_self)]
     fn ui(&mut self, ui: &mut Ui) -> Response {
         let id = ui.make_persistent_id("interaction_demo");
 
@@ -786,12 +928,16 @@ impl InteractionDemo {
             ));
         }
 
-        let plot = Plot::new("interaction_demo").id(id).height(300.0);
+        let plot = Plot::new("interaction_demo")
+            .id(id)
+            .height(300.0)
+            .box_selection(Modifiers::SHIFT);
 
         let PlotResponse {
             response,
             inner: (screen_pos, pointer_coordinate, pointer_coordinate_drag_delta, bounds, hovered),
             hovered_plot_item,
+            selection,
             ..
         } = plot.show(ui, |plot_ui| {
             plot_ui.line(
@@ -853,6 +999,21 @@ impl InteractionDemo {
         };
         ui.label(format!("hovered plot item: {hovered_item}"));
 
+        if selection.is_some() {
+            self.selection = selection;
+        }
+        let selection_text = if let Some(selection) = &self.selection {
+            format!(
+                "{} points, min: {:.02?}, max: {:.02?}",
+                selection.points.len(),
+                selection.bounds.min(),
+                selection.bounds.max()
+            )
+        } else {
+            "None (shift + right-drag to select)".to_owned()
+        };
+        ui.label(format!("selection: {selection_text}"));
+
         response
     }
 }
@@ -863,7 +1024,10 @@ impl InteractionDemo {
 enum Chart {
     GaussBars,
     StackedBars,
+    GroupedBars,
     BoxPlot,
+    Candlestick,
+    Heatmap,
 }
 
 impl Default for Chart {
@@ -901,7 +1065,10 @@ impl ChartsDemo {
                 ui.horizontal(|ui| {
                     ui.selectable_value(&mut self.chart, Chart::GaussBars, "Histogram");
                     ui.selectable_value(&mut self.chart, Chart::StackedBars, "Stacked Bar Chart");
+                    ui.selectable_value(&mut self.chart, Chart::GroupedBars, "Grouped Bar Chart");
                     ui.selectable_value(&mut self.chart, Chart::BoxPlot, "Box Plot");
+                    ui.selectable_value(&mut self.chart, Chart::Candlestick, "Candlestick");
+                    ui.selectable_value(&mut self.chart, Chart::Heatmap, "Heatmap");
                 });
                 ui.label("Orientation:");
                 ui.horizontal(|ui| {
@@ -934,27 +1101,32 @@ impl ChartsDemo {
         match self.chart {
             Chart::GaussBars => self.bar_gauss(ui),
             Chart::StackedBars => self.bar_stacked(ui),
+            Chart::GroupedBars => self.bar_grouped(ui),
             Chart::BoxPlot => self.box_plot(ui),
+            Chart::Candlestick => self.candlestick(ui),
+            Chart::Heatmap => self.heatmap(ui),
         }
     }
 
     fn bar_gauss(&self, ui: &mut Ui) -> Response {
-        let mut chart = BarChart::new(
-            (-395..=395)
-                .step_by(10)
-                .map(|x| x as f64 * 0.01)
-                .map(|x| {
-                    (
-                        x,
-                        (-x * x / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt(),
-                    )
-                })
-                // The 10 factor here is purely for a nice 1:1 aspect ratio
-                .map(|(x, f)| Bar::new(x, f * 10.0).width(0.095))
-                .collect(),
-        )
-        .color(Color32::LIGHT_BLUE)
-        .name("Normal Distribution");
+        let bars: Vec<Bar> = (-395..=395)
+            .step_by(10)
+            .map(|x| x as f64 * 0.01)
+            .map(|x| {
+                (
+                    x,
+                    (-x * x / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt(),
+                )
+            })
+            // The 10 factor here is purely for a nice 1:1 aspect ratio
+            .map(|(x, f)| Bar::new(x, f * 10.0).width(0.095))
+            .collect();
+        // The uncertainty of counting samples grows with the square root of the count:
+        let errors = ErrorBars::symmetric(bars.iter().map(|bar| 0.1 * bar.value.sqrt()));
+        let mut chart = BarChart::new(bars)
+            .error_bars(errors)
+            .color(Color32::LIGHT_BLUE)
+            .name("Normal Distribution");
         if !self.vertical {
             chart = chart.horizontal();
         }
@@ -971,7 +1143,7 @@ impl ChartsDemo {
     }
 
     fn bar_stacked(&self, ui: &mut Ui) -> Response {
-        let mut chart1 = BarChart::new(vec![
+        let chart1 = BarChart::new(vec![
             Bar::new(0.5, 1.0).name("Day 1"),
             Bar::new(1.5, 3.0).name("Day 2"),
             Bar::new(2.5, 1.0).name("Day 3"),
@@ -981,7 +1153,7 @@ impl ChartsDemo {
         .width(0.7)
         .name("Set 1");
 
-        let mut chart2 = BarChart::new(vec![
+        let chart2 = BarChart::new(vec![
             Bar::new(0.5, 1.0),
             Bar::new(1.5, 1.5),
             Bar::new(2.5, 0.1),
@@ -989,10 +1161,9 @@ impl ChartsDemo {
             Bar::new(4.5, 0.8),
         ])
         .width(0.7)
-        .name("Set 2")
-        .stack_on(&[&chart1]);
+        .name("Set 2");
 
-        let mut chart3 = BarChart::new(vec![
+        let chart3 = BarChart::new(vec![
             Bar::new(0.5, -0.5),
             Bar::new(1.5, 1.0),
             Bar::new(2.5, 0.5),
@@ -1000,10 +1171,9 @@ impl ChartsDemo {
             Bar::new(4.5, 0.3),
         ])
         .width(0.7)
-        .name("Set 3")
-        .stack_on(&[&chart1, &chart2]);
+        .name("Set 3");
 
-        let mut chart4 = BarChart::new(vec![
+        let chart4 = BarChart::new(vec![
             Bar::new(0.5, 0.5),
             Bar::new(1.5, 1.0),
             Bar::new(2.5, 0.5),
@@ -1011,25 +1181,59 @@ impl ChartsDemo {
             Bar::new(4.5, -0.5),
         ])
         .width(0.7)
-        .name("Set 4")
-        .stack_on(&[&chart1, &chart2, &chart3]);
+        .name("Set 4");
 
-        if !self.vertical {
-            chart1 = chart1.horizontal();
-            chart2 = chart2.horizontal();
-            chart3 = chart3.horizontal();
-            chart4 = chart4.horizontal();
-        }
+        let charts = BarChart::stacked(vec![chart1, chart2, chart3, chart4]);
 
         Plot::new("Stacked Bar Chart Demo")
             .legend(Legend::default())
             .data_aspect(1.0)
             .allow_drag(self.allow_drag)
             .show(ui, |plot_ui| {
-                plot_ui.bar_chart(chart1);
-                plot_ui.bar_chart(chart2);
-                plot_ui.bar_chart(chart3);
-                plot_ui.bar_chart(chart4);
+                for mut chart in charts {
+                    if !self.vertical {
+                        chart = chart.horizontal();
+                    }
+                    plot_ui.bar_chart(chart);
+                }
+            })
+            .response
+    }
+
+    fn bar_grouped(&self, ui: &mut Ui) -> Response {
+        let chart = |name: &str, values: [f64; 4]| {
+            let mut chart = BarChart::new(
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(quarter, &value)| {
+                        Bar::new(quarter as f64 + 1.0, value).name(format!("Q{}", quarter + 1))
+                    })
+                    .collect(),
+            )
+            .width(0.8)
+            .name(name);
+            if !self.vertical {
+                chart = chart.horizontal();
+            }
+            chart
+        };
+
+        let charts = BarChart::grouped(vec![
+            chart("North", [3.0, 4.5, 2.0, 5.0]),
+            chart("East", [2.5, 3.0, 3.5, 4.0]),
+            chart("South", [1.0, 2.0, 4.5, 3.0]),
+        ]);
+
+        Plot::new("Grouped Bar Chart Demo")
+            .legend(Legend::default())
+            .allow_zoom(self.allow_zoom)
+            .allow_drag(self.allow_drag)
+            .allow_scroll(self.allow_scroll)
+            .show(ui, |plot_ui| {
+                for chart in charts {
+                    plot_ui.bar_chart(chart);
+                }
             })
             .response
     }
@@ -1077,6 +1281,59 @@ impl ChartsDemo {
             })
             .response
     }
+
+    fn candlestick(&self, ui: &mut Ui) -> Response {
+        // A made up price for 60 days:
+        let mut close = 100.0;
+        let candles = (0..60)
+            .map(|day| {
+                let t = day as f64;
+                let open = close;
+                close = open + 3.0 * (0.9 * t).sin() + 1.5 * (0.31 * t).cos();
+                let high = open.max(close) + 1.0 + (1.7 * t).sin().abs();
+                let low = open.min(close) - 1.0 - (1.3 * t).cos().abs();
+                Candle::new(t, open, high, low, close).name(format!("Day {day}"))
+            })
+            .collect();
+
+        Plot::new("Candlestick Demo")
+            .legend(Legend::default())
+            .allow_zoom(self.allow_zoom)
+            .allow_drag(self.allow_drag)
+            .allow_scroll(self.allow_scroll)
+            .show(ui, |plot_ui| {
+                plot_ui.candlestick(Candlestick::new(candles).name("Price"));
+            })
+            .response
+    }
+
+    fn heatmap(&self, ui: &mut Ui) -> Response {
+        // Interference of two waves, on a 100 by 100 grid:
+        let size = 100;
+        let values = (0..size * size)
+            .map(|i| {
+                let x = (i % size) as f64 / size as f64 * TAU;
+                let y = (i / size) as f64 / size as f64 * TAU;
+                (2.0 * x).sin() + (3.0 * y).cos()
+            })
+            .collect();
+
+        Plot::new("Heatmap Demo")
+            .legend(Legend::default())
+            .data_aspect(1.0)
+            .allow_zoom(self.allow_zoom)
+            .allow_drag(self.allow_drag)
+            .allow_scroll(self.allow_scroll)
+            .show(ui, |plot_ui| {
+                plot_ui.heatmap(
+                    Heatmap::new(values, size)
+                        .position([0.0, 0.0], [TAU, TAU])
+                        .show_colorbar(true)
+                        .name("Waves"),
+                );
+            })
+            .response
+    }
 }
 
 fn is_approx_zero(val: f64) -> bool {
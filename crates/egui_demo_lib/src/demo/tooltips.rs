@@ -54,6 +54,18 @@ impl super::View for Tooltips {
                 ui.label("You can select this text.");
             });
 
+        ui.label("Interactive tooltips stay open while you use them.")
+            .on_hover_ui_interactive(|ui| {
+                ui.label("Scroll through this list and click a button:");
+                egui::ScrollArea::vertical()
+                    .max_height(100.0)
+                    .show(ui, |ui| {
+                        for i in 0..20 {
+                            _ = ui.button(format!("Button {i}"));
+                        }
+                    });
+            });
+
         ui.separator(); // ---------------------------------------------------------
 
         let tooltip_ui = |ui: &mut egui::Ui| {
@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 enum Enum {
     First,
@@ -15,6 +15,8 @@ pub struct WidgetGallery {
     opacity: f32,
     radio: Enum,
     scalar: f32,
+    rating: f32,
+    vector: [f32; 3],
     string: String,
     color: egui::Color32,
     animate_progress_bar: bool,
@@ -22,6 +24,10 @@ pub struct WidgetGallery {
     #[cfg(feature = "chrono")]
     #[cfg_attr(feature = "serde", serde(skip))]
     date: Option<chrono::NaiveDate>,
+
+    #[cfg(feature = "chrono")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    time: Option<chrono::NaiveTime>,
 }
 
 impl Default for WidgetGallery {
@@ -33,11 +39,15 @@ impl Default for WidgetGallery {
             boolean: false,
             radio: Enum::First,
             scalar: 42.0,
+            rating: 3.5,
+            vector: [1.0, 2.0, 3.0],
             string: Default::default(),
             color: egui::Color32::LIGHT_BLUE.linear_multiply(0.5),
             animate_progress_bar: false,
             #[cfg(feature = "chrono")]
             date: None,
+            #[cfg(feature = "chrono")]
+            time: None,
         }
     }
 }
@@ -114,11 +124,15 @@ impl WidgetGallery {
             boolean,
             radio,
             scalar,
+            rating,
+            vector,
             string,
             color,
             animate_progress_bar,
             #[cfg(feature = "chrono")]
             date,
+            #[cfg(feature = "chrono")]
+            time,
         } = self;
 
         ui.add(doc_link_label("Label", "label"));
@@ -153,6 +167,10 @@ impl WidgetGallery {
         ui.checkbox(boolean, "Checkbox");
         ui.end_row();
 
+        ui.add(doc_link_label("ToggleSwitch", "ToggleSwitch"));
+        ui.add(egui::ToggleSwitch::new(boolean, "Switch"));
+        ui.end_row();
+
         ui.add(doc_link_label("RadioButton", "radio"));
         ui.horizontal(|ui| {
             ui.radio_value(radio, Enum::First, "First");
@@ -161,6 +179,24 @@ impl WidgetGallery {
         });
         ui.end_row();
 
+        ui.add(doc_link_label("Chip", "Chip"));
+        if ui
+            .add(egui::Chip::new("Chip").icon("🏷").selected(*boolean))
+            .clicked()
+        {
+            *boolean = !*boolean;
+        }
+        ui.end_row();
+
+        ui.add(doc_link_label("SegmentedControl", "SegmentedControl"));
+        ui.add(
+            egui::SegmentedControl::new(radio)
+                .segment(Enum::First, "First")
+                .segment(Enum::Second, "Second")
+                .segment(Enum::Third, "Third"),
+        );
+        ui.end_row();
+
         ui.add(doc_link_label("SelectableLabel", "SelectableLabel"));
         ui.horizontal(|ui| {
             ui.selectable_value(radio, Enum::First, "First");
@@ -188,6 +224,18 @@ impl WidgetGallery {
         ui.add(egui::DragValue::new(scalar).speed(1.0));
         ui.end_row();
 
+        ui.add(doc_link_label("Knob", "Knob"));
+        ui.add(egui::Knob::new(scalar, 0.0..=360.0).suffix("°").ticks(9));
+        ui.end_row();
+
+        ui.add(doc_link_label("Rating", "Rating"));
+        ui.add(egui::Rating::new(rating).half_steps(true));
+        ui.end_row();
+
+        ui.add(doc_link_label("VectorEdit", "VectorEdit"));
+        ui.add(egui::VectorEdit::new(vector).uniform_lock(true));
+        ui.end_row();
+
         ui.add(doc_link_label("ProgressBar", "ProgressBar"));
         let progress = *scalar / 360.0;
         let progress_bar = egui::ProgressBar::new(progress)
@@ -203,6 +251,23 @@ impl WidgetGallery {
         ui.color_edit_button_srgba(color);
         ui.end_row();
 
+        ui.add(doc_link_label("ColorPalette", "ColorPalette"));
+        ui.add(egui::ColorPalette::new(
+            color,
+            [
+                egui::Color32::RED,
+                egui::Color32::YELLOW,
+                egui::Color32::GREEN,
+                egui::Color32::LIGHT_BLUE,
+                egui::Color32::BLUE,
+                egui::Color32::GRAY,
+                egui::Color32::WHITE,
+                egui::Color32::BLACK,
+            ],
+        ))
+        .on_hover_text("Drag a color onto the color picker button above");
+        ui.end_row();
+
         ui.add(doc_link_label("Image", "Image"));
         let egui_icon = egui::include_image!("../../data/icon.png");
         ui.add(egui::Image::new(egui_icon.clone()));
@@ -230,6 +295,15 @@ impl WidgetGallery {
             ));
             ui.add(egui_extras::DatePickerButton::new(date));
             ui.end_row();
+
+            let time = time.get_or_insert_with(|| chrono::offset::Local::now().time());
+            ui.add(doc_link_label_with_crate(
+                "egui_extras",
+                "TimePicker",
+                "TimePicker",
+            ));
+            ui.add(egui_extras::TimePicker::new(time));
+            ui.end_row();
         }
 
         ui.add(doc_link_label("Separator", "separator"));
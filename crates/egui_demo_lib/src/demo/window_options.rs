@@ -7,8 +7,12 @@ pub struct WindowOptions {
     title_bar: bool,
     closable: bool,
     collapsible: bool,
+    minimizable: bool,
+    maximizable: bool,
     resizable: bool,
     constrain: bool,
+    snap_distance: f32,
+    opacity: f32,
     scroll2: Vec2b,
     disabled_time: f64,
 
@@ -24,8 +28,12 @@ impl Default for WindowOptions {
             title_bar: true,
             closable: true,
             collapsible: true,
+            minimizable: true,
+            maximizable: true,
             resizable: true,
             constrain: true,
+            snap_distance: 0.0,
+            opacity: 1.0,
             scroll2: Vec2b::TRUE,
             disabled_time: f64::NEG_INFINITY,
             anchored: false,
@@ -46,8 +54,12 @@ impl super::Demo for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            minimizable,
+            maximizable,
             resizable,
             constrain,
+            snap_distance,
+            opacity,
             scroll2,
             disabled_time,
             anchored,
@@ -65,7 +77,11 @@ impl super::Demo for WindowOptions {
             .id(egui::Id::new("demo_window_options")) // required since we change the title
             .resizable(resizable)
             .constrain(constrain)
+            .snap_distance(snap_distance)
+            .opacity(opacity)
             .collapsible(collapsible)
+            .minimizable(minimizable)
+            .maximizable(maximizable)
             .title_bar(title_bar)
             .scroll(scroll2)
             .enabled(enabled);
@@ -86,8 +102,12 @@ impl super::View for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            minimizable,
+            maximizable,
             resizable,
             constrain,
+            snap_distance,
+            opacity,
             scroll2,
             disabled_time: _,
             anchored,
@@ -105,9 +125,14 @@ impl super::View for WindowOptions {
                     ui.checkbox(title_bar, "title_bar");
                     ui.checkbox(closable, "closable");
                     ui.checkbox(collapsible, "collapsible");
+                    ui.checkbox(minimizable, "minimizable");
+                    ui.checkbox(maximizable, "maximizable");
                     ui.checkbox(resizable, "resizable");
                     ui.checkbox(constrain, "constrain")
                         .on_hover_text("Constrain window to the screen");
+                    ui.add(egui::Slider::new(snap_distance, 0.0..=20.0).text("snap_distance"))
+                        .on_hover_text("Snap to the edges of the screen, panels and other windows");
+                    ui.add(egui::Slider::new(opacity, 0.2..=1.0).text("opacity"));
                     ui.checkbox(&mut scroll2[0], "hscroll");
                     ui.checkbox(&mut scroll2[1], "vscroll");
                 });
@@ -1,4 +1,4 @@
-use crate::easy_mark::easy_mark_parser;
+use egui::easy_mark::easy_mark_parser;
 
 /// Highlight easymark, memoizing previous output to save CPU.
 ///
@@ -64,7 +64,7 @@ pub fn highlight_easymark(egui_style: &egui::Style, mut text: &str) -> egui::tex
             // we don't preview indentation, because it is confusing
             skip = 1;
         } else if start_of_line && text.starts_with("# ") {
-            style.heading = true;
+            style.heading = 1;
             skip = 2;
         } else if start_of_line && text.starts_with("> ") {
             style.quoted = true;
@@ -138,7 +138,7 @@ fn format_from_style(
 ) -> egui::text::TextFormat {
     use egui::{Align, Color32, Stroke, TextStyle};
 
-    let color = if emark_style.strong || emark_style.heading {
+    let color = if emark_style.strong || emark_style.heading != 0 {
         egui_style.visuals.strong_text_color()
     } else if emark_style.quoted {
         egui_style.visuals.weak_text_color()
@@ -146,7 +146,7 @@ fn format_from_style(
         egui_style.visuals.text_color()
     };
 
-    let text_style = if emark_style.heading {
+    let text_style = if emark_style.heading != 0 {
         TextStyle::Heading
     } else if emark_style.code {
         TextStyle::Monospace
@@ -1,11 +1,10 @@
 //! Experimental markup language
+//!
+//! The parser and viewer live in [`egui::easy_mark`], where they also show [`egui::Markdown`].
 
 mod easy_mark_editor;
 mod easy_mark_highlighter;
-pub mod easy_mark_parser;
-mod easy_mark_viewer;
 
 pub use easy_mark_editor::EasyMarkEditor;
 pub use easy_mark_highlighter::MemoizedEasymarkHighlighter;
-pub use easy_mark_parser as parser;
-pub use easy_mark_viewer::easy_mark;
+pub use egui::easy_mark::{easy_mark, parser};
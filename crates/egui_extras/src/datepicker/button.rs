@@ -8,6 +8,9 @@ pub(crate) struct DatePickerButtonState {
 }
 
 /// Shows a date, and will open a date picker popup when clicked.
+///
+/// In the popup the arrow keys move the selected day, Page Up and Page Down the month
+/// (with Shift: the year), Enter saves and Escape cancels.
 pub struct DatePickerButton<'a> {
     selection: &'a mut NaiveDate,
     id_source: Option<&'a str>,
@@ -18,6 +21,9 @@ pub struct DatePickerButton<'a> {
     show_icon: bool,
     format: String,
     highlight_weekends: bool,
+    today_button: bool,
+    min_date: Option<NaiveDate>,
+    max_date: Option<NaiveDate>,
 }
 
 impl<'a> DatePickerButton<'a> {
@@ -32,6 +38,9 @@ impl<'a> DatePickerButton<'a> {
             show_icon: true,
             format: "%Y-%m-%d".to_owned(),
             highlight_weekends: true,
+            today_button: true,
+            min_date: None,
+            max_date: None,
         }
     }
 
@@ -92,6 +101,31 @@ impl<'a> DatePickerButton<'a> {
         self.highlight_weekends = highlight_weekends;
         self
     }
+
+    /// Show a button in the date picker popup that goes to today's date. (Default: true)
+    #[inline]
+    pub fn today_button(mut self, today_button: bool) -> Self {
+        self.today_button = today_button;
+        self
+    }
+
+    /// The earliest date that can be picked. (Default: no limit)
+    ///
+    /// Days before it are greyed out in the calendar.
+    #[inline]
+    pub fn min_date(mut self, min_date: NaiveDate) -> Self {
+        self.min_date = Some(min_date);
+        self
+    }
+
+    /// The latest date that can be picked. (Default: no limit)
+    ///
+    /// Days after it are greyed out in the calendar.
+    #[inline]
+    pub fn max_date(mut self, max_date: NaiveDate) -> Self {
+        self.max_date = Some(max_date);
+        self
+    }
 }
 
 impl<'a> Widget for DatePickerButton<'a> {
@@ -158,6 +192,9 @@ impl<'a> Widget for DatePickerButton<'a> {
                                 calendar: self.calendar,
                                 calendar_week: self.calendar_week,
                                 highlight_weekends: self.highlight_weekends,
+                                today_button: self.today_button,
+                                min_date: self.min_date,
+                                max_date: self.max_date,
                             }
                             .draw(ui)
                         })
@@ -1,8 +1,10 @@
 mod button;
 mod popup;
+mod time_picker;
 
 pub use button::DatePickerButton;
 use chrono::{Datelike, Duration, NaiveDate, Weekday};
+pub use time_picker::TimePicker;
 
 #[derive(Debug)]
 struct Week {
@@ -1,6 +1,10 @@
-use chrono::{Datelike, NaiveDate, Weekday};
+use std::ops::RangeInclusive;
 
-use egui::{Align, Button, Color32, ComboBox, Direction, Id, Layout, RichText, Ui, Vec2};
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+
+use egui::{
+    Align, Button, Color32, ComboBox, Direction, Id, Key, Layout, Modifiers, RichText, Ui, Vec2,
+};
 
 use super::{button::DatePickerButtonState, month_data};
 
@@ -24,6 +28,17 @@ impl DatePickerPopupState {
             .or_else(|| date.with_day(29).map(|_| 29))
             .unwrap_or(28)
     }
+
+    fn date(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)
+            .expect("Could not create NaiveDate")
+    }
+
+    fn set_date(&mut self, date: NaiveDate) {
+        self.year = date.year();
+        self.month = date.month();
+        self.day = date.day();
+    }
 }
 
 pub(crate) struct DatePickerPopup<'a> {
@@ -34,9 +49,33 @@ pub(crate) struct DatePickerPopup<'a> {
     pub calendar: bool,
     pub calendar_week: bool,
     pub highlight_weekends: bool,
+    pub today_button: bool,
+    pub min_date: Option<NaiveDate>,
+    pub max_date: Option<NaiveDate>,
 }
 
 impl<'a> DatePickerPopup<'a> {
+    fn clamp(&self, date: NaiveDate) -> NaiveDate {
+        let date = self.min_date.map_or(date, |min| date.max(min));
+        self.max_date.map_or(date, |max| date.min(max))
+    }
+
+    fn in_range(&self, date: NaiveDate) -> bool {
+        self.clamp(date) == date
+    }
+
+    /// The years to pick from. A missing limit is taken relative to today,
+    /// but never so that the range ends before it starts.
+    fn year_range(&self, today: NaiveDate) -> RangeInclusive<i32> {
+        let min_year = self.min_date.map(|min| min.year());
+        let max_year = self.max_date.map(|max| max.year());
+        let first_year = min_year.unwrap_or_else(|| {
+            max_year.map_or(today.year() - 100, |max| max.min(today.year() - 100))
+        });
+        let last_year = max_year.unwrap_or_else(|| first_year.max(today.year() + 9));
+        first_year..=last_year
+    }
+
     /// Returns `true` if user pressed `Save` button.
     pub fn draw(&mut self, ui: &mut Ui) -> bool {
         let id = ui.make_persistent_id("date_picker");
@@ -48,12 +87,53 @@ impl<'a> DatePickerPopup<'a> {
             popup_state.year = self.selection.year();
             popup_state.month = self.selection.month();
             popup_state.day = self.selection.day();
+            popup_state.set_date(self.clamp(popup_state.date()));
             popup_state.setup = true;
             ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
         }
 
-        let weeks = month_data(popup_state.year, popup_state.month);
         let (mut close, mut saved) = (false, false);
+
+        // Keyboard navigation, unless the keys are for one of the combo boxes:
+        if !ui.memory(|mem| mem.any_popup_open()) {
+            let date = popup_state.date();
+            let new_date = ui.input_mut(|i| {
+                if i.consume_key(Modifiers::NONE, Key::ArrowLeft) {
+                    date.checked_sub_signed(Duration::days(1))
+                } else if i.consume_key(Modifiers::NONE, Key::ArrowRight) {
+                    date.checked_add_signed(Duration::days(1))
+                } else if i.consume_key(Modifiers::NONE, Key::ArrowUp) {
+                    date.checked_sub_signed(Duration::days(7))
+                } else if i.consume_key(Modifiers::NONE, Key::ArrowDown) {
+                    date.checked_add_signed(Duration::days(7))
+                } else if i.consume_key(Modifiers::SHIFT, Key::PageUp) {
+                    date.checked_sub_months(Months::new(12))
+                } else if i.consume_key(Modifiers::SHIFT, Key::PageDown) {
+                    date.checked_add_months(Months::new(12))
+                } else if i.consume_key(Modifiers::NONE, Key::PageUp) {
+                    date.checked_sub_months(Months::new(1))
+                } else if i.consume_key(Modifiers::NONE, Key::PageDown) {
+                    date.checked_add_months(Months::new(1))
+                } else {
+                    None
+                }
+            });
+            if let Some(new_date) = new_date {
+                popup_state.set_date(self.clamp(new_date));
+                ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
+            }
+
+            if ui.input_mut(|i| i.consume_key(Modifiers::NONE, Key::Enter)) {
+                *self.selection = self.clamp(popup_state.date());
+                saved = true;
+                close = true;
+            }
+            if ui.input(|i| i.key_pressed(Key::Escape)) {
+                close = true;
+            }
+        }
+
+        let weeks = month_data(popup_state.year, popup_state.month);
         let height = 20.0;
         let spacing = 2.0;
         ui.spacing_mut().item_spacing = Vec2::splat(spacing);
@@ -80,7 +160,7 @@ impl<'a> DatePickerPopup<'a> {
                                 ComboBox::from_id_source("date_picker_year")
                                     .selected_text(popup_state.year.to_string())
                                     .show_ui(ui, |ui| {
-                                        for year in today.year() - 100..today.year() + 10 {
+                                        for year in self.year_range(today) {
                                             if ui
                                                 .selectable_value(
                                                     &mut popup_state.year,
@@ -329,7 +409,8 @@ impl<'a> DatePickerPopup<'a> {
                                                                 text_color.linear_multiply(0.5);
                                                         };
 
-                                                        let button_response = ui.add(
+                                                        let button_response = ui.add_enabled(
+                                                            self.in_range(day),
                                                             Button::new(
                                                                 RichText::new(
                                                                     day.day().to_string(),
@@ -376,7 +457,23 @@ impl<'a> DatePickerPopup<'a> {
 
                 strip.strip(|builder| {
                     builder.sizes(Size::remainder(), 3).horizontal(|mut strip| {
-                        strip.empty();
+                        if self.today_button {
+                            strip.cell(|ui| {
+                                ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
+                                    if ui
+                                        .add_enabled(self.in_range(today), Button::new("Today"))
+                                        .clicked()
+                                    {
+                                        popup_state.set_date(today);
+                                        ui.data_mut(|data| {
+                                            data.insert_persisted(id, popup_state.clone());
+                                        });
+                                    }
+                                });
+                            });
+                        } else {
+                            strip.empty();
+                        }
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                 if ui.button("Cancel").clicked() {
@@ -387,12 +484,7 @@ impl<'a> DatePickerPopup<'a> {
                         strip.cell(|ui| {
                             ui.with_layout(Layout::top_down_justified(Align::Center), |ui| {
                                 if ui.button("Save").clicked() {
-                                    *self.selection = NaiveDate::from_ymd_opt(
-                                        popup_state.year,
-                                        popup_state.month,
-                                        popup_state.day,
-                                    )
-                                    .expect("Could not create NaiveDate");
+                                    *self.selection = self.clamp(popup_state.date());
                                     saved = true;
                                     close = true;
                                 }
@@ -402,6 +494,14 @@ impl<'a> DatePickerPopup<'a> {
                 });
             });
 
+        // The arrows and combo boxes can go past the limits:
+        let clamped = self.clamp(popup_state.date());
+        if clamped != popup_state.date() {
+            popup_state.set_date(clamped);
+            ui.data_mut(|data| data.insert_persisted(id, popup_state.clone()));
+            ui.ctx().request_repaint();
+        }
+
         if close {
             popup_state.setup = false;
             ui.data_mut(|data| {
@@ -432,3 +532,178 @@ fn month_name(i: u32) -> &'static str {
         _ => panic!("Unknown month: {i}"),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, Event, RawInput};
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn popup(selection: &mut NaiveDate) -> DatePickerPopup<'_> {
+        DatePickerPopup {
+            selection,
+            button_id: Id::new("date_picker_button"),
+            combo_boxes: true,
+            arrows: true,
+            calendar: true,
+            calendar_week: true,
+            highlight_weekends: true,
+            today_button: true,
+            min_date: None,
+            max_date: None,
+        }
+    }
+
+    fn key(key: Key, modifiers: Modifiers) -> Event {
+        Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        let mut selection = date(2020, 4, 1);
+        let mut popup = popup(&mut selection);
+        let today = date(2024, 6, 15);
+        assert_eq!(popup.year_range(today), 1924..=2033);
+        assert!(popup.in_range(date(1, 1, 1)));
+
+        popup.min_date = Some(date(2020, 3, 10));
+        popup.max_date = Some(date(2020, 5, 20));
+        assert_eq!(popup.clamp(date(2019, 12, 31)), date(2020, 3, 10));
+        assert_eq!(popup.clamp(date(2020, 4, 1)), date(2020, 4, 1));
+        assert_eq!(popup.clamp(date(2020, 5, 21)), date(2020, 5, 20));
+        assert!(popup.in_range(date(2020, 3, 10)));
+        assert!(popup.in_range(date(2020, 5, 20)));
+        assert!(!popup.in_range(date(2020, 3, 9)));
+        assert!(!popup.in_range(date(2020, 5, 21)));
+        assert_eq!(popup.year_range(today), 2020..=2020);
+
+        // A missing limit never puts the end of the years before their start:
+        popup.min_date = Some(date(2050, 1, 1));
+        popup.max_date = None;
+        assert_eq!(popup.year_range(today), 2050..=2050);
+        popup.min_date = None;
+        popup.max_date = Some(date(1900, 1, 1));
+        assert_eq!(popup.year_range(today), 1900..=1900);
+    }
+
+    #[test]
+    fn test_keyboard() {
+        let ctx = Context::default();
+        let button_id = Id::new("date_picker_button");
+        let run = |selection: &mut NaiveDate, max_date: Option<NaiveDate>, events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut saved = false;
+            let _ = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    let mut popup = popup(selection);
+                    popup.max_date = max_date;
+                    saved = popup.draw(ui);
+                });
+            });
+            saved
+        };
+        let set_visible = || {
+            ctx.data_mut(|data| {
+                data.get_persisted_mut_or_default::<DatePickerButtonState>(button_id)
+                    .picker_visible = true;
+            });
+        };
+        let is_visible = || {
+            ctx.data_mut(|data| {
+                data.get_persisted::<DatePickerButtonState>(button_id)
+                    .unwrap_or_default()
+                    .picker_visible
+            })
+        };
+
+        // Nothing is saved until Enter is pressed:
+        let mut selection = date(2020, 1, 31);
+        set_visible();
+        assert!(!run(&mut selection, None, vec![]));
+        assert!(!run(
+            &mut selection,
+            None,
+            vec![key(Key::ArrowRight, Modifiers::NONE)]
+        ));
+        assert_eq!(selection, date(2020, 1, 31));
+        assert!(is_visible());
+        assert!(run(
+            &mut selection,
+            None,
+            vec![key(Key::Enter, Modifiers::NONE)]
+        ));
+        assert_eq!(selection, date(2020, 2, 1));
+        assert!(!is_visible());
+
+        // Each move starts from the date saved by the one before it:
+        let moves = [
+            (Key::ArrowDown, Modifiers::NONE, date(2020, 2, 8)),
+            (Key::ArrowUp, Modifiers::NONE, date(2020, 2, 1)),
+            (Key::ArrowLeft, Modifiers::NONE, date(2020, 1, 31)),
+            (Key::PageDown, Modifiers::NONE, date(2020, 2, 29)),
+            (Key::PageDown, Modifiers::SHIFT, date(2021, 2, 28)),
+            (Key::PageUp, Modifiers::NONE, date(2021, 1, 28)),
+            (Key::PageUp, Modifiers::SHIFT, date(2020, 1, 28)),
+        ];
+        for (pressed, modifiers, expected) in moves {
+            assert!(!run(&mut selection, None, vec![key(pressed, modifiers)]));
+            assert!(run(
+                &mut selection,
+                None,
+                vec![key(Key::Enter, Modifiers::NONE)]
+            ));
+            assert_eq!(selection, expected, "{pressed:?} {modifiers:?}");
+        }
+
+        // The keys can't go past the limits:
+        let max_date = Some(date(2020, 2, 3));
+        let mut selection = date(2020, 1, 31);
+        assert!(!run(
+            &mut selection,
+            max_date,
+            vec![key(Key::ArrowDown, Modifiers::NONE)]
+        ));
+        assert!(run(
+            &mut selection,
+            max_date,
+            vec![key(Key::Enter, Modifiers::NONE)]
+        ));
+        assert_eq!(selection, date(2020, 2, 3));
+
+        // Escape closes the popup without saving:
+        set_visible();
+        assert!(!run(
+            &mut selection,
+            None,
+            vec![key(Key::ArrowLeft, Modifiers::NONE)]
+        ));
+        assert!(!run(
+            &mut selection,
+            None,
+            vec![key(Key::Escape, Modifiers::NONE)]
+        ));
+        assert_eq!(selection, date(2020, 2, 3));
+        assert!(!is_visible());
+
+        // And the next time the popup starts from the selection again:
+        assert!(run(
+            &mut selection,
+            None,
+            vec![key(Key::Enter, Modifiers::NONE)]
+        ));
+        assert_eq!(selection, date(2020, 2, 3));
+    }
+}
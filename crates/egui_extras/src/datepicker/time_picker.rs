@@ -0,0 +1,191 @@
+use chrono::{NaiveTime, Timelike};
+use egui::{DragValue, Response, Ui, Widget};
+
+/// Edit a time of day with a spinner each for the hour, minute and (optionally) second.
+///
+/// Drag a number or use the arrow keys while it has focus to change it,
+/// or click it to type it in.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut time = chrono::NaiveTime::from_hms_opt(13, 37, 0).unwrap();
+/// ui.add(egui_extras::TimePicker::new(&mut time).twelve_hour(true));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct TimePicker<'a> {
+    time: &'a mut NaiveTime,
+    seconds: bool,
+    twelve_hour: bool,
+}
+
+impl<'a> TimePicker<'a> {
+    pub fn new(time: &'a mut NaiveTime) -> Self {
+        Self {
+            time,
+            seconds: false,
+            twelve_hour: false,
+        }
+    }
+
+    /// Show a spinner for the seconds. (Default: false)
+    ///
+    /// Without it, editing the time keeps the seconds as they are.
+    #[inline]
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Show the hour as 1-12 with an AM/PM button, instead of 0-23. (Default: false)
+    #[inline]
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.twelve_hour = twelve_hour;
+        self
+    }
+}
+
+/// Show the number with two digits, e.g. `07`.
+fn two_digits(drag_value: DragValue<'_>) -> DragValue<'_> {
+    drag_value
+        .speed(0.1)
+        .custom_formatter(|n, _| format!("{:02}", n as u32))
+        .custom_parser(|s| s.trim().parse::<u32>().ok().map(f64::from))
+}
+
+/// `13` is `(1, true)`, i.e. 1 PM, and midnight is `(12, false)`.
+fn to_twelve_hour(hour: u32) -> (u32, bool) {
+    let pm = 12 <= hour;
+    match hour % 12 {
+        0 => (12, pm),
+        h => (h, pm),
+    }
+}
+
+/// The inverse of [`to_twelve_hour`].
+fn from_twelve_hour(hour12: u32, pm: bool) -> u32 {
+    hour12 % 12 + if pm { 12 } else { 0 }
+}
+
+impl<'a> Widget for TimePicker<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            time,
+            seconds,
+            twelve_hour,
+        } = self;
+
+        let mut hour = time.hour();
+        let mut minute = time.minute();
+        let mut second = time.second();
+        let (mut hour12, mut pm) = to_twelve_hour(hour);
+
+        let mut response = ui
+            .horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 2.0;
+
+                let mut response = if twelve_hour {
+                    ui.add(two_digits(DragValue::new(&mut hour12).clamp_range(1..=12)))
+                } else {
+                    ui.add(two_digits(DragValue::new(&mut hour).clamp_range(0..=23)))
+                };
+                ui.label(":");
+                response |= ui.add(two_digits(DragValue::new(&mut minute).clamp_range(0..=59)));
+                if seconds {
+                    ui.label(":");
+                    response |= ui.add(two_digits(DragValue::new(&mut second).clamp_range(0..=59)));
+                }
+
+                if twelve_hour {
+                    ui.add_space(4.0);
+                    let am_pm = ui
+                        .button(if pm { "PM" } else { "AM" })
+                        .on_hover_text("Switch between AM and PM");
+                    if am_pm.clicked() {
+                        pm = !pm;
+                    }
+                    response |= am_pm;
+                }
+                response
+            })
+            .inner;
+
+        if twelve_hour {
+            hour = from_twelve_hour(hour12, pm);
+        }
+        let new_time =
+            NaiveTime::from_hms_opt(hour, minute, second).expect("Could not create NaiveTime");
+        if new_time != time.with_nanosecond(0).unwrap_or(*time) {
+            *time = new_time;
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{CentralPanel, Context, Event, Modifiers, PointerButton, RawInput, Shape};
+
+    use super::*;
+
+    #[test]
+    fn test_twelve_hour() {
+        for (hour, twelve_hour) in [
+            (0, (12, false)),
+            (1, (1, false)),
+            (11, (11, false)),
+            (12, (12, true)),
+            (13, (1, true)),
+            (23, (11, true)),
+        ] {
+            assert_eq!(to_twelve_hour(hour), twelve_hour);
+            assert_eq!(from_twelve_hour(twelve_hour.0, twelve_hour.1), hour);
+        }
+    }
+
+    #[test]
+    fn test_switch_to_pm() {
+        let ctx = Context::default();
+        let mut time = NaiveTime::from_hms_opt(11, 20, 30).unwrap();
+        let mut run = |events: Vec<Event>| {
+            let input = RawInput {
+                events,
+                ..Default::default()
+            };
+            let mut changed = false;
+            let output = ctx.run(input, |ctx| {
+                CentralPanel::default().show(ctx, |ui| {
+                    changed = ui
+                        .add(TimePicker::new(&mut time).twelve_hour(true))
+                        .changed();
+                });
+            });
+            (changed, output.shapes)
+        };
+
+        let (changed, shapes) = run(vec![]);
+        assert!(!changed);
+        let am = shapes
+            .iter()
+            .find_map(|clipped| match &clipped.shape {
+                Shape::Text(text) if text.galley.text() == "AM" => {
+                    Some(text.pos + text.galley.rect.center().to_vec2())
+                }
+                _ => None,
+            })
+            .expect("No AM button");
+        let button = |pressed: bool| Event::PointerButton {
+            pos: am,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        };
+        run(vec![Event::PointerMoved(am)]);
+        let (changed, _) = run(vec![button(true), button(false)]);
+        assert!(changed);
+
+        // 11 AM is 23 in the afternoon, and the hidden seconds are kept:
+        assert_eq!(time, NaiveTime::from_hms_opt(23, 20, 30).unwrap());
+    }
+}
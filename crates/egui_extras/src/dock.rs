@@ -0,0 +1,979 @@
+use egui::{
+    vec2, Align, Color32, CursorIcon, Frame, Id, LayerId, Layout, Order, Pos2, Rect, Response,
+    Sense, Stroke, TextStyle, TextWrapMode, Ui, Vec2, WidgetText,
+};
+
+/// Tells a [`DockArea`] how to show your tabs.
+///
+/// `Tab` is your own type, e.g. an enum of the different panes of your app.
+pub trait TabViewer {
+    type Tab;
+
+    /// The title shown in the tab bar.
+    fn title(&mut self, tab: &mut Self::Tab) -> WidgetText;
+
+    /// Show the contents of the tab.
+    fn ui(&mut self, ui: &mut Ui, tab: &mut Self::Tab);
+
+    /// A unique and stable id of the tab.
+    ///
+    /// This is used for the id of the [`Ui`] of the tab,
+    /// so that its state (e.g. scroll position) is kept when it is moved around.
+    ///
+    /// Defaults to an id based on the title.
+    fn id(&mut self, tab: &mut Self::Tab) -> Id {
+        Id::new(self.title(tab).text())
+    }
+
+    /// Can this tab be closed by the user?
+    ///
+    /// Default: `false`.
+    fn closeable(&mut self, _tab: &mut Self::Tab) -> bool {
+        false
+    }
+
+    /// Called when the user closes the tab.
+    ///
+    /// Return `false` to keep the tab open anyway.
+    fn on_close(&mut self, _tab: &mut Self::Tab) -> bool {
+        true
+    }
+}
+
+/// A side of some docked tabs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DockSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+enum SplitDirection {
+    /// The children are left and right of each other.
+    Horizontal,
+
+    /// The children are above and below each other.
+    Vertical,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+enum Node<Tab> {
+    Empty,
+
+    Leaf {
+        tabs: Vec<Tab>,
+        active: usize,
+    },
+
+    Split {
+        direction: SplitDirection,
+
+        /// How much of the space the first child gets, in `0..=1`.
+        fraction: f32,
+
+        children: Box<[Node<Tab>; 2]>,
+    },
+}
+
+impl<Tab> Node<Tab> {
+    fn leaf(tabs: Vec<Tab>) -> Self {
+        if tabs.is_empty() {
+            Self::Empty
+        } else {
+            Self::Leaf { tabs, active: 0 }
+        }
+    }
+
+    fn at_mut(&mut self, path: &[usize]) -> Option<&mut Self> {
+        match (self, path) {
+            (node, []) => Some(node),
+            (Self::Split { children, .. }, [i, rest @ ..]) => children.get_mut(*i)?.at_mut(rest),
+            _ => None,
+        }
+    }
+
+    fn first_leaf_tabs(&mut self) -> Option<&mut Vec<Tab>> {
+        match self {
+            Self::Empty => None,
+            Self::Leaf { tabs, .. } => Some(tabs),
+            Self::Split { children, .. } => {
+                let [first, second] = &mut **children;
+                first.first_leaf_tabs().or_else(|| second.first_leaf_tabs())
+            }
+        }
+    }
+
+    fn for_each_leaf_mut(&mut self, f: &mut dyn FnMut(&mut Vec<Tab>, &mut usize)) {
+        match self {
+            Self::Empty => {}
+            Self::Leaf { tabs, active } => f(tabs, active),
+            Self::Split { children, .. } => {
+                for child in children.iter_mut() {
+                    child.for_each_leaf_mut(f);
+                }
+            }
+        }
+    }
+
+    fn iter_tabs(&self) -> Box<dyn Iterator<Item = &Tab> + '_> {
+        match self {
+            Self::Empty => Box::new(std::iter::empty()),
+            Self::Leaf { tabs, .. } => Box::new(tabs.iter()),
+            Self::Split { children, .. } => Box::new(children.iter().flat_map(Self::iter_tabs)),
+        }
+    }
+
+    fn iter_tabs_mut(&mut self) -> Box<dyn Iterator<Item = &mut Tab> + '_> {
+        match self {
+            Self::Empty => Box::new(std::iter::empty()),
+            Self::Leaf { tabs, .. } => Box::new(tabs.iter_mut()),
+            Self::Split { children, .. } => {
+                Box::new(children.iter_mut().flat_map(Self::iter_tabs_mut))
+            }
+        }
+    }
+
+    fn into_tabs(self) -> Vec<Tab> {
+        match self {
+            Self::Empty => vec![],
+            Self::Leaf { tabs, .. } => tabs,
+            Self::Split { children, .. } => {
+                let [first, second] = *children;
+                let mut tabs = first.into_tabs();
+                tabs.extend(second.into_tabs());
+                tabs
+            }
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Empty)
+    }
+
+    /// Remove empty leaves, and splits with only one child left.
+    fn simplify(&mut self) {
+        match self {
+            Self::Empty => {}
+            Self::Leaf { tabs, active } => {
+                if tabs.is_empty() {
+                    *self = Self::Empty;
+                } else {
+                    *active = (*active).min(tabs.len() - 1);
+                }
+            }
+            Self::Split { children, .. } => {
+                for child in children.iter_mut() {
+                    child.simplify();
+                }
+                if children[0].is_empty() {
+                    *self = std::mem::replace(&mut children[1], Self::Empty);
+                } else if children[1].is_empty() {
+                    *self = std::mem::replace(&mut children[0], Self::Empty);
+                }
+            }
+        }
+    }
+
+    /// Split this node, putting `new` on the given side of it.
+    ///
+    /// `fraction` is how much of the space `new` gets.
+    fn split(&mut self, side: DockSide, fraction: f32, new: Self) {
+        if new.is_empty() {
+            return;
+        }
+        let old = std::mem::replace(self, Self::Empty);
+        if old.is_empty() {
+            *self = new;
+            return;
+        }
+        let direction = match side {
+            DockSide::Left | DockSide::Right => SplitDirection::Horizontal,
+            DockSide::Top | DockSide::Bottom => SplitDirection::Vertical,
+        };
+        let fraction = fraction.clamp(0.0, 1.0);
+        *self = match side {
+            DockSide::Left | DockSide::Top => Self::Split {
+                direction,
+                fraction,
+                children: Box::new([new, old]),
+            },
+            DockSide::Right | DockSide::Bottom => Self::Split {
+                direction,
+                fraction: 1.0 - fraction,
+                children: Box::new([old, new]),
+            },
+        };
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+struct DockWindow<Tab> {
+    id: u64,
+    pos: Pos2,
+    size: Vec2,
+    root: Node<Tab>,
+}
+
+/// The layout of all the tabs of a [`DockArea`]: how they are split, and which are in floating windows.
+///
+/// Store this in your app state.
+/// It can be serialized with `serde` to save the layout between runs.
+///
+/// ```
+/// # #[derive(Clone, Copy, PartialEq)]
+/// # enum Pane { Files, Editor, Console }
+/// let mut dock = egui_extras::DockState::new(vec![Pane::Editor]);
+/// dock.split(egui_extras::DockSide::Left, 0.25, vec![Pane::Files]);
+/// dock.split(egui_extras::DockSide::Bottom, 0.3, vec![Pane::Console]);
+/// assert_eq!(dock.iter_tabs().count(), 3);
+/// ```
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct DockState<Tab> {
+    root: Node<Tab>,
+    windows: Vec<DockWindow<Tab>>,
+    next_window_id: u64,
+}
+
+impl<Tab> Default for DockState<Tab> {
+    fn default() -> Self {
+        Self::new(vec![])
+    }
+}
+
+impl<Tab> DockState<Tab> {
+    /// All the given tabs in one tab bar.
+    pub fn new(tabs: Vec<Tab>) -> Self {
+        Self {
+            root: Node::leaf(tabs),
+            windows: vec![],
+            next_window_id: 0,
+        }
+    }
+
+    /// Add a tab to the first tab bar of the main area.
+    pub fn push_tab(&mut self, tab: Tab) {
+        if let Some(tabs) = self.root.first_leaf_tabs() {
+            tabs.push(tab);
+        } else {
+            self.root = Node::leaf(vec![tab]);
+        }
+    }
+
+    /// Split the main area, putting the given tabs on one side of everything else.
+    ///
+    /// `fraction` is how much of the space the new tabs get, in `0..=1`.
+    pub fn split(&mut self, side: DockSide, fraction: f32, tabs: Vec<Tab>) {
+        self.root.split(side, fraction, Node::leaf(tabs));
+    }
+
+    /// Add the given tabs as a new floating window.
+    pub fn add_window(&mut self, tabs: Vec<Tab>, rect: Rect) {
+        if tabs.is_empty() {
+            return;
+        }
+        let id = self.next_window_id;
+        self.next_window_id += 1;
+        self.windows.push(DockWindow {
+            id,
+            pos: rect.min,
+            size: rect.size(),
+            root: Node::leaf(tabs),
+        });
+    }
+
+    /// Number of floating windows.
+    pub fn num_windows(&self) -> usize {
+        self.windows.len()
+    }
+
+    /// All tabs, both docked and in floating windows.
+    pub fn iter_tabs(&self) -> impl Iterator<Item = &Tab> + '_ {
+        self.root.iter_tabs().chain(
+            self.windows
+                .iter()
+                .flat_map(|window| window.root.iter_tabs()),
+        )
+    }
+
+    /// All tabs, both docked and in floating windows.
+    pub fn iter_tabs_mut(&mut self) -> impl Iterator<Item = &mut Tab> + '_ {
+        self.root.iter_tabs_mut().chain(
+            self.windows
+                .iter_mut()
+                .flat_map(|window| window.root.iter_tabs_mut()),
+        )
+    }
+
+    /// Only keep the tabs for which the predicate returns `true`.
+    pub fn retain_tabs(&mut self, mut keep: impl FnMut(&mut Tab) -> bool) {
+        self.for_each_leaf_mut(&mut |tabs, _| tabs.retain_mut(|tab| keep(tab)));
+        self.simplify();
+    }
+
+    /// Make the first tab matching the predicate the active tab in its tab bar.
+    ///
+    /// Returns `false` if there was no such tab.
+    pub fn set_active_tab(&mut self, mut predicate: impl FnMut(&Tab) -> bool) -> bool {
+        let mut found = false;
+        self.for_each_leaf_mut(&mut |tabs, active| {
+            if !found {
+                if let Some(index) = tabs.iter().position(&mut predicate) {
+                    *active = index;
+                    found = true;
+                }
+            }
+        });
+        found
+    }
+
+    fn for_each_leaf_mut(&mut self, f: &mut dyn FnMut(&mut Vec<Tab>, &mut usize)) {
+        self.root.for_each_leaf_mut(f);
+        for window in &mut self.windows {
+            window.root.for_each_leaf_mut(f);
+        }
+    }
+
+    fn simplify(&mut self) {
+        self.root.simplify();
+        for window in &mut self.windows {
+            window.root.simplify();
+        }
+        self.windows.retain(|window| !window.root.is_empty());
+    }
+
+    fn surface_mut(&mut self, surface: Surface) -> Option<&mut Node<Tab>> {
+        match surface {
+            Surface::Main => Some(&mut self.root),
+            Surface::Window(id) => self
+                .windows
+                .iter_mut()
+                .find(|window| window.id == id)
+                .map(|window| &mut window.root),
+        }
+    }
+
+    fn leaf_tabs_mut(&mut self, leaf: &LeafAddr) -> Option<(&mut Vec<Tab>, &mut usize)> {
+        match self.surface_mut(leaf.surface)?.at_mut(&leaf.path)? {
+            Node::Leaf { tabs, active } => Some((tabs, active)),
+            _ => None,
+        }
+    }
+
+    /// Put the given node at the drop target.
+    fn insert(&mut self, target: &LeafAddr, zone: DropZone, node: Node<Tab>) {
+        match zone {
+            DropZone::Center => {
+                if let Some((tabs, active)) = self.leaf_tabs_mut(target) {
+                    *active = tabs.len();
+                    tabs.extend(node.into_tabs());
+                }
+            }
+            DropZone::Side(side) => {
+                if let Some(target) = self
+                    .surface_mut(target.surface)
+                    .and_then(|root| root.at_mut(&target.path))
+                {
+                    target.split(side, 0.5, node);
+                }
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Which tree of tabs: the main area or a floating window.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Surface {
+    Main,
+    Window(u64),
+}
+
+/// Where a tab bar is.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct LeafAddr {
+    surface: Surface,
+
+    /// Child indices from the root of the surface.
+    path: Vec<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DropZone {
+    /// Become a tab of the tab bar.
+    Center,
+
+    /// Split the tab bar.
+    Side(DockSide),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum DragSource {
+    Tab { leaf: LeafAddr, index: usize },
+    Window(u64),
+}
+
+struct DropTarget {
+    leaf: LeafAddr,
+    rect: Rect,
+    layer_id: LayerId,
+}
+
+/// What happened while showing the tabs this frame.
+#[derive(Default)]
+struct Interaction {
+    drop_targets: Vec<DropTarget>,
+    dragged: Option<DragSource>,
+    released: Option<DragSource>,
+    window_delta: Option<(u64, Vec2)>,
+    close: Option<(LeafAddr, usize)>,
+}
+
+/// Shows the tabs of a [`DockState`]: tabs can be dragged onto each other to be grouped in tab bars,
+/// to the sides of each other to split the area, or out into floating windows.
+///
+/// Floating windows can be moved by dragging their tab bar, and docked again by dropping them onto a tab bar.
+///
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// struct MyTabViewer;
+///
+/// impl egui_extras::TabViewer for MyTabViewer {
+///     type Tab = String;
+///
+///     fn title(&mut self, tab: &mut String) -> egui::WidgetText {
+///         tab.as_str().into()
+///     }
+///
+///     fn ui(&mut self, ui: &mut egui::Ui, tab: &mut String) {
+///         ui.label(format!("This is {tab}"));
+///     }
+/// }
+///
+/// # let mut dock = egui_extras::DockState::new(vec!["First".to_owned(), "Second".to_owned()]);
+/// egui_extras::DockArea::new(&mut dock).show(ctx, &mut MyTabViewer);
+/// # });
+/// ```
+pub struct DockArea<'a, Tab> {
+    state: &'a mut DockState<Tab>,
+    id_source: Id,
+    allow_tear_off: bool,
+}
+
+impl<'a, Tab> DockArea<'a, Tab> {
+    pub fn new(state: &'a mut DockState<Tab>) -> Self {
+        Self {
+            state,
+            id_source: Id::new("dock_area"),
+            allow_tear_off: true,
+        }
+    }
+
+    /// Must be set if you show more than one [`DockArea`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Id::new(id_source);
+        self
+    }
+
+    /// Can tabs be dragged out into floating windows?
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn allow_tear_off(mut self, allow_tear_off: bool) -> Self {
+        self.allow_tear_off = allow_tear_off;
+        self
+    }
+
+    /// Show the tabs in a [`egui::CentralPanel`], with the floating windows on top.
+    pub fn show(self, ctx: &egui::Context, viewer: &mut impl TabViewer<Tab = Tab>) {
+        egui::CentralPanel::default()
+            .frame(Frame::central_panel(&ctx.style()).inner_margin(0.0))
+            .show(ctx, |ui| self.show_inside(ui, viewer));
+    }
+
+    /// Show the tabs in all of the available space of the given [`Ui`],
+    /// with the floating windows on top.
+    pub fn show_inside(self, ui: &mut Ui, viewer: &mut impl TabViewer<Tab = Tab>) {
+        let Self {
+            state,
+            id_source,
+            allow_tear_off,
+        } = self;
+        let id = id_source;
+
+        let rect = ui.available_rect_before_wrap();
+        ui.allocate_rect(rect, Sense::hover());
+
+        let mut interaction = Interaction::default();
+        node_ui(
+            ui,
+            id,
+            &mut state.root,
+            rect,
+            &mut LeafAddr {
+                surface: Surface::Main,
+                path: vec![],
+            },
+            viewer,
+            &mut interaction,
+        );
+
+        let ctx = ui.ctx().clone();
+        for window in &mut state.windows {
+            let surface = Surface::Window(window.id);
+            let response = egui::Window::new("")
+                .id(id.with(surface))
+                .title_bar(false)
+                .collapsible(false)
+                .movable(false) // We move it ourselves, by the tab bar
+                .resizable(true)
+                .current_pos(window.pos)
+                .default_size(window.size)
+                .frame(Frame::window(&ctx.style()).inner_margin(0.0))
+                .show(&ctx, |ui| {
+                    let rect = ui.available_rect_before_wrap();
+                    ui.allocate_rect(rect, Sense::hover());
+                    node_ui(
+                        ui,
+                        id,
+                        &mut window.root,
+                        rect,
+                        &mut LeafAddr {
+                            surface,
+                            path: vec![],
+                        },
+                        viewer,
+                        &mut interaction,
+                    );
+                    rect.size()
+                });
+            if let Some(size) = response.and_then(|response| response.inner) {
+                window.size = size;
+            }
+        }
+
+        let Interaction {
+            drop_targets,
+            dragged,
+            released,
+            window_delta,
+            close,
+        } = interaction;
+
+        if let Some((window_id, delta)) = window_delta {
+            if let Some(window) = state.windows.iter_mut().find(|w| w.id == window_id) {
+                window.pos += delta;
+            }
+        }
+
+        if let Some((leaf, index)) = close {
+            if let Some((tabs, _)) = state.leaf_tabs_mut(&leaf) {
+                if index < tabs.len() && viewer.on_close(&mut tabs[index]) {
+                    tabs.remove(index);
+                    state.simplify();
+                }
+            }
+        }
+
+        let Some(pointer) = ctx.input(|i| i.pointer.latest_pos()) else {
+            return;
+        };
+
+        if let Some(source) = &dragged {
+            ctx.set_cursor_icon(CursorIcon::Grabbing);
+            let painter = ctx.layer_painter(LayerId::new(Order::Tooltip, id.with("drop_preview")));
+            let color = ctx.style().visuals.selection.bg_fill;
+            let preview = match find_drop_target(&ctx, &drop_targets, source, pointer) {
+                Some((target, zone)) => Some(zone_rect(target.rect, zone)),
+                None if allow_tear_off && matches!(source, DragSource::Tab { .. }) => Some(
+                    Rect::from_min_size(pointer, vec2(200.0, 150.0)).translate(vec2(-20.0, -10.0)),
+                ),
+                None => None,
+            };
+            if let Some(preview) = preview {
+                painter.rect(
+                    preview,
+                    2.0,
+                    color.gamma_multiply(0.3),
+                    Stroke::new(1.0, color),
+                );
+            }
+        }
+
+        if let Some(source) = released {
+            let target = find_drop_target(&ctx, &drop_targets, &source, pointer)
+                .map(|(target, zone)| (target.leaf.clone(), zone));
+            match source {
+                DragSource::Tab { leaf, index } => {
+                    move_tab(state, &leaf, index, target, pointer, allow_tear_off);
+                }
+                DragSource::Window(window_id) => {
+                    if let Some((target, zone)) = target {
+                        if let Some(index) = state.windows.iter().position(|w| w.id == window_id) {
+                            let window = state.windows.remove(index);
+                            state.insert(&target, zone, window.root);
+                            state.simplify();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn move_tab<Tab>(
+    state: &mut DockState<Tab>,
+    leaf: &LeafAddr,
+    index: usize,
+    target: Option<(LeafAddr, DropZone)>,
+    pointer: Pos2,
+    allow_tear_off: bool,
+) {
+    let num_tabs = state.leaf_tabs_mut(leaf).map_or(0, |(tabs, _)| tabs.len());
+    if num_tabs <= index {
+        return;
+    }
+    match &target {
+        Some((target, DropZone::Center)) if target == leaf => return,
+        Some((target, DropZone::Side(_))) if target == leaf && num_tabs == 1 => return,
+        None if !allow_tear_off => return,
+        _ => {}
+    }
+
+    let Some((tabs, _)) = state.leaf_tabs_mut(leaf) else {
+        return;
+    };
+    let tab = tabs.remove(index);
+
+    // Removing the tab left the tree structure intact, so the target address is still valid.
+    if let Some((target, zone)) = target {
+        state.insert(&target, zone, Node::leaf(vec![tab]));
+    } else {
+        let rect = Rect::from_min_size(pointer, vec2(300.0, 200.0)).translate(vec2(-20.0, -10.0));
+        state.add_window(vec![tab], rect);
+    }
+    state.simplify();
+}
+
+/// The tab bar under the pointer, and where on it the pointer is.
+fn find_drop_target<'t>(
+    ctx: &egui::Context,
+    targets: &'t [DropTarget],
+    source: &DragSource,
+    pointer: Pos2,
+) -> Option<(&'t DropTarget, DropZone)> {
+    let candidates = targets.iter().filter(|target| {
+        target.rect.contains(pointer)
+            && match source {
+                DragSource::Window(id) => target.leaf.surface != Surface::Window(*id),
+                DragSource::Tab { .. } => true,
+            }
+    });
+
+    // Prefer whatever is on top, and floating windows over the main area:
+    let top_layer = ctx.layer_id_at(pointer);
+    let target = candidates
+        .max_by_key(|target| (Some(target.layer_id) == top_layer, target.layer_id.order))?;
+
+    let rel = (pointer - target.rect.min) / target.rect.size();
+    let edges = [
+        (rel.x, DockSide::Left),
+        (1.0 - rel.x, DockSide::Right),
+        (rel.y, DockSide::Top),
+        (1.0 - rel.y, DockSide::Bottom),
+    ];
+    let (distance, side) = edges
+        .into_iter()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap_or((1.0, DockSide::Left));
+    let zone = if distance < 0.25 {
+        DropZone::Side(side)
+    } else {
+        DropZone::Center
+    };
+    Some((target, zone))
+}
+
+/// The area that a tab dropped in the given zone will get.
+fn zone_rect(rect: Rect, zone: DropZone) -> Rect {
+    let center = rect.center();
+    match zone {
+        DropZone::Center => rect,
+        DropZone::Side(DockSide::Left) => rect.split_left_right_at_x(center.x).0,
+        DropZone::Side(DockSide::Right) => rect.split_left_right_at_x(center.x).1,
+        DropZone::Side(DockSide::Top) => rect.split_top_bottom_at_y(center.y).0,
+        DropZone::Side(DockSide::Bottom) => rect.split_top_bottom_at_y(center.y).1,
+    }
+}
+
+fn node_ui<Tab>(
+    ui: &mut Ui,
+    id: Id,
+    node: &mut Node<Tab>,
+    rect: Rect,
+    addr: &mut LeafAddr,
+    viewer: &mut impl TabViewer<Tab = Tab>,
+    interaction: &mut Interaction,
+) {
+    match node {
+        Node::Empty => {
+            interaction.drop_targets.push(DropTarget {
+                leaf: addr.clone(),
+                rect,
+                layer_id: ui.layer_id(),
+            });
+        }
+
+        Node::Leaf { tabs, active } => {
+            leaf_ui(ui, id, tabs, active, rect, addr, viewer, interaction);
+        }
+
+        Node::Split {
+            direction,
+            fraction,
+            children,
+        } => {
+            let separator_width = 1.0;
+            let (first_rect, second_rect, separator_rect) = match direction {
+                SplitDirection::Horizontal => {
+                    let x = rect.left() + *fraction * rect.width();
+                    let (first, second) = rect.split_left_right_at_x(x);
+                    let separator = Rect::from_x_y_ranges(x..=x, rect.y_range())
+                        .expand2(vec2(separator_width, 0.0));
+                    (first, second, separator)
+                }
+                SplitDirection::Vertical => {
+                    let y = rect.top() + *fraction * rect.height();
+                    let (first, second) = rect.split_top_bottom_at_y(y);
+                    let separator = Rect::from_x_y_ranges(rect.x_range(), y..=y)
+                        .expand2(vec2(0.0, separator_width));
+                    (first, second, separator)
+                }
+            };
+
+            for (i, (child, child_rect)) in children
+                .iter_mut()
+                .zip([first_rect, second_rect])
+                .enumerate()
+            {
+                addr.path.push(i);
+                node_ui(ui, id, child, child_rect, addr, viewer, interaction);
+                addr.path.pop();
+            }
+
+            // Interact with the separator last, so that it is on top of the tabs:
+            let grab_rect = separator_rect.expand(ui.style().interaction.resize_grab_radius_side);
+            let response = ui.interact(grab_rect, id.with(&*addr).with("separator"), Sense::drag());
+            let cursor = match direction {
+                SplitDirection::Horizontal => CursorIcon::ResizeHorizontal,
+                SplitDirection::Vertical => CursorIcon::ResizeVertical,
+            };
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(cursor);
+            }
+            if let Some(pointer) = response
+                .interact_pointer_pos()
+                .filter(|_| response.dragged())
+            {
+                let new_fraction = match direction {
+                    SplitDirection::Horizontal => (pointer.x - rect.left()) / rect.width(),
+                    SplitDirection::Vertical => (pointer.y - rect.top()) / rect.height(),
+                };
+                if new_fraction.is_finite() {
+                    *fraction = new_fraction.clamp(0.1, 0.9);
+                }
+            }
+
+            let stroke = if response.hovered() || response.dragged() {
+                ui.visuals().widgets.hovered.bg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            ui.painter().rect_filled(separator_rect, 0.0, stroke.color);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn leaf_ui<Tab>(
+    ui: &mut Ui,
+    id: Id,
+    tabs: &mut [Tab],
+    active: &mut usize,
+    rect: Rect,
+    addr: &LeafAddr,
+    viewer: &mut impl TabViewer<Tab = Tab>,
+    interaction: &mut Interaction,
+) {
+    let leaf_id = id.with(addr);
+    let visuals = ui.visuals().clone();
+    let tab_bar_height = ui.spacing().interact_size.y + 4.0;
+    let (tab_bar_rect, body_rect) = rect.split_top_bottom_at_y(rect.top() + tab_bar_height);
+    let painter = ui.painter_at(rect);
+
+    painter.rect_filled(tab_bar_rect, 0.0, visuals.extreme_bg_color);
+    painter.rect_filled(body_rect, 0.0, visuals.panel_fill);
+
+    // Behind the tabs, so that the tabs get the clicks and drags:
+    let tab_bar_response = ui.interact(tab_bar_rect, leaf_id.with("tab_bar"), Sense::drag());
+    if let Surface::Window(window_id) = addr.surface {
+        if tab_bar_response.dragged() {
+            interaction.window_delta = Some((window_id, tab_bar_response.drag_delta()));
+            interaction.dragged = Some(DragSource::Window(window_id));
+        }
+        if tab_bar_response.drag_stopped() {
+            interaction.released = Some(DragSource::Window(window_id));
+        }
+    }
+
+    let padding = ui.spacing().button_padding.x;
+    let mut x = tab_bar_rect.left();
+    for (index, tab) in tabs.iter_mut().enumerate() {
+        let is_active = index == *active;
+        let galley = viewer.title(tab).into_galley(
+            ui,
+            Some(TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Button,
+        );
+        let closeable = viewer.closeable(tab);
+        let close_size = if closeable {
+            ui.spacing().icon_width
+        } else {
+            0.0
+        };
+
+        let tab_width = galley.size().x + 2.0 * padding + close_size;
+        let tab_rect = Rect::from_min_size(
+            egui::pos2(x, tab_bar_rect.top()),
+            vec2(tab_width, tab_bar_height),
+        );
+        x += tab_width + 1.0;
+
+        let tab_id = leaf_id.with(index);
+        let response = ui.interact(tab_rect, tab_id, Sense::click_and_drag());
+        let close_rect = Rect::from_center_size(
+            egui::pos2(
+                tab_rect.right() - padding - 0.5 * close_size,
+                tab_rect.center().y,
+            ),
+            Vec2::splat(close_size),
+        );
+        let close_response =
+            closeable.then(|| ui.interact(close_rect, tab_id.with("close"), Sense::click()));
+
+        if response.clicked() {
+            *active = index;
+        }
+        if response.dragged() {
+            interaction.dragged = Some(DragSource::Tab {
+                leaf: addr.clone(),
+                index,
+            });
+        }
+        if response.drag_stopped() {
+            interaction.released = Some(DragSource::Tab {
+                leaf: addr.clone(),
+                index,
+            });
+        }
+        if close_response.as_ref().map_or(false, Response::clicked) {
+            interaction.close = Some((addr.clone(), index));
+        }
+
+        let fill = if is_active {
+            visuals.panel_fill
+        } else if response.hovered() {
+            visuals.widgets.hovered.weak_bg_fill
+        } else {
+            Color32::TRANSPARENT
+        };
+        painter.rect_filled(tab_rect, 0.0, fill);
+        let text_color = if is_active {
+            visuals.strong_text_color()
+        } else {
+            visuals.text_color()
+        };
+        let text_pos = egui::pos2(
+            tab_rect.left() + padding,
+            tab_rect.center().y - 0.5 * galley.size().y,
+        );
+        painter.galley(text_pos, galley, text_color);
+
+        if let Some(close_response) = close_response {
+            let stroke = ui.style().interact(&close_response).fg_stroke;
+            let cross = close_rect.shrink(0.25 * close_size);
+            painter.line_segment([cross.left_top(), cross.right_bottom()], stroke);
+            painter.line_segment([cross.right_top(), cross.left_bottom()], stroke);
+        }
+    }
+
+    if let Some(tab) = tabs.get_mut(*active) {
+        let tab_id = viewer.id(tab);
+        let mut tab_ui = ui.child_ui_with_id_source(
+            body_rect.shrink(ui.spacing().window_margin.left),
+            Layout::top_down(Align::Min),
+            tab_id,
+            None,
+        );
+        tab_ui.set_clip_rect(body_rect.intersect(ui.clip_rect()));
+        viewer.ui(&mut tab_ui, tab);
+    }
+
+    interaction.drop_targets.push(DropTarget {
+        leaf: addr.clone(),
+        rect,
+        layer_id: ui.layer_id(),
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_tabs() {
+        let main = |path: Vec<usize>| LeafAddr {
+            surface: Surface::Main,
+            path,
+        };
+        let tabs = |state: &DockState<i32>| state.iter_tabs().copied().collect::<Vec<_>>();
+
+        let mut state = DockState::new(vec![1, 2, 3]);
+
+        // Dropping a tab on the side of its own tab bar splits it:
+        let target = Some((main(vec![]), DropZone::Side(DockSide::Right)));
+        move_tab(&mut state, &main(vec![]), 0, target, Pos2::ZERO, true);
+        assert_eq!(tabs(&state), vec![2, 3, 1]);
+        assert!(matches!(
+            state.root,
+            Node::Split {
+                direction: SplitDirection::Horizontal,
+                ..
+            }
+        ));
+
+        // Tear off into a window:
+        move_tab(&mut state, &main(vec![0]), 0, None, Pos2::ZERO, true);
+        assert_eq!(state.num_windows(), 1);
+        assert_eq!(tabs(&state), vec![3, 1, 2]);
+
+        // Dock the window as a tab:
+        let window = state.windows.remove(0);
+        state.insert(&main(vec![1]), DropZone::Center, window.root);
+        state.simplify();
+        assert_eq!(tabs(&state), vec![3, 1, 2]);
+
+        // Moving the last tab out of a tab bar removes the split:
+        let target = Some((main(vec![1]), DropZone::Center));
+        move_tab(&mut state, &main(vec![0]), 0, target, Pos2::ZERO, true);
+        assert_eq!(tabs(&state), vec![1, 2, 3]);
+        assert!(matches!(state.root, Node::Leaf { active: 2, .. }));
+
+        state.retain_tabs(|tab| *tab != 2);
+        assert_eq!(tabs(&state), vec![1, 3]);
+    }
+}
@@ -14,21 +14,26 @@ mod datepicker;
 
 pub mod syntax_highlighting;
 
+mod dock;
 #[doc(hidden)]
 pub mod image;
 mod layout;
 mod loaders;
+mod log_view;
 mod sizing;
 mod strip;
 mod table;
 
 #[cfg(feature = "chrono")]
-pub use crate::datepicker::DatePickerButton;
+pub use crate::datepicker::{DatePickerButton, TimePicker};
+
+pub use crate::dock::{DockArea, DockSide, DockState, TabViewer};
 
 #[doc(hidden)]
 #[allow(deprecated)]
 pub use crate::image::RetainedImage;
 pub(crate) use crate::layout::StripLayout;
+pub use crate::log_view::{LogBuffer, LogView};
 pub use crate::sizing::Size;
 pub use crate::strip::*;
 pub use crate::table::*;
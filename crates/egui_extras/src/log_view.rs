@@ -0,0 +1,477 @@
+use egui::{
+    text::LayoutJob, Color32, FontId, Id, Label, ScrollArea, Stroke, TextFormat, TextStyle,
+    TextWrapMode, Ui,
+};
+
+/// Append-only text with [ANSI color codes](https://en.wikipedia.org/wiki/ANSI_escape_code#SGR_(Select_Graphic_Rendition)_parameters),
+/// e.g. the output of a process or a log, shown with [`LogView`].
+///
+/// The text is parsed as it is pushed, so that showing it is cheap,
+/// even for hundreds of thousands of lines.
+///
+/// SGR codes (colors, bold, italics, underline, …) are parsed into styled spans.
+/// Other escape sequences are removed.
+#[derive(Clone, Debug, Default)]
+pub struct LogBuffer {
+    /// The last line is the one we are currently appending to.
+    lines: Vec<LogLine>,
+
+    /// The style at the end of the text.
+    style: AnsiStyle,
+
+    /// An incomplete escape sequence at the end of the last push.
+    pending_escape: String,
+
+    max_lines: Option<usize>,
+}
+
+/// A single line of a [`LogBuffer`].
+#[derive(Clone, Debug, Default, PartialEq)]
+struct LogLine {
+    /// The text, without any escape codes.
+    text: String,
+
+    /// Byte ranges of `text` and their style.
+    spans: Vec<(std::ops::Range<usize>, AnsiStyle)>,
+}
+
+impl LogLine {
+    fn push_str(&mut self, text: &str, style: AnsiStyle) {
+        if text.is_empty() {
+            return;
+        }
+        let start = self.text.len();
+        self.text += text;
+        let end = self.text.len();
+
+        match self.spans.last_mut() {
+            Some((range, last_style)) if *last_style == style => range.end = end,
+            _ => self.spans.push((start..end, style)),
+        }
+    }
+}
+
+/// Parsed ANSI SGR state.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct AnsiStyle {
+    foreground: Option<Color32>,
+    background: Option<Color32>,
+    bold: bool,
+    dim: bool,
+    italics: bool,
+    underline: bool,
+    strikethrough: bool,
+    inverse: bool,
+}
+
+impl LogBuffer {
+    /// Keep at most this many lines, removing the oldest ones.
+    ///
+    /// By default there is no limit.
+    #[inline]
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = Some(max_lines);
+        self.enforce_max_lines();
+        self
+    }
+
+    /// Append text, which may contain ANSI escape codes and newlines.
+    ///
+    /// The text does not need to end with a newline,
+    /// and may even end in the middle of an escape code.
+    pub fn push_str(&mut self, text: &str) {
+        crate::profile_function!();
+
+        let text = if self.pending_escape.is_empty() {
+            std::borrow::Cow::Borrowed(text)
+        } else {
+            let mut pending = std::mem::take(&mut self.pending_escape);
+            pending.push_str(text);
+            std::borrow::Cow::Owned(pending)
+        };
+
+        if self.lines.is_empty() {
+            self.lines.push(LogLine::default());
+        }
+
+        let mut rest = &text[..];
+        while !rest.is_empty() {
+            let plain_len = rest.find(['\x1b', '\n', '\r']).unwrap_or(rest.len());
+            let (plain, after) = rest.split_at(plain_len);
+            let style = self.style;
+            self.current_line().push_str(plain, style);
+            rest = after;
+
+            if let Some(after) = rest.strip_prefix('\n') {
+                self.lines.push(LogLine::default());
+                rest = after;
+            } else if let Some(after) = rest.strip_prefix('\r') {
+                // We only support `\r\n`, so we just ignore the `\r`.
+                rest = after;
+            } else if rest.starts_with('\x1b') {
+                if let Some((escape, after)) = parse_escape(rest) {
+                    if let Some(params) = escape
+                        .strip_prefix("\x1b[")
+                        .and_then(|e| e.strip_suffix('m'))
+                    {
+                        apply_sgr(&mut self.style, params);
+                    }
+                    rest = after;
+                } else {
+                    // Incomplete - wait for the rest of it:
+                    self.pending_escape = rest.to_owned();
+                    break;
+                }
+            }
+        }
+
+        self.enforce_max_lines();
+    }
+
+    /// Remove all text and reset the style.
+    pub fn clear(&mut self) {
+        let max_lines = self.max_lines;
+        *self = Self::default();
+        self.max_lines = max_lines;
+    }
+
+    /// Number of lines, including the last (maybe incomplete) one.
+    #[inline]
+    pub fn num_lines(&self) -> usize {
+        self.lines.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.lines.iter().all(|line| line.text.is_empty())
+    }
+
+    /// The text of the given line, without escape codes.
+    pub fn line(&self, index: usize) -> Option<&str> {
+        self.lines.get(index).map(|line| line.text.as_str())
+    }
+
+    /// All the text, without escape codes.
+    ///
+    /// Useful for a "copy all" button.
+    pub fn text(&self) -> String {
+        let mut text = String::new();
+        for (i, line) in self.lines.iter().enumerate() {
+            if i != 0 {
+                text.push('\n');
+            }
+            text += &line.text;
+        }
+        text
+    }
+
+    fn current_line(&mut self) -> &mut LogLine {
+        self.lines
+            .last_mut()
+            .expect("We always have a current line")
+    }
+
+    fn enforce_max_lines(&mut self) {
+        if let Some(max_lines) = self.max_lines {
+            if max_lines < self.lines.len() {
+                self.lines.drain(..self.lines.len() - max_lines);
+            }
+        }
+    }
+
+    fn layout_job(&self, index: usize, font_id: &FontId, ui: &Ui) -> LayoutJob {
+        let mut job = LayoutJob::default();
+        let Some(line) = self.lines.get(index) else {
+            return job;
+        };
+
+        let visuals = ui.visuals();
+        for (range, style) in &line.spans {
+            let mut color = style.foreground.unwrap_or(if style.bold {
+                visuals.strong_text_color()
+            } else {
+                visuals.text_color()
+            });
+            let mut background = style.background.unwrap_or(Color32::TRANSPARENT);
+            if style.inverse {
+                let inverse_background = if background == Color32::TRANSPARENT {
+                    visuals.extreme_bg_color
+                } else {
+                    background
+                };
+                background = color;
+                color = inverse_background;
+            }
+            if style.dim {
+                color = color.gamma_multiply(0.6);
+            }
+
+            let stroke = |enabled: bool| {
+                if enabled {
+                    Stroke::new(1.0, color)
+                } else {
+                    Stroke::NONE
+                }
+            };
+
+            job.append(
+                &line.text[range.clone()],
+                0.0,
+                TextFormat {
+                    font_id: font_id.clone(),
+                    color,
+                    background,
+                    italics: style.italics,
+                    underline: stroke(style.underline),
+                    strikethrough: stroke(style.strikethrough),
+                    ..Default::default()
+                },
+            );
+        }
+
+        if job.sections.is_empty() {
+            // Make sure empty lines get the same height as the others:
+            job.append(
+                "",
+                0.0,
+                TextFormat::simple(font_id.clone(), visuals.text_color()),
+            );
+        }
+
+        job
+    }
+}
+
+/// Returns the escape sequence at the start of `text` and what follows it,
+/// or `None` if it is incomplete.
+fn parse_escape(text: &str) -> Option<(&str, &str)> {
+    let after_esc = &text[1..];
+    let len = if let Some(csi) = after_esc.strip_prefix('[') {
+        // Control Sequence: parameters and intermediate bytes, ended by a byte in `@..=~`:
+        let end = csi.find(|c| ('@'..='~').contains(&c))?;
+        2 + end + 1
+    } else if let Some(osc) = after_esc.strip_prefix(']') {
+        // Operating System Command (e.g. window title), ended by BEL or ST:
+        if let Some(end) = osc.find('\x07') {
+            2 + end + 1
+        } else {
+            2 + osc.find("\x1b\\")? + 2
+        }
+    } else {
+        1 + after_esc.chars().next()?.len_utf8()
+    };
+    Some(text.split_at(len))
+}
+
+fn apply_sgr(style: &mut AnsiStyle, params: &str) {
+    let mut codes = params
+        .split(';')
+        .map(|code| code.parse::<u8>().unwrap_or(0));
+
+    while let Some(code) = codes.next() {
+        match code {
+            0 => *style = AnsiStyle::default(),
+            1 => style.bold = true,
+            2 => style.dim = true,
+            3 => style.italics = true,
+            4 => style.underline = true,
+            7 => style.inverse = true,
+            9 => style.strikethrough = true,
+            22 => {
+                style.bold = false;
+                style.dim = false;
+            }
+            23 => style.italics = false,
+            24 => style.underline = false,
+            27 => style.inverse = false,
+            29 => style.strikethrough = false,
+            30..=37 => style.foreground = Some(ansi_color(code - 30)),
+            38 => style.foreground = extended_color(&mut codes),
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(ansi_color(code - 40)),
+            48 => style.background = extended_color(&mut codes),
+            49 => style.background = None,
+            90..=97 => style.foreground = Some(ansi_color(code - 90 + 8)),
+            100..=107 => style.background = Some(ansi_color(code - 100 + 8)),
+            _ => {}
+        }
+    }
+}
+
+/// `38;5;n` and `38;2;r;g;b` (and the same for `48`).
+fn extended_color(codes: &mut impl Iterator<Item = u8>) -> Option<Color32> {
+    match codes.next()? {
+        5 => Some(ansi_color(codes.next()?)),
+        2 => Some(Color32::from_rgb(
+            codes.next()?,
+            codes.next()?,
+            codes.next()?,
+        )),
+        _ => None,
+    }
+}
+
+/// One of the 256 colors of xterm.
+fn ansi_color(index: u8) -> Color32 {
+    const BASIC: [Color32; 16] = [
+        Color32::from_rgb(0, 0, 0),
+        Color32::from_rgb(205, 49, 49),
+        Color32::from_rgb(13, 188, 121),
+        Color32::from_rgb(229, 229, 16),
+        Color32::from_rgb(36, 114, 200),
+        Color32::from_rgb(188, 63, 188),
+        Color32::from_rgb(17, 168, 203),
+        Color32::from_rgb(229, 229, 229),
+        Color32::from_rgb(102, 102, 102),
+        Color32::from_rgb(241, 76, 76),
+        Color32::from_rgb(35, 209, 139),
+        Color32::from_rgb(245, 245, 67),
+        Color32::from_rgb(59, 142, 234),
+        Color32::from_rgb(214, 112, 214),
+        Color32::from_rgb(41, 184, 219),
+        Color32::from_rgb(255, 255, 255),
+    ];
+
+    match index {
+        0..=15 => BASIC[index as usize],
+        16..=231 => {
+            // 6x6x6 color cube:
+            let index = index - 16;
+            let level = |i: u8| if i == 0 { 0 } else { 55 + 40 * i };
+            Color32::from_rgb(level(index / 36), level((index / 6) % 6), level(index % 6))
+        }
+        232..=255 => {
+            let gray = 8 + 10 * (index - 232);
+            Color32::from_gray(gray)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Shows a [`LogBuffer`] in a scroll area.
+///
+/// Only the visible rows are laid out, so this is fast even for very long logs.
+/// The text is selectable, and can be copied with the usual shortcuts.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut log = egui_extras::LogBuffer::default(); // Keep this in your app state
+/// log.push_str("\x1b[32mINFO\x1b[0m Starting up\n");
+/// log.push_str("\x1b[1;31mERROR\x1b[0m Something went wrong\n");
+///
+/// egui_extras::LogView::new(&log).show(ui);
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct LogView<'a> {
+    buffer: &'a LogBuffer,
+    id_source: Id,
+    follow_tail: bool,
+    max_height: f32,
+    text_style: TextStyle,
+}
+
+impl<'a> LogView<'a> {
+    pub fn new(buffer: &'a LogBuffer) -> Self {
+        Self {
+            buffer,
+            id_source: Id::new("log_view"),
+            follow_tail: true,
+            max_height: f32::INFINITY,
+            text_style: TextStyle::Monospace,
+        }
+    }
+
+    /// A source for the unique [`Id`], e.g. `.id_source("build_log")` or `.id_source(index)`.
+    ///
+    /// Needed if you have more than one [`LogView`] in the same [`Ui`].
+    #[inline]
+    pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
+        self.id_source = Id::new(id_source);
+        self
+    }
+
+    /// If `true` (default), stay scrolled to the bottom when new lines are added,
+    /// as long as the user is already scrolled to the bottom.
+    #[inline]
+    pub fn follow_tail(mut self, follow_tail: bool) -> Self {
+        self.follow_tail = follow_tail;
+        self
+    }
+
+    /// The maximum height of the view. Default: all available height.
+    #[inline]
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// The text style to use. Default: [`TextStyle::Monospace`].
+    #[inline]
+    pub fn text_style(mut self, text_style: TextStyle) -> Self {
+        self.text_style = text_style;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> egui::scroll_area::ScrollAreaOutput<()> {
+        let Self {
+            buffer,
+            id_source,
+            follow_tail,
+            max_height,
+            text_style,
+        } = self;
+
+        let font_id = text_style.resolve(ui.style());
+        let row_height = ui.fonts(|f| f.row_height(&font_id));
+
+        ScrollArea::both()
+            .id_source(id_source)
+            .auto_shrink(false)
+            .max_height(max_height)
+            .stick_to_bottom(follow_tail)
+            .show_rows(ui, row_height, buffer.num_lines(), |ui, row_range| {
+                for row in row_range {
+                    let job = buffer.layout_job(row, &font_id, ui);
+                    ui.add(
+                        Label::new(job)
+                            .wrap_mode(TextWrapMode::Extend)
+                            .selectable(true),
+                    );
+                }
+            })
+    }
+}
+
+#[test]
+fn test_log_buffer() {
+    let mut log = LogBuffer::default();
+    log.push_str("plain \x1b[31mred\x1b[0m\r\n\x1b[1;38;5;46mbold");
+    log.push_str(" green\x1b[");
+    log.push_str("0m reset\n\x1b]0;title\x07next");
+
+    assert_eq!(log.text(), "plain red\nbold green reset\nnext");
+    assert_eq!(log.num_lines(), 3);
+
+    let red = AnsiStyle {
+        foreground: Some(ansi_color(1)),
+        ..Default::default()
+    };
+    let bold_green = AnsiStyle {
+        foreground: Some(Color32::from_rgb(0, 255, 0)),
+        bold: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        log.lines[0].spans,
+        vec![(0..6, AnsiStyle::default()), (6..9, red)]
+    );
+    assert_eq!(
+        log.lines[1].spans,
+        vec![(0..10, bold_green), (10..16, AnsiStyle::default())]
+    );
+
+    let log = log.with_max_lines(2);
+    assert_eq!(log.text(), "bold green reset\nnext");
+}
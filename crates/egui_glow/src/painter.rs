@@ -83,6 +83,9 @@ pub struct Painter {
     program: glow::Program,
     u_screen_size: glow::UniformLocation,
     u_sampler: glow::UniformLocation,
+    u_has_color_filter: glow::UniformLocation,
+    u_color_matrix: glow::UniformLocation,
+    u_color_offset: glow::UniformLocation,
     is_webgl_1: bool,
     vao: crate::vao::VertexArrayObject,
     srgb_textures: bool,
@@ -212,6 +215,11 @@ impl Painter {
             gl.delete_shader(frag);
             let u_screen_size = gl.get_uniform_location(program, "u_screen_size").unwrap();
             let u_sampler = gl.get_uniform_location(program, "u_sampler").unwrap();
+            let u_has_color_filter = gl
+                .get_uniform_location(program, "u_has_color_filter")
+                .unwrap();
+            let u_color_matrix = gl.get_uniform_location(program, "u_color_matrix").unwrap();
+            let u_color_offset = gl.get_uniform_location(program, "u_color_offset").unwrap();
 
             let vbo = gl.create_buffer()?;
 
@@ -258,6 +266,9 @@ impl Painter {
                 program,
                 u_screen_size,
                 u_sampler,
+                u_has_color_filter,
+                u_color_matrix,
+                u_color_offset,
                 is_webgl_1,
                 vao,
                 srgb_textures,
@@ -482,6 +493,20 @@ impl Painter {
                 );
 
                 self.gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+
+                let has_color_filter = !mesh.color_filter.is_identity();
+                self.gl
+                    .uniform_1_i32(Some(&self.u_has_color_filter), has_color_filter as i32);
+                if has_color_filter {
+                    let [c0, c1, c2, c3, offset] = mesh.color_filter.columns();
+                    self.gl.uniform_matrix_4_f32_slice(
+                        Some(&self.u_color_matrix),
+                        false,
+                        bytemuck::cast_slice(&[c0, c1, c2, c3]),
+                    );
+                    self.gl
+                        .uniform_4_f32_slice(Some(&self.u_color_offset), &offset);
+                }
             }
 
             unsafe {
@@ -526,6 +551,20 @@ impl Painter {
 
                 self.upload_texture_srgb(delta.pos, image.size, delta.options, data);
             }
+            egui::ImageData::Palette(image) => {
+                assert_eq!(
+                    image.width() * image.height(),
+                    image.indices.len(),
+                    "Mismatch between texture size and texel count"
+                );
+
+                let data: Vec<u8> = {
+                    crate::profile_scope!("palette -> sRGBA");
+                    image.srgba_pixels().flat_map(|a| a.to_array()).collect()
+                };
+
+                self.upload_texture_srgb(delta.pos, image.size, delta.options, &data);
+            }
             egui::ImageData::Font(image) => {
                 assert_eq!(
                     image.width() * image.height(),
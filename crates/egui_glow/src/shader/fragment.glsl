@@ -3,6 +3,9 @@
 #endif
 
 uniform sampler2D u_sampler;
+uniform bool u_has_color_filter;
+uniform mat4 u_color_matrix;
+uniform vec4 u_color_offset;
 
 #if NEW_SHADER_INTERFACE
     in vec4 v_rgba_in_gamma;
@@ -29,6 +32,16 @@ vec4 srgba_gamma_from_linear(vec4 rgba) {
     return vec4(srgb_gamma_from_linear(rgba.rgb), rgba.a);
 }
 
+// The `color_filter` of the mesh, on a premultiplied gamma color
+vec4 filter_color(vec4 rgba) {
+    if (!u_has_color_filter) {
+        return rgba;
+    }
+    vec4 unmultiplied = rgba.a > 0.0 ? vec4(rgba.rgb / rgba.a, rgba.a) : vec4(0.0);
+    vec4 filtered = clamp(u_color_matrix * unmultiplied + u_color_offset, 0.0, 1.0);
+    return vec4(filtered.rgb * filtered.a, filtered.a);
+}
+
 void main() {
 #if SRGB_TEXTURES
     vec4 texture_in_gamma = srgba_gamma_from_linear(texture2D(u_sampler, v_tc));
@@ -37,5 +50,5 @@ void main() {
 #endif
 
     // We multiply the colors in gamma space, because that's the only way to get text to look right.
-    gl_FragColor = v_rgba_in_gamma * texture_in_gamma;
+    gl_FragColor = v_rgba_in_gamma * filter_color(texture_in_gamma);
 }
@@ -121,6 +121,11 @@ impl Bar {
         }
     }
 
+    /// The end of the bar, where its value is.
+    pub(super) fn value_end(&self) -> PlotPoint {
+        self.point_at(self.argument, self.base_offset.unwrap_or(0.0) + self.value)
+    }
+
     pub(super) fn add_shapes(
         &self,
         transform: &PlotTransform,
@@ -0,0 +1,177 @@
+use egui::emath::NumExt as _;
+use egui::epaint::{Color32, RectShape, Rounding, Shape, Stroke};
+
+use crate::{Candlestick, Cursor, PlotPoint, PlotTransform};
+
+use super::{add_rulers_and_text, highlighted_color, Orientation, PlotConfig, RectElement};
+
+/// One candle in a [`Candlestick`] chart, with the open, high, low and close values at an x value,
+/// e.g. the prices of a stock during a day.
+///
+/// The candle is colored by its parent [`Candlestick`],
+/// depending on whether the value went up (`open <= close`) or down.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candle {
+    /// Name of plot element in the diagram (annotated by default formatter).
+    pub name: String,
+
+    /// Position on the X axis, usually the start or middle of the time span of the candle.
+    pub x: f64,
+
+    /// The first value.
+    pub open: f64,
+
+    /// The highest value.
+    pub high: f64,
+
+    /// The lowest value.
+    pub low: f64,
+
+    /// The last value.
+    pub close: f64,
+
+    /// Width of the body of the candle, in X axis units.
+    pub width: f64,
+}
+
+impl Candle {
+    /// Create a candle. Check [`Candle`] fields for detailed description.
+    pub fn new(x: f64, open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            name: String::default(),
+            x,
+            open,
+            high,
+            low,
+            close,
+            width: 0.6,
+        }
+    }
+
+    /// Name of this candle.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the width of the body of the candle, in X axis units.
+    ///
+    /// Default: `0.6`.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Did the value go up, i.e. is the close at least the open?
+    #[inline]
+    pub fn is_up(&self) -> bool {
+        self.open <= self.close
+    }
+
+    pub(super) fn add_shapes(
+        &self,
+        transform: &PlotTransform,
+        color: Color32,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let (stroke, fill) = if highlighted {
+            highlighted_color(Stroke::new(1.0, color), color)
+        } else {
+            (Stroke::new(1.0, color), color)
+        };
+
+        let wick = Shape::line_segment(
+            [
+                transform.position_from_point(&PlotPoint::new(self.x, self.low)),
+                transform.position_from_point(&PlotPoint::new(self.x, self.high)),
+            ],
+            stroke,
+        );
+        shapes.push(wick);
+
+        let body = transform.rect_from_values(
+            &PlotPoint::new(self.x - self.width / 2.0, self.open),
+            &PlotPoint::new(self.x + self.width / 2.0, self.close),
+        );
+        shapes.push(Shape::Rect(RectShape::new(
+            body,
+            Rounding::ZERO,
+            fill,
+            stroke,
+        )));
+    }
+
+    pub(super) fn add_rulers_and_text(
+        &self,
+        parent: &Candlestick,
+        plot: &PlotConfig<'_>,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+    ) {
+        let text: Option<String> = parent
+            .element_formatter
+            .as_ref()
+            .map(|fmt| fmt(self, parent));
+
+        add_rulers_and_text(self, plot, text, shapes, cursors);
+    }
+}
+
+impl RectElement for Candle {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        PlotPoint::new(
+            self.x - self.width / 2.0,
+            self.low.min(self.open.min(self.close)),
+        )
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        PlotPoint::new(
+            self.x + self.width / 2.0,
+            self.high.max(self.open.max(self.close)),
+        )
+    }
+
+    fn arguments_with_ruler(&self) -> Vec<PlotPoint> {
+        vec![PlotPoint::new(self.x, self.close)]
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        [self.open, self.high, self.low, self.close]
+            .map(|value| PlotPoint::new(self.x, value))
+            .to_vec()
+    }
+
+    fn orientation(&self) -> Orientation {
+        Orientation::Vertical
+    }
+
+    fn corner_value(&self) -> PlotPoint {
+        PlotPoint::new(self.x + self.width / 2.0, self.high)
+    }
+
+    fn default_values_format(&self, transform: &PlotTransform) -> String {
+        let scale = transform.dvalue_dpos()[1];
+        let decimals = ((-scale.abs().log10()).ceil().at_least(0.0) as usize)
+            .at_most(6)
+            .at_least(1);
+        format!(
+            "Open = {open:.decimals$}\
+             \nHigh = {high:.decimals$}\
+             \nLow = {low:.decimals$}\
+             \nClose = {close:.decimals$}",
+            open = self.open,
+            high = self.high,
+            low = self.low,
+            close = self.close,
+        )
+    }
+}
@@ -0,0 +1,140 @@
+use egui::epaint::{Color32, Shape, Stroke};
+use egui::{vec2, Vec2};
+
+use crate::{PlotBounds, PlotPoint, PlotTransform};
+
+use super::Orientation;
+
+/// Error bars for the elements of [`crate::Points`] or a [`crate::BarChart`],
+/// showing the uncertainty of their values.
+///
+/// The errors are given in the same order as the elements they belong to.
+/// Elements without an error, e.g. because there are fewer errors than elements, get no error bar.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorBars {
+    /// How far each error bar extends below and above its value, as `[minus, plus]`.
+    pub(super) errors: Vec<[f64; 2]>,
+
+    /// The width of the caps at the ends of the error bars, in ui points.
+    pub(super) cap_width: f32,
+
+    /// A transparent color means the color of the element is used.
+    pub(super) stroke: Stroke,
+}
+
+impl ErrorBars {
+    /// Error bars extending by the same amount below and above each value.
+    pub fn symmetric(errors: impl IntoIterator<Item = f64>) -> Self {
+        Self::asymmetric(errors.into_iter().map(|error| [error, error]))
+    }
+
+    /// Error bars extending by `[minus, plus]` below and above each value.
+    pub fn asymmetric(errors: impl IntoIterator<Item = [f64; 2]>) -> Self {
+        Self {
+            errors: errors.into_iter().collect(),
+            cap_width: 6.0,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+        }
+    }
+
+    /// The width of the caps at the ends of the error bars, in ui points.
+    /// Use `0.0` for no caps.
+    ///
+    /// Default: `6.0`.
+    #[inline]
+    pub fn cap_width(mut self, cap_width: f32) -> Self {
+        self.cap_width = cap_width;
+        self
+    }
+
+    /// Set the stroke of the error bars.
+    ///
+    /// Default: `1.0` wide, in the color of the elements.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// The lowest and highest value of the error bar at `index`, around `value`.
+    fn range(&self, index: usize, value: f64) -> Option<[f64; 2]> {
+        let [minus, plus] = self.errors.get(index)?;
+        Some([value - minus, value + plus])
+    }
+
+    /// The ends of the error bar at `index`, for an element at `point`.
+    pub(super) fn ends(
+        &self,
+        index: usize,
+        point: PlotPoint,
+        orientation: Orientation,
+    ) -> Option<[PlotPoint; 2]> {
+        Some(match orientation {
+            Orientation::Vertical => self
+                .range(index, point.y)?
+                .map(|y| PlotPoint::new(point.x, y)),
+            Orientation::Horizontal => self
+                .range(index, point.x)?
+                .map(|x| PlotPoint::new(x, point.y)),
+        })
+    }
+
+    pub(super) fn extend_bounds(
+        &self,
+        index: usize,
+        point: PlotPoint,
+        orientation: Orientation,
+        bounds: &mut PlotBounds,
+    ) {
+        for end in self.ends(index, point, orientation).into_iter().flatten() {
+            bounds.extend_with(&end);
+        }
+    }
+
+    /// The stroke of the error bars of an element in the given `color`.
+    pub(super) fn element_stroke(&self, color: Color32, highlighted: bool) -> Stroke {
+        let mut stroke = self.stroke;
+        if stroke.color == Color32::TRANSPARENT {
+            stroke.color = color;
+        }
+        if highlighted {
+            stroke.width *= 2.0;
+        }
+        stroke
+    }
+
+    /// Add the error bar at `index`, for an element at `point`.
+    pub(super) fn add_shapes(
+        &self,
+        index: usize,
+        point: PlotPoint,
+        orientation: Orientation,
+        transform: &PlotTransform,
+        stroke: Stroke,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let Some(ends) = self.ends(index, point, orientation) else {
+            return;
+        };
+        if !transform.can_show(&point) {
+            return;
+        }
+
+        // On a logarithmic axis, the lower end may be at or below zero:
+        let ends = ends.map(|end| transform.position_from_point_clamped(&end));
+        shapes.push(Shape::line_segment(ends, stroke));
+
+        if 0.0 < self.cap_width {
+            let half_cap: Vec2 = match orientation {
+                Orientation::Vertical => vec2(self.cap_width / 2.0, 0.0),
+                Orientation::Horizontal => vec2(0.0, self.cap_width / 2.0),
+            };
+            for end in ends {
+                shapes.push(Shape::line_segment(
+                    [end - half_cap, end + half_cap],
+                    stroke,
+                ));
+            }
+        }
+    }
+}
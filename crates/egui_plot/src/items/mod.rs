@@ -12,12 +12,16 @@ use rect_elem::*;
 
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
+pub use candle::Candle;
+pub use error_bars::ErrorBars;
 pub use values::{
     ClosestElem, LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints,
 };
 
 mod bar;
 mod box_elem;
+mod candle;
+mod error_bars;
 mod rect_elem;
 mod values;
 
@@ -29,6 +33,9 @@ pub struct PlotConfig<'a> {
     pub transform: &'a PlotTransform,
     pub show_x: bool,
     pub show_y: bool,
+
+    /// Are the x values unix timestamps, see [`crate::Plot::x_time_axis`]?
+    pub x_time_axis: bool,
 }
 
 /// Trait shared by things that can be drawn in the plot.
@@ -53,8 +60,28 @@ pub trait PlotItem {
 
     fn bounds(&self) -> PlotBounds;
 
+    /// The bounds in plot space, see [`PlotTransform::bounds`].
+    ///
+    /// On a logarithmic axis (see [`crate::Plot::log_axes`]) only the values that can be shown count.
+    fn plot_bounds(&self, log_axes: Vec2b) -> PlotBounds {
+        if !log_axes.any() {
+            return self.bounds();
+        }
+        match self.geometry() {
+            PlotGeometry::Points(points) if !points.is_empty() => {
+                transform::plot_bounds_from_points(points.iter().copied(), log_axes)
+            }
+            _ => transform::plot_bounds_from_values(&self.bounds(), log_axes),
+        }
+    }
+
     fn id(&self) -> Option<Id>;
 
+    /// Is this item plotted against the secondary y axis, see [`crate::Plot::secondary_y_axis`]?
+    fn secondary_y(&self) -> bool {
+        false
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         match self.geometry() {
             PlotGeometry::None => None,
@@ -127,6 +154,7 @@ pub struct HLine {
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
     pub(super) style: LineStyle,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
@@ -139,6 +167,7 @@ impl HLine {
             highlight: false,
             allow_hover: true,
             style: LineStyle::Solid,
+            secondary_y: false,
             id: None,
         }
     }
@@ -204,6 +233,15 @@ impl HLine {
         self.id = Some(id);
         self
     }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
 }
 
 impl PlotItem for HLine {
@@ -218,12 +256,14 @@ impl PlotItem for HLine {
 
         // Round to minimize aliasing:
         let points = vec![
-            ui.painter().round_pos_to_pixels(
-                transform.position_from_point(&PlotPoint::new(transform.bounds().min[0], *y)),
-            ),
-            ui.painter().round_pos_to_pixels(
-                transform.position_from_point(&PlotPoint::new(transform.bounds().max[0], *y)),
-            ),
+            ui.painter().round_pos_to_pixels(pos2(
+                transform.frame().left(),
+                transform.position_from_point_y(*y),
+            )),
+            ui.painter().round_pos_to_pixels(pos2(
+                transform.frame().right(),
+                transform.position_from_point_y(*y),
+            )),
         ];
         style.style_line(points, *stroke, *highlight, shapes);
     }
@@ -264,6 +304,10 @@ impl PlotItem for HLine {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
 }
 
 /// A vertical line in a plot, filling the full width
@@ -275,6 +319,7 @@ pub struct VLine {
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
     pub(super) style: LineStyle,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
@@ -287,6 +332,7 @@ impl VLine {
             highlight: false,
             allow_hover: true,
             style: LineStyle::Solid,
+            secondary_y: false,
             id: None,
         }
     }
@@ -352,6 +398,15 @@ impl VLine {
         self.id = Some(id);
         self
     }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
 }
 
 impl PlotItem for VLine {
@@ -366,12 +421,14 @@ impl PlotItem for VLine {
 
         // Round to minimize aliasing:
         let points = vec![
-            ui.painter().round_pos_to_pixels(
-                transform.position_from_point(&PlotPoint::new(*x, transform.bounds().min[1])),
-            ),
-            ui.painter().round_pos_to_pixels(
-                transform.position_from_point(&PlotPoint::new(*x, transform.bounds().max[1])),
-            ),
+            ui.painter().round_pos_to_pixels(pos2(
+                transform.position_from_point_x(*x),
+                transform.frame().bottom(),
+            )),
+            ui.painter().round_pos_to_pixels(pos2(
+                transform.position_from_point_x(*x),
+                transform.frame().top(),
+            )),
         ];
         style.style_line(points, *stroke, *highlight, shapes);
     }
@@ -412,6 +469,10 @@ impl PlotItem for VLine {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
 }
 
 /// A series of values forming a path.
@@ -423,6 +484,7 @@ pub struct Line {
     pub(super) allow_hover: bool,
     pub(super) fill: Option<f32>,
     pub(super) style: LineStyle,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
@@ -436,6 +498,7 @@ impl Line {
             allow_hover: true,
             fill: None,
             style: LineStyle::Solid,
+            secondary_y: false,
             id: None,
         }
     }
@@ -508,6 +571,15 @@ impl Line {
         self.id = Some(id);
         self
     }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
 }
 
 /// Returns the x-coordinate of a possible intersection between a line segment from `p1` to `p2` and
@@ -517,64 +589,75 @@ fn y_intersection(p1: &Pos2, p2: &Pos2, y: f32) -> Option<f32> {
         .then_some(((y * (p1.x - p2.x)) - (p1.x * p2.y - p1.y * p2.x)) / (p1.y - p2.y))
 }
 
+/// The area between a line and a horizontal reference line at `y`, in screen space.
+fn line_fill_mesh(values_tf: &[Pos2], y: f32, fill_color: Color32) -> Mesh {
+    let n_values = values_tf.len();
+    let mut mesh = Mesh::default();
+    let expected_intersections = 20;
+    mesh.reserve_triangles((n_values - 1) * 2);
+    mesh.reserve_vertices(n_values * 2 + expected_intersections);
+    values_tf.windows(2).for_each(|w| {
+        let i = mesh.vertices.len() as u32;
+        mesh.colored_vertex(w[0], fill_color);
+        mesh.colored_vertex(pos2(w[0].x, y), fill_color);
+        if let Some(x) = y_intersection(&w[0], &w[1], y) {
+            let point = pos2(x, y);
+            mesh.colored_vertex(point, fill_color);
+            mesh.add_triangle(i, i + 1, i + 2);
+            mesh.add_triangle(i + 2, i + 3, i + 4);
+        } else {
+            mesh.add_triangle(i, i + 1, i + 2);
+            mesh.add_triangle(i + 1, i + 2, i + 3);
+        }
+    });
+    let last = values_tf[n_values - 1];
+    mesh.colored_vertex(last, fill_color);
+    mesh.colored_vertex(pos2(last.x, y), fill_color);
+    mesh
+}
+
 impl PlotItem for Line {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             series,
             stroke,
             highlight,
-            mut fill,
+            fill,
             style,
             ..
         } = self;
 
-        let values_tf: Vec<_> = series
+        let mut fill_alpha = DEFAULT_FILL_ALPHA;
+        if *highlight {
+            fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+        }
+        let fill_color = Rgba::from(stroke.color)
+            .to_opaque()
+            .multiply(fill_alpha)
+            .into();
+        let fill_y = fill.map(|y_reference| {
+            transform
+                .position_from_point_clamped(&PlotPoint::new(0.0, y_reference))
+                .y
+        });
+
+        // The line is broken where it has points that can't be shown on a logarithmic axis:
+        for part in series
             .points()
-            .iter()
-            .map(|v| transform.position_from_point(v))
-            .collect();
-        let n_values = values_tf.len();
+            .split(|value| !transform.can_show(value))
+            .filter(|part| !part.is_empty())
+        {
+            let values_tf: Vec<_> = part
+                .iter()
+                .map(|v| transform.position_from_point(v))
+                .collect();
 
-        // Fill the area between the line and a reference line, if required.
-        if n_values < 2 {
-            fill = None;
-        }
-        if let Some(y_reference) = fill {
-            let mut fill_alpha = DEFAULT_FILL_ALPHA;
-            if *highlight {
-                fill_alpha = (2.0 * fill_alpha).at_most(1.0);
+            // Fill the area between the line and a reference line, if required.
+            if let Some(y) = fill_y.filter(|_| 2 <= values_tf.len()) {
+                shapes.push(Shape::Mesh(line_fill_mesh(&values_tf, y, fill_color)));
             }
-            let y = transform
-                .position_from_point(&PlotPoint::new(0.0, y_reference))
-                .y;
-            let fill_color = Rgba::from(stroke.color)
-                .to_opaque()
-                .multiply(fill_alpha)
-                .into();
-            let mut mesh = Mesh::default();
-            let expected_intersections = 20;
-            mesh.reserve_triangles((n_values - 1) * 2);
-            mesh.reserve_vertices(n_values * 2 + expected_intersections);
-            values_tf.windows(2).for_each(|w| {
-                let i = mesh.vertices.len() as u32;
-                mesh.colored_vertex(w[0], fill_color);
-                mesh.colored_vertex(pos2(w[0].x, y), fill_color);
-                if let Some(x) = y_intersection(&w[0], &w[1], y) {
-                    let point = pos2(x, y);
-                    mesh.colored_vertex(point, fill_color);
-                    mesh.add_triangle(i, i + 1, i + 2);
-                    mesh.add_triangle(i + 2, i + 3, i + 4);
-                } else {
-                    mesh.add_triangle(i, i + 1, i + 2);
-                    mesh.add_triangle(i + 1, i + 2, i + 3);
-                }
-            });
-            let last = values_tf[n_values - 1];
-            mesh.colored_vertex(last, fill_color);
-            mesh.colored_vertex(pos2(last.x, y), fill_color);
-            shapes.push(Shape::Mesh(mesh));
+            style.style_line(values_tf, *stroke, *highlight, shapes);
         }
-        style.style_line(values_tf, *stroke, *highlight, shapes);
     }
 
     fn initialize(&mut self, x_range: RangeInclusive<f64>) {
@@ -612,6 +695,10 @@ impl PlotItem for Line {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
 }
 
 /// A convex polygon.
@@ -623,6 +710,7 @@ pub struct Polygon {
     pub(super) allow_hover: bool,
     pub(super) fill_color: Option<Color32>,
     pub(super) style: LineStyle,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
@@ -636,6 +724,7 @@ impl Polygon {
             allow_hover: true,
             fill_color: None,
             style: LineStyle::Solid,
+            secondary_y: false,
             id: None,
         }
     }
@@ -702,6 +791,15 @@ impl Polygon {
         self.id = Some(id);
         self
     }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
 }
 
 impl PlotItem for Polygon {
@@ -718,8 +816,12 @@ impl PlotItem for Polygon {
         let mut values_tf: Vec<_> = series
             .points()
             .iter()
+            .filter(|v| transform.can_show(v))
             .map(|v| transform.position_from_point(v))
             .collect();
+        if values_tf.is_empty() {
+            return;
+        }
 
         let fill_color = fill_color.unwrap_or(stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
 
@@ -764,36 +866,64 @@ impl PlotItem for Polygon {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
 }
 
-/// Text inside the plot.
-#[derive(Clone)]
-pub struct Text {
-    pub(super) text: WidgetText,
-    pub(super) position: PlotPoint,
+/// What a [`FillBetween`] fills to.
+enum FillLimit {
+    Series(PlotPoints),
+    Constant(f64),
+}
+
+/// The filled area between two series, or a series and a constant,
+/// e.g. for a confidence band or a min/max envelope.
+///
+/// The series are paired point by point, so they should share their x values,
+/// e.g. by coming from the same samples.
+pub struct FillBetween {
+    pub(super) series: PlotPoints,
+    limit: FillLimit,
+    pub(super) stroke: Stroke,
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
-    pub(super) color: Color32,
-    pub(super) anchor: Align2,
+    pub(super) fill_color: Option<Color32>,
+    pub(super) style: LineStyle,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
-impl Text {
-    pub fn new(position: PlotPoint, text: impl Into<WidgetText>) -> Self {
+impl FillBetween {
+    /// Fill the area between the two series.
+    pub fn new(series1: impl Into<PlotPoints>, series2: impl Into<PlotPoints>) -> Self {
+        Self::with_limit(series1.into(), FillLimit::Series(series2.into()))
+    }
+
+    /// Fill the area between the series and a horizontal line at `y`.
+    pub fn constant(series: impl Into<PlotPoints>, y: f64) -> Self {
+        Self::with_limit(series.into(), FillLimit::Constant(y))
+    }
+
+    fn with_limit(series: PlotPoints, limit: FillLimit) -> Self {
         Self {
-            text: text.into(),
-            position,
+            series,
+            limit,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             name: Default::default(),
             highlight: false,
             allow_hover: true,
-            color: Color32::TRANSPARENT,
-            anchor: Align2::CENTER_CENTER,
+            fill_color: None,
+            style: LineStyle::Solid,
+            secondary_y: false,
             id: None,
         }
     }
 
-    /// Highlight this text in the plot by drawing a rectangle around it.
+    /// Highlight this area in the plot by scaling up the stroke and reducing the fill
+    /// transparency.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -807,21 +937,43 @@ impl Text {
         self
     }
 
-    /// Text color.
+    /// Add a custom stroke for the lines along the edges of the area.
+    /// Use a width of `0.0` for no lines.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Set the stroke width.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
-        self.color = color.into();
+        self.stroke.color = color.into();
         self
     }
 
-    /// Anchor position of the text. Default is `Align2::CENTER_CENTER`.
+    /// Fill color. Defaults to the stroke color with added transparency.
     #[inline]
-    pub fn anchor(mut self, anchor: Align2) -> Self {
-        self.anchor = anchor;
+    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
+        self.fill_color = Some(color.into());
         self
     }
 
-    /// Name of this text.
+    /// Set the style of the lines along the edges. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this area.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -834,51 +986,107 @@ impl Text {
         self
     }
 
-    /// Set the text's id which is used to identify it in the plot's response.
+    /// Set the area's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
         self
     }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
+
+    /// The points of the other edge of the area, paired with the points of the series.
+    fn limit_points(&self) -> Vec<PlotPoint> {
+        match &self.limit {
+            FillLimit::Series(series) => series.points().to_vec(),
+            FillLimit::Constant(y) => self
+                .series
+                .points()
+                .iter()
+                .map(|point| PlotPoint::new(point.x, *y))
+                .collect(),
+        }
+    }
 }
 
-impl PlotItem for Text {
-    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let color = if self.color == Color32::TRANSPARENT {
-            ui.style().visuals.text_color()
-        } else {
-            self.color
-        };
+impl PlotItem for FillBetween {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            series,
+            stroke,
+            highlight,
+            fill_color,
+            style,
+            ..
+        } = self;
 
-        let galley = self.text.clone().into_galley(
-            ui,
-            Some(egui::TextWrapMode::Extend),
-            f32::INFINITY,
-            TextStyle::Small,
-        );
+        // On a logarithmic axis, the fill goes past the edge of the plot where an edge is at or below zero:
+        let edge1: Vec<_> = series
+            .points()
+            .iter()
+            .map(|v| transform.position_from_point_clamped(v))
+            .collect();
+        let edge2: Vec<_> = self
+            .limit_points()
+            .iter()
+            .map(|v| transform.position_from_point_clamped(v))
+            .collect();
 
-        let pos = transform.position_from_point(&self.position);
-        let rect = self.anchor.anchor_size(pos, galley.size());
+        let mut fill_color = fill_color.unwrap_or(stroke.color.linear_multiply(DEFAULT_FILL_ALPHA));
+        if *highlight {
+            fill_color = fill_color.linear_multiply(2.0);
+        }
 
-        shapes.push(epaint::TextShape::new(rect.min, galley, color).into());
+        let mut mesh = Mesh::default();
+        for (a, b) in edge1.windows(2).zip(edge2.windows(2)) {
+            let i = mesh.vertices.len() as u32;
+            mesh.colored_vertex(a[0], fill_color);
+            mesh.colored_vertex(b[0], fill_color);
+            mesh.colored_vertex(a[1], fill_color);
+            mesh.colored_vertex(b[1], fill_color);
+
+            let (d0, d1) = (a[0].y - b[0].y, a[1].y - b[1].y);
+            if d0 * d1 < 0.0 {
+                // The edges cross, so fill the two triangles on either side of the crossing:
+                let crossing = a[0].lerp(a[1], d0 / (d0 - d1));
+                mesh.colored_vertex(crossing, fill_color);
+                mesh.add_triangle(i, i + 1, i + 4);
+                mesh.add_triangle(i + 4, i + 2, i + 3);
+            } else {
+                mesh.add_triangle(i, i + 1, i + 2);
+                mesh.add_triangle(i + 1, i + 2, i + 3);
+            }
+        }
+        shapes.push(Shape::Mesh(mesh));
 
-        if self.highlight {
-            shapes.push(Shape::rect_stroke(
-                rect.expand(2.0),
-                1.0,
-                Stroke::new(0.5, color),
-            ));
+        if 0.0 < stroke.width {
+            style.style_line(edge1, *stroke, *highlight, shapes);
+            if matches!(self.limit, FillLimit::Series(_)) {
+                style.style_line(edge2, *stroke, *highlight, shapes);
+            }
         }
     }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range.clone());
+        if let FillLimit::Series(series) = &mut self.limit {
+            series.generate_points(x_range);
+        }
+    }
 
     fn name(&self) -> &str {
         self.name.as_str()
     }
 
     fn color(&self) -> Color32 {
-        self.color
+        self.stroke.color
     }
 
     fn highlight(&mut self) {
@@ -894,69 +1102,109 @@ impl PlotItem for Text {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::None
+        PlotGeometry::Points(self.series.points())
     }
 
     fn bounds(&self) -> PlotBounds {
-        let mut bounds = PlotBounds::NOTHING;
-        bounds.extend_with(&self.position);
+        let mut bounds = self.series.bounds();
+        match &self.limit {
+            FillLimit::Series(series) => bounds.merge(&series.bounds()),
+            FillLimit::Constant(y) => bounds.extend_with_y(*y),
+        }
         bounds
     }
 
-    fn id(&self) -> Option<Id> {
-        self.id
+    fn plot_bounds(&self, log_axes: Vec2b) -> PlotBounds {
+        if !log_axes.any() {
+            return self.bounds();
+        }
+        let points = self.series.points().iter().copied();
+        transform::plot_bounds_from_points(points.chain(self.limit_points()), log_axes)
     }
-}
 
-/// A set of points.
-pub struct Points {
-    pub(super) series: PlotPoints,
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        // The points of the first series come first, then those of the other edge:
+        self.series
+            .points()
+            .iter()
+            .chain(&self.limit_points())
+            .enumerate()
+            .map(|(index, value)| {
+                let pos = transform.position_from_point(value);
+                let dist_sq = point.distance_sq(pos);
+                ClosestElem { index, dist_sq }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
 
-    pub(super) shape: MarkerShape,
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        let points = self.series.points();
+        let value = if elem.index < points.len() {
+            points[elem.index]
+        } else {
+            self.limit_points()[elem.index - points.len()]
+        };
 
-    /// Color of the marker. `Color32::TRANSPARENT` means that it will be picked automatically.
-    pub(super) color: Color32,
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(pointer, 3.0, rulers_color(plot.ui)));
 
-    /// Whether to fill the marker. Does not apply to all types.
-    pub(super) filled: bool,
+        rulers_at_value(
+            pointer,
+            value,
+            self.name(),
+            plot,
+            shapes,
+            cursors,
+            label_formatter,
+        );
+    }
 
-    /// The maximum extent of the marker from its center.
-    pub(super) radius: f32,
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
 
-    pub(super) name: String,
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
+}
 
+/// Text inside the plot.
+#[derive(Clone)]
+pub struct Text {
+    pub(super) text: WidgetText,
+    pub(super) position: PlotPoint,
+    pub(super) name: String,
     pub(super) highlight: bool,
-
     pub(super) allow_hover: bool,
-
-    pub(super) stems: Option<f32>,
+    pub(super) color: Color32,
+    pub(super) anchor: Align2,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
-impl Points {
-    pub fn new(series: impl Into<PlotPoints>) -> Self {
+impl Text {
+    pub fn new(position: PlotPoint, text: impl Into<WidgetText>) -> Self {
         Self {
-            series: series.into(),
-            shape: MarkerShape::Circle,
-            color: Color32::TRANSPARENT,
-            filled: true,
-            radius: 1.0,
+            text: text.into(),
+            position,
             name: Default::default(),
             highlight: false,
             allow_hover: true,
-            stems: None,
+            color: Color32::TRANSPARENT,
+            anchor: Align2::CENTER_CENTER,
+            secondary_y: false,
             id: None,
         }
     }
 
-    /// Set the shape of the markers.
-    #[inline]
-    pub fn shape(mut self, shape: MarkerShape) -> Self {
-        self.shape = shape;
-        self
-    }
-
-    /// Highlight these points in the plot by scaling up their markers.
+    /// Highlight this text in the plot by drawing a rectangle around it.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -970,35 +1218,21 @@ impl Points {
         self
     }
 
-    /// Set the marker's color.
+    /// Text color.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
         self.color = color.into();
         self
     }
 
-    /// Whether to fill the marker.
-    #[inline]
-    pub fn filled(mut self, filled: bool) -> Self {
-        self.filled = filled;
-        self
-    }
-
-    /// Whether to add stems between the markers and a horizontal reference line.
-    #[inline]
-    pub fn stems(mut self, y_reference: impl Into<f32>) -> Self {
-        self.stems = Some(y_reference.into());
-        self
-    }
-
-    /// Set the maximum extent of the marker around its position, in ui points.
+    /// Anchor position of the text. Default is `Align2::CENTER_CENTER`.
     #[inline]
-    pub fn radius(mut self, radius: impl Into<f32>) -> Self {
-        self.radius = radius.into();
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
         self
     }
 
-    /// Name of this set of points.
+    /// Name of this text.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -1011,146 +1245,53 @@ impl Points {
         self
     }
 
-    /// Set the points' id which is used to identify them in the plot's response.
+    /// Set the text's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
         self
     }
-}
 
-impl PlotItem for Points {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let sqrt_3 = 3_f32.sqrt();
-        let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
-        let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
-
-        let Self {
-            series,
-            shape,
-            color,
-            filled,
-            mut radius,
-            highlight,
-            stems,
-            ..
-        } = self;
-
-        let stroke_size = radius / 5.0;
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
+}
 
-        let default_stroke = Stroke::new(stroke_size, *color);
-        let mut stem_stroke = default_stroke;
-        let (fill, stroke) = if *filled {
-            (*color, Stroke::NONE)
+impl PlotItem for Text {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let color = if self.color == Color32::TRANSPARENT {
+            ui.style().visuals.text_color()
         } else {
-            (Color32::TRANSPARENT, default_stroke)
+            self.color
         };
 
-        if *highlight {
-            radius *= 2f32.sqrt();
-            stem_stroke.width *= 2.0;
-        }
-
-        let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
+        let galley = self.text.clone().into_galley(
+            ui,
+            Some(egui::TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Small,
+        );
 
-        series
-            .points()
-            .iter()
-            .map(|value| transform.position_from_point(value))
-            .for_each(|center| {
-                let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+        let pos = transform.position_from_point(&self.position);
+        let rect = self.anchor.anchor_size(pos, galley.size());
 
-                if let Some(y) = y_reference {
-                    let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
-                    shapes.push(stem);
-                }
+        shapes.push(epaint::TextShape::new(rect.min, galley, color).into());
 
-                match shape {
-                    MarkerShape::Circle => {
-                        shapes.push(Shape::Circle(epaint::CircleShape {
-                            center,
-                            radius,
-                            fill,
-                            stroke,
-                        }));
-                    }
-                    MarkerShape::Diamond => {
-                        let points = vec![
-                            tf(0.0, 1.0),  // bottom
-                            tf(-1.0, 0.0), // left
-                            tf(0.0, -1.0), // top
-                            tf(1.0, 0.0),  // right
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Square => {
-                        let points = vec![
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Cross => {
-                        let diagonal1 = [
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        let diagonal2 = [
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                    MarkerShape::Plus => {
-                        let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        shapes.push(Shape::line_segment(horizontal, default_stroke));
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                    }
-                    MarkerShape::Up => {
-                        let points =
-                            vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Down => {
-                        let points = vec![
-                            tf(0.0, 1.0),
-                            tf(-0.5 * sqrt_3, -0.5),
-                            tf(0.5 * sqrt_3, -0.5),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Left => {
-                        let points =
-                            vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Right => {
-                        let points = vec![
-                            tf(1.0, 0.0),
-                            tf(-0.5, 0.5 * sqrt_3),
-                            tf(-0.5, -0.5 * sqrt_3),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Asterisk => {
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
-                        let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                }
-            });
+        if self.highlight {
+            shapes.push(Shape::rect_stroke(
+                rect.expand(2.0),
+                1.0,
+                Stroke::new(0.5, color),
+            ));
+        }
     }
 
-    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
-        self.series.generate_points(x_range);
-    }
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
 
     fn name(&self) -> &str {
         self.name.as_str()
@@ -1173,45 +1314,102 @@ impl PlotItem for Points {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::Points(self.series.points())
+        PlotGeometry::None
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.position);
+        bounds
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
 }
 
-/// A set of arrows.
-pub struct Arrows {
-    pub(super) origins: PlotPoints,
-    pub(super) tips: PlotPoints,
-    pub(super) tip_length: Option<f32>,
-    pub(super) color: Color32,
+/// Where the text of an [`Annotation`] is.
+enum AnnotationPosition {
+    /// An offset in ui points from the target.
+    Offset(Vec2),
+
+    /// A position in plot coordinates.
+    Point(PlotPoint),
+}
+
+/// A text pointing at a position in the plot, e.g. to mark an event in a time series.
+///
+/// By default the text is in a callout box at a fixed offset in ui points from its target,
+/// so it stays next to the target while zooming, with an arrow pointing at the target.
+pub struct Annotation {
+    pub(super) text: WidgetText,
+    pub(super) target: PlotPoint,
+    position: AnnotationPosition,
+    pub(super) arrow: bool,
+    pub(super) callout: bool,
     pub(super) name: String,
     pub(super) highlight: bool,
     pub(super) allow_hover: bool,
+    pub(super) color: Color32,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
-impl Arrows {
-    pub fn new(origins: impl Into<PlotPoints>, tips: impl Into<PlotPoints>) -> Self {
+impl Annotation {
+    /// Annotate the `target`, in plot coordinates, with `text`.
+    pub fn new(target: PlotPoint, text: impl Into<WidgetText>) -> Self {
         Self {
-            origins: origins.into(),
-            tips: tips.into(),
-            tip_length: None,
-            color: Color32::TRANSPARENT,
+            text: text.into(),
+            target,
+            position: AnnotationPosition::Offset(vec2(20.0, -30.0)),
+            arrow: true,
+            callout: true,
             name: Default::default(),
             highlight: false,
             allow_hover: true,
+            color: Color32::TRANSPARENT,
+            secondary_y: false,
             id: None,
         }
     }
 
-    /// Highlight these arrows in the plot.
+    /// Put the center of the text `offset` ui points away from the target,
+    /// so it stays at the same distance from the target while zooming.
+    ///
+    /// Default: `vec2(20.0, -30.0)`, i.e. up and to the right.
+    #[inline]
+    pub fn offset(mut self, offset: impl Into<Vec2>) -> Self {
+        self.position = AnnotationPosition::Offset(offset.into());
+        self
+    }
+
+    /// Put the center of the text at a position in plot coordinates instead,
+    /// so it moves with the plot like the target.
+    #[inline]
+    pub fn text_position(mut self, position: PlotPoint) -> Self {
+        self.position = AnnotationPosition::Point(position);
+        self
+    }
+
+    /// Draw an arrow from the text to the target. Default: `true`.
+    #[inline]
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    /// Draw the text in a box. Default: `true`.
+    #[inline]
+    pub fn callout(mut self, callout: bool) -> Self {
+        self.callout = callout;
+        self
+    }
+
+    /// Highlight this annotation in the plot by scaling up the arrow and the outline of the box.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -1225,21 +1423,14 @@ impl Arrows {
         self
     }
 
-    /// Set the length of the arrow tips
-    #[inline]
-    pub fn tip_length(mut self, tip_length: f32) -> Self {
-        self.tip_length = Some(tip_length);
-        self
-    }
-
-    /// Set the arrows' color.
+    /// Color of the text, the arrow and the outline of the box.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
         self.color = color.into();
         self
     }
 
-    /// Name of this set of arrows.
+    /// Name of this annotation.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -1252,64 +1443,85 @@ impl Arrows {
         self
     }
 
-    /// Set the arrows' id which is used to identify them in the plot's response.
+    /// Set the annotation's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
         self
     }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
 }
 
-impl PlotItem for Arrows {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+impl PlotItem for Annotation {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         use crate::emath::*;
-        let Self {
-            origins,
-            tips,
-            tip_length,
-            color,
-            highlight,
-            ..
-        } = self;
-        let stroke = Stroke::new(if *highlight { 2.0 } else { 1.0 }, *color);
-        origins
-            .points()
-            .iter()
-            .zip(tips.points().iter())
-            .map(|(origin, tip)| {
-                (
-                    transform.position_from_point(origin),
-                    transform.position_from_point(tip),
-                )
-            })
-            .for_each(|(origin, tip)| {
-                let vector = tip - origin;
-                let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
-                let tip_length = if let Some(tip_length) = tip_length {
-                    *tip_length
-                } else {
-                    vector.length() / 4.0
-                };
-                let tip = origin + vector;
+
+        let color = if self.color == Color32::TRANSPARENT {
+            ui.style().visuals.text_color()
+        } else {
+            self.color
+        };
+        let stroke = Stroke::new(if self.highlight { 2.0 } else { 1.0 }, color);
+
+        let galley = self.text.clone().into_galley(
+            ui,
+            Some(egui::TextWrapMode::Extend),
+            f32::INFINITY,
+            TextStyle::Small,
+        );
+
+        let target = transform.position_from_point(&self.target);
+        let center = match self.position {
+            AnnotationPosition::Offset(offset) => target + offset,
+            AnnotationPosition::Point(position) => transform.position_from_point(&position),
+        };
+        let rect = Align2::CENTER_CENTER.anchor_size(center, galley.size());
+        let outline = if self.callout { rect.expand(4.0) } else { rect };
+
+        if self.arrow {
+            // Start at the edge of the text, on the way to the target:
+            let vector = target - center;
+            let half_size = outline.size() / 2.0;
+            let t = (half_size.x / vector.x.abs()).min(half_size.y / vector.y.abs());
+            if t < 1.0 {
+                let start = center + t * vector;
                 let dir = vector.normalized();
-                shapes.push(Shape::line_segment([origin, tip], stroke));
+                let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
+                let tip_length = 6.0;
+                shapes.push(Shape::line_segment([start, target], stroke));
                 shapes.push(Shape::line(
                     vec![
-                        tip - tip_length * (rot.inverse() * dir),
-                        tip,
-                        tip - tip_length * (rot * dir),
+                        target - tip_length * (rot.inverse() * dir),
+                        target,
+                        target - tip_length * (rot * dir),
                     ],
                     stroke,
                 ));
-            });
-    }
+            }
+        }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
-        self.origins
-            .generate_points(f64::NEG_INFINITY..=f64::INFINITY);
-        self.tips.generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+        if self.callout {
+            shapes.push(Shape::rect_filled(
+                outline,
+                2.0,
+                ui.visuals().extreme_bg_color,
+            ));
+            shapes.push(Shape::rect_stroke(outline, 2.0, stroke));
+        }
+
+        shapes.push(epaint::TextShape::new(rect.min, galley, color).into());
     }
 
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
     fn name(&self) -> &str {
         self.name.as_str()
     }
@@ -1331,57 +1543,80 @@ impl PlotItem for Arrows {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::Points(self.origins.points())
+        PlotGeometry::None
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.origins.bounds()
+        let mut bounds = PlotBounds::NOTHING;
+        bounds.extend_with(&self.target);
+        if let AnnotationPosition::Point(position) = &self.position {
+            bounds.extend_with(position);
+        }
+        bounds
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
 }
 
-/// An image in the plot.
-#[derive(Clone)]
-pub struct PlotImage {
-    pub(super) position: PlotPoint,
-    pub(super) texture_id: TextureId,
-    pub(super) uv: Rect,
-    pub(super) size: Vec2,
-    pub(crate) rotation: f64,
-    pub(super) bg_fill: Color32,
-    pub(super) tint: Color32,
+/// A set of points.
+pub struct Points {
+    pub(super) series: PlotPoints,
+
+    pub(super) shape: MarkerShape,
+
+    /// Color of the marker. `Color32::TRANSPARENT` means that it will be picked automatically.
+    pub(super) color: Color32,
+
+    /// Whether to fill the marker. Does not apply to all types.
+    pub(super) filled: bool,
+
+    /// The maximum extent of the marker from its center.
+    pub(super) radius: f32,
+
+    pub(super) name: String,
+
     pub(super) highlight: bool,
+
     pub(super) allow_hover: bool,
-    pub(super) name: String,
+
+    pub(super) stems: Option<f32>,
+    pub(super) error_bars: Option<ErrorBars>,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
-impl PlotImage {
-    /// Create a new image with position and size in plot coordinates.
-    pub fn new(
-        texture_id: impl Into<TextureId>,
-        center_position: PlotPoint,
-        size: impl Into<Vec2>,
-    ) -> Self {
+impl Points {
+    pub fn new(series: impl Into<PlotPoints>) -> Self {
         Self {
-            position: center_position,
+            series: series.into(),
+            shape: MarkerShape::Circle,
+            color: Color32::TRANSPARENT,
+            filled: true,
+            radius: 1.0,
             name: Default::default(),
             highlight: false,
             allow_hover: true,
-            texture_id: texture_id.into(),
-            uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
-            size: size.into(),
-            rotation: 0.0,
-            bg_fill: Default::default(),
-            tint: Color32::WHITE,
+            stems: None,
+            error_bars: None,
+            secondary_y: false,
             id: None,
         }
     }
 
-    /// Highlight this image in the plot.
+    /// Set the shape of the markers.
+    #[inline]
+    pub fn shape(mut self, shape: MarkerShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Highlight these points in the plot by scaling up their markers.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
@@ -1395,28 +1630,42 @@ impl PlotImage {
         self
     }
 
-    /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
+    /// Set the marker's color.
     #[inline]
-    pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
-        self.uv = uv.into();
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
         self
     }
 
-    /// A solid color to put behind the image. Useful for transparent images.
+    /// Whether to fill the marker.
     #[inline]
-    pub fn bg_fill(mut self, bg_fill: impl Into<Color32>) -> Self {
-        self.bg_fill = bg_fill.into();
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
         self
     }
 
-    /// Multiply image color with this. Default is WHITE (no tint).
+    /// Whether to add stems between the markers and a horizontal reference line.
     #[inline]
-    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
-        self.tint = tint.into();
+    pub fn stems(mut self, y_reference: impl Into<f32>) -> Self {
+        self.stems = Some(y_reference.into());
         self
     }
 
-    /// Name of this image.
+    /// Set the maximum extent of the marker around its position, in ui points.
+    #[inline]
+    pub fn radius(mut self, radius: impl Into<f32>) -> Self {
+        self.radius = radius.into();
+        self
+    }
+
+    /// Add vertical error bars to the points, showing the uncertainty of their y values.
+    #[inline]
+    pub fn error_bars(mut self, error_bars: ErrorBars) -> Self {
+        self.error_bars = Some(error_bars);
+        self
+    }
+
+    /// Name of this set of points.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
     ///
@@ -1429,82 +1678,849 @@ impl PlotImage {
         self
     }
 
-    /// Rotate the image counter-clockwise around its center by an angle in radians.
+    /// Set the points' id which is used to identify them in the plot's response.
     #[inline]
-    pub fn rotate(mut self, angle: f64) -> Self {
-        self.rotation = angle;
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
         self
     }
 }
 
-impl PlotItem for PlotImage {
-    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+impl PlotItem for Points {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let sqrt_3 = 3_f32.sqrt();
+        let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
+        let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+
         let Self {
-            position,
-            rotation,
-            texture_id,
-            uv,
-            size,
-            bg_fill,
-            tint,
+            series,
+            shape,
+            color,
+            filled,
+            mut radius,
             highlight,
+            stems,
+            error_bars,
             ..
         } = self;
-        let image_screen_rect = {
-            let left_top = PlotPoint::new(
-                position.x - 0.5 * size.x as f64,
-                position.y - 0.5 * size.y as f64,
-            );
-            let right_bottom = PlotPoint::new(
-                position.x + 0.5 * size.x as f64,
-                position.y + 0.5 * size.y as f64,
-            );
-            let left_top_screen = transform.position_from_point(&left_top);
-            let right_bottom_screen = transform.position_from_point(&right_bottom);
-            Rect::from_two_pos(left_top_screen, right_bottom_screen)
+
+        let stroke_size = radius / 5.0;
+
+        let default_stroke = Stroke::new(stroke_size, *color);
+        let mut stem_stroke = default_stroke;
+        let (fill, stroke) = if *filled {
+            (*color, Stroke::NONE)
+        } else {
+            (Color32::TRANSPARENT, default_stroke)
         };
-        let screen_rotation = -*rotation as f32;
 
-        egui::paint_texture_at(
-            ui.painter(),
-            image_screen_rect,
-            &ImageOptions {
-                uv: *uv,
-                bg_fill: *bg_fill,
-                tint: *tint,
-                rotation: Some((Rot2::from_angle(screen_rotation), Vec2::splat(0.5))),
-                rounding: Rounding::ZERO,
-            },
-            &(*texture_id, image_screen_rect.size()).into(),
-        );
         if *highlight {
-            let center = image_screen_rect.center();
-            let rotation = Rot2::from_angle(screen_rotation);
-            let outline = [
-                image_screen_rect.right_bottom(),
-                image_screen_rect.right_top(),
-                image_screen_rect.left_top(),
-                image_screen_rect.left_bottom(),
-            ]
-            .iter()
-            .map(|point| center + rotation * (*point - center))
-            .collect();
-            shapes.push(Shape::closed_line(
-                outline,
-                Stroke::new(1.0, ui.visuals().strong_text_color()),
-            ));
+            radius *= 2f32.sqrt();
+            stem_stroke.width *= 2.0;
         }
-    }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+        let y_reference = stems.map(|y| {
+            transform
+                .position_from_point_clamped(&PlotPoint::new(0.0, y))
+                .y
+        });
+
+        if let Some(error_bars) = error_bars {
+            let stroke = error_bars.element_stroke(*color, *highlight);
+            for (index, point) in series.points().iter().enumerate() {
+                error_bars.add_shapes(
+                    index,
+                    *point,
+                    Orientation::Vertical,
+                    transform,
+                    stroke,
+                    shapes,
+                );
+            }
+        }
 
-    fn name(&self) -> &str {
-        self.name.as_str()
-    }
+        series
+            .points()
+            .iter()
+            .filter(|value| transform.can_show(value))
+            .map(|value| transform.position_from_point(value))
+            .for_each(|center| {
+                let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
 
-    fn color(&self) -> Color32 {
-        Color32::TRANSPARENT
-    }
+                if let Some(y) = y_reference {
+                    let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
+                    shapes.push(stem);
+                }
+
+                match shape {
+                    MarkerShape::Circle => {
+                        shapes.push(Shape::Circle(epaint::CircleShape {
+                            center,
+                            radius,
+                            fill,
+                            stroke,
+                        }));
+                    }
+                    MarkerShape::Diamond => {
+                        let points = vec![
+                            tf(0.0, 1.0),  // bottom
+                            tf(-1.0, 0.0), // left
+                            tf(0.0, -1.0), // top
+                            tf(1.0, 0.0),  // right
+                        ];
+                        shapes.push(Shape::convex_polygon(points, fill, stroke));
+                    }
+                    MarkerShape::Square => {
+                        let points = vec![
+                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
+                        ];
+                        shapes.push(Shape::convex_polygon(points, fill, stroke));
+                    }
+                    MarkerShape::Cross => {
+                        let diagonal1 = [
+                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
+                        ];
+                        let diagonal2 = [
+                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+                        ];
+                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
+                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
+                    }
+                    MarkerShape::Plus => {
+                        let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
+                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+                        shapes.push(Shape::line_segment(horizontal, default_stroke));
+                        shapes.push(Shape::line_segment(vertical, default_stroke));
+                    }
+                    MarkerShape::Up => {
+                        let points =
+                            vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
+                        shapes.push(Shape::convex_polygon(points, fill, stroke));
+                    }
+                    MarkerShape::Down => {
+                        let points = vec![
+                            tf(0.0, 1.0),
+                            tf(-0.5 * sqrt_3, -0.5),
+                            tf(0.5 * sqrt_3, -0.5),
+                        ];
+                        shapes.push(Shape::convex_polygon(points, fill, stroke));
+                    }
+                    MarkerShape::Left => {
+                        let points =
+                            vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
+                        shapes.push(Shape::convex_polygon(points, fill, stroke));
+                    }
+                    MarkerShape::Right => {
+                        let points = vec![
+                            tf(1.0, 0.0),
+                            tf(-0.5, 0.5 * sqrt_3),
+                            tf(-0.5, -0.5 * sqrt_3),
+                        ];
+                        shapes.push(Shape::convex_polygon(points, fill, stroke));
+                    }
+                    MarkerShape::Asterisk => {
+                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+                        let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
+                        let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
+                        shapes.push(Shape::line_segment(vertical, default_stroke));
+                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
+                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
+                    }
+                }
+            });
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.series.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.series.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.series.bounds();
+        if let Some(error_bars) = &self.error_bars {
+            for (index, point) in self.series.points().iter().enumerate() {
+                error_bars.extend_bounds(index, *point, Orientation::Vertical, &mut bounds);
+            }
+        }
+        bounds
+    }
+
+    fn plot_bounds(&self, log_axes: Vec2b) -> PlotBounds {
+        if !log_axes.any() {
+            return self.bounds();
+        }
+        let points = self.series.points();
+        let error_ends = self.error_bars.iter().flat_map(|error_bars| {
+            points.iter().enumerate().flat_map(|(index, point)| {
+                error_bars
+                    .ends(index, *point, Orientation::Vertical)
+                    .into_iter()
+                    .flatten()
+            })
+        });
+        transform::plot_bounds_from_points(points.iter().copied().chain(error_ends), log_axes)
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
+}
+
+/// A set of arrows.
+pub struct Arrows {
+    pub(super) origins: PlotPoints,
+    pub(super) tips: PlotPoints,
+    pub(super) tip_length: Option<f32>,
+    pub(super) color: Color32,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    secondary_y: bool,
+    id: Option<Id>,
+}
+
+impl Arrows {
+    pub fn new(origins: impl Into<PlotPoints>, tips: impl Into<PlotPoints>) -> Self {
+        Self {
+            origins: origins.into(),
+            tips: tips.into(),
+            tip_length: None,
+            color: Color32::TRANSPARENT,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            secondary_y: false,
+            id: None,
+        }
+    }
+
+    /// Highlight these arrows in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Set the length of the arrow tips
+    #[inline]
+    pub fn tip_length(mut self, tip_length: f32) -> Self {
+        self.tip_length = Some(tip_length);
+        self
+    }
+
+    /// Set the arrows' color.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Name of this set of arrows.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the arrows' id which is used to identify them in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
+}
+
+impl PlotItem for Arrows {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        use crate::emath::*;
+        let Self {
+            origins,
+            tips,
+            tip_length,
+            color,
+            highlight,
+            ..
+        } = self;
+        let stroke = Stroke::new(if *highlight { 2.0 } else { 1.0 }, *color);
+        origins
+            .points()
+            .iter()
+            .zip(tips.points().iter())
+            .map(|(origin, tip)| {
+                (
+                    transform.position_from_point(origin),
+                    transform.position_from_point(tip),
+                )
+            })
+            .for_each(|(origin, tip)| {
+                let vector = tip - origin;
+                let rot = Rot2::from_angle(std::f32::consts::TAU / 10.0);
+                let tip_length = if let Some(tip_length) = tip_length {
+                    *tip_length
+                } else {
+                    vector.length() / 4.0
+                };
+                let tip = origin + vector;
+                let dir = vector.normalized();
+                shapes.push(Shape::line_segment([origin, tip], stroke));
+                shapes.push(Shape::line(
+                    vec![
+                        tip - tip_length * (rot.inverse() * dir),
+                        tip,
+                        tip - tip_length * (rot * dir),
+                    ],
+                    stroke,
+                ));
+            });
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        self.origins
+            .generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+        self.tips.generate_points(f64::NEG_INFINITY..=f64::INFINITY);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(self.origins.points())
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.origins.bounds()
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
+}
+
+/// An image in the plot.
+#[derive(Clone)]
+pub struct PlotImage {
+    pub(super) position: PlotPoint,
+    pub(super) texture_id: TextureId,
+    pub(super) uv: Rect,
+    pub(super) size: Vec2,
+    pub(crate) rotation: f64,
+    pub(super) bg_fill: Color32,
+    pub(super) tint: Color32,
+    pub(super) highlight: bool,
+    pub(super) allow_hover: bool,
+    pub(super) name: String,
+    secondary_y: bool,
+    id: Option<Id>,
+}
+
+impl PlotImage {
+    /// Create a new image with position and size in plot coordinates.
+    pub fn new(
+        texture_id: impl Into<TextureId>,
+        center_position: PlotPoint,
+        size: impl Into<Vec2>,
+    ) -> Self {
+        Self {
+            position: center_position,
+            name: Default::default(),
+            highlight: false,
+            allow_hover: true,
+            texture_id: texture_id.into(),
+            uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            size: size.into(),
+            rotation: 0.0,
+            bg_fill: Default::default(),
+            tint: Color32::WHITE,
+            secondary_y: false,
+            id: None,
+        }
+    }
+
+    /// Highlight this image in the plot.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
+    #[inline]
+    pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
+        self.uv = uv.into();
+        self
+    }
+
+    /// A solid color to put behind the image. Useful for transparent images.
+    #[inline]
+    pub fn bg_fill(mut self, bg_fill: impl Into<Color32>) -> Self {
+        self.bg_fill = bg_fill.into();
+        self
+    }
+
+    /// Multiply image color with this. Default is WHITE (no tint).
+    #[inline]
+    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Name of this image.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Rotate the image counter-clockwise around its center by an angle in radians.
+    #[inline]
+    pub fn rotate(mut self, angle: f64) -> Self {
+        self.rotation = angle;
+        self
+    }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
+}
+
+impl PlotItem for PlotImage {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Self {
+            position,
+            rotation,
+            texture_id,
+            uv,
+            size,
+            bg_fill,
+            tint,
+            highlight,
+            ..
+        } = self;
+        let image_screen_rect = {
+            let left_top = PlotPoint::new(
+                position.x - 0.5 * size.x as f64,
+                position.y - 0.5 * size.y as f64,
+            );
+            let right_bottom = PlotPoint::new(
+                position.x + 0.5 * size.x as f64,
+                position.y + 0.5 * size.y as f64,
+            );
+            let left_top_screen = transform.position_from_point(&left_top);
+            let right_bottom_screen = transform.position_from_point(&right_bottom);
+            Rect::from_two_pos(left_top_screen, right_bottom_screen)
+        };
+        let screen_rotation = -*rotation as f32;
+
+        egui::paint_texture_at(
+            ui.painter(),
+            image_screen_rect,
+            &ImageOptions {
+                uv: *uv,
+                bg_fill: *bg_fill,
+                tint: *tint,
+                rotation: Some((Rot2::from_angle(screen_rotation), Vec2::splat(0.5))),
+                rounding: Rounding::ZERO,
+                ..Default::default()
+            },
+            &(*texture_id, image_screen_rect.size()).into(),
+        );
+        if *highlight {
+            let center = image_screen_rect.center();
+            let rotation = Rot2::from_angle(screen_rotation);
+            let outline = [
+                image_screen_rect.right_bottom(),
+                image_screen_rect.right_top(),
+                image_screen_rect.left_top(),
+                image_screen_rect.left_bottom(),
+            ]
+            .iter()
+            .map(|point| center + rotation * (*point - center))
+            .collect();
+            shapes.push(Shape::closed_line(
+                outline,
+                Stroke::new(1.0, ui.visuals().strong_text_color()),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        Color32::TRANSPARENT
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        let left_top = PlotPoint::new(
+            self.position.x as f32 - self.size.x / 2.0,
+            self.position.y as f32 - self.size.y / 2.0,
+        );
+        let right_bottom = PlotPoint::new(
+            self.position.x as f32 + self.size.x / 2.0,
+            self.position.y as f32 + self.size.y / 2.0,
+        );
+        bounds.extend_with(&left_top);
+        bounds.extend_with(&right_bottom);
+        bounds
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A bar chart.
+pub struct BarChart {
+    pub(super) bars: Vec<Bar>,
+    pub(super) default_color: Color32,
+    pub(super) name: String,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&Bar, &BarChart) -> String>>,
+
+    pub(super) error_bars: Option<ErrorBars>,
+
+    highlight: bool,
+    allow_hover: bool,
+    secondary_y: bool,
+    id: Option<Id>,
+}
+
+impl BarChart {
+    /// Create a bar chart. It defaults to vertically oriented elements.
+    pub fn new(bars: Vec<Bar>) -> Self {
+        Self {
+            bars,
+            default_color: Color32::TRANSPARENT,
+            name: String::new(),
+            element_formatter: None,
+            error_bars: None,
+            highlight: false,
+            allow_hover: true,
+            secondary_y: false,
+            id: None,
+        }
+    }
+
+    /// Set the default color. It is set on all elements that do not already have a specific color.
+    /// This is the color that shows up in the legend.
+    /// It can be overridden at the bar level (see [[`Bar`]]).
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        let plot_color = color.into();
+        self.default_color = plot_color;
+        for b in &mut self.bars {
+            if b.fill == Color32::TRANSPARENT && b.stroke.color == Color32::TRANSPARENT {
+                b.fill = plot_color.linear_multiply(0.2);
+                b.stroke.color = plot_color;
+            }
+        }
+        self
+    }
+
+    /// Name of this chart.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple charts may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set all elements to be in a vertical orientation.
+    /// Argument axis will be X and bar values will be on the Y axis.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        for b in &mut self.bars {
+            b.orientation = Orientation::Vertical;
+        }
+        self
+    }
+
+    /// Set all elements to be in a horizontal orientation.
+    /// Argument axis will be Y and bar values will be on the X axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        for b in &mut self.bars {
+            b.orientation = Orientation::Horizontal;
+        }
+        self
+    }
+
+    /// Set the width (thickness) of all its elements.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        for b in &mut self.bars {
+            b.bar_width = width;
+        }
+        self
+    }
+
+    /// Highlight all plot elements.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals or custom labels.
+    #[inline]
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&Bar, &Self) -> String>) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    /// Add error bars to the bars, showing the uncertainty of their values.
+    ///
+    /// The error bars are at the end of the bars, so for stacked bars at the top of each segment.
+    #[inline]
+    pub fn error_bars(mut self, error_bars: ErrorBars) -> Self {
+        self.error_bars = Some(error_bars);
+        self
+    }
+
+    /// Stacks the bars on top of another chart.
+    /// Positive values are stacked on top of other positive values.
+    /// Negative values are stacked below other negative values.
+    ///
+    /// The bars are matched by their index, so the charts should have their bars in the same order.
+    ///
+    /// See also [`Self::stacked`].
+    #[inline]
+    pub fn stack_on(mut self, others: &[&Self]) -> Self {
+        for (index, bar) in self.bars.iter_mut().enumerate() {
+            let new_base_offset = if bar.value.is_sign_positive() {
+                others
+                    .iter()
+                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.upper()))
+                    .max_by_key(|value| value.ord())
+            } else {
+                others
+                    .iter()
+                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.lower()))
+                    .min_by_key(|value| value.ord())
+            };
+
+            if let Some(value) = new_base_offset {
+                bar.base_offset = Some(value);
+            }
+        }
+        self
+    }
+
+    /// Stack each of the `charts` on top of the ones before it, see [`Self::stack_on`].
+    pub fn stacked(charts: Vec<Self>) -> Vec<Self> {
+        let mut stacked: Vec<Self> = Vec::with_capacity(charts.len());
+        for chart in charts {
+            let chart = chart.stack_on(&stacked.iter().collect::<Vec<_>>());
+            stacked.push(chart);
+        }
+        stacked
+    }
+
+    /// Place the bars of the `charts` side by side, in the order of the charts.
+    ///
+    /// Each bar gets an equal share of its original width,
+    /// so bars with the same argument form a group as wide as one of the original bars,
+    /// centered on the argument.
+    pub fn grouped(mut charts: Vec<Self>) -> Vec<Self> {
+        let count = charts.len() as f64;
+        for (index, chart) in charts.iter_mut().enumerate() {
+            for bar in &mut chart.bars {
+                let width = bar.bar_width / count;
+                bar.argument += (index as f64 + 0.5) * width - bar.bar_width / 2.0;
+                bar.bar_width = width;
+            }
+        }
+        charts
+    }
+
+    /// Set the bar chart's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
+}
+
+impl PlotItem for BarChart {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for b in &self.bars {
+            b.add_shapes(transform, self.highlight, shapes);
+        }
+        if let Some(error_bars) = &self.error_bars {
+            for (index, b) in self.bars.iter().enumerate() {
+                error_bars.add_shapes(
+                    index,
+                    b.value_end(),
+                    b.orientation,
+                    transform,
+                    error_bars.element_stroke(b.stroke.color, self.highlight),
+                    shapes,
+                );
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.default_color
+    }
 
     fn highlight(&mut self) {
         self.highlight = true;
@@ -1519,113 +2535,327 @@ impl PlotItem for PlotImage {
     }
 
     fn geometry(&self) -> PlotGeometry<'_> {
-        PlotGeometry::None
+        PlotGeometry::Rects
     }
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        let left_top = PlotPoint::new(
-            self.position.x as f32 - self.size.x / 2.0,
-            self.position.y as f32 - self.size.y / 2.0,
-        );
-        let right_bottom = PlotPoint::new(
-            self.position.x as f32 + self.size.x / 2.0,
-            self.position.y as f32 + self.size.y / 2.0,
-        );
-        bounds.extend_with(&left_top);
-        bounds.extend_with(&right_bottom);
+        for (index, b) in self.bars.iter().enumerate() {
+            bounds.merge(&b.bounds());
+            if let Some(error_bars) = &self.error_bars {
+                error_bars.extend_bounds(index, b.value_end(), b.orientation, &mut bounds);
+            }
+        }
         bounds
     }
 
+    fn plot_bounds(&self, log_axes: Vec2b) -> PlotBounds {
+        if !log_axes.any() {
+            return self.bounds();
+        }
+        // The corners of the bars and the ends of the error bars,
+        // so the tops of the bars count even when their bases at zero can't be shown:
+        let points = self.bars.iter().enumerate().flat_map(|(index, b)| {
+            let error_ends = self
+                .error_bars
+                .as_ref()
+                .and_then(|error_bars| error_bars.ends(index, b.value_end(), b.orientation));
+            [b.bounds_min(), b.bounds_max()]
+                .into_iter()
+                .chain(error_ends.into_iter().flatten())
+        });
+        transform::plot_bounds_from_points(points, log_axes)
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.bars, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let bar = &self.bars[elem.index];
+
+        bar.add_shapes(plot.transform, true, shapes);
+        bar.add_rulers_and_text(self, plot, shapes, cursors);
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
-}
 
-// ----------------------------------------------------------------------------
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
+}
 
-/// A bar chart.
-pub struct BarChart {
-    pub(super) bars: Vec<Bar>,
+/// A diagram containing a series of [`BoxElem`] elements.
+pub struct BoxPlot {
+    pub(super) boxes: Vec<BoxElem>,
     pub(super) default_color: Color32,
     pub(super) name: String,
 
     /// A custom element formatter
-    pub(super) element_formatter: Option<Box<dyn Fn(&Bar, &BarChart) -> String>>,
+    pub(super) element_formatter: Option<Box<dyn Fn(&BoxElem, &BoxPlot) -> String>>,
 
     highlight: bool,
     allow_hover: bool,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
-impl BarChart {
-    /// Create a bar chart. It defaults to vertically oriented elements.
-    pub fn new(bars: Vec<Bar>) -> Self {
+impl BoxPlot {
+    /// Create a plot containing multiple `boxes`. It defaults to vertically oriented elements.
+    pub fn new(boxes: Vec<BoxElem>) -> Self {
         Self {
-            bars,
+            boxes,
             default_color: Color32::TRANSPARENT,
             name: String::new(),
             element_formatter: None,
             highlight: false,
             allow_hover: true,
+            secondary_y: false,
             id: None,
         }
     }
 
     /// Set the default color. It is set on all elements that do not already have a specific color.
     /// This is the color that shows up in the legend.
-    /// It can be overridden at the bar level (see [[`Bar`]]).
+    /// It can be overridden at the element level (see [`BoxElem`]).
     /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
     #[inline]
     pub fn color(mut self, color: impl Into<Color32>) -> Self {
         let plot_color = color.into();
         self.default_color = plot_color;
-        for b in &mut self.bars {
-            if b.fill == Color32::TRANSPARENT && b.stroke.color == Color32::TRANSPARENT {
-                b.fill = plot_color.linear_multiply(0.2);
-                b.stroke.color = plot_color;
+        for box_elem in &mut self.boxes {
+            if box_elem.fill == Color32::TRANSPARENT
+                && box_elem.stroke.color == Color32::TRANSPARENT
+            {
+                box_elem.fill = plot_color.linear_multiply(0.2);
+                box_elem.stroke.color = plot_color;
             }
         }
-        self
+        self
+    }
+
+    /// Name of this box plot diagram.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set all elements to be in a vertical orientation.
+    /// Argument axis will be X and values will be on the Y axis.
+    #[inline]
+    pub fn vertical(mut self) -> Self {
+        for box_elem in &mut self.boxes {
+            box_elem.orientation = Orientation::Vertical;
+        }
+        self
+    }
+
+    /// Set all elements to be in a horizontal orientation.
+    /// Argument axis will be Y and values will be on the X axis.
+    #[inline]
+    pub fn horizontal(mut self) -> Self {
+        for box_elem in &mut self.boxes {
+            box_elem.orientation = Orientation::Horizontal;
+        }
+        self
+    }
+
+    /// Highlight all plot elements.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Allowed hovering this item in the plot. Default: `true`.
+    #[inline]
+    pub fn allow_hover(mut self, hovering: bool) -> Self {
+        self.allow_hover = hovering;
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals or custom labels.
+    #[inline]
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&BoxElem, &Self) -> String>) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    /// Set the box plot's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
+}
+
+impl PlotItem for BoxPlot {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for b in &self.boxes {
+            b.add_shapes(transform, self.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.default_color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn allow_hover(&self) -> bool {
+        self.allow_hover
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for b in &self.boxes {
+            bounds.merge(&b.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(&self.boxes, point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter<'_>,
+    ) {
+        let box_plot = &self.boxes[elem.index];
+
+        box_plot.add_shapes(plot.transform, true, shapes);
+        box_plot.add_rulers_and_text(self, plot, shapes, cursors);
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
+}
+
+/// A candlestick chart, also known as an OHLC chart, e.g. for showing how a price changed over time.
+///
+/// Each [`Candle`] has a wick from its low to its high value, and a body from its open to its close value.
+/// The candles are colored by whether the value went up or down.
+pub struct Candlestick {
+    pub(super) candles: Vec<Candle>,
+    pub(super) name: String,
+    pub(super) up_color: Color32,
+    pub(super) down_color: Color32,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&Candle, &Candlestick) -> String>>,
+
+    highlight: bool,
+    allow_hover: bool,
+    secondary_y: bool,
+    id: Option<Id>,
+}
+
+impl Candlestick {
+    /// Create a chart of the given `candles`.
+    pub fn new(candles: Vec<Candle>) -> Self {
+        Self {
+            candles,
+            name: String::new(),
+            up_color: Color32::from_rgb(38, 166, 91),
+            down_color: Color32::from_rgb(217, 48, 37),
+            element_formatter: None,
+            highlight: false,
+            allow_hover: true,
+            secondary_y: false,
+            id: None,
+        }
     }
 
-    /// Name of this chart.
+    /// The color of the candles where the value went up.
+    /// This is the color that shows up in the legend.
     ///
-    /// This name will show up in the plot legend, if legends are turned on. Multiple charts may
-    /// share the same name, in which case they will also share an entry in the legend.
-    #[allow(clippy::needless_pass_by_value)]
+    /// Default: green.
     #[inline]
-    pub fn name(mut self, name: impl ToString) -> Self {
-        self.name = name.to_string();
+    pub fn up_color(mut self, color: impl Into<Color32>) -> Self {
+        self.up_color = color.into();
         self
     }
 
-    /// Set all elements to be in a vertical orientation.
-    /// Argument axis will be X and bar values will be on the Y axis.
+    /// The color of the candles where the value went down.
+    ///
+    /// Default: red.
     #[inline]
-    pub fn vertical(mut self) -> Self {
-        for b in &mut self.bars {
-            b.orientation = Orientation::Vertical;
-        }
+    pub fn down_color(mut self, color: impl Into<Color32>) -> Self {
+        self.down_color = color.into();
         self
     }
 
-    /// Set all elements to be in a horizontal orientation.
-    /// Argument axis will be Y and bar values will be on the X axis.
+    /// Set the width of the bodies of all candles, in X axis units.
+    ///
+    /// For instance, for daily candles on a [`Plot::x_time_axis`] this could be `0.6 * 86400.0`.
     #[inline]
-    pub fn horizontal(mut self) -> Self {
-        for b in &mut self.bars {
-            b.orientation = Orientation::Horizontal;
+    pub fn width(mut self, width: f64) -> Self {
+        for candle in &mut self.candles {
+            candle.width = width;
         }
         self
     }
 
-    /// Set the width (thickness) of all its elements.
+    /// Name of this chart.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
     #[inline]
-    pub fn width(mut self, width: f64) -> Self {
-        for b in &mut self.bars {
-            b.bar_width = width;
-        }
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
         self
     }
 
@@ -1646,48 +2876,41 @@ impl BarChart {
     /// Add a custom way to format an element.
     /// Can be used to display a set number of decimals or custom labels.
     #[inline]
-    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&Bar, &Self) -> String>) -> Self {
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&Candle, &Self) -> String>) -> Self {
         self.element_formatter = Some(formatter);
         self
     }
 
-    /// Stacks the bars on top of another chart.
-    /// Positive values are stacked on top of other positive values.
-    /// Negative values are stacked below other negative values.
+    /// Set the chart's id which is used to identify it in the plot's response.
     #[inline]
-    pub fn stack_on(mut self, others: &[&Self]) -> Self {
-        for (index, bar) in self.bars.iter_mut().enumerate() {
-            let new_base_offset = if bar.value.is_sign_positive() {
-                others
-                    .iter()
-                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.upper()))
-                    .max_by_key(|value| value.ord())
-            } else {
-                others
-                    .iter()
-                    .filter_map(|other_chart| other_chart.bars.get(index).map(|bar| bar.lower()))
-                    .min_by_key(|value| value.ord())
-            };
-
-            if let Some(value) = new_base_offset {
-                bar.base_offset = Some(value);
-            }
-        }
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
         self
     }
 
-    /// Set the bar chart's id which is used to identify it in the plot's response.
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
     #[inline]
-    pub fn id(mut self, id: Id) -> Self {
-        self.id = Some(id);
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
         self
     }
+
+    fn candle_color(&self, candle: &Candle) -> Color32 {
+        if candle.is_up() {
+            self.up_color
+        } else {
+            self.down_color
+        }
+    }
 }
 
-impl PlotItem for BarChart {
+impl PlotItem for Candlestick {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        for b in &self.bars {
-            b.add_shapes(transform, self.highlight, shapes);
+        for candle in &self.candles {
+            let color = self.candle_color(candle);
+            candle.add_shapes(transform, color, self.highlight, shapes);
         }
     }
 
@@ -1700,7 +2923,7 @@ impl PlotItem for BarChart {
     }
 
     fn color(&self) -> Color32 {
-        self.default_color
+        self.up_color
     }
 
     fn highlight(&mut self) {
@@ -1721,14 +2944,14 @@ impl PlotItem for BarChart {
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        for b in &self.bars {
-            bounds.merge(&b.bounds());
+        for candle in &self.candles {
+            bounds.merge(&candle.bounds());
         }
         bounds
     }
 
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
-        find_closest_rect(&self.bars, point, transform)
+        find_closest_rect(&self.candles, point, transform)
     }
 
     fn on_hover(
@@ -1739,142 +2962,360 @@ impl PlotItem for BarChart {
         plot: &PlotConfig<'_>,
         _: &LabelFormatter<'_>,
     ) {
-        let bar = &self.bars[elem.index];
+        let candle = &self.candles[elem.index];
 
-        bar.add_shapes(plot.transform, true, shapes);
-        bar.add_rulers_and_text(self, plot, shapes, cursors);
+        candle.add_shapes(plot.transform, self.candle_color(candle), true, shapes);
+        candle.add_rulers_and_text(self, plot, shapes, cursors);
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
 }
 
-/// A diagram containing a series of [`BoxElem`] elements.
-pub struct BoxPlot {
-    pub(super) boxes: Vec<BoxElem>,
-    pub(super) default_color: Color32,
+/// A heatmap: a grid of values, each painted as a cell with a color from a gradient.
+///
+/// The whole grid is painted as a single mesh, so it stays fast for large grids.
+pub struct Heatmap {
+    pub(super) values: Vec<f64>,
+    pub(super) columns: usize,
+    pub(super) rows: usize,
+    pub(super) min: PlotPoint,
+    pub(super) max: PlotPoint,
+    pub(super) gradient: Vec<Color32>,
+    pub(super) value_range: Option<RangeInclusive<f64>>,
+    pub(super) show_colorbar: bool,
     pub(super) name: String,
 
-    /// A custom element formatter
-    pub(super) element_formatter: Option<Box<dyn Fn(&BoxElem, &BoxPlot) -> String>>,
+    /// A custom formatter for the values, used for the hover readout and the colorbar.
+    pub(super) value_formatter: Option<Box<dyn Fn(f64) -> String>>,
 
     highlight: bool,
     allow_hover: bool,
+    secondary_y: bool,
     id: Option<Id>,
 }
 
-impl BoxPlot {
-    /// Create a plot containing multiple `boxes`. It defaults to vertically oriented elements.
-    pub fn new(boxes: Vec<BoxElem>) -> Self {
+impl Heatmap {
+    /// Create a heatmap of `values` in `columns` columns.
+    ///
+    /// The values are in row-major order, starting with the bottom row (the smallest y).
+    /// Values that don't fill a whole row are ignored,
+    /// and non-finite values (e.g. `f64::NAN`) leave their cell empty.
+    ///
+    /// By default the cell in column `c` and row `r` covers `c..c + 1` on the x axis
+    /// and `r..r + 1` on the y axis, see [`Self::position`].
+    pub fn new(values: Vec<f64>, columns: usize) -> Self {
+        let rows = if columns == 0 {
+            0
+        } else {
+            values.len() / columns
+        };
         Self {
-            boxes,
-            default_color: Color32::TRANSPARENT,
+            values,
+            columns,
+            rows,
+            min: PlotPoint::new(0.0, 0.0),
+            max: PlotPoint::new(columns as f64, rows as f64),
+            gradient: vec![
+                Color32::from_rgb(68, 1, 84),
+                Color32::from_rgb(59, 82, 139),
+                Color32::from_rgb(33, 145, 140),
+                Color32::from_rgb(94, 201, 98),
+                Color32::from_rgb(253, 231, 37),
+            ],
+            value_range: None,
+            show_colorbar: false,
             name: String::new(),
-            element_formatter: None,
+            value_formatter: None,
             highlight: false,
             allow_hover: true,
+            secondary_y: false,
             id: None,
         }
     }
 
-    /// Set the default color. It is set on all elements that do not already have a specific color.
-    /// This is the color that shows up in the legend.
-    /// It can be overridden at the element level (see [`BoxElem`]).
-    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    /// Place the heatmap in the rectangle from `min` to `max`, in plot coordinates.
+    /// The cells share it evenly.
     #[inline]
-    pub fn color(mut self, color: impl Into<Color32>) -> Self {
-        let plot_color = color.into();
-        self.default_color = plot_color;
-        for box_elem in &mut self.boxes {
-            if box_elem.fill == Color32::TRANSPARENT
-                && box_elem.stroke.color == Color32::TRANSPARENT
-            {
-                box_elem.fill = plot_color.linear_multiply(0.2);
-                box_elem.stroke.color = plot_color;
-            }
-        }
+    pub fn position(mut self, min: impl Into<PlotPoint>, max: impl Into<PlotPoint>) -> Self {
+        self.min = min.into();
+        self.max = max.into();
         self
     }
 
-    /// Name of this box plot diagram.
+    /// The colors the values are mapped to, from the lowest to the highest value.
+    /// Between them the colors are interpolated.
     ///
-    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
-    /// share the same name, in which case they will also share an entry in the legend.
-    #[allow(clippy::needless_pass_by_value)]
+    /// Default: a gradient from dark purple via teal to yellow (viridis).
     #[inline]
-    pub fn name(mut self, name: impl ToString) -> Self {
-        self.name = name.to_string();
+    pub fn gradient(mut self, colors: impl IntoIterator<Item = Color32>) -> Self {
+        self.gradient = colors.into_iter().collect();
         self
     }
 
-    /// Set all elements to be in a vertical orientation.
-    /// Argument axis will be X and values will be on the Y axis.
+    /// The values mapped to the first and last color of the [`Self::gradient`].
+    /// Values outside of it get the first or last color.
+    /// The smaller end is always the first color, so `1.0..=0.0` is the same as `0.0..=1.0`
+    /// (reverse the [`Self::gradient`] instead).
+    ///
+    /// Default: the range of the finite values.
     #[inline]
-    pub fn vertical(mut self) -> Self {
-        for box_elem in &mut self.boxes {
-            box_elem.orientation = Orientation::Vertical;
-        }
+    pub fn value_range(mut self, range: RangeInclusive<f64>) -> Self {
+        let (start, end) = range.into_inner();
+        self.value_range = Some(start.min(end)..=start.max(end));
         self
     }
 
-    /// Set all elements to be in a horizontal orientation.
-    /// Argument axis will be Y and values will be on the X axis.
+    /// Show a colorbar at the right edge of the plot,
+    /// telling which value each color stands for.
+    ///
+    /// Default: `false`.
     #[inline]
-    pub fn horizontal(mut self) -> Self {
-        for box_elem in &mut self.boxes {
-            box_elem.orientation = Orientation::Horizontal;
-        }
+    pub fn show_colorbar(mut self, show_colorbar: bool) -> Self {
+        self.show_colorbar = show_colorbar;
         self
     }
 
-    /// Highlight all plot elements.
+    /// Name of this heatmap.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight this heatmap in the plot.
     #[inline]
     pub fn highlight(mut self, highlight: bool) -> Self {
         self.highlight = highlight;
         self
     }
 
-    /// Allowed hovering this item in the plot. Default: `true`.
+    /// Allowed hovering this item in the plot, showing the value of the hovered cell.
+    /// Default: `true`.
     #[inline]
     pub fn allow_hover(mut self, hovering: bool) -> Self {
         self.allow_hover = hovering;
         self
     }
 
-    /// Add a custom way to format an element.
-    /// Can be used to display a set number of decimals or custom labels.
+    /// Add a custom way to format the values, in the hover readout and on the colorbar.
+    /// Can be used to display a set number of decimals or units.
     #[inline]
-    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&BoxElem, &Self) -> String>) -> Self {
-        self.element_formatter = Some(formatter);
+    pub fn value_formatter(mut self, formatter: Box<dyn Fn(f64) -> String>) -> Self {
+        self.value_formatter = Some(formatter);
         self
     }
 
-    /// Set the box plot's id which is used to identify it in the plot's response.
+    /// Set the heatmap's id which is used to identify it in the plot's response.
     #[inline]
     pub fn id(mut self, id: Id) -> Self {
         self.id = Some(id);
         self
     }
-}
 
-impl PlotItem for BoxPlot {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        for b in &self.boxes {
-            b.add_shapes(transform, self.highlight, shapes);
+    /// Plot this item against the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn secondary_y(mut self, secondary_y: bool) -> Self {
+        self.secondary_y = secondary_y;
+        self
+    }
+
+    fn value_range_or_auto(&self) -> RangeInclusive<f64> {
+        self.value_range.clone().unwrap_or_else(|| {
+            let (min, max) = self
+                .cell_values()
+                .filter(|v| v.is_finite())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+                    (min.min(v), max.max(v))
+                });
+            min..=max
+        })
+    }
+
+    /// The values of all whole rows.
+    fn cell_values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.values[..self.columns * self.rows].iter().copied()
+    }
+
+    /// The color at a fraction `t` in `0..=1` of the gradient.
+    fn gradient_color(&self, t: f32) -> Color32 {
+        match self.gradient.as_slice() {
+            [] => Color32::TRANSPARENT,
+            [color] => *color,
+            colors => {
+                let position = t.clamp(0.0, 1.0) * (colors.len() - 1) as f32;
+                let index = (position as usize).min(colors.len() - 2);
+                colors[index].lerp_to_gamma(colors[index + 1], position - index as f32)
+            }
         }
     }
 
-    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
-        // nothing to do
+    fn value_color(&self, value: f64, range: &RangeInclusive<f64>) -> Color32 {
+        let t = if range.start() < range.end() {
+            remap(value, range.clone(), 0.0..=1.0) as f32
+        } else {
+            0.5
+        };
+        self.gradient_color(t)
+    }
+
+    fn format_value(&self, value: f64, range: &RangeInclusive<f64>) -> String {
+        if let Some(formatter) = &self.value_formatter {
+            formatter(value)
+        } else {
+            let decimals = ((-((range.end() - range.start()) / 100.0).log10())
+                .ceil()
+                .at_least(0.0) as usize)
+                .at_most(6);
+            format!("{value:.decimals$}")
+        }
+    }
+
+    /// The corners of the cell in `column` and `row`, in plot coordinates.
+    fn cell_bounds(&self, column: usize, row: usize) -> (PlotPoint, PlotPoint) {
+        let x = |column: usize| lerp(self.min.x..=self.max.x, column as f64 / self.columns as f64);
+        let y = |row: usize| lerp(self.min.y..=self.max.y, row as f64 / self.rows as f64);
+        (
+            PlotPoint::new(x(column), y(row)),
+            PlotPoint::new(x(column + 1), y(row + 1)),
+        )
+    }
+
+    fn cell_rect(&self, transform: &PlotTransform, column: usize, row: usize) -> Rect {
+        let (min, max) = self.cell_bounds(column, row);
+        transform.rect_from_values(&min, &max)
+    }
+
+    fn add_colorbar(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        const BAR_WIDTH: f32 = 12.0;
+        const MARGIN: f32 = 8.0;
+        const NUM_LABELS: usize = 5;
+
+        let frame = transform.frame();
+        let height = (frame.height() - 2.0 * MARGIN).at_most(200.0);
+        if height <= 0.0 {
+            return;
+        }
+        let bar = Rect::from_min_size(
+            pos2(
+                frame.right() - 2.0 * MARGIN - BAR_WIDTH,
+                frame.center().y - height / 2.0,
+            ),
+            vec2(BAR_WIDTH, height),
+        );
+
+        let range = self.value_range_or_auto();
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let text_color = ui.visuals().text_color();
+        let labels: Vec<_> = (0..NUM_LABELS)
+            .map(|i| {
+                let t = i as f64 / (NUM_LABELS - 1) as f64;
+                let text = self.format_value(lerp(range.clone(), t), &range);
+                let galley = ui.fonts(|f| f.layout_no_wrap(text, font_id.clone(), text_color));
+                (lerp(bar.bottom()..=bar.top(), t as f32), galley)
+            })
+            .collect();
+        let label_width = labels
+            .iter()
+            .map(|(_, galley)| galley.size().x)
+            .fold(0.0, f32::max);
+
+        let background = Rect::from_min_max(
+            pos2(bar.left() - MARGIN / 2.0 - label_width, bar.top()),
+            bar.right_bottom(),
+        )
+        .expand(MARGIN / 2.0);
+        shapes.push(Shape::rect_filled(
+            background,
+            ui.visuals().widgets.noninteractive.rounding,
+            ui.visuals().extreme_bg_color,
+        ));
+
+        let mut mesh = Mesh::default();
+        let num_steps = self.gradient.len().at_least(2);
+        for i in 0..num_steps {
+            let t = i as f32 / (num_steps - 1) as f32;
+            let y = lerp(bar.bottom()..=bar.top(), t);
+            let color = self.gradient_color(t);
+            mesh.colored_vertex(pos2(bar.left(), y), color);
+            mesh.colored_vertex(pos2(bar.right(), y), color);
+            if 0 < i {
+                let index = 2 * i as u32;
+                mesh.add_triangle(index - 2, index - 1, index);
+                mesh.add_triangle(index - 1, index, index + 1);
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+        shapes.push(Shape::rect_stroke(
+            bar,
+            0.0,
+            ui.visuals().widgets.noninteractive.bg_stroke,
+        ));
+
+        for (y, galley) in labels {
+            let pos = pos2(
+                bar.left() - MARGIN / 2.0 - galley.size().x,
+                y - galley.size().y / 2.0,
+            );
+            shapes.push(Shape::line_segment(
+                [pos2(bar.left() - 2.0, y), pos2(bar.left(), y)],
+                (1.0, text_color),
+            ));
+            shapes.push(Shape::galley(pos, galley, text_color));
+        }
+    }
+}
+
+impl PlotItem for Heatmap {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let range = self.value_range_or_auto();
+        let frame = transform.frame();
+
+        let mut mesh = Mesh::default();
+        for (index, value) in self.cell_values().enumerate() {
+            if !value.is_finite() {
+                continue;
+            }
+            let rect = self.cell_rect(transform, index % self.columns, index / self.columns);
+            if rect.intersects(*frame) {
+                mesh.add_colored_rect(rect, self.value_color(value, &range));
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        if self.highlight {
+            let rect = transform.rect_from_values(&self.min, &self.max);
+            shapes.push(Shape::rect_stroke(
+                rect,
+                0.0,
+                Stroke::new(2.0, ui.visuals().text_color()),
+            ));
+        }
+
+        if self.show_colorbar {
+            self.add_colorbar(ui, transform, shapes);
+        }
     }
 
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
     fn name(&self) -> &str {
         self.name.as_str()
     }
 
     fn color(&self) -> Color32 {
-        self.default_color
+        self.gradient_color(0.5)
     }
 
     fn highlight(&mut self) {
@@ -1895,14 +3336,34 @@ impl PlotItem for BoxPlot {
 
     fn bounds(&self) -> PlotBounds {
         let mut bounds = PlotBounds::NOTHING;
-        for b in &self.boxes {
-            bounds.merge(&b.bounds());
+        if 0 < self.rows {
+            bounds.extend_with(&self.min);
+            bounds.extend_with(&self.max);
         }
         bounds
     }
 
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
-        find_closest_rect(&self.boxes, point, transform)
+        if self.rows == 0 {
+            return None;
+        }
+
+        // Find the cell under the point, or the closest one at the edge of the heatmap:
+        let value = transform.value_from_position(point);
+        let cell = |value: f64, min: f64, max: f64, count: usize| {
+            let cell = ((value - min) / (max - min) * count as f64).floor();
+            (cell.at_least(0.0) as usize).at_most(count - 1)
+        };
+        let column = cell(value.x, self.min.x, self.max.x, self.columns);
+        let row = cell(value.y, self.min.y, self.max.y, self.rows);
+
+        let index = row * self.columns + column;
+        self.values[index].is_finite().then(|| ClosestElem {
+            index,
+            dist_sq: self
+                .cell_rect(transform, column, row)
+                .distance_sq_to_pos(point),
+        })
     }
 
     fn on_hover(
@@ -1913,15 +3374,51 @@ impl PlotItem for BoxPlot {
         plot: &PlotConfig<'_>,
         _: &LabelFormatter<'_>,
     ) {
-        let box_plot = &self.boxes[elem.index];
+        let (column, row) = (elem.index % self.columns, elem.index / self.columns);
+        let value = self.values[elem.index];
+        let (min, max) = self.cell_bounds(column, row);
+        let center = PlotPoint::new((min.x + max.x) / 2.0, (min.y + max.y) / 2.0);
+
+        shapes.push(Shape::rect_stroke(
+            self.cell_rect(plot.transform, column, row),
+            0.0,
+            Stroke::new(2.0, plot.ui.visuals().text_color()),
+        ));
 
-        box_plot.add_shapes(plot.transform, true, shapes);
-        box_plot.add_rulers_and_text(self, plot, shapes, cursors);
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x: center.x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: center.y });
+        }
+
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&self.format_value(value, &self.value_range_or_auto()));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        let corner = PlotPoint::new(max.x.max(min.x), max.y.max(min.y));
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                plot.transform.position_from_point(&corner) + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn secondary_y(&self) -> bool {
+        self.secondary_y
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -2052,17 +3549,27 @@ pub(super) fn rulers_at_value(
 
     let text = {
         let scale = plot.transform.dvalue_dpos();
-        let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
-        let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
+        let log_axes = plot.transform.log_axes();
+        let decimals = |axis: usize, value: f64| {
+            let mut scale = scale[axis].abs();
+            if log_axes[axis] {
+                // How much the value changes per ui point:
+                scale *= value.abs() * std::f64::consts::LN_10;
+            }
+            ((-scale.log10()).ceil().at_least(0.0) as usize).clamp(1, 6)
+        };
+        let x_text = if plot.x_time_axis {
+            time_axis::format_time(value.x, scale[0].abs())
+        } else {
+            format!("{:.*}", decimals(0, value.x), value.x)
+        };
+        let y_decimals = decimals(1, value.y);
         if let Some(custom_label) = label_formatter {
             custom_label(name, &value)
         } else if plot.show_x && plot.show_y {
-            format!(
-                "{}x = {:.*}\ny = {:.*}",
-                prefix, x_decimals, value.x, y_decimals, value.y
-            )
+            format!("{prefix}x = {x_text}\ny = {:.*}", y_decimals, value.y)
         } else if plot.show_x {
-            format!("{}x = {:.*}", prefix, x_decimals, value.x)
+            format!("{prefix}x = {x_text}")
         } else if plot.show_y {
             format!("{}y = {:.*}", prefix, y_decimals, value.y)
         } else {
@@ -2102,3 +3609,104 @@ where
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[test]
+fn test_heatmap_colors() {
+    let heatmap = Heatmap::new(vec![0.0, 1.0, 2.0, f64::NAN, 3.0, 4.0, 5.0], 3)
+        .gradient([Color32::BLACK, Color32::WHITE]);
+    assert_eq!(heatmap.rows, 2);
+    assert_eq!(heatmap.value_range_or_auto(), 0.0..=4.0);
+
+    let range = heatmap.value_range_or_auto();
+    assert_eq!(heatmap.value_color(0.0, &range), Color32::BLACK);
+    assert_eq!(heatmap.value_color(4.0, &range), Color32::WHITE);
+    assert_eq!(heatmap.value_color(-1.0, &range), Color32::BLACK);
+    assert_eq!(heatmap.value_color(10.0, &range), Color32::WHITE);
+
+    // A reversed range is the same range:
+    let heatmap = heatmap.value_range(4.0..=0.0);
+    let range = heatmap.value_range_or_auto();
+    assert_eq!(range, 0.0..=4.0);
+    assert_eq!(heatmap.value_color(0.0, &range), Color32::BLACK);
+    assert_eq!(heatmap.value_color(4.0, &range), Color32::WHITE);
+
+    let (min, max) = heatmap.cell_bounds(2, 1);
+    assert_eq!((min.x, min.y, max.x, max.y), (2.0, 1.0, 3.0, 2.0));
+}
+
+#[test]
+fn test_error_bar_bounds() {
+    let points = Points::new(vec![[1.0, 10.0], [2.0, 100.0]])
+        .error_bars(ErrorBars::asymmetric([[5.0, 10.0], [200.0, 900.0]]));
+    let bounds = points.bounds();
+    assert_eq!(bounds.min(), [1.0, -100.0]);
+    assert_eq!(bounds.max(), [2.0, 1000.0]);
+
+    // On a logarithmic axis, the lower end of the second error bar can't be shown:
+    let bounds = points.plot_bounds(Vec2b::new(false, true));
+    assert_eq!(bounds.min(), [1.0, 5.0_f64.log10()]);
+    assert_eq!(bounds.max(), [2.0, 3.0]);
+
+    let chart = BarChart::new(vec![Bar::new(1.0, 10.0), Bar::new(2.0, 100.0)])
+        .error_bars(ErrorBars::symmetric([1.0, 900.0]));
+    let bounds = chart.plot_bounds(Vec2b::new(false, true));
+    assert_eq!(bounds.min()[1], 9.0_f64.log10());
+    assert_eq!(bounds.max()[1], 3.0);
+}
+
+#[test]
+fn test_fill_between_bounds() {
+    let fill = FillBetween::new(vec![[1.0, 10.0], [2.0, 20.0]], vec![[1.0, 0.1], [2.0, 1.0]]);
+    let bounds = fill.bounds();
+    assert_eq!(bounds.min(), [1.0, 0.1]);
+    assert_eq!(bounds.max(), [2.0, 20.0]);
+
+    let bounds = fill.plot_bounds(Vec2b::new(false, true));
+    assert_eq!(bounds.min(), [1.0, -1.0]);
+    assert_eq!(bounds.max(), [2.0, 20.0_f64.log10()]);
+
+    // A constant at zero can't be shown, so only the series counts:
+    let fill = FillBetween::constant(vec![[1.0, 10.0], [2.0, 100.0]], 0.0);
+    let bounds = fill.plot_bounds(Vec2b::new(false, true));
+    assert_eq!(bounds.min(), [1.0, 1.0]);
+    assert_eq!(bounds.max(), [2.0, 2.0]);
+}
+
+#[test]
+fn test_stacked_and_grouped_bars() {
+    let chart = |values: &[f64]| {
+        BarChart::new(
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, value)| Bar::new(i as f64, *value))
+                .collect(),
+        )
+    };
+
+    let charts = BarChart::stacked(vec![
+        chart(&[1.0, -1.0]),
+        chart(&[2.0, -2.0]),
+        chart(&[3.0, 3.0]),
+    ]);
+    let bases: Vec<Vec<Option<f64>>> = charts
+        .iter()
+        .map(|chart| chart.bars.iter().map(|bar| bar.base_offset).collect())
+        .collect();
+    // Positive values go on top of the positive ones, negative ones below the negative ones:
+    assert_eq!(
+        bases,
+        [
+            vec![None, None],
+            vec![Some(1.0), Some(-1.0)],
+            vec![Some(3.0), Some(0.0)]
+        ]
+    );
+
+    let charts = BarChart::grouped(vec![chart(&[1.0]), chart(&[2.0])]);
+    let bars: Vec<(f64, f64)> = charts
+        .iter()
+        .map(|chart| (chart.bars[0].argument, chart.bars[0].bar_width))
+        .collect();
+    assert_eq!(bars, [(-0.125, 0.25), (0.125, 0.25)]);
+}
@@ -11,6 +11,7 @@ mod items;
 mod legend;
 mod memory;
 mod plot_ui;
+mod time_axis;
 mod transform;
 
 use std::{cmp::Ordering, ops::RangeInclusive, sync::Arc};
@@ -23,13 +24,15 @@ use epaint::Hsva;
 pub use crate::{
     axis::{Axis, AxisHints, HPlacement, Placement, VPlacement},
     items::{
-        Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, ClosestElem, HLine, Line, LineStyle,
-        MarkerShape, Orientation, PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotPoint,
-        PlotPoints, Points, Polygon, Text, VLine,
+        Annotation, Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Candle, Candlestick,
+        ClosestElem, ErrorBars, FillBetween, HLine, Heatmap, Line, LineStyle, MarkerShape,
+        Orientation, PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotPoint, PlotPoints, Points,
+        Polygon, Text, VLine,
     },
     legend::{Corner, Legend},
     memory::PlotMemory,
     plot_ui::PlotUi,
+    time_axis::{time_axis_formatter, time_grid_spacer},
     transform::{PlotBounds, PlotTransform},
 };
 
@@ -123,6 +126,37 @@ pub struct PlotResponse<R> {
     ///
     /// This is `None` if either no item was hovered, or the hovered item didn't provide an id.
     pub hovered_plot_item: Option<Id>,
+
+    /// The selection the user finished this frame, if any, see [`Plot::box_selection`].
+    pub selection: Option<PlotSelection>,
+}
+
+/// A selection the user made in a plot by dragging out a box, see [`Plot::box_selection`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotSelection {
+    /// The selected range, in data coordinates.
+    ///
+    /// For a [`Plot::x_span_selection`] the y range is the visible range.
+    pub bounds: PlotBounds,
+
+    /// The points of the items inside the selection.
+    pub points: Vec<SelectedPoint>,
+}
+
+/// A point of an item inside a [`PlotSelection`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectedPoint {
+    /// The id of the item, if it has one.
+    pub item_id: Option<Id>,
+
+    /// The name of the item.
+    pub item_name: String,
+
+    /// The index of the point in the item.
+    pub index: usize,
+
+    /// The point, in data coordinates.
+    pub value: PlotPoint,
 }
 
 // ----------------------------------------------------------------------------
@@ -153,6 +187,8 @@ pub struct Plot<'a> {
     allow_scroll: Vec2b,
     allow_double_click_reset: bool,
     allow_boxed_zoom: bool,
+    box_selection: Option<Modifiers>,
+    x_span_selection: bool,
     default_auto_bounds: Vec2b,
     min_auto_bounds: PlotBounds,
     margin_fraction: Vec2,
@@ -165,6 +201,8 @@ pub struct Plot<'a> {
     height: Option<f32>,
     data_aspect: Option<f32>,
     view_aspect: Option<f32>,
+    log_axes: Vec2b,
+    x_time_axis: bool,
 
     reset: bool,
 
@@ -174,13 +212,15 @@ pub struct Plot<'a> {
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
     x_axes: Vec<AxisHints<'a>>, // default x axes
     y_axes: Vec<AxisHints<'a>>, // default y axes
+    secondary_y_axis: Option<AxisHints<'a>>,
     legend_config: Option<Legend>,
     show_background: bool,
     show_axes: Vec2b,
 
     show_grid: Vec2b,
     grid_spacing: Rangef,
-    grid_spacers: [GridSpacer<'a>; 2],
+    /// Set with [`Self::x_grid_spacer`] and [`Self::y_grid_spacer`], otherwise it depends on the axis.
+    grid_spacers: [Option<GridSpacer<'a>>; 2],
     sharp_grid_lines: bool,
     clamp_grid: bool,
 
@@ -200,6 +240,8 @@ impl<'a> Plot<'a> {
             allow_scroll: true.into(),
             allow_double_click_reset: true,
             allow_boxed_zoom: true,
+            box_selection: None,
+            x_span_selection: false,
             default_auto_bounds: true.into(),
             min_auto_bounds: PlotBounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
@@ -212,6 +254,8 @@ impl<'a> Plot<'a> {
             height: None,
             data_aspect: None,
             view_aspect: None,
+            log_axes: false.into(),
+            x_time_axis: false,
 
             reset: false,
 
@@ -221,13 +265,14 @@ impl<'a> Plot<'a> {
             coordinates_formatter: None,
             x_axes: vec![AxisHints::new(Axis::X)],
             y_axes: vec![AxisHints::new(Axis::Y)],
+            secondary_y_axis: None,
             legend_config: None,
             show_background: true,
             show_axes: true.into(),
 
             show_grid: true.into(),
             grid_spacing: Rangef::new(8.0, 300.0),
-            grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
+            grid_spacers: [None, None],
             sharp_grid_lines: true,
             clamp_grid: false,
 
@@ -264,6 +309,41 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Use a logarithmic scale on the x and/or y axis.
+    ///
+    /// Each power of ten then takes up the same space, with grid lines at 1, 2 and 5 times them
+    /// (see [`log_axis_grid_spacer`]), unless a grid spacer was set with [`Self::x_grid_spacer`] or [`Self::y_grid_spacer`].
+    /// Values that are zero or negative can't be shown on a logarithmic axis:
+    /// lines are broken at them, and bars, stems and fills reaching down to them extend past the edge of the plot.
+    ///
+    /// The plot bounds given to and by [`PlotUi`] are then in "plot space",
+    /// which for a logarithmic axis is the base-10 logarithm of the values.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn log_axes(mut self, log_axes: impl Into<Vec2b>) -> Self {
+        self.log_axes = log_axes.into();
+        self
+    }
+
+    /// Treat the x values as unix timestamps, i.e. seconds since 1970-01-01 00:00 UTC.
+    ///
+    /// The grid marks of the x-axis are then placed at calendar boundaries (see [`time_grid_spacer`],
+    /// unless a grid spacer was set with [`Self::x_grid_spacer`]),
+    /// with labels that adapt to the zoom level, from years down to seconds (see [`time_axis_formatter`]).
+    /// When hovering, the x value is shown as a date and time.
+    ///
+    /// All dates and times are in UTC.
+    /// This sets the formatter of the main X-axis, so call [`Self::custom_x_axes`] before this.
+    #[inline]
+    pub fn x_time_axis(mut self) -> Self {
+        self.x_time_axis = true;
+        if let Some(main) = self.x_axes.first_mut() {
+            main.formatter = Arc::new(time_axis_formatter);
+        }
+        self
+    }
+
     /// Width of plot. By default a plot will fill the ui it is in.
     /// If you set [`Self::view_aspect`], the width can be calculated from the height.
     #[inline]
@@ -372,6 +452,30 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Select instead of zoom when dragging out a box with the [`Self::boxed_zoom_pointer_button`]
+    /// while holding `modifiers`, e.g. [`Modifiers::SHIFT`].
+    ///
+    /// This doesn't change the view. Instead the selection is reported in
+    /// [`PlotResponse::selection`] when the button is released.
+    /// With [`Modifiers::NONE`] dragging out a box always selects.
+    ///
+    /// Default: no selection.
+    #[inline]
+    pub fn box_selection(mut self, modifiers: Modifiers) -> Self {
+        self.box_selection = Some(modifiers);
+        self
+    }
+
+    /// Select a range of x values spanning the full height of the plot,
+    /// instead of a box, see [`Self::box_selection`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn x_span_selection(mut self, on: bool) -> Self {
+        self.x_span_selection = on;
+        self
+    }
+
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
     #[inline]
     pub fn allow_drag<T>(mut self, on: T) -> Self
@@ -453,7 +557,7 @@ impl<'a> Plot<'a> {
     /// There are helpers for common cases, see [`log_grid_spacer`] and [`uniform_grid_spacer`].
     #[inline]
     pub fn x_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'a) -> Self {
-        self.grid_spacers[0] = Box::new(spacer);
+        self.grid_spacers[0] = Some(Box::new(spacer));
         self
     }
 
@@ -462,7 +566,7 @@ impl<'a> Plot<'a> {
     /// See [`Self::x_grid_spacer`] for explanation.
     #[inline]
     pub fn y_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'a) -> Self {
-        self.grid_spacers[1] = Box::new(spacer);
+        self.grid_spacers[1] = Some(Box::new(spacer));
         self
     }
 
@@ -717,6 +821,20 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Add a secondary Y-axis to the right of the plot, with its own range, ticks and label,
+    /// e.g. for showing values with different units in the same plot.
+    ///
+    /// Items are plotted against it with e.g. [`Line::secondary_y`].
+    /// Its range fits those items when the y-axis is automatically bounded,
+    /// and it follows the main Y-axis when panning and zooming.
+    ///
+    /// The secondary Y-axis is never logarithmic, and always uses a log-10 grid for its ticks.
+    #[inline]
+    pub fn secondary_y_axis(mut self, hints: AxisHints<'a>) -> Self {
+        self.secondary_y_axis = Some(hints.placement(HPlacement::Right));
+        self
+    }
+
     /// Interact with and add items to the plot and finally draw it.
     pub fn show<R>(
         self,
@@ -740,6 +858,8 @@ impl<'a> Plot<'a> {
             allow_scroll,
             allow_double_click_reset,
             allow_boxed_zoom,
+            box_selection,
+            x_span_selection,
             boxed_zoom_pointer_button,
             default_auto_bounds,
             min_auto_bounds,
@@ -749,12 +869,15 @@ impl<'a> Plot<'a> {
             mut min_size,
             data_aspect,
             view_aspect,
+            log_axes,
+            x_time_axis,
             mut show_x,
             mut show_y,
             label_formatter,
             coordinates_formatter,
             x_axes,
-            y_axes,
+            mut y_axes,
+            secondary_y_axis,
             legend_config,
             reset,
             show_background,
@@ -770,6 +893,12 @@ impl<'a> Plot<'a> {
             sense,
         } = self;
 
+        let [x_spacer, y_spacer] = grid_spacers;
+        let grid_spacers = [
+            x_spacer.unwrap_or_else(|| default_grid_spacer(Axis::X, log_axes, x_time_axis)),
+            y_spacer.unwrap_or_else(|| default_grid_spacer(Axis::Y, log_axes, x_time_axis)),
+        ];
+
         // Disable interaction if ui is disabled.
         let allow_zoom = allow_zoom.and(ui.is_enabled());
         let allow_drag = allow_drag.and(ui.is_enabled());
@@ -813,6 +942,12 @@ impl<'a> Plot<'a> {
 
         let plot_id = id.unwrap_or_else(|| ui.make_persistent_id(id_source));
 
+        // Put the secondary axis closest to the plot, on the right.
+        let has_secondary_y = secondary_y_axis.is_some();
+        if let Some(secondary_y_axis) = secondary_y_axis {
+            y_axes.insert(0, secondary_y_axis);
+        }
+
         let ([x_axis_widgets, y_axis_widgets], plot_rect) = axis_widgets(
             PlotMemory::load(ui.ctx(), plot_id).as_ref(), // TODO(emilk): avoid loading plot memory twice
             show_axes,
@@ -841,12 +976,21 @@ impl<'a> Plot<'a> {
             auto_bounds: default_auto_bounds,
             hovered_legend_item: None,
             hidden_items: Default::default(),
-            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y),
+            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y)
+                .with_log_axes(log_axes),
+            secondary_y: [1.0, 0.0],
             last_click_pos_for_zoom: None,
+            box_selecting: false,
             x_axis_thickness: Default::default(),
             y_axis_thickness: Default::default(),
         });
 
+        // The bounds are in another space when switching to or from logarithmic axes.
+        if mem.transform.log_axes() != log_axes {
+            mem.transform = mem.transform.with_log_axes(log_axes);
+            mem.auto_bounds = true.into();
+        }
+
         let last_plot_transform = mem.transform;
 
         // Call the plot build function.
@@ -968,13 +1112,17 @@ impl<'a> Plot<'a> {
                     mem.auto_bounds = new_auto_bounds;
                 }
                 BoundsModification::Zoom(zoom_factor, center) => {
-                    bounds.zoom(zoom_factor, center);
+                    bounds.zoom(
+                        zoom_factor,
+                        last_plot_transform.plot_point_from_value(&center),
+                    );
                     mem.auto_bounds = false.into();
                 }
             }
         }
 
         // Reset bounds to initial bounds if they haven't been modified.
+        let min_auto_bounds = transform::plot_bounds_from_values(&min_auto_bounds, log_axes);
         if mem.auto_bounds.x {
             bounds.set_x(&min_auto_bounds);
         }
@@ -987,8 +1135,8 @@ impl<'a> Plot<'a> {
 
         // Set bounds automatically based on content.
         if auto_x || auto_y {
-            for item in &items {
-                let item_bounds = item.bounds();
+            for item in items.iter().filter(|item| !item.secondary_y()) {
+                let item_bounds = item.plot_bounds(log_axes);
                 if auto_x {
                     bounds.merge_x(&item_bounds);
                 }
@@ -1006,7 +1154,8 @@ impl<'a> Plot<'a> {
             }
         }
 
-        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y);
+        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y)
+            .with_log_axes(log_axes);
 
         // Enforce aspect ratio
         if let Some(data_aspect) = data_aspect {
@@ -1024,6 +1173,21 @@ impl<'a> Plot<'a> {
             }
         }
 
+        // Fit the secondary y axis to its items.
+        if has_secondary_y && mem.auto_bounds.y {
+            let mut secondary_bounds = PlotBounds::NOTHING;
+            for item in items.iter().filter(|item| item.secondary_y()) {
+                secondary_bounds.merge_y(&item.bounds());
+            }
+            secondary_bounds.add_relative_margin_y(margin_fraction);
+            let bounds = mem.transform.bounds();
+            if secondary_bounds.is_valid_y() && bounds.is_valid_y() {
+                let scale = secondary_bounds.height() / bounds.height();
+                let offset = secondary_bounds.min[1] - scale * bounds.min[1];
+                mem.secondary_y = [scale, offset];
+            }
+        }
+
         // Dragging
         if allow_drag.any() && response.dragged_by(PointerButton::Primary) {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
@@ -1038,54 +1202,82 @@ impl<'a> Plot<'a> {
             mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
         }
 
-        // Zooming
-        let mut boxed_zoom_rect = None;
-        if allow_boxed_zoom {
+        // Zooming or selecting
+        let mut drag_box_shapes = None;
+        let mut selection_rect = None;
+        if allow_boxed_zoom || box_selection.is_some() {
             // Save last click to allow boxed zooming
             if response.drag_started() && response.dragged_by(boxed_zoom_pointer_button) {
-                // it would be best for egui that input has a memory of the last click pos because it's a common pattern
-                mem.last_click_pos_for_zoom = response.hover_pos();
+                mem.box_selecting = box_selection.map_or(false, |modifiers| {
+                    ui.input(|i| i.modifiers.matches_logically(modifiers))
+                });
+                if allow_boxed_zoom || mem.box_selecting {
+                    // it would be best for egui that input has a memory of the last click pos because it's a common pattern
+                    mem.last_click_pos_for_zoom = response.hover_pos();
+                }
             }
             let box_start_pos = mem.last_click_pos_for_zoom;
             let box_end_pos = response.hover_pos();
             if let (Some(box_start_pos), Some(box_end_pos)) = (box_start_pos, box_end_pos) {
+                let mut rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
+                if mem.box_selecting && x_span_selection {
+                    rect.set_top(plot_rect.top());
+                    rect.set_bottom(plot_rect.bottom());
+                }
                 // while dragging prepare a Shape and draw it later on top of the plot
                 if response.dragged_by(boxed_zoom_pointer_button) {
-                    response = response.on_hover_cursor(CursorIcon::ZoomIn);
-                    let rect = epaint::Rect::from_two_pos(box_start_pos, box_end_pos);
-                    boxed_zoom_rect = Some((
-                        epaint::RectShape::stroke(
-                            rect,
-                            0.0,
-                            epaint::Stroke::new(4., Color32::DARK_BLUE),
-                        ), // Outer stroke
-                        epaint::RectShape::stroke(
-                            rect,
-                            0.0,
-                            epaint::Stroke::new(2., Color32::WHITE),
-                        ), // Inner stroke
-                    ));
+                    if mem.box_selecting {
+                        response = response.on_hover_cursor(CursorIcon::Crosshair);
+                        let selection = ui.visuals().selection;
+                        drag_box_shapes = Some((
+                            epaint::RectShape::filled(
+                                rect,
+                                0.0,
+                                selection.bg_fill.gamma_multiply(0.3),
+                            ),
+                            epaint::RectShape::stroke(rect, 0.0, selection.stroke),
+                        ));
+                    } else {
+                        response = response.on_hover_cursor(CursorIcon::ZoomIn);
+                        drag_box_shapes = Some((
+                            epaint::RectShape::stroke(
+                                rect,
+                                0.0,
+                                epaint::Stroke::new(4., Color32::DARK_BLUE),
+                            ), // Outer stroke
+                            epaint::RectShape::stroke(
+                                rect,
+                                0.0,
+                                epaint::Stroke::new(2., Color32::WHITE),
+                            ), // Inner stroke
+                        ));
+                    }
                 }
-                // when the click is release perform the zoom
+                // when the click is release perform the zoom, or report the selection
                 if response.drag_stopped() {
-                    let box_start_pos = mem.transform.value_from_position(box_start_pos);
-                    let box_end_pos = mem.transform.value_from_position(box_end_pos);
-                    let new_bounds = PlotBounds {
-                        min: [
-                            box_start_pos.x.min(box_end_pos.x),
-                            box_start_pos.y.min(box_end_pos.y),
-                        ],
-                        max: [
-                            box_start_pos.x.max(box_end_pos.x),
-                            box_start_pos.y.max(box_end_pos.y),
-                        ],
-                    };
-                    if new_bounds.is_valid() {
-                        mem.transform.set_bounds(new_bounds);
-                        mem.auto_bounds = false.into();
+                    if mem.box_selecting {
+                        selection_rect = Some(rect);
+                    } else {
+                        let box_start_pos = mem.transform.plot_point_from_position(box_start_pos);
+                        let box_end_pos = mem.transform.plot_point_from_position(box_end_pos);
+                        let new_bounds = PlotBounds {
+                            min: [
+                                box_start_pos.x.min(box_end_pos.x),
+                                box_start_pos.y.min(box_end_pos.y),
+                            ],
+                            max: [
+                                box_start_pos.x.max(box_end_pos.x),
+                                box_start_pos.y.max(box_end_pos.y),
+                            ],
+                        };
+                        if new_bounds.is_valid() {
+                            mem.transform.set_bounds(new_bounds);
+                            mem.auto_bounds = false.into();
+                        }
                     }
                     // reset the boxed zoom state
                     mem.last_click_pos_for_zoom = None;
+                    mem.box_selecting = false;
                 }
             }
         }
@@ -1133,7 +1325,9 @@ impl<'a> Plot<'a> {
 
         // Add legend widgets to plot
         let bounds = mem.transform.bounds();
-        let x_axis_range = bounds.range_x();
+        let value_min = mem.transform.value_from_plot_point(&bounds.min.into());
+        let value_max = mem.transform.value_from_plot_point(&bounds.max.into());
+        let x_axis_range = value_min.x..=value_max.x;
         let x_steps = Arc::new({
             let input = GridInput {
                 bounds: (bounds.min[0], bounds.max[0]),
@@ -1141,7 +1335,7 @@ impl<'a> Plot<'a> {
             };
             (grid_spacers[0])(input)
         });
-        let y_axis_range = bounds.range_y();
+        let y_axis_range = value_min.y..=value_max.y;
         let y_steps = Arc::new({
             let input = GridInput {
                 bounds: (bounds.min[1], bounds.max[1]),
@@ -1156,19 +1350,37 @@ impl<'a> Plot<'a> {
             let (_response, thickness) = widget.ui(ui, Axis::X);
             mem.x_axis_thickness.insert(i, thickness);
         }
+        let secondary_transform = mem.transform.secondary_y(mem.secondary_y);
+        let num_y_axis_widgets = y_axis_widgets.len();
         for (i, mut widget) in y_axis_widgets.into_iter().enumerate() {
-            widget.range = y_axis_range.clone();
-            widget.transform = Some(mem.transform);
-            widget.steps = y_steps.clone();
+            // The widgets are in reverse order, so the secondary axis is the last one.
+            if has_secondary_y && i + 1 == num_y_axis_widgets {
+                let bounds = secondary_transform.bounds();
+                let input = GridInput {
+                    bounds: (bounds.min[1], bounds.max[1]),
+                    base_step_size: secondary_transform.dvalue_dpos()[1].abs()
+                        * grid_spacing.min as f64,
+                };
+                widget.range = bounds.range_y();
+                widget.transform = Some(secondary_transform);
+                widget.steps = Arc::new((log_grid_spacer(10))(input));
+            } else {
+                widget.range = y_axis_range.clone();
+                widget.transform = Some(mem.transform);
+                widget.steps = y_steps.clone();
+            }
             let (_response, thickness) = widget.ui(ui, Axis::Y);
             mem.y_axis_thickness.insert(i, thickness);
         }
 
         // Initialize values from functions.
         for item in &mut items {
-            item.initialize(mem.transform.bounds().range_x());
+            item.initialize(x_axis_range.clone());
         }
 
+        let selection = selection_rect
+            .map(|rect| select_points(&items, rect, &mem.transform, &secondary_transform));
+
         let prepared = PreparedPlot {
             items,
             show_x,
@@ -1178,6 +1390,8 @@ impl<'a> Plot<'a> {
             show_grid,
             grid_spacing,
             transform: mem.transform,
+            secondary_transform,
+            x_time_axis,
             draw_cursor_x: linked_cursors.as_ref().map_or(false, |group| group.1.x),
             draw_cursor_y: linked_cursors.as_ref().map_or(false, |group| group.1.y),
             draw_cursors,
@@ -1188,13 +1402,13 @@ impl<'a> Plot<'a> {
 
         let (plot_cursors, hovered_plot_item) = prepared.ui(ui, &response);
 
-        if let Some(boxed_zoom_rect) = boxed_zoom_rect {
+        if let Some(drag_box_shapes) = drag_box_shapes {
             ui.painter()
                 .with_clip_rect(plot_rect)
-                .add(boxed_zoom_rect.0);
+                .add(drag_box_shapes.0);
             ui.painter()
                 .with_clip_rect(plot_rect)
-                .add(boxed_zoom_rect.1);
+                .add(drag_box_shapes.1);
         }
 
         if let Some(mut legend) = legend {
@@ -1245,10 +1459,49 @@ impl<'a> Plot<'a> {
             response,
             transform,
             hovered_plot_item,
+            selection,
         }
     }
 }
 
+/// The selection of the points of the `items` inside `rect`, in screen coordinates.
+fn select_points(
+    items: &[Box<dyn PlotItem>],
+    rect: Rect,
+    transform: &PlotTransform,
+    secondary_transform: &PlotTransform,
+) -> PlotSelection {
+    let min = transform.value_from_position(rect.left_bottom());
+    let max = transform.value_from_position(rect.right_top());
+
+    let mut points = Vec::new();
+    for item in items {
+        let PlotGeometry::Points(item_points) = item.geometry() else {
+            continue;
+        };
+        let transform = if item.secondary_y() {
+            secondary_transform
+        } else {
+            transform
+        };
+        for (index, value) in item_points.iter().enumerate() {
+            if rect.contains(transform.position_from_point(value)) {
+                points.push(SelectedPoint {
+                    item_id: item.id(),
+                    item_name: item.name().to_owned(),
+                    index,
+                    value: *value,
+                });
+            }
+        }
+    }
+
+    PlotSelection {
+        bounds: PlotBounds::from_min_max([min.x, min.y], [max.x, max.y]),
+        points,
+    }
+}
+
 /// Returns the rect left after adding axes.
 fn axis_widgets<'a>(
     mem: Option<&PlotMemory>,
@@ -1378,6 +1631,9 @@ enum BoundsModification {
 pub struct GridInput {
     /// Min/max of the visible data range (the values at the two edges of the plot,
     /// for the current axis).
+    ///
+    /// For a logarithmic axis, these are the base-10 logarithms of the values (see [`Plot::log_axes`]),
+    /// and so is [`Self::base_step_size`].
     pub bounds: (f64, f64),
 
     /// Recommended (but not required) lower-bound on the step size returned by custom grid spacers.
@@ -1401,6 +1657,8 @@ pub struct GridMark {
     /// matches the difference between two `value`s precisely, but rather that grid marks of
     /// same thickness have same `step_size`. For example, months can have a different number
     /// of days, but consistently using a `step_size` of 30 days is a valid approximation.
+    ///
+    /// For a logarithmic axis, this is in powers of ten, while [`Self::value`] is the value itself.
     pub step_size: f64,
 }
 
@@ -1432,6 +1690,115 @@ pub fn log_grid_spacer(log_base: i64) -> GridSpacer<'static> {
     Box::new(step_sizes)
 }
 
+/// The grid of an axis without a custom grid spacer.
+fn default_grid_spacer(axis: Axis, log_axes: Vec2b, x_time_axis: bool) -> GridSpacer<'static> {
+    if log_axes[axis as usize] {
+        log_axis_grid_spacer()
+    } else if axis == Axis::X && x_time_axis {
+        time_grid_spacer()
+    } else {
+        log_grid_spacer(10)
+    }
+}
+
+/// The grid of a logarithmic axis, see [`Plot::log_axes`].
+///
+/// There are lines at every power of ten, and when there is room for them,
+/// also at 2 and 5 times them, and then at the other multiples.
+/// When zoomed out, only every tenth or hundredth power of ten gets a line.
+pub fn log_axis_grid_spacer() -> GridSpacer<'static> {
+    let step_sizes = |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+
+        // The bounds and step sizes are in powers of ten:
+        let (min, max) = input.bounds;
+        let decades = next_power(input.base_step_size.at_least(1.0), 10.0);
+        let mut marks = generate_marks([decades, decades * 10.0, decades * 100.0], input.bounds);
+        for mark in &mut marks {
+            mark.value = 10.0_f64.powi(mark.value.round() as i32);
+        }
+
+        if input.base_step_size < 1.0 {
+            for power in (min.floor() as i32)..=(max.floor() as i32) {
+                for multiple in 2..=9 {
+                    // 1, 2, 5 are about equally far apart, the others are closer:
+                    let step_size = if multiple == 2 || multiple == 5 {
+                        2.0_f64.log10()
+                    } else {
+                        (10.0_f64 / 9.0).log10()
+                    };
+                    let value = multiple as f64 * 10.0_f64.powi(power);
+                    if (min..=max).contains(&value.log10()) {
+                        marks.push(GridMark { value, step_size });
+                    }
+                }
+                sub_decade_marks(&mut marks, power, &input);
+            }
+        }
+
+        marks
+    };
+
+    Box::new(step_sizes)
+}
+
+/// The marks between the multiples of `10^power` on a logarithmic axis,
+/// at 1, 2 and 5 times smaller powers of ten, for when the plot is zoomed in to within a decade.
+fn sub_decade_marks(marks: &mut Vec<GridMark>, power: i32, input: &GridInput) {
+    let (min, max) = input.bounds;
+    let decade = 10.0_f64.powi(power);
+    let start = 10.0_f64.powf(min).max(decade);
+    let end = 10.0_f64.powf(max).min(10.0 * decade);
+    if end <= start {
+        return;
+    }
+
+    // The marks are the closest together at the end of the range,
+    // where a step of `x` in values is `x / (end * ln(10))` in plot space:
+    let min_step = input.base_step_size * end * std::f64::consts::LN_10;
+
+    // All values are `i * unit`, and the steps are 1, 2 or 5 times a power of ten:
+    let unit = next_power(min_step, 10.0) / 10.0;
+    let units_per_decade = (decade / unit).round() as i64;
+    let Some(fine) = [1, 2, 5, 10]
+        .into_iter()
+        .find(|&step| min_step <= step as f64 * unit)
+        .filter(|&step| step < units_per_decade)
+    else {
+        // There is only room for the multiples of the decade.
+        return;
+    };
+    let steps: Vec<i64> = (0..)
+        .flat_map(|power| [1, 2, 5].map(|multiple| multiple * 10_i64.pow(power)))
+        .take_while(|&step| step < units_per_decade)
+        .filter(|step| step % fine == 0)
+        .collect();
+
+    let first = (start / unit).ceil() as i64;
+    let last = (end / unit).floor() as i64;
+    for i in (first..=last).filter(|i| i % fine == 0 && i % units_per_decade != 0) {
+        // Use the largest step the value is on, so those lines are the strongest:
+        let step = steps
+            .iter()
+            .rev()
+            .find(|&&step| i % step == 0)
+            .unwrap_or(&fine);
+        // Dividing by a whole number avoids values like 1.4000000000000001:
+        let value = if unit < 1.0 {
+            i as f64 / (1.0 / unit).round()
+        } else {
+            i as f64 * unit
+        };
+        marks.push(GridMark {
+            value,
+            step_size: *step as f64 * unit / (value * std::f64::consts::LN_10),
+        });
+    }
+}
+
 /// Splits the grid into uniform-sized spacings (e.g. 100, 25, 1).
 ///
 /// This function should return 3 positive step sizes, designating where the lines in the grid are drawn.
@@ -1459,6 +1826,8 @@ struct PreparedPlot<'a> {
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
     // axis_formatters: [AxisFormatter; 2],
     transform: PlotTransform,
+    secondary_transform: PlotTransform,
+    x_time_axis: bool,
     show_grid: Vec2b,
     grid_spacing: Rangef,
     grid_spacers: [GridSpacer<'a>; 2],
@@ -1491,7 +1860,7 @@ impl<'a> PreparedPlot<'a> {
         let mut plot_ui = ui.child_ui(*transform.frame(), Layout::default(), None);
         plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
         for item in &self.items {
-            item.shapes(&plot_ui, transform, &mut shapes);
+            item.shapes(&plot_ui, self.item_transform(&**item), &mut shapes);
         }
 
         let hover_pos = response.hover_pos();
@@ -1569,7 +1938,10 @@ impl<'a> PreparedPlot<'a> {
 
         // Where on the cross-dimension to show the label values
         let bounds = transform.bounds();
-        let value_cross = 0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]);
+        let value_cross = transform::value_from_plot(
+            transform.log_axes()[1 - iaxis],
+            0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]),
+        );
 
         let input = GridInput {
             bounds: (bounds.min[iaxis], bounds.max[iaxis]),
@@ -1652,6 +2024,15 @@ impl<'a> PreparedPlot<'a> {
         }
     }
 
+    /// The transform to use for the item, which depends on its y axis.
+    fn item_transform(&self, item: &dyn PlotItem) -> &PlotTransform {
+        if item.secondary_y() {
+            &self.secondary_transform
+        } else {
+            &self.transform
+        }
+    }
+
     fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> (Vec<Cursor>, Option<Id>) {
         let Self {
             transform,
@@ -1659,6 +2040,7 @@ impl<'a> PreparedPlot<'a> {
             show_y,
             label_formatter,
             items,
+            x_time_axis,
             ..
         } = self;
 
@@ -1673,7 +2055,7 @@ impl<'a> PreparedPlot<'a> {
             .filter(|entry| entry.allow_hover())
             .filter_map(|item| {
                 let item = &**item;
-                let closest = item.find_closest(pointer, transform);
+                let closest = item.find_closest(pointer, self.item_transform(item));
 
                 Some(item).zip(closest)
             });
@@ -1687,12 +2069,27 @@ impl<'a> PreparedPlot<'a> {
             transform,
             show_x: *show_x,
             show_y: *show_y,
+            x_time_axis: *x_time_axis,
         };
 
         let mut cursors = Vec::new();
 
         let hovered_plot_item_id = if let Some((item, elem)) = closest {
-            item.on_hover(elem, shapes, &mut cursors, &plot, label_formatter);
+            let item_transform = self.item_transform(item);
+            let item_plot = items::PlotConfig {
+                transform: item_transform,
+                ..plot
+            };
+            item.on_hover(elem, shapes, &mut cursors, &item_plot, label_formatter);
+            if item.secondary_y() {
+                // The cursors are drawn on the main y axis:
+                for cursor in &mut cursors {
+                    if let Cursor::Horizontal { y } = cursor {
+                        let pos_y = item_transform.position_from_point_y(*y);
+                        *y = transform.value_from_position(pos2(0.0, pos_y)).y;
+                    }
+                }
+            }
             item.id()
         } else {
             let value = transform.value_from_position(pointer);
@@ -1787,3 +2184,37 @@ pub fn color_from_strength(ui: &Ui, strength: f32) -> Color32 {
     let base_color = ui.visuals().text_color();
     base_color.gamma_multiply(strength.sqrt())
 }
+
+#[test]
+fn test_log_axis_grid_spacer() {
+    let values = |bounds: (f64, f64), base_step_size: f64| -> Vec<f64> {
+        let mut values: Vec<f64> = (log_axis_grid_spacer())(GridInput {
+            bounds,
+            base_step_size,
+        })
+        .into_iter()
+        .map(|mark| mark.value)
+        .collect();
+        values.sort_by(|a, b| a.total_cmp(b));
+        values
+    };
+
+    // Zoomed out, there are only powers of ten:
+    assert_eq!(values((-0.5, 3.5), 1.0), [1.0, 10.0, 100.0, 1000.0]);
+
+    // With more room, the multiples of them too:
+    assert_eq!(
+        values((-0.01, 1.01), 0.05),
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0]
+    );
+
+    // And then between the multiples:
+    assert_eq!(values((0.0, 0.5), 0.03), [1.0, 1.5, 2.0, 2.5, 3.0]);
+    assert_eq!(values((0.0, 0.1), 0.02), [1.0, 1.1, 1.2]);
+
+    // Zoomed in to within a decade, between 3 and 4:
+    let marks = values((3.1_f64.log10(), 3.9_f64.log10()), 0.002);
+    assert!(10 <= marks.len(), "{marks:?}");
+    assert!(marks.iter().all(|value| (3.1..=3.9).contains(value)));
+    assert!(marks.iter().any(|value| (value - 3.5).abs() < 1e-9));
+}
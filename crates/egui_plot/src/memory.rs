@@ -23,9 +23,18 @@ pub struct PlotMemory {
     /// The transform from last frame.
     pub(crate) transform: PlotTransform,
 
+    /// The `[scale, offset]` from the y axis to the secondary y axis,
+    /// see [`PlotTransform::secondary_y`].
+    #[cfg_attr(feature = "serde", serde(default = "default_secondary_y"))]
+    pub(crate) secondary_y: [f64; 2],
+
     /// Allows to remember the first click position when performing a boxed zoom
     pub(crate) last_click_pos_for_zoom: Option<Pos2>,
 
+    /// Is the current box a selection rather than a zoom, see [`crate::Plot::box_selection`]?
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) box_selecting: bool,
+
     /// The thickness of each of the axes the previous frame.
     ///
     /// This is used in the next frame to make the axes thicker
@@ -34,6 +43,11 @@ pub struct PlotMemory {
     pub(crate) y_axis_thickness: BTreeMap<usize, f32>,
 }
 
+#[cfg(feature = "serde")]
+fn default_secondary_y() -> [f64; 2] {
+    [1.0, 0.0]
+}
+
 impl PlotMemory {
     #[inline]
     pub fn transform(&self) -> PlotTransform {
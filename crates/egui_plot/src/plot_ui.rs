@@ -28,11 +28,15 @@ impl PlotUi {
     /// The plot bounds as they were in the last frame. If called on the first frame and the bounds were not
     /// further specified in the plot builder, this will return bounds centered on the origin. The bounds do
     /// not change until the plot is drawn.
+    ///
+    /// For a logarithmic axis, these are the base-10 logarithms of the values, see [`Plot::log_axes`].
     pub fn plot_bounds(&self) -> PlotBounds {
         *self.last_plot_transform.bounds()
     }
 
     /// Set the plot bounds. Can be useful for implementing alternative plot navigation methods.
+    ///
+    /// Like [`Self::plot_bounds`], these are in plot space.
     pub fn set_plot_bounds(&mut self, plot_bounds: PlotBounds) {
         self.bounds_modifications
             .push(BoundsModification::Set(plot_bounds));
@@ -95,6 +99,8 @@ impl PlotUi {
     }
 
     /// The pointer drag delta in plot coordinates.
+    ///
+    /// Like [`Self::plot_bounds`], this is in plot space.
     pub fn pointer_coordinate_drag_delta(&self) -> Vec2 {
         let delta = self.response.drag_delta();
         let dp_dv = self.last_plot_transform.dpos_dvalue();
@@ -147,6 +153,19 @@ impl PlotUi {
         self.items.push(Box::new(polygon));
     }
 
+    /// Fill the area between two series, or a series and a constant.
+    pub fn fill_between(&mut self, mut fill_between: FillBetween) {
+        if fill_between.series.is_empty() {
+            return;
+        };
+
+        // Give the stroke an automatic color if no color has been assigned.
+        if fill_between.stroke.color == Color32::TRANSPARENT {
+            fill_between.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(fill_between));
+    }
+
     /// Add a text.
     pub fn text(&mut self, text: Text) {
         if text.text.is_empty() {
@@ -156,6 +175,15 @@ impl PlotUi {
         self.items.push(Box::new(text));
     }
 
+    /// Add an annotation.
+    pub fn annotation(&mut self, annotation: Annotation) {
+        if annotation.text.is_empty() {
+            return;
+        };
+
+        self.items.push(Box::new(annotation));
+    }
+
     /// Add data points.
     pub fn points(&mut self, mut points: Points) {
         if points.series.is_empty() {
@@ -232,4 +260,20 @@ impl PlotUi {
         }
         self.items.push(Box::new(chart));
     }
+
+    /// Add a candlestick chart.
+    pub fn candlestick(&mut self, candlestick: Candlestick) {
+        if candlestick.candles.is_empty() {
+            return;
+        }
+        self.items.push(Box::new(candlestick));
+    }
+
+    /// Add a heatmap.
+    pub fn heatmap(&mut self, heatmap: Heatmap) {
+        if heatmap.rows == 0 {
+            return;
+        }
+        self.items.push(Box::new(heatmap));
+    }
 }
@@ -0,0 +1,337 @@
+//! Placing and formatting grid marks on an axis of unix timestamps, see [`crate::Plot::x_time_axis`].
+
+use std::ops::RangeInclusive;
+
+use crate::{GridInput, GridMark, GridSpacer};
+
+const MINUTE: f64 = 60.0;
+const HOUR: f64 = 60.0 * MINUTE;
+const DAY: f64 = 24.0 * HOUR;
+
+/// The average length of a month, for the step size of grid marks.
+const MONTH: f64 = 30.436_875 * DAY;
+
+/// The average length of a year, for the step size of grid marks.
+const YEAR: f64 = 365.2425 * DAY;
+
+/// About 30 million years.
+const MAX_TIMESTAMP: f64 = 1e15;
+
+/// The distance between grid marks on a time axis.
+#[derive(Clone, Copy)]
+enum TimeStep {
+    Seconds(f64),
+    Months(i64),
+    Years(i64),
+}
+
+/// From thinnest to thickest.
+const TIME_STEPS: [TimeStep; 38] = [
+    TimeStep::Seconds(0.001),
+    TimeStep::Seconds(0.002),
+    TimeStep::Seconds(0.005),
+    TimeStep::Seconds(0.01),
+    TimeStep::Seconds(0.02),
+    TimeStep::Seconds(0.05),
+    TimeStep::Seconds(0.1),
+    TimeStep::Seconds(0.2),
+    TimeStep::Seconds(0.5),
+    TimeStep::Seconds(1.0),
+    TimeStep::Seconds(2.0),
+    TimeStep::Seconds(5.0),
+    TimeStep::Seconds(10.0),
+    TimeStep::Seconds(15.0),
+    TimeStep::Seconds(30.0),
+    TimeStep::Seconds(MINUTE),
+    TimeStep::Seconds(2.0 * MINUTE),
+    TimeStep::Seconds(5.0 * MINUTE),
+    TimeStep::Seconds(10.0 * MINUTE),
+    TimeStep::Seconds(15.0 * MINUTE),
+    TimeStep::Seconds(30.0 * MINUTE),
+    TimeStep::Seconds(HOUR),
+    TimeStep::Seconds(2.0 * HOUR),
+    TimeStep::Seconds(3.0 * HOUR),
+    TimeStep::Seconds(6.0 * HOUR),
+    TimeStep::Seconds(12.0 * HOUR),
+    TimeStep::Seconds(DAY),
+    TimeStep::Seconds(2.0 * DAY),
+    TimeStep::Months(1),
+    TimeStep::Months(3),
+    TimeStep::Months(6),
+    TimeStep::Years(1),
+    TimeStep::Years(2),
+    TimeStep::Years(5),
+    TimeStep::Years(10),
+    TimeStep::Years(20),
+    TimeStep::Years(50),
+    TimeStep::Years(100),
+];
+
+impl TimeStep {
+    /// The (approximate) length of the step.
+    fn seconds(self) -> f64 {
+        match self {
+            Self::Seconds(seconds) => seconds,
+            Self::Months(months) => months as f64 * MONTH,
+            Self::Years(years) => years as f64 * YEAR,
+        }
+    }
+
+    /// Add the marks in `min..=max` that are a multiple of this step.
+    fn fill_marks(self, out: &mut Vec<GridMark>, (min, max): (f64, f64)) {
+        let step_size = self.seconds();
+        let mut push = |value: f64| {
+            if (min..=max).contains(&value) {
+                out.push(GridMark { value, step_size });
+            }
+        };
+        match self {
+            Self::Seconds(seconds) => {
+                let first = (min / seconds).ceil() as i64;
+                let last = (max / seconds).floor() as i64;
+                for i in first..=last {
+                    push(i as f64 * seconds);
+                }
+            }
+            Self::Months(months) => {
+                let first = DateTime::from_timestamp(min);
+                let last = DateTime::from_timestamp(max);
+                let first_month = first.year * 12 + first.month as i64 - 1;
+                let last_month = last.year * 12 + last.month as i64 - 1;
+                for month in first_month..=last_month {
+                    if month.rem_euclid(months) == 0 {
+                        let (year, month) = (month.div_euclid(12), month.rem_euclid(12) + 1);
+                        push(days_from_civil(year, month as u32, 1) as f64 * DAY);
+                    }
+                }
+            }
+            Self::Years(years) => {
+                let first = DateTime::from_timestamp(min).year.div_euclid(years);
+                let last = DateTime::from_timestamp(max).year.div_euclid(years);
+                for i in first..=last {
+                    push(days_from_civil(i * years, 1, 1) as f64 * DAY);
+                }
+            }
+        }
+    }
+}
+
+/// A UTC date and time.
+struct DateTime {
+    year: i64,
+
+    /// 1-12
+    month: u32,
+
+    /// 1-31
+    day: u32,
+
+    hour: u32,
+    minute: u32,
+    second: u32,
+    millisecond: u32,
+}
+
+impl DateTime {
+    /// From seconds since 1970-01-01 00:00 UTC, rounded to the millisecond.
+    fn from_timestamp(seconds: f64) -> Self {
+        let milliseconds = (seconds * 1000.0).round() as i64;
+        let days = milliseconds.div_euclid(1000 * 86_400);
+        let milliseconds = milliseconds.rem_euclid(1000 * 86_400);
+        let (year, month, day) = civil_from_days(days);
+        Self {
+            year,
+            month,
+            day,
+            hour: (milliseconds / (1000 * 3600)) as u32,
+            minute: (milliseconds / (1000 * 60) % 60) as u32,
+            second: (milliseconds / 1000 % 60) as u32,
+            millisecond: (milliseconds % 1000) as u32,
+        }
+    }
+
+    fn date(&self) -> String {
+        format!("{}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// The time of day with the given number of decimals for the seconds,
+    /// or without seconds if `decimals` is `None`.
+    fn time(&self, decimals: Option<usize>) -> String {
+        let Self {
+            hour,
+            minute,
+            second,
+            millisecond,
+            ..
+        } = self;
+        match decimals {
+            None => format!("{hour:02}:{minute:02}"),
+            Some(0) => format!("{hour:02}:{minute:02}:{second:02}"),
+            Some(decimals) => {
+                let fraction = format!("{millisecond:03}");
+                let fraction = &fraction[..decimals.min(3)];
+                format!("{hour:02}:{minute:02}:{second:02}.{fraction}")
+            }
+        }
+    }
+
+    fn is_midnight(&self) -> bool {
+        self.hour == 0 && self.minute == 0 && self.second == 0 && self.millisecond == 0
+    }
+}
+
+/// The `(year, month, day)` of a number of days since 1970-01-01.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html>.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097); // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32; // [1, 31]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32; // [1, 12]
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// The number of days since 1970-01-01 of a date.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html>.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400); // [0, 399]
+    let mp = (month as i64 + 9) % 12; // [0, 11]
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    era * 146_097 + doe - 719_468
+}
+
+/// The number of decimals needed to show the seconds with this precision.
+fn second_decimals(precision: f64) -> usize {
+    (-precision.log10()).ceil().clamp(0.0, 3.0) as usize
+}
+
+/// Grid marks for an axis of unix timestamps, i.e. seconds since 1970-01-01 00:00 UTC.
+///
+/// The marks are at calendar boundaries: whole seconds, minutes, hours, days, months and years.
+///
+/// Use together with [`time_axis_formatter`], or just use [`crate::Plot::x_time_axis`].
+pub fn time_grid_spacer() -> GridSpacer<'static> {
+    let step_sizes = |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON || !input.base_step_size.is_finite() {
+            return Vec::new();
+        }
+
+        let first = TIME_STEPS
+            .iter()
+            .position(|step| input.base_step_size <= step.seconds());
+        let steps: Vec<TimeStep> = if let Some(first) = first {
+            TIME_STEPS[first..].iter().copied().take(3).collect()
+        } else {
+            // Powers of ten of years:
+            let years = 10.0_f64.powf((input.base_step_size / YEAR).log10().ceil());
+            let years = years.min(1e15) as i64;
+            vec![
+                TimeStep::Years(years),
+                TimeStep::Years(years.saturating_mul(10)),
+                TimeStep::Years(years.saturating_mul(100)),
+            ]
+        };
+
+        // Dates further away than this can't be represented:
+        let (min, max) = input.bounds;
+        let bounds = (min.max(-MAX_TIMESTAMP), max.min(MAX_TIMESTAMP));
+
+        let mut marks = vec![];
+        for step in steps {
+            step.fill_marks(&mut marks, bounds);
+        }
+
+        // Keep the largest step size of marks at the same time:
+        marks.sort_by(|a, b| {
+            a.value
+                .total_cmp(&b.value)
+                .then(b.step_size.total_cmp(&a.step_size))
+        });
+        marks.dedup_by(|a, b| a.value == b.value);
+        marks
+    };
+
+    Box::new(step_sizes)
+}
+
+/// Format the grid marks of an axis of unix timestamps, see [`time_grid_spacer`].
+///
+/// The labels adapt to the distance between the marks,
+/// e.g. `2024` for years, `2024-03` for months, `2024-03-15` for days and `12:30` for minutes.
+/// Marks at midnight show the date instead of the time.
+///
+/// Can be used with [`crate::AxisHints::formatter`] and [`crate::Plot::x_axis_formatter`].
+pub fn time_axis_formatter(
+    mark: GridMark,
+    _max_digits: usize,
+    _range: &RangeInclusive<f64>,
+) -> String {
+    let time = DateTime::from_timestamp(mark.value);
+    let step_size = mark.step_size;
+    if YEAR * 0.9 <= step_size {
+        time.year.to_string()
+    } else if MONTH * 0.9 <= step_size {
+        format!("{}-{:02}", time.year, time.month)
+    } else if DAY <= step_size || time.is_midnight() {
+        time.date()
+    } else if MINUTE <= step_size {
+        time.time(None)
+    } else {
+        time.time(Some(second_decimals(step_size)))
+    }
+}
+
+/// Format a unix timestamp as a date and time, with the given precision in seconds.
+pub(crate) fn format_time(seconds: f64, precision: f64) -> String {
+    let time = DateTime::from_timestamp(seconds);
+    if DAY <= precision {
+        time.date()
+    } else if MINUTE <= precision {
+        format!("{} {}", time.date(), time.time(None))
+    } else {
+        let decimals = second_decimals(precision);
+        format!("{} {}", time.date(), time.time(Some(decimals)))
+    }
+}
+
+#[test]
+fn test_time_axis() {
+    // 2024-02-29 12:34:56.789 UTC:
+    let timestamp = 1_709_210_096.789;
+    assert_eq!(format_time(timestamp, 0.001), "2024-02-29 12:34:56.789");
+    assert_eq!(format_time(timestamp, 1.0), "2024-02-29 12:34:56");
+    assert_eq!(format_time(timestamp, 60.0), "2024-02-29 12:34");
+    assert_eq!(format_time(-DAY, DAY), "1969-12-31");
+
+    for days in [-800_000, -1, 0, 59, 19_782, 1_000_000] {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), days);
+    }
+
+    // Months are at the first day of the month:
+    let marks = (time_grid_spacer())(GridInput {
+        bounds: (
+            days_from_civil(2024, 1, 15) as f64 * DAY,
+            days_from_civil(2024, 6, 15) as f64 * DAY,
+        ),
+        base_step_size: 20.0 * DAY,
+    });
+    let labels: Vec<String> = marks
+        .into_iter()
+        .map(|mark| time_axis_formatter(mark, 5, &(0.0..=0.0)))
+        .collect();
+    assert_eq!(
+        labels,
+        ["2024-02", "2024-03", "2024-04", "2024-05", "2024-06"]
+    );
+}
@@ -235,6 +235,9 @@ impl PlotBounds {
 }
 
 /// Contains the screen rectangle and the plot bounds and provides methods to transform between them.
+///
+/// The bounds are in "plot space", which is the same as the values of the plot,
+/// except for logarithmic axes (see [`Plot::log_axes`]) where it is their base-10 logarithm.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug)]
 pub struct PlotTransform {
@@ -249,6 +252,77 @@ pub struct PlotTransform {
 
     /// Whether to always center the y-range of the bounds.
     y_centered: bool,
+
+    /// Which axes are logarithmic.
+    #[cfg_attr(feature = "serde", serde(default))]
+    log_axes: Vec2b,
+}
+
+/// Plot space from a value on an axis.
+#[inline]
+pub(crate) fn plot_from_value(log: bool, value: f64) -> f64 {
+    if log {
+        value.log10()
+    } else {
+        value
+    }
+}
+
+/// A value on an axis from plot space.
+#[inline]
+pub(crate) fn value_from_plot(log: bool, plot: f64) -> f64 {
+    if log {
+        10.0_f64.powf(plot)
+    } else {
+        plot
+    }
+}
+
+/// Plot-space bounds of points, see [`PlotTransform::bounds`].
+///
+/// Non-positive values can't be shown on a logarithmic axis, so they are left out.
+pub(crate) fn plot_bounds_from_points(
+    points: impl IntoIterator<Item = PlotPoint>,
+    log_axes: Vec2b,
+) -> PlotBounds {
+    let mut bounds = PlotBounds::NOTHING;
+    for point in points {
+        for axis in 0..2 {
+            let value = [point.x, point.y][axis];
+            if !log_axes[axis] {
+                bounds.min[axis] = bounds.min[axis].min(value);
+                bounds.max[axis] = bounds.max[axis].max(value);
+            } else if 0.0 < value {
+                bounds.min[axis] = bounds.min[axis].min(value.log10());
+                bounds.max[axis] = bounds.max[axis].max(value.log10());
+            }
+        }
+    }
+    bounds
+}
+
+/// Plot-space bounds from bounds of values, see [`PlotTransform::bounds`].
+///
+/// Non-positive values can't be shown on a logarithmic axis, so they are left out.
+pub(crate) fn plot_bounds_from_values(values: &PlotBounds, log_axes: Vec2b) -> PlotBounds {
+    let mut bounds = *values;
+    for axis in 0..2 {
+        if !log_axes[axis] {
+            continue;
+        }
+        if 0.0 < values.max[axis] {
+            bounds.max[axis] = values.max[axis].log10();
+            bounds.min[axis] = if 0.0 < values.min[axis] {
+                values.min[axis].log10()
+            } else {
+                bounds.max[axis]
+            };
+        } else {
+            bounds.min[axis] = PlotBounds::NOTHING.min[axis];
+            bounds.max[axis] = PlotBounds::NOTHING.max[axis];
+        }
+    }
+    bounds
 }
 
 impl PlotTransform {
@@ -301,9 +375,33 @@ impl PlotTransform {
             bounds: new_bounds,
             x_centered,
             y_centered,
+            log_axes: Vec2b::FALSE,
         }
     }
 
+    #[inline]
+    pub(crate) fn with_log_axes(mut self, log_axes: Vec2b) -> Self {
+        self.log_axes = log_axes;
+        self
+    }
+
+    /// The transform of the items on the secondary y axis, see [`Plot::secondary_y_axis`].
+    ///
+    /// Its y values are `scale * y + offset` of the y values in the plot space of `self`.
+    pub(crate) fn secondary_y(&self, [scale, offset]: [f64; 2]) -> Self {
+        let mut secondary = *self;
+        secondary.bounds.min[1] = scale * self.bounds.min[1] + offset;
+        secondary.bounds.max[1] = scale * self.bounds.max[1] + offset;
+        secondary.log_axes.y = false;
+        secondary
+    }
+
+    /// Which axes are logarithmic, see [`Plot::log_axes`].
+    #[inline]
+    pub fn log_axes(&self) -> Vec2b {
+        self.log_axes
+    }
+
     /// ui-space rectangle.
     #[inline]
     pub fn frame(&self) -> &Rect {
@@ -311,6 +409,8 @@ impl PlotTransform {
     }
 
     /// Plot-space bounds.
+    ///
+    /// For logarithmic axes, these are the base-10 logarithms of the values at the edges.
     #[inline]
     pub fn bounds(&self) -> &PlotBounds {
         &self.bounds
@@ -335,7 +435,7 @@ impl PlotTransform {
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
-        let center = self.value_from_position(center);
+        let center = self.plot_point_from_position(center);
 
         let mut new_bounds = self.bounds;
         new_bounds.zoom(zoom_factor, center);
@@ -347,7 +447,7 @@ impl PlotTransform {
 
     pub fn position_from_point_x(&self, value: f64) -> f32 {
         remap(
-            value,
+            plot_from_value(self.log_axes.x, value),
             self.bounds.min[0]..=self.bounds.max[0],
             (self.frame.left() as f64)..=(self.frame.right() as f64),
         ) as f32
@@ -355,7 +455,7 @@ impl PlotTransform {
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
         remap(
-            value,
+            plot_from_value(self.log_axes.y, value),
             self.bounds.min[1]..=self.bounds.max[1],
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
         ) as f32
@@ -369,8 +469,49 @@ impl PlotTransform {
         )
     }
 
+    /// Whether the point can be shown, i.e. it isn't zero or negative on a logarithmic axis.
+    pub fn can_show(&self, value: &PlotPoint) -> bool {
+        (!self.log_axes.x || 0.0 < value.x) && (!self.log_axes.y || 0.0 < value.y)
+    }
+
+    /// Like [`Self::position_from_point`], but values that can't be shown on a logarithmic axis
+    /// are put just outside the left or bottom edge of the frame.
+    ///
+    /// Used for what extends towards zero, like bars, stems and fills.
+    pub fn position_from_point_clamped(&self, value: &PlotPoint) -> Pos2 {
+        let mut pos = self.position_from_point(value);
+        if self.log_axes.x && value.x <= 0.0 {
+            pos.x = self.frame.left() - 1.0;
+        }
+        if self.log_axes.y && value.y <= 0.0 {
+            pos.y = self.frame.bottom() + 1.0;
+        }
+        pos
+    }
+
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
+        self.value_from_plot_point(&self.plot_point_from_position(pos))
+    }
+
+    /// Plot space from a plot point, see [`Self::bounds`].
+    pub fn plot_point_from_value(&self, value: &PlotPoint) -> PlotPoint {
+        PlotPoint::new(
+            plot_from_value(self.log_axes.x, value.x),
+            plot_from_value(self.log_axes.y, value.y),
+        )
+    }
+
+    /// Plot point from plot space, see [`Self::bounds`].
+    pub fn value_from_plot_point(&self, plot: &PlotPoint) -> PlotPoint {
+        PlotPoint::new(
+            value_from_plot(self.log_axes.x, plot.x),
+            value_from_plot(self.log_axes.y, plot.y),
+        )
+    }
+
+    /// Plot space from screen/ui position, see [`Self::bounds`].
+    pub(crate) fn plot_point_from_position(&self, pos: Pos2) -> PlotPoint {
         let x = remap(
             pos.x as f64,
             (self.frame.left() as f64)..=(self.frame.right() as f64),
@@ -388,9 +529,12 @@ impl PlotTransform {
     ///
     /// This typically means that the rect is mirrored vertically (top becomes bottom and vice versa),
     /// since the plot's coordinate system has +Y up, while egui has +Y down.
+    ///
+    /// On a logarithmic axis, a rectangle that reaches zero extends beyond the frame,
+    /// see [`Self::position_from_point_clamped`].
     pub fn rect_from_values(&self, value1: &PlotPoint, value2: &PlotPoint) -> Rect {
-        let pos1 = self.position_from_point(value1);
-        let pos2 = self.position_from_point(value2);
+        let pos1 = self.position_from_point_clamped(value1);
+        let pos2 = self.position_from_point_clamped(value2);
 
         let mut rect = Rect::NOTHING;
         rect.extend_with(pos1);
@@ -470,3 +614,24 @@ impl PlotTransform {
         }
     }
 }
+
+#[test]
+fn test_plot_bounds_from_values() {
+    let values = PlotBounds::from_min_max([-1.0, 0.1], [100.0, 1000.0]);
+
+    let bounds = plot_bounds_from_values(&values, Vec2b::FALSE);
+    assert_eq!(bounds, values);
+
+    let bounds = plot_bounds_from_values(&values, Vec2b::new(false, true));
+    assert_eq!(bounds.min(), [-1.0, -1.0]);
+    assert_eq!(bounds.max(), [100.0, 3.0]);
+
+    // What is zero or negative is left out:
+    let bounds = plot_bounds_from_values(&values, Vec2b::new(true, false));
+    assert_eq!(bounds.min(), [2.0, 0.1]);
+    assert_eq!(bounds.max(), [2.0, 1000.0]);
+
+    let values = PlotBounds::from_min_max([-2.0, 0.0], [-1.0, 0.0]);
+    let bounds = plot_bounds_from_values(&values, Vec2b::TRUE);
+    assert!(!bounds.is_valid());
+}
@@ -0,0 +1,277 @@
+//! Exact formatting and parsing of fixed-point numbers, i.e. an `i128` mantissa scaled by `10^-decimals`.
+
+/// `10^exponent`, or `None` on overflow.
+#[inline]
+pub fn pow10_i128(exponent: u32) -> Option<i128> {
+    10_i128.checked_pow(exponent)
+}
+
+/// Format the fixed-point number `mantissa * 10^-decimals` exactly.
+///
+/// ```
+/// assert_eq!(emath::fixed_point::format_fixed_point(-12345, 2), "-123.45");
+/// assert_eq!(emath::fixed_point::format_fixed_point(5, 3), "0.005");
+/// assert_eq!(emath::fixed_point::format_fixed_point(i128::MAX, 0), i128::MAX.to_string());
+/// ```
+pub fn format_fixed_point(mantissa: i128, decimals: u32) -> String {
+    let digits = mantissa.unsigned_abs().to_string();
+    let sign = if mantissa < 0 { "-" } else { "" };
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        format!("{sign}{digits}")
+    } else if digits.len() <= decimals {
+        format!("{sign}0.{digits:0>decimals$}")
+    } else {
+        let (int, frac) = digits.split_at(digits.len() - decimals);
+        format!("{sign}{int}.{frac}")
+    }
+}
+
+/// Parse a decimal number (like `-123.45`) exactly into a mantissa with the given number of decimals.
+///
+/// Extra decimals are rounded away (half away from zero).
+/// Returns `None` if the text is not a plain decimal number, or if it doesn't fit.
+///
+/// ```
+/// assert_eq!(emath::fixed_point::parse_fixed_point("-123.45", 2), Some(-12345));
+/// assert_eq!(emath::fixed_point::parse_fixed_point("1.005", 2), Some(101));
+/// assert_eq!(emath::fixed_point::parse_fixed_point("1e3", 2), None);
+/// assert_eq!(emath::fixed_point::parse_fixed_point(&i128::MIN.to_string(), 0), Some(i128::MIN));
+/// ```
+pub fn parse_fixed_point(text: &str, decimals: u32) -> Option<i128> {
+    let text = text.trim();
+    let (negative, text) = if let Some(rest) = text.strip_prefix(['-', '−']) {
+        (true, rest)
+    } else {
+        (false, text.strip_prefix('+').unwrap_or(text))
+    };
+
+    let (int, frac) = text.split_once('.').unwrap_or((text, ""));
+    let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (int.is_empty() && frac.is_empty()) || !is_digits(int) || !is_digits(frac) {
+        return None;
+    }
+
+    // Accumulate with the sign, so that we can parse `i128::MIN`:
+    let sign: i128 = if negative { -1 } else { 1 };
+    let mut mantissa: i128 = 0;
+    for c in int.bytes() {
+        let digit = (c - b'0') as i128;
+        mantissa = mantissa.checked_mul(10)?.checked_add(sign * digit)?;
+    }
+    let mut frac = frac.bytes();
+    for _ in 0..decimals {
+        let digit = frac.next().map_or(0, |c| c - b'0') as i128;
+        mantissa = mantissa.checked_mul(10)?.checked_add(sign * digit)?;
+    }
+    if frac.next().map_or(false, |c| b'5' <= c) {
+        mantissa = mantissa.checked_add(sign)?;
+    }
+
+    Some(mantissa)
+}
+
+// ----------------------------------------------------------------------------
+
+/// An exact decimal number with a fixed number of decimals, e.g. `Decimal<2>` for money.
+///
+/// Stored as an `i128` mantissa, so it can be edited with a `DragValue` or `Slider`
+/// without any rounding errors from going through `f64`.
+///
+/// ```
+/// use emath::Decimal;
+/// let price: Decimal<2> = "19.99".parse().unwrap();
+/// assert_eq!(price.mantissa(), 1999);
+/// assert_eq!(price.to_string(), "19.99");
+/// ```
+#[cfg(feature = "decimal")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Decimal<const DECIMALS: u32> {
+    mantissa: i128,
+}
+
+#[cfg(feature = "decimal")]
+impl<const DECIMALS: u32> Decimal<DECIMALS> {
+    pub const ZERO: Self = Self { mantissa: 0 };
+    pub const MIN: Self = Self {
+        mantissa: i128::MIN,
+    };
+    pub const MAX: Self = Self {
+        mantissa: i128::MAX,
+    };
+
+    /// The number `mantissa * 10^-DECIMALS`.
+    #[inline]
+    pub const fn from_mantissa(mantissa: i128) -> Self {
+        Self { mantissa }
+    }
+
+    /// The value in units of `10^-DECIMALS`.
+    #[inline]
+    pub const fn mantissa(self) -> i128 {
+        self.mantissa
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl<const DECIMALS: u32> std::fmt::Display for Decimal<DECIMALS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format_fixed_point(self.mantissa, DECIMALS))
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl<const DECIMALS: u32> std::str::FromStr for Decimal<DECIMALS> {
+    type Err = ParseDecimalError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_fixed_point(s, DECIMALS)
+            .map(Self::from_mantissa)
+            .ok_or(ParseDecimalError)
+    }
+}
+
+/// The error returned when parsing a [`Decimal`] fails.
+#[cfg(feature = "decimal")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseDecimalError;
+
+#[cfg(feature = "decimal")]
+impl std::fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid decimal number")
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl std::error::Error for ParseDecimalError {}
+
+#[cfg(feature = "decimal")]
+impl<const DECIMALS: u32> crate::Numeric for Decimal<DECIMALS> {
+    const INTEGRAL: bool = false;
+    const MIN: Self = Self::MIN;
+    const MAX: Self = Self::MAX;
+    const FIXED_DECIMALS: u32 = DECIMALS;
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        let scale = pow10_i128(DECIMALS).map_or(f64::INFINITY, |scale| scale as f64);
+        self.mantissa as f64 / scale
+    }
+
+    #[inline]
+    fn from_f64(num: f64) -> Self {
+        let scale = pow10_i128(DECIMALS).map_or(f64::INFINITY, |scale| scale as f64);
+        Self::from_mantissa((num * scale).round() as i128)
+    }
+
+    #[inline]
+    fn to_i128(self) -> Option<i128> {
+        Some(self.mantissa)
+    }
+
+    #[inline]
+    fn from_i128(num: i128) -> Self {
+        Self::from_mantissa(num)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_fixed_point() {
+        assert_eq!(format_fixed_point(0, 0), "0");
+        assert_eq!(format_fixed_point(0, 2), "0.00");
+        assert_eq!(format_fixed_point(12345, 2), "123.45");
+        assert_eq!(format_fixed_point(-5, 3), "-0.005");
+        assert_eq!(format_fixed_point(-100, 2), "-1.00");
+        assert_eq!(
+            format_fixed_point(i128::MIN, 0),
+            "-170141183460469231731687303715884105728"
+        );
+        assert_eq!(
+            format_fixed_point(i128::MIN, 38),
+            "-1.70141183460469231731687303715884105728"
+        );
+    }
+
+    #[test]
+    fn test_parse_fixed_point_rounding() {
+        assert_eq!(parse_fixed_point("1.004", 2), Some(100));
+        assert_eq!(parse_fixed_point("1.005", 2), Some(101));
+        assert_eq!(parse_fixed_point("1.0049999", 2), Some(100));
+        assert_eq!(parse_fixed_point("0.5", 0), Some(1));
+        assert_eq!(parse_fixed_point("0.49", 0), Some(0));
+
+        // Half away from zero:
+        assert_eq!(parse_fixed_point("-1.005", 2), Some(-101));
+        assert_eq!(parse_fixed_point("-0.5", 0), Some(-1));
+        assert_eq!(parse_fixed_point("-0.4", 0), Some(0));
+
+        // Missing decimals are zeros:
+        assert_eq!(parse_fixed_point("7", 3), Some(7000));
+        assert_eq!(parse_fixed_point("7.", 3), Some(7000));
+        assert_eq!(parse_fixed_point(".5", 1), Some(5));
+    }
+
+    #[test]
+    fn test_parse_fixed_point_signs() {
+        assert_eq!(parse_fixed_point("-12.5", 1), Some(-125));
+        assert_eq!(parse_fixed_point("−12.5", 1), Some(-125)); // Unicode minus
+        assert_eq!(parse_fixed_point("+12.5", 1), Some(125));
+        assert_eq!(parse_fixed_point("  12.5\n", 1), Some(125));
+        assert_eq!(parse_fixed_point("-0", 2), Some(0));
+    }
+
+    #[test]
+    fn test_parse_fixed_point_overflow() {
+        let max = i128::MAX.to_string();
+        let min = i128::MIN.to_string();
+        assert_eq!(parse_fixed_point(&max, 0), Some(i128::MAX));
+        assert_eq!(parse_fixed_point(&min, 0), Some(i128::MIN));
+
+        // One more than fits:
+        assert_eq!(
+            parse_fixed_point("170141183460469231731687303715884105728", 0),
+            None
+        );
+        assert_eq!(
+            parse_fixed_point("-170141183460469231731687303715884105729", 0),
+            None
+        );
+
+        // Fits as an integer, but not with the decimals:
+        assert_eq!(parse_fixed_point(&max, 1), None);
+
+        // Rounding up past the max:
+        assert_eq!(parse_fixed_point(&format!("{max}.5"), 0), None);
+        assert_eq!(parse_fixed_point(&format!("{min}.5"), 0), None);
+        assert_eq!(parse_fixed_point(&format!("{max}.4"), 0), Some(i128::MAX));
+
+        assert_eq!(pow10_i128(38), Some(10_i128.pow(38)));
+        assert_eq!(pow10_i128(39), None);
+    }
+
+    #[test]
+    fn test_parse_fixed_point_malformed() {
+        for text in [
+            "", " ", ".", "-", "+", "-.", "1.2.3", "1,5", "1e3", "0x10", "--1", "+-1", "1 000",
+            "abc", "NaN", "inf", "1.5x",
+        ] {
+            assert_eq!(parse_fixed_point(text, 2), None, "{text:?}");
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for mantissa in [0, 1, -1, 99, -100, 123_456_789, i128::MAX, i128::MIN] {
+            for decimals in [0, 1, 2, 5, 38] {
+                let text = format_fixed_point(mantissa, decimals);
+                assert_eq!(parse_fixed_point(&text, decimals), Some(mantissa), "{text}");
+            }
+        }
+    }
+}
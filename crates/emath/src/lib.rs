@@ -27,6 +27,7 @@ use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
 
 pub mod align;
 pub mod easing;
+pub mod fixed_point;
 mod history;
 mod numeric;
 mod ordered_float;
@@ -40,6 +41,9 @@ mod ts_transform;
 mod vec2;
 mod vec2b;
 
+#[cfg(feature = "decimal")]
+pub use self::fixed_point::{Decimal, ParseDecimalError};
+
 pub use self::{
     align::{Align, Align2},
     history::History,
@@ -12,6 +12,32 @@ pub trait Numeric: Clone + Copy + PartialEq + PartialOrd + 'static {
     fn to_f64(self) -> f64;
 
     fn from_f64(num: f64) -> Self;
+
+    /// For exact fixed-point types: the number of decimals of the mantissa returned by [`Self::to_i128`].
+    ///
+    /// Zero for integers.
+    const FIXED_DECIMALS: u32 = 0;
+
+    /// The exact value as an `i128`, or `None` if this is not an integer (or fixed-point) type.
+    ///
+    /// Unlike [`Self::to_f64`] this is lossless for all the builtin integer types,
+    /// including `i64` and `u64` values above 2^53.
+    /// The exception is `u128`: values above [`i128::MAX`] return `None`,
+    /// so they are edited (approximately) via `f64` instead.
+    ///
+    /// For fixed-point types this is the value in units of `10^-FIXED_DECIMALS`.
+    #[inline(always)]
+    fn to_i128(self) -> Option<i128> {
+        None
+    }
+
+    /// Convert from an `i128`, saturating at [`Self::MIN`] and [`Self::MAX`].
+    ///
+    /// Lossless for integer types (within their range).
+    #[inline(always)]
+    fn from_i128(num: i128) -> Self {
+        Self::from_f64(num as f64)
+    }
 }
 
 macro_rules! impl_numeric_float {
@@ -56,6 +82,22 @@ macro_rules! impl_numeric_integer {
             fn from_f64(num: f64) -> Self {
                 num as Self
             }
+
+            #[inline(always)]
+            fn to_i128(self) -> Option<i128> {
+                #[allow(trivial_numeric_casts)]
+                {
+                    Some(self as i128)
+                }
+            }
+
+            #[inline(always)]
+            fn from_i128(num: i128) -> Self {
+                #[allow(trivial_numeric_casts)]
+                {
+                    num.clamp(Self::MIN as i128, Self::MAX as i128) as Self
+                }
+            }
         }
     };
 }
@@ -70,5 +112,33 @@ impl_numeric_integer!(i32);
 impl_numeric_integer!(u32);
 impl_numeric_integer!(i64);
 impl_numeric_integer!(u64);
+impl_numeric_integer!(i128);
 impl_numeric_integer!(isize);
 impl_numeric_integer!(usize);
+
+impl Numeric for u128 {
+    const INTEGRAL: bool = true;
+    const MIN: Self = std::u128::MIN;
+    const MAX: Self = std::u128::MAX;
+
+    #[inline(always)]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline(always)]
+    fn from_f64(num: f64) -> Self {
+        num as Self
+    }
+
+    /// `None` above [`i128::MAX`].
+    #[inline(always)]
+    fn to_i128(self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+
+    #[inline(always)]
+    fn from_i128(num: i128) -> Self {
+        Self::try_from(num).unwrap_or_default()
+    }
+}
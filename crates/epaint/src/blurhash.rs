@@ -0,0 +1,89 @@
+use crate::{Color32, ColorImage};
+use ecolor::{gamma_u8_from_linear_f32, linear_f32_from_gamma_u8};
+
+const BASE83: &[u8] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+fn decode_base83(text: &[u8]) -> Option<u32> {
+    text.iter().try_fold(0_u32, |value, &c| {
+        let digit = BASE83.iter().position(|&d| d == c)?;
+        Some(value * 83 + digit as u32)
+    })
+}
+
+fn sign_pow(value: f32, exp: f32) -> f32 {
+    value.abs().powf(exp).copysign(value)
+}
+
+impl ColorImage {
+    /// Decode a [BlurHash](https://blurha.sh), a compact text representation of a blurry image.
+    ///
+    /// Such hashes are small enough to send along with the url of an image,
+    /// so they can be shown as a placeholder while the image is loading.
+    ///
+    /// A small `size` like `[32, 32]` is enough, since the image is blurry anyway.
+    ///
+    /// Returns `None` if the hash is invalid.
+    pub fn from_blurhash(blurhash: &str, size: [usize; 2]) -> Option<Self> {
+        let hash = blurhash.as_bytes();
+        if hash.len() < 6 {
+            return None;
+        }
+
+        let size_flag = decode_base83(&hash[0..1])?;
+        let num_x = (size_flag % 9 + 1) as usize;
+        let num_y = (size_flag / 9 + 1) as usize;
+        if hash.len() != 4 + 2 * num_x * num_y {
+            return None;
+        }
+        let max_value = (decode_base83(&hash[1..2])? + 1) as f32 / 166.0;
+
+        let mut components = Vec::with_capacity(num_x * num_y);
+        let dc = decode_base83(&hash[2..6])?;
+        components
+            .push([dc >> 16, (dc >> 8) & 255, dc & 255].map(|c| linear_f32_from_gamma_u8(c as u8)));
+        for i in 1..num_x * num_y {
+            let ac = decode_base83(&hash[4 + 2 * i..6 + 2 * i])?;
+            components.push(
+                [ac / (19 * 19), (ac / 19) % 19, ac % 19]
+                    .map(|q| sign_pow((q as f32 - 9.0) / 9.0, 2.0) * max_value),
+            );
+        }
+
+        let [width, height] = size;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let mut rgb = [0.0; 3];
+                for j in 0..num_y {
+                    let basis_y = (std::f32::consts::PI * (y * j) as f32 / height as f32).cos();
+                    for i in 0..num_x {
+                        let basis_x = (std::f32::consts::PI * (x * i) as f32 / width as f32).cos();
+                        let component = components[i + j * num_x];
+                        for (c, component) in rgb.iter_mut().zip(component) {
+                            *c += component * basis_x * basis_y;
+                        }
+                    }
+                }
+                let [r, g, b] = rgb.map(gamma_u8_from_linear_f32);
+                pixels.push(Color32::from_rgb(r, g, b));
+            }
+        }
+
+        Some(Self { size, pixels })
+    }
+}
+
+#[test]
+fn test_blurhash() {
+    let image = ColorImage::from_blurhash("LEHV6nWB2yk8pyo0adR*.7kCMdnj", [32, 24]).unwrap();
+    assert_eq!(image.size, [32, 24]);
+    assert_eq!(image.pixels.len(), 32 * 24);
+
+    // A single component is a solid color:
+    let solid = ColorImage::from_blurhash("00TI:j", [4, 4]).unwrap();
+    assert!(solid.pixels.iter().all(|&p| p == solid.pixels[0]));
+
+    assert!(ColorImage::from_blurhash("LEHV6n", [4, 4]).is_none()); // too short
+    assert!(ColorImage::from_blurhash("not a hash!", [4, 4]).is_none());
+}
@@ -7,13 +7,16 @@ use std::sync::Arc;
 ///
 /// In order to paint the image on screen, you first need to convert it to
 ///
-/// See also: [`ColorImage`], [`FontImage`].
+/// See also: [`ColorImage`], [`PaletteImage`], [`FontImage`].
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum ImageData {
     /// RGBA image.
     Color(Arc<ColorImage>),
 
+    /// Image where each pixel is an index into a palette of colors.
+    Palette(Arc<PaletteImage>),
+
     /// Used for the font texture.
     Font(FontImage),
 }
@@ -22,6 +25,7 @@ impl ImageData {
     pub fn size(&self) -> [usize; 2] {
         match self {
             Self::Color(image) => image.size,
+            Self::Palette(image) => image.size,
             Self::Font(image) => image.size,
         }
     }
@@ -36,7 +40,9 @@ impl ImageData {
 
     pub fn bytes_per_pixel(&self) -> usize {
         match self {
-            Self::Color(_) | Self::Font(_) => 4,
+            // Paletted images are expanded to RGBA on the CPU before they are uploaded,
+            // so they take up as much texture memory as color images:
+            Self::Color(_) | Self::Palette(_) | Self::Font(_) => 4,
         }
     }
 }
@@ -259,6 +265,132 @@ impl std::fmt::Debug for ColorImage {
 
 // ----------------------------------------------------------------------------
 
+/// A 2D image in RAM where each pixel is an index into a palette of up to 256 colors.
+///
+/// Useful for indexed data, like a minimap or the screen of an emulator,
+/// where you change the indices or the palette rather than the colors themselves.
+///
+/// The backends expand it with [`Self::srgba_pixels`] when uploading it,
+/// so the texture is as large as that of the same [`ColorImage`].
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PaletteImage {
+    /// width, height.
+    pub size: [usize; 2],
+
+    /// The palette index of each pixel, row by row, from top to bottom.
+    pub indices: Vec<u8>,
+
+    /// The colors of the palette.
+    ///
+    /// Pixels with an index outside of the palette are transparent.
+    pub palette: Vec<Color32>,
+}
+
+impl PaletteImage {
+    /// Create an image filled with the first color of the palette.
+    pub fn new(size: [usize; 2], palette: Vec<Color32>) -> Self {
+        Self {
+            size,
+            indices: vec![0; size[0] * size[1]],
+            palette,
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.size[0]
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.size[1]
+    }
+
+    /// Returns the colors of the pixels, row by row, top to bottom.
+    #[inline]
+    pub fn srgba_pixels(&self) -> impl ExactSizeIterator<Item = Color32> + '_ {
+        self.indices.iter().map(|&index| {
+            self.palette
+                .get(index as usize)
+                .copied()
+                .unwrap_or(Color32::TRANSPARENT)
+        })
+    }
+
+    /// Expand the palette into a [`ColorImage`].
+    pub fn to_color_image(&self) -> ColorImage {
+        ColorImage {
+            size: self.size,
+            pixels: self.srgba_pixels().collect(),
+        }
+    }
+
+    /// Clone a sub-region as a new image, with the same palette.
+    pub fn region(&self, [x, y]: [usize; 2], [w, h]: [usize; 2]) -> Self {
+        assert!(x + w <= self.width());
+        assert!(y + h <= self.height());
+
+        let mut indices = Vec::with_capacity(w * h);
+        for y in y..y + h {
+            let offset = y * self.width() + x;
+            indices.extend(&self.indices[offset..(offset + w)]);
+        }
+        assert_eq!(indices.len(), w * h);
+        Self {
+            size: [w, h],
+            indices,
+            palette: self.palette.clone(),
+        }
+    }
+}
+
+impl std::ops::Index<(usize, usize)> for PaletteImage {
+    type Output = u8;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &u8 {
+        let [w, h] = self.size;
+        assert!(x < w && y < h);
+        &self.indices[y * w + x]
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for PaletteImage {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut u8 {
+        let [w, h] = self.size;
+        assert!(x < w && y < h);
+        &mut self.indices[y * w + x]
+    }
+}
+
+impl From<PaletteImage> for ImageData {
+    #[inline(always)]
+    fn from(image: PaletteImage) -> Self {
+        Self::Palette(Arc::new(image))
+    }
+}
+
+impl From<Arc<PaletteImage>> for ImageData {
+    #[inline]
+    fn from(image: Arc<PaletteImage>) -> Self {
+        Self::Palette(image)
+    }
+}
+
+impl std::fmt::Debug for PaletteImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PaletteImage")
+            .field("size", &self.size)
+            .field("pixel-count", &self.indices.len())
+            .field("palette-size", &self.palette.len())
+            .finish_non_exhaustive()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A single-channel image designed for the font texture.
 ///
 /// Each value represents "coverage", i.e. how much a texel is covered by a character.
@@ -409,3 +541,65 @@ impl ImageDelta {
         self.pos.is_none()
     }
 }
+
+#[test]
+fn test_palette_image() {
+    let palette = vec![Color32::RED, Color32::GREEN, Color32::BLUE];
+    let mut image = PaletteImage::new([3, 2], palette);
+    image[(1, 0)] = 1;
+    image[(2, 1)] = 2;
+    image[(0, 1)] = 7; // not in the palette
+
+    let pixels: Vec<Color32> = image.srgba_pixels().collect();
+    assert_eq!(
+        pixels,
+        [
+            Color32::RED,
+            Color32::GREEN,
+            Color32::RED,
+            Color32::TRANSPARENT,
+            Color32::RED,
+            Color32::BLUE,
+        ]
+    );
+    assert_eq!(image.to_color_image().pixels, pixels);
+
+    let region = image.region([1, 0], [2, 2]);
+    assert_eq!(region.size, [2, 2]);
+    assert_eq!(region.indices, [1, 0, 0, 2]);
+    assert_eq!(region.palette, image.palette);
+}
+
+#[test]
+fn test_partial_palette_delta() {
+    use crate::textures::TextureManager;
+
+    let mut textures = TextureManager::default();
+    let options = TextureOptions::NEAREST;
+    let id = textures.alloc(
+        "map".into(),
+        ColorImage::new([4, 3], Color32::BLACK).into(),
+        options,
+    );
+
+    let mut image = PaletteImage::new([2, 2], vec![Color32::RED, Color32::GREEN]);
+    image[(1, 1)] = 1;
+    textures.set(id, ImageDelta::partial([1, 1], image, options));
+
+    // A partial update keeps the size of the texture:
+    let meta = textures.meta(id).unwrap();
+    assert_eq!(meta.size, [4, 3]);
+    assert_eq!(meta.bytes_per_pixel, 4);
+
+    let delta = textures.take_delta();
+    let (_, partial) = delta.set.last().unwrap();
+    assert_eq!(partial.pos, Some([1, 1]));
+    let ImageData::Palette(image) = &partial.image else {
+        panic!("Expected a palette image");
+    };
+    let pixels: Vec<Color32> = image.srgba_pixels().collect();
+    assert_eq!(
+        pixels,
+        [Color32::RED, Color32::RED, Color32::RED, Color32::GREEN]
+    );
+}
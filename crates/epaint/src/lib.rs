@@ -24,11 +24,13 @@
 #![allow(clippy::manual_range_contains)]
 
 mod bezier;
+mod blurhash;
 pub mod color;
 pub mod image;
 mod margin;
 mod mesh;
 pub mod mutex;
+mod nine_patch;
 mod shadow;
 mod shape;
 pub mod shape_transform;
@@ -44,9 +46,10 @@ pub mod util;
 pub use self::{
     bezier::{CubicBezierShape, QuadraticBezierShape},
     color::ColorMode,
-    image::{ColorImage, FontImage, ImageData, ImageDelta},
+    image::{ColorImage, FontImage, ImageData, ImageDelta, PaletteImage},
     margin::Margin,
     mesh::{Mesh, Mesh16, Vertex},
+    nine_patch::{NinePatch, NinePatchFill},
     shadow::Shadow,
     shape::{
         CircleShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape, RectShape,
@@ -64,7 +67,7 @@ pub use self::{
 #[allow(deprecated)]
 pub use tessellator::tessellate_shapes;
 
-pub use ecolor::{Color32, Hsva, HsvaGamma, Rgba};
+pub use ecolor::{Color32, ColorMatrix, Hsva, HsvaGamma, Rgba};
 pub use emath::{pos2, vec2, Pos2, Rect, Vec2};
 
 pub use ahash;
@@ -58,6 +58,11 @@ pub struct Mesh {
 
     /// The texture to use when drawing these triangles.
     pub texture_id: TextureId,
+
+    /// Transform the colors sampled from the texture, before they are multiplied with the vertex colors.
+    ///
+    /// Backends apply this in the fragment shader.
+    pub color_filter: ColorMatrix,
     // TODO(emilk): bounding rectangle
 }
 
@@ -125,11 +130,16 @@ impl Mesh {
 
         if self.is_empty() {
             self.texture_id = other.texture_id;
+            self.color_filter = other.color_filter;
         } else {
             assert_eq!(
                 self.texture_id, other.texture_id,
                 "Can't merge Mesh using different textures"
             );
+            assert_eq!(
+                self.color_filter, other.color_filter,
+                "Can't merge Mesh using different color filters"
+            );
         }
 
         let index_offset = self.vertices.len() as u32;
@@ -222,6 +232,7 @@ impl Mesh {
                 indices: self.indices.iter().map(|&i| i as u16).collect(),
                 vertices: self.vertices,
                 texture_id: self.texture_id,
+                color_filter: self.color_filter,
             }];
         }
 
@@ -264,6 +275,7 @@ impl Mesh {
                     .collect(),
                 vertices: self.vertices[(min_vindex as usize)..=(max_vindex as usize)].to_vec(),
                 texture_id: self.texture_id,
+                color_filter: self.color_filter,
             };
             debug_assert!(mesh.is_valid());
             output.push(mesh);
@@ -311,6 +323,9 @@ pub struct Mesh16 {
 
     /// The texture to use when drawing these triangles.
     pub texture_id: TextureId,
+
+    /// See [`Mesh::color_filter`].
+    pub color_filter: ColorMatrix,
 }
 
 impl Mesh16 {
@@ -0,0 +1,222 @@
+use crate::{Color32, Margin, Mesh, Rect, Vec2};
+
+/// How the edges and the center of a [`NinePatch`] fill their space.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum NinePatchFill {
+    /// Stretch them to fit.
+    #[default]
+    Stretch,
+
+    /// Repeat them, cutting off the last tile.
+    ///
+    /// If that would take more than [`NinePatch::MAX_TILES`] tiles along a side,
+    /// the tiles are scaled up so that exactly that many fit.
+    Tile,
+}
+
+/// Paint a texture so that its corners keep their size, while its edges and center are
+/// stretched or tiled to fill the rest, e.g. for skinning buttons and panels with textures.
+///
+/// The texture is cut into nine parts by the [`Self::insets`]:
+/// four corners, four edges and the center.
+///
+/// Use with [`Mesh::add_nine_patch`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct NinePatch {
+    /// How far in from each side of the texture the cuts are,
+    /// in the same units as the size of the texture (usually points).
+    ///
+    /// The corners are painted with this size,
+    /// unless the painted rectangle is too small for them.
+    pub insets: Margin,
+
+    /// How the edges and the center fill their space.
+    pub fill: NinePatchFill,
+}
+
+impl NinePatch {
+    /// The most tiles along one side of the center, see [`NinePatchFill::Tile`].
+    ///
+    /// This keeps the mesh small when a tiny texture is tiled over a large rectangle.
+    pub const MAX_TILES: usize = 64;
+
+    #[inline]
+    pub fn new(insets: impl Into<Margin>) -> Self {
+        Self {
+            insets: insets.into(),
+            fill: NinePatchFill::Stretch,
+        }
+    }
+
+    /// Tile the edges and the center instead of stretching them.
+    #[inline]
+    pub fn tiled(mut self) -> Self {
+        self.fill = NinePatchFill::Tile;
+        self
+    }
+}
+
+/// The spans of painted positions and uv coordinates along one axis.
+///
+/// `pos` and `uv` are the start, first cut, second cut and end.
+/// `tile` is the length of the middle part of the texture, if it should be tiled.
+fn spans(pos: [f32; 4], uv: [f32; 4], tile: Option<f32>) -> [Vec<([f32; 2], [f32; 2])>; 3] {
+    let middle = match tile {
+        Some(tile_length) if 0.0 < tile_length => {
+            let length = pos[2] - pos[1];
+            let max_tiles = NinePatch::MAX_TILES as f32;
+            // Rather than too many tiles, use fewer and larger ones:
+            let (tile_length, num_tiles) = if length / tile_length <= max_tiles {
+                (tile_length, (length / tile_length).ceil() as usize)
+            } else {
+                (length / max_tiles, NinePatch::MAX_TILES)
+            };
+            (0..num_tiles)
+                .map(|i| {
+                    let start = pos[1] + i as f32 * tile_length;
+                    let end = if i + 1 == num_tiles {
+                        pos[2]
+                    } else {
+                        start + tile_length
+                    };
+                    let t = (end - start) / tile_length;
+                    ([start, end], [uv[1], uv[1] + t * (uv[2] - uv[1])])
+                })
+                .collect()
+        }
+        _ => vec![([pos[1], pos[2]], [uv[1], uv[2]])],
+    };
+    [
+        vec![([pos[0], pos[1]], [uv[0], uv[1]])],
+        middle,
+        vec![([pos[2], pos[3]], [uv[2], uv[3]])],
+    ]
+}
+
+impl Mesh {
+    /// Add a [`NinePatch`] of the texture of this mesh.
+    ///
+    /// `uv` is the part of the texture to use, and `source_size` is the size of that part
+    /// in the same units as [`NinePatch::insets`].
+    pub fn add_nine_patch(
+        &mut self,
+        rect: Rect,
+        uv: Rect,
+        source_size: Vec2,
+        nine_patch: &NinePatch,
+        color: Color32,
+    ) {
+        let NinePatch { insets, fill } = *nine_patch;
+
+        // Shrink the corners if they don't fit:
+        let scale = (rect.size() / insets.sum()).min(Vec2::splat(1.0));
+        let (left, right) = (insets.left * scale.x, insets.right * scale.x);
+        let (top, bottom) = (insets.top * scale.y, insets.bottom * scale.y);
+
+        let uv_per_point = uv.size() / source_size;
+        let x_uv = [
+            uv.left(),
+            uv.left() + insets.left * uv_per_point.x,
+            uv.right() - insets.right * uv_per_point.x,
+            uv.right(),
+        ];
+        let y_uv = [
+            uv.top(),
+            uv.top() + insets.top * uv_per_point.y,
+            uv.bottom() - insets.bottom * uv_per_point.y,
+            uv.bottom(),
+        ];
+        let x_pos = [
+            rect.left(),
+            rect.left() + left,
+            rect.right() - right,
+            rect.right(),
+        ];
+        let y_pos = [
+            rect.top(),
+            rect.top() + top,
+            rect.bottom() - bottom,
+            rect.bottom(),
+        ];
+
+        let tile = fill == NinePatchFill::Tile;
+        let x_spans = spans(x_pos, x_uv, tile.then(|| source_size.x - insets.sum().x));
+        let y_spans = spans(y_pos, y_uv, tile.then(|| source_size.y - insets.sum().y));
+
+        for y_spans in &y_spans {
+            for &([top, bottom], [uv_top, uv_bottom]) in y_spans {
+                for x_spans in &x_spans {
+                    for &([left, right], [uv_left, uv_right]) in x_spans {
+                        if left < right && top < bottom {
+                            self.add_rect_with_uv(
+                                Rect::from_x_y_ranges(left..=right, top..=bottom),
+                                Rect::from_x_y_ranges(uv_left..=uv_right, uv_top..=uv_bottom),
+                                color,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_nine_patch() {
+    use crate::{pos2, vec2, TextureId};
+
+    let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 50.0));
+    let uv = Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0));
+    let source_size = vec2(30.0, 30.0);
+    let nine_patch = NinePatch::new(Margin::same(10.0));
+
+    let mut mesh = Mesh::with_texture(TextureId::Managed(1));
+    mesh.add_nine_patch(rect, uv, source_size, &nine_patch, Color32::WHITE);
+    assert_eq!(mesh.vertices.len(), 9 * 4);
+    // The top left corner keeps its size, and uses the top left third of the texture:
+    assert_eq!(mesh.vertices[3].pos, pos2(10.0, 10.0));
+    assert_eq!(mesh.vertices[3].uv, pos2(1.0 / 3.0, 1.0 / 3.0));
+    assert_eq!(mesh.calc_bounds(), rect);
+
+    // The middle is 80 wide with 10 wide tiles, and 30 high with 10 high tiles:
+    let mut mesh = Mesh::with_texture(TextureId::Managed(1));
+    mesh.add_nine_patch(rect, uv, source_size, &nine_patch.tiled(), Color32::WHITE);
+    let columns = 1 + 8 + 1;
+    let rows = 1 + 3 + 1;
+    assert_eq!(mesh.vertices.len(), columns * rows * 4);
+    assert_eq!(mesh.calc_bounds(), rect);
+
+    // Too many tiles, so they are made larger:
+    let huge_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(10_000.0, 50.0));
+    let mut mesh = Mesh::with_texture(TextureId::Managed(1));
+    mesh.add_nine_patch(
+        huge_rect,
+        uv,
+        source_size,
+        &nine_patch.tiled(),
+        Color32::WHITE,
+    );
+    let columns = 1 + NinePatch::MAX_TILES + 1;
+    assert_eq!(mesh.vertices.len(), columns * rows * 4);
+    assert_eq!(mesh.calc_bounds(), huge_rect);
+    // Each tile of the top edge is the whole middle of the texture:
+    let tile_width = (10_000.0 - 20.0) / NinePatch::MAX_TILES as f32;
+    let top_edge = mesh.vertices.chunks(4).skip(1).take(NinePatch::MAX_TILES);
+    for (i, tile) in top_edge.enumerate() {
+        let left = 10.0 + i as f32 * tile_width;
+        let (top_left, bottom_right) = (tile[0], tile[3]);
+        assert!((top_left.pos.x - left).abs() < 0.01);
+        assert!((bottom_right.pos.x - (left + tile_width)).abs() < 0.01);
+        assert!((top_left.uv.x - 1.0 / 3.0).abs() < 1e-5);
+        assert!((bottom_right.uv.x - 2.0 / 3.0).abs() < 1e-5);
+    }
+
+    // Too small for the corners:
+    let small_rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(10.0, 10.0));
+    let mut mesh = Mesh::with_texture(TextureId::Managed(1));
+    mesh.add_nine_patch(small_rect, uv, source_size, &nine_patch, Color32::WHITE);
+    assert_eq!(mesh.calc_bounds(), small_rect);
+    assert_eq!(mesh.vertices[3].pos, pos2(5.0, 5.0));
+}
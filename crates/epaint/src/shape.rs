@@ -373,6 +373,16 @@ impl Shape {
         }
     }
 
+    /// The [`Mesh::color_filter`] of this shape.
+    #[inline(always)]
+    pub fn color_filter(&self) -> super::ColorMatrix {
+        if let Self::Mesh(mesh) = self {
+            mesh.color_filter
+        } else {
+            super::ColorMatrix::IDENTITY
+        }
+    }
+
     /// Scale the shape by `factor`, in-place.
     ///
     /// A wrapper around [`Self::transform`].
@@ -689,6 +699,7 @@ pub struct RectShape {
     ///
     /// To display a texture, set [`Self::fill_texture_id`],
     /// and set this to `Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0))`.
+    /// A range with `min` larger than `max` flips the texture along that axis.
     ///
     /// Use [`Rect::ZERO`] to turn off texturing.
     pub uv: Rect,
@@ -856,6 +867,50 @@ impl Rounding {
             se: self.se.min(max),
         }
     }
+
+    /// The rounding of segment `index` of `count` segments side by side, left to right,
+    /// that together make up a shape with this rounding.
+    ///
+    /// Only the outer corners are rounded: the left ones of the first segment and the right ones of the last.
+    #[inline]
+    pub fn segment(&self, index: usize, count: usize) -> Self {
+        let first = index == 0;
+        let last = index + 1 == count;
+        Self {
+            nw: if first { self.nw } else { 0.0 },
+            sw: if first { self.sw } else { 0.0 },
+            ne: if last { self.ne } else { 0.0 },
+            se: if last { self.se } else { 0.0 },
+        }
+    }
+}
+
+#[test]
+fn test_rounding_segment() {
+    let rounding = Rounding {
+        nw: 1.0,
+        ne: 2.0,
+        sw: 3.0,
+        se: 4.0,
+    };
+    assert_eq!(rounding.segment(0, 1), rounding);
+    assert_eq!(
+        rounding.segment(0, 3),
+        Rounding {
+            nw: 1.0,
+            sw: 3.0,
+            ..Rounding::ZERO
+        }
+    );
+    assert_eq!(rounding.segment(1, 3), Rounding::ZERO);
+    assert_eq!(
+        rounding.segment(2, 3),
+        Rounding {
+            ne: 2.0,
+            se: 4.0,
+            ..Rounding::ZERO
+        }
+    );
 }
 
 impl std::ops::Add for Rounding {
@@ -1264,6 +1264,7 @@ impl Tessellator {
                     || match &output_clipped_primitive.primitive {
                         Primitive::Mesh(output_mesh) => {
                             output_mesh.texture_id != shape.texture_id()
+                                || output_mesh.color_filter != shape.color_filter()
                         }
                         Primitive::Callback(_) => true,
                     }
@@ -1647,8 +1648,8 @@ impl Tessellator {
             path::rounded_rectangle(&mut self.scratchpad_points, rect, rounding);
             path.add_line_loop(&self.scratchpad_points);
 
-            if uv.is_positive() {
-                // Textured
+            if uv.is_finite() && uv.width() != 0.0 && uv.height() != 0.0 {
+                // Textured (possibly flipped)
                 let uv_from_pos = |p: Pos2| {
                     pos2(
                         remap(p.x, rect.x_range(), uv.x_range()),
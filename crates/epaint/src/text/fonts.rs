@@ -404,16 +404,28 @@ impl Fonts {
 
         if needs_recreate {
             let definitions = fonts_and_cache.fonts.definitions.clone();
+            let max_cached_galleys = fonts_and_cache.galley_cache.max_len;
 
             *fonts_and_cache = FontsAndCache {
-                fonts: FontsImpl::new(pixels_per_point, max_texture_side, definitions),
-                galley_cache: Default::default(),
+                fonts: FontsImpl::new_shared(pixels_per_point, max_texture_side, definitions),
+                galley_cache: GalleyCache {
+                    max_len: max_cached_galleys,
+                    ..Default::default()
+                },
             };
         }
 
         fonts_and_cache.galley_cache.flush_cache();
     }
 
+    /// Limit the number of [`Galley`]s that are cached between frames.
+    ///
+    /// Text that doesn't fit in the cache is laid out again each frame.
+    /// `None` (the default) means no limit.
+    pub fn set_max_cached_galleys(&self, max_cached_galleys: Option<usize>) {
+        self.lock().galley_cache.max_len = max_cached_galleys;
+    }
+
     /// Call at the end of each frame (before painting) to get the change to the font texture since last call.
     pub fn font_image_delta(&self) -> Option<crate::ImageDelta> {
         self.lock().fonts.atlas.lock().take_delta()
@@ -436,6 +448,13 @@ impl Fonts {
         self.lock().fonts.max_texture_side
     }
 
+    /// A copy of the fonts that can lay out text on another thread.
+    ///
+    /// See [`FontsImpl::fork`].
+    pub fn fork(&self) -> FontsImpl {
+        self.lock().fonts.fork()
+    }
+
     /// The font atlas.
     /// Pass this to [`crate::Tessellator`].
     pub fn texture_atlas(&self) -> Arc<Mutex<TextureAtlas>> {
@@ -573,7 +592,7 @@ impl FontsAndCache {
 pub struct FontsImpl {
     pixels_per_point: f32,
     max_texture_side: usize,
-    definitions: FontDefinitions,
+    definitions: Arc<FontDefinitions>,
     atlas: Arc<Mutex<TextureAtlas>>,
     font_impl_cache: FontImplCache,
     sized_family: ahash::HashMap<(OrderedFloat<f32>, FontFamily), Font>,
@@ -586,6 +605,14 @@ impl FontsImpl {
         pixels_per_point: f32,
         max_texture_side: usize,
         definitions: FontDefinitions,
+    ) -> Self {
+        Self::new_shared(pixels_per_point, max_texture_side, Arc::new(definitions))
+    }
+
+    fn new_shared(
+        pixels_per_point: f32,
+        max_texture_side: usize,
+        definitions: Arc<FontDefinitions>,
     ) -> Self {
         assert!(
             0.0 < pixels_per_point && pixels_per_point < 100.0,
@@ -611,6 +638,28 @@ impl FontsImpl {
         }
     }
 
+    /// A copy of these fonts that shares the same font atlas and rasterized glyphs.
+    ///
+    /// This is cheap, and the copy can be moved to another thread
+    /// to lay out text there using [`super::layout`].
+    /// Any new glyphs are added to the shared atlas.
+    pub fn fork(&self) -> Self {
+        Self {
+            pixels_per_point: self.pixels_per_point,
+            max_texture_side: self.max_texture_side,
+            definitions: self.definitions.clone(),
+            atlas: self.atlas.clone(),
+            font_impl_cache: self.font_impl_cache.clone(),
+            sized_family: Default::default(),
+        }
+    }
+
+    /// The font atlas that the glyphs are rasterized into.
+    #[inline]
+    pub fn texture_atlas(&self) -> &Arc<Mutex<TextureAtlas>> {
+        &self.atlas
+    }
+
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
         self.pixels_per_point
@@ -675,11 +724,17 @@ struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
     cache: nohash_hasher::IntMap<u64, CachedGalley>,
+
+    /// If set, don't cache more than this many galleys.
+    max_len: Option<usize>,
 }
 
 impl GalleyCache {
     fn layout(&mut self, fonts: &mut FontsImpl, job: LayoutJob) -> Arc<Galley> {
         let hash = crate::util::hash(&job); // TODO(emilk): even faster hasher?
+        let is_full = self
+            .max_len
+            .map_or(false, |max_len| max_len <= self.cache.len());
 
         match self.cache.entry(hash) {
             std::collections::hash_map::Entry::Occupied(entry) => {
@@ -690,6 +745,9 @@ impl GalleyCache {
             std::collections::hash_map::Entry::Vacant(entry) => {
                 let galley = super::layout(fonts, job.into());
                 let galley = Arc::new(galley);
+                if is_full {
+                    return galley;
+                }
                 entry.insert(CachedGalley {
                     last_used: self.generation,
                     galley: galley.clone(),
@@ -715,6 +773,7 @@ impl GalleyCache {
 
 // ----------------------------------------------------------------------------
 
+#[derive(Clone)]
 struct FontImplCache {
     atlas: Arc<Mutex<TextureAtlas>>,
     pixels_per_point: f32,
@@ -783,3 +842,24 @@ impl FontImplCache {
             .clone()
     }
 }
+
+#[test]
+fn test_max_cached_galleys() {
+    let fonts = Fonts::new(1.0, 1024, FontDefinitions::default());
+    fonts.set_max_cached_galleys(Some(2));
+    let layout = |text: &str| {
+        fonts.layout_no_wrap(text.to_owned(), FontId::default(), crate::Color32::WHITE)
+    };
+
+    for text in ["a", "b", "c"] {
+        layout(text);
+    }
+    assert_eq!(fonts.num_galleys_in_cache(), 2);
+
+    // The limit survives the fonts being recreated:
+    fonts.begin_frame(2.0, 1024);
+    layout("d");
+    layout("e");
+    layout("f");
+    assert_eq!(fonts.num_galleys_in_cache(), 2);
+}
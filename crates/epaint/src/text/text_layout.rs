@@ -1,11 +1,14 @@
-use std::ops::RangeInclusive;
+use std::ops::{Range, RangeInclusive};
 use std::sync::Arc;
 
 use emath::*;
 
 use crate::{stroke::PathStroke, text::font::Font, Color32, Mesh, Stroke, Vertex};
 
-use super::{FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, Row, RowVisuals};
+use super::{
+    ElisionPosition, FontsImpl, Galley, Glyph, LayoutJob, LayoutSection, LineBreak, Row,
+    RowVisuals, TextWrapping,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -81,6 +84,7 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
             num_indices: 0,
             pixels_per_point: fonts.pixels_per_point(),
             elided: true,
+            elided_middle: None,
         };
     }
 
@@ -94,12 +98,23 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
     let point_scale = PointScale::new(fonts.pixels_per_point());
 
     let mut elided = false;
-    let mut rows = rows_from_paragraphs(paragraphs, &job, &mut elided);
-    if elided {
-        if let Some(last_row) = rows.last_mut() {
-            replace_last_glyph_with_overflow_character(fonts, &job, last_row);
+    let mut elided_middle = None;
+    let mut rows = if let Some((row, hidden_chars)) = elide_middle(fonts, &job, &paragraphs) {
+        elided = true;
+        elided_middle = Some(hidden_chars);
+        vec![row]
+    } else {
+        let mut rows = rows_from_paragraphs(paragraphs, &job, &mut elided);
+        if job.wrap.hyphenate {
+            show_soft_hyphens_at_row_ends(fonts, &job, &mut rows);
         }
-    }
+        if elided {
+            if let Some(last_row) = rows.last_mut() {
+                replace_last_glyph_with_overflow_character(fonts, &job, last_row);
+            }
+        }
+        rows
+    };
 
     let justify = job.justify && job.wrap.max_width.is_finite();
 
@@ -119,7 +134,9 @@ pub fn layout(fonts: &mut FontsImpl, job: Arc<LayoutJob>) -> Galley {
     }
 
     // Calculate the Y positions and tessellate the text:
-    galley_from_rows(point_scale, job, rows, elided)
+    let mut galley = galley_from_rows(point_scale, job, rows, elided);
+    galley.elided_middle = elided_middle;
+    galley
 }
 
 // Ignores the Y coordinate.
@@ -156,6 +173,34 @@ fn layout_section(
             out_paragraphs.push(Paragraph::from_section_index(section_index));
             paragraph = out_paragraphs.last_mut().unwrap();
             paragraph.empty_paragraph_height = line_height; // TODO(emilk): replace this hack with actually including `\n` in the glyphs?
+        } else if job.wrap.hyphenate && chr == SOFT_HYPHEN {
+            // Invisible, unless we break the row here (see `show_soft_hyphens_at_row_ends`).
+            paragraph.glyphs.push(Glyph {
+                chr,
+                pos: pos2(paragraph.cursor_x, f32::NAN),
+                size: vec2(0.0, line_height),
+                ascent: 0.0,
+                uv_rect: Default::default(),
+                section_index,
+            });
+        } else if let Some(tab_stop) = job
+            .tab_stops
+            .as_ref()
+            .filter(|_| chr == '\t')
+            .and_then(|tab_stops| tab_stops.next_stop(paragraph.cursor_x))
+        {
+            let (font_impl, glyph_info) = font.font_impl_and_glyph_info(chr);
+            let advance_width = tab_stop - paragraph.cursor_x;
+            paragraph.glyphs.push(Glyph {
+                chr,
+                pos: pos2(paragraph.cursor_x, f32::NAN),
+                size: vec2(advance_width, line_height),
+                ascent: font_impl.map_or(0.0, |font| font.ascent()),
+                uv_rect: Default::default(),
+                section_index,
+            });
+            paragraph.cursor_x = tab_stop;
+            last_glyph_id = Some(glyph_info.id);
         } else {
             let (font_impl, glyph_info) = font.font_impl_and_glyph_info(chr);
             if let Some(font_impl) = font_impl {
@@ -308,7 +353,7 @@ fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, e
             }
         }
 
-        row_break_candidates.add(i, &paragraph.glyphs[i..]);
+        row_break_candidates.add(i, &paragraph.glyphs[i..], &job.wrap);
     }
 
     if row_start_idx < paragraph.glyphs.len() {
@@ -341,6 +386,33 @@ fn line_break(paragraph: &Paragraph, job: &LayoutJob, out_rows: &mut Vec<Row>, e
     }
 }
 
+/// A row that was broken at a soft hyphen should end with a visible hyphen.
+fn show_soft_hyphens_at_row_ends(fonts: &mut FontsImpl, job: &LayoutJob, rows: &mut [Row]) {
+    for row in rows {
+        if row.ends_with_newline {
+            continue;
+        }
+        let Some(last_glyph) = row.glyphs.last_mut() else {
+            continue;
+        };
+        if last_glyph.chr != SOFT_HYPHEN {
+            continue;
+        }
+
+        let section = &job.sections[last_glyph.section_index as usize];
+        let font = fonts.font(&section.format.font_id);
+        let (font_impl, glyph_info) = font.font_impl_and_glyph_info('-');
+
+        // We keep `chr` as the soft hyphen, so that the glyphs still match the text.
+        last_glyph.size.x = glyph_info.advance_width;
+        last_glyph.ascent = font_impl.map_or(0.0, |font| font.ascent());
+        last_glyph.uv_rect = glyph_info.uv_rect;
+
+        let max_x = last_glyph.max_x();
+        row.rect.max.x = row.rect.max.x.max(max_x);
+    }
+}
+
 /// Trims the last glyphs in the row and replaces it with an overflow character (e.g. `…`).
 ///
 /// Called before we have any Y coordinates.
@@ -481,6 +553,139 @@ fn replace_last_glyph_with_overflow_character(
     }
 }
 
+/// Implements [`ElisionPosition::Middle`].
+///
+/// Returns the row, and the range of characters that were hidden.
+///
+/// Returns `None` if the text should not be elided in the middle,
+/// either because it fits, or because another elision mode was asked for.
+fn elide_middle(
+    fonts: &mut FontsImpl,
+    job: &LayoutJob,
+    paragraphs: &[Paragraph],
+) -> Option<(Row, Range<usize>)> {
+    if job.wrap.elision_position != ElisionPosition::Middle || job.wrap.max_rows != 1 {
+        return None;
+    }
+
+    let (first, last) = (paragraphs.first()?, paragraphs.last()?);
+    if paragraphs.len() == 1
+        && first
+            .glyphs
+            .last()
+            .map_or(true, |g| g.max_x() <= job.wrap.max_width)
+    {
+        return None; // It all fits
+    }
+
+    let x_start = first
+        .glyphs
+        .first()
+        .map_or(first.cursor_x, |glyph| glyph.pos.x);
+
+    let overflow_glyph = job.wrap.overflow_character.map(|overflow_character| {
+        let section_index = first
+            .glyphs
+            .first()
+            .map_or(first.section_index_at_start, |glyph| glyph.section_index);
+        let section = &job.sections[section_index as usize];
+        let font = fonts.font(&section.format.font_id);
+        let line_height = section
+            .format
+            .line_height
+            .unwrap_or_else(|| font.row_height());
+        let (font_impl, glyph_info) = font.font_impl_and_glyph_info(overflow_character);
+        Glyph {
+            chr: overflow_character,
+            pos: pos2(0.0, f32::NAN),
+            size: vec2(glyph_info.advance_width, line_height),
+            ascent: font_impl.map_or(0.0, |font| font.ascent()),
+            uv_rect: glyph_info.uv_rect,
+            section_index,
+        }
+    });
+    let overflow_width = overflow_glyph.as_ref().map_or(0.0, |glyph| glyph.size.x);
+
+    // How many glyphs to keep from the start of the first paragraph,
+    // and from the end of the last paragraph:
+    let same_paragraph = paragraphs.len() == 1;
+    let (mut num_prefix, mut num_suffix) = (0, 0);
+    let width_of = |num_prefix: usize, num_suffix: usize| {
+        let prefix_width = if num_prefix == 0 {
+            0.0
+        } else {
+            first.glyphs[num_prefix - 1].max_x() - x_start
+        };
+        let suffix_width = if num_suffix == 0 {
+            0.0
+        } else {
+            last.glyphs.last().unwrap().max_x() - last.glyphs[last.glyphs.len() - num_suffix].pos.x
+        };
+        x_start + prefix_width + overflow_width + suffix_width
+    };
+
+    loop {
+        let num_left = if same_paragraph {
+            first.glyphs.len() - num_prefix - num_suffix
+        } else {
+            usize::from(num_prefix < first.glyphs.len())
+                + usize::from(num_suffix < last.glyphs.len())
+        };
+        if num_left == 0 {
+            break;
+        }
+
+        // Alternate between the start and the end, so we keep about as much of both:
+        let take_prefix = if same_paragraph {
+            num_prefix <= num_suffix
+        } else {
+            num_suffix == last.glyphs.len()
+                || (num_prefix < first.glyphs.len() && num_prefix <= num_suffix)
+        };
+        let (new_prefix, new_suffix) = if take_prefix {
+            (num_prefix + 1, num_suffix)
+        } else {
+            (num_prefix, num_suffix + 1)
+        };
+        if job.wrap.max_width < width_of(new_prefix, new_suffix) {
+            break;
+        }
+        (num_prefix, num_suffix) = (new_prefix, new_suffix);
+    }
+
+    let mut glyphs: Vec<Glyph> = first.glyphs[..num_prefix].to_vec();
+
+    let mut x = glyphs.last().map_or(x_start, |glyph| glyph.max_x());
+    if let Some(mut overflow_glyph) = overflow_glyph {
+        overflow_glyph.pos.x = x;
+        x = overflow_glyph.max_x();
+        glyphs.push(overflow_glyph);
+    }
+
+    let suffix = &last.glyphs[last.glyphs.len() - num_suffix..];
+    if let Some(first_suffix_glyph) = suffix.first() {
+        let dx = x - first_suffix_glyph.pos.x;
+        glyphs.extend(suffix.iter().map(|glyph| Glyph {
+            pos: pos2(glyph.pos.x + dx, glyph.pos.y),
+            ..*glyph
+        }));
+    }
+
+    // Each glyph of the last paragraph is one of the last characters of the text:
+    let num_chars = job.text.chars().count();
+    let hidden_chars = num_prefix..num_chars - num_suffix;
+
+    let max_x = glyphs.last().map_or(x_start, |glyph| glyph.max_x());
+    let row = Row {
+        section_index_at_start: first.section_index_at_start,
+        glyphs,
+        visuals: Default::default(),
+        rect: rect_from_x_range(x_start..=max_x),
+        ends_with_newline: false,
+    };
+    Some((row, hidden_chars))
+}
+
 /// Horizontally aligned the text on a row.
 ///
 /// /// Ignores the Y coordinate.
@@ -664,6 +869,7 @@ fn galley_from_rows(
         num_vertices,
         num_indices,
         pixels_per_point: point_scale.pixels_per_point,
+        elided_middle: None,
     }
 }
 
@@ -924,10 +1130,30 @@ struct RowBreakCandidates {
 }
 
 impl RowBreakCandidates {
-    fn add(&mut self, index: usize, glyphs: &[Glyph]) {
+    fn add(&mut self, index: usize, glyphs: &[Glyph], wrap: &TextWrapping) {
         let chr = glyphs[0].chr;
-        const NON_BREAKING_SPACE: char = '\u{A0}';
-        if chr.is_whitespace() && chr != NON_BREAKING_SPACE {
+        let next = glyphs.get(1).map(|glyph| glyph.chr);
+
+        self.any = Some(index);
+
+        if let Some(line_break_fn) = wrap.line_break_fn {
+            match line_break_fn(chr, next) {
+                LineBreak::Default => {}
+                LineBreak::Allowed => {
+                    self.space = Some(index);
+                    return;
+                }
+                LineBreak::Forbidden => {
+                    return;
+                }
+            }
+        }
+
+        if is_non_breaking(chr) || next.map_or(false, is_non_breaking) {
+            // Glue the characters on both sides together.
+        } else if wrap.hyphenate && chr == SOFT_HYPHEN {
+            self.dash = Some(index);
+        } else if chr.is_whitespace() {
             self.space = Some(index);
         } else if is_cjk(chr) && (glyphs.len() == 1 || is_cjk_break_allowed(glyphs[1].chr)) {
             self.cjk = Some(index);
@@ -938,7 +1164,6 @@ impl RowBreakCandidates {
         } else if glyphs.len() > 1 && is_cjk(glyphs[1].chr) {
             self.pre_cjk = Some(index);
         }
-        self.any = Some(index);
     }
 
     fn word_boundary(&self) -> Option<usize> {
@@ -997,6 +1222,23 @@ impl RowBreakCandidates {
     }
 }
 
+/// Marks a place where a word may be hyphenated. Invisible unless the row is broken there.
+const SOFT_HYPHEN: char = '\u{AD}';
+
+/// Characters that should never be broken around.
+#[inline]
+fn is_non_breaking(c: char) -> bool {
+    matches!(
+        c,
+        '\u{A0}' // No-break space
+        | '\u{2007}' // Figure space
+        | '\u{2011}' // Non-breaking hyphen
+        | '\u{202F}' // Narrow no-break space
+        | '\u{2060}' // Word joiner
+        | '\u{FEFF}' // Zero width no-break space
+    )
+}
+
 #[inline]
 fn is_cjk_ideograph(c: char) -> bool {
     ('\u{4E00}' <= c && c <= '\u{9FFF}')
@@ -1084,6 +1326,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_elide_middle() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let text_format = TextFormat {
+            font_id: FontId::monospace(12.0),
+            ..Default::default()
+        };
+        let char_width = layout(
+            &mut fonts,
+            LayoutJob::single_section("x".into(), text_format.clone()).into(),
+        )
+        .size()
+        .x;
+
+        let layout_middle = |fonts: &mut FontsImpl, text: &str, num_chars: f32| {
+            let mut layout_job = LayoutJob::single_section(text.into(), text_format.clone());
+            layout_job.wrap = TextWrapping::from_wrap_mode_and_width(
+                TextWrapMode::TruncateMiddle,
+                num_chars * char_width,
+            );
+            layout(fonts, layout_job.into())
+        };
+
+        let galley = layout_middle(&mut fonts, "/home/user/file.txt", 9.5);
+        assert!(galley.elided);
+        assert_eq!(galley.text(), "/home/user/file.txt");
+        assert_eq!(galley.rows.len(), 1);
+        assert_eq!(galley.rows[0].text(), "/hom….txt");
+
+        let galley = layout_middle(&mut fonts, "short", 9.0);
+        assert!(!galley.elided);
+        assert_eq!(galley.rows[0].text(), "short");
+
+        let galley = layout_middle(&mut fonts, "first\nsecond", 9.5);
+        assert!(galley.elided);
+        assert_eq!(galley.rows.len(), 1);
+        assert_eq!(galley.rows[0].text(), "firs…cond");
+    }
+
+    #[test]
+    fn test_placed_glyphs_elide_middle() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let text_format = TextFormat {
+            font_id: FontId::monospace(12.0),
+            ..Default::default()
+        };
+        let char_width = layout(
+            &mut fonts,
+            LayoutJob::single_section("x".into(), text_format.clone()).into(),
+        )
+        .size()
+        .x;
+
+        let text = "/home/user/file.txt";
+        let mut layout_job = LayoutJob::single_section(text.into(), text_format);
+        layout_job.wrap =
+            TextWrapping::from_wrap_mode_and_width(TextWrapMode::TruncateMiddle, 9.5 * char_width);
+        let galley = layout(&mut fonts, layout_job.into());
+        assert_eq!(galley.rows[0].text(), "/hom….txt");
+        assert_eq!(galley.elided_middle, Some(4..15));
+
+        let glyphs: Vec<_> = galley.placed_glyphs().collect();
+        let glyph_texts: Vec<&str> = glyphs
+            .iter()
+            .map(|glyph| &text[glyph.byte_range.clone()])
+            .collect();
+        assert_eq!(
+            glyph_texts,
+            vec!["/", "h", "o", "m", "e/user/file", ".", "t", "x", "t"]
+        );
+
+        // The visible suffix maps back to the end of the text, not the hidden middle:
+        let suffix_start = text.len() - ".txt".len();
+        let suffix_glyph = &glyphs[5];
+        assert_eq!(suffix_glyph.byte_range.start, suffix_start);
+        assert_eq!(
+            galley.pos_from_byte_offset(suffix_start).left(),
+            suffix_glyph.rect.left()
+        );
+        assert_eq!(
+            galley.byte_offset_from_pos(suffix_glyph.rect.left_center().to_vec2()),
+            suffix_start
+        );
+        let suffix_rects = galley.rects_from_byte_range(suffix_start..text.len());
+        assert_eq!(suffix_rects.len(), 1);
+        assert_eq!(suffix_rects[0].left(), suffix_glyph.rect.left());
+
+        // A caret in the hidden middle is put before the overflow character:
+        assert_eq!(galley.pos_from_byte_offset(8).left(), glyphs[4].rect.left());
+    }
+
     #[test]
     fn test_cjk() {
         let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
@@ -1113,4 +1446,111 @@ mod tests {
             vec!["日本語とEnglish", "の混在した文章"]
         );
     }
+
+    #[test]
+    fn test_non_breaking_space() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let mut layout_job =
+            LayoutJob::single_section("aaaa bbbb\u{A0}cccc".into(), TextFormat::default());
+        layout_job.wrap.max_width = 70.0;
+        let galley = layout(&mut fonts, layout_job.into());
+        assert_eq!(
+            galley.rows.iter().map(|row| row.text()).collect::<Vec<_>>(),
+            vec!["aaaa ", "bbbb\u{A0}cccc"]
+        );
+    }
+
+    #[test]
+    fn test_soft_hyphen() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let text = "hyphen\u{AD}ation";
+
+        let mut layout_job = LayoutJob::single_section(text.into(), TextFormat::default());
+        layout_job.wrap.max_width = 50.0;
+        let galley = layout(&mut fonts, layout_job.into());
+        assert_eq!(
+            galley.rows.iter().map(|row| row.text()).collect::<Vec<_>>(),
+            vec!["hyphen\u{AD}", "ation"]
+        );
+        assert!(
+            galley.rows[0].glyphs.last().unwrap().size.x > 0.0,
+            "The hyphen should be visible at the end of the row"
+        );
+
+        let mut layout_job = LayoutJob::single_section(text.into(), TextFormat::default());
+        layout_job.wrap.max_width = f32::INFINITY;
+        let galley = layout(&mut fonts, layout_job.into());
+        assert_eq!(galley.rows.len(), 1);
+        assert_eq!(galley.rows[0].glyphs[6].size.x, 0.0);
+    }
+
+    #[test]
+    fn test_line_break_fn() {
+        fn break_after_middle_dot(chr: char, _next: Option<char>) -> LineBreak {
+            if chr == '·' {
+                LineBreak::Allowed
+            } else {
+                LineBreak::Default
+            }
+        }
+
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let mut layout_job =
+            LayoutJob::single_section("some·long·path".into(), TextFormat::default());
+        layout_job.wrap.max_width = 60.0;
+        layout_job.wrap.line_break_fn = Some(break_after_middle_dot);
+        let galley = layout(&mut fonts, layout_job.into());
+        assert_eq!(
+            galley.rows.iter().map(|row| row.text()).collect::<Vec<_>>(),
+            vec!["some·", "long·", "path"]
+        );
+    }
+
+    #[test]
+    fn test_tab_stops() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+
+        let mut x_after_tab = vec![];
+        for text in ["a\tb", "abcdef\tb"] {
+            let mut layout_job = LayoutJob::single_section(text.into(), TextFormat::default());
+            layout_job.tab_stops = Some(TabStops::every(50.0));
+            let galley = layout(&mut fonts, layout_job.into());
+            x_after_tab.push(galley.rows[0].glyphs.last().unwrap().pos.x);
+        }
+        assert_eq!(x_after_tab, vec![50.0, 50.0]);
+
+        let tab_stops = TabStops::at(vec![30.0, 100.0]).then_every(50.0);
+        assert_eq!(tab_stops.next_stop(0.0), Some(30.0));
+        assert_eq!(tab_stops.next_stop(30.0), Some(100.0));
+        assert_eq!(tab_stops.next_stop(120.0), Some(150.0));
+        assert_eq!(TabStops::at(vec![30.0]).next_stop(40.0), None);
+    }
+
+    #[test]
+    fn test_placed_glyphs() {
+        let mut fonts = FontsImpl::new(1.0, 1024, FontDefinitions::default());
+        let text = "aé\nb";
+        let layout_job = LayoutJob::single_section(text.into(), TextFormat::default());
+        let galley = layout(&mut fonts, layout_job.into());
+
+        let glyphs: Vec<_> = galley.placed_glyphs().collect();
+        assert_eq!(
+            glyphs
+                .iter()
+                .map(|glyph| (glyph.chr, glyph.byte_range.clone(), glyph.row))
+                .collect::<Vec<_>>(),
+            vec![('a', 0..1, 0), ('é', 1..3, 0), ('b', 4..5, 1)]
+        );
+
+        for glyph in &glyphs {
+            let caret = galley.pos_from_byte_offset(glyph.byte_range.start);
+            assert_eq!(caret.left(), glyph.rect.left());
+            assert_eq!(
+                galley.byte_offset_from_pos(glyph.rect.left_center().to_vec2()),
+                glyph.byte_range.start
+            );
+        }
+
+        assert_eq!(galley.rects_from_byte_range(1..5).len(), 2);
+    }
 }
@@ -78,6 +78,11 @@ pub struct LayoutJob {
     /// Rounding to the closest ui point (not pixel!) allows the rest of the
     /// layout code to run on perfect integers, avoiding rounding errors.
     pub round_output_size_to_nearest_ui_point: bool,
+
+    /// Where `\t` characters should align the following text to.
+    ///
+    /// If `None` (the default), each `\t` is as wide as [`super::TAB_SIZE`] spaces.
+    pub tab_stops: Option<TabStops>,
 }
 
 impl Default for LayoutJob {
@@ -92,6 +97,7 @@ impl Default for LayoutJob {
             halign: Align::LEFT,
             justify: false,
             round_output_size_to_nearest_ui_point: true,
+            tab_stops: None,
         }
     }
 }
@@ -186,6 +192,7 @@ impl std::hash::Hash for LayoutJob {
             halign,
             justify,
             round_output_size_to_nearest_ui_point,
+            tab_stops,
         } = self;
 
         text.hash(state);
@@ -196,6 +203,93 @@ impl std::hash::Hash for LayoutJob {
         halign.hash(state);
         justify.hash(state);
         round_output_size_to_nearest_ui_point.hash(state);
+        tab_stops.hash(state);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Tab stop positions for a [`LayoutJob`], so that `\t` can be used to align text in columns.
+///
+/// All positions are in points, measured from the start of the paragraph.
+/// A `\t` advances the text to the first tab stop that is to the right of it.
+///
+/// Tab stops are resolved before the text is wrapped,
+/// so tabs on the continuation rows of a wrapped paragraph won't line up with the columns of the first row.
+/// Use `\n` to start a new paragraph if you want every row to align.
+///
+/// ```
+/// # use epaint::text::{LayoutJob, TabStops};
+/// let mut job = LayoutJob::default();
+/// job.tab_stops = Some(TabStops::at(vec![100.0, 150.0]).then_every(50.0));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TabStops {
+    /// Explicit tab stop positions. Should be sorted in ascending order.
+    pub positions: Vec<f32>,
+
+    /// After the last of the [`Self::positions`], there is a tab stop every this many points.
+    ///
+    /// If this is zero, a `\t` past the last explicit position falls back to the normal tab width
+    /// of the font ([`crate::text::TAB_SIZE`] spaces).
+    pub interval: f32,
+}
+
+impl TabStops {
+    /// A tab stop every `interval` points.
+    #[inline]
+    pub fn every(interval: f32) -> Self {
+        Self {
+            positions: vec![],
+            interval,
+        }
+    }
+
+    /// Tab stops at the given positions.
+    #[inline]
+    pub fn at(positions: Vec<f32>) -> Self {
+        Self {
+            positions,
+            interval: 0.0,
+        }
+    }
+
+    /// After the last explicit position, have a tab stop every `interval` points.
+    #[inline]
+    pub fn then_every(mut self, interval: f32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The first tab stop that is strictly to the right of `x`, if any.
+    pub fn next_stop(&self, x: f32) -> Option<f32> {
+        if let Some(&stop) = self.positions.iter().find(|&&stop| x < stop) {
+            return Some(stop);
+        }
+
+        if 0.0 < self.interval {
+            let start = self.positions.last().copied().unwrap_or(0.0).max(0.0);
+            let num_intervals = ((x - start) / self.interval).floor().max(0.0) + 1.0;
+            Some(start + num_intervals * self.interval)
+        } else {
+            None
+        }
+    }
+}
+
+impl std::hash::Hash for TabStops {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let Self {
+            positions,
+            interval,
+        } = self;
+        positions.len().hash(state);
+        for position in positions {
+            OrderedFloat(*position).hash(state);
+        }
+        OrderedFloat(*interval).hash(state);
     }
 }
 
@@ -329,8 +423,11 @@ impl TextFormat {
 /// How to wrap and elide text.
 ///
 /// This enum is used in high-level APIs where providing a [`TextWrapping`] is too verbose.
+///
+/// More ways of wrapping text may be added in the future, so a `match` on it needs a wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[non_exhaustive]
 pub enum TextWrapMode {
     /// The text should expand the `Ui` size when reaching its boundary.
     Extend,
@@ -342,6 +439,15 @@ pub enum TextWrapMode {
     ///
     /// Note that using [`TextWrapping`] and [`LayoutJob`] offers more control over the elision.
     Truncate,
+
+    /// Like [`Self::Truncate`], but the "…" replaces the middle of the text,
+    /// keeping both the start and the end of it visible.
+    ///
+    /// Useful for file paths and other text where the end is as important as the start.
+    ///
+    /// The glyphs of such text don't line up with its characters, so labels elided in the middle
+    /// can't be selected, and a `TextEdit` ignores the wrap mode and never elides its text.
+    TruncateMiddle,
 }
 
 /// Controls the text wrapping and elision of a [`LayoutJob`].
@@ -389,8 +495,65 @@ pub struct TextWrapping {
     ///
     /// If not set, no character will be used (but the text will still be elided).
     pub overflow_character: Option<char>,
+
+    /// Where to elide text that doesn't fit.
+    ///
+    /// [`ElisionPosition::Middle`] only has an effect when [`Self::max_rows`] is `1`.
+    pub elision_position: ElisionPosition,
+
+    /// If `true` (default): soft hyphens (`U+00AD`) are invisible, but mark places
+    /// where a word may be broken. If a row is broken at a soft hyphen, a `-` is shown.
+    ///
+    /// If `false`, soft hyphens are treated like any other character.
+    pub hyphenate: bool,
+
+    /// Optional hook for deciding where text may be broken when wrapping.
+    ///
+    /// It is called for every character, and decides whether or not
+    /// a row may be broken right after it.
+    /// Return [`LineBreak::Default`] to fall back to the built-in rules
+    /// (which handle spaces, non-breaking spaces, dashes, punctuation and CJK).
+    ///
+    /// This is a plain `fn` (rather than a closure) so that [`LayoutJob`] stays hashable.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub line_break_fn: Option<LineBreakFn>,
+}
+
+/// Where to put the [`TextWrapping::overflow_character`] when eliding text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ElisionPosition {
+    /// Keep the start of the text: `a long sent…`
+    #[default]
+    End,
+
+    /// Keep the start and the end of the text: `a lon…tence`
+    ///
+    /// Any `\n` in the elided text is dropped, so the result is always a single row.
+    Middle,
+}
+
+/// The decision of a [`LineBreakFn`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LineBreak {
+    /// Use the built-in rules.
+    Default,
+
+    /// A good place to break the row, as good as a space.
+    Allowed,
+
+    /// Only break here if there is no other choice,
+    /// i.e. if the word doesn't fit on a row by itself.
+    Forbidden,
 }
 
+/// Decides if a row may be broken right after `chr`.
+///
+/// `next` is the character following `chr` in the same paragraph, if any.
+///
+/// See [`TextWrapping::line_break_fn`].
+pub type LineBreakFn = fn(chr: char, next: Option<char>) -> LineBreak;
+
 impl std::hash::Hash for TextWrapping {
     #[inline]
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
@@ -399,11 +562,17 @@ impl std::hash::Hash for TextWrapping {
             max_rows,
             break_anywhere,
             overflow_character,
+            elision_position,
+            hyphenate,
+            line_break_fn,
         } = self;
         emath::OrderedFloat(*max_width).hash(state);
         max_rows.hash(state);
         break_anywhere.hash(state);
         overflow_character.hash(state);
+        elision_position.hash(state);
+        hyphenate.hash(state);
+        line_break_fn.hash(state);
     }
 }
 
@@ -414,6 +583,9 @@ impl Default for TextWrapping {
             max_rows: usize::MAX,
             break_anywhere: false,
             overflow_character: Some('…'),
+            elision_position: ElisionPosition::End,
+            hyphenate: true,
+            line_break_fn: None,
         }
     }
 }
@@ -425,6 +597,10 @@ impl TextWrapping {
             TextWrapMode::Extend => Self::no_max_width(),
             TextWrapMode::Wrap => Self::wrap_at_width(max_width),
             TextWrapMode::Truncate => Self::truncate_at_width(max_width),
+            TextWrapMode::TruncateMiddle => Self {
+                elision_position: ElisionPosition::Middle,
+                ..Self::truncate_at_width(max_width)
+            },
         }
     }
 
@@ -488,8 +664,18 @@ pub struct Galley {
     pub rows: Vec<Row>,
 
     /// Set to true the text was truncated due to [`TextWrapping::max_rows`].
+    ///
+    /// The text of an elided galley is the full text,
+    /// so you can e.g. show it in a tooltip.
     pub elided: bool,
 
+    /// The range of characters (not bytes) of the text that were hidden by [`ElisionPosition::Middle`].
+    ///
+    /// If set, the glyphs after the elision point do not line up with the characters of the text,
+    /// and [`CCursor`]s count glyphs rather than characters.
+    /// [`Self::placed_glyphs`] and the byte offset functions take this into account.
+    pub elided_middle: Option<Range<usize>>,
+
     /// Bounding rect.
     ///
     /// `rect.top()` is always 0.0.
@@ -832,6 +1018,177 @@ impl Galley {
     }
 }
 
+/// A glyph of a [`Galley`], together with where it came from in the text.
+///
+/// See [`Galley::placed_glyphs`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlacedGlyph {
+    /// The character this glyph represents.
+    pub chr: char,
+
+    /// The logical rectangle of the glyph, relative to the galley.
+    ///
+    /// The rectangle covers the full row height, so it is suitable for e.g. hit-testing and selections.
+    pub rect: Rect,
+
+    /// The range of bytes in [`LayoutJob::text`] that this glyph represents.
+    ///
+    /// If the galley was elided, the overflow character represents the hidden text,
+    /// i.e. the rest of the text, or the middle of it with [`ElisionPosition::Middle`].
+    pub byte_range: Range<usize>,
+
+    /// Index into [`Galley::rows`].
+    pub row: usize,
+
+    /// Index into [`LayoutJob::sections`].
+    pub section_index: u32,
+}
+
+/// ## Byte offsets and glyph geometry
+impl Galley {
+    /// Convert a byte offset into [`LayoutJob::text`] into a [`CCursor`].
+    ///
+    /// Byte offsets that are not on a character boundary are rounded down to one.
+    /// Byte offsets in text hidden by [`ElisionPosition::Middle`] map to the overflow character.
+    pub fn ccursor_from_byte_offset(&self, byte_offset: usize) -> CCursor {
+        let text = self.text();
+        let mut byte_offset = byte_offset.min(text.len());
+        while !text.is_char_boundary(byte_offset) {
+            byte_offset -= 1;
+        }
+        let char_index = text[..byte_offset].chars().count();
+        CCursor::new(self.glyph_index_from_char_index(char_index))
+    }
+
+    /// Convert a [`CCursor`] into a byte offset into [`LayoutJob::text`].
+    pub fn byte_offset_from_ccursor(&self, ccursor: CCursor) -> usize {
+        let text = self.text();
+        text.char_indices()
+            .nth(self.char_index_from_glyph_index(ccursor.index))
+            .map_or(text.len(), |(byte_offset, _)| byte_offset)
+    }
+
+    /// Number of glyphs standing in for the characters hidden by [`Self::elided_middle`].
+    fn num_overflow_glyphs(&self) -> usize {
+        usize::from(self.job.wrap.overflow_character.is_some())
+    }
+
+    fn glyph_index_from_char_index(&self, char_index: usize) -> usize {
+        match &self.elided_middle {
+            Some(hidden) if hidden.end <= char_index => {
+                char_index - hidden.len() + self.num_overflow_glyphs()
+            }
+            Some(hidden) if hidden.start < char_index => hidden.start,
+            _ => char_index,
+        }
+    }
+
+    fn char_index_from_glyph_index(&self, glyph_index: usize) -> usize {
+        match &self.elided_middle {
+            Some(hidden) if hidden.start + self.num_overflow_glyphs() <= glyph_index => {
+                glyph_index - self.num_overflow_glyphs() + hidden.len()
+            }
+            _ => glyph_index,
+        }
+    }
+
+    /// The caret rectangle before the character at the given byte offset into [`LayoutJob::text`].
+    ///
+    /// Returns a 0-width Rect, relative to the galley.
+    pub fn pos_from_byte_offset(&self, byte_offset: usize) -> Rect {
+        self.pos_from_ccursor(self.ccursor_from_byte_offset(byte_offset))
+    }
+
+    /// The byte offset into [`LayoutJob::text`] of the caret position closest to the given position.
+    ///
+    /// `pos` is relative to the galley.
+    pub fn byte_offset_from_pos(&self, pos: Vec2) -> usize {
+        self.byte_offset_from_ccursor(self.cursor_from_pos(pos).ccursor)
+    }
+
+    /// Iterate over all glyphs in the galley, with their positions and source byte ranges.
+    ///
+    /// Newlines that break paragraphs do not produce any glyphs.
+    pub fn placed_glyphs(&self) -> impl Iterator<Item = PlacedGlyph> + '_ {
+        let text = self.text();
+        let mut chars = text.char_indices().enumerate().peekable();
+        let num_rows = self.rows.len();
+        let num_overflow_glyphs = self.num_overflow_glyphs();
+
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(move |(row_index, row)| {
+                let is_last_row = row_index + 1 == num_rows;
+                let num_glyphs = row.glyphs.len();
+
+                let skip_hidden = |chars: &mut std::iter::Peekable<_>| {
+                    if let Some(hidden) = &self.elided_middle {
+                        while chars.next_if(|&(c, _)| hidden.contains(&c)).is_some() {}
+                    }
+                };
+
+                let mut placed = Vec::with_capacity(num_glyphs);
+                for (glyph_index, glyph) in row.glyphs.iter().enumerate() {
+                    let is_last_glyph = is_last_row && glyph_index + 1 == num_glyphs;
+                    if num_overflow_glyphs == 0 {
+                        skip_hidden(&mut chars);
+                    }
+                    let (char_index, start) = chars
+                        .next()
+                        .map_or((usize::MAX, text.len()), |(c, (i, _))| (c, i));
+                    let is_overflow_glyph = 0 < num_overflow_glyphs
+                        && self
+                            .elided_middle
+                            .as_ref()
+                            .map_or(false, |hidden| hidden.start == char_index);
+                    if is_overflow_glyph {
+                        skip_hidden(&mut chars);
+                    }
+                    let end = if is_last_glyph && self.elided && self.elided_middle.is_none() {
+                        text.len()
+                    } else {
+                        chars.peek().map_or(text.len(), |&(_, (i, _))| i)
+                    };
+                    placed.push(PlacedGlyph {
+                        chr: glyph.chr,
+                        rect: Rect::from_x_y_ranges(
+                            glyph.pos.x..=glyph.max_x(),
+                            row.rect.y_range(),
+                        ),
+                        byte_range: start..end,
+                        row: row_index,
+                        section_index: glyph.section_index,
+                    });
+                }
+                if row.ends_with_newline {
+                    chars.next(); // The `\n` has no glyph
+                }
+                placed
+            })
+    }
+
+    /// One rectangle per row, covering those glyphs that overlap the given byte range.
+    ///
+    /// Useful for painting custom selections, underlines, or highlights.
+    /// The rectangles are relative to the galley.
+    pub fn rects_from_byte_range(&self, byte_range: Range<usize>) -> Vec<Rect> {
+        let mut rects: Vec<(usize, Rect)> = vec![];
+        for glyph in self.placed_glyphs() {
+            let overlaps =
+                glyph.byte_range.start < byte_range.end && byte_range.start < glyph.byte_range.end;
+            if !overlaps {
+                continue;
+            }
+            match rects.last_mut() {
+                Some((row, rect)) if *row == glyph.row => *rect = rect.union(glyph.rect),
+                _ => rects.push((glyph.row, glyph.rect)),
+            }
+        }
+        rects.into_iter().map(|(_, rect)| rect).collect()
+    }
+}
+
 /// ## Cursor positions
 impl Galley {
     /// Cursor to the first character.
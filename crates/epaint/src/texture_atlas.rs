@@ -75,8 +75,13 @@ pub struct TextureAtlas {
 }
 
 impl TextureAtlas {
+    /// The smallest allowed width of the atlas.
+    ///
+    /// This is still enough for the pre-rasterized discs and a few sizes of text.
+    pub const MIN_SIDE: usize = 512;
+
     pub fn new(size: [usize; 2]) -> Self {
-        assert!(size[0] >= 1024, "Tiny texture atlas");
+        assert!(size[0] >= Self::MIN_SIDE, "Tiny texture atlas");
         let mut atlas = Self {
             image: FontImage::new(size),
             dirty: Rectu::EVERYTHING,